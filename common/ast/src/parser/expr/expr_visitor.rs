@@ -22,7 +22,9 @@ use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ListAgg;
 use sqlparser::ast::Query;
+use sqlparser::ast::TrimWhereField;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
 
@@ -50,6 +52,8 @@ pub trait ExprVisitor: Sized + Send {
             Expr::CompoundIdentifier(idents) => self.visit_identifiers(idents),
             Expr::IsNull(expr) => self.visit_simple_function(expr, "isnull").await,
             Expr::IsNotNull(expr) => self.visit_simple_function(expr, "isnotnull").await,
+            Expr::IsDistinctFrom(left, right) => self.visit_distinct_from(left, right).await,
+            Expr::IsNotDistinctFrom(left, right) => self.visit_distinct_from(left, right).await,
             Expr::UnaryOp { op, expr } => self.visit_unary_expr(op, expr).await,
             Expr::BinaryOp { left, op, right } => self.visit_binary_expr(left, op, right).await,
             Expr::Exists(subquery) => self.visit_exists(subquery),
@@ -69,6 +73,7 @@ pub trait ExprVisitor: Sized + Send {
                 self.visit_substring(expr, substring_from, substring_for)
                     .await
             }
+            Expr::Trim { expr, trim_where } => self.visit_trim(expr, trim_where).await,
             Expr::Between {
                 expr,
                 negated,
@@ -77,6 +82,7 @@ pub trait ExprVisitor: Sized + Send {
             } => self.visit_between(expr, negated, low, high).await,
             Expr::Tuple(exprs) => self.visit_tuple(exprs).await,
             Expr::InList { expr, list, .. } => self.visit_inlist(expr, list).await,
+            Expr::ListAgg(list_agg) => self.visit_listagg(list_agg).await,
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -96,6 +102,14 @@ pub trait ExprVisitor: Sized + Send {
         Ok(())
     }
 
+    async fn visit_listagg(&mut self, list_agg: &ListAgg) -> Result<()> {
+        ExprTraverser::accept(&list_agg.expr, self).await?;
+        for order_by in &list_agg.within_group {
+            ExprTraverser::accept(&order_by.expr, self).await?;
+        }
+        Ok(())
+    }
+
     async fn visit_tuple(&mut self, exprs: &[Expr]) -> Result<()> {
         match exprs.len() {
             0 => Err(ErrorCode::SyntaxException(
@@ -155,6 +169,16 @@ pub trait ExprVisitor: Sized + Send {
             };
         }
 
+        // The window follows the arguments: the partition expressions, then the sort keys.
+        if let Some(window) = &function.over {
+            for expr in &window.partition_by {
+                ExprTraverser::accept(expr, self).await?;
+            }
+            for order_by in &window.order_by {
+                ExprTraverser::accept(&order_by.expr, self).await?;
+            }
+        }
+
         Ok(())
     }
 
@@ -196,6 +220,11 @@ pub trait ExprVisitor: Sized + Send {
         ExprTraverser::accept(high, self).await
     }
 
+    async fn visit_distinct_from(&mut self, left: &Expr, right: &Expr) -> Result<()> {
+        ExprTraverser::accept(left, self).await?;
+        ExprTraverser::accept(right, self).await
+    }
+
     async fn visit_position(&mut self, substr_expr: &Expr, str_expr: &Expr) -> Result<()> {
         ExprTraverser::accept(substr_expr, self).await?;
         ExprTraverser::accept(str_expr, self).await
@@ -219,4 +248,18 @@ pub trait ExprVisitor: Sized + Send {
 
         Ok(())
     }
+
+    async fn visit_trim(
+        &mut self,
+        expr: &Expr,
+        trim_where: &Option<(TrimWhereField, Box<Expr>)>,
+    ) -> Result<()> {
+        ExprTraverser::accept(expr, self).await?;
+
+        if let Some((_, trim_chars)) = trim_where {
+            ExprTraverser::accept(trim_chars, self).await?;
+        }
+
+        Ok(())
+    }
 }
@@ -12,16 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UDFDefinition {
     pub parameters: Vec<String>,
     pub expr: Expr,
+    // The types of the typed UDF, the arguments and the result are cast to them.
+    pub parameter_types: Vec<DataType>,
+    pub return_type: Option<DataType>,
 }
 
 impl UDFDefinition {
     pub fn new(parameters: Vec<String>, expr: Expr) -> Self {
-        Self { parameters, expr }
+        Self {
+            parameters,
+            expr,
+            parameter_types: vec![],
+            return_type: None,
+        }
+    }
+
+    pub fn with_types(mut self, parameter_types: Vec<DataType>, return_type: DataType) -> Self {
+        self.parameter_types = parameter_types;
+        self.return_type = Some(return_type);
+        self
     }
 }
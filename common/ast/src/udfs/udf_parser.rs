@@ -18,6 +18,7 @@ use async_trait::async_trait;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::is_builtin_function;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Function;
 use sqlparser::ast::Ident;
@@ -46,6 +47,22 @@ impl UDFParser {
         Ok(expr)
     }
 
+    pub fn parse_data_type(&self, sql_type: &str) -> Result<DataType> {
+        let dialect = &GenericDialect {};
+        let mut tokenizer = Tokenizer::new(dialect, sql_type);
+
+        match tokenizer.tokenize() {
+            Ok(tokens) => match Parser::new(tokens, dialect).parse_data_type() {
+                Ok(data_type) => Ok(data_type),
+                Err(parse_error) => Err(ErrorCode::from(parse_error)),
+            },
+            Err(tokenize_error) => Err(ErrorCode::SyntaxException(format!(
+                "Can not tokenize data type: {}, Error: {:?}",
+                sql_type, tokenize_error
+            ))),
+        }
+    }
+
     fn parse_definition(&mut self, definition: &str) -> Result<Expr> {
         let dialect = &GenericDialect {};
         let mut tokenizer = Tokenizer::new(dialect, definition);
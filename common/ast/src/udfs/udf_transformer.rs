@@ -35,6 +35,7 @@ impl UDFTransformer {
             .get_udf_definition(&function.name.to_string())
             .await?;
         let parameters = definition.parameters;
+        let parameter_types = definition.parameter_types;
         let expr = definition.expr;
 
         if parameters.len() != function.args.len() {
@@ -48,23 +49,41 @@ impl UDFTransformer {
         let mut args_map = HashMap::new();
         function.args.iter().enumerate().for_each(|(index, f_arg)| {
             if let Some(param) = parameters.get(index) {
-                args_map.insert(param, match f_arg {
-                    FunctionArg::Named { arg, .. } => arg.clone(),
-                    FunctionArg::Unnamed(unnamed_arg) => unnamed_arg.clone(),
-                });
+                args_map.insert(
+                    param,
+                    (index, match f_arg {
+                        FunctionArg::Named { arg, .. } => arg.clone(),
+                        FunctionArg::Unnamed(unnamed_arg) => unnamed_arg.clone(),
+                    }),
+                );
             }
         });
 
-        Self::clone_expr_with_replacement(&expr, &|nest_expr| {
+        let expr = Self::clone_expr_with_replacement(&expr, &|nest_expr| {
             if let Expr::Identifier(Ident { value, .. }) = nest_expr {
-                if let Some(arg) = args_map.get(value) {
+                if let Some((index, arg)) = args_map.get(value) {
                     if let Ok(expr) = function_arg_as_expr(arg) {
-                        return Ok(Some(expr));
+                        // The arguments of the typed UDF are cast to the parameter types.
+                        return Ok(Some(match parameter_types.get(*index) {
+                            Some(data_type) => Expr::Cast {
+                                expr: Box::new(expr),
+                                data_type: data_type.clone(),
+                            },
+                            None => expr,
+                        }));
                     }
                 }
             }
 
             Ok(None)
+        })?;
+
+        Ok(match definition.return_type {
+            Some(data_type) => Expr::Cast {
+                expr: Box::new(expr),
+                data_type,
+            },
+            None => expr,
         })
     }
 
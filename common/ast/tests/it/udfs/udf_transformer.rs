@@ -18,6 +18,7 @@ use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use pretty_assertions::assert_eq;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArg;
@@ -38,6 +39,14 @@ impl UDFFetcher for TestFetcher {
                 .await?;
 
             Ok(UDFDefinition::new(vec!["p".to_string()], expr))
+        } else if name == "test_typed_transformer" {
+            let mut parser = UDFParser::default();
+            let expr = parser.parse(name, &["p".to_string()], "p").await?;
+            let parameter_types = vec![parser.parse_data_type("INT")?];
+            let return_type = parser.parse_data_type("BIGINT")?;
+
+            Ok(UDFDefinition::new(vec!["p".to_string()], expr)
+                .with_types(parameter_types, return_type))
         } else {
             Err(ErrorCode::UnImplement("Unimplement error"))
         }
@@ -109,3 +118,37 @@ async fn test_udf_transformer() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_typed_udf_transformer() -> Result<()> {
+    let fetcher = &TestFetcher {};
+    let arg = Expr::Identifier(Ident {
+        value: "test".to_string(),
+        quote_style: None,
+    });
+    let result = UDFTransformer::transform_function(
+        &Function {
+            name: ObjectName(vec![Ident {
+                value: "test_typed_transformer".to_string(),
+                quote_style: None,
+            }]),
+            params: vec![],
+            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(arg.clone()))],
+            over: None,
+            distinct: false,
+        },
+        fetcher,
+    )
+    .await?;
+
+    // The argument is cast to the parameter type, and the result to the return type.
+    assert_eq!(result, Expr::Cast {
+        expr: Box::new(Expr::Cast {
+            expr: Box::new(arg),
+            data_type: DataType::Int(None),
+        }),
+        data_type: DataType::BigInt(None),
+    });
+
+    Ok(())
+}
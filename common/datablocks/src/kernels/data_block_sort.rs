@@ -61,6 +61,39 @@ impl DataBlock {
         DataBlock::block_take_by_indices(block, indices.values())
     }
 
+    /// Keep the first `limit` rows of a sorted block, and the rows following them which tie
+    /// with the last kept row on the sort columns.
+    pub fn limit_sorted_block_with_ties(
+        block: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+        limit: usize,
+    ) -> Result<DataBlock> {
+        if block.num_rows() <= limit {
+            return Ok(block.clone());
+        }
+
+        if limit == 0 {
+            return Ok(DataBlock::slice_block(block, 0, 0));
+        }
+
+        let sort_columns = sort_columns_descriptions
+            .iter()
+            .map(|f| block.try_column_by_name(&f.column_name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let last = limit - 1;
+        let mut end = limit;
+        while end < block.num_rows()
+            && sort_columns
+                .iter()
+                .all(|column| column.get(end) == column.get(last))
+        {
+            end += 1;
+        }
+
+        Ok(DataBlock::slice_block(block, 0, end))
+    }
+
     pub fn merge_sort_block(
         lhs: &DataBlock,
         rhs: &DataBlock,
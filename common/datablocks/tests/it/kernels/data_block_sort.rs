@@ -116,3 +116,62 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_limit_sorted_block_with_ties() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let raw = DataBlock::create(schema, vec![
+        Series::from_data(vec![1, 2, 2, 2, 3, 4]),
+        Series::from_data(vec!["b1", "b2", "b3", "b4", "b5", "b6"]),
+    ]);
+
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+    }];
+
+    {
+        let results = DataBlock::limit_sorted_block_with_ties(&raw, &options, 2)?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | b1 |",
+            "| 2 | b2 |",
+            "| 2 | b3 |",
+            "| 2 | b4 |",
+            "+---+----+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let results = DataBlock::limit_sorted_block_with_ties(&raw, &options, 5)?;
+        let expected = vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | b1 |",
+            "| 2 | b2 |",
+            "| 2 | b3 |",
+            "| 2 | b4 |",
+            "| 3 | b5 |",
+            "+---+----+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let results = DataBlock::limit_sorted_block_with_ties(&raw, &options, 0)?;
+        assert_eq!(results.num_rows(), 0);
+    }
+
+    Ok(())
+}
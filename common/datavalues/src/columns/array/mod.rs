@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mutable;
+
 use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::types::Index;
+pub use mutable::*;
 
 use crate::prelude::*;
 
@@ -139,9 +142,33 @@ impl Column for ArrayColumn {
             "Size of offsets must match size of column"
         );
 
-        // match datatypes
-        // TODO: see https://github.com/ClickHouse/ClickHouse/blob/340b53ef853348758c9042b16a8599120ebc8d22/src/Columns/ColumnArray.cpp
-        todo!()
+        if offsets.is_empty() {
+            return self.slice(0, 0);
+        }
+
+        // Replicate the element ranges of each row, then take the values by the new indices.
+        let mut indices: Vec<u32> = Vec::with_capacity(self.values.len());
+        let mut new_offsets: Vec<i64> = Vec::with_capacity(*offsets.last().unwrap() + 1);
+        new_offsets.push(0);
+
+        let mut previous_offset: usize = 0;
+        (0..self.len()).for_each(|i| {
+            let offset: usize = offsets[i];
+            let start = self.offsets[i] as u32;
+            let end = self.offsets[i + 1] as u32;
+            for _ in previous_offset..offset {
+                indices.extend(start..end);
+                new_offsets.push(indices.len() as i64);
+            }
+            previous_offset = offset;
+        });
+
+        let values = Series::take(&self.values, &indices).unwrap();
+        Arc::new(Self {
+            data_type: self.data_type.clone(),
+            offsets: new_offsets.into(),
+            values,
+        })
     }
 
     fn convert_full_column(&self) -> ColumnRef {
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+pub struct MutableArrayColumn {
+    data_type: DataTypePtr,
+    offsets: Vec<i64>,
+    inner_column: Box<dyn MutableColumn>,
+}
+
+impl MutableArrayColumn {
+    pub fn from_data(data_type: DataTypePtr, inner_column: Box<dyn MutableColumn>) -> Self {
+        Self {
+            data_type,
+            offsets: vec![0],
+            inner_column,
+        }
+    }
+
+    #[inline]
+    pub fn append_value(&mut self, values: Vec<DataValue>) -> Result<()> {
+        let length = values.len() as i64;
+        for value in values {
+            self.inner_column.append_data_value(value)?;
+        }
+        self.offsets.push(self.offsets.last().unwrap() + length);
+        Ok(())
+    }
+}
+
+impl MutableColumn for MutableArrayColumn {
+    fn data_type(&self) -> DataTypePtr {
+        self.data_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn append_default(&mut self) {
+        self.offsets.push(*self.offsets.last().unwrap());
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.offsets.shrink_to_fit();
+        self.inner_column.shrink_to_fit();
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn to_column(&mut self) -> ColumnRef {
+        let offsets = std::mem::replace(&mut self.offsets, vec![0]);
+        Arc::new(ArrayColumn::from_data(
+            self.data_type.clone(),
+            offsets.into(),
+            self.inner_column.to_column(),
+        ))
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(values) => self.append_value(values),
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append into array column",
+                other.value_type()
+            ))),
+        }
+    }
+}
+
+/// The mutable column of Map, it can only hold the empty maps so far.
+/// Appending a map with entries returns an UnImplement error.
+pub struct MutableMapColumn {
+    data_type: DataTypePtr,
+    length: usize,
+}
+
+impl MutableMapColumn {
+    pub fn from_data(data_type: DataTypePtr) -> Self {
+        Self {
+            data_type,
+            length: 0,
+        }
+    }
+}
+
+impl MutableColumn for MutableMapColumn {
+    fn data_type(&self) -> DataTypePtr {
+        self.data_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn append_default(&mut self) {
+        self.length += 1;
+    }
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn to_column(&mut self) -> ColumnRef {
+        let length = std::mem::take(&mut self.length);
+        let map_type: &MapType = self.data_type.as_any().downcast_ref().unwrap();
+        // The entry column of the empty maps is empty, it can't fail to be created.
+        let entries = map_type.entry_type().create_column(&[]).unwrap();
+        Arc::new(ArrayColumn::from_data(
+            self.data_type.clone(),
+            vec![0i64; length + 1].into(),
+            entries,
+        ))
+    }
+
+    fn append_data_value(&mut self, value: DataValue) -> Result<()> {
+        match value {
+            DataValue::Array(values) if values.is_empty() => {
+                self.append_default();
+                Ok(())
+            }
+            DataValue::Array(_) => Err(ErrorCode::UnImplement(
+                "Unimplemented appending the entries into map column",
+            )),
+            other => Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to append into map column",
+                other.value_type()
+            ))),
+        }
+    }
+}
@@ -173,9 +173,9 @@ where A: AsRef<dyn Array>
             Int64 | Interval | DateTime64 => Arc::new(Int64Column::from_arrow_array(self.as_ref())),
             Float32 => Arc::new(Float32Column::from_arrow_array(self.as_ref())),
             Float64 => Arc::new(Float64Column::from_arrow_array(self.as_ref())),
-            Array => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
+            Array | Map => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
             Struct => Arc::new(StructColumn::from_arrow_array(self.as_ref())),
-            String => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
+            String | Variant => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
         }
     }
 
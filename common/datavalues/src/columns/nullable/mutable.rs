@@ -61,6 +61,11 @@ impl MutableColumn for MutableNullableColumn {
     }
 
     fn append_data_value(&mut self, value: crate::DataValue) -> Result<()> {
+        if value.is_null() {
+            self.append_default();
+            return Ok(());
+        }
+
         self.values.push(true);
         self.inner.append_data_value(value)
     }
@@ -25,7 +25,7 @@ use common_macros::MallocSizeOf;
 use crate::prelude::*;
 
 /// A specific value of a data type.
-#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, MallocSizeOf)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, PartialOrd, MallocSizeOf)]
 pub enum DataValue {
     /// Base type.
     Null,
@@ -161,6 +161,16 @@ pub fn from_arrow_field(f: &ArrowField) -> DataTypePtr {
                 None => return DateTime64Type::arc(3, None),
             },
             "Interval" => return IntervalType::arc(metadata.unwrap().into()),
+            "Variant" => return VariantType::arc(),
+            "Map" => {
+                if let ArrowType::LargeList(entry) = f.data_type() {
+                    if let ArrowType::Struct(fields) = entry.data_type() {
+                        let key = from_arrow_field(&fields[0]);
+                        let value = from_arrow_field(&fields[1]);
+                        return MapType::arc(key, value);
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::ColumnRef;
+use crate::MutableColumn;
+use crate::MutableMapColumn;
+use crate::TypeDeserializer;
+
+pub struct MapDeserializer {
+    pub builder: MutableMapColumn,
+}
+
+impl TypeDeserializer for MapDeserializer {
+    fn de(&mut self, _reader: &mut &[u8]) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Unimplemented deserializing the map type",
+        ))
+    }
+
+    fn de_default(&mut self) {
+        self.builder.append_default();
+    }
+
+    fn de_batch(&mut self, _reader: &[u8], _step: usize, _rows: usize) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Unimplemented deserializing the map type",
+        ))
+    }
+
+    fn de_text(&mut self, _reader: &[u8]) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Unimplemented deserializing the map type",
+        ))
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
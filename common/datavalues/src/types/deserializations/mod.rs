@@ -19,18 +19,22 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod map;
 mod null;
 mod nullable;
 mod number;
 mod string;
+mod variant;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
 pub use string::*;
+pub use variant::*;
 
 pub trait TypeDeserializer: Send + Sync {
     fn de(&mut self, reader: &mut &[u8]) -> Result<()>;
@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+pub struct VariantDeserializer {
+    pub inner: StringDeserializer,
+}
+
+impl VariantDeserializer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: StringDeserializer::with_capacity(capacity),
+        }
+    }
+}
+
+impl TypeDeserializer for VariantDeserializer {
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.inner.de(reader)
+    }
+
+    fn de_default(&mut self) {
+        self.inner.builder.append_value("null");
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        self.inner.de_batch(reader, step, rows)
+    }
+
+    // The text must be a valid JSON, it's normalized before stored.
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        let value = serde_json::from_slice::<Value>(reader)
+            .map_err(|e| ErrorCode::BadBytes(format!("Incorrect Variant value, cause: {}", e)))?;
+        self.inner.builder.append_value(value.to_string());
+        Ok(())
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.inner.finish_to_column()
+    }
+}
@@ -47,7 +47,8 @@ pub fn equal(lhs: &dyn DataType, rhs: &dyn DataType) -> bool {
     use crate::prelude::TypeID::*;
     match lhs.data_type_id() {
         Boolean | UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32
-        | Float64 | String | Date16 | Date32 | Interval | DateTime32 | DateTime64 | Null => true,
+        | Float64 | String | Date16 | Date32 | Interval | DateTime32 | DateTime64 | Variant
+        | Null => true,
 
         Nullable => {
             let lhs: &NullableType = lhs.as_any().downcast_ref().unwrap();
@@ -63,6 +64,13 @@ pub fn equal(lhs: &dyn DataType, rhs: &dyn DataType) -> bool {
             *lhs.inner_type() == *rhs.inner_type()
         }
 
+        Map => {
+            let lhs: &MapType = lhs.as_any().downcast_ref().unwrap();
+            let rhs: &MapType = rhs.as_any().downcast_ref().unwrap();
+
+            *lhs.key_type() == *rhs.key_type() && *lhs.value_type() == *rhs.value_type()
+        }
+
         Struct => {
             let lhs: &StructType = lhs.as_any().downcast_ref().unwrap();
             let rhs: &StructType = rhs.as_any().downcast_ref().unwrap();
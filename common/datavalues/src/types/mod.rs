@@ -22,12 +22,14 @@ pub mod type_date32;
 pub mod type_datetime32;
 pub mod type_datetime64;
 pub mod type_interval;
+pub mod type_map;
 pub mod type_null;
 pub mod type_nullable;
 pub mod type_primitive;
 pub mod type_string;
 pub mod type_struct;
 pub mod type_traits;
+pub mod type_variant;
 
 pub mod eq;
 pub mod type_id;
@@ -53,9 +55,11 @@ pub use type_datetime64::*;
 pub use type_factory::*;
 pub use type_id::*;
 pub use type_interval::*;
+pub use type_map::*;
 pub use type_null::*;
 pub use type_nullable::*;
 pub use type_primitive::*;
 pub use type_string::*;
 pub use type_struct::*;
 pub use type_traits::*;
+pub use type_variant::*;
@@ -21,21 +21,25 @@ mod array;
 mod boolean;
 mod date;
 mod date_time;
+mod map;
 mod null;
 mod nullable;
 mod number;
 mod string;
 mod struct_;
+mod variant;
 
 pub use array::*;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
 pub use string::*;
 pub use struct_::*;
+pub use variant::*;
 
 pub trait TypeSerializer: Send + Sync {
     fn serialize_value(&self, value: &DataValue) -> Result<String>;
@@ -125,8 +125,11 @@ impl DataType for ArrayType {
         todo!()
     }
 
-    fn create_mutable(&self, _capacity: usize) -> Box<dyn MutableColumn> {
-        todo!()
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableArrayColumn::from_data(
+            Arc::new(self.clone()),
+            self.inner.create_mutable(capacity),
+        ))
     }
 }
 
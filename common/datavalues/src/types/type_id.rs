@@ -67,6 +67,14 @@ pub enum TypeID {
 
     Array,
     Struct,
+
+    /// Map holds the key-value pairs.
+    /// It's stored as an array of the (key, value) structs, it's physical type is Array
+    Map,
+
+    /// Variant holds semi-structured data, e.g. JSON.
+    /// It's stored as the JSON text, it's physical type is String
+    Variant,
 }
 
 impl TypeID {
@@ -168,6 +176,16 @@ impl TypeID {
         matches!(self, TypeID::Struct)
     }
 
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, TypeID::Map)
+    }
+
+    #[inline]
+    pub fn is_variant(&self) -> bool {
+        matches!(self, TypeID::Variant)
+    }
+
     #[inline]
     pub fn is_quoted(&self) -> bool {
         matches!(
@@ -213,8 +231,8 @@ impl TypeID {
             Float32 => PhysicalTypeID::Float32,
             Float64 => PhysicalTypeID::Float64,
 
-            String => PhysicalTypeID::String,
-            Array => PhysicalTypeID::Array,
+            String | Variant => PhysicalTypeID::String,
+            Array | Map => PhysicalTypeID::Array,
             Struct => PhysicalTypeID::Struct,
         }
     }
@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::type_id::TypeID;
+use crate::prelude::*;
+
+/// Variant holds semi-structured data, it's stored as the JSON text in a string column.
+#[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
+pub struct VariantType {}
+
+impl VariantType {
+    pub fn arc() -> DataTypePtr {
+        Arc::new(Self {})
+    }
+}
+
+#[typetag::serde]
+impl DataType for VariantType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Variant
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "Variant"
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["Json"]
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::String(b"null".to_vec())
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_string()?;
+        let bytes = value.as_slice();
+
+        let column = Series::from_data(&[bytes]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let mut values: Vec<u8> = vec![];
+        let mut offsets: Vec<i64> = vec![0];
+        for v in data.iter() {
+            let value = v.as_string()?;
+            offsets.push(offsets.last().unwrap() + value.len() as i64);
+            values.extend_from_slice(&value);
+        }
+
+        unsafe {
+            Ok(Arc::new(StringColumn::from_data_unchecked(
+                offsets.into(),
+                values.into(),
+            )))
+        }
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::LargeBinary
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Variant".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer(&self) -> Box<dyn TypeSerializer> {
+        Box::new(VariantSerializer {})
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
+        Box::new(VariantDeserializer::with_capacity(capacity))
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableStringColumn::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for VariantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+
+#[test]
+fn test_array_column_replicate() {
+    let data_type = Arc::new(ArrayType::create(Int64Type::arc()));
+    let column = ArrayColumn::from_data(
+        data_type,
+        vec![0, 2, 2, 5].into(),
+        Series::from_data(vec![1i64, 2, 3, 4, 5]),
+    );
+
+    // [1, 2] x 2, [] x 1, [3, 4, 5] x 0
+    let replicated = column.replicate(&[2, 3, 3]);
+    assert_eq!(replicated.len(), 3);
+    assert_eq!(
+        replicated.get(0),
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)])
+    );
+    assert_eq!(replicated.get(1), replicated.get(0));
+    assert_eq!(replicated.get(2), DataValue::Array(vec![]));
+}
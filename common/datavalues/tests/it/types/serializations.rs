@@ -78,6 +78,18 @@ fn test_serializers() -> Result<()> {
             val_str: "hello",
             col_str: vec!["hello".to_owned(), "world".to_owned(), "NULL".to_owned()],
         },
+        Test {
+            name: "variant",
+            data_type: VariantType::arc(),
+            value: DataValue::String(r#"{"a":1}"#.as_bytes().to_vec()),
+            column: Series::from_data(vec![r#"{"a":1}"#, "[1,2]", "null"]),
+            val_str: r#"{"a":1}"#,
+            col_str: vec![
+                r#"{"a":1}"#.to_owned(),
+                "[1,2]".to_owned(),
+                "null".to_owned(),
+            ],
+        },
         Test {
             name: "array",
             data_type: Arc::new(ArrayType::create(StringType::arc())),
@@ -97,6 +109,29 @@ fn test_serializers() -> Result<()> {
                 "['hello', 'world', 'NULL']".to_owned(),
             ],
         },
+        Test {
+            name: "map",
+            data_type: MapType::arc(StringType::arc(), Int8Type::arc()),
+            value: DataValue::Array(vec![
+                DataValue::Struct(vec![
+                    DataValue::String("data".as_bytes().to_vec()),
+                    DataValue::Int64(1),
+                ]),
+                DataValue::Struct(vec![
+                    DataValue::String("bend".as_bytes().to_vec()),
+                    DataValue::Int64(2),
+                ]),
+            ]),
+            column: MapType::arc(StringType::arc(), Int8Type::arc()).create_column(&[
+                DataValue::Array(vec![DataValue::Struct(vec![
+                    DataValue::String("test".as_bytes().to_vec()),
+                    DataValue::Int64(1),
+                ])]),
+                DataValue::Array(vec![]),
+            ])?,
+            val_str: "{'data':1, 'bend':2}",
+            col_str: vec!["{'test':1}".to_owned(), "{}".to_owned()],
+        },
         Test {
             name: "struct",
             data_type: Arc::new(StructType::create(
@@ -162,11 +197,64 @@ fn test_serializers() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_map_unimplemented() -> Result<()> {
+    let data_type = MapType::arc(StringType::arc(), Int8Type::arc());
+    let column = data_type.create_column(&[DataValue::Array(vec![DataValue::Struct(vec![
+        DataValue::String("test".as_bytes().to_vec()),
+        DataValue::Int64(1),
+    ])])])?;
+
+    let serializer = data_type.create_serializer();
+    let err = serializer.serialize_json(&column).unwrap_err();
+    assert_eq!(err.code(), 1002);
+    let err = serializer
+        .serialize_clickhouse_format(&column)
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), 1002);
+
+    let mut deserializer = data_type.create_deserializer(1);
+    let err = deserializer.de_text("{'test':1}".as_bytes()).unwrap_err();
+    assert_eq!(err.code(), 1002);
+
+    // The empty maps can still be created as the default values.
+    deserializer.de_default();
+    let column = deserializer.finish_to_column();
+    assert_eq!(column.len(), 1);
+    assert_eq!(column.get(0), DataValue::Array(vec![]));
+
+    let mut mutable = data_type.create_mutable(2);
+    mutable.append_data_value(DataValue::Array(vec![]))?;
+    let err = mutable
+        .append_data_value(DataValue::Array(vec![DataValue::Struct(vec![
+            DataValue::String("test".as_bytes().to_vec()),
+            DataValue::Int64(1),
+        ])]))
+        .unwrap_err();
+    assert_eq!(err.code(), 1002);
+    assert_eq!(mutable.to_column().len(), 1);
+
+    Ok(())
+}
+
 #[test]
 fn test_convert_arrow() {
     let t = DateTime32Type::arc(None);
     let arrow_y = t.to_arrow_field("x");
     let new_t = from_arrow_field(&arrow_y);
 
+    assert_eq!(new_t.name(), t.name());
+
+    let t = VariantType::arc();
+    let arrow_y = t.to_arrow_field("x");
+    let new_t = from_arrow_field(&arrow_y);
+
+    assert_eq!(new_t.name(), t.name());
+
+    let t = MapType::arc(StringType::arc(), UInt64Type::arc());
+    let arrow_y = t.to_arrow_field("x");
+    let new_t = from_arrow_field(&arrow_y);
+
     assert_eq!(new_t.name(), t.name())
 }
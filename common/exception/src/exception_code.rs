@@ -135,6 +135,15 @@ build_exceptions! {
     // Network error codes.
     NetworkRequestError(1073),
 
+    // Query error codes.
+    UnknownQuery(1074),
+
+    // Python UDF error codes.
+    PythonUdfError(1075),
+
+    // Source error codes.
+    DecompressError(1076),
+
     // Tenant error codes.
     TenantIsEmpty(1101),
     IndexOutOfBounds(1102),
@@ -180,6 +189,7 @@ build_exceptions! {
     // Database error codes.
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
+    DatabaseNotEmpty(2703),
 
     // Variable error codes.
     UnknownVariable(2801),
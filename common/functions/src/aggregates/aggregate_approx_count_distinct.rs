@@ -0,0 +1,264 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionProperties;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_hashable_argument;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::scalars::Function;
+use crate::scalars::XxHash64Function;
+
+// 2^14 registers, the standard error of the estimate is 1.04 / sqrt(2^14), about 0.81%.
+const HLL_PRECISION: u32 = 14;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// The HyperLogLog sketch of the 64-bit hashes.
+///
+/// The registers are allocated on the first value, so the state of an empty group is small.
+struct AggregateApproxCountDistinctState {
+    registers: Vec<u8>,
+}
+
+impl AggregateApproxCountDistinctState {
+    #[inline(always)]
+    fn add(&mut self, hash: u64) {
+        if self.registers.is_empty() {
+            self.registers = vec![0; HLL_REGISTERS];
+        }
+
+        // The first bits of the hash select the register, the register keeps the max position
+        // of the first 1 bit in the remaining bits.
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+        if self.registers[index] < rank as u8 {
+            self.registers[index] = rank as u8;
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        if rhs.registers.is_empty() {
+            return;
+        }
+        if self.registers.is_empty() {
+            self.registers = rhs.registers.clone();
+            return;
+        }
+        for (register, rhs_register) in self.registers.iter_mut().zip(rhs.registers.iter()) {
+            *register = (*register).max(*rhs_register);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        if self.registers.is_empty() {
+            return 0;
+        }
+
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, zeros) = self
+            .registers
+            .iter()
+            .fold((0.0, 0usize), |(sum, zeros), register| {
+                (
+                    sum + 2f64.powi(-(*register as i32)),
+                    zeros + (*register == 0) as usize,
+                )
+            });
+
+        let estimate = alpha * m * m / sum;
+        // Linear counting is more accurate for the small cardinalities. With 64-bit hashes, the
+        // large range correction is not needed.
+        if estimate <= 2.5 * m && zeros > 0 {
+            return (m * (m / zeros as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        serialize_into_buf(writer, &self.registers)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.registers = deserialize_from_slice(reader)?;
+        if !self.registers.is_empty() && self.registers.len() != HLL_REGISTERS {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid HyperLogLog registers, expected {} but got {}",
+                HLL_REGISTERS,
+                self.registers.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// APPROX_COUNT_DISTINCT estimates the number of the distinct values by HyperLogLog.
+///
+/// The values are hashed by XXHASH64, the sketches of the partial states are merged by the max
+/// of each register, so the estimate is the same however the rows are split. NULL values are
+/// skipped.
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+    argument: DataField,
+    hasher: Box<dyn Function>,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        // The columns are accumulated without the null bits.
+        let data_type = remove_nullable(arguments[0].data_type());
+        assert_hashable_argument(display_name, &data_type)?;
+
+        Ok(Arc::new(AggregateApproxCountDistinctFunction {
+            display_name: display_name.to_string(),
+            argument: DataField::new(arguments[0].name(), data_type),
+            hasher: XxHash64Function::try_create("xxhash64")?,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        // Same as COUNT(DISTINCT), 0 is returned if all the values are NULL.
+        let properties = AggregateFunctionProperties {
+            returns_default_when_only_null: true,
+        };
+        AggregateFunctionDescription::creator_with_properties(
+            Box::new(Self::try_create),
+            properties,
+        )
+    }
+
+    fn eval_hash(&self, column: &ColumnRef) -> Result<ColumnRef> {
+        let column = column.convert_full_column();
+        let rows = column.len();
+        let columns = [ColumnWithField::new(column, self.argument.clone())];
+        self.hasher.eval(&columns, rows)
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateApproxCountDistinctState { registers: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateApproxCountDistinctState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let hash_column = self.eval_hash(&columns[0])?;
+        let hash_column: &UInt64Column = Series::check_get(&hash_column)?;
+        match validity {
+            Some(validity) => hash_column
+                .iter()
+                .zip(validity.iter())
+                .filter(|(_, valid)| *valid)
+                .for_each(|(hash, _)| state.add(*hash)),
+            None => hash_column.iter().for_each(|hash| state.add(*hash)),
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let hash_column = self.eval_hash(&columns[0])?;
+        let hash_column: &UInt64Column = Series::check_get(&hash_column)?;
+        hash_column
+            .iter()
+            .zip(places.iter())
+            .for_each(|(hash, place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateApproxCountDistinctState>();
+                state.add(*hash);
+            });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let hash_column = self.eval_hash(&columns[0].slice(row, 1))?;
+        let hash_column: &UInt64Column = Series::check_get(&hash_column)?;
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.add(hash_column.get_data(0));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateApproxCountDistinctState>();
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateApproxCountDistinctState>();
+        let builder: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(array)?;
+        builder.append_value(state.estimate());
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
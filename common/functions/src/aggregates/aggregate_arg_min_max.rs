@@ -48,7 +48,7 @@ pub trait AggregateArgMinMaxState<S: Scalar>: Send + Sync + 'static {
     fn merge(&mut self, rhs: &Self) -> Result<()>;
     fn serialize(&self, writer: &mut BytesMut) -> Result<()>;
     fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()>;
-    fn merge_result(&mut self, column: &mut dyn MutableColumn) -> Result<()>;
+    fn merge_result(&mut self, column: &mut dyn MutableColumn, nullable: bool) -> Result<()>;
 }
 
 #[derive(serde::Serialize, Deserialize)]
@@ -107,7 +107,7 @@ where
             let viewer = S::try_create_viewer(column)?;
 
             for (row, data) in viewer.iter().enumerate() {
-                if viewer.null_at(row) {
+                if !bit.get_bit(row) {
                     continue;
                 }
                 if !has_v {
@@ -155,22 +155,28 @@ where
         Ok(())
     }
 
-    fn merge_result(&mut self, column: &mut dyn MutableColumn) -> Result<()> {
+    fn merge_result(&mut self, column: &mut dyn MutableColumn, nullable: bool) -> Result<()> {
         // TODO:
         // Currently,this is all virtual call.
         // data can be dispatched into scalars to improve the performance.
         match self.value {
             Some(_) => column.append_data_value(self.data.clone())?,
+            None if nullable => column.append_data_value(DataValue::Null)?,
             None => column.append_default(),
         }
         Ok(())
     }
 }
 
-#[derive(Clone)]
+/// ARG_MIN and ARG_MAX return the value of the first argument at the row of the minimum or the
+/// maximum second argument, the first row of them if the second argument has equal values.
+///
+/// The rows with NULL in the second argument are ignored, but NULL of the first argument may be
+/// returned, so the functions have their own null adaptor, which returns NULL if no row is left.
 pub struct AggregateArgMinMaxFunction<S, C, State> {
     display_name: String,
     arguments: Vec<DataField>,
+    nullable: bool,
     _s: PhantomData<S>,
     _c: PhantomData<C>,
     _state: PhantomData<State>,
@@ -187,7 +193,10 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypePtr> {
-        Ok(self.arguments[0].data_type().clone())
+        match self.nullable {
+            true => Ok(wrap_nullable(self.arguments[0].data_type())),
+            false => Ok(self.arguments[0].data_type().clone()),
+        }
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -205,8 +214,15 @@ where
         validity: Option<&Bitmap>,
         _input_rows: usize,
     ) -> Result<()> {
+        let (all_null, order_validity) = columns[1].validity();
+        if all_null {
+            return Ok(());
+        }
+        let validity = combine_validities(validity, order_validity);
+        let order = Series::remove_nullable(&columns[1]);
+
         let state: &mut State = place.get();
-        state.add_batch(&columns[0], &columns[1], validity)
+        state.add_batch(&columns[0], &order, validity.as_ref())
     }
 
     fn accumulate_keys(
@@ -216,12 +232,20 @@ where
         columns: &[ColumnRef],
         _input_rows: usize,
     ) -> Result<()> {
-        let col: &<S as Scalar>::ColumnType = unsafe { Series::static_cast(&columns[1]) };
+        let (all_null, order_validity) = columns[1].validity();
+        if all_null {
+            return Ok(());
+        }
+        let order = Series::remove_nullable(&columns[1]);
+        let col: &<S as Scalar>::ColumnType = unsafe { Series::static_cast(&order) };
 
         col.scalar_iter()
             .enumerate()
             .zip(places.iter())
             .try_for_each(|((row, item), place)| {
+                if matches!(order_validity, Some(v) if !v.get_bit(row)) {
+                    return Ok(());
+                }
                 let addr = place.next(offset);
                 let state = addr.get::<State>();
                 state.add(item, columns[0].get(row))
@@ -229,7 +253,11 @@ where
     }
 
     fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
-        let col: &<S as Scalar>::ColumnType = unsafe { Series::static_cast(&columns[1]) };
+        if columns[1].null_at(row) {
+            return Ok(());
+        }
+        let order = Series::remove_nullable(&columns[1]);
+        let col: &<S as Scalar>::ColumnType = unsafe { Series::static_cast(&order) };
         let state = place.get::<State>();
         state.add(col.get_data(row), columns[0].get(row))
     }
@@ -252,7 +280,16 @@ where
 
     fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
         let state = place.get::<State>();
-        state.merge_result(array)
+        state.merge_result(array, self.nullable)
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        Ok(Some(Self::try_create(&self.display_name, arguments, true)?))
     }
 }
 
@@ -271,10 +308,12 @@ where
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+        nullable: bool,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(AggregateArgMinMaxFunction::<S, C, State> {
             display_name: display_name.to_owned(),
             arguments,
+            nullable,
             _s: PhantomData,
             _c: PhantomData,
             _state: PhantomData,
@@ -296,12 +335,14 @@ pub fn try_create_aggregate_arg_minmax_function<const IS_MIN: bool>(
              AggregateArgMinMaxFunction::<$T, CmpMin, State>::try_create(
                 display_name,
                 arguments,
+                false,
             )
         } else {
             type State = ArgMinMaxState<$T, CmpMax>;
              AggregateArgMinMaxFunction::<$T, CmpMax, State>::try_create(
                 display_name,
                 arguments,
+                false,
             )
         }
 
@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateArrayAggState {
+    values: Vec<DataValue>,
+    // Sort keys of the values, empty if the function has no ORDER BY argument.
+    keys: Vec<DataValue>,
+}
+
+/// ARRAY_AGG collects the values of a group into an array.
+///
+/// `array_agg(expr)` keeps the values in input order, `array_agg(expr, key)` sorts them
+/// by `key` before finalizing. The parameters `'DESC'` and `'IGNORE NULLS'` can be used to
+/// sort in descending order and to exclude NULL values, e.g.
+/// `array_agg('DESC', 'IGNORE NULLS')(expr, key)`, which is what the SQL syntax
+/// `ARRAY_AGG(expr ORDER BY key DESC) IGNORE NULLS` is parsed as.
+#[derive(Clone)]
+pub struct AggregateArrayAggFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+    desc: bool,
+    ignore_nulls: bool,
+    // Whether the arguments are nullable, set by the own null adaptor.
+    nullable: bool,
+}
+
+impl AggregateFunction for AggregateArrayAggFunction {
+    fn name(&self) -> &str {
+        "AggregateArrayAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        let inner_type = self.arguments[0].data_type().clone();
+        match self.nullable && !self.ignore_nulls {
+            true => Ok(Arc::new(ArrayType::create(wrap_nullable(&inner_type)))),
+            false => Ok(Arc::new(ArrayType::create(inner_type))),
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateArrayAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateArrayAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if let Some(validity) = validity {
+                if !validity.get_bit(row) {
+                    continue;
+                }
+            }
+
+            self.accumulate_row(place, columns, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let value = columns[0].get(row);
+        if self.ignore_nulls && value.is_null() {
+            return Ok(());
+        }
+
+        let state = place.get::<AggregateArrayAggState>();
+        state.values.push(value);
+        if columns.len() > 1 {
+            state.keys.push(columns[1].get(row));
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateArrayAggState>();
+        let state = place.get::<AggregateArrayAggState>();
+        state.values.extend_from_slice(&rhs.values);
+        state.keys.extend_from_slice(&rhs.keys);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+
+        if state.keys.is_empty() {
+            return array.append_data_value(DataValue::Array(state.values.clone()));
+        }
+
+        // Stable sort by the keys, equal keys keep the input order.
+        let mut indices = (0..state.values.len()).collect::<Vec<_>>();
+        indices.sort_by(|a, b| {
+            let ordering = state.keys[*a]
+                .partial_cmp(&state.keys[*b])
+                .unwrap_or(Ordering::Equal);
+            match self.desc {
+                true => ordering.reverse(),
+                false => ordering,
+            }
+        });
+
+        let values = indices
+            .into_iter()
+            .map(|index| state.values[index].clone())
+            .collect::<Vec<_>>();
+        array.append_data_value(DataValue::Array(values))
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // NULL values are collected into the array unless IGNORE NULLS is specified.
+        let mut f = self.clone();
+        f.nullable = true;
+        Ok(Some(Arc::new(f)))
+    }
+}
+
+impl fmt::Display for AggregateArrayAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateArrayAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+
+        let mut desc = false;
+        let mut ignore_nulls = false;
+        for param in &params {
+            let param = String::from_utf8(param.as_string()?)?;
+            match param.to_uppercase().as_str() {
+                "ASC" => desc = false,
+                "DESC" => desc = true,
+                "IGNORE NULLS" => ignore_nulls = true,
+                "RESPECT NULLS" => ignore_nulls = false,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "{} unsupported parameter: '{}', expect 'ASC', 'DESC', 'IGNORE NULLS' or 'RESPECT NULLS'",
+                        display_name, param
+                    )));
+                }
+            }
+        }
+
+        Ok(Arc::new(AggregateArrayAggFunction {
+            display_name: display_name.to_string(),
+            arguments,
+            desc,
+            ignore_nulls,
+            nullable: false,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
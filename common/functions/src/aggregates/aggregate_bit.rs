@@ -0,0 +1,274 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::PrimInt;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+pub trait BitOperation<T: PrimInt>: Send + Sync + Clone + 'static {
+    const NAME: &'static str;
+
+    /// The value of an empty group, which is the identity of the operation.
+    fn init() -> T;
+
+    fn apply(lhs: T, rhs: T) -> T;
+}
+
+#[derive(Clone)]
+pub struct BitAnd;
+
+impl<T: PrimInt> BitOperation<T> for BitAnd {
+    const NAME: &'static str = "AggregateBitAndFunction";
+
+    fn init() -> T {
+        !T::zero()
+    }
+
+    fn apply(lhs: T, rhs: T) -> T {
+        lhs & rhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitOr;
+
+impl<T: PrimInt> BitOperation<T> for BitOr {
+    const NAME: &'static str = "AggregateBitOrFunction";
+
+    fn init() -> T {
+        T::zero()
+    }
+
+    fn apply(lhs: T, rhs: T) -> T {
+        lhs | rhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitXor;
+
+impl<T: PrimInt> BitOperation<T> for BitXor {
+    const NAME: &'static str = "AggregateBitXorFunction";
+
+    fn init() -> T {
+        T::zero()
+    }
+
+    fn apply(lhs: T, rhs: T) -> T {
+        lhs ^ rhs
+    }
+}
+
+struct AggregateBitState<T> {
+    pub value: T,
+}
+
+impl<T> AggregateBitState<T>
+where T: PrimitiveType + PrimInt
+{
+    #[inline(always)]
+    fn add<Op: BitOperation<T>>(&mut self, other: T) {
+        self.value = Op::apply(self.value, other);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        serialize_into_buf(writer, &self.value)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.value = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateBitFunction<T, Op> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+    op: PhantomData<Op>,
+}
+
+impl<T, Op> AggregateFunction for AggregateBitFunction<T, Op>
+where
+    T: PrimitiveType + ToDataType + PrimInt,
+    Op: BitOperation<T>,
+{
+    fn name(&self) -> &str {
+        Op::NAME
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(T::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateBitState::<T> { value: Op::init() });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitState<T>>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitState<T>>();
+        let column: &PrimitiveColumn<T> = Series::check_get(&columns[0])?;
+        match validity {
+            Some(validity) => column
+                .iter()
+                .zip(validity.iter())
+                .filter(|(_, valid)| *valid)
+                .for_each(|(v, _)| state.add::<Op>(*v)),
+            None => column.iter().for_each(|v| state.add::<Op>(*v)),
+        }
+        Ok(())
+    }
+
+    // null bits can be ignored above the level of the aggregate function
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+        column.iter().zip(places.iter()).for_each(|(v, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateBitState<T>>();
+            state.add::<Op>(*v);
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+        let state = place.get::<AggregateBitState<T>>();
+        state.add::<Op>(unsafe { column.value_unchecked(row) });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateBitState<T>>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitState<T>>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateBitState<T>>();
+        let state = place.get::<AggregateBitState<T>>();
+        state.add::<Op>(rhs.value);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateBitState<T>>();
+        let builder: &mut MutablePrimitiveColumn<T> = Series::check_get_mutable_column(array)?;
+        builder.append_value(state.value);
+        Ok(())
+    }
+}
+
+impl<T, Op> fmt::Display for AggregateBitFunction<T, Op> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, Op> AggregateBitFunction<T, Op>
+where
+    T: PrimitiveType + ToDataType + PrimInt,
+    Op: BitOperation<T>,
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            _arguments: arguments,
+            t: PhantomData,
+            op: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_bit_function<Op>(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef>
+where
+    Op: BitOperation<u8>
+        + BitOperation<u16>
+        + BitOperation<u32>
+        + BitOperation<u64>
+        + BitOperation<i8>
+        + BitOperation<i16>
+        + BitOperation<i32>
+        + BitOperation<i64>,
+{
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    with_match_integer_type_id!(data_type.data_type_id(), |$T| {
+        AggregateBitFunction::<$T, Op>::try_create(display_name, arguments)
+    },
+
+    // no matching branch
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}', expect integer",
+            display_name, data_type
+        )))
+    })
+}
+
+pub fn aggregate_bit_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bit_function::<BitAnd>))
+}
+
+pub fn aggregate_bit_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bit_function::<BitOr>))
+}
+
+pub fn aggregate_bit_xor_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bit_function::<BitXor>))
+}
@@ -0,0 +1,192 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_hashable_argument;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::scalars::CRC32Function;
+use crate::scalars::Function;
+
+struct AggregateChecksumState {
+    pub value: u64,
+}
+
+impl AggregateChecksumState {
+    #[inline(always)]
+    fn add(&mut self, crc: u32) {
+        self.value = self.value.wrapping_add(crc as u64);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        serialize_into_buf(writer, &self.value)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.value = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+}
+
+/// CHECKSUM_AGG combines the CRC32 of the values in a group, the same as SUM(CRC32(x)) wrapped
+/// around at 2^64.
+///
+/// The addition is commutative, so the checksum doesn't depend on the order of the rows or the
+/// blocks, unlike XOR the duplicate rows don't cancel each other. The NULL values are skipped.
+#[derive(Clone)]
+pub struct AggregateChecksumFunction {
+    display_name: String,
+    argument: DataField,
+    crc32: Box<dyn Function>,
+}
+
+impl AggregateChecksumFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_unary_arguments(display_name, arguments.len())?;
+
+        // The columns are accumulated without the null bits.
+        let data_type = remove_nullable(arguments[0].data_type());
+        assert_hashable_argument(display_name, &data_type)?;
+
+        Ok(Arc::new(AggregateChecksumFunction {
+            display_name: display_name.to_string(),
+            argument: DataField::new(arguments[0].name(), data_type),
+            crc32: CRC32Function::try_create("crc32")?,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn eval_crc32(&self, column: &ColumnRef) -> Result<ColumnRef> {
+        let column = column.convert_full_column();
+        let rows = column.len();
+        let columns = [ColumnWithField::new(column, self.argument.clone())];
+        self.crc32.eval(&columns, rows)
+    }
+}
+
+impl AggregateFunction for AggregateChecksumFunction {
+    fn name(&self) -> &str {
+        "AggregateChecksumFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(u64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateChecksumState { value: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateChecksumState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateChecksumState>();
+        let crc_column = self.eval_crc32(&columns[0])?;
+        let crc_column: &UInt32Column = Series::check_get(&crc_column)?;
+        match validity {
+            Some(validity) => crc_column
+                .iter()
+                .zip(validity.iter())
+                .filter(|(_, valid)| *valid)
+                .for_each(|(crc, _)| state.add(*crc)),
+            None => crc_column.iter().for_each(|crc| state.add(*crc)),
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let crc_column = self.eval_crc32(&columns[0])?;
+        let crc_column: &UInt32Column = Series::check_get(&crc_column)?;
+        crc_column
+            .iter()
+            .zip(places.iter())
+            .for_each(|(crc, place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateChecksumState>();
+                state.add(*crc);
+            });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let crc_column = self.eval_crc32(&columns[0].slice(row, 1))?;
+        let crc_column: &UInt32Column = Series::check_get(&crc_column)?;
+        let state = place.get::<AggregateChecksumState>();
+        state.add(crc_column.get_data(0));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateChecksumState>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateChecksumState>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateChecksumState>();
+        let state = place.get::<AggregateChecksumState>();
+        state.value = state.value.wrapping_add(rhs.value);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateChecksumState>();
+        let builder: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(array)?;
+        builder.append_value(state.value);
+        Ok(())
+    }
+}
+
+impl fmt::Display for AggregateChecksumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
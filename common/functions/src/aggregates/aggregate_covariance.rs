@@ -39,6 +39,8 @@ pub struct AggregateCovarianceState {
     pub co_moments: f64,
     pub left_mean: f64,
     pub right_mean: f64,
+    pub left_moments: f64,
+    pub right_moments: f64,
 }
 
 /*
@@ -64,6 +66,9 @@ impl AggregateCovarianceState {
         let new_right_mean = self.right_mean + right_delta / self.count as f64;
 
         self.co_moments += (s - new_left_mean) * (t - self.right_mean);
+        // The moments of each side are the co-moments of the side with itself.
+        self.left_moments += (s - new_left_mean) * left_delta;
+        self.right_moments += (t - new_right_mean) * right_delta;
         self.left_mean = new_left_mean;
         self.right_mean = new_right_mean;
     }
@@ -84,6 +89,8 @@ impl AggregateCovarianceState {
         let right_delta = self.right_mean - other.right_mean;
 
         self.co_moments += other.co_moments + left_delta * right_delta * factor;
+        self.left_moments += other.left_moments + left_delta * left_delta * factor;
+        self.right_moments += other.right_moments + right_delta * right_delta * factor;
 
         if large_and_comparable(self.count, other.count) {
             self.left_mean = (self.left_sum() + other.left_sum()) / total as f64;
@@ -140,7 +147,7 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypePtr> {
-        Ok(f64::to_data_type())
+        Ok(R::return_type())
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -149,6 +156,8 @@ where
             left_mean: 0.0,
             right_mean: 0.0,
             co_moments: 0.0,
+            left_moments: 0.0,
+            right_moments: 0.0,
         });
     }
 
@@ -242,9 +251,13 @@ where
     #[allow(unused_mut)]
     fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
         let state = place.get::<AggregateCovarianceState>();
-        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
-        column.append_value(R::apply(state));
-        Ok(())
+        match R::apply(state) {
+            Some(value) => column.append_data_value(DataValue::Float64(value)),
+            None => {
+                column.append_default();
+                Ok(())
+            }
+        }
     }
 }
 
@@ -298,7 +311,12 @@ pub fn try_create_aggregate_covariance<R: AggregateCovariance>(
 pub trait AggregateCovariance: Send + Sync + 'static {
     fn name() -> &'static str;
 
-    fn apply(state: &AggregateCovarianceState) -> f64;
+    fn return_type() -> DataTypePtr {
+        f64::to_data_type()
+    }
+
+    // None is returned as NULL, which requires a nullable return type.
+    fn apply(state: &AggregateCovarianceState) -> Option<f64>;
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -310,11 +328,11 @@ impl AggregateCovariance for AggregateCovarianceSampleImpl {
         "AggregateCovarianceSampleFunction"
     }
 
-    fn apply(state: &AggregateCovarianceState) -> f64 {
+    fn apply(state: &AggregateCovarianceState) -> Option<f64> {
         if state.count < 2 {
-            f64::INFINITY
+            Some(f64::INFINITY)
         } else {
-            state.co_moments / (state.count - 1) as f64
+            Some(state.co_moments / (state.count - 1) as f64)
         }
     }
 }
@@ -336,13 +354,13 @@ impl AggregateCovariance for AggregateCovariancePopulationImpl {
         "AggregateCovariancePopulationFunction"
     }
 
-    fn apply(state: &AggregateCovarianceState) -> f64 {
+    fn apply(state: &AggregateCovarianceState) -> Option<f64> {
         if state.count == 0 {
-            f64::INFINITY
+            Some(f64::INFINITY)
         } else if state.count == 1 {
-            0.0
+            Some(0.0)
         } else {
-            state.co_moments / state.count as f64
+            Some(state.co_moments / state.count as f64)
         }
     }
 }
@@ -356,6 +374,34 @@ pub fn aggregate_covariance_population_desc() -> AggregateFunctionDescription {
 ///////////////////////////////////////////////////////////////////////////////
 
 ///////////////////////////////////////////////////////////////////////////////
-// TODO: correlation function
-//struct AggregateCorrelationImpl;
+// Pearson correlation function implementation
+struct AggregateCorrelationImpl;
+
+impl AggregateCovariance for AggregateCorrelationImpl {
+    fn name() -> &'static str {
+        "AggregateCorrelationFunction"
+    }
+
+    fn return_type() -> DataTypePtr {
+        wrap_nullable(&f64::to_data_type())
+    }
+
+    // corr = co-moments / sqrt(left_moments * right_moments), it is undefined for empty
+    // data sets or when either side has zero variance.
+    fn apply(state: &AggregateCovarianceState) -> Option<f64> {
+        if state.count == 0 || state.left_moments <= 0.0 || state.right_moments <= 0.0 {
+            return None;
+        }
+
+        let corr = state.co_moments / (state.left_moments * state.right_moments).sqrt();
+        Some(corr.clamp(-1.0, 1.0))
+    }
+}
+
+pub fn aggregate_correlation_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateCorrelationImpl>,
+    ))
+}
+
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,247 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateFirstLastValueState {
+    // None if no value is aggregated, the value itself may be NULL.
+    value: Option<DataValue>,
+    // Sort key of the value, None if the function has no ORDER BY argument.
+    key: Option<DataValue>,
+}
+
+/// FIRST_VALUE and LAST_VALUE return the first and the last value of a group.
+///
+/// `first_value(expr)` follows the input order, which is not deterministic across the partitions
+/// of a parallel query, `first_value(expr, key)` returns the value of the smallest `key`, and the
+/// value first aggregated for the equal keys. The parameters `'DESC'` and `'IGNORE NULLS'` can be
+/// used to sort in descending order and to skip NULL values, e.g.
+/// `last_value('IGNORE NULLS')(expr, key)`, which is what the SQL syntax
+/// `LAST_VALUE(expr ORDER BY key) IGNORE NULLS` is parsed as.
+#[derive(Clone)]
+pub struct AggregateFirstLastValueFunction {
+    display_name: String,
+    arguments: Vec<DataField>,
+    last: bool,
+    desc: bool,
+    ignore_nulls: bool,
+}
+
+impl AggregateFunction for AggregateFirstLastValueFunction {
+    fn name(&self) -> &str {
+        match self.last {
+            true => "AggregateLastValueFunction",
+            false => "AggregateFirstValueFunction",
+        }
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        // NULL is returned for an empty group.
+        Ok(wrap_nullable(&remove_nullable(
+            self.arguments[0].data_type(),
+        )))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateFirstLastValueState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateFirstLastValueState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if let Some(validity) = validity {
+                if !validity.get_bit(row) {
+                    continue;
+                }
+            }
+
+            self.accumulate_row(place, columns, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let value = columns[0].get(row);
+        if self.ignore_nulls && value.is_null() {
+            return Ok(());
+        }
+
+        let state = place.get::<AggregateFirstLastValueState>();
+        let key = columns.get(1).map(|column| column.get(row));
+        self.update(state, value, key);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateFirstLastValueState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateFirstLastValueState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateFirstLastValueState>();
+        let state = place.get::<AggregateFirstLastValueState>();
+        if let Some(value) = &rhs.value {
+            self.update(state, value.clone(), rhs.key.clone());
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateFirstLastValueState>();
+        match &state.value {
+            Some(value) => array.append_data_value(value.clone()),
+            None => array.append_data_value(DataValue::Null),
+        }
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // NULL values are kept unless IGNORE NULLS is specified.
+        Ok(Some(Arc::new(self.clone())))
+    }
+}
+
+impl fmt::Display for AggregateFirstLastValueFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateFirstLastValueFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+        last: bool,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+
+        let mut desc = false;
+        let mut ignore_nulls = false;
+        for param in &params {
+            let param = String::from_utf8(param.as_string()?)?;
+            match param.to_uppercase().as_str() {
+                "ASC" => desc = false,
+                "DESC" => desc = true,
+                "IGNORE NULLS" => ignore_nulls = true,
+                "RESPECT NULLS" => ignore_nulls = false,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "{} unsupported parameter: '{}', expect 'ASC', 'DESC', 'IGNORE NULLS' or 'RESPECT NULLS'",
+                        display_name, param
+                    )));
+                }
+            }
+        }
+
+        Ok(Arc::new(AggregateFirstLastValueFunction {
+            display_name: display_name.to_string(),
+            arguments,
+            last,
+            desc,
+            ignore_nulls,
+        }))
+    }
+
+    pub fn try_create_first_value(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create(display_name, params, arguments, false)
+    }
+
+    pub fn try_create_last_value(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create(display_name, params, arguments, true)
+    }
+
+    pub fn first_value_desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create_first_value))
+    }
+
+    pub fn last_value_desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create_last_value))
+    }
+
+    // The values are aggregated in the input order, a later value replaces the kept one if it's
+    // last_value, or it's sorted before the kept one.
+    fn update(
+        &self,
+        state: &mut AggregateFirstLastValueState,
+        value: DataValue,
+        key: Option<DataValue>,
+    ) {
+        let replace = match (&state.value, &key, &state.key) {
+            (None, _, _) => true,
+            (Some(_), Some(key), Some(state_key)) => {
+                let ordering = key.partial_cmp(state_key).unwrap_or(Ordering::Equal);
+                let ordering = match self.desc {
+                    true => ordering.reverse(),
+                    false => ordering,
+                };
+                match self.last {
+                    true => ordering != Ordering::Less,
+                    false => ordering == Ordering::Less,
+                }
+            }
+            _ => self.last,
+        };
+
+        if replace {
+            state.value = Some(value);
+            state.key = key;
+        }
+    }
+}
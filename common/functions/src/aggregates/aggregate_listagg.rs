@@ -0,0 +1,307 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateListAggState {
+    values: Vec<Vec<u8>>,
+    // Sort keys of the values, empty if the function has no WITHIN GROUP clause.
+    keys: Vec<Vec<DataValue>>,
+}
+
+#[derive(Clone)]
+enum ListAggOverflow {
+    Error,
+    Truncate { filler: Vec<u8>, with_count: bool },
+}
+
+/// LISTAGG concatenates the non-NULL string values of a group with a separator.
+///
+/// It is planned from `LISTAGG(expr, separator) WITHIN GROUP (ORDER BY key [ASC|DESC], ...)
+/// [ON OVERFLOW ...]` as `listagg(separator, max_length, filler, with_count, order, ...)(expr, key, ...)`,
+/// the parameters are:
+/// - `separator`: the string put between the values.
+/// - `max_length`: the maximum length of the result in bytes.
+/// - `filler`: NULL for `ON OVERFLOW ERROR`, otherwise the filler of `ON OVERFLOW TRUNCATE`.
+/// - `with_count`: whether the number of omitted values follows the filler.
+/// - `order`: `'ASC'` or `'DESC'` for each sort key.
+///
+/// `GROUP_CONCAT(expr [, separator])` and `STRING_AGG(expr, separator)` are planned the same,
+/// without the sort keys and with `ON OVERFLOW ERROR`.
+#[derive(Clone)]
+pub struct AggregateListAggFunction {
+    display_name: String,
+    separator: Vec<u8>,
+    max_length: usize,
+    overflow: ListAggOverflow,
+    // Whether the sort keys are in descending order.
+    descs: Vec<bool>,
+}
+
+impl AggregateFunction for AggregateListAggFunction {
+    fn name(&self) -> &str {
+        "AggregateListAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(wrap_nullable(&Vu8::to_data_type()))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateListAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateListAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if let Some(validity) = validity {
+                if !validity.get_bit(row) {
+                    continue;
+                }
+            }
+
+            self.accumulate_row(place, columns, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        // NULL values are skipped, but the NULL sort keys are kept.
+        let value = columns[0].get(row);
+        if value.is_null() {
+            return Ok(());
+        }
+
+        let state = place.get::<AggregateListAggState>();
+        state.values.push(value.as_string()?);
+        if columns.len() > 1 {
+            state
+                .keys
+                .push(columns[1..].iter().map(|c| c.get(row)).collect());
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateListAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateListAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateListAggState>();
+        let state = place.get::<AggregateListAggState>();
+        state.values.extend_from_slice(&rhs.values);
+        state.keys.extend_from_slice(&rhs.keys);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateListAggState>();
+        if state.values.is_empty() {
+            return array.append_data_value(DataValue::Null);
+        }
+
+        // Stable sort by the keys, equal keys keep the input order.
+        let mut indices = (0..state.values.len()).collect::<Vec<_>>();
+        if !state.keys.is_empty() {
+            indices.sort_by(|a, b| self.compare_keys(&state.keys[*a], &state.keys[*b]));
+        }
+
+        let values = indices
+            .into_iter()
+            .map(|index| state.values[index].as_slice())
+            .collect::<Vec<_>>();
+        let result = self.concat(&values)?;
+        array.append_data_value(DataValue::String(result))
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // The rows with NULL sort keys are still aggregated.
+        Ok(Some(Arc::new(self.clone())))
+    }
+}
+
+impl fmt::Display for AggregateListAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateListAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        if arguments.is_empty() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at least 1 argument, but got 0",
+                display_name
+            )));
+        }
+
+        let value_type = remove_nullable(arguments[0].data_type());
+        if value_type.data_type_id() != TypeID::String {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "{} does not support type '{:?}', expect String",
+                display_name, value_type
+            )));
+        }
+
+        if params.len() != arguments.len() + 3 {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} expect to have {} parameters, but got {}",
+                display_name,
+                arguments.len() + 3,
+                params.len()
+            )));
+        }
+
+        let separator = params[0].as_string()?;
+        let max_length = params[1].as_u64()? as usize;
+        let overflow = match &params[2] {
+            DataValue::Null => ListAggOverflow::Error,
+            filler => ListAggOverflow::Truncate {
+                filler: filler.as_string()?,
+                with_count: params[3].as_bool()?,
+            },
+        };
+
+        let mut descs = Vec::with_capacity(arguments.len() - 1);
+        for param in &params[4..] {
+            let param = String::from_utf8(param.as_string()?)?;
+            match param.to_uppercase().as_str() {
+                "ASC" => descs.push(false),
+                "DESC" => descs.push(true),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "{} unsupported sort order: '{}', expect 'ASC' or 'DESC'",
+                        display_name, param
+                    )));
+                }
+            }
+        }
+
+        Ok(Arc::new(AggregateListAggFunction {
+            display_name: display_name.to_string(),
+            separator,
+            max_length,
+            overflow,
+            descs,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn compare_keys(&self, lhs: &[DataValue], rhs: &[DataValue]) -> Ordering {
+        for ((lhs, rhs), desc) in lhs.iter().zip(rhs.iter()).zip(self.descs.iter()) {
+            let ordering = lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal);
+            let ordering = match desc {
+                true => ordering.reverse(),
+                false => ordering,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn concat(&self, values: &[&[u8]]) -> Result<Vec<u8>> {
+        let length = values.iter().map(|v| v.len()).sum::<usize>()
+            + self.separator.len() * (values.len() - 1);
+        if length <= self.max_length {
+            return Ok(values.join(self.separator.as_slice()));
+        }
+
+        match &self.overflow {
+            ListAggOverflow::Error => Err(ErrorCode::Overflow(format!(
+                "The result of {} is {} bytes, exceeds the limit {} bytes",
+                self.display_name, length, self.max_length
+            ))),
+            ListAggOverflow::Truncate { filler, with_count } => {
+                // Keep the whole values which fit in the limit together with the filler.
+                let count_length = match with_count {
+                    true => values.len().to_string().len() + 2,
+                    false => 0,
+                };
+                let suffix_length = self.separator.len() + filler.len() + count_length;
+
+                let mut result = Vec::with_capacity(self.max_length);
+                let mut kept = 0;
+                for value in values {
+                    let separator_length = if kept == 0 { 0 } else { self.separator.len() };
+                    if result.len() + separator_length + value.len() + suffix_length
+                        > self.max_length
+                    {
+                        break;
+                    }
+                    if kept > 0 {
+                        result.extend_from_slice(&self.separator);
+                    }
+                    result.extend_from_slice(value);
+                    kept += 1;
+                }
+
+                if kept > 0 {
+                    result.extend_from_slice(&self.separator);
+                }
+                result.extend_from_slice(filler);
+                if *with_count {
+                    result.extend_from_slice(format!("({})", values.len() - kept).as_bytes());
+                }
+                Ok(result)
+            }
+        }
+    }
+}
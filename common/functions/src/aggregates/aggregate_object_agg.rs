@@ -0,0 +1,206 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateObjectAggState {
+    // The keys and the JSON text of the values, in input order.
+    entries: Vec<(String, String)>,
+}
+
+/// OBJECT_AGG builds a Variant object from the key-value pairs of a group.
+///
+/// The rows with NULL keys are skipped, NULL values are kept as JSON null. Duplicate keys
+/// are overwritten, the last value wins, the same as JSON_OBJECT.
+#[derive(Clone)]
+pub struct AggregateObjectAggFunction {
+    display_name: String,
+    value_type: DataTypePtr,
+}
+
+impl AggregateFunction for AggregateObjectAggFunction {
+    fn name(&self) -> &str {
+        "AggregateObjectAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(VariantType::arc())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateObjectAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateObjectAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let values = self.serialize_values(&columns[1])?;
+        let state = place.get::<AggregateObjectAggState>();
+        for (row, value) in values.into_iter().enumerate().take(input_rows) {
+            if let Some(validity) = validity {
+                if !validity.get_bit(row) {
+                    continue;
+                }
+            }
+
+            if let Some(key) = Self::key_at(&columns[0], row)? {
+                state.entries.push((key, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let values = self.serialize_values(&columns[1])?;
+        for ((row, place), value) in places.iter().enumerate().zip(values.into_iter()) {
+            if let Some(key) = Self::key_at(&columns[0], row)? {
+                let state = place.next(offset).get::<AggregateObjectAggState>();
+                state.entries.push((key, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        if let Some(key) = Self::key_at(&columns[0], row)? {
+            let mut values = self.serialize_values(&columns[1].slice(row, 1))?;
+            let state = place.get::<AggregateObjectAggState>();
+            state.entries.push((key, values.remove(0)));
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateObjectAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateObjectAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<AggregateObjectAggState>();
+        let state = place.get::<AggregateObjectAggState>();
+        state.entries.extend_from_slice(&rhs.entries);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateObjectAggState>();
+        let mut object = Map::with_capacity(state.entries.len());
+        for (key, value) in &state.entries {
+            object.insert(key.clone(), serde_json::from_str::<Value>(value)?);
+        }
+
+        let object = Value::Object(object).to_string();
+        array.append_data_value(DataValue::String(object.into_bytes()))
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // The NULL values are kept, so the rows can't be filtered by the nullable adaptor.
+        Ok(Some(Arc::new(self.clone())))
+    }
+}
+
+impl fmt::Display for AggregateObjectAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl AggregateObjectAggFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_binary_arguments(display_name, arguments.len())?;
+
+        let key_type = remove_nullable(arguments[0].data_type());
+        if !key_type.data_type_id().is_string() {
+            return Err(ErrorCode::BadArguments(format!(
+                "{} key must be String, but got {:?}",
+                display_name, key_type
+            )));
+        }
+
+        Ok(Arc::new(AggregateObjectAggFunction {
+            display_name: display_name.to_string(),
+            value_type: remove_nullable(arguments[1].data_type()),
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn key_at(column: &ColumnRef, row: usize) -> Result<Option<String>> {
+        match column.get(row) {
+            DataValue::Null => Ok(None),
+            key => Ok(Some(String::from_utf8(key.as_string()?)?)),
+        }
+    }
+
+    fn serialize_values(&self, column: &ColumnRef) -> Result<Vec<String>> {
+        let data_type = match column.is_nullable() {
+            true => wrap_nullable(&self.value_type),
+            false => self.value_type.clone(),
+        };
+        let values = data_type
+            .create_serializer()
+            .serialize_json(&column.convert_full_column())?;
+        Ok(values.into_iter().map(|v| v.to_string()).collect())
+    }
+}
@@ -0,0 +1,349 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::aggregator_common::assert_unary_params;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+// The compression of the t-digest, it keeps about 100 centroids, and the error of the
+// percentiles near 0 and 1 is much smaller than the error of the median.
+const TDIGEST_COMPRESSION: f64 = 100.0;
+// The values are buffered and merged into the centroids in batches.
+const TDIGEST_BUFFER_SIZE: usize = 1000;
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// The merging t-digest of the values.
+///
+/// The centroids are sorted by mean, the number of values a centroid can take is limited by the
+/// scale function `k(q) = compression / 2π * asin(2q - 1)` of its quantile, so the centroids
+/// are small at the both ends. The centroids of a few values are not merged at all, so the
+/// percentiles are exact until there are about hundreds of values.
+#[derive(Serialize, Deserialize)]
+struct AggregatePercentileState {
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl AggregatePercentileState {
+    fn new() -> Self {
+        AggregatePercentileState {
+            centroids: vec![],
+            buffer: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        // NaN has no order among the values.
+        if value.is_nan() {
+            return;
+        }
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress(&[]);
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        self.min = self.min.min(rhs.min);
+        self.max = self.max.max(rhs.max);
+        self.buffer.extend_from_slice(&rhs.buffer);
+        self.compress(&rhs.centroids);
+    }
+
+    // Merge the buffered values and the other centroids into the centroids.
+    fn compress(&mut self, others: &[Centroid]) {
+        if self.buffer.is_empty() && others.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend(self.buffer.drain(..).map(|value| Centroid {
+            mean: value,
+            weight: 1.0,
+        }));
+        centroids.extend_from_slice(others);
+        centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(Ordering::Equal));
+
+        let total = centroids.iter().map(|c| c.weight).sum::<f64>();
+        let k = |q: f64| TDIGEST_COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin();
+
+        let mut merged: Vec<Centroid> = Vec::with_capacity(centroids.len());
+        // The weight of the centroids before the last merged one.
+        let mut weight_before = 0.0;
+        for centroid in centroids {
+            match merged.last_mut() {
+                Some(last)
+                    if k((weight_before + last.weight + centroid.weight) / total)
+                        - k(weight_before / total)
+                        <= 1.0 =>
+                {
+                    let weight = last.weight + centroid.weight;
+                    last.mean += (centroid.mean - last.mean) * centroid.weight / weight;
+                    last.weight = weight;
+                }
+                Some(last) => {
+                    weight_before += last.weight;
+                    merged.push(centroid);
+                }
+                None => merged.push(centroid),
+            }
+        }
+        self.centroids = merged;
+    }
+
+    // The percentile of the compressed centroids by linear interpolation, it's the same as the
+    // exact percentile if no values are merged.
+    fn percentile(&self, level: f64) -> Option<f64> {
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        let interpolate = |(x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64| match x1 <= x0 {
+            true => y1,
+            false => y0 + (y1 - y0) * (x - x0) / (x1 - x0),
+        };
+
+        // A centroid of the ranks [r, r + weight - 1] is placed at the middle of them, the ranks
+        // between the centroids are interpolated, and so are the ranks before the first centroid
+        // and after the last one with the min and the max.
+        let total = self.centroids.iter().map(|c| c.weight).sum::<f64>();
+        let rank = level * (total - 1.0);
+        let mut prev = (0.0, self.min);
+        let mut weight_before = 0.0;
+        for centroid in &self.centroids {
+            let position = weight_before + (centroid.weight - 1.0) / 2.0;
+            if rank <= position {
+                return Some(interpolate(prev, (position, centroid.mean), rank));
+            }
+            prev = (position, centroid.mean);
+            weight_before += centroid.weight;
+        }
+        Some(interpolate(prev, (total - 1.0, self.max), rank))
+    }
+}
+
+/// PERCENTILE and MEDIAN approximate the percentile of the non-NULL values by t-digest.
+///
+/// `PERCENTILE(expr, level)` is planned as `percentile(level)(expr)`, the level is in [0, 1].
+/// MEDIAN is the percentile of the level 0.5. The result is NULL if there are no values.
+#[derive(Clone)]
+pub struct AggregatePercentileFunction<T> {
+    display_name: String,
+    level: f64,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregatePercentileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregatePercentileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypePtr> {
+        Ok(wrap_nullable(&f64::to_data_type()))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregatePercentileState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregatePercentileState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregatePercentileState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregatePercentileState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregatePercentileState>();
+        state.add(unsafe { column.value_unchecked(row).as_() });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregatePercentileState>();
+        state.compress(&[]);
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregatePercentileState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregatePercentileState>();
+        let rhs = rhs.get::<AggregatePercentileState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregatePercentileState>();
+        state.compress(&[]);
+        match state.percentile(self.level) {
+            Some(value) => array.append_data_value(DataValue::Float64(value)),
+            None => array.append_data_value(DataValue::Null),
+        }
+    }
+}
+
+impl<T> fmt::Display for AggregatePercentileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregatePercentileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(display_name: &str, level: f64) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            level,
+            t: PhantomData,
+        }))
+    }
+}
+
+fn try_create_with_level(
+    display_name: &str,
+    level: f64,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregatePercentileFunction::<$T>::try_create(display_name, level)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_percentile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_params(display_name, params.len())?;
+
+    let level = params[0].as_f64()?;
+    if !(0.0..=1.0).contains(&level) {
+        return Err(ErrorCode::BadArguments(format!(
+            "The level of {} must be in [0, 1], but got {}",
+            display_name, level
+        )));
+    }
+    try_create_with_level(display_name, level, arguments)
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create_with_level(display_name, 0.5, arguments)
+}
+
+pub fn aggregate_percentile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_percentile_function))
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_median_function))
+}
@@ -15,16 +15,31 @@
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
+use super::aggregate_bit::aggregate_bit_and_function_desc;
+use super::aggregate_bit::aggregate_bit_or_function_desc;
+use super::aggregate_bit::aggregate_bit_xor_function_desc;
 use super::aggregate_combinator_distinct::AggregateDistinctCombinator;
+use super::aggregate_covariance::aggregate_correlation_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_min_max::aggregate_max_function_desc;
 use super::aggregate_min_max::aggregate_min_function_desc;
-use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
+use super::aggregate_percentile::aggregate_median_function_desc;
+use super::aggregate_percentile::aggregate_percentile_function_desc;
+use super::aggregate_variance::aggregate_stddev_pop_function_desc;
+use super::aggregate_variance::aggregate_stddev_sample_desc;
+use super::aggregate_variance::aggregate_variance_population_desc;
+use super::aggregate_variance::aggregate_variance_sample_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
+use super::AggregateApproxCountDistinctFunction;
+use super::AggregateArrayAggFunction;
+use super::AggregateChecksumFunction;
 use super::AggregateCountFunction;
+use super::AggregateFirstLastValueFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateListAggFunction;
+use super::AggregateObjectAggFunction;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 
 pub struct Aggregators;
@@ -40,16 +55,49 @@ impl Aggregators {
 
         factory.register("argMin", aggregate_arg_min_function_desc());
         factory.register("argMax", aggregate_arg_max_function_desc());
+        factory.register("arg_min", aggregate_arg_min_function_desc());
+        factory.register("arg_max", aggregate_arg_max_function_desc());
 
         factory.register("stddev", aggregate_stddev_pop_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("stddev_samp", aggregate_stddev_sample_desc());
+        factory.register("var_pop", aggregate_variance_population_desc());
+        factory.register("variance", aggregate_variance_population_desc());
+        factory.register("var_samp", aggregate_variance_sample_desc());
+
+        factory.register("median", aggregate_median_function_desc());
+        factory.register("percentile", aggregate_percentile_function_desc());
+
+        factory.register("bit_and", aggregate_bit_and_function_desc());
+        factory.register("bit_or", aggregate_bit_or_function_desc());
+        factory.register("bit_xor", aggregate_bit_xor_function_desc());
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("corr", aggregate_correlation_desc());
 
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register(
+            "approx_count_distinct",
+            AggregateApproxCountDistinctFunction::desc(),
+        );
+
+        factory.register("array_agg", AggregateArrayAggFunction::desc());
+        factory.register(
+            "first_value",
+            AggregateFirstLastValueFunction::first_value_desc(),
+        );
+        factory.register(
+            "last_value",
+            AggregateFirstLastValueFunction::last_value_desc(),
+        );
+        factory.register("listagg", AggregateListAggFunction::desc());
+        factory.register("group_concat", AggregateListAggFunction::desc());
+        factory.register("string_agg", AggregateListAggFunction::desc());
+        factory.register("object_agg", AggregateObjectAggFunction::desc());
+        factory.register("checksum_agg", AggregateChecksumFunction::desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
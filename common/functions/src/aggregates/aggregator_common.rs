@@ -17,7 +17,10 @@ use std::fmt::Display;
 use bumpalo::Bump;
 use common_datavalues::ColumnRef;
 use common_datavalues::ColumnWithField;
+use common_datavalues::DataTypePtr;
 use common_datavalues::DataValue;
+use common_datavalues::PhysicalTypeID;
+use common_datavalues::TypeID;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -77,6 +80,27 @@ pub fn assert_variadic_arguments<D: Display>(
     Ok(())
 }
 
+// The hash functions, e.g. CRC32 and XXHASH64, support the numbers, booleans and strings.
+pub fn assert_hashable_argument<D: Display>(name: D, data_type: &DataTypePtr) -> Result<()> {
+    let supported = match data_type.data_type_id() {
+        TypeID::Variant => false,
+        type_id => !matches!(
+            type_id.to_physical_type(),
+            PhysicalTypeID::Null
+                | PhysicalTypeID::Nullable
+                | PhysicalTypeID::Array
+                | PhysicalTypeID::Struct
+        ),
+    };
+    if !supported {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            name, data_type
+        )));
+    }
+    Ok(())
+}
+
 pub fn eval_aggr(
     name: &str,
     params: Vec<DataValue>,
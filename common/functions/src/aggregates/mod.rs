@@ -26,35 +26,51 @@ mod adaptors;
 
 #[macro_use]
 mod macros;
+mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
+mod aggregate_array_agg;
 mod aggregate_avg;
+mod aggregate_bit;
+mod aggregate_checksum;
 mod aggregate_combinator;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
+mod aggregate_first_last_value;
+mod aggregate_listagg;
 mod aggregate_min_max;
 mod aggregate_null_result;
+mod aggregate_object_agg;
+mod aggregate_percentile;
 mod aggregate_scalar_state;
-mod aggregate_stddev_pop;
+mod aggregate_variance;
 mod aggregate_window_funnel;
 
 pub use adaptors::*;
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
+pub use aggregate_array_agg::AggregateArrayAggFunction;
 pub use aggregate_avg::AggregateAvgFunction;
+pub use aggregate_bit::AggregateBitFunction;
+pub use aggregate_checksum::AggregateChecksumFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_first_last_value::AggregateFirstLastValueFunction;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_listagg::AggregateListAggFunction;
 pub use aggregate_min_max::AggregateMinMaxFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
-pub use aggregate_stddev_pop::AggregateStddevPopFunction;
+pub use aggregate_object_agg::AggregateObjectAggFunction;
+pub use aggregate_percentile::AggregatePercentileFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_variance::AggregateVarianceFunction;
 pub use aggregate_window_funnel::AggregateWindowFunnelFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -16,12 +16,16 @@
 
 pub mod aggregates;
 pub mod scalars;
+pub mod windows;
 
 mod macros;
 
 use aggregates::AggregateFunctionFactory;
 use scalars::FunctionFactory;
+use windows::RankingFunction;
 
 pub fn is_builtin_function(name: &str) -> bool {
-    FunctionFactory::instance().check(name) || AggregateFunctionFactory::instance().check(name)
+    FunctionFactory::instance().check(name)
+        || AggregateFunctionFactory::instance().check(name)
+        || RankingFunction::check(name)
 }
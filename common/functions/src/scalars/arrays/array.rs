@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::ArrayContainsFunction;
+use crate::scalars::ArrayDistinctFunction;
+use crate::scalars::ArraySizeFunction;
+use crate::scalars::ArraySliceFunction;
+
+#[derive(Clone)]
+pub struct ArrayFunction;
+
+impl ArrayFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("array_contains", ArrayContainsFunction::desc());
+        factory.register("array_distinct", ArrayDistinctFunction::desc());
+        factory.register("array_slice", ArraySliceFunction::desc());
+        factory.register("array_size", ArraySizeFunction::desc());
+        factory.register("array_length", ArraySizeFunction::desc());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+#[derive(Clone)]
+pub struct ArrayContainsFunction {
+    display_name: String,
+}
+
+impl ArrayContainsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayContainsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+
+    fn array_type(&self, data_type: &DataTypePtr) -> Result<ArrayType> {
+        let array_type = remove_nullable(data_type);
+        match array_type.as_any().downcast_ref::<ArrayType>() {
+            Some(v) => Ok(v.clone()),
+            None => Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the first argument to be Array, but got {:?}",
+                self.display_name, data_type
+            ))),
+        }
+    }
+}
+
+impl Function for ArrayContainsFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        self.array_type(args[0])?;
+        match args[0].is_nullable() {
+            true => Ok(wrap_nullable(&BooleanType::arc())),
+            false => Ok(BooleanType::arc()),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        // Cast the value to the element type, so that they are compared as the same type.
+        let array_type = self.array_type(columns[0].data_type())?;
+        let value_type = wrap_nullable(&remove_nullable(array_type.inner_type()));
+        let values = cast_column_field(&columns[1], &value_type)?.convert_full_column();
+        let arrays = columns[0].column().convert_full_column();
+
+        // A null value is not contained in any array, nor a null element matches any value.
+        let result = (0..input_rows)
+            .map(|row| match arrays.get(row) {
+                DataValue::Array(elements) => {
+                    let value = values.get(row);
+                    !value.is_null() && elements.contains(&value)
+                }
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+        let result = Series::from_data(result);
+
+        match arrays.is_nullable() {
+            true => {
+                let (_, validity) = arrays.validity();
+                Ok(Arc::new(NullableColumn::new_from_opt(
+                    result,
+                    validity.cloned(),
+                )))
+            }
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for ArrayContainsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
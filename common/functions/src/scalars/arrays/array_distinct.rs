@@ -0,0 +1,119 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+#[derive(Clone)]
+pub struct ArrayDistinctFunction {
+    display_name: String,
+}
+
+impl ArrayDistinctFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayDistinctFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl Function for ArrayDistinctFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        if !remove_nullable(args[0]).data_type_id().is_array() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be Array, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let column = columns[0].column().convert_full_column();
+        let inner = Series::remove_nullable(&column);
+        let array_column: &ArrayColumn = Series::check_get(&inner)?;
+        let offsets = array_column.offsets();
+        let values = array_column.values();
+
+        // Keep the first occurrence of each element, nulls are deduped as the same element.
+        let mut keep = Vec::with_capacity(values.len());
+        let mut new_offsets = Vec::with_capacity(input_rows + 1);
+        new_offsets.push(0i64);
+        for row in 0..input_rows {
+            let mut distinct: Vec<DataValue> = vec![];
+            for i in offsets[row] as usize..offsets[row + 1] as usize {
+                let value = values.get(i);
+                let duplicated = distinct.contains(&value);
+                if !duplicated {
+                    distinct.push(value);
+                }
+                keep.push(!duplicated);
+            }
+            new_offsets.push(new_offsets[row] + distinct.len() as i64);
+        }
+
+        let filter = BooleanColumn::from_iterator(keep.into_iter());
+        let result = Arc::new(ArrayColumn::from_data(
+            remove_nullable(columns[0].data_type()),
+            new_offsets.into(),
+            values.filter(&filter),
+        ));
+
+        match column.is_nullable() {
+            true => {
+                let (_, validity) = column.validity();
+                Ok(Arc::new(NullableColumn::new_from_opt(
+                    result,
+                    validity.cloned(),
+                )))
+            }
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for ArrayDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
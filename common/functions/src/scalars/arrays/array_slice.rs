@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+#[derive(Clone)]
+pub struct ArraySliceFunction {
+    display_name: String,
+}
+
+impl ArraySliceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySliceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+impl Function for ArraySliceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if !args[0].data_type_id().is_array() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the first argument to be Array, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+
+        for arg in &args[1..] {
+            if !arg.data_type_id().is_integer() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Function {} expects the index to be integer, but got {:?}",
+                    self.display_name, arg
+                )));
+            }
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+        let offsets = array_column.offsets();
+
+        let start_column = cast_column_field(&columns[1], &Int64Type::arc())?;
+        let start_viewer = i64::try_create_viewer(&start_column)?;
+        let end_column = match columns.len() > 2 {
+            true => Some(cast_column_field(&columns[2], &Int64Type::arc())?),
+            false => None,
+        };
+        let end_viewer = match &end_column {
+            Some(v) => Some(i64::try_create_viewer(v)?),
+            None => None,
+        };
+
+        // Keep the elements in the slice of each array.
+        let mut keep = Vec::with_capacity(array_column.values().len());
+        let mut new_offsets = Vec::with_capacity(input_rows + 1);
+        new_offsets.push(0i64);
+        for row in 0..input_rows {
+            let len = offsets[row + 1] - offsets[row];
+            let start = slice_index(start_viewer.value_at(row), len);
+            let end = match &end_viewer {
+                Some(v) => slice_index(v.value_at(row), len),
+                None => len,
+            };
+
+            keep.extend((0..len).map(|i| i >= start && i < end));
+            new_offsets.push(new_offsets[row] + (end - start).max(0));
+        }
+
+        let filter = BooleanColumn::from_iterator(keep.into_iter());
+        Ok(Arc::new(ArrayColumn::from_data(
+            columns[0].data_type().clone(),
+            new_offsets.into(),
+            array_column.values().filter(&filter),
+        )))
+    }
+}
+
+impl fmt::Display for ArraySliceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+// The 0-based index clamped into [0, len], a negative index counts from the end.
+#[inline]
+fn slice_index(index: i64, len: i64) -> i64 {
+    match index < 0 {
+        true => len.saturating_add(index).max(0),
+        false => index.min(len),
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod array;
+mod array_contains;
+mod array_distinct;
+mod array_size;
+mod array_slice;
+
+pub use array::ArrayFunction;
+pub use array_contains::ArrayContainsFunction;
+pub use array_distinct::ArrayDistinctFunction;
+pub use array_size::ArraySizeFunction;
+pub use array_slice::ArraySliceFunction;
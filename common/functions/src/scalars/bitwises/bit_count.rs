@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
+use common_exception::Result;
+use num_traits::PrimInt;
+
+use crate::scalars::assert_integer;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::ScalarUnaryExpression;
+
+/// BIT_COUNT(n) returns the number of bits set in the two's complement representation of `n`.
+#[derive(Clone)]
+pub struct BitCountFunction {
+    display_name: String,
+}
+
+impl BitCountFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitCountFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+fn bit_count<T: PrimInt>(value: T, _ctx: &mut EvalContext) -> u8 {
+    value.count_ones() as u8
+}
+
+impl Function for BitCountFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_integer(args[0])?;
+        Ok(u8::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let mut ctx = EvalContext::default();
+        with_match_integer_type_id!(columns[0].data_type().data_type_id(), |$T| {
+            let unary = ScalarUnaryExpression::<$T, u8, _>::new(bit_count::<$T>);
+            let col = unary.eval(columns[0].column(), &mut ctx)?;
+            Ok(Arc::new(col))
+        },{
+            unreachable!()
+        })
+    }
+}
+
+impl fmt::Display for BitCountFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::BitAndFunction;
+use crate::scalars::BitCountFunction;
+use crate::scalars::BitOrFunction;
+use crate::scalars::BitShiftLeftFunction;
+use crate::scalars::BitShiftRightFunction;
+use crate::scalars::BitXorFunction;
+use crate::scalars::FunctionFactory;
+
+pub struct BitwiseFunction;
+
+impl BitwiseFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("bit_count", BitCountFunction::desc());
+        factory.register("bit_and", BitAndFunction::desc());
+        factory.register("bit_or", BitOrFunction::desc());
+        factory.register("bit_xor", BitXorFunction::desc());
+        factory.register("bit_shift_left", BitShiftLeftFunction::desc());
+        factory.register("bit_shift_right", BitShiftRightFunction::desc());
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
+use common_exception::Result;
+use num_traits::AsPrimitive;
+use num_traits::PrimInt;
+
+use crate::scalars::assert_integer;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::ScalarBinaryExpression;
+
+pub trait BitwiseOperator: Send + Sync + Clone + 'static {
+    fn apply<T: PrimInt>(l: T, r: T) -> T;
+}
+
+#[derive(Clone)]
+pub struct BitAnd;
+
+impl BitwiseOperator for BitAnd {
+    fn apply<T: PrimInt>(l: T, r: T) -> T {
+        l & r
+    }
+}
+
+#[derive(Clone)]
+pub struct BitOr;
+
+impl BitwiseOperator for BitOr {
+    fn apply<T: PrimInt>(l: T, r: T) -> T {
+        l | r
+    }
+}
+
+#[derive(Clone)]
+pub struct BitXor;
+
+impl BitwiseOperator for BitXor {
+    fn apply<T: PrimInt>(l: T, r: T) -> T {
+        l ^ r
+    }
+}
+
+/// Bitwise operation on two integers.
+///
+/// Both arguments are widened to their least common integer type first, sign extending
+/// the signed ones, e.g. BIT_AND(UInt8, Int8) is evaluated on Int16.
+#[derive(Clone)]
+pub struct BitwiseBinaryFunction<T> {
+    display_name: String,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: BitwiseOperator> BitwiseBinaryFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitwiseBinaryFunction::<T> {
+            display_name: display_name.to_string(),
+            _phantom: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+fn bitwise_scalar<L, R, O, T>(l: L::RefType<'_>, r: R::RefType<'_>, _ctx: &mut EvalContext) -> O
+where
+    L: PrimitiveType + AsPrimitive<O>,
+    R: PrimitiveType + AsPrimitive<O>,
+    O: PrimInt + 'static,
+    T: BitwiseOperator,
+{
+    T::apply(l.to_owned_scalar().as_(), r.to_owned_scalar().as_())
+}
+
+impl<T: BitwiseOperator> Function for BitwiseBinaryFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_integer(args[0])?;
+        assert_integer(args[1])?;
+
+        with_match_integer_type_id!(args[0].data_type_id(), |$L| {
+            with_match_integer_type_id!(args[1].data_type_id(), |$R| {
+                Ok(<($L, $R) as ResultTypeOfBinary>::LeastSuper::to_data_type())
+            }, {
+                unreachable!()
+            })
+        }, {
+            unreachable!()
+        })
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let mut ctx = EvalContext::default();
+        with_match_integer_type_id!(columns[0].data_type().data_type_id(), |$L| {
+            with_match_integer_type_id!(columns[1].data_type().data_type_id(), |$R| {
+                let binary = ScalarBinaryExpression::<$L, $R, <($L, $R) as ResultTypeOfBinary>::LeastSuper, _>::new(
+                    bitwise_scalar::<$L, $R, <($L, $R) as ResultTypeOfBinary>::LeastSuper, T>
+                );
+                let col = binary.eval(columns[0].column(), columns[1].column(), &mut ctx)?;
+                Ok(Arc::new(col))
+            }, {
+                unreachable!()
+            })
+        }, {
+            unreachable!()
+        })
+    }
+}
+
+impl<T> fmt::Display for BitwiseBinaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+pub type BitAndFunction = BitwiseBinaryFunction<BitAnd>;
+pub type BitOrFunction = BitwiseBinaryFunction<BitOr>;
+pub type BitXorFunction = BitwiseBinaryFunction<BitXor>;
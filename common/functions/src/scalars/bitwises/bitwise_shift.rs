@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num_traits::AsPrimitive;
+use num_traits::PrimInt;
+
+use crate::scalars::assert_integer;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::ScalarBinaryExpression;
+
+pub trait ShiftOperator: Send + Sync + Clone + 'static {
+    /// Shifts `value` by `count` bits, `count` is less than the width of `T`.
+    fn shift<T: PrimInt>(value: T, count: usize) -> T;
+
+    /// The result of shifting `value` by the whole width of `T` or more.
+    fn overflow<T: PrimInt>(value: T) -> T;
+}
+
+#[derive(Clone)]
+pub struct BitShiftLeft;
+
+impl ShiftOperator for BitShiftLeft {
+    fn shift<T: PrimInt>(value: T, count: usize) -> T {
+        value << count
+    }
+
+    fn overflow<T: PrimInt>(_value: T) -> T {
+        T::zero()
+    }
+}
+
+#[derive(Clone)]
+pub struct BitShiftRight;
+
+impl ShiftOperator for BitShiftRight {
+    /// Arithmetic shift for signed integers, logical shift for unsigned ones.
+    fn shift<T: PrimInt>(value: T, count: usize) -> T {
+        value >> count
+    }
+
+    /// Only the sign bits are left.
+    fn overflow<T: PrimInt>(value: T) -> T {
+        if value < T::zero() {
+            !T::zero()
+        } else {
+            T::zero()
+        }
+    }
+}
+
+/// Shifts the first integer argument by the second one, the result keeps the type of
+/// the first argument. A shift count of the type width or more shifts out all the bits.
+#[derive(Clone)]
+pub struct BitwiseShiftFunction<T> {
+    display_name: String,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: ShiftOperator> BitwiseShiftFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitwiseShiftFunction::<T> {
+            display_name: display_name.to_string(),
+            _phantom: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+fn shift_scalar<V, C, T>(value: V::RefType<'_>, count: C::RefType<'_>, ctx: &mut EvalContext) -> V
+where
+    V: PrimitiveType + PrimInt,
+    C: PrimitiveType + AsPrimitive<i128>,
+    T: ShiftOperator,
+{
+    let value = value.to_owned_scalar();
+    let count: i128 = count.to_owned_scalar().as_();
+    if std::intrinsics::unlikely(count < 0) {
+        ctx.set_error(ErrorCode::BadArguments(format!(
+            "Shift count must not be negative, but got {}",
+            count
+        )));
+        return V::zero();
+    }
+
+    if count >= (std::mem::size_of::<V>() * 8) as i128 {
+        T::overflow(value)
+    } else {
+        T::shift(value, count as usize)
+    }
+}
+
+impl<T: ShiftOperator> Function for BitwiseShiftFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_integer(args[0])?;
+        assert_integer(args[1])?;
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let mut ctx = EvalContext::default();
+        with_match_integer_type_id!(columns[0].data_type().data_type_id(), |$V| {
+            with_match_integer_type_id!(columns[1].data_type().data_type_id(), |$C| {
+                let binary = ScalarBinaryExpression::<$V, $C, $V, _>::new(shift_scalar::<$V, $C, T>);
+                let col = binary.eval(columns[0].column(), columns[1].column(), &mut ctx)?;
+                Ok(Arc::new(col))
+            }, {
+                unreachable!()
+            })
+        }, {
+            unreachable!()
+        })
+    }
+}
+
+impl<T> fmt::Display for BitwiseShiftFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+pub type BitShiftLeftFunction = BitwiseShiftFunction<BitShiftLeft>;
+pub type BitShiftRightFunction = BitwiseShiftFunction<BitShiftRight>;
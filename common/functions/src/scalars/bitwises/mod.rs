@@ -0,0 +1,35 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bit_count;
+mod bitwise;
+mod bitwise_binary;
+mod bitwise_shift;
+
+pub use bit_count::BitCountFunction;
+pub use bitwise::BitwiseFunction;
+pub use bitwise_binary::BitAnd;
+pub use bitwise_binary::BitAndFunction;
+pub use bitwise_binary::BitOr;
+pub use bitwise_binary::BitOrFunction;
+pub use bitwise_binary::BitXor;
+pub use bitwise_binary::BitXorFunction;
+pub use bitwise_binary::BitwiseBinaryFunction;
+pub use bitwise_binary::BitwiseOperator;
+pub use bitwise_shift::BitShiftLeft;
+pub use bitwise_shift::BitShiftLeftFunction;
+pub use bitwise_shift::BitShiftRight;
+pub use bitwise_shift::BitShiftRightFunction;
+pub use bitwise_shift::BitwiseShiftFunction;
+pub use bitwise_shift::ShiftOperator;
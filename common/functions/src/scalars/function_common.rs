@@ -36,6 +36,16 @@ pub fn assert_numeric(data_type: &DataTypePtr) -> Result<()> {
     Ok(())
 }
 
+pub fn assert_integer(data_type: &DataTypePtr) -> Result<()> {
+    if !data_type.data_type_id().is_integer() {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Expected an integer type, but got {:?}",
+            data_type
+        )));
+    }
+    Ok(())
+}
+
 pub fn assert_date_or_datetime(data_type: &DataTypePtr) -> Result<()> {
     if !data_type.data_type_id().is_date_or_date_time() {
         return Err(ErrorCode::IllegalDataType(format!(
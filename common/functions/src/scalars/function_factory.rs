@@ -23,14 +23,18 @@ use once_cell::sync::Lazy;
 use super::function::Function;
 use super::ArithmeticAdapter;
 use super::ArithmeticFunction;
+use super::ArrayFunction;
+use super::BitwiseFunction;
 use super::ComparisonFunction;
 use super::ConditionalFunction;
 use super::FunctionAdapter;
 use super::HashesFunction;
 use super::LogicFunction;
+use super::MapFunction;
 use super::MathsFunction;
 use super::NullableFunction;
 use super::OtherFunction;
+use super::SemiStructuredFunction;
 use super::StringFunction;
 use super::ToCastFunction;
 use super::TupleClassFunction;
@@ -67,6 +71,10 @@ pub struct FunctionFeatures {
     // (1, 2) means we only accept [1, 2] arguments
     // None means it's not variadic function.
     pub variadic_arguments: Option<(usize, usize)>,
+
+    // The internal function is only called by the rewritten expressions, it can't be called
+    // from SQL and isn't listed in system.functions.
+    pub is_internal: bool,
 }
 
 impl FunctionFeatures {
@@ -80,6 +88,7 @@ impl FunctionFeatures {
             passthrough_null: true,
             num_arguments: 0,
             variadic_arguments: None,
+            is_internal: false,
         }
     }
 
@@ -122,6 +131,11 @@ impl FunctionFeatures {
         self.variadic_arguments = Some((min, max));
         self
     }
+
+    pub fn internal_function(mut self) -> FunctionFeatures {
+        self.is_internal = true;
+        self
+    }
 }
 
 pub struct FunctionDescription {
@@ -186,6 +200,10 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     OtherFunction::register(&mut function_factory);
     UUIDFunction::register(&mut function_factory);
     MathsFunction::register(&mut function_factory);
+    BitwiseFunction::register(&mut function_factory);
+    SemiStructuredFunction::register(&mut function_factory);
+    MapFunction::register(&mut function_factory);
+    ArrayFunction::register(&mut function_factory);
 
     Arc::new(function_factory)
 });
@@ -265,7 +283,9 @@ impl FunctionFactory {
 
     pub fn registered_names(&self) -> Vec<String> {
         self.case_insensitive_desc
-            .keys()
+            .iter()
+            .filter(|(_, desc)| !desc.features.is_internal)
+            .map(|(name, _)| name)
             .chain(self.case_insensitive_arithmetic_desc.keys())
             .cloned()
             .collect::<Vec<_>>()
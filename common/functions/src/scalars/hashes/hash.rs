@@ -21,8 +21,10 @@ use super::BaseHashFunction;
 use crate::scalars::Blake3HashFunction;
 use crate::scalars::City64WithSeedFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::HashFunction;
 use crate::scalars::Md5HashFunction;
 use crate::scalars::Sha1HashFunction;
+use crate::scalars::Sha256HashFunction;
 use crate::scalars::Sha2HashFunction;
 
 #[derive(Clone)]
@@ -38,6 +40,7 @@ impl HashesFunction {
         factory.register("sha", Sha1HashFunction::desc());
         factory.register("sha1", Sha1HashFunction::desc());
         factory.register("sha2", Sha2HashFunction::desc());
+        factory.register("sha256", Sha256HashFunction::desc());
 
         factory.register("blake3", Blake3HashFunction::desc());
         factory.register("xxhash32", XxHash32Function::desc());
@@ -45,5 +48,6 @@ impl HashesFunction {
         factory.register("siphash64", SipHash64Function::desc());
         factory.register("siphash", SipHash64Function::desc());
         factory.register("city64WithSeed", City64WithSeedFunction::desc());
+        factory.register("hash", HashFunction::desc());
     }
 }
@@ -17,6 +17,7 @@ mod city64_with_seed;
 mod hash;
 mod hash_base;
 mod md5hash;
+mod row_hash;
 mod sha1hash;
 mod sha2hash;
 
@@ -25,5 +26,7 @@ pub use city64_with_seed::City64WithSeedFunction;
 pub use hash::*;
 pub use hash_base::BaseHashFunction;
 pub use md5hash::Md5HashFunction;
+pub use row_hash::HashFunction;
 pub use sha1hash::Sha1HashFunction;
+pub use sha2hash::Sha256HashFunction;
 pub use sha2hash::Sha2HashFunction;
@@ -0,0 +1,157 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::hash::Hasher;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_scalar_types_error;
+use common_exception::Result;
+use twox_hash::XxHash64;
+
+use super::hash_base::DFHash;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+const NULL_TAG: u8 = 0;
+const VALUE_TAG: u8 = 1;
+
+/// HASH(expr1, expr2, ...) combines all the arguments of a row into one UInt64.
+///
+/// It uses XxHash64 with the default seed, so the result is the same across runs and machines.
+/// Every argument is prefixed with a tag, so NULL is hashed as a distinguished value, and
+/// strings are prefixed with their length, so HASH('ab', 'c') differs from HASH('a', 'bc').
+#[derive(Clone)]
+pub struct HashFunction {
+    display_name: String,
+}
+
+impl HashFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(HashFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(1, 1024),
+        )
+    }
+
+    fn hash_column(column: &ColumnRef, hashers: &mut [XxHash64]) -> Result<()> {
+        let physical_type = remove_nullable(&column.data_type())
+            .data_type_id()
+            .to_physical_type();
+
+        match physical_type {
+            PhysicalTypeID::Null | PhysicalTypeID::Array | PhysicalTypeID::Struct => {
+                for (row, hasher) in hashers.iter_mut().enumerate() {
+                    hash_data_value(&column.get(row), hasher);
+                }
+            }
+            PhysicalTypeID::String => {
+                let viewer = Vu8::try_create_viewer(column)?;
+                for (row, hasher) in hashers.iter_mut().enumerate() {
+                    if viewer.null_at(row) {
+                        hasher.write_u8(NULL_TAG);
+                    } else {
+                        hash_bytes(viewer.value_at(row), hasher);
+                    }
+                }
+            }
+            _ => {
+                with_match_scalar_types_error!(physical_type, |$S| {
+                    let viewer = <$S as Scalar>::try_create_viewer(column)?;
+                    for (row, hasher) in hashers.iter_mut().enumerate() {
+                        if viewer.null_at(row) {
+                            hasher.write_u8(NULL_TAG);
+                        } else {
+                            hasher.write_u8(VALUE_TAG);
+                            DFHash::hash(&viewer.value_at(row), hasher);
+                        }
+                    }
+                })
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Function for HashFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        Ok(UInt64Type::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let mut hashers = vec![XxHash64::default(); input_rows];
+        for column in columns {
+            Self::hash_column(column.column(), &mut hashers)?;
+        }
+
+        let values = hashers.iter().map(|h| h.finish()).collect::<Vec<_>>();
+        Ok(UInt64Column::new_from_vec(values).arc())
+    }
+}
+
+impl fmt::Display for HashFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
+
+#[inline]
+fn hash_bytes<H: Hasher>(value: &[u8], hasher: &mut H) {
+    hasher.write_u8(VALUE_TAG);
+    hasher.write_u64(value.len() as u64);
+    hasher.write(value);
+}
+
+fn hash_data_value<H: Hasher>(value: &DataValue, hasher: &mut H) {
+    match value {
+        DataValue::Null => hasher.write_u8(NULL_TAG),
+        DataValue::Boolean(v) => {
+            hasher.write_u8(VALUE_TAG);
+            DFHash::hash(v, hasher);
+        }
+        DataValue::Int64(v) => {
+            hasher.write_u8(VALUE_TAG);
+            DFHash::hash(v, hasher);
+        }
+        DataValue::UInt64(v) => {
+            hasher.write_u8(VALUE_TAG);
+            DFHash::hash(v, hasher);
+        }
+        DataValue::Float64(v) => {
+            hasher.write_u8(VALUE_TAG);
+            DFHash::hash(v, hasher);
+        }
+        DataValue::String(v) => hash_bytes(v, hasher),
+        DataValue::Array(values) | DataValue::Struct(values) => {
+            hasher.write_u8(VALUE_TAG);
+            hasher.write_u64(values.len() as u64);
+            for value in values {
+                hash_data_value(value, hasher);
+            }
+        }
+    }
+}
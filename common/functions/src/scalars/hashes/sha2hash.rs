@@ -24,6 +24,8 @@ use sha2::Digest;
 
 use crate::scalars::cast_column_field;
 use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::strings::String2StringFunction;
+use crate::scalars::strings::StringOperator;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 
@@ -173,3 +175,25 @@ impl fmt::Display for Sha2HashFunction {
         write!(f, "{}", self.display_name)
     }
 }
+
+#[derive(Clone, Default)]
+pub struct Sha256 {}
+
+impl StringOperator for Sha256 {
+    #[inline]
+    fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
+        let buffer = &mut buffer[0..64];
+        let mut h = sha2::Sha256::new();
+        h.update(s);
+        hex::encode_to_slice(h.finalize().as_slice(), buffer)
+            .map_err(|e| ErrorCode::StrParseError(e.to_string()))?;
+        Ok(64)
+    }
+
+    fn estimate_bytes(&self, array: &StringColumn) -> usize {
+        array.len() * 64
+    }
+}
+
+/// SHA256(str) is the same as SHA2(str, 256).
+pub type Sha256HashFunction = String2StringFunction<Sha256>;
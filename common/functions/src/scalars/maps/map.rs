@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::MapKeysFunction;
+use crate::scalars::MapSizeFunction;
+use crate::scalars::MapValuesFunction;
+
+#[derive(Clone)]
+pub struct MapFunction;
+
+impl MapFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("map_keys", MapKeysFunction::desc());
+        factory.register("map_values", MapValuesFunction::desc());
+        factory.register("map_size", MapSizeFunction::desc());
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+pub type MapKeysFunction = MapEntryFunction<true>;
+pub type MapValuesFunction = MapEntryFunction<false>;
+
+/// MAP_KEYS(map) returns the array of the keys of the map,
+/// MAP_VALUES(map) returns the array of the values of the map.
+#[derive(Clone)]
+pub struct MapEntryFunction<const KEYS: bool> {
+    display_name: String,
+}
+
+impl<const KEYS: bool> MapEntryFunction<KEYS> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapEntryFunction::<KEYS> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl<const KEYS: bool> Function for MapEntryFunction<KEYS> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        let map_type = args[0].as_any().downcast_ref::<MapType>().ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be Map, but got {:?}",
+                self.display_name, args[0]
+            ))
+        })?;
+
+        let inner = match KEYS {
+            true => map_type.key_type(),
+            false => map_type.value_type(),
+        };
+        Ok(Arc::new(ArrayType::create(inner.clone())))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entry_column: &StructColumn = Series::check_get(map_column.values())?;
+
+        // The keys or the values share the offsets of the map entries.
+        let inner = match KEYS {
+            true => entry_column.values()[0].clone(),
+            false => entry_column.values()[1].clone(),
+        };
+        let data_type = self.return_type(&[columns[0].data_type()])?;
+        Ok(Arc::new(ArrayColumn::from_data(
+            data_type,
+            map_column.offsets().to_vec().into(),
+            inner,
+        )))
+    }
+}
+
+impl<const KEYS: bool> fmt::Display for MapEntryFunction<KEYS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+/// MAP_SIZE(map) returns the number of the entries in the map.
+#[derive(Clone)]
+pub struct MapSizeFunction {
+    display_name: String,
+}
+
+impl MapSizeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MapSizeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl Function for MapSizeFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        if !args[0].data_type_id().is_map() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be Map, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+        Ok(UInt64Type::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let sizes = (0..map_column.len())
+            .map(|row| map_column.size_at_index(row) as u64)
+            .collect::<Vec<_>>();
+        Ok(Series::from_data(sizes))
+    }
+}
+
+impl fmt::Display for MapSizeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
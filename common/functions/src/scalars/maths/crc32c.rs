@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use crate::scalars::BaseHashFunction;
+
+/// The reversed Castagnoli polynomial.
+const CASTAGNOLI_POLY: u32 = 0x82F6_3B78;
+
+const CRC32C_TABLE: [u32; 256] = make_crc32c_table();
+
+const fn make_crc32c_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CASTAGNOLI_POLY
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// CRC32C (Castagnoli) checksum, as used by iSCSI, ext4 and many columnar formats.
+#[derive(Clone, Default)]
+pub struct Crc32cHasher {
+    crc: u32,
+}
+
+impl Hasher for Crc32cHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut crc = !self.crc;
+        for byte in bytes {
+            crc = CRC32C_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+        }
+        self.crc = !crc;
+    }
+
+    fn finish(&self) -> u64 {
+        self.crc as u64
+    }
+}
+
+pub type Crc32cFunction = BaseHashFunction<Crc32cHasher, u32>;
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::math_domain::split_math_domain_mode;
+use super::math_domain::MathDomainErrorMode;
+use crate::scalars::assert_integer;
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// The largest argument whose factorial fits in Int64.
+const MAX_FACTORIAL_ARGUMENT: i64 = 20;
+
+#[derive(Clone)]
+pub struct FactorialFunction {
+    display_name: String,
+}
+
+impl FactorialFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FactorialFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+fn factorial(n: i64) -> i64 {
+    (1..=n).product()
+}
+
+impl Function for FactorialFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_integer(args[0])?;
+        // NULL for the negative arguments, and for the overflowing ones unless the mode is error.
+        Ok(wrap_nullable(&Int64Type::arc()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let (columns, mode) = split_math_domain_mode(columns)?;
+        let column = cast_column_field(&columns[0], &Int64Type::arc())?;
+        let viewer = i64::try_create_viewer(&column)?;
+
+        let mut builder = NullableColumnBuilder::<i64>::with_capacity(input_rows);
+        for n in viewer.iter() {
+            if n < 0 {
+                builder.append_null();
+            } else if n > MAX_FACTORIAL_ARGUMENT {
+                if mode == Some(MathDomainErrorMode::Error) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Function {} overflows Int64 with argument {}, the maximum is {}",
+                        self.display_name.to_uppercase(),
+                        n,
+                        MAX_FACTORIAL_ARGUMENT
+                    )));
+                }
+                builder.append_null();
+            } else {
+                builder.append(factorial(n), true);
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for FactorialFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FACTORIAL")
+    }
+}
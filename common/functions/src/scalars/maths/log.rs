@@ -22,7 +22,9 @@ use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
 use num_traits::AsPrimitive;
 
-use crate::scalars::assert_numeric;
+use super::math_domain::apply_math_domain_mode;
+use super::math_domain::math_domain_return_type;
+use super::math_domain::split_math_domain_mode;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
@@ -80,7 +82,7 @@ impl<T: Base> GenericLogFunction<T> {
         FunctionDescription::creator(Box::new(Self::try_create)).features(
             FunctionFeatures::default()
                 .deterministic()
-                .variadic_arguments(1, 2),
+                .variadic_arguments(1, 3),
         )
     }
 
@@ -104,19 +106,17 @@ impl<T: Base> Function for GenericLogFunction<T> {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        for arg in args {
-            assert_numeric(*arg)?;
-        }
-        Ok(f64::to_data_type())
+        math_domain_return_type(&self.display_name, args, 2)
     }
 
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
         let mut ctx = EvalContext::default();
-        if columns.len() == 1 {
+        let (columns, mode) = split_math_domain_mode(columns)?;
+        let col: ColumnRef = if columns.len() == 1 {
             with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
                 let unary = ScalarUnaryExpression::<$S, f64, _>::new(Self::log);
                 let col = unary.eval(columns[0].column(), &mut ctx)?;
-                Ok(Arc::new(col))
+                Arc::new(col)
             },{
                 unreachable!()
             })
@@ -125,14 +125,15 @@ impl<T: Base> Function for GenericLogFunction<T> {
                 with_match_primitive_type_id!(columns[1].data_type().data_type_id(), |$T| {
                     let binary = ScalarBinaryExpression::<$S, $T, f64, _>::new(Self::log_with_base);
                     let col = binary.eval(columns[0].column(), columns[1].column(), &mut ctx)?;
-                    Ok(Arc::new(col))
+                    Arc::new(col)
                 },{
                     unreachable!()
                 })
             },{
                 unreachable!()
             })
-        }
+        };
+        apply_math_domain_mode(&self.display_name, col, mode)
     }
 }
 
@@ -19,8 +19,10 @@ use super::round::TruncNumberFunction;
 use crate::scalars::AbsFunction;
 use crate::scalars::BaseHashFunction;
 use crate::scalars::CeilFunction;
+use crate::scalars::Crc32cFunction;
 use crate::scalars::DegressFunction;
 use crate::scalars::ExpFunction;
+use crate::scalars::FactorialFunction;
 use crate::scalars::FloorFunction;
 use crate::scalars::FunctionFactory;
 use crate::scalars::LnFunction;
@@ -34,13 +36,19 @@ use crate::scalars::RandomFunction;
 use crate::scalars::SignFunction;
 use crate::scalars::SqrtFunction;
 use crate::scalars::TrigonometricAcosFunction;
+use crate::scalars::TrigonometricAcoshFunction;
 use crate::scalars::TrigonometricAsinFunction;
+use crate::scalars::TrigonometricAsinhFunction;
 use crate::scalars::TrigonometricAtan2Function;
 use crate::scalars::TrigonometricAtanFunction;
+use crate::scalars::TrigonometricAtanhFunction;
 use crate::scalars::TrigonometricCosFunction;
+use crate::scalars::TrigonometricCoshFunction;
 use crate::scalars::TrigonometricCotFunction;
 use crate::scalars::TrigonometricSinFunction;
+use crate::scalars::TrigonometricSinhFunction;
 use crate::scalars::TrigonometricTanFunction;
+use crate::scalars::TrigonometricTanhFunction;
 
 pub type CRC32Function = BaseHashFunction<CRC32, u32>;
 
@@ -52,7 +60,9 @@ impl MathsFunction {
         factory.register("sign", SignFunction::desc());
         factory.register("pi", PiFunction::desc());
         factory.register("crc32", CRC32Function::desc());
+        factory.register("crc32c", Crc32cFunction::desc());
         factory.register("exp", ExpFunction::desc());
+        factory.register("factorial", FactorialFunction::desc());
         factory.register("sqrt", SqrtFunction::desc());
         factory.register("ceil", CeilFunction::desc());
         factory.register("ceiling", CeilFunction::desc());
@@ -76,6 +86,12 @@ impl MathsFunction {
         factory.register("acos", TrigonometricAcosFunction::desc());
         factory.register("atan", TrigonometricAtanFunction::desc());
         factory.register("atan2", TrigonometricAtan2Function::desc());
+        factory.register("sinh", TrigonometricSinhFunction::desc());
+        factory.register("cosh", TrigonometricCoshFunction::desc());
+        factory.register("tanh", TrigonometricTanhFunction::desc());
+        factory.register("asinh", TrigonometricAsinhFunction::desc());
+        factory.register("acosh", TrigonometricAcoshFunction::desc());
+        factory.register("atanh", TrigonometricAtanhFunction::desc());
 
         factory.register("degrees", DegressFunction::desc());
         factory.register("radians", RadiansFunction::desc());
@@ -0,0 +1,196 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::assert_numeric;
+
+/// Functions whose result is NaN when the argument is outside of their domain, e.g. SQRT(-1),
+/// and FACTORIAL, whose result overflows Int64 when the argument is greater than 20.
+///
+/// The analyzer appends the session's `math_domain_error` as a trailing String argument
+/// to these functions, which decides what an out-of-domain result turns into.
+const MATH_DOMAIN_FUNCTIONS: [&str; 22] = [
+    "sqrt",
+    "ln",
+    "log",
+    "log2",
+    "log10",
+    "pow",
+    "power",
+    "sin",
+    "cos",
+    "tan",
+    "cot",
+    "asin",
+    "acos",
+    "atan",
+    "atan2",
+    "sinh",
+    "cosh",
+    "tanh",
+    "asinh",
+    "acosh",
+    "atanh",
+    "factorial",
+];
+
+pub fn is_math_domain_function(name: &str) -> bool {
+    let name = name.to_lowercase();
+    MATH_DOMAIN_FUNCTIONS.iter().any(|v| *v == name)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathDomainErrorMode {
+    /// The out-of-domain result is NULL.
+    Null,
+    /// The out-of-domain result is NaN.
+    NaN,
+    /// The query fails on the first out-of-domain result.
+    Error,
+}
+
+impl MathDomainErrorMode {
+    pub const POSSIBLE_VALUES: [&'static str; 3] = ["null", "nan", "error"];
+}
+
+impl fmt::Display for MathDomainErrorMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathDomainErrorMode::Null => write!(f, "null"),
+            MathDomainErrorMode::NaN => write!(f, "nan"),
+            MathDomainErrorMode::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FromStr for MathDomainErrorMode {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "null" => Ok(MathDomainErrorMode::Null),
+            "nan" => Ok(MathDomainErrorMode::NaN),
+            "error" => Ok(MathDomainErrorMode::Error),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown math domain error mode: {}, expected one of {:?}",
+                s,
+                Self::POSSIBLE_VALUES
+            ))),
+        }
+    }
+}
+
+/// Whether the last argument is the mode appended by the analyzer.
+fn has_mode_arg(args: &[&DataTypePtr]) -> bool {
+    args.len() > 1
+        && remove_nullable(args[args.len() - 1])
+            .data_type_id()
+            .is_string()
+}
+
+/// The return type of a math domain function, the numeric arguments are checked.
+///
+/// Without a mode argument the result keeps being a non-nullable Float64 with NaN.
+pub fn math_domain_return_type(
+    display_name: &str,
+    args: &[&DataTypePtr],
+    max_numeric_args: usize,
+) -> Result<DataTypePtr> {
+    let has_mode = has_mode_arg(args);
+    let numeric_args = match has_mode {
+        true => &args[..args.len() - 1],
+        false => args,
+    };
+
+    if numeric_args.len() > max_numeric_args {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "Function `{}` expect to have at most {} numeric arguments, but got {}",
+            display_name,
+            max_numeric_args,
+            numeric_args.len()
+        )));
+    }
+
+    for arg in numeric_args {
+        assert_numeric(*arg)?;
+    }
+
+    match has_mode {
+        true => Ok(wrap_nullable(&Float64Type::arc())),
+        false => Ok(Float64Type::arc()),
+    }
+}
+
+/// Splits the columns into the numeric arguments and the optional mode.
+pub fn split_math_domain_mode(
+    columns: &ColumnsWithField,
+) -> Result<(&ColumnsWithField, Option<MathDomainErrorMode>)> {
+    let types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+    if !has_mode_arg(&types) {
+        return Ok((columns, None));
+    }
+
+    let (mode, numeric_columns) = columns.split_last().unwrap();
+    let mode = match mode.column().get(0) {
+        DataValue::String(v) => MathDomainErrorMode::from_str(&String::from_utf8_lossy(&v))?,
+        other => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Math domain error mode must be a String, but got {:?}",
+                other
+            )))
+        }
+    };
+    Ok((numeric_columns, Some(mode)))
+}
+
+/// Turns the NaN values of `column` into NULL, or an error, according to `mode`.
+pub fn apply_math_domain_mode(
+    display_name: &str,
+    column: ColumnRef,
+    mode: Option<MathDomainErrorMode>,
+) -> Result<ColumnRef> {
+    let mode = match mode {
+        None => return Ok(column),
+        Some(mode) => mode,
+    };
+
+    let viewer = f64::try_create_viewer(&column)?;
+    let mut validity = MutableBitmap::with_capacity(column.len());
+    for value in viewer.iter() {
+        if value.is_nan() {
+            match mode {
+                MathDomainErrorMode::Null => validity.push(false),
+                MathDomainErrorMode::NaN => validity.push(true),
+                MathDomainErrorMode::Error => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Function {} got an argument out of its domain",
+                        display_name.to_uppercase()
+                    )));
+                }
+            }
+        } else {
+            validity.push(true);
+        }
+    }
+
+    Ok(Arc::new(NullableColumn::new(column, validity.into())))
+}
@@ -15,10 +15,13 @@
 mod abs;
 mod angle;
 mod ceil;
+mod crc32c;
 mod exp;
+mod factorial;
 mod floor;
 mod log;
 mod math;
+mod math_domain;
 mod pi;
 mod pow;
 mod random;
@@ -31,7 +34,9 @@ pub use abs::AbsFunction;
 pub use angle::DegressFunction;
 pub use angle::RadiansFunction;
 pub use ceil::CeilFunction;
+pub use crc32c::Crc32cFunction;
 pub use exp::ExpFunction;
+pub use factorial::FactorialFunction;
 pub use floor::FloorFunction;
 pub use log::LnFunction;
 pub use log::Log10Function;
@@ -39,6 +44,8 @@ pub use log::Log2Function;
 pub use log::LogFunction;
 pub use math::CRC32Function;
 pub use math::MathsFunction;
+pub use math_domain::is_math_domain_function;
+pub use math_domain::MathDomainErrorMode;
 pub use pi::PiFunction;
 pub use pow::PowFunction;
 pub use random::RandomFunction;
@@ -48,10 +55,16 @@ pub use sign::SignFunction;
 pub use sqrt::SqrtFunction;
 pub use trigonometric::Trigonometric;
 pub use trigonometric::TrigonometricAcosFunction;
+pub use trigonometric::TrigonometricAcoshFunction;
 pub use trigonometric::TrigonometricAsinFunction;
+pub use trigonometric::TrigonometricAsinhFunction;
 pub use trigonometric::TrigonometricAtan2Function;
 pub use trigonometric::TrigonometricAtanFunction;
+pub use trigonometric::TrigonometricAtanhFunction;
 pub use trigonometric::TrigonometricCosFunction;
+pub use trigonometric::TrigonometricCoshFunction;
 pub use trigonometric::TrigonometricCotFunction;
 pub use trigonometric::TrigonometricSinFunction;
+pub use trigonometric::TrigonometricSinhFunction;
 pub use trigonometric::TrigonometricTanFunction;
+pub use trigonometric::TrigonometricTanhFunction;
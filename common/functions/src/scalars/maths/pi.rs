@@ -16,11 +16,16 @@ use std::f64::consts::PI;
 use std::fmt;
 
 use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
+use num::cast::AsPrimitive;
 
+use crate::scalars::function_common::assert_numeric;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
 use crate::scalars::Function;
+use crate::scalars::ScalarUnaryExpression;
 
 #[derive(Clone)]
 pub struct PiFunction {
@@ -35,8 +40,16 @@ impl PiFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().deterministic())
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(0, 1),
+        )
+    }
+
+    fn multiple<S>(value: S, _ctx: &mut EvalContext) -> f64
+    where S: AsPrimitive<f64> {
+        value.as_() * PI
     }
 }
 
@@ -45,12 +58,27 @@ impl Function for PiFunction {
         &*self.display_name
     }
 
-    fn return_type(&self, _args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if !args.is_empty() {
+            assert_numeric(args[0])?;
+        }
         Ok(Float64Type::arc())
     }
 
-    fn eval(&self, _columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
-        Ok(ConstColumn::new(Series::from_data(vec![PI]), input_rows).arc())
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns.is_empty() {
+            return Ok(ConstColumn::new(Series::from_data(vec![PI]), input_rows).arc());
+        }
+
+        // PI(n) returns n * PI.
+        let mut ctx = EvalContext::default();
+        with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
+            let unary = ScalarUnaryExpression::<$S, f64, _>::new(Self::multiple);
+            let col = unary.eval(columns[0].column(), &mut ctx)?;
+            Ok(col.arc())
+        },{
+            unreachable!()
+        })
     }
 }
 
@@ -21,7 +21,9 @@ use common_exception::Result;
 use num::traits::Pow;
 use num_traits::AsPrimitive;
 
-use crate::scalars::assert_numeric;
+use super::math_domain::apply_math_domain_mode;
+use super::math_domain::math_domain_return_type;
+use super::math_domain::split_math_domain_mode;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
@@ -41,8 +43,11 @@ impl PowFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
     }
 }
 
@@ -60,24 +65,23 @@ impl Function for PowFunction {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        for arg in args {
-            assert_numeric(*arg)?;
-        }
-        Ok(f64::to_data_type())
+        math_domain_return_type(&self.display_name, args, 2)
     }
 
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
-        with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
+        let (columns, mode) = split_math_domain_mode(columns)?;
+        let col: ColumnRef = with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
             with_match_primitive_type_id!(columns[1].data_type().data_type_id(), |$T| {
                 let binary = ScalarBinaryExpression::<$S, $T, f64, _>::new(scalar_pow);
                 let col = binary.eval(columns[0].column(), columns[1].column(), &mut EvalContext::default())?;
-                Ok(Arc::new(col))
+                Arc::new(col)
             },{
                 unreachable!()
             })
         },{
             unreachable!()
-        })
+        });
+        apply_math_domain_mode(&self.display_name, col, mode)
     }
 }
 
@@ -19,7 +19,9 @@ use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
 use num::cast::AsPrimitive;
 
-use crate::scalars::function_common::assert_numeric;
+use super::math_domain::apply_math_domain_mode;
+use super::math_domain::math_domain_return_type;
+use super::math_domain::split_math_domain_mode;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::EvalContext;
@@ -39,8 +41,11 @@ impl SqrtFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -55,19 +60,19 @@ impl Function for SqrtFunction {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        assert_numeric(args[0])?;
-        Ok(Float64Type::arc())
+        math_domain_return_type(&self.display_name, args, 1)
     }
 
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
         let mut ctx = EvalContext::default();
-        with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
+        let (columns, mode) = split_math_domain_mode(columns)?;
+        let col = with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
              let unary = ScalarUnaryExpression::<$S, f64, _>::new(sqrt::<$S>);
-             let col = unary.eval(columns[0].column(), &mut ctx)?;
-             Ok(col.arc())
+             unary.eval(columns[0].column(), &mut ctx)?.arc()
         },{
             unreachable!()
-        })
+        });
+        apply_math_domain_mode(&self.display_name, col, mode)
     }
 }
 
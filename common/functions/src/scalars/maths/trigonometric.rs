@@ -20,7 +20,9 @@ use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
 use num_traits::AsPrimitive;
 
-use crate::scalars::assert_numeric;
+use super::math_domain::apply_math_domain_mode;
+use super::math_domain::math_domain_return_type;
+use super::math_domain::split_math_domain_mode;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
@@ -43,6 +45,12 @@ pub enum Trigonometric {
     ASIN,
     ATAN,
     ATAN2,
+    SINH,
+    COSH,
+    TANH,
+    ASINH,
+    ACOSH,
+    ATANH,
 }
 
 impl fmt::Display for Trigonometric {
@@ -56,6 +64,12 @@ impl fmt::Display for Trigonometric {
             Trigonometric::ASIN => "asin",
             Trigonometric::ATAN => "atan",
             Trigonometric::ATAN2 => "atan2",
+            Trigonometric::SINH => "sinh",
+            Trigonometric::COSH => "cosh",
+            Trigonometric::TANH => "tanh",
+            Trigonometric::ASINH => "asinh",
+            Trigonometric::ACOSH => "acosh",
+            Trigonometric::ATANH => "atanh",
         };
         write!(f, "{}", display)
     }
@@ -73,53 +87,87 @@ impl Function for TrigonometricFunction {
     }
 
     fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
-        for arg in args {
-            assert_numeric(*arg)?;
-        }
-        Ok(f64::to_data_type())
+        let max_numeric_args = match self.t {
+            Trigonometric::ATAN | Trigonometric::ATAN2 => 2,
+            _ => 1,
+        };
+        math_domain_return_type(&self.t.to_string(), args, max_numeric_args)
     }
 
     fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
         let mut ctx = EvalContext::default();
-        match columns.len() {
+        let (columns, mode) = split_math_domain_mode(columns)?;
+        let col: ColumnRef = match columns.len() {
             1 => {
                 with_match_primitive_type_id!(columns[0].data_type().data_type_id(), |$S| {
                    match self.t {
                         Trigonometric::COS => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext|  AsPrimitive::<f64>::as_(v).cos());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         Trigonometric::SIN => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext|  AsPrimitive::<f64>::as_(v).sin());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         Trigonometric::COT => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| 1.0 /  AsPrimitive::<f64>::as_(v).tan());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         Trigonometric::TAN => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).tan());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         // the range [0, pi] or NaN if the number is outside the range
                         Trigonometric::ACOS => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).acos());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         Trigonometric::ASIN => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).asin());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
                         },
                         Trigonometric::ATAN => {
                            let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).atan());
                            let col = unary.eval(columns[0].column(), &mut ctx)?;
-                           Ok(Arc::new(col))
+                           Arc::new(col)
+                        },
+                        Trigonometric::SINH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).sinh());
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
+                        },
+                        Trigonometric::COSH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).cosh());
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
+                        },
+                        Trigonometric::TANH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).tanh());
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
+                        },
+                        Trigonometric::ASINH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).asinh());
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
+                        },
+                        // NaN if the number is less than 1
+                        Trigonometric::ACOSH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| AsPrimitive::<f64>::as_(v).acosh());
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
+                        },
+                        // the range (-1, 1), NaN instead of the infinity at 1 and -1
+                        Trigonometric::ATANH => {
+                           let unary =  ScalarUnaryExpression::<$S, f64, _>::new(|v: $S, _ctx: &mut EvalContext| scalar_atanh(AsPrimitive::<f64>::as_(v)));
+                           let col = unary.eval(columns[0].column(), &mut ctx)?;
+                           Arc::new(col)
                         },
                         _ => unreachable!(),
                     }
@@ -134,7 +182,7 @@ impl Function for TrigonometricFunction {
                     with_match_primitive_type_id!(columns[1].data_type().data_type_id(), |$T| {
                         let binary = ScalarBinaryExpression::<$S, $T, f64, _>::new(scalar_atan2);
                         let col = binary.eval(columns[0].column(), columns[1].column(), &mut EvalContext::default())?;
-                        Ok(Arc::new(col))
+                        Arc::new(col)
                     }, {
                         unreachable!()
                     })
@@ -142,7 +190,8 @@ impl Function for TrigonometricFunction {
                     unreachable!()
                 })
             }
-        }
+        };
+        apply_math_domain_mode(&self.t.to_string(), col, mode)
     }
 }
 
@@ -154,6 +203,13 @@ fn scalar_atan2<S: AsPrimitive<f64>, T: AsPrimitive<f64>>(
     s.as_().atan2(t.as_())
 }
 
+fn scalar_atanh(v: f64) -> f64 {
+    match v.abs() >= 1.0 {
+        true => f64::NAN,
+        false => v.atanh(),
+    }
+}
+
 impl fmt::Display for TrigonometricFunction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.t)
@@ -168,8 +224,11 @@ impl TrigonometricSinFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -181,8 +240,11 @@ impl TrigonometricCosFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -194,8 +256,11 @@ impl TrigonometricTanFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -207,8 +272,11 @@ impl TrigonometricCotFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -220,8 +288,11 @@ impl TrigonometricAsinFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -233,8 +304,11 @@ impl TrigonometricAcosFunction {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
 
@@ -249,7 +323,7 @@ impl TrigonometricAtanFunction {
         FunctionDescription::creator(Box::new(Self::try_create_func)).features(
             FunctionFeatures::default()
                 .deterministic()
-                .variadic_arguments(1, 2),
+                .variadic_arguments(1, 3),
         )
     }
 }
@@ -262,7 +336,106 @@ impl TrigonometricAtan2Function {
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create_func))
-            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+pub struct TrigonometricSinhFunction;
+
+impl TrigonometricSinhFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::SINH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+pub struct TrigonometricCoshFunction;
+
+impl TrigonometricCoshFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::COSH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+pub struct TrigonometricTanhFunction;
+
+impl TrigonometricTanhFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::TANH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+pub struct TrigonometricAsinhFunction;
+
+impl TrigonometricAsinhFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::ASINH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+pub struct TrigonometricAcoshFunction;
+
+impl TrigonometricAcoshFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::ACOSH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
+    }
+}
+
+pub struct TrigonometricAtanhFunction;
+
+impl TrigonometricAtanhFunction {
+    pub fn try_create_func(_display_name: &str) -> Result<Box<dyn Function>> {
+        TrigonometricFunction::try_create_func(Trigonometric::ATANH)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(1, 2),
+        )
     }
 }
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitwises;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -24,15 +26,19 @@ mod function_factory;
 mod function_monotonic;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
 mod uuids;
 
 pub use arithmetics::*;
+pub use arrays::*;
+pub use bitwises::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -45,9 +51,11 @@ pub use function_factory::*;
 pub use function_monotonic::Monotonicity;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use nullables::*;
 pub use others::*;
+pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
 pub use udfs::*;
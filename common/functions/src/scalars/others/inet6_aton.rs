@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+use std::net::IpAddr;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+/// Converts an IPv6 or IPv4 address string into its network byte order binary form,
+/// 16 bytes for IPv6 and 4 bytes for IPv4, the invalid address yields NULL.
+#[derive(Clone)]
+pub struct Inet6AtonFunction {
+    display_name: String,
+}
+
+impl Inet6AtonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Inet6AtonFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl Function for Inet6AtonFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args[0].data_type_id() {
+            TypeID::String => Ok(wrap_nullable(&StringType::arc())),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null type, but got {}",
+                args[0].name()
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+
+        let mut builder = NullableColumnBuilder::<Vu8>::with_capacity(input_rows);
+        for (i, input) in viewer.iter().enumerate() {
+            let addr = std::str::from_utf8(input)
+                .ok()
+                .and_then(|v| v.parse::<IpAddr>().ok());
+            match addr {
+                Some(IpAddr::V4(addr)) => {
+                    builder.append(addr.octets().as_slice(), viewer.valid_at(i))
+                }
+                Some(IpAddr::V6(addr)) => {
+                    builder.append(addr.octets().as_slice(), viewer.valid_at(i))
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for Inet6AtonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -28,8 +28,9 @@ use crate::scalars::FunctionDescription;
 #[doc(alias = "TryIPv4StringToNumFunction")]
 pub type TryInetAtonFunction = InetAtonFunctionImpl<true>;
 
+// Same as MySQL, inet_aton yields NULL for the invalid input instead of failing.
 #[doc(alias = "IPv4StringToNumFunction")]
-pub type InetAtonFunction = InetAtonFunctionImpl<false>;
+pub type InetAtonFunction = InetAtonFunctionImpl<true>;
 
 #[derive(Clone)]
 pub struct InetAtonFunctionImpl<const SUPPRESS_PARSE_ERROR: bool> {
@@ -13,12 +13,16 @@
 // limitations under the License.
 
 mod ignore;
+mod inet6_aton;
+mod inet6_ntoa;
 mod inet_aton;
 mod inet_ntoa;
 mod other;
 mod running_difference_function;
 
 pub use ignore::IgnoreFunction;
+pub use inet6_aton::Inet6AtonFunction;
+pub use inet6_ntoa::Inet6NtoaFunction;
 pub use inet_aton::InetAtonFunction;
 pub use inet_aton::TryInetAtonFunction;
 pub use inet_ntoa::InetNtoaFunction;
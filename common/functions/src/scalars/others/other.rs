@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::inet6_aton::Inet6AtonFunction;
+use super::inet6_ntoa::Inet6NtoaFunction;
 use super::inet_aton::InetAtonFunction;
 use super::inet_aton::TryInetAtonFunction;
 use super::inet_ntoa::InetNtoaFunction;
@@ -43,5 +45,11 @@ impl OtherFunction {
         // try_inet_ntoa
         factory.register("try_inet_ntoa", TryInetNtoaFunction::desc());
         factory.register("TryIPv4NumToString", TryInetNtoaFunction::desc());
+
+        // inet6_aton
+        factory.register("inet6_aton", Inet6AtonFunction::desc());
+
+        // inet6_ntoa
+        factory.register("inet6_ntoa", Inet6NtoaFunction::desc());
     }
 }
@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::semi_structureds::json_path::extract_value_by_path;
+use crate::scalars::semi_structureds::json_path::parse_json_path;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+pub type JsonExtractPathFunction = JsonExtractPathFunctionImpl<false>;
+pub type JsonExtractPathTextFunction = JsonExtractPathFunctionImpl<true>;
+
+#[derive(Clone)]
+pub struct JsonExtractPathFunctionImpl<const AS_TEXT: bool> {
+    display_name: String,
+}
+
+impl<const AS_TEXT: bool> JsonExtractPathFunctionImpl<AS_TEXT> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonExtractPathFunctionImpl::<AS_TEXT> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(2),
+        )
+    }
+}
+
+impl<const AS_TEXT: bool> Function for JsonExtractPathFunctionImpl<AS_TEXT> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() || args[1].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        let json_type = remove_nullable(args[0]).data_type_id();
+        if !json_type.is_variant() && !json_type.is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the first argument to be Variant or String, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+        if !remove_nullable(args[1]).data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the path to be String, but got {:?}",
+                self.display_name, args[1]
+            )));
+        }
+
+        // Null if the path does not exist.
+        match AS_TEXT {
+            true => Ok(wrap_nullable(&StringType::arc())),
+            false => Ok(wrap_nullable(&VariantType::arc())),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() || columns[1].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let json_viewer = Vu8::try_create_viewer(columns[0].column())?;
+        let path_column = columns[1].column();
+
+        // The path is parsed only once if it's a constant.
+        let const_path = match path_column.is_const() && !path_column.null_at(0) {
+            true => Some(parse_json_path(&path_column.get_string(0)?)?),
+            false => None,
+        };
+        let path_viewer = Vu8::try_create_viewer(path_column)?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut validity = MutableBitmap::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if json_viewer.null_at(row) || path_viewer.null_at(row) {
+                builder.append_default();
+                validity.push(false);
+                continue;
+            }
+
+            let json = serde_json::from_slice::<Value>(json_viewer.value_at(row)).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Function {} got invalid JSON value: {}",
+                    self.display_name, e
+                ))
+            })?;
+
+            let row_path;
+            let path = match &const_path {
+                Some(path) => path,
+                None => {
+                    row_path = parse_json_path(path_viewer.value_at(row))?;
+                    &row_path
+                }
+            };
+
+            match extract_value_by_path(&json, path) {
+                // The text of a JSON null is NULL.
+                Some(Value::Null) if AS_TEXT => {
+                    builder.append_default();
+                    validity.push(false);
+                }
+                Some(Value::String(v)) if AS_TEXT => {
+                    builder.append_value(v);
+                    validity.push(true);
+                }
+                Some(value) => {
+                    builder.append_value(value.to_string());
+                    validity.push(true);
+                }
+                None => {
+                    builder.append_default();
+                    validity.push(false);
+                }
+            }
+        }
+
+        Ok(Arc::new(NullableColumn::new(
+            builder.to_column(),
+            validity.into(),
+        )))
+    }
+}
+
+impl<const AS_TEXT: bool> fmt::Display for JsonExtractPathFunctionImpl<AS_TEXT> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+#[derive(Clone)]
+pub struct JsonObjectFunction {
+    display_name: String,
+}
+
+impl JsonObjectFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonObjectFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .variadic_arguments(0, usize::MAX),
+        )
+    }
+}
+
+impl Function for JsonObjectFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args.len() % 2 != 0 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Function {} requires an even number of arguments, but got {}",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        for key in args.iter().step_by(2) {
+            let key_type = remove_nullable(key).data_type_id();
+            if !key_type.is_string() && !key_type.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Function {} key must be String, but got {:?}",
+                    self.display_name, key
+                )));
+            }
+        }
+        Ok(VariantType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let values = columns
+            .iter()
+            .map(|c| {
+                let column = c.column().convert_full_column();
+                c.data_type().create_serializer().serialize_json(&column)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        for row in 0..input_rows {
+            // Duplicate keys are overwritten, the last value wins.
+            let mut object = Map::with_capacity(values.len() / 2);
+            for pair in values.chunks(2) {
+                let key = match &pair[0][row] {
+                    Value::String(key) => key.clone(),
+                    _ => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Function {} key must not be null",
+                            self.display_name
+                        )));
+                    }
+                };
+                object.insert(key, pair[1][row].clone());
+            }
+            builder.append_value(Value::Object(object).to_string());
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for JsonObjectFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
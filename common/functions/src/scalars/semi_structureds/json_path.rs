@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathElement {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse the JSONPath like `$.user.name`, `$.items[0]`, `$['first name']` or `user.name`.
+/// The leading `$` is optional.
+pub fn parse_json_path(path: &[u8]) -> Result<Vec<JsonPathElement>> {
+    let invalid = |cause: &str| {
+        ErrorCode::BadArguments(format!(
+            "Invalid JSON path {:?}: {}",
+            String::from_utf8_lossy(path),
+            cause
+        ))
+    };
+
+    if path.is_empty() {
+        return Err(invalid("path is empty"));
+    }
+
+    let mut elements = vec![];
+    let mut pos = 0;
+    if path[0] == b'$' {
+        pos = 1;
+    } else if path[0] != b'[' {
+        // The first key without the leading `$.`.
+        let end = key_end(path, pos);
+        if end == pos {
+            return Err(invalid("empty key at position 0"));
+        }
+        elements.push(JsonPathElement::Key(to_key(&path[pos..end])));
+        pos = end;
+    }
+
+    while pos < path.len() {
+        match path[pos] {
+            b'.' => {
+                let end = key_end(path, pos + 1);
+                if end == pos + 1 {
+                    return Err(invalid(&format!("empty key at position {}", pos)));
+                }
+                elements.push(JsonPathElement::Key(to_key(&path[pos + 1..end])));
+                pos = end;
+            }
+            b'[' => {
+                let end = path[pos..]
+                    .iter()
+                    .position(|c| *c == b']')
+                    .map(|p| p + pos)
+                    .ok_or_else(|| invalid(&format!("unclosed '[' at position {}", pos)))?;
+                let inner = &path[pos + 1..end];
+                match inner {
+                    [q, key @ .., e] if (*q == b'\'' || *q == b'"') && q == e => {
+                        elements.push(JsonPathElement::Key(to_key(key)));
+                    }
+                    _ => {
+                        let index = std::str::from_utf8(inner)
+                            .ok()
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .ok_or_else(|| {
+                                invalid(&format!("invalid array index at position {}", pos))
+                            })?;
+                        elements.push(JsonPathElement::Index(index));
+                    }
+                }
+                pos = end + 1;
+            }
+            c => {
+                return Err(invalid(&format!(
+                    "unexpected character {:?} at position {}",
+                    c as char, pos
+                )));
+            }
+        }
+    }
+    Ok(elements)
+}
+
+/// Get the value by the path, None if the path does not exist.
+pub fn extract_value_by_path<'a>(value: &'a Value, path: &[JsonPathElement]) -> Option<&'a Value> {
+    path.iter().try_fold(value, |value, element| match element {
+        JsonPathElement::Key(key) => value.as_object()?.get(key),
+        JsonPathElement::Index(index) => value.as_array()?.get(*index),
+    })
+}
+
+fn key_end(path: &[u8], start: usize) -> usize {
+    path[start..]
+        .iter()
+        .position(|c| *c == b'.' || *c == b'[')
+        .map(|p| p + start)
+        .unwrap_or(path.len())
+}
+
+fn to_key(key: &[u8]) -> String {
+    String::from_utf8_lossy(key).to_string()
+}
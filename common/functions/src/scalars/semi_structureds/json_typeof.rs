@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+#[derive(Clone)]
+pub struct JsonTypeofFunction {
+    display_name: String,
+}
+
+impl JsonTypeofFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(JsonTypeofFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl Function for JsonTypeofFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        if !remove_nullable(args[0]).data_type_id().is_variant() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be Variant, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+
+        Ok(wrap_nullable(&StringType::arc()))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let json_viewer = Vu8::try_create_viewer(columns[0].column())?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut validity = MutableBitmap::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if json_viewer.null_at(row) {
+                builder.append_default();
+                validity.push(false);
+                continue;
+            }
+
+            let json = serde_json::from_slice::<Value>(json_viewer.value_at(row)).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Function {} got invalid JSON value: {}",
+                    self.display_name, e
+                ))
+            })?;
+
+            let type_name = match json {
+                Value::Null => "null",
+                Value::Bool(_) => "boolean",
+                Value::Number(_) => "number",
+                Value::String(_) => "string",
+                Value::Array(_) => "array",
+                Value::Object(_) => "object",
+            };
+            builder.append_value(type_name);
+            validity.push(true);
+        }
+
+        Ok(Arc::new(NullableColumn::new(
+            builder.to_column(),
+            validity.into(),
+        )))
+    }
+}
+
+impl fmt::Display for JsonTypeofFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
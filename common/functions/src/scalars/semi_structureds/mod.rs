@@ -0,0 +1,36 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod json_array_length;
+mod json_extract_path;
+mod json_object;
+mod json_path;
+mod json_typeof;
+mod parse_json;
+mod semi_structured;
+mod to_json;
+
+pub use json_array_length::JsonArrayLengthFunction;
+pub use json_extract_path::JsonExtractPathFunction;
+pub use json_extract_path::JsonExtractPathTextFunction;
+pub use json_object::JsonObjectFunction;
+pub use json_path::extract_value_by_path;
+pub use json_path::parse_json_path;
+pub use json_path::JsonPathElement;
+pub use json_typeof::JsonTypeofFunction;
+pub use parse_json::ParseJsonFunction;
+pub use parse_json::ParseJsonFunctionImpl;
+pub use parse_json::TryParseJsonFunction;
+pub use semi_structured::SemiStructuredFunction;
+pub use to_json::ToJsonFunction;
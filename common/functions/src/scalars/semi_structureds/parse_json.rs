@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+pub type ParseJsonFunction = ParseJsonFunctionImpl<false>;
+pub type TryParseJsonFunction = ParseJsonFunctionImpl<true>;
+
+/// PARSE_JSON(str) parses the JSON string into a Variant, an invalid JSON string is an error.
+/// TRY_PARSE_JSON(str) is the lenient version, which returns NULL for the invalid ones.
+#[derive(Clone)]
+pub struct ParseJsonFunctionImpl<const SUPPRESS_PARSE_ERROR: bool> {
+    display_name: String,
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> ParseJsonFunctionImpl<SUPPRESS_PARSE_ERROR> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseJsonFunctionImpl::<SUPPRESS_PARSE_ERROR> {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> Function for ParseJsonFunctionImpl<SUPPRESS_PARSE_ERROR> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        let type_id = remove_nullable(args[0]).data_type_id();
+        if !type_id.is_string() && !type_id.is_variant() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be String, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+
+        if SUPPRESS_PARSE_ERROR || args[0].is_nullable() {
+            return Ok(wrap_nullable(&VariantType::arc()));
+        }
+        Ok(VariantType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+
+        // The parsed value is written to the column directly in the compact form,
+        // instead of being formatted to an intermediate string.
+        let mut buf = Vec::new();
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut validity = MutableBitmap::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if viewer.null_at(row) {
+                builder.append_default();
+                validity.push(false);
+                continue;
+            }
+
+            match serde_json::from_slice::<Value>(viewer.value_at(row)) {
+                Ok(value) => {
+                    buf.clear();
+                    serde_json::to_writer(&mut buf, &value)?;
+                    builder.append_value(&buf);
+                    validity.push(true);
+                }
+                Err(_) if SUPPRESS_PARSE_ERROR => {
+                    builder.append_default();
+                    validity.push(false);
+                }
+                Err(e) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Function {} got invalid JSON string at row {}: {}",
+                        self.display_name, row, e
+                    )));
+                }
+            }
+        }
+
+        let column = builder.to_column();
+        match SUPPRESS_PARSE_ERROR || columns[0].data_type().is_nullable() {
+            true => Ok(Arc::new(NullableColumn::new(column, validity.into()))),
+            false => Ok(column),
+        }
+    }
+}
+
+impl<const SUPPRESS_PARSE_ERROR: bool> fmt::Display
+    for ParseJsonFunctionImpl<SUPPRESS_PARSE_ERROR>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::JsonArrayLengthFunction;
+use crate::scalars::JsonExtractPathFunction;
+use crate::scalars::JsonExtractPathTextFunction;
+use crate::scalars::JsonObjectFunction;
+use crate::scalars::JsonTypeofFunction;
+use crate::scalars::ParseJsonFunction;
+use crate::scalars::ToJsonFunction;
+use crate::scalars::TryParseJsonFunction;
+
+#[derive(Clone)]
+pub struct SemiStructuredFunction;
+
+impl SemiStructuredFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("json_object", JsonObjectFunction::desc());
+        factory.register("json_extract_path", JsonExtractPathFunction::desc());
+        factory.register(
+            "json_extract_path_text",
+            JsonExtractPathTextFunction::desc(),
+        );
+        factory.register("json_array_length", JsonArrayLengthFunction::desc());
+        factory.register("json_typeof", JsonTypeofFunction::desc());
+        factory.register("parse_json", ParseJsonFunction::desc());
+        factory.register("try_parse_json", TryParseJsonFunction::desc());
+        factory.register("to_json", ToJsonFunction::desc());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+/// TO_JSON(variant) serializes the Variant to a compact JSON string.
+#[derive(Clone)]
+pub struct ToJsonFunction {
+    display_name: String,
+}
+
+impl ToJsonFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToJsonFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .disable_passthrough_null()
+                .num_arguments(1),
+        )
+    }
+}
+
+impl Function for ToJsonFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        if args[0].is_null() {
+            return Ok(NullType::arc());
+        }
+
+        if !remove_nullable(args[0]).data_type_id().is_variant() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function {} expects the argument to be Variant, but got {:?}",
+                self.display_name, args[0]
+            )));
+        }
+
+        if args[0].is_nullable() {
+            return Ok(wrap_nullable(&StringType::arc()));
+        }
+        Ok(StringType::arc())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+
+        let mut buf = Vec::new();
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut validity = MutableBitmap::with_capacity(input_rows);
+        for row in 0..input_rows {
+            if viewer.null_at(row) {
+                builder.append_default();
+                validity.push(false);
+                continue;
+            }
+
+            let value = serde_json::from_slice::<Value>(viewer.value_at(row)).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Function {} got invalid JSON value: {}",
+                    self.display_name, e
+                ))
+            })?;
+            buf.clear();
+            serde_json::to_writer(&mut buf, &value)?;
+            builder.append_value(&buf);
+            validity.push(true);
+        }
+
+        let column = builder.to_column();
+        match columns[0].data_type().is_nullable() {
+            true => Ok(Arc::new(NullableColumn::new(column, validity.into()))),
+            false => Ok(column),
+        }
+    }
+}
+
+impl fmt::Display for ToJsonFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -12,36 +12,50 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::marker::PhantomData;
+
 use common_datavalues::Column;
 use common_datavalues::StringColumn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::decode::DecodeFunctionImpl;
+use super::decode::StringDecoder;
 use super::string2string::String2StringFunction;
 use super::string2string::StringOperator;
 
+/// The base64 alphabet, both of them are defined in RFC 4648 and padded with '='.
+pub trait Base64Alphabet: Send + Sync + Clone + Default + 'static {
+    fn config() -> base64::Config;
+}
+
 #[derive(Clone, Default)]
-pub struct Encode {}
+pub struct Standard;
 
-impl StringOperator for Encode {
-    #[inline]
-    fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
-        Ok(base64::encode_config_slice(s, base64::STANDARD, buffer))
+#[derive(Clone, Default)]
+pub struct UrlSafe;
+
+impl Base64Alphabet for Standard {
+    fn config() -> base64::Config {
+        base64::STANDARD
     }
+}
 
-    fn estimate_bytes(&self, array: &StringColumn) -> usize {
-        array.values().len() * 4 / 3 + array.len() * 4
+impl Base64Alphabet for UrlSafe {
+    fn config() -> base64::Config {
+        base64::URL_SAFE
     }
 }
 
 #[derive(Clone, Default)]
-pub struct Decode {}
+pub struct Encode<T> {
+    _marker: PhantomData<T>,
+}
 
-impl StringOperator for Decode {
+impl<T: Base64Alphabet> StringOperator for Encode<T> {
     #[inline]
     fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
-        base64::decode_config_slice(s, base64::STANDARD, buffer)
-            .map_err(|e| ErrorCode::StrParseError(e.to_string()))
+        Ok(base64::encode_config_slice(s, T::config(), buffer))
     }
 
     fn estimate_bytes(&self, array: &StringColumn) -> usize {
@@ -49,5 +63,23 @@ impl StringOperator for Decode {
     }
 }
 
-pub type Base64EncodeFunction = String2StringFunction<Encode>;
-pub type Base64DecodeFunction = String2StringFunction<Decode>;
+#[derive(Clone, Default)]
+pub struct Decode<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: Base64Alphabet> StringDecoder for Decode<T> {
+    #[inline]
+    fn decode(&self, input: &[u8], buffer: &mut Vec<u8>) -> Result<()> {
+        base64::decode_config_buf(input, T::config(), buffer)
+            .map_err(|e| ErrorCode::BadArguments(e.to_string()))
+    }
+}
+
+pub type Base64EncodeFunction = String2StringFunction<Encode<Standard>>;
+pub type Base64DecodeFunction = DecodeFunctionImpl<Decode<Standard>, false>;
+pub type TryBase64DecodeFunction = DecodeFunctionImpl<Decode<Standard>, true>;
+
+pub type Base64UrlEncodeFunction = String2StringFunction<Encode<UrlSafe>>;
+pub type Base64UrlDecodeFunction = DecodeFunctionImpl<Decode<UrlSafe>, false>;
+pub type TryBase64UrlDecodeFunction = DecodeFunctionImpl<Decode<UrlSafe>, true>;
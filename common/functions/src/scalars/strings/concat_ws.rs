@@ -43,6 +43,21 @@ impl ConcatWsFunction {
         )
     }
 
+    // Joins the non-null values of the row, the separator is only put between two values.
+    #[inline]
+    fn concat_row(buffer: &mut Vec<u8>, sep: &[u8], viewers: &[StringViewer<'_>], row: usize) {
+        let mut first = true;
+        for viewer in viewers {
+            if !viewer.null_at(row) {
+                if !first {
+                    buffer.extend_from_slice(sep);
+                }
+                first = false;
+                buffer.extend_from_slice(viewer.value_at(row));
+            }
+        }
+    }
+
     fn concat_column_with_constant_seperator(
         sep: &[u8],
         columns: &[ColumnWithField],
@@ -57,15 +72,7 @@ impl ConcatWsFunction {
         let mut buffer: Vec<u8> = Vec::with_capacity(32);
         (0..rows).for_each(|row| {
             buffer.clear();
-            for (idx, viewer) in viewers.iter().enumerate() {
-                if !viewer.null_at(row) {
-                    if idx > 0 {
-                        buffer.extend_from_slice(sep);
-                    }
-
-                    buffer.extend_from_slice(viewer.value_at(row));
-                }
-            }
+            Self::concat_row(&mut buffer, sep, &viewers, row);
             builder.append_value(buffer.as_slice());
         });
         Ok(builder.to_column())
@@ -87,15 +94,7 @@ impl ConcatWsFunction {
         (0..rows).for_each(|row| {
             buffer.clear();
             let sep = sep_c.get_data(row);
-            for (idx, viewer) in viewers.iter().enumerate() {
-                if !viewer.null_at(row) {
-                    if idx > 0 {
-                        buffer.extend_from_slice(sep);
-                    }
-
-                    buffer.extend_from_slice(viewer.value_at(row));
-                }
-            }
+            Self::concat_row(&mut buffer, sep, &viewers, row);
             builder.append_value(buffer.as_slice());
         });
         Ok(builder.to_column())
@@ -118,17 +117,10 @@ impl ConcatWsFunction {
             buffer.clear();
             if sep_viewer.null_at(row) {
                 builder.append_null();
+                return;
             }
             let sep = sep_viewer.value_at(row);
-            for (idx, viewer) in viewers.iter().enumerate() {
-                if !viewer.null_at(row) {
-                    if idx > 0 {
-                        buffer.extend_from_slice(sep);
-                    }
-
-                    buffer.extend_from_slice(viewer.value_at(row));
-                }
-            }
+            Self::concat_row(&mut buffer, sep, &viewers, row);
             builder.append(buffer.as_slice(), true);
         });
         Ok(builder.build(rows))
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+
+pub trait StringDecoder: Send + Sync + Clone + Default + 'static {
+    // Decode the input into the buffer, the buffer is cleared by the caller.
+    fn decode(&self, input: &[u8], buffer: &mut Vec<u8>) -> Result<()>;
+}
+
+/// A common function template that decodes string column into string column
+/// Eg: unhex, from_base64.
+/// The invalid input fails the function, or yields NULL if SUPPRESS_PARSE_ERROR, Eg: try_unhex.
+#[derive(Clone)]
+pub struct DecodeFunctionImpl<T, const SUPPRESS_PARSE_ERROR: bool> {
+    display_name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T: StringDecoder, const SUPPRESS_PARSE_ERROR: bool>
+    DecodeFunctionImpl<T, SUPPRESS_PARSE_ERROR>
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            _marker: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl<T: StringDecoder, const SUPPRESS_PARSE_ERROR: bool> Function
+    for DecodeFunctionImpl<T, SUPPRESS_PARSE_ERROR>
+{
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        match args[0].data_type_id() {
+            TypeID::Null => Ok(NullType::arc()),
+            // For invalid input, we suppress parse error and return null.
+            TypeID::String if SUPPRESS_PARSE_ERROR => Ok(wrap_nullable(&StringType::arc())),
+            TypeID::String => Ok(StringType::arc()),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[0].data_type_id()
+            ))),
+        }
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        if columns[0].data_type().is_null() {
+            return NullType::arc().create_constant_column(&DataValue::Null, input_rows);
+        }
+
+        let decoder = T::default();
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+        let mut buffer = Vec::new();
+
+        if SUPPRESS_PARSE_ERROR {
+            let mut builder = NullableColumnBuilder::<Vu8>::with_capacity(input_rows);
+            for (i, input) in viewer.iter().enumerate() {
+                buffer.clear();
+                match decoder.decode(input, &mut buffer) {
+                    Ok(_) => builder.append(buffer.as_slice(), viewer.valid_at(i)),
+                    Err(_) => builder.append_null(),
+                }
+            }
+            return Ok(builder.build(input_rows));
+        }
+
+        let mut builder = ColumnBuilder::<Vu8>::with_capacity(input_rows);
+        for input in viewer.iter() {
+            buffer.clear();
+            decoder.decode(input, &mut buffer)?;
+            builder.append(buffer.as_slice());
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl<T, const SUPPRESS_PARSE_ERROR: bool> fmt::Display
+    for DecodeFunctionImpl<T, SUPPRESS_PARSE_ERROR>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 use std::fmt;
-use std::ops::AddAssign;
 
 use common_datavalues::prelude::*;
 use common_datavalues::with_match_primitive_type_id;
@@ -88,7 +87,7 @@ impl Function for FormatFunction {
 
 fn format_en_us<L, R>(number: L, precision: R, _ctx: &mut EvalContext) -> Vec<u8>
 where
-    L: AsPrimitive<f64> + AsPrimitive<i64>,
+    L: AsPrimitive<f64> + AsPrimitive<i128>,
     R: AsPrimitive<i64>,
 {
     let precision = precision.as_();
@@ -100,16 +99,27 @@ where
         precision
     };
 
-    let trunc: i64 = number.as_();
+    let trunc: i128 = number.as_();
     let number: f64 = number.as_();
-    let fract = (number - trunc as f64).abs();
-    let fract_str = format!("{0:.1$}", fract, precision as usize);
-    let fract_str = fract_str.strip_prefix('0');
-    let mut trunc_str = trunc.to_formatted_string(&Locale::en);
-    if let Some(s) = fract_str {
-        trunc_str.add_assign(s)
+
+    // Round half away from zero, the fraction may be rounded up to the integer part.
+    let scale = 10f64.powi(precision as i32);
+    let mut fract = ((number - trunc as f64).abs() * scale).round();
+    let mut trunc = trunc.unsigned_abs();
+    if fract >= scale {
+        trunc += 1;
+        fract -= scale;
+    }
+
+    let mut formatted = String::new();
+    if number < 0.0 {
+        formatted.push('-');
+    }
+    formatted.push_str(&trunc.to_formatted_string(&Locale::en));
+    if precision > 0 {
+        formatted.push_str(&format!(".{:01$.0}", fract, precision as usize));
     }
-    Vec::from(trunc_str)
+    Vec::from(formatted)
 }
 
 impl fmt::Display for FormatFunction {
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::Ordering;
 use std::fmt;
 use std::sync::Arc;
 
@@ -64,27 +63,22 @@ impl Function for HexFunction {
             TypeID::UInt8 | TypeID::UInt16 | TypeID::UInt32 | TypeID::UInt64 => {
                 let col = cast_column_field(&columns[0], &UInt64Type::arc())?;
                 let col = col.as_any().downcast_ref::<UInt64Column>().unwrap();
-                let iter = col.iter().map(|val| format!("{:x}", val).into_bytes());
+                let iter = col.iter().map(|val| format!("{:X}", val).into_bytes());
                 Ok(Arc::new(StringColumn::from_owned_iterator(iter)))
             }
             TypeID::Int8 | TypeID::Int16 | TypeID::Int32 | TypeID::Int64 => {
                 let col = cast_column_field(&columns[0], &Int64Type::arc())?;
                 let col = col.as_any().downcast_ref::<Int64Column>().unwrap();
-                let iter = col.iter().map(|val| match val.cmp(&0) {
-                    Ordering::Less => format!("-{:x}", val.unsigned_abs()).into_bytes(),
-                    _ => format!("{:x}", val).into_bytes(),
-                });
+                // Same as MySQL, negative values are treated as 64-bit two's complement.
+                let iter = col
+                    .iter()
+                    .map(|val| format!("{:X}", *val as u64).into_bytes());
                 Ok(Arc::new(StringColumn::from_owned_iterator(iter)))
             }
             TypeID::String => {
                 let col = cast_column_field(&columns[0], &StringType::arc())?;
                 let col = col.as_any().downcast_ref::<StringColumn>().unwrap();
-                let iter = col.iter().map(|val| {
-                    let mut buffer = vec![0u8; val.len() * 2];
-                    let buff = &mut buffer[0..val.len() * 2];
-                    let _ = hex::encode_to_slice(val, buff);
-                    buffer
-                });
+                let iter = col.iter().map(|val| hex::encode_upper(val).into_bytes());
                 Ok(Arc::new(StringColumn::from_owned_iterator(iter)))
             }
             _ => {
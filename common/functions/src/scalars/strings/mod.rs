@@ -20,6 +20,7 @@ mod char_;
 mod char_length;
 mod concat;
 mod concat_ws;
+mod decode;
 mod elt;
 mod export_set;
 mod field;
@@ -46,6 +47,7 @@ mod strcmp;
 mod string;
 mod string2number;
 mod string2string;
+mod string_predicate;
 mod substring;
 mod substring_index;
 mod trim;
@@ -55,12 +57,18 @@ mod upper;
 pub use ascii::AsciiFunction;
 pub use base_64::Base64DecodeFunction;
 pub use base_64::Base64EncodeFunction;
+pub use base_64::Base64UrlDecodeFunction;
+pub use base_64::Base64UrlEncodeFunction;
+pub use base_64::TryBase64DecodeFunction;
+pub use base_64::TryBase64UrlDecodeFunction;
 pub use bin::BinFunction;
 pub use bit_length::BitLengthFunction;
 pub use char_::CharFunction;
 pub use char_length::CharLengthFunction;
 pub use concat::ConcatFunction;
 pub use concat_ws::ConcatWsFunction;
+pub use decode::DecodeFunctionImpl;
+pub use decode::StringDecoder;
 pub use elt::EltFunction;
 pub use export_set::ExportSetFunction;
 pub use field::FieldFunction;
@@ -92,11 +100,23 @@ pub use string2number::NumberOperator;
 pub use string2number::String2NumberFunction;
 pub use string2string::String2StringFunction;
 pub use string2string::StringOperator;
+pub use string_predicate::Contains;
+pub use string_predicate::ContainsFunction;
+pub use string_predicate::EndsWith;
+pub use string_predicate::EndsWithFunction;
+pub use string_predicate::StartsWith;
+pub use string_predicate::StartsWithFunction;
+pub use string_predicate::StringPredicate;
+pub use string_predicate::StringPredicateFunction;
 pub use substring::SubstringFunction;
 pub use substring_index::SubstringIndexFunction;
 pub use trim::LTrimFunction;
 pub use trim::RTrimFunction;
+pub use trim::TrimBothFunction;
 pub use trim::TrimFunction;
+pub use trim::TrimLeadingFunction;
+pub use trim::TrimTrailingFunction;
+pub use unhex::TryUnhexFunction;
 pub use unhex::UnhexFunction;
 pub use upper::UpperFunction;
 
@@ -43,7 +43,10 @@ impl PadOperator for LeftPad {
     fn apply<'a>(&'a mut self, str: &'a [u8], l: usize, pad: &'a [u8]) -> &'a [u8] {
         self.buff.clear();
         if l != 0 {
-            if l > str.len() {
+            if l > str.len() && pad.is_empty() {
+                // Nothing to pad with, keep the original string.
+                self.buff.extend_from_slice(str);
+            } else if l > str.len() {
                 let l = l - str.len();
                 while self.buff.len() < l {
                     if self.buff.len() + pad.len() <= l {
@@ -71,7 +74,10 @@ impl PadOperator for RightPad {
     fn apply<'a>(&'a mut self, str: &'a [u8], l: usize, pad: &'a [u8]) -> &'a [u8] {
         self.buff.clear();
         if l != 0 {
-            if l > str.len() {
+            if l > str.len() && pad.is_empty() {
+                // Nothing to pad with, keep the original string.
+                self.buff.extend_from_slice(str);
+            } else if l > str.len() {
                 self.buff.extend_from_slice(str);
                 while self.buff.len() < l {
                     if self.buff.len() + pad.len() <= l {
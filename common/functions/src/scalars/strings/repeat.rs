@@ -23,7 +23,7 @@ use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
 
-const MAX_REPEAT_TIMES: u64 = 1000000;
+const MAX_REPEAT_TIMES: i64 = 1000000;
 
 #[derive(Clone)]
 pub struct RepeatFunction {
@@ -56,9 +56,9 @@ impl Function for RepeatFunction {
             )));
         }
 
-        if !args[1].data_type_id().is_unsigned_integer() && !args[1].data_type_id().is_null() {
+        if !args[1].data_type_id().is_integer() && !args[1].data_type_id().is_null() {
             return Err(ErrorCode::IllegalDataType(format!(
-                "Expected parameter 2 is unsigned integer or null, but got {}",
+                "Expected parameter 2 is integer or null, but got {}",
                 args[1].data_type_id()
             )));
         }
@@ -70,8 +70,8 @@ impl Function for RepeatFunction {
         let col1 = cast_column_field(&columns[0], &StringType::arc())?;
         let col1_viewer = Vu8::try_create_viewer(&col1)?;
 
-        let col2 = cast_column_field(&columns[1], &UInt64Type::arc())?;
-        let col2_viewer = u64::try_create_viewer(&col2)?;
+        let col2 = cast_column_field(&columns[1], &Int64Type::arc())?;
+        let col2_viewer = i64::try_create_viewer(&col2)?;
 
         let mut builder = ColumnBuilder::<Vu8>::with_capacity(input_rows);
 
@@ -91,8 +91,12 @@ impl fmt::Display for RepeatFunction {
     }
 }
 
+// Returns the empty string if the times is zero or negative.
 #[inline]
-fn repeat(string: impl AsRef<[u8]>, times: u64) -> Result<Vec<u8>> {
+fn repeat(string: impl AsRef<[u8]>, times: i64) -> Result<Vec<u8>> {
+    if times <= 0 {
+        return Ok(vec![]);
+    }
     if times > MAX_REPEAT_TIMES {
         return Err(ErrorCode::BadArguments(format!(
             "Too many times to repeat: ({}), maximum is: {}",
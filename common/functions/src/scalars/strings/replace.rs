@@ -28,19 +28,20 @@ fn apply<'a>(str: &'a [u8], from: &'a [u8], to: &'a [u8], buf: &mut Vec<u8>) {
         buf.extend_from_slice(str);
         return;
     }
-    let mut skip = 0;
-    for (p, w) in str.windows(from.len()).enumerate() {
-        if w == from {
+    // Scan from left to right, the replaced bytes are not matched again.
+    let mut start = 0;
+    let mut p = 0;
+    while p + from.len() <= str.len() {
+        if &str[p..p + from.len()] == from {
+            buf.extend_from_slice(&str[start..p]);
             buf.extend_from_slice(to);
-            skip = from.len();
-        } else if p + w.len() == str.len() {
-            buf.extend_from_slice(w);
-        } else if skip > 1 {
-            skip -= 1;
+            p += from.len();
+            start = p;
         } else {
-            buf.extend_from_slice(&w[0..1]);
+            p += 1;
         }
     }
+    buf.extend_from_slice(&str[start..]);
 }
 
 #[derive(Clone)]
@@ -24,8 +24,21 @@ impl StringOperator for Reverse {
     #[inline]
     fn try_apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Result<usize> {
         let buffer = &mut buffer[0..s.len()];
-        buffer.copy_from_slice(s);
-        buffer.reverse();
+        match std::str::from_utf8(s) {
+            // Reverse by the characters, the bytes of each character keep the order.
+            Ok(str) => {
+                for (i, c) in str.char_indices() {
+                    let end = s.len() - i;
+                    let start = end - c.len_utf8();
+                    buffer[start..end].copy_from_slice(&s[i..i + c.len_utf8()]);
+                }
+            }
+            // Reverse the bytes if not valid UTF-8.
+            Err(_) => {
+                buffer.copy_from_slice(s);
+                buffer.reverse();
+            }
+        }
         Ok(s.len())
     }
 }
@@ -15,13 +15,17 @@
 use crate::scalars::AsciiFunction;
 use crate::scalars::Base64DecodeFunction;
 use crate::scalars::Base64EncodeFunction;
+use crate::scalars::Base64UrlDecodeFunction;
+use crate::scalars::Base64UrlEncodeFunction;
 use crate::scalars::BinFunction;
 use crate::scalars::BitLengthFunction;
 use crate::scalars::CharFunction;
 use crate::scalars::CharLengthFunction;
 use crate::scalars::ConcatFunction;
 use crate::scalars::ConcatWsFunction;
+use crate::scalars::ContainsFunction;
 use crate::scalars::EltFunction;
+use crate::scalars::EndsWithFunction;
 use crate::scalars::ExportSetFunction;
 use crate::scalars::FieldFunction;
 use crate::scalars::FindInSetFunction;
@@ -50,10 +54,17 @@ use crate::scalars::RightFunction;
 use crate::scalars::RightPadFunction;
 use crate::scalars::SoundexFunction;
 use crate::scalars::SpaceFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::StrcmpFunction;
 use crate::scalars::SubstringFunction;
 use crate::scalars::SubstringIndexFunction;
+use crate::scalars::TrimBothFunction;
 use crate::scalars::TrimFunction;
+use crate::scalars::TrimLeadingFunction;
+use crate::scalars::TrimTrailingFunction;
+use crate::scalars::TryBase64DecodeFunction;
+use crate::scalars::TryBase64UrlDecodeFunction;
+use crate::scalars::TryUnhexFunction;
 use crate::scalars::UnhexFunction;
 use crate::scalars::UpperFunction;
 
@@ -64,9 +75,19 @@ impl StringFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("to_base64", Base64EncodeFunction::desc());
         factory.register("from_base64", Base64DecodeFunction::desc());
+        factory.register("try_from_base64", TryBase64DecodeFunction::desc());
+        factory.register("base64_encode", Base64EncodeFunction::desc());
+        factory.register("base64_decode", Base64DecodeFunction::desc());
+        factory.register("try_base64_decode", TryBase64DecodeFunction::desc());
+        factory.register("base64url_encode", Base64UrlEncodeFunction::desc());
+        factory.register("base64url_decode", Base64UrlDecodeFunction::desc());
+        factory.register("try_base64url_decode", TryBase64UrlDecodeFunction::desc());
         factory.register("rtrim", RTrimFunction::desc());
         factory.register("trim", TrimFunction::desc());
         factory.register("ltrim", LTrimFunction::desc());
+        factory.register("trim_leading", TrimLeadingFunction::desc());
+        factory.register("trim_trailing", TrimTrailingFunction::desc());
+        factory.register("trim_both", TrimBothFunction::desc());
         factory.register("quote", QuoteFunction::desc());
         factory.register("lower", LowerFunction::desc());
         factory.register("lcase", LowerFunction::desc());
@@ -85,7 +106,11 @@ impl StringFunction {
         factory.register("bin", BinFunction::desc());
         factory.register("oct", OctFunction::desc());
         factory.register("hex", HexFunction::desc());
+        factory.register("to_hex", HexFunction::desc());
         factory.register("unhex", UnhexFunction::desc());
+        factory.register("from_hex", UnhexFunction::desc());
+        factory.register("try_unhex", TryUnhexFunction::desc());
+        factory.register("try_from_hex", TryUnhexFunction::desc());
         factory.register("repeat", RepeatFunction::desc());
         factory.register("substring", SubstringFunction::desc());
         factory.register("mid", SubstringFunction::desc());
@@ -110,5 +135,8 @@ impl StringFunction {
         factory.register("locate", LocateFunction::desc());
         factory.register("position", PositionFunction::desc());
         factory.register("instr", InstrFunction::desc());
+        factory.register("startswith", StartsWithFunction::desc());
+        factory.register("endswith", EndsWithFunction::desc());
+        factory.register("contains", ContainsFunction::desc());
     }
 }
@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::assert_string;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::ScalarBinaryExpression;
+
+pub trait StringPredicate: Send + Sync + Clone + 'static {
+    fn apply(str: &[u8], pattern: &[u8]) -> bool;
+}
+
+#[derive(Clone)]
+pub struct StartsWith;
+
+impl StringPredicate for StartsWith {
+    #[inline]
+    fn apply(str: &[u8], prefix: &[u8]) -> bool {
+        str.starts_with(prefix)
+    }
+}
+
+#[derive(Clone)]
+pub struct EndsWith;
+
+impl StringPredicate for EndsWith {
+    #[inline]
+    fn apply(str: &[u8], suffix: &[u8]) -> bool {
+        str.ends_with(suffix)
+    }
+}
+
+#[derive(Clone)]
+pub struct Contains;
+
+impl StringPredicate for Contains {
+    #[inline]
+    fn apply(str: &[u8], substr: &[u8]) -> bool {
+        substr.is_empty() || str.windows(substr.len()).any(|window| window == substr)
+    }
+}
+
+pub type StartsWithFunction = StringPredicateFunction<StartsWith>;
+pub type EndsWithFunction = StringPredicateFunction<EndsWith>;
+pub type ContainsFunction = StringPredicateFunction<Contains>;
+
+#[derive(Clone)]
+pub struct StringPredicateFunction<T> {
+    display_name: String,
+    t: PhantomData<T>,
+}
+
+impl<T: StringPredicate> StringPredicateFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl<T: StringPredicate> Function for StringPredicateFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in args {
+            assert_string(*arg)?;
+        }
+        Ok(bool::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let binary = ScalarBinaryExpression::<Vu8, Vu8, bool, _>::new(apply::<T>);
+        let col = binary.eval(
+            columns[0].column(),
+            columns[1].column(),
+            &mut EvalContext::default(),
+        )?;
+        Ok(col.arc())
+    }
+}
+
+impl<T> fmt::Display for StringPredicateFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[inline]
+fn apply<T: StringPredicate>(str: &[u8], pattern: &[u8], _ctx: &mut EvalContext) -> bool {
+    T::apply(str, pattern)
+}
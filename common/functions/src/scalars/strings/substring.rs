@@ -92,20 +92,20 @@ impl Function for SubstringFunction {
         let mut builder = ColumnBuilder::<Vu8>::with_capacity(input_rows);
 
         if columns.len() > 2 {
-            let p2_column = cast_column_field(&columns[2], &UInt64Type::arc())?;
-            let p2_viewer = u64::try_create_viewer(&p2_column)?;
+            let p2_column = cast_column_field(&columns[2], &Int64Type::arc())?;
+            let p2_viewer = i64::try_create_viewer(&p2_column)?;
 
             let iter = izip!(s_viewer, p_viewer, p2_viewer);
 
             for (str, pos, len) in iter {
-                let val = substr_from_for(str, &pos, &len);
+                let val = substr(str, pos, Some(len));
                 builder.append(val);
             }
         } else {
             let iter = s_viewer.iter().zip(p_viewer.iter());
 
             for (str, pos) in iter {
-                let val = substr_from(str, &pos);
+                let val = substr(str, pos, None);
                 builder.append(val);
             }
         }
@@ -119,35 +119,41 @@ impl fmt::Display for SubstringFunction {
     }
 }
 
+// The substring from the 1-based `pos` in characters, a negative `pos` counts from the end.
+// Out of range `pos` or non-positive `len` gives an empty string.
 #[inline]
-fn substr_from<'a>(str: &'a [u8], pos: &i64) -> &'a [u8] {
-    substr(str, pos, &(str.len() as u64))
-}
-
-#[inline]
-fn substr_from_for<'a>(str: &'a [u8], pos: &i64, len: &u64) -> &'a [u8] {
-    substr(str, pos, len)
-}
+fn substr(str: &[u8], pos: i64, len: Option<i64>) -> &[u8] {
+    if str.is_ascii() {
+        let (start, end) = substr_range(str.len(), pos, len);
+        return &str[start..end];
+    }
 
-#[inline]
-fn substr<'a>(str: &'a [u8], pos: &i64, len: &u64) -> &'a [u8] {
-    if *pos > 0 && *pos <= str.len() as i64 {
-        let l = str.len() as usize;
-        let s = (*pos - 1) as usize;
-        let mut e = *len as usize + s;
-        if e > l {
-            e = l;
+    match std::str::from_utf8(str) {
+        Ok(s) => {
+            let (start, end) = substr_range(s.chars().count(), pos, len);
+            let byte_offset = |n: usize| s.char_indices().nth(n).map_or(s.len(), |(i, _)| i);
+            &str[byte_offset(start)..byte_offset(end)]
         }
-        return &str[s..e];
-    }
-    if *pos < 0 && -(*pos) <= str.len() as i64 {
-        let l = str.len() as usize;
-        let s = l - -*pos as usize;
-        let mut e = *len as usize + s;
-        if e > l {
-            e = l;
+        // Not a valid UTF-8 string, fallback to bytes.
+        Err(_) => {
+            let (start, end) = substr_range(str.len(), pos, len);
+            &str[start..end]
         }
-        return &str[s..e];
     }
-    &str[0..0]
+}
+
+#[inline]
+fn substr_range(n: usize, pos: i64, len: Option<i64>) -> (usize, usize) {
+    let n = n as i64;
+    let start = match pos {
+        p if p > 0 && p <= n => p - 1,
+        p if p < 0 && p >= -n => n + p,
+        _ => return (0, 0),
+    };
+    let end = match len {
+        None => n,
+        Some(l) if l <= 0 => start,
+        Some(l) => start.saturating_add(l).min(n),
+    };
+    (start as usize, end as usize)
 }
@@ -12,10 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
 use common_exception::Result;
 
 use super::string2string::String2StringFunction;
 use super::string2string::StringOperator;
+use crate::scalars::assert_string;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::ScalarBinaryExpression;
 
 #[derive(Clone, Default)]
 pub struct LTrim;
@@ -74,3 +84,92 @@ impl StringOperator for Trim {
 pub type LTrimFunction = String2StringFunction<LTrim>;
 pub type RTrimFunction = String2StringFunction<RTrim>;
 pub type TrimFunction = String2StringFunction<Trim>;
+
+pub type TrimLeadingFunction = TrimCharsFunction<true, false>;
+pub type TrimTrailingFunction = TrimCharsFunction<false, true>;
+pub type TrimBothFunction = TrimCharsFunction<true, true>;
+
+// Remove the characters in the set from the start and/or the end of the string.
+#[inline]
+fn trim_chars<'a, const LEADING: bool, const TRAILING: bool>(
+    str: &'a [u8],
+    chars: &'a [u8],
+    _ctx: &mut EvalContext,
+) -> &'a [u8] {
+    match (std::str::from_utf8(str), std::str::from_utf8(chars)) {
+        (Ok(mut str), Ok(chars)) => {
+            let is_trim = |c: char| chars.contains(c);
+            if LEADING {
+                str = str.trim_start_matches(is_trim);
+            }
+            if TRAILING {
+                str = str.trim_end_matches(is_trim);
+            }
+            str.as_bytes()
+        }
+        // Trim the bytes if not valid UTF-8.
+        _ => {
+            let is_trim = |c: &u8| chars.contains(c);
+            let start = match LEADING {
+                true => str.iter().position(|c| !is_trim(c)).unwrap_or(str.len()),
+                false => 0,
+            };
+            let end = match TRAILING {
+                true => str.iter().rposition(|c| !is_trim(c)).map_or(0, |p| p + 1),
+                false => str.len(),
+            };
+            &str[start..end.max(start)]
+        }
+    }
+}
+
+/// TRIM({LEADING | TRAILING | BOTH} <chars> FROM <str>) is mapped to trim_leading(str, chars),
+/// trim_trailing(str, chars) or trim_both(str, chars).
+#[derive(Clone)]
+pub struct TrimCharsFunction<const LEADING: bool, const TRAILING: bool> {
+    display_name: String,
+}
+
+impl<const LEADING: bool, const TRAILING: bool> TrimCharsFunction<LEADING, TRAILING> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl<const LEADING: bool, const TRAILING: bool> Function for TrimCharsFunction<LEADING, TRAILING> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        assert_string(args[0])?;
+        assert_string(args[1])?;
+        Ok(Vu8::to_data_type())
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, _input_rows: usize) -> Result<ColumnRef> {
+        let binary =
+            ScalarBinaryExpression::<Vu8, Vu8, Vu8, _>::new_ref(trim_chars::<LEADING, TRAILING>);
+        let col = binary.eval_ref(
+            columns[0].column(),
+            columns[1].column(),
+            &mut EvalContext::default(),
+        )?;
+        Ok(Arc::new(col))
+    }
+}
+
+impl<const LEADING: bool, const TRAILING: bool> fmt::Display
+    for TrimCharsFunction<LEADING, TRAILING>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.display_name)
+    }
+}
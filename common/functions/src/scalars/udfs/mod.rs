@@ -16,6 +16,8 @@ mod current_user;
 mod database;
 mod exists;
 mod in_basic;
+mod python_udf;
+mod python_udf_server;
 mod sleep;
 mod to_type_name;
 mod udf;
@@ -25,6 +27,9 @@ mod version;
 pub use current_user::CurrentUserFunction;
 pub use database::DatabaseFunction;
 pub use in_basic::InFunction;
+pub use python_udf::PythonUdfFunction;
+pub use python_udf_server::PythonUdfConfig;
+pub use python_udf_server::PythonUdfServer;
 pub use sleep::SleepFunction;
 pub use to_type_name::ToTypeNameFunction;
 pub use udf::UdfFunction;
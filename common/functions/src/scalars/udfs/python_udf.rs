@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+use crate::scalars::FunctionDescription;
+use crate::scalars::PythonUdfServer;
+
+/// `python_udf(file, function, return_type, arg1, arg2, ...)` calls the handler `function` of the
+/// python `file` for each row, in the python process of `PythonUdfServer`.
+///
+/// The `return_type` argument is a NULL literal of the return type, which is nullable because the
+/// handler may return None. The python UDFs created by `CREATE FUNCTION ... LANGUAGE python` are
+/// rewritten to this function, which is internal and can't be called from SQL.
+#[derive(Clone)]
+pub struct PythonUdfFunction {
+    display_name: String,
+}
+
+impl PythonUdfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(PythonUdfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .disable_passthrough_null()
+                .internal_function()
+                .variadic_arguments(3, usize::MAX),
+        )
+    }
+
+    fn constant_string(&self, column: &ColumnWithField, name: &str) -> Result<String> {
+        match column.column().is_const() {
+            true => Ok(String::from_utf8(column.column().get(0).as_string()?)?),
+            false => Err(ErrorCode::BadArguments(format!(
+                "The {} argument of function {} must be constant string",
+                name, self.display_name
+            ))),
+        }
+    }
+}
+
+impl Function for PythonUdfFunction {
+    fn name(&self) -> &str {
+        "PythonUdfFunction"
+    }
+
+    fn return_type(&self, args: &[&DataTypePtr]) -> Result<DataTypePtr> {
+        for arg in &args[0..2] {
+            if !arg.data_type_id().is_string() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string for the file and function of {}, but got {}",
+                    self.display_name,
+                    arg.name()
+                )));
+            }
+        }
+        Ok(wrap_nullable(args[2]))
+    }
+
+    fn eval(&self, columns: &ColumnsWithField, input_rows: usize) -> Result<ColumnRef> {
+        let file = self.constant_string(&columns[0], "file")?;
+        let function = self.constant_string(&columns[1], "function")?;
+        let return_type = wrap_nullable(columns[2].data_type());
+        if input_rows == 0 {
+            return Ok(return_type.create_mutable(0).to_column());
+        }
+
+        let arguments = columns[3..]
+            .iter()
+            .map(|column| {
+                let array = column.column().convert_full_column().as_arrow_array();
+                (column.field().to_arrow(), array)
+            })
+            .collect::<Vec<_>>();
+
+        let result = PythonUdfServer::instance().call(&file, &function, arguments, input_rows)?;
+        if result.len() != input_rows {
+            return Err(ErrorCode::PythonUdfError(format!(
+                "Python UDF {} returns {} rows, but expected {}",
+                function,
+                result.len(),
+                input_rows
+            )));
+        }
+
+        // The result type is inferred by python, cast it to the return type.
+        let column = result.into_nullable_column();
+        let column =
+            ColumnWithField::new(column.clone(), DataField::new("result", column.data_type()));
+        cast_column_field(&column, &return_type)
+    }
+
+    fn passthrough_constant(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for PythonUdfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,104 @@
+# Copyright 2022 Datafuse Labs.
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+# The server of python UDFs, started by databend-query.
+#
+# Each connection is one call:
+#     request: an Arrow IPC stream of the arguments, the schema metadata has the
+#              `file` and the `function` of the handler, and the number of `rows`.
+#     response: status byte 0 and an Arrow IPC stream of the `result` column, or
+#               status byte 1 and the error message as u32 big endian length + utf8.
+
+import argparse
+import importlib.util
+import os
+import socketserver
+import struct
+import threading
+import traceback
+
+import pyarrow as pa
+
+_modules = {}
+_modules_lock = threading.Lock()
+
+
+def load_function(file, function):
+    mtime = os.path.getmtime(file)
+    with _modules_lock:
+        cached = _modules.get(file)
+        if cached is None or cached[0] != mtime:
+            spec = importlib.util.spec_from_file_location(
+                "udf_%d" % len(_modules), file)
+            module = importlib.util.module_from_spec(spec)
+            spec.loader.exec_module(module)
+            cached = (mtime, module)
+            _modules[file] = cached
+
+    handler = getattr(cached[1], function, None)
+    if not callable(handler):
+        raise ValueError("%s is not a function of %s" % (function, file))
+    return handler
+
+
+def call(table):
+    metadata = table.schema.metadata or {}
+    file = metadata[b"file"].decode()
+    function = metadata[b"function"].decode()
+    rows = int(metadata[b"rows"].decode())
+    handler = load_function(file, function)
+
+    # The handler is called for each row, the NULL arguments are None.
+    columns = [column.to_pylist() for column in table.columns]
+    arguments = zip(*columns) if columns else [()] * rows
+    result = [handler(*row) for row in arguments]
+    return pa.record_batch([pa.array(result)], names=["result"])
+
+
+class UdfHandler(socketserver.StreamRequestHandler):
+    def handle(self):
+        try:
+            batch = call(pa.ipc.open_stream(self.rfile).read_all())
+        except Exception:
+            message = traceback.format_exc().encode()
+            self.wfile.write(b"\x01")
+            self.wfile.write(struct.pack(">I", len(message)))
+            self.wfile.write(message)
+            return
+
+        self.wfile.write(b"\x00")
+        with pa.ipc.new_stream(self.wfile, batch.schema) as writer:
+            writer.write_batch(batch)
+
+
+class UdfServer(socketserver.ThreadingMixIn, socketserver.UnixStreamServer):
+    daemon_threads = True
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument("--socket", required=True)
+    args = parser.parse_args()
+
+    # The socket is created under a temporary name, the server is ready once it's renamed.
+    pending = args.socket + ".pending"
+    if os.path.exists(pending):
+        os.remove(pending)
+    with UdfServer(pending, UdfHandler) as server:
+        os.rename(pending, args.socket)
+        server.serve_forever()
+
+
+if __name__ == "__main__":
+    main()
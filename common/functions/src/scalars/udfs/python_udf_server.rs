@@ -0,0 +1,337 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_base::tokio::runtime::Handle;
+use common_base::tokio::runtime::RuntimeFlavor;
+use common_base::tokio::task::block_in_place;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use once_cell::sync::Lazy;
+
+const SERVER_SCRIPT: &str = include_str!("python_udf_server.py");
+
+#[derive(Clone, Debug)]
+pub struct PythonUdfConfig {
+    /// The python interpreter to run the UDF server.
+    pub executable: String,
+    /// The directory of the handler files, python UDFs are disabled if it's empty.
+    pub dir: String,
+    pub timeout: Duration,
+}
+
+impl Default for PythonUdfConfig {
+    fn default() -> Self {
+        PythonUdfConfig {
+            executable: "python3".to_string(),
+            dir: "".to_string(),
+            timeout: Duration::from_millis(10000),
+        }
+    }
+}
+
+/// The python process running the handlers of python UDFs, the arguments and the results are
+/// exchanged as Arrow IPC streams over a unix socket.
+///
+/// The process is started on the first call, and restarted if it has exited, e.g. crashed, or
+/// if a call of it timed out, since the handler may still be running.
+pub struct PythonUdfServer {
+    config: RwLock<PythonUdfConfig>,
+    process: Mutex<Option<PythonUdfProcess>>,
+}
+
+struct PythonUdfProcess {
+    child: Child,
+    dir: PathBuf,
+    socket: PathBuf,
+}
+
+impl Drop for PythonUdfProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+static PYTHON_UDF_SERVER: Lazy<Arc<PythonUdfServer>> = Lazy::new(|| {
+    Arc::new(PythonUdfServer {
+        config: RwLock::new(PythonUdfConfig::default()),
+        process: Mutex::new(None),
+    })
+});
+
+impl PythonUdfServer {
+    pub fn instance() -> &'static PythonUdfServer {
+        PYTHON_UDF_SERVER.as_ref()
+    }
+
+    /// Apply the config, the running process is restarted with it on the next call.
+    pub fn configure(&self, config: PythonUdfConfig) {
+        *self.config.write().unwrap() = config;
+        self.process.lock().unwrap().take();
+    }
+
+    /// Call the handler `function` of `file` with the arguments, returns the `result` array.
+    ///
+    /// The call blocks on the socket until the handler returns or times out. On the workers of
+    /// a multi-thread tokio runtime, it's run by `block_in_place`, the other tasks of the worker
+    /// are moved to the other workers meanwhile.
+    pub fn call(
+        &self,
+        file: &str,
+        function: &str,
+        arguments: Vec<(ArrowField, ArrayRef)>,
+        rows: usize,
+    ) -> Result<ArrayRef> {
+        let call = || self.call_blocking(file, function, arguments, rows);
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                block_in_place(call)
+            }
+            _ => call(),
+        }
+    }
+
+    fn call_blocking(
+        &self,
+        file: &str,
+        function: &str,
+        arguments: Vec<(ArrowField, ArrayRef)>,
+        rows: usize,
+    ) -> Result<ArrayRef> {
+        let config = self.config.read().unwrap().clone();
+        let file = Self::resolve_file(&config, file)?;
+
+        let mut metadata = BTreeMap::new();
+        metadata.insert("file".to_string(), file.to_string_lossy().to_string());
+        metadata.insert("function".to_string(), function.to_string());
+        metadata.insert("rows".to_string(), rows.to_string());
+        let (fields, arrays): (Vec<_>, Vec<_>) = arguments.into_iter().unzip();
+        let schema = ArrowSchema::from(fields).with_metadata(metadata);
+        let chunk = Chunk::try_new(arrays)?;
+
+        let (mut stream, pid) = self.connect(&config)?;
+        let start = Instant::now();
+        let res = Self::exchange(&mut stream, &schema, &chunk);
+        res.map_err(|cause| match cause.code() {
+            code if code == ErrorCode::PythonUdfErrorCode() => cause,
+            // The reads and writes of the socket fail after the timeout.
+            _ if start.elapsed() >= config.timeout => {
+                self.kill(pid);
+                ErrorCode::Timeout(format!(
+                    "Python UDF {} of {:?} timed out after {} ms",
+                    function,
+                    file,
+                    config.timeout.as_millis()
+                ))
+            }
+            _ => ErrorCode::PythonUdfError(format!(
+                "Python UDF {} of {:?} failed, the python process may have exited: {}",
+                function,
+                file,
+                cause.message()
+            )),
+        })
+    }
+
+    // The handler files are relative to the configured dir, and must not be outside of it.
+    fn resolve_file(config: &PythonUdfConfig, file: &str) -> Result<PathBuf> {
+        if config.dir.is_empty() {
+            return Err(ErrorCode::PythonUdfError(
+                "Python UDF is disabled, set python_udf_dir in the config to enable it",
+            ));
+        }
+
+        let path = Path::new(file);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(ErrorCode::PythonUdfError(format!(
+                "The handler file of python UDF must be a relative path in python_udf_dir, but got {:?}",
+                file
+            )));
+        }
+
+        let path = Path::new(&config.dir).join(path);
+        if !path.is_file() {
+            return Err(ErrorCode::PythonUdfError(format!(
+                "The handler file {:?} of python UDF does not exist",
+                path
+            )));
+        }
+        Ok(path)
+    }
+
+    // Connect to the running process, the process is (re)started if it's not running.
+    // Returns the stream with the id of the process.
+    fn connect(&self, config: &PythonUdfConfig) -> Result<(UnixStream, u32)> {
+        let mut process = self.process.lock().unwrap();
+        let running = match process.as_mut() {
+            None => false,
+            Some(process) => match process.child.try_wait()? {
+                None => true,
+                Some(status) => {
+                    tracing::warn!("Python UDF server exited with {}, restarting it", status);
+                    false
+                }
+            },
+        };
+
+        if running {
+            let running = process.as_ref().unwrap();
+            if let Ok(stream) = Self::open(&running.socket, config) {
+                return Ok((stream, running.child.id()));
+            }
+        }
+
+        // Drop the exited process before starting a new one, its dir is removed.
+        process.take();
+        let started = Self::start(config)?;
+        let stream = Self::open(&started.socket, config)?;
+        let pid = started.child.id();
+        *process = Some(started);
+        Ok((stream, pid))
+    }
+
+    // Kill the process timed out, it's restarted by the next call. The process may have been
+    // restarted by the other calls meanwhile, which is kept.
+    fn kill(&self, pid: u32) {
+        let mut process = self.process.lock().unwrap();
+        if matches!(process.as_ref(), Some(running) if running.child.id() == pid) {
+            tracing::warn!("Python UDF server {} timed out, killing it", pid);
+            process.take();
+        }
+    }
+
+    fn open(socket: &Path, config: &PythonUdfConfig) -> Result<UnixStream> {
+        let stream = UnixStream::connect(socket)?;
+        stream.set_read_timeout(Some(config.timeout))?;
+        stream.set_write_timeout(Some(config.timeout))?;
+        Ok(stream)
+    }
+
+    fn start(config: &PythonUdfConfig) -> Result<PythonUdfProcess> {
+        let dir = std::env::temp_dir().join(format!(
+            "databend-python-udf-{}-{}",
+            std::process::id(),
+            uuid::Uuid::new_v4()
+        ));
+        std::fs::create_dir_all(&dir)?;
+        let script = dir.join("python_udf_server.py");
+        std::fs::write(&script, SERVER_SCRIPT)?;
+        let socket = dir.join("udf.sock");
+
+        let child = Command::new(&config.executable)
+            .arg(&script)
+            .arg("--socket")
+            .arg(&socket)
+            .spawn()
+            .map_err(|cause| {
+                ErrorCode::PythonUdfError(format!(
+                    "Cannot start python UDF server with {}: {}",
+                    config.executable, cause
+                ))
+            })?;
+        let mut process = PythonUdfProcess { child, dir, socket };
+
+        // The server renames its socket to the socket path once it's listening.
+        let start = Instant::now();
+        while !process.socket.exists() {
+            if let Some(status) = process.child.try_wait()? {
+                return Err(ErrorCode::PythonUdfError(format!(
+                    "Python UDF server exited with {} on startup, pyarrow is required",
+                    status
+                )));
+            }
+            if start.elapsed() > config.timeout {
+                return Err(ErrorCode::Timeout(format!(
+                    "Python UDF server is not started in {} ms",
+                    config.timeout.as_millis()
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        Ok(process)
+    }
+
+    fn exchange(
+        stream: &mut UnixStream,
+        schema: &ArrowSchema,
+        chunk: &Chunk<ArrayRef>,
+    ) -> Result<ArrayRef> {
+        let mut writer = StreamWriter::new(&mut *stream, WriteOptions { compression: None });
+        writer.start(schema, None)?;
+        writer.write(chunk, None)?;
+        writer.finish()?;
+        stream.flush()?;
+
+        let mut status = [0u8; 1];
+        stream.read_exact(&mut status)?;
+        if status[0] != 0 {
+            let mut length = [0u8; 4];
+            stream.read_exact(&mut length)?;
+            let mut message = vec![0u8; u32::from_be_bytes(length) as usize];
+            stream.read_exact(&mut message)?;
+            return Err(ErrorCode::PythonUdfError(
+                String::from_utf8_lossy(&message).to_string(),
+            ));
+        }
+
+        let metadata = read_stream_metadata(&mut *stream)?;
+        let reader = StreamReader::new(&mut *stream, metadata);
+        let mut result = None;
+        for state in reader {
+            match state? {
+                StreamState::Some(chunk) if result.is_none() && chunk.arrays().len() == 1 => {
+                    result = Some(chunk.arrays()[0].clone());
+                }
+                StreamState::Some(_) => {
+                    return Err(ErrorCode::PythonUdfError(
+                        "The result of python UDF must be one batch of one column",
+                    ));
+                }
+                StreamState::Waiting => continue,
+            }
+        }
+
+        result.ok_or_else(|| ErrorCode::PythonUdfError("Python UDF returns no result"))
+    }
+}
@@ -17,6 +17,7 @@ use crate::scalars::udfs::in_basic::InFunction;
 use crate::scalars::CurrentUserFunction;
 use crate::scalars::DatabaseFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::PythonUdfFunction;
 use crate::scalars::SleepFunction;
 use crate::scalars::ToTypeNameFunction;
 use crate::scalars::UdfExampleFunction;
@@ -36,5 +37,6 @@ impl UdfFunction {
         factory.register("version", VersionFunction::desc());
         factory.register("current_user", CurrentUserFunction::desc());
         factory.register("sleep", SleepFunction::desc());
+        factory.register("python_udf", PythonUdfFunction::desc());
     }
 }
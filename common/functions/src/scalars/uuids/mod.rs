@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod uuid;
+mod uuid_converter;
 mod uuid_creator;
 mod uuid_verifier;
 
+pub use uuid_converter::UUIDStringToBinaryFunction;
 pub use uuid_creator::UUIDZeroFunction;
 pub use uuid_creator::UUIDv4Function;
 pub use uuid_verifier::UUIDIsEmptyFunction;
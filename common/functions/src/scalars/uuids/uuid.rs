@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::uuid_converter::UUIDStringToBinaryFunction;
 use super::uuid_creator::UUIDZeroFunction;
 use super::uuid_creator::UUIDv4Function;
 use super::uuid_verifier::UUIDIsEmptyFunction;
@@ -23,8 +24,11 @@ pub struct UUIDFunction;
 impl UUIDFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("generateUUIDv4", UUIDv4Function::desc());
+        factory.register("uuid", UUIDv4Function::desc());
+        factory.register("gen_random_uuid", UUIDv4Function::desc());
         factory.register("zeroUUID", UUIDZeroFunction::desc());
         factory.register("isemptyUUID", UUIDIsEmptyFunction::desc());
         factory.register("isnotemptyUUID", UUIDIsNotEmptyFunction::desc());
+        factory.register("uuid_string_to_binary", UUIDStringToBinaryFunction::desc());
     }
 }
@@ -0,0 +1,46 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::scalars::DecodeFunctionImpl;
+use crate::scalars::StringDecoder;
+
+/// Converts the uuid string into its 16-byte binary representation.
+#[derive(Clone, Default)]
+pub struct UUIDDecoder;
+
+impl StringDecoder for UUIDDecoder {
+    #[inline]
+    fn decode(&self, input: &[u8], buffer: &mut Vec<u8>) -> Result<()> {
+        let uuid = str::from_utf8(input)
+            .map_err(|err| err.to_string())
+            .and_then(|s| Uuid::parse_str(s).map_err(|err| err.to_string()))
+            .map_err(|err| {
+                ErrorCode::UnexpectedError(format!(
+                    "{} is not a valid uuid because: {}",
+                    String::from_utf8_lossy(input),
+                    err
+                ))
+            })?;
+        buffer.extend_from_slice(uuid.as_bytes());
+        Ok(())
+    }
+}
+
+pub type UUIDStringToBinaryFunction = DecodeFunctionImpl<UUIDDecoder, false>;
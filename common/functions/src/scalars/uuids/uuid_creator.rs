@@ -59,6 +59,9 @@ impl<T> fmt::Display for UUIDCreatorFunction<T> {
 }
 
 pub trait UUIDCreator {
+    /// Whether all the rows share the same uuid, otherwise a fresh uuid is created per row.
+    const CONSTANT: bool;
+
     fn create() -> Uuid;
 }
 
@@ -66,6 +69,8 @@ pub trait UUIDCreator {
 pub struct UUIDv4;
 
 impl UUIDCreator for UUIDv4 {
+    const CONSTANT: bool = false;
+
     fn create() -> Uuid {
         Uuid::new_v4()
     }
@@ -75,6 +80,8 @@ impl UUIDCreator for UUIDv4 {
 pub struct UUIDZero;
 
 impl UUIDCreator for UUIDZero {
+    const CONSTANT: bool = true;
+
     fn create() -> Uuid {
         Uuid::nil()
     }
@@ -99,9 +106,13 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
         _columns: &common_datavalues::ColumnsWithField,
         input_rows: usize,
     ) -> Result<common_datavalues::ColumnRef> {
-        let uuid = T::create();
-        let col = StringColumn::new_from_slice(vec![uuid.to_string()]);
-
-        Ok(ConstColumn::new(col.arc(), input_rows).arc())
+        if T::CONSTANT {
+            let uuid = T::create();
+            let col = StringColumn::new_from_slice(vec![uuid.to_string()]);
+            return Ok(ConstColumn::new(col.arc(), input_rows).arc());
+        }
+
+        let col = StringColumn::new_from_iter((0..input_rows).map(|_| T::create().to_string()));
+        Ok(col.arc())
     }
 }
@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// The window functions ranking the rows of a partition by the ORDER BY of the window,
+/// the rows of the same order values are peers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankingFunction {
+    /// The number of the row in the partition, peers get distinct numbers.
+    RowNumber,
+    /// The row number of the first peer, gaps are left after the peers.
+    Rank,
+    /// The number of the distinct order values up to the row, no gaps are left.
+    DenseRank,
+}
+
+impl RankingFunction {
+    pub fn try_create(name: &str) -> Result<RankingFunction> {
+        match name.to_lowercase().as_str() {
+            "row_number" => Ok(RankingFunction::RowNumber),
+            "rank" => Ok(RankingFunction::Rank),
+            "dense_rank" => Ok(RankingFunction::DenseRank),
+            _ => Err(ErrorCode::UnknownFunction(format!(
+                "Unsupported window function: {}",
+                name
+            ))),
+        }
+    }
+
+    pub fn check(name: &str) -> bool {
+        Self::try_create(name).is_ok()
+    }
+
+    pub fn return_type(&self) -> DataTypePtr {
+        u64::to_data_type()
+    }
+
+    /// Ranks the rows sorted by the partition and the order columns.
+    ///
+    /// `partition_starts[i]` tells whether the row `i` is the first one of its partition,
+    /// `peers[i]` whether it has the same order values as the row `i - 1` of the partition.
+    pub fn eval(&self, partition_starts: &[bool], peers: &[bool]) -> Result<ColumnRef> {
+        if partition_starts.len() != peers.len() {
+            return Err(ErrorCode::LogicalError(format!(
+                "The ranked rows mismatch: {} partition starts, {} peers",
+                partition_starts.len(),
+                peers.len()
+            )));
+        }
+
+        let mut values = Vec::with_capacity(peers.len());
+        let mut row_number = 0u64;
+        let mut rank = 0u64;
+        for (partition_start, peer) in partition_starts.iter().zip(peers.iter()) {
+            if *partition_start {
+                row_number = 0;
+                rank = 0;
+            }
+            row_number += 1;
+            if *partition_start || !*peer {
+                rank = match self {
+                    RankingFunction::DenseRank => rank + 1,
+                    _ => row_number,
+                };
+            }
+            values.push(match self {
+                RankingFunction::RowNumber => row_number,
+                _ => rank,
+            });
+        }
+        Ok(Series::from_data(values))
+    }
+}
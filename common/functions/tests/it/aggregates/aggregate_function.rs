@@ -13,12 +13,15 @@
 // limitations under the License.
 
 use std::borrow::BorrowMut;
+use std::sync::Arc;
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues::prelude::*;
 use common_datavalues::with_match_primitive_type_id;
 use common_exception::Result;
 use common_functions::aggregates::*;
+use common_functions::scalars::CRC32Function;
 use float_cmp::approx_eq;
 use pretty_assertions::assert_eq;
 
@@ -103,6 +106,51 @@ fn test_aggregate_function() -> Result<()> {
                 Vec::from([1i64]),
             )),
         },
+        Test {
+            name: "bit_and-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "bit_and",
+            func_name: "bit_and",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<i64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<i64>::from_data(
+                i64::to_data_type(),
+                Vec::from([0i64]),
+            )),
+        },
+        Test {
+            name: "bit_or-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "bit_or",
+            func_name: "bit_or",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<i64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<i64>::from_data(
+                i64::to_data_type(),
+                Vec::from([7i64]),
+            )),
+        },
+        Test {
+            name: "bit_xor-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "bit_xor",
+            func_name: "bit_xor",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<i64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<i64>::from_data(
+                i64::to_data_type(),
+                Vec::from([4i64]),
+            )),
+        },
         Test {
             name: "avg-passed",
             eval_nums: 1,
@@ -1068,3 +1116,855 @@ fn test_aggregate_function_on_boolean() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_aggregate_function_array_agg() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        params: Vec<DataValue>,
+        args: Vec<DataField>,
+        arrays: Vec<ColumnRef>,
+        expect_type: DataTypePtr,
+        expect: DataValue,
+    }
+
+    let arrays: Vec<ColumnRef> = vec![
+        Series::from_data(vec![4i64, 3, 2, 1]),
+        Series::from_data(vec![2u8, 4, 1, 3]),
+        Series::from_data(vec![Some(4i64), None, Some(2), None]),
+    ];
+
+    let args = vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", u8::to_data_type()),
+        DataField::new_nullable("c", i64::to_data_type()),
+    ];
+
+    let tests = vec![
+        Test {
+            name: "array-agg-passed",
+            params: vec![],
+            args: vec![args[0].clone()],
+            arrays: vec![arrays[0].clone()],
+            expect_type: Arc::new(ArrayType::create(i64::to_data_type())),
+            expect: DataValue::Array(vec![
+                DataValue::Int64(4),
+                DataValue::Int64(3),
+                DataValue::Int64(2),
+                DataValue::Int64(1),
+            ]),
+        },
+        Test {
+            name: "array-agg-order-by-passed",
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            expect_type: Arc::new(ArrayType::create(i64::to_data_type())),
+            expect: DataValue::Array(vec![
+                DataValue::Int64(2),
+                DataValue::Int64(4),
+                DataValue::Int64(1),
+                DataValue::Int64(3),
+            ]),
+        },
+        Test {
+            name: "array-agg-order-by-desc-passed",
+            params: vec![DataValue::String("desc".as_bytes().to_vec())],
+            args: vec![args[0].clone(), args[1].clone()],
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            expect_type: Arc::new(ArrayType::create(i64::to_data_type())),
+            expect: DataValue::Array(vec![
+                DataValue::Int64(3),
+                DataValue::Int64(1),
+                DataValue::Int64(4),
+                DataValue::Int64(2),
+            ]),
+        },
+        Test {
+            name: "array-agg-respect-nulls-passed",
+            params: vec![],
+            args: vec![args[2].clone()],
+            arrays: vec![arrays[2].clone()],
+            expect_type: Arc::new(ArrayType::create(wrap_nullable(&i64::to_data_type()))),
+            expect: DataValue::Array(vec![
+                DataValue::Int64(4),
+                DataValue::Null,
+                DataValue::Int64(2),
+                DataValue::Null,
+            ]),
+        },
+        Test {
+            name: "array-agg-ignore-nulls-passed",
+            params: vec![DataValue::String("IGNORE NULLS".as_bytes().to_vec())],
+            args: vec![args[2].clone()],
+            arrays: vec![arrays[2].clone()],
+            expect_type: Arc::new(ArrayType::create(i64::to_data_type())),
+            expect: DataValue::Array(vec![DataValue::Int64(4), DataValue::Int64(2)]),
+        },
+    ];
+
+    for t in tests {
+        let arena = Bump::new();
+        let rows = t.arrays[0].len();
+
+        let factory = AggregateFunctionFactory::instance();
+        let func = factory.get("array_agg", t.params.clone(), t.args.clone())?;
+        assert_eq!(func.return_type()?, t.expect_type, "{}", t.name);
+
+        // Accumulate half of the rows into each state and merge them.
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        let columns = t.arrays.iter().map(|c| c.slice(0, 2)).collect::<Vec<_>>();
+        func.accumulate(addr1.into(), &columns, None, 2)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        let columns = t
+            .arrays
+            .iter()
+            .map(|c| c.slice(2, rows - 2))
+            .collect::<Vec<_>>();
+        func.accumulate(addr2.into(), &columns, None, rows - 2)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr1.into(), array.as_mut())?;
+        let column = array.to_column();
+        assert_eq!(column.len(), 1, "{}", t.name);
+        assert_eq!(column.get(0), t.expect, "{}", t.name);
+    }
+
+    let params = vec![DataValue::String("first".as_bytes().to_vec())];
+    let result =
+        AggregateFunctionFactory::instance().get("array_agg", params, vec![args[0].clone()]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "array_agg unsupported parameter: 'first', expect 'ASC', 'DESC', 'IGNORE NULLS' or 'RESPECT NULLS'"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_listagg() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        params: Vec<DataValue>,
+        args: Vec<DataField>,
+        arrays: Vec<ColumnRef>,
+        expect: DataValue,
+    }
+
+    let arrays: Vec<ColumnRef> = vec![
+        Series::from_data(vec![Some("a"), Some("bb"), None, Some("c")]),
+        Series::from_data(vec![2u8, 4, 1, 3]),
+        Series::from_data(vec![None::<&str>, None, None, None]),
+    ];
+
+    let args = vec![
+        DataField::new_nullable("a", Vu8::to_data_type()),
+        DataField::new("b", u8::to_data_type()),
+    ];
+
+    let string = |v: &str| DataValue::String(v.as_bytes().to_vec());
+    let params = |max_length: u64, filler: DataValue, with_count: bool, orders: &[&str]| {
+        let mut params = vec![
+            string(","),
+            DataValue::UInt64(max_length),
+            filler,
+            DataValue::Boolean(with_count),
+        ];
+        params.extend(orders.iter().map(|v| string(v)));
+        params
+    };
+
+    let tests = vec![
+        Test {
+            name: "listagg-passed",
+            params: params(1024, DataValue::Null, false, &[]),
+            args: vec![args[0].clone()],
+            arrays: vec![arrays[0].clone()],
+            expect: string("a,bb,c"),
+        },
+        Test {
+            name: "listagg-order-by-passed",
+            params: params(1024, DataValue::Null, false, &["ASC"]),
+            args: vec![args[0].clone(), args[1].clone()],
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            expect: string("a,c,bb"),
+        },
+        Test {
+            name: "listagg-order-by-desc-passed",
+            params: params(1024, DataValue::Null, false, &["DESC"]),
+            args: vec![args[0].clone(), args[1].clone()],
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            expect: string("bb,c,a"),
+        },
+        Test {
+            name: "listagg-on-overflow-truncate-with-count-passed",
+            params: params(9, string("..."), true, &[]),
+            args: vec![args[0].clone()],
+            arrays: vec![arrays[0].clone()],
+            expect: string("a,...(2)"),
+        },
+        Test {
+            name: "listagg-on-overflow-truncate-without-count-passed",
+            params: params(5, string("..."), false, &[]),
+            args: vec![args[0].clone()],
+            arrays: vec![arrays[0].clone()],
+            expect: string("a,..."),
+        },
+        Test {
+            name: "listagg-all-nulls-passed",
+            params: params(1024, DataValue::Null, false, &[]),
+            args: vec![args[0].clone()],
+            arrays: vec![arrays[2].clone()],
+            expect: DataValue::Null,
+        },
+    ];
+
+    let run = |params: Vec<DataValue>, args: Vec<DataField>, arrays: &[ColumnRef]| {
+        let arena = Bump::new();
+        let rows = arrays[0].len();
+
+        let func = AggregateFunctionFactory::instance().get("listagg", params, args)?;
+        assert_eq!(func.return_type()?, wrap_nullable(&Vu8::to_data_type()));
+
+        // Accumulate half of the rows into each state and merge them.
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        let columns = arrays.iter().map(|c| c.slice(0, 2)).collect::<Vec<_>>();
+        func.accumulate(addr1.into(), &columns, None, 2)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        let columns = arrays
+            .iter()
+            .map(|c| c.slice(2, rows - 2))
+            .collect::<Vec<_>>();
+        func.accumulate(addr2.into(), &columns, None, rows - 2)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr1.into(), array.as_mut())?;
+        Result::Ok(array.to_column().get(0))
+    };
+
+    for t in tests {
+        assert_eq!(run(t.params, t.args, &t.arrays)?, t.expect, "{}", t.name);
+    }
+
+    let params = params(5, DataValue::Null, false, &[]);
+    let result = run(params, vec![args[0].clone()], &arrays[0..1]);
+    assert_eq!(
+        result.err().unwrap().message(),
+        "The result of listagg is 6 bytes, exceeds the limit 5 bytes"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_object_agg() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+
+    let args = vec![
+        DataField::new_nullable("k", Vu8::to_data_type()),
+        DataField::new_nullable("v", i64::to_data_type()),
+    ];
+    let arrays: Vec<ColumnRef> = vec![
+        Series::from_data(vec![Some("a"), None, Some("b"), Some("a")]),
+        Series::from_data(vec![Some(1i64), Some(2), None, Some(4)]),
+    ];
+
+    let func = factory.get("object_agg", vec![], args.clone())?;
+    assert_eq!(func.return_type()?, VariantType::arc());
+
+    // Accumulate half of the rows into each state and merge them.
+    let addr1 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr1.into());
+    let columns = arrays.iter().map(|c| c.slice(0, 2)).collect::<Vec<_>>();
+    func.accumulate(addr1.into(), &columns, None, 2)?;
+
+    let addr2 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr2.into());
+    let columns = arrays.iter().map(|c| c.slice(2, 2)).collect::<Vec<_>>();
+    func.accumulate(addr2.into(), &columns, None, 2)?;
+
+    func.merge(addr1.into(), addr2.into())?;
+
+    // NULL keys are skipped, NULL values are kept and the last value of a key wins.
+    let mut array = func.return_type()?.create_mutable(1);
+    func.merge_result(addr1.into(), array.as_mut())?;
+    let column = array.to_column();
+    assert_eq!(
+        column.get(0),
+        DataValue::String(br#"{"a":4,"b":null}"#.to_vec())
+    );
+
+    // An empty group is an empty object.
+    let addr3 = arena.alloc_layout(func.state_layout());
+    func.init_state(addr3.into());
+    let mut array = func.return_type()?.create_mutable(1);
+    func.merge_result(addr3.into(), array.as_mut())?;
+    assert_eq!(array.to_column().get(0), DataValue::String(b"{}".to_vec()));
+
+    let result = factory.get("object_agg", vec![], vec![
+        DataField::new("k", u8::to_data_type()),
+        args[1].clone(),
+    ]);
+    let err = result.err().unwrap();
+    assert_eq!(err.code(), 1006);
+    assert_eq!(
+        err.message(),
+        "object_agg key must be String, but got UInt8"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_corr() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        arrays: Vec<ColumnRef>,
+        expect: Option<f64>,
+    }
+
+    let args = vec![
+        DataField::new("x", i64::to_data_type()),
+        DataField::new("y", i64::to_data_type()),
+    ];
+
+    let tests = vec![
+        Test {
+            name: "corr-negative",
+            arrays: vec![
+                Series::from_data(vec![4i64, 3, 2, 1]),
+                Series::from_data(vec![1i64, 2, 3, 4]),
+            ],
+            expect: Some(-1.0),
+        },
+        Test {
+            name: "corr-positive",
+            arrays: vec![
+                Series::from_data(vec![1i64, 2, 3, 4]),
+                Series::from_data(vec![3i64, 5, 7, 9]),
+            ],
+            expect: Some(1.0),
+        },
+        Test {
+            name: "corr-partial",
+            arrays: vec![
+                Series::from_data(vec![1i64, 2, 3, 4]),
+                Series::from_data(vec![1i64, 3, 2, 4]),
+            ],
+            expect: Some(0.8),
+        },
+        Test {
+            name: "corr-zero-variance",
+            arrays: vec![
+                Series::from_data(vec![1i64, 1, 1, 1]),
+                Series::from_data(vec![1i64, 3, 2, 4]),
+            ],
+            expect: None,
+        },
+    ];
+
+    let factory = AggregateFunctionFactory::instance();
+    let func = factory.get("corr", vec![], args)?;
+    assert_eq!(func.return_type()?, wrap_nullable(&f64::to_data_type()));
+
+    for t in tests {
+        let arena = Bump::new();
+        let rows = t.arrays[0].len();
+
+        // Accumulate half of the rows into each state and merge them.
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        let columns = t.arrays.iter().map(|c| c.slice(0, 2)).collect::<Vec<_>>();
+        func.accumulate(addr1.into(), &columns, None, 2)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        let columns = t
+            .arrays
+            .iter()
+            .map(|c| c.slice(2, rows - 2))
+            .collect::<Vec<_>>();
+        func.accumulate(addr2.into(), &columns, None, rows - 2)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr1.into(), array.as_mut())?;
+        let column = array.to_column();
+        match (column.get(0), t.expect) {
+            (DataValue::Float64(v), Some(expect)) => {
+                assert!(approx_eq!(f64, expect, v, epsilon = 0.000001), "{}", t.name)
+            }
+            (v, expect) => assert_eq!(
+                v,
+                expect.map_or(DataValue::Null, DataValue::Float64),
+                "{}",
+                t.name
+            ),
+        }
+    }
+
+    // Empty group.
+    let arena = Bump::new();
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    let mut array = func.return_type()?.create_mutable(1);
+    func.merge_result(addr.into(), array.as_mut())?;
+    assert_eq!(array.to_column().get(0), DataValue::Null);
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_checksum_agg() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("x", i64::to_data_type())];
+
+    let func = factory.get("checksum_agg", vec![], args.clone())?;
+    assert_eq!(func.return_type()?, u64::to_data_type());
+
+    let checksum = |slices: Vec<ColumnRef>| -> Result<DataValue> {
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        for slice in slices {
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.accumulate(temp.into(), &[slice.clone()], None, slice.len())?;
+            func.merge(addr.into(), temp.into())?;
+        }
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr.into(), array.as_mut())?;
+        Ok(array.to_column().get(0))
+    };
+
+    let rows = Series::from_data(vec![1i64, 2, 3, 2, 5]);
+    let expect = checksum(vec![rows.clone()])?;
+
+    // The sum of the CRC32 of the rows.
+    let crc32 = CRC32Function::try_create("crc32")?;
+    let crc_column = crc32.eval(&[ColumnWithField::new(rows.clone(), args[0].clone())], 5)?;
+    let crc_column: &UInt32Column = Series::check_get(&crc_column)?;
+    let sum = crc_column.iter().map(|v| *v as u64).sum::<u64>();
+    assert_eq!(expect, DataValue::UInt64(sum));
+
+    // The order of the rows and blocks doesn't matter.
+    let reversed = Series::from_data(vec![5i64, 2, 3, 2, 1]);
+    assert_eq!(checksum(vec![reversed])?, expect);
+    assert_eq!(
+        checksum(vec![rows.slice(3, 2), rows.slice(0, 1), rows.slice(1, 2)])?,
+        expect
+    );
+
+    // The rows accumulated one by one.
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+    for row in (0..rows.len()).rev() {
+        func.accumulate_row(addr.into(), &[rows.clone()], row)?;
+    }
+    let mut array = func.return_type()?.create_mutable(1);
+    func.merge_result(addr.into(), array.as_mut())?;
+    assert_eq!(array.to_column().get(0), expect);
+
+    // The duplicated rows don't cancel each other, unlike XOR.
+    let distinct = Series::from_data(vec![1i64, 3, 5]);
+    assert_ne!(checksum(vec![distinct])?, expect);
+
+    // An empty group is 0.
+    assert_eq!(checksum(vec![])?, DataValue::UInt64(0));
+
+    let result = factory.get("checksum_agg", vec![], vec![DataField::new(
+        "x",
+        Arc::new(ArrayType::create(i64::to_data_type())),
+    )]);
+    let err = result.err().unwrap();
+    assert_eq!(err.code(), 1010);
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_approx_count_distinct() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new_nullable("x", u64::to_data_type())];
+
+    let func = factory.get("approx_count_distinct", vec![], args)?;
+    assert_eq!(func.return_type()?, u64::to_data_type());
+
+    let estimate = |slices: Vec<ColumnRef>| -> Result<u64> {
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        for slice in slices {
+            // The partial states are serialized and merged, as in the distributed execution.
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.accumulate(temp.into(), &[slice.clone()], None, slice.len())?;
+
+            let mut bytes = BytesMut::new();
+            func.serialize(temp.into(), &mut bytes)?;
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.deserialize(temp.into(), &mut bytes.as_ref())?;
+            func.merge(addr.into(), temp.into())?;
+        }
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr.into(), array.as_mut())?;
+        array.to_column().get_u64(0)
+    };
+
+    // 100000 distinct values, each of them twice, and NULLs.
+    let values = (0..200000u64)
+        .map(|v| if v % 10 == 9 { None } else { Some(v % 100000) })
+        .collect::<Vec<_>>();
+    let column = Series::from_data(values);
+
+    let expect = estimate(vec![column.clone()])?;
+    let distinct = 90000.0;
+    assert!((expect as f64 - distinct).abs() / distinct < 0.05);
+
+    // Merging the partial states is the same as a single pass.
+    let slices = vec![
+        column.slice(150000, 50000),
+        column.slice(0, 70000),
+        column.slice(70000, 80000),
+    ];
+    assert_eq!(estimate(slices)?, expect);
+
+    // The small cardinalities are exact in practice.
+    let column = Series::from_data(vec![Some(1u64), Some(2), None, Some(2), Some(3)]);
+    assert_eq!(estimate(vec![column])?, 3);
+
+    // An empty group is 0.
+    assert_eq!(estimate(vec![])?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_percentile() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new_nullable("x", i64::to_data_type())];
+
+    let percentile =
+        |name: &str, params: Vec<DataValue>, slices: Vec<ColumnRef>| -> Result<DataValue> {
+            let func = factory.get(name, params, args.clone())?;
+            assert_eq!(func.return_type()?, wrap_nullable(&f64::to_data_type()));
+
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            for slice in slices {
+                // The partial states are serialized and merged, as in the distributed execution.
+                let temp = arena.alloc_layout(func.state_layout());
+                func.init_state(temp.into());
+                func.accumulate(temp.into(), &[slice.clone()], None, slice.len())?;
+
+                let mut bytes = BytesMut::new();
+                func.serialize(temp.into(), &mut bytes)?;
+                let temp = arena.alloc_layout(func.state_layout());
+                func.init_state(temp.into());
+                func.deserialize(temp.into(), &mut bytes.as_ref())?;
+                func.merge(addr.into(), temp.into())?;
+            }
+            let mut array = func.return_type()?.create_mutable(1);
+            func.merge_result(addr.into(), array.as_mut())?;
+            Ok(array.to_column().get(0))
+        };
+
+    // The 50th percentile is the sorted middle, NULLs are skipped.
+    let column = Series::from_data(vec![Some(5i64), Some(1), None, Some(3), Some(4), Some(2)]);
+    let expect = DataValue::Float64(3.0);
+    assert_eq!(percentile("median", vec![], vec![column.clone()])?, expect);
+    let params = vec![DataValue::Float64(0.5)];
+    assert_eq!(percentile("percentile", params, vec![column])?, expect);
+
+    // The middle two are averaged for an even number of values.
+    let column = Series::from_data(vec![Some(4i64), Some(1), Some(3), Some(2)]);
+    let slices = vec![column.slice(0, 1), column.slice(1, 3)];
+    assert_eq!(
+        percentile("median", vec![], slices)?,
+        DataValue::Float64(2.5)
+    );
+
+    // The 99th percentile of 0..1000000, approximated by t-digest.
+    let column = Series::from_data((0..1000000i64).map(Some).collect::<Vec<_>>());
+    let slices = vec![
+        column.slice(500000, 500000),
+        column.slice(0, 300000),
+        column.slice(300000, 200000),
+    ];
+    let params = vec![DataValue::Float64(0.99)];
+    let value = percentile("percentile", params, slices)?.as_f64()?;
+    assert!((value - 989999.01).abs() < 1000000.0 * 0.001, "{}", value);
+
+    // The result is NULL for an empty group.
+    let params = vec![DataValue::Float64(0.99)];
+    assert_eq!(percentile("percentile", params, vec![])?, DataValue::Null);
+
+    let params = vec![DataValue::Float64(1.5)];
+    let err = percentile("percentile", params, vec![]).unwrap_err();
+    assert_eq!(
+        err.message(),
+        "The level of percentile must be in [0, 1], but got 1.5"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_variance() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("x", f64::to_data_type())];
+
+    let variance = |name: &str, slices: Vec<ColumnRef>| -> Result<DataValue> {
+        let func = factory.get(name, vec![], args.clone())?;
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        for slice in slices {
+            // The partial states are serialized and merged, as in the distributed execution.
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.accumulate(temp.into(), &[slice.clone()], None, slice.len())?;
+
+            let mut bytes = BytesMut::new();
+            func.serialize(temp.into(), &mut bytes)?;
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.deserialize(temp.into(), &mut bytes.as_ref())?;
+            func.merge(addr.into(), temp.into())?;
+        }
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr.into(), array.as_mut())?;
+        Ok(array.to_column().get(0))
+    };
+
+    // The mean is 5 and the sum of the squared differences is 32.
+    let column = Series::from_data(vec![2f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    let expects = vec![
+        ("var_pop", 4.0),
+        ("variance", 4.0),
+        ("stddev_pop", 2.0),
+        ("stddev", 2.0),
+        ("var_samp", 32.0 / 7.0),
+        ("stddev_samp", (32.0f64 / 7.0).sqrt()),
+    ];
+    for (name, expect) in expects {
+        let single = variance(name, vec![column.clone()])?.as_f64()?;
+        assert!((single - expect).abs() < 1e-12, "{}: {}", name, single);
+
+        // Merging the partial states equals to the single pass.
+        let slices = vec![column.slice(0, 3), column.slice(3, 0), column.slice(3, 5)];
+        let merged = variance(name, slices)?.as_f64()?;
+        assert!((merged - single).abs() < 1e-12, "{}: {}", name, merged);
+    }
+
+    // The differences are small relative to the values, which the sum of squares loses.
+    let column = Series::from_data(vec![1e9f64 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+    let value = variance("var_samp", vec![column.clone()])?.as_f64()?;
+    assert!((value - 30.0).abs() < 1e-6, "{}", value);
+    let slices = vec![column.slice(0, 1), column.slice(1, 3)];
+    let value = variance("var_samp", slices)?.as_f64()?;
+    assert!((value - 30.0).abs() < 1e-6, "{}", value);
+
+    // The sample variants are NULL for less than 2 values.
+    let column = Series::from_data(vec![1f64]);
+    assert_eq!(variance("var_samp", vec![column.clone()])?, DataValue::Null);
+    assert_eq!(
+        variance("stddev_samp", vec![column.clone()])?,
+        DataValue::Null
+    );
+    assert_eq!(variance("stddev_samp", vec![])?, DataValue::Null);
+    assert_eq!(variance("var_pop", vec![column])?, DataValue::Float64(0.0));
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_first_last_value() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![
+        DataField::new_nullable("c", i64::to_data_type()),
+        DataField::new("k", u8::to_data_type()),
+    ];
+    let columns: Vec<ColumnRef> = vec![
+        Series::from_data(vec![None, Some(4i64), None, Some(2), None]),
+        Series::from_data(vec![5u8, 2, 4, 3, 1]),
+    ];
+
+    let eval = |name: &str, params: &[&str], with_key: bool, rows: usize| -> Result<DataValue> {
+        let params = params
+            .iter()
+            .map(|p| DataValue::String(p.as_bytes().to_vec()))
+            .collect::<Vec<_>>();
+        let num_args = if with_key { 2 } else { 1 };
+        let func = factory.get(name, params, args[0..num_args].to_vec())?;
+        assert_eq!(func.return_type()?, wrap_nullable(&i64::to_data_type()));
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        // The partial states of the slices are serialized and merged in order.
+        for (offset, length) in [(0, rows.min(2)), (rows.min(2), rows - rows.min(2))] {
+            let slice = columns[0..num_args]
+                .iter()
+                .map(|c| c.slice(offset, length))
+                .collect::<Vec<_>>();
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.accumulate(temp.into(), &slice, None, length)?;
+
+            let mut bytes = BytesMut::new();
+            func.serialize(temp.into(), &mut bytes)?;
+            let temp = arena.alloc_layout(func.state_layout());
+            func.init_state(temp.into());
+            func.deserialize(temp.into(), &mut bytes.as_ref())?;
+            func.merge(addr.into(), temp.into())?;
+        }
+        let mut array = func.return_type()?.create_mutable(1);
+        func.merge_result(addr.into(), array.as_mut())?;
+        Ok(array.to_column().get(0))
+    };
+
+    let ignore_nulls = "IGNORE NULLS";
+    let tests = vec![
+        // In the input order.
+        ("first_value", vec![], false, DataValue::Null),
+        (
+            "first_value",
+            vec![ignore_nulls],
+            false,
+            DataValue::Int64(4),
+        ),
+        ("last_value", vec!["RESPECT NULLS"], false, DataValue::Null),
+        ("last_value", vec![ignore_nulls], false, DataValue::Int64(2)),
+        // In the order of the keys.
+        ("first_value", vec![], true, DataValue::Null),
+        ("first_value", vec![ignore_nulls], true, DataValue::Int64(4)),
+        ("last_value", vec![], true, DataValue::Null),
+        ("last_value", vec![ignore_nulls], true, DataValue::Int64(2)),
+        (
+            "first_value",
+            vec!["DESC", ignore_nulls],
+            true,
+            DataValue::Int64(2),
+        ),
+        (
+            "last_value",
+            vec!["DESC", ignore_nulls],
+            true,
+            DataValue::Int64(4),
+        ),
+    ];
+    for (name, params, with_key, expect) in tests {
+        let value = eval(name, &params, with_key, 5)?;
+        assert_eq!(value, expect, "{} {:?} {}", name, params, with_key);
+    }
+
+    // NULL is returned for an empty group.
+    assert_eq!(eval("first_value", &[], false, 0)?, DataValue::Null);
+    assert_eq!(
+        eval("last_value", &[ignore_nulls], true, 0)?,
+        DataValue::Null
+    );
+
+    let result = factory.get(
+        "first_value",
+        vec![DataValue::String(b"first".to_vec())],
+        vec![args[0].clone()],
+    );
+    assert_eq!(
+        result.err().unwrap().message(),
+        "first_value unsupported parameter: 'first', expect 'ASC', 'DESC', 'IGNORE NULLS' or 'RESPECT NULLS'"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_function_arg_min_max() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![
+        DataField::new_nullable("v", i64::to_data_type()),
+        DataField::new_nullable("o", u8::to_data_type()),
+    ];
+
+    let eval =
+        |name: &str, values: Vec<Option<i64>>, orders: Vec<Option<u8>>| -> Result<DataValue> {
+            let rows = values.len();
+            let columns: Vec<ColumnRef> =
+                vec![Series::from_data(values), Series::from_data(orders)];
+            let func = factory.get(name, vec![], args.clone())?;
+            assert_eq!(func.return_type()?, wrap_nullable(&i64::to_data_type()));
+
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            // The partial states of the slices are serialized and merged in order.
+            for (offset, length) in [(0, rows.min(2)), (rows.min(2), rows - rows.min(2))] {
+                let slice = columns
+                    .iter()
+                    .map(|c| c.slice(offset, length))
+                    .collect::<Vec<_>>();
+                let temp = arena.alloc_layout(func.state_layout());
+                func.init_state(temp.into());
+                func.accumulate(temp.into(), &slice, None, length)?;
+
+                let mut bytes = BytesMut::new();
+                func.serialize(temp.into(), &mut bytes)?;
+                let temp = arena.alloc_layout(func.state_layout());
+                func.init_state(temp.into());
+                func.deserialize(temp.into(), &mut bytes.as_ref())?;
+                func.merge(addr.into(), temp.into())?;
+            }
+            let mut array = func.return_type()?.create_mutable(1);
+            func.merge_result(addr.into(), array.as_mut())?;
+            let value = array.to_column().get(0);
+
+            // The rows accumulated one by one have the same result.
+            let addr = arena.alloc_layout(func.state_layout());
+            func.init_state(addr.into());
+            for row in 0..rows {
+                func.accumulate_row(addr.into(), &columns, row)?;
+            }
+            let mut array = func.return_type()?.create_mutable(1);
+            func.merge_result(addr.into(), array.as_mut())?;
+            assert_eq!(array.to_column().get(0), value, "{} by rows", name);
+            Ok(value)
+        };
+
+    let values = vec![Some(1i64), Some(2), Some(3), Some(4), None];
+    let orders = vec![Some(3u8), Some(7), None, Some(7), Some(1)];
+    let tests = vec![
+        // The first row of the equal maximums in different slices is kept.
+        (
+            "arg_max",
+            values.clone(),
+            orders.clone(),
+            DataValue::Int64(2),
+        ),
+        // The rows with NULL orders are ignored, the NULL value is returned.
+        ("arg_min", values.clone(), orders.clone(), DataValue::Null),
+        // The first row of the equal minimums in the same slice is kept.
+        (
+            "arg_min",
+            values.clone(),
+            vec![Some(2), Some(2), None, Some(5), Some(3)],
+            DataValue::Int64(1),
+        ),
+        ("argMax", values.clone(), orders, DataValue::Int64(2)),
+        // NULL is returned if all the orders are NULL.
+        ("arg_max", values.clone(), vec![None; 5], DataValue::Null),
+        ("arg_min", vec![], vec![], DataValue::Null),
+    ];
+    for (name, values, orders, expect) in tests {
+        let value = eval(name, values.clone(), orders.clone())?;
+        assert_eq!(value, expect, "{} {:?} {:?}", name, values, orders);
+    }
+    Ok(())
+}
@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ArrayContainsFunction;
+use common_functions::scalars::ArrayDistinctFunction;
+use common_functions::scalars::ArraySizeFunction;
+use common_functions::scalars::ArraySliceFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+fn array_column(inner: DataTypePtr, values: Vec<Vec<DataValue>>) -> Result<ColumnRef> {
+    let values = values.into_iter().map(DataValue::Array).collect::<Vec<_>>();
+    ArrayType::create(inner).create_column(&values)
+}
+
+// [[1, 2], [], [3, NULL]]
+fn int_array_column() -> Result<ColumnRef> {
+    array_column(wrap_nullable(&Int64Type::arc()), vec![
+        vec![DataValue::Int64(1), DataValue::Int64(2)],
+        vec![],
+        vec![DataValue::Int64(3), DataValue::Null],
+    ])
+}
+
+#[test]
+fn test_array_contains_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "array_contains-passed",
+            columns: vec![int_array_column()?, Series::from_data(vec![2i64, 1, 3])],
+            expect: Series::from_data(vec![true, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_contains-cast-value-passed",
+            columns: vec![int_array_column()?, Series::from_data(vec![1u8, 2, 4])],
+            expect: Series::from_data(vec![true, false, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_contains-null-value-passed",
+            columns: vec![
+                int_array_column()?,
+                Series::from_data(vec![Some(2i64), None, None]),
+            ],
+            expect: Series::from_data(vec![true, false, false]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_contains-null-array-passed",
+            columns: vec![
+                wrap_nullable(&Arc::new(ArrayType::create(StringType::arc()))).create_column(&[
+                    DataValue::Array(vec![DataValue::String(b"a".to_vec())]),
+                    DataValue::Null,
+                ])?,
+                Series::from_data(vec!["a", "a"]),
+            ],
+            expect: Series::from_data(vec![Some(true), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_contains-string-error",
+            columns: vec![Series::from_data(vec!["a"]), Series::from_data(vec!["a"])],
+            expect: Series::from_data(vec![false]),
+            error: "Function array_contains expects the first argument to be Array, but got String",
+        },
+    ];
+
+    test_scalar_functions(
+        ArrayContainsFunction::try_create("array_contains")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_array_distinct_function() -> Result<()> {
+    let nullable_int = wrap_nullable(&Int64Type::arc());
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "array_distinct-passed",
+            columns: vec![array_column(nullable_int.clone(), vec![
+                vec![
+                    DataValue::Int64(1),
+                    DataValue::Int64(2),
+                    DataValue::Int64(1),
+                    DataValue::Int64(3),
+                    DataValue::Int64(2),
+                ],
+                vec![],
+                vec![DataValue::Null, DataValue::Int64(1), DataValue::Null],
+            ])?],
+            expect: array_column(nullable_int, vec![
+                vec![
+                    DataValue::Int64(1),
+                    DataValue::Int64(2),
+                    DataValue::Int64(3),
+                ],
+                vec![],
+                vec![DataValue::Null, DataValue::Int64(1)],
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_distinct-string-passed",
+            columns: vec![array_column(StringType::arc(), vec![vec![
+                DataValue::String(b"b".to_vec()),
+                DataValue::String(b"a".to_vec()),
+                DataValue::String(b"b".to_vec()),
+            ]])?],
+            expect: array_column(StringType::arc(), vec![vec![
+                DataValue::String(b"b".to_vec()),
+                DataValue::String(b"a".to_vec()),
+            ]])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_distinct-number-error",
+            columns: vec![Series::from_data(vec![1u64])],
+            expect: Series::from_data(vec![1u64]),
+            error: "Function array_distinct expects the argument to be Array, but got UInt64",
+        },
+    ];
+
+    test_scalar_functions(
+        ArrayDistinctFunction::try_create("array_distinct")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_array_slice_function() -> Result<()> {
+    let nullable_int = wrap_nullable(&Int64Type::arc());
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "array_slice-passed",
+            columns: vec![
+                int_array_column()?,
+                Series::from_data(vec![1i64, 0, 0]),
+                Series::from_data(vec![2i64, 1, 1]),
+            ],
+            expect: array_column(nullable_int.clone(), vec![
+                vec![DataValue::Int64(2)],
+                vec![],
+                vec![DataValue::Int64(3)],
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_slice-negative-index-passed",
+            columns: vec![
+                int_array_column()?,
+                Series::from_data(vec![-2i64, -1, -1]),
+                Series::from_data(vec![-1i64, 0, 2]),
+            ],
+            expect: array_column(nullable_int.clone(), vec![
+                vec![DataValue::Int64(1)],
+                vec![],
+                vec![DataValue::Null],
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_slice-out-of-range-passed",
+            columns: vec![
+                int_array_column()?,
+                Series::from_data(vec![-10i64, 5, 1]),
+                Series::from_data(vec![10i64, 10, 0]),
+            ],
+            expect: array_column(nullable_int.clone(), vec![
+                vec![DataValue::Int64(1), DataValue::Int64(2)],
+                vec![],
+                vec![],
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_slice-without-end-passed",
+            columns: vec![int_array_column()?, Series::from_data(vec![1u8, 0, 1])],
+            expect: array_column(nullable_int, vec![vec![DataValue::Int64(2)], vec![], vec![
+                DataValue::Null,
+            ]])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_slice-number-error",
+            columns: vec![Series::from_data(vec![1u64]), Series::from_data(vec![0i64])],
+            expect: Series::from_data(vec![1u64]),
+            error: "Function array_slice expects the first argument to be Array, but got UInt64",
+        },
+        ScalarFunctionTest {
+            name: "array_slice-index-error",
+            columns: vec![int_array_column()?, Series::from_data(vec!["a", "b", "c"])],
+            expect: Series::from_data(vec![1u64]),
+            error: "Function array_slice expects the index to be integer, but got String",
+        },
+    ];
+
+    test_scalar_functions(ArraySliceFunction::try_create("array_slice")?, &tests, true)
+}
+
+#[test]
+fn test_array_size_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "array_size-passed",
+            columns: vec![int_array_column()?],
+            expect: Series::from_data(vec![2u64, 0, 2]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "array_size-number-error",
+            columns: vec![Series::from_data(vec![1u64])],
+            expect: Series::from_data(vec![1u64]),
+            error: "Function array_size expects the argument to be Array, but got UInt64",
+        },
+    ];
+
+    test_scalar_functions(ArraySizeFunction::try_create("array_size")?, &tests, true)
+}
@@ -0,0 +1,256 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use super::scalar_function2_test::test_scalar_functions;
+use super::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_bit_count_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_count-int8",
+            columns: vec![Series::from_data(vec![0i8, 7, -1, -128])],
+            expect: Series::from_data(vec![0u8, 3, 8, 1]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_count-int64",
+            columns: vec![Series::from_data(vec![-1i64, i64::MIN, 1024])],
+            expect: Series::from_data(vec![64u8, 1, 1]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_count-uint32",
+            columns: vec![Series::from_data(vec![u32::MAX, 5])],
+            expect: Series::from_data(vec![32u8, 2]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_count-with-null",
+            columns: vec![Series::from_data(vec![Some(3i32), None])],
+            expect: Series::from_data(vec![Some(2u8), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_count-float",
+            columns: vec![Series::from_data(vec![1.5f64])],
+            expect: Series::from_data(Vec::<u8>::new()),
+            error: "Expected an integer type, but got Float64",
+        },
+    ];
+
+    test_scalar_functions(BitCountFunction::try_create("bit_count")?, &tests, true)
+}
+
+#[test]
+fn test_bit_and_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_and-negative",
+            columns: vec![
+                Series::from_data(vec![-1i8, -2, 12]),
+                Series::from_data(vec![5i8, 7, 10]),
+            ],
+            expect: Series::from_data(vec![5i8, 6, 8]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_and-widening",
+            columns: vec![
+                Series::from_data(vec![255u8, 15]),
+                Series::from_data(vec![-1i8, -16]),
+            ],
+            expect: Series::from_data(vec![255i16, 0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_and-constant",
+            columns: vec![
+                Series::from_data(vec![6u32, 7, 8]),
+                ConstColumn::new(Series::from_data(vec![3u32]), 3).arc(),
+            ],
+            expect: Series::from_data(vec![2u32, 3, 0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_and-with-null",
+            columns: vec![
+                Series::from_data(vec![Some(3i32), None, Some(1)]),
+                Series::from_data(vec![Some(1i32), Some(1), None]),
+            ],
+            expect: Series::from_data(vec![Some(1i32), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_and-float",
+            columns: vec![
+                Series::from_data(vec![1i32]),
+                Series::from_data(vec![1.0f32]),
+            ],
+            expect: Series::from_data(Vec::<i32>::new()),
+            error: "Expected an integer type, but got Float32",
+        },
+    ];
+
+    test_scalar_functions(BitAndFunction::try_create("bit_and")?, &tests, true)
+}
+
+#[test]
+fn test_bit_or_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_or-negative",
+            columns: vec![
+                Series::from_data(vec![-128i8, 1]),
+                Series::from_data(vec![1i8, 2]),
+            ],
+            expect: Series::from_data(vec![-127i8, 3]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_or-widening",
+            columns: vec![
+                Series::from_data(vec![240u8]),
+                Series::from_data(vec![-16i8]),
+            ],
+            expect: Series::from_data(vec![-16i16]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(BitOrFunction::try_create("bit_or")?, &tests, true)
+}
+
+#[test]
+fn test_bit_xor_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_xor-negative",
+            columns: vec![
+                Series::from_data(vec![-1i32, 5]),
+                Series::from_data(vec![0i32, 3]),
+            ],
+            expect: Series::from_data(vec![-1i32, 6]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_xor-widening",
+            columns: vec![
+                Series::from_data(vec![1u16]),
+                Series::from_data(vec![-1i64]),
+            ],
+            expect: Series::from_data(vec![-2i64]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(BitXorFunction::try_create("bit_xor")?, &tests, true)
+}
+
+#[test]
+fn test_bit_shift_left_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_shift_left-int8",
+            columns: vec![
+                Series::from_data(vec![1i8, 1, -1, 1]),
+                Series::from_data(vec![3u8, 7, 1, 8]),
+            ],
+            expect: Series::from_data(vec![8i8, -128, -2, 0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_left-larger-than-width",
+            columns: vec![
+                Series::from_data(vec![1u8, 255]),
+                Series::from_data(vec![100i64, 64]),
+            ],
+            expect: Series::from_data(vec![0u8, 0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_left-with-null",
+            columns: vec![
+                Series::from_data(vec![Some(1i64), None]),
+                Series::from_data(vec![Some(62u8), Some(1)]),
+            ],
+            expect: Series::from_data(vec![Some(1i64 << 62), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_left-negative-count",
+            columns: vec![
+                Series::from_data(vec![1i32]),
+                Series::from_data(vec![-1i32]),
+            ],
+            expect: Series::from_data(Vec::<i32>::new()),
+            error: "Shift count must not be negative, but got -1",
+        },
+    ];
+
+    test_scalar_functions(
+        BitShiftLeftFunction::try_create("bit_shift_left")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_bit_shift_right_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "bit_shift_right-int8",
+            columns: vec![
+                Series::from_data(vec![-128i8, -1, 64, 64]),
+                Series::from_data(vec![7u8, 1, 6, 7]),
+            ],
+            expect: Series::from_data(vec![-1i8, -1, 1, 0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_right-larger-than-width",
+            columns: vec![
+                Series::from_data(vec![-128i8, 127, i8::MIN]),
+                Series::from_data(vec![8u64, 8, 200]),
+            ],
+            expect: Series::from_data(vec![-1i8, 0, -1]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_right-unsigned",
+            columns: vec![
+                Series::from_data(vec![255u8, 16, 128]),
+                Series::from_data(vec![8i32, 0, 7]),
+            ],
+            expect: Series::from_data(vec![0u8, 16, 1]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "bit_shift_right-negative-count",
+            columns: vec![Series::from_data(vec![1u64]), Series::from_data(vec![-3i8])],
+            expect: Series::from_data(Vec::<u64>::new()),
+            error: "Shift count must not be negative, but got -3",
+        },
+    ];
+
+    test_scalar_functions(
+        BitShiftRightFunction::try_create("bit_shift_right")?,
+        &tests,
+        true,
+    )
+}
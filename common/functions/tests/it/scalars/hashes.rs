@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::Blake3HashFunction;
 use common_functions::scalars::City64WithSeedFunction;
+use common_functions::scalars::HashFunction;
 use common_functions::scalars::Md5HashFunction;
 use common_functions::scalars::Sha1HashFunction;
 use common_functions::scalars::Sha2HashFunction;
@@ -27,6 +28,7 @@ use common_functions::scalars::XxHash32Function;
 use common_functions::scalars::XxHash64Function;
 use naive_cityhash::cityhash64_with_seed;
 use twox_hash::XxHash32;
+use twox_hash::XxHash64;
 
 use super::scalar_function2_test::test_scalar_functions;
 use super::scalar_function2_test::ScalarFunctionTest;
@@ -225,6 +227,32 @@ fn test_sha2hash_function() -> Result<()> {
     test_scalar_functions(Sha2HashFunction::try_create("sha2")?, &tests, true)
 }
 
+#[test]
+fn test_sha256hash_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid input",
+            columns: vec![Series::from_data(["abc", ""])],
+            expect: Series::from_data([
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null input",
+            columns: vec![Series::from_data([Some("abc"), None])],
+            expect: Series::from_data([
+                Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+                None,
+            ]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(Sha256HashFunction::try_create("sha256")?, &tests, true)
+}
+
 #[test]
 fn test_blake3hash_function() -> Result<()> {
     let tests = vec![ScalarFunctionTest {
@@ -371,3 +399,65 @@ fn test_cityhash64_with_seed_string() -> Result<()> {
         true,
     )
 }
+
+#[test]
+fn test_hash_function() -> Result<()> {
+    // Every argument is prefixed with a tag: 0 for NULL, 1 for value.
+    // Strings are prefixed with their length.
+    let int_hash = |values: &[i32]| {
+        let mut h = XxHash64::default();
+        for v in values {
+            h.write_u8(1);
+            Hash::hash(v, &mut h);
+        }
+        h.finish()
+    };
+    let string_hash = |values: &[Option<&str>]| {
+        let mut h = XxHash64::default();
+        for v in values {
+            match v {
+                Some(v) => {
+                    h.write_u8(1);
+                    h.write_u64(v.len() as u64);
+                    h.write(v.as_bytes());
+                }
+                None => h.write_u8(0),
+            }
+        }
+        h.finish()
+    };
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "one int argument",
+            columns: vec![Series::from_data(vec![1i32, 2, 1])],
+            expect: Series::from_data(vec![int_hash(&[1]), int_hash(&[2]), int_hash(&[1])]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "two int arguments",
+            columns: vec![
+                Series::from_data(vec![1i32, 2]),
+                Series::from_data(vec![2i32, 1]),
+            ],
+            expect: Series::from_data(vec![int_hash(&[1, 2]), int_hash(&[2, 1])]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullable string arguments",
+            columns: vec![
+                Series::from_data(vec![Some("ab"), Some("a"), None, Some("")]),
+                Series::from_data(vec![Some("c"), Some("bc"), Some("c"), Some("c")]),
+            ],
+            expect: Series::from_data(vec![
+                string_hash(&[Some("ab"), Some("c")]),
+                string_hash(&[Some("a"), Some("bc")]),
+                string_hash(&[None, Some("c")]),
+                string_hash(&[Some(""), Some("c")]),
+            ]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(HashFunction::try_create("hash")?, &tests, false)
+}
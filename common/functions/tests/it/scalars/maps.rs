@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::MapKeysFunction;
+use common_functions::scalars::MapSizeFunction;
+use common_functions::scalars::MapValuesFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+fn map_entry(key: &str, value: u64) -> DataValue {
+    DataValue::Struct(vec![
+        DataValue::String(key.as_bytes().to_vec()),
+        DataValue::UInt64(value),
+    ])
+}
+
+// [{a:1, b:2}, {}, {c:3}]
+fn map_column() -> Result<ColumnRef> {
+    MapType::create(StringType::arc(), UInt64Type::arc()).create_column(&[
+        DataValue::Array(vec![map_entry("a", 1), map_entry("b", 2)]),
+        DataValue::Array(vec![]),
+        DataValue::Array(vec![map_entry("c", 3)]),
+    ])
+}
+
+fn array_column(inner: DataTypePtr, values: Vec<Vec<DataValue>>) -> Result<ColumnRef> {
+    let values = values.into_iter().map(DataValue::Array).collect::<Vec<_>>();
+    ArrayType::create(inner).create_column(&values)
+}
+
+#[test]
+fn test_map_keys_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "map_keys-passed",
+            columns: vec![map_column()?],
+            expect: array_column(StringType::arc(), vec![
+                vec![
+                    DataValue::String(b"a".to_vec()),
+                    DataValue::String(b"b".to_vec()),
+                ],
+                vec![],
+                vec![DataValue::String(b"c".to_vec())],
+            ])?,
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "map_keys-string-error",
+            columns: vec![Series::from_data(vec!["a"])],
+            expect: Series::from_data(vec![0u64]),
+            error: "Function map_keys expects the argument to be Map, but got String",
+        },
+    ];
+
+    test_scalar_functions(MapKeysFunction::try_create("map_keys")?, &tests, false)
+}
+
+#[test]
+fn test_map_values_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "map_values-passed",
+        columns: vec![map_column()?],
+        expect: array_column(UInt64Type::arc(), vec![
+            vec![DataValue::UInt64(1), DataValue::UInt64(2)],
+            vec![],
+            vec![DataValue::UInt64(3)],
+        ])?,
+        error: "",
+    }];
+
+    test_scalar_functions(MapValuesFunction::try_create("map_values")?, &tests, false)
+}
+
+#[test]
+fn test_map_size_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "map_size-passed",
+            columns: vec![map_column()?],
+            expect: Series::from_data(vec![2u64, 0, 1]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "map_size-null-passed",
+            columns: vec![NullColumn::new(2).arc()],
+            expect: NullColumn::new(2).arc(),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(MapSizeFunction::try_create("map_size")?, &tests, false)
+}
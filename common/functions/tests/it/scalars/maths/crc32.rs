@@ -44,3 +44,24 @@ fn test_crc32_function() -> Result<()> {
 
     test_scalar_functions(CRC32Function::try_create("crc")?, &tests, true)
 }
+
+#[test]
+fn test_crc32c_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "crc32c-check-passed",
+            columns: vec![Series::from_data(vec!["123456789", "", "a", "MySQL"])],
+            expect: Series::from_data(vec![3808858755u32, 0, 3251651376, 1398257063]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            // The test vectors from RFC 3720.
+            name: "crc32c-rfc3720-passed",
+            columns: vec![Series::from_data(vec![vec![0u8; 32], vec![0xFFu8; 32]])],
+            expect: Series::from_data(vec![0x8A9136AAu32, 0x62A8AB43]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(Crc32cFunction::try_create("crc32c")?, &tests, true)
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_factorial_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "factorial-with-series",
+            columns: vec![Series::from_data(vec![0_u8, 1, 5, 20])],
+            expect: Series::from_data(vec![
+                Some(1_i64),
+                Some(1),
+                Some(120),
+                Some(2432902008176640000),
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "factorial-with-negative",
+            columns: vec![Series::from_data(vec![-1_i32, 3])],
+            expect: Series::from_data(vec![None, Some(6_i64)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "factorial-with-overflow",
+            columns: vec![Series::from_data(vec![20_i64, 21])],
+            expect: Series::from_data(vec![Some(2432902008176640000_i64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "factorial-with-null",
+            columns: vec![Series::from_data(vec![Some(4_i32), None])],
+            expect: Series::from_data(vec![Some(24_i64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "factorial-with-float",
+            columns: vec![Series::from_data(vec![1.5_f64])],
+            expect: Series::from_data(Vec::<i64>::new()),
+            error: "Expected an integer type, but got Float64",
+        },
+    ];
+
+    test_scalar_functions(FactorialFunction::try_create("factorial")?, &tests, true)
+}
+
+#[test]
+fn test_factorial_function_with_math_domain_error() -> Result<()> {
+    let mode = |v: &str| ConstColumn::new(Series::from_data(vec![v]), 2).arc();
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "factorial-overflow-null",
+            columns: vec![Series::from_data(vec![20, 21]), mode("null")],
+            expect: Series::from_data(vec![Some(2432902008176640000_i64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "factorial-overflow-error",
+            columns: vec![Series::from_data(vec![20, 21]), mode("error")],
+            expect: Series::from_data(Vec::<i64>::new()),
+            error: "Function FACTORIAL overflows Int64 with argument 21, the maximum is 20",
+        },
+        ScalarFunctionTest {
+            name: "factorial-negative-error",
+            columns: vec![Series::from_data(vec![-1, 0]), mode("error")],
+            expect: Series::from_data(vec![None, Some(1_i64)]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(FactorialFunction::try_create("factorial")?, &tests, true)
+}
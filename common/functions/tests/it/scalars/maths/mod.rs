@@ -17,6 +17,7 @@ mod angle;
 mod ceil;
 mod crc32;
 mod exp;
+mod factorial;
 mod floor;
 mod log;
 mod pi;
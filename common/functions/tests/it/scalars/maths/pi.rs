@@ -13,23 +13,58 @@
 // limitations under the License.
 
 use std::f64::consts::PI;
+use std::sync::Arc;
 
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
+use pretty_assertions::assert_eq;
 
+use crate::scalars::scalar_function2_test::test_eval_with_type;
 use crate::scalars::scalar_function2_test::test_scalar_functions;
 use crate::scalars::scalar_function2_test::ScalarFunctionTest;
 
 #[test]
 fn test_pi_function() -> Result<()> {
-    let tests = vec![ScalarFunctionTest {
-        name: "pi-function-passed",
-        // TODO: this should be `vec![]`, consider adding the `input_row` field to `ScalarFunctionTest`.
-        columns: vec![Series::from_data(vec![PI])],
-        expect: Series::from_data(vec![PI]),
-        error: "",
-    }];
+    let pi = PiFunction::try_create("pi")?;
+    let result = test_eval_with_type(&pi, 2, &[], &[], true)?;
+    assert_eq!(
+        result.convert_full_column(),
+        Series::from_data(vec![PI, PI])
+    );
 
-    test_scalar_functions(PiFunction::try_create("pi()")?, &tests, true)
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "pi-multiple-passed",
+            columns: vec![Series::from_data([2_i32, -1, 0])],
+            expect: Series::from_data([2.0 * PI, -PI, 0.0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "pi-multiple-float-passed",
+            columns: vec![Series::from_data([0.5_f64])],
+            expect: Series::from_data([PI / 2.0]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "pi-multiple-nullable-passed",
+            columns: vec![Series::from_data([Some(2_u8), None])],
+            expect: Series::from_data([Some(2.0 * PI), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "pi-multiple-null-passed",
+            columns: vec![Arc::new(NullColumn::new(1))],
+            expect: Arc::new(NullColumn::new(1)),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "pi-multiple-string-failed",
+            columns: vec![Series::from_data(["2"])],
+            expect: Series::from_data([0.0_f64]),
+            error: "Expected a numeric type, but got String",
+        },
+    ];
+
+    test_scalar_functions(pi, &tests, true)
 }
@@ -16,6 +16,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
+use crate::scalars::scalar_function2_test::test_eval;
 use crate::scalars::scalar_function2_test::test_scalar_functions;
 use crate::scalars::scalar_function2_test::ScalarFunctionTest;
 
@@ -44,3 +45,40 @@ fn test_sqrt_function() -> Result<()> {
 
     test_scalar_functions(SqrtFunction::try_create("sqrt")?, &tests, true)
 }
+
+#[test]
+fn test_sqrt_function_with_math_domain_error() -> Result<()> {
+    let mode = |v: &str| ConstColumn::new(Series::from_data(vec![v]), 2).arc();
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "sqrt-domain-error-null",
+            columns: vec![Series::from_data(vec![4, -1]), mode("null")],
+            expect: Series::from_data(vec![Some(2_f64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "sqrt-domain-error-error",
+            columns: vec![Series::from_data(vec![4, -1]), mode("error")],
+            expect: Series::from_data(Vec::<f64>::new()),
+            error: "Function SQRT got an argument out of its domain",
+        },
+        ScalarFunctionTest {
+            name: "sqrt-domain-error-unknown-mode",
+            columns: vec![Series::from_data(vec![4, -1]), mode("zero")],
+            expect: Series::from_data(Vec::<f64>::new()),
+            error: "Unknown math domain error mode: zero, expected one of [\"null\", \"nan\", \"error\"]",
+        },
+    ];
+    test_scalar_functions(SqrtFunction::try_create("sqrt")?, &tests, true)?;
+
+    // NaN doesn't equal to itself, so check it by hand.
+    let sqrt = SqrtFunction::try_create("sqrt")?;
+    let result = test_eval(&sqrt, &[Series::from_data(vec![4, -1]), mode("nan")], true)?;
+    let viewer = f64::try_create_viewer(&result)?;
+    assert_eq!(viewer.value_at(0), 2_f64);
+    assert!(!viewer.null_at(1));
+    assert!(viewer.value_at(1).is_nan());
+
+    Ok(())
+}
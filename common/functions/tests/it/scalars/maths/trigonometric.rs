@@ -20,6 +20,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
 
+use crate::scalars::scalar_function2_test::test_eval;
 use crate::scalars::scalar_function2_test::test_scalar_functions;
 use crate::scalars::scalar_function2_test::ScalarFunctionTest;
 
@@ -137,6 +138,50 @@ fn test_trigonometric_asin_function() -> Result<()> {
     )
 }
 
+#[test]
+fn test_trigonometric_asin_function_with_math_domain_error() -> Result<()> {
+    let mode = |v: &str| ConstColumn::new(Series::from_data(vec![v]), 2).arc();
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "asin-domain-error-null",
+            columns: vec![Series::from_data(vec![0.2_f64, 2.0]), mode("null")],
+            expect: Series::from_data(vec![Some(0.2013579207903308_f64), None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "asin-domain-error-error",
+            columns: vec![Series::from_data(vec![0.2_f64, 2.0]), mode("error")],
+            expect: Series::from_data(Vec::<f64>::new()),
+            error: "Function ASIN got an argument out of its domain",
+        },
+        ScalarFunctionTest {
+            name: "asin-domain-error-in-domain",
+            columns: vec![Series::from_data(vec![0.2_f64, 1.0]), mode("error")],
+            expect: Series::from_data(vec![Some(0.2013579207903308_f64), Some(FRAC_PI_2)]),
+            error: "",
+        },
+    ];
+    test_scalar_functions(
+        TrigonometricAsinFunction::try_create_func("asin")?,
+        &tests,
+        true,
+    )?;
+
+    // NaN doesn't equal to itself, so check it by hand.
+    let asin = TrigonometricAsinFunction::try_create_func("asin")?;
+    let result = test_eval(
+        &asin,
+        &[Series::from_data(vec![0.2_f64, 2.0]), mode("nan")],
+        true,
+    )?;
+    let viewer = f64::try_create_viewer(&result)?;
+    assert!(!viewer.null_at(1));
+    assert!(viewer.value_at(1).is_nan());
+
+    Ok(())
+}
+
 #[test]
 fn test_trigonometric_acos_function() -> Result<()> {
     let tests = vec![ScalarFunctionTest {
@@ -218,3 +263,109 @@ fn test_trigonometric_atan2_function() -> Result<()> {
         true,
     )
 }
+
+#[test]
+fn test_trigonometric_hyperbolic_functions() -> Result<()> {
+    let columns = vec![Series::from_data(vec![0_f64, 1.0])];
+    test_scalar_functions(
+        TrigonometricSinhFunction::try_create_func("sinh")?,
+        &[ScalarFunctionTest {
+            name: "sinh-passed",
+            columns: columns.clone(),
+            expect: Series::from_data(vec![0_f64, 1.1752011936438014]),
+            error: "",
+        }],
+        true,
+    )?;
+    test_scalar_functions(
+        TrigonometricCoshFunction::try_create_func("cosh")?,
+        &[ScalarFunctionTest {
+            name: "cosh-passed",
+            columns: columns.clone(),
+            expect: Series::from_data(vec![1_f64, 1.5430806348152437]),
+            error: "",
+        }],
+        true,
+    )?;
+    test_scalar_functions(
+        TrigonometricTanhFunction::try_create_func("tanh")?,
+        &[ScalarFunctionTest {
+            name: "tanh-passed",
+            columns,
+            expect: Series::from_data(vec![0_f64, 0.7615941559557649]),
+            error: "",
+        }],
+        true,
+    )
+}
+
+#[test]
+fn test_trigonometric_inverse_hyperbolic_functions_domain() -> Result<()> {
+    let mode = |v: &str, rows: usize| ConstColumn::new(Series::from_data(vec![v]), rows).arc();
+
+    test_scalar_functions(
+        TrigonometricAsinhFunction::try_create_func("asinh")?,
+        &[ScalarFunctionTest {
+            name: "asinh-passed",
+            columns: vec![Series::from_data(vec![0_i32]), mode("null", 1)],
+            expect: Series::from_data(vec![Some(0_f64)]),
+            error: "",
+        }],
+        true,
+    )?;
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "acosh-domain-error-null",
+            columns: vec![
+                Series::from_data(vec![0.5_f64, 0.9999, 1.0, 2.0]),
+                mode("null", 4),
+            ],
+            expect: Series::from_data(vec![None, None, Some(0_f64), Some(1.3169578969248166)]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "acosh-domain-error-error",
+            columns: vec![Series::from_data(vec![0_u8]), mode("error", 1)],
+            expect: Series::from_data(Vec::<f64>::new()),
+            error: "Function ACOSH got an argument out of its domain",
+        },
+    ];
+    test_scalar_functions(
+        TrigonometricAcoshFunction::try_create_func("acosh")?,
+        &tests,
+        true,
+    )?;
+
+    // The infinity at 1 and -1 is out of the domain too.
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "atanh-domain-error-null",
+            columns: vec![
+                Series::from_data(vec![-2_f64, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0]),
+                mode("null", 7),
+            ],
+            expect: Series::from_data(vec![
+                None,
+                None,
+                Some(-0.5493061443340548_f64),
+                Some(0.0),
+                Some(0.5493061443340548),
+                None,
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "atanh-domain-error-error",
+            columns: vec![Series::from_data(vec![1_i8]), mode("error", 1)],
+            expect: Series::from_data(Vec::<f64>::new()),
+            error: "Function ATANH got an argument out of its domain",
+        },
+    ];
+    test_scalar_functions(
+        TrigonometricAtanhFunction::try_create_func("atanh")?,
+        &tests,
+        true,
+    )
+}
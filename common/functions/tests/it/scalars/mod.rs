@@ -13,16 +13,20 @@
 // limitations under the License.
 
 mod arithmetics;
+mod arrays;
+mod bitwises;
 mod comparisons;
 mod conditionals;
 mod dates;
 mod expressions;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
 mod scalar_function2_test;
+mod semi_structureds;
 mod strings;
 mod tuples;
 mod udfs;
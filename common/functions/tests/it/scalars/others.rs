@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_functions::scalars::Inet6AtonFunction;
+use common_functions::scalars::Inet6NtoaFunction;
 use common_functions::scalars::InetAtonFunction;
 use common_functions::scalars::InetNtoaFunction;
 use common_functions::scalars::RunningDifferenceFunction;
@@ -299,8 +301,8 @@ fn test_inet_aton_function() -> Result<()> {
     let tests = vec![
         ScalarFunctionTest {
             name: "valid input",
-            columns: vec![Series::from_data([Some("127.0.0.1")])],
-            expect: Series::from_data(vec![Option::<u32>::Some(2130706433_u32)]),
+            columns: vec![Series::from_data([Some("127.0.0.1"), Some("192.168.1.1")])],
+            expect: Series::from_data(vec![Some(2130706433_u32), Some(3232235777)]),
             error: "",
         },
         ScalarFunctionTest {
@@ -312,19 +314,19 @@ fn test_inet_aton_function() -> Result<()> {
         ScalarFunctionTest {
             name: "invalid input",
             columns: vec![Series::from_data([Some("1.1.1.1"), Some("batman")])],
-            expect: Series::from_data(vec![Option::<u32>::None]),
-            error: "Failed to parse 'batman' into a IPV4 address, invalid IP address syntax",
+            expect: Series::from_data(vec![Some(16843009_u32), None]),
+            error: "",
         },
         ScalarFunctionTest {
             name: "empty string",
             columns: vec![Series::from_data([Some("1.1.1.1"), Some("")])],
-            expect: Series::from_data(vec![Option::<u32>::None]),
-            error: "Failed to parse '' into a IPV4 address, invalid IP address syntax",
+            expect: Series::from_data(vec![Some(16843009_u32), None]),
+            error: "",
         },
     ];
 
     let test_func = InetAtonFunction::try_create("inet_aton")?;
-    test_scalar_functions(test_func, &tests, false)
+    test_scalar_functions(test_func, &tests, true)
 }
 
 #[test]
@@ -426,3 +428,82 @@ fn test_inet_ntoa_function() -> Result<()> {
     let test_func = InetNtoaFunction::try_create("inet_ntoa")?;
     test_scalar_functions(test_func, &tests, true)
 }
+
+#[test]
+fn test_inet6_aton_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let ipv6 = [
+        0x20_u8, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01,
+    ];
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid input",
+            columns: vec![Series::from_data(vec!["2001:db8::1", "192.168.1.1"])],
+            expect: Series::from_data(vec![
+                Some(ipv6.as_slice()),
+                Some([192_u8, 168, 1, 1].as_slice()),
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid input",
+            columns: vec![Series::from_data(vec!["2001:db8::g", "", "1.1.1"])],
+            expect: Series::from_data(vec![Option::<&[u8]>::None, None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null input",
+            columns: vec![Series::from_data(vec![Some("::1"), None])],
+            expect: Series::from_data(vec![
+                Some([0_u8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1].as_slice()),
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "integer input",
+            columns: vec![Series::from_data(vec![1_u32])],
+            expect: Series::from_data(vec![Option::<&[u8]>::None]),
+            error: "Expected string or null type, but got UInt32",
+        },
+    ];
+
+    let test_func = Inet6AtonFunction::try_create("inet6_aton")?;
+    test_scalar_functions(test_func, &tests, true)
+}
+
+#[test]
+fn test_inet6_ntoa_function() -> Result<()> {
+    use common_datavalues::prelude::*;
+
+    let ipv6 = [
+        0x20_u8, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01,
+    ];
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid input",
+            columns: vec![Series::from_data(vec![
+                ipv6.as_slice(),
+                [192_u8, 168, 1, 1].as_slice(),
+            ])],
+            expect: Series::from_data(vec![Some("2001:db8::1"), Some("192.168.1.1")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid length",
+            columns: vec![Series::from_data(vec!["", "abc", "abcde"])],
+            expect: Series::from_data(vec![Option::<&str>::None, None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "null input",
+            columns: vec![Series::from_data(vec![Option::<&[u8]>::None])],
+            expect: Series::from_data(vec![Option::<&str>::None]),
+            error: "",
+        },
+    ];
+
+    let test_func = Inet6NtoaFunction::try_create("inet6_ntoa")?;
+    test_scalar_functions(test_func, &tests, true)
+}
@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::JsonArrayLengthFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+#[test]
+fn test_json_array_length_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "json_array_length-passed",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![
+                    Some("[1,2,3]"),
+                    Some("[]"),
+                    Some(r#"[{"a":1},[2,3]]"#),
+                    Some(r#"{"a":[1,2]}"#),
+                    Some(r#""abc""#),
+                    Some("null"),
+                    None,
+                ]),
+                DataField::new_nullable("json", VariantType::arc()),
+            )],
+            expect: Series::from_data(vec![
+                Some(3u64),
+                Some(0),
+                Some(2),
+                None,
+                None,
+                None,
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "json_array_length-invalid-json-error",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec!["[1,"]),
+                DataField::new("json", VariantType::arc()),
+            )],
+            expect: Series::from_data(vec![None::<u64>]),
+            error: "Function json_array_length got invalid JSON value: EOF while parsing a value at line 1 column 3",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "json_array_length-string-error",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec!["[1,2]"]),
+                DataField::new("json", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![None::<u64>]),
+            error: "Function json_array_length expects the argument to be Variant, but got String",
+        },
+    ];
+
+    test_scalar_functions_with_type(
+        JsonArrayLengthFunction::try_create("json_array_length")?,
+        &tests,
+        false,
+    )
+}
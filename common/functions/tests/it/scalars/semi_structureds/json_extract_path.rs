@@ -0,0 +1,169 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::JsonExtractPathFunction;
+use common_functions::scalars::JsonExtractPathTextFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+const JSON: &str = r#"{"user":{"name":"databend","tags":["a","b"]},"items":[{"id":1},{"id":2}],"first name":"x","empty":null}"#;
+
+#[test]
+fn test_json_extract_path_function() -> Result<()> {
+    let json = || Series::from_data(vec![JSON, JSON, JSON, JSON, JSON]);
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "json_extract_path-passed",
+            columns: vec![
+                json(),
+                Series::from_data(vec![
+                    "$.user.name",
+                    "$.user",
+                    "$.items[1].id",
+                    "user.tags[0]",
+                    "$['first name']",
+                ]),
+            ],
+            expect: Series::from_data(vec![
+                Some(r#""databend""#),
+                Some(r#"{"name":"databend","tags":["a","b"]}"#),
+                Some("2"),
+                Some(r#""a""#),
+                Some(r#""x""#),
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path-not-exists-passed",
+            columns: vec![
+                json(),
+                Series::from_data(vec![
+                    "$.user.age",
+                    "$.items[2]",
+                    "$.user.name[0]",
+                    "$.items.id",
+                    "$.empty",
+                ]),
+            ],
+            expect: Series::from_data(vec![None, None, None, None, Some("null")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path-invalid-path-error",
+            columns: vec![json(), Series::from_data(vec!["$.user", "$.items[x]", "$", "$", "$"])],
+            expect: Series::from_data(vec![""]),
+            error: "Invalid JSON path \"$.items[x]\": invalid array index at position 7",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path-unclosed-bracket-error",
+            columns: vec![json(), Series::from_data(vec!["$.items[0", "$", "$", "$", "$"])],
+            expect: Series::from_data(vec![""]),
+            error: "Invalid JSON path \"$.items[0\": unclosed '[' at position 7",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path-empty-key-error",
+            columns: vec![json(), Series::from_data(vec!["$..user", "$", "$", "$", "$"])],
+            expect: Series::from_data(vec![""]),
+            error: "Invalid JSON path \"$..user\": empty key at position 1",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path-invalid-json-error",
+            columns: vec![
+                Series::from_data(vec!["{\"a\":"]),
+                Series::from_data(vec!["$.a"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Function json_extract_path got invalid JSON value: EOF while parsing a value at line 1 column 5",
+        },
+    ];
+
+    test_scalar_functions(
+        JsonExtractPathFunction::try_create("json_extract_path")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_json_extract_path_variant() -> Result<()> {
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "json_extract_path-variant-passed",
+        columns: vec![
+            ColumnWithField::new(
+                Series::from_data(vec![JSON, JSON]),
+                DataField::new("json", VariantType::arc()),
+            ),
+            ColumnWithField::new(
+                Series::from_data(vec!["$.items[0]", "$.user.tags"]),
+                DataField::new("path", StringType::arc()),
+            ),
+        ],
+        expect: Series::from_data(vec![Some(r#"{"id":1}"#), Some(r#"["a","b"]"#)]),
+        error: "",
+    }];
+
+    test_scalar_functions_with_type(
+        JsonExtractPathFunction::try_create("json_extract_path")?,
+        &tests,
+        false,
+    )
+}
+
+#[test]
+fn test_json_extract_path_text_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "json_extract_path_text-passed",
+            columns: vec![
+                Series::from_data(vec![JSON, JSON, JSON, JSON, JSON]),
+                Series::from_data(vec![
+                    "$.user.name",
+                    "$.user.tags",
+                    "$.items[0].id",
+                    "$.empty",
+                    "$.user.age",
+                ]),
+            ],
+            expect: Series::from_data(vec![
+                Some("databend"),
+                Some(r#"["a","b"]"#),
+                Some("1"),
+                None,
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_extract_path_text-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some(JSON), None]),
+                Series::from_data(vec!["$.user.name", "$.user.name"]),
+            ],
+            expect: Series::from_data(vec![Some("databend"), None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(
+        JsonExtractPathTextFunction::try_create("json_extract_path_text")?,
+        &tests,
+        false,
+    )
+}
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::JsonObjectFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_json_object_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "json_object-passed",
+            columns: vec![
+                Series::from_data(vec!["id", "id"]),
+                Series::from_data(vec![1_u64, 2]),
+                Series::from_data(vec!["name", "name"]),
+                Series::from_data(vec!["databend", "datafuse"]),
+                Series::from_data(vec!["ok", "ok"]),
+                Series::from_data(vec![true, false]),
+            ],
+            expect: Series::from_data(vec![
+                r#"{"id":1,"name":"databend","ok":true}"#,
+                r#"{"id":2,"name":"datafuse","ok":false}"#,
+            ]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_object-null-value-passed",
+            columns: vec![
+                Series::from_data(vec!["a", "a"]),
+                Series::from_data(vec![Some(1.5_f64), None]),
+            ],
+            expect: Series::from_data(vec![r#"{"a":1.5}"#, r#"{"a":null}"#]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_object-duplicate-key-passed",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![1_i32]),
+                Series::from_data(vec!["b"]),
+                Series::from_data(vec![2_i32]),
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![3_i32]),
+            ],
+            expect: Series::from_data(vec![r#"{"a":3,"b":2}"#]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "json_object-odd-arguments-error",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![1_i32]),
+                Series::from_data(vec!["b"]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Function json_object requires an even number of arguments, but got 3",
+        },
+        ScalarFunctionTest {
+            name: "json_object-null-key-error",
+            columns: vec![
+                Series::from_data(vec![Some("a"), None]),
+                Series::from_data(vec![1_i32, 2]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Function json_object key must not be null",
+        },
+        ScalarFunctionTest {
+            name: "json_object-key-type-error",
+            columns: vec![
+                Series::from_data(vec![1_i32]),
+                Series::from_data(vec![1_i32]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Function json_object key must be String, but got Int32",
+        },
+    ];
+
+    test_scalar_functions(
+        JsonObjectFunction::try_create("json_object")?,
+        &tests,
+        false,
+    )
+}
@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::JsonTypeofFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+#[test]
+fn test_json_typeof_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "json_typeof-passed",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![
+                    Some(r#"{"a":1}"#),
+                    Some("[1,2]"),
+                    Some(r#""abc""#),
+                    Some("1.5"),
+                    Some("true"),
+                    Some("null"),
+                    None,
+                ]),
+                DataField::new_nullable("json", VariantType::arc()),
+            )],
+            expect: Series::from_data(vec![
+                Some("object"),
+                Some("array"),
+                Some("string"),
+                Some("number"),
+                Some("boolean"),
+                Some("null"),
+                None,
+            ]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "json_typeof-string-error",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec!["1"]),
+                DataField::new("json", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Function json_typeof expects the argument to be Variant, but got String",
+        },
+    ];
+
+    test_scalar_functions_with_type(
+        JsonTypeofFunction::try_create("json_typeof")?,
+        &tests,
+        false,
+    )
+}
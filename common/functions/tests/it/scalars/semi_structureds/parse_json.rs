@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ParseJsonFunction;
+use common_functions::scalars::TryParseJsonFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions_with_type;
+use crate::scalars::scalar_function2_test::ScalarFunctionWithFieldTest;
+
+#[test]
+fn test_parse_json_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "parse_json-passed",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![
+                    r#"{ "a" : 1, "b" : [1, 2] }"#,
+                    " [ true , null ] ",
+                    r#""abc""#,
+                    "1.5",
+                ]),
+                DataField::new("str", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![r#"{"a":1,"b":[1,2]}"#, "[true,null]", r#""abc""#, "1.5"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "parse_json-nullable-passed",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![Some("[1, 2]"), None]),
+                DataField::new_nullable("str", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![Some("[1,2]"), None]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "parse_json-invalid-error",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec!["1", "{"]),
+                DataField::new("str", StringType::arc()),
+            )],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Function parse_json got invalid JSON string at row 1: EOF while parsing an object at line 1 column 1",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "parse_json-number-error",
+            columns: vec![ColumnWithField::new(
+                Series::from_data(vec![1_i32]),
+                DataField::new("str", Int32Type::arc()),
+            )],
+            expect: Series::from_data(vec![None::<&str>]),
+            error: "Function parse_json expects the argument to be String, but got Int32",
+        },
+    ];
+
+    test_scalar_functions_with_type(ParseJsonFunction::try_create("parse_json")?, &tests, false)
+}
+
+#[test]
+fn test_try_parse_json_function() -> Result<()> {
+    let tests = vec![ScalarFunctionWithFieldTest {
+        name: "try_parse_json-passed",
+        columns: vec![ColumnWithField::new(
+            Series::from_data(vec![r#"{"a": 1}"#, "{", "abc"]),
+            DataField::new("str", StringType::arc()),
+        )],
+        expect: Series::from_data(vec![Some(r#"{"a":1}"#), None, None]),
+        error: "",
+    }];
+
+    test_scalar_functions_with_type(
+        TryParseJsonFunction::try_create("try_parse_json")?,
+        &tests,
+        false,
+    )
+}
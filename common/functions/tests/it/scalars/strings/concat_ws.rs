@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ConcatWsFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_concat_ws_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "concat_ws-passed",
+            columns: vec![
+                Series::from_data(vec![",", ",", "-"]),
+                Series::from_data(vec!["a", "b", "c"]),
+                Series::from_data(vec!["d", "e", "f"]),
+            ],
+            expect: Series::from_data(vec!["a,d", "b,e", "c-f"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "concat_ws-null-values-passed",
+            columns: vec![
+                Series::from_data(vec![",", ",", ","]),
+                Series::from_data(vec![None, Some("b"), None]),
+                Series::from_data(vec![Some("c"), None, None]),
+                Series::from_data(vec![Some("d"), Some("e"), None]),
+            ],
+            expect: Series::from_data(vec!["c,d", "b,e", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "concat_ws-null-separator-passed",
+            columns: vec![
+                Series::from_data(vec![Some(","), None, Some("-")]),
+                Series::from_data(vec!["a", "b", "c"]),
+                Series::from_data(vec![Some("d"), Some("e"), None]),
+            ],
+            expect: Series::from_data(vec![Some("a,d"), None, Some("c")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "concat_ws-number-error",
+            columns: vec![Series::from_data(vec![","]), Series::from_data(vec![1_i64])],
+            expect: Series::from_data(vec![""]),
+            error: "Expected a string type, but got Int64",
+        },
+    ];
+
+    test_scalar_functions(ConcatWsFunction::try_create("concat_ws")?, &tests, false)
+}
@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::Base64DecodeFunction;
+use common_functions::scalars::Base64EncodeFunction;
+use common_functions::scalars::Base64UrlDecodeFunction;
+use common_functions::scalars::Base64UrlEncodeFunction;
+use common_functions::scalars::HexFunction;
+use common_functions::scalars::TryBase64DecodeFunction;
+use common_functions::scalars::TryBase64UrlDecodeFunction;
+use common_functions::scalars::TryUnhexFunction;
+use common_functions::scalars::UnhexFunction;
+
+use crate::scalars::scalar_function2_test::test_eval;
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_hex_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "hex-uint-passed",
+            columns: vec![Series::from_data(vec![0u8, 10, 255])],
+            expect: Series::from_data(vec!["0", "A", "FF"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "hex-int-passed",
+            columns: vec![Series::from_data(vec![100i64, -1, -100])],
+            expect: Series::from_data(vec!["64", "FFFFFFFFFFFFFFFF", "FFFFFFFFFFFFFF9C"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "hex-string-passed",
+            columns: vec![Series::from_data(vec!["AB", "", "Now"])],
+            expect: Series::from_data(vec!["4142", "", "4E6F77"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(HexFunction::try_create("hex")?, &tests, true)
+}
+
+#[test]
+fn test_unhex_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "unhex-passed",
+            columns: vec![Series::from_data(vec!["616263", "", "E695B0", "4e6f77"])],
+            expect: Series::from_data(vec![Some("abc"), Some(""), Some("数"), Some("Now")]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "unhex-long-passed",
+            columns: vec![Series::from_data(vec!["61".repeat(100).as_str()])],
+            expect: Series::from_data(vec![Some("a".repeat(100).as_str())]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "unhex-invalid-passed",
+            columns: vec![Series::from_data(vec!["616263", "zz", "616"])],
+            expect: Series::from_data(vec![Some("abc"), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(UnhexFunction::try_create("unhex")?, &tests, true)
+}
+
+#[test]
+fn test_from_base64_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "from_base64-passed",
+            columns: vec![Series::from_data(vec!["YWJj", "", "5pWw"])],
+            expect: Series::from_data(vec!["abc", "", "数"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "from_base64-invalid-error",
+            columns: vec![Series::from_data(vec!["1"])],
+            expect: Series::from_data(vec![""]),
+            error: "Encoded text cannot have a 6-bit remainder.",
+        },
+    ];
+
+    test_scalar_functions(
+        Base64DecodeFunction::try_create("from_base64")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_try_from_base64_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "try_from_base64-invalid-passed",
+        columns: vec![Series::from_data(vec!["YWJj", "1", "YW?j"])],
+        expect: Series::from_data(vec![Some("abc"), None, None]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        TryBase64DecodeFunction::try_create("try_from_base64")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_base64url_encode_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "base64url_encode-passed",
+        columns: vec![Series::from_data(vec!["??>", "???", "ab", ""])],
+        expect: Series::from_data(vec!["Pz8-", "Pz8_", "YWI=", ""]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        Base64UrlEncodeFunction::try_create("base64url_encode")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_base64url_decode_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "base64url_decode-passed",
+            columns: vec![Series::from_data(vec!["Pz8-", "Pz8_", "YWI="])],
+            expect: Series::from_data(vec!["??>", "???", "ab"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "base64url_decode-standard-alphabet-error",
+            columns: vec![Series::from_data(vec!["Pz8+"])],
+            expect: Series::from_data(vec![""]),
+            error: "Invalid byte 43, offset 3.",
+        },
+    ];
+
+    test_scalar_functions(
+        Base64UrlDecodeFunction::try_create("base64url_decode")?,
+        &tests,
+        true,
+    )?;
+
+    let tests = vec![ScalarFunctionTest {
+        name: "try_base64url_decode-invalid-passed",
+        columns: vec![Series::from_data(vec!["Pz8-", "Pz8+", "1"])],
+        expect: Series::from_data(vec![Some("??>"), None, None]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        TryBase64UrlDecodeFunction::try_create("try_base64url_decode")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_encode_decode_round_trip() -> Result<()> {
+    let input = Series::from_data(vec!["", "abc", "数据", "a\0b\n"]);
+
+    let hex = HexFunction::try_create("hex")?;
+    let unhex = UnhexFunction::try_create("unhex")?;
+    let encoded = test_eval(&hex, &[input.clone()], true)?;
+    let decoded = test_eval(&unhex, &[encoded], true)?;
+    let expect = Series::from_data(vec![Some(""), Some("abc"), Some("数据"), Some("a\0b\n")]);
+    assert_eq!(expect, decoded);
+
+    let to_base64 = Base64EncodeFunction::try_create("to_base64")?;
+    let from_base64 = Base64DecodeFunction::try_create("from_base64")?;
+    let encoded = test_eval(&to_base64, &[input.clone()], true)?;
+    let decoded = test_eval(&from_base64, &[encoded], true)?;
+    assert_eq!(input, decoded);
+
+    let to_base64url = Base64UrlEncodeFunction::try_create("base64url_encode")?;
+    let from_base64url = Base64UrlDecodeFunction::try_create("base64url_decode")?;
+    let encoded = test_eval(&to_base64url, &[input.clone()], true)?;
+    let decoded = test_eval(&from_base64url, &[encoded], true)?;
+    assert_eq!(input, decoded);
+
+    Ok(())
+}
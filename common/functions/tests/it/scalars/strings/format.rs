@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::FormatFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_format_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "format-float-passed",
+            columns: vec![
+                Series::from_data(vec![1234567.891_f64, 1234567.891, 0.5, 1.999, 12332.5]),
+                Series::from_data(vec![2_i64, 0, 1, 2, 0]),
+            ],
+            expect: Series::from_data(vec!["1,234,567.89", "1,234,568", "0.5", "2.00", "12,333"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format-negative-passed",
+            columns: vec![
+                Series::from_data(vec![-1234567.891_f64, -0.5, -0.999, -12332.5]),
+                Series::from_data(vec![2_i64, 1, 2, 0]),
+            ],
+            expect: Series::from_data(vec!["-1,234,567.89", "-0.5", "-1.00", "-12,333"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format-integer-passed",
+            columns: vec![
+                Series::from_data(vec![u64::MAX, 0]),
+                Series::from_data(vec![0_u8, 3]),
+            ],
+            expect: Series::from_data(vec!["18,446,744,073,709,551,615", "0.000"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format-negative-decimals-passed",
+            columns: vec![
+                Series::from_data(vec![-1234.5_f64]),
+                Series::from_data(vec![-1_i64]),
+            ],
+            expect: Series::from_data(vec!["-1,235"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "format-null-passed",
+            columns: vec![
+                Series::from_data(vec![Some(1234.5_f64), None]),
+                Series::from_data(vec![1_i64, 1]),
+            ],
+            expect: Series::from_data(vec![Some("1,234.5"), None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(FormatFunction::try_create("format")?, &tests, true)
+}
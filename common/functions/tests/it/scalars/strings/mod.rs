@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod concat_ws;
+mod decode;
+mod format;
 // mod locate;
 mod lower;
+mod pad;
 mod regexp_like;
+mod repeat;
+mod replace;
+mod reverse;
+mod string_predicate;
 mod substring;
 mod trim;
 
@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::LeftPadFunction;
+use common_functions::scalars::RightPadFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_lpad_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "lpad-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "abc", "abc", "abc"]),
+                Series::from_data(vec![0u64, 2, 3, 8]),
+                Series::from_data(vec!["xy", "xy", "xy", "xy"]),
+            ],
+            expect: Series::from_data(vec!["", "ab", "abc", "xyxyxabc"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "lpad-empty-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "abc"]),
+                Series::from_data(vec![2u64, 5]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec!["ab", "abc"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "lpad-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("abc"), None]),
+                Series::from_data(vec![5u64, 5]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec![Some("xxabc"), None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(LeftPadFunction::try_create("lpad")?, &tests, true)
+}
+
+#[test]
+fn test_rpad_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "rpad-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "abc", "abc", "abc"]),
+                Series::from_data(vec![0u64, 2, 3, 8]),
+                Series::from_data(vec!["xy", "xy", "xy", "xy"]),
+            ],
+            expect: Series::from_data(vec!["", "ab", "abc", "abcxyxyx"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "rpad-empty-pad-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "abc"]),
+                Series::from_data(vec![2u64, 5]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec!["ab", "abc"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "rpad-nullable-passed",
+            columns: vec![
+                Series::from_data(vec![Some("abc"), None]),
+                Series::from_data(vec![5u64, 5]),
+                Series::from_data(vec!["x", "x"]),
+            ],
+            expect: Series::from_data(vec![Some("abcxx"), None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(RightPadFunction::try_create("rpad")?, &tests, true)
+}
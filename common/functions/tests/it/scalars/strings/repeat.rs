@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RepeatFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_repeat_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "repeat-passed",
+            columns: vec![
+                Series::from_data(vec!["abc", "abc", "abc"]),
+                Series::from_data(vec![3_i64, 0, -1]),
+            ],
+            expect: Series::from_data(vec!["abcabcabc", "", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "repeat-unsigned-passed",
+            columns: vec![Series::from_data(vec!["数"]), Series::from_data(vec![2_u8])],
+            expect: Series::from_data(vec!["数数"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "repeat-too-many-times-error",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![1000001_i64]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Too many times to repeat: (1000001), maximum is: 1000000",
+        },
+        ScalarFunctionTest {
+            name: "repeat-float-error",
+            columns: vec![
+                Series::from_data(vec!["a"]),
+                Series::from_data(vec![1.5_f64]),
+            ],
+            expect: Series::from_data(vec![""]),
+            error: "Expected parameter 2 is integer or null, but got Float64",
+        },
+    ];
+
+    test_scalar_functions(RepeatFunction::try_create("repeat")?, &tests, true)
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ReplaceFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_replace_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "replace-passed",
+            columns: vec![
+                Series::from_data(vec!["a1b1c", "abc", "abc"]),
+                Series::from_data(vec!["1", "", "b"]),
+                Series::from_data(vec!["22", "x", ""]),
+            ],
+            expect: Series::from_data(vec!["a22b22c", "abc", "ac"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "replace-overlapping-passed",
+            columns: vec![
+                Series::from_data(vec!["aaa", "ababab", "aaaa"]),
+                Series::from_data(vec!["aa", "aba", "aa"]),
+                Series::from_data(vec!["b", "x", "a"]),
+            ],
+            expect: Series::from_data(vec!["ba", "xbab", "aa"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "replace-longer-from-passed",
+            columns: vec![
+                Series::from_data(vec!["ab"]),
+                Series::from_data(vec!["abc"]),
+                Series::from_data(vec!["x"]),
+            ],
+            expect: Series::from_data(vec!["ab"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(ReplaceFunction::try_create("replace")?, &tests, true)
+}
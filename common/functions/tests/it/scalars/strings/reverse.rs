@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ReverseFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_reverse_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "reverse-passed",
+            columns: vec![Series::from_data(vec!["abc", ""])],
+            expect: Series::from_data(vec!["cba", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "reverse-utf8-passed",
+            columns: vec![Series::from_data(vec!["数据库abc", "Dobrý"])],
+            expect: Series::from_data(vec!["cba库据数", "ýrboD"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(ReverseFunction::try_create("reverse")?, &tests, true)
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ContainsFunction;
+use common_functions::scalars::EndsWithFunction;
+use common_functions::scalars::StartsWithFunction;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_startswith_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "startswith-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", "databend", "data", ""]),
+                Series::from_data(vec!["data", "bend", "databend", ""]),
+            ],
+            expect: Series::from_data(vec![true, false, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "startswith-empty-prefix-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", ""]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec![true, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "startswith-null-passed",
+            columns: vec![
+                Series::from_data(vec![Some("databend"), None, Some("databend")]),
+                Series::from_data(vec![Some("data"), Some("data"), None]),
+            ],
+            expect: Series::from_data(vec![Some(true), None, None]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "startswith-number-error",
+            columns: vec![
+                Series::from_data(vec!["databend"]),
+                Series::from_data(vec![1u8]),
+            ],
+            expect: Series::from_data(vec![true]),
+            error: "Expected a string type, but got UInt8",
+        },
+    ];
+
+    test_scalar_functions(StartsWithFunction::try_create("startswith")?, &tests, true)
+}
+
+#[test]
+fn test_endswith_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "endswith-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", "databend", "bend", ""]),
+                Series::from_data(vec!["bend", "data", "databend", ""]),
+            ],
+            expect: Series::from_data(vec![true, false, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "endswith-empty-suffix-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", ""]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec![true, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "endswith-null-passed",
+            columns: vec![
+                Series::from_data(vec![Some("databend"), None, Some("databend")]),
+                Series::from_data(vec![Some("bend"), Some("bend"), None]),
+            ],
+            expect: Series::from_data(vec![Some(true), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(EndsWithFunction::try_create("endswith")?, &tests, true)
+}
+
+#[test]
+fn test_contains_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "contains-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", "databend", "data", "databend"]),
+                Series::from_data(vec!["tab", "bent", "databend", "databend"]),
+            ],
+            expect: Series::from_data(vec![true, false, false, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "contains-empty-substr-passed",
+            columns: vec![
+                Series::from_data(vec!["databend", ""]),
+                Series::from_data(vec!["", ""]),
+            ],
+            expect: Series::from_data(vec![true, true]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "contains-null-passed",
+            columns: vec![
+                Series::from_data(vec![Some("databend"), None, Some("databend")]),
+                Series::from_data(vec![Some("tab"), Some("tab"), None]),
+            ],
+            expect: Series::from_data(vec![Some(true), None, None]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(ContainsFunction::try_create("contains")?, &tests, true)
+}
@@ -71,6 +71,45 @@ fn test_substring_function() -> Result<()> {
             expect: Series::from_data(vec!["890"]),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "substring-negative-pos-passed",
+            columns: vec![
+                Series::from_data(vec!["abcde", "abcde", "abcde"]),
+                Series::from_data(vec![-1_i64, -5_i64, -6_i64]),
+            ],
+            expect: Series::from_data(vec!["e", "abcde", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "substring-out-of-range-passed",
+            columns: vec![
+                Series::from_data(vec!["abcde", "abcde", "abcde", "abcde"]),
+                Series::from_data(vec![0_i64, 6_i64, 4_i64, -2_i64]),
+                Series::from_data(vec![2_i64, 2_i64, 10_i64, i64::MAX]),
+            ],
+            expect: Series::from_data(vec!["", "", "de", "de"]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "substring-zero-negative-len-passed",
+            columns: vec![
+                Series::from_data(vec!["abcde", "abcde"]),
+                Series::from_data(vec![2_i64, 2_i64]),
+                Series::from_data(vec![0_i64, -1_i64]),
+            ],
+            expect: Series::from_data(vec!["", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "substring-utf8-passed",
+            columns: vec![
+                Series::from_data(vec!["数据库引擎", "数据库引擎", "héllo"]),
+                Series::from_data(vec![2_i64, -2_i64, 2_i64]),
+                Series::from_data(vec![2_i64, 5_i64, 3_i64]),
+            ],
+            expect: Series::from_data(vec!["据库", "引擎", "éll"]),
+            error: "",
+        },
     ];
 
     test_scalar_functions(SubstringFunction::try_create("substring")?, &tests, true)
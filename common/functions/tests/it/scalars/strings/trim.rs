@@ -16,7 +16,10 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::LTrimFunction;
 use common_functions::scalars::RTrimFunction;
+use common_functions::scalars::TrimBothFunction;
 use common_functions::scalars::TrimFunction;
+use common_functions::scalars::TrimLeadingFunction;
+use common_functions::scalars::TrimTrailingFunction;
 
 use crate::scalars::scalar_function2_test::test_scalar_functions;
 use crate::scalars::scalar_function2_test::ScalarFunctionTest;
@@ -76,3 +79,78 @@ fn test_trim_nullable() -> Result<()> {
 
     test_scalar_functions(TrimFunction::try_create("trim")?, &tests, true)
 }
+
+#[test]
+fn test_trim_leading_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "trim-leading-passed",
+            columns: vec![
+                Series::from_data(vec!["xxabcxx", "abc", "xxxx"]),
+                Series::from_data(vec!["x", "x", "x"]),
+            ],
+            expect: Series::from_data(vec!["abcxx", "abc", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "trim-leading-multi-chars-passed",
+            columns: vec![
+                Series::from_data(vec!["xyabcyx"]),
+                Series::from_data(vec!["xy"]),
+            ],
+            expect: Series::from_data(vec!["abcyx"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(
+        TrimLeadingFunction::try_create("trim_leading")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_trim_trailing_function() -> Result<()> {
+    let tests = vec![ScalarFunctionTest {
+        name: "trim-trailing-passed",
+        columns: vec![
+            Series::from_data(vec!["xyabcyx", "  abc  "]),
+            Series::from_data(vec!["xy", " "]),
+        ],
+        expect: Series::from_data(vec!["xyabc", "  abc"]),
+        error: "",
+    }];
+
+    test_scalar_functions(
+        TrimTrailingFunction::try_create("trim_trailing")?,
+        &tests,
+        true,
+    )
+}
+
+#[test]
+fn test_trim_both_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "trim-both-passed",
+            columns: vec![
+                Series::from_data(vec!["xyabcyx", "abc", ""]),
+                Series::from_data(vec!["xy", "", "xy"]),
+            ],
+            expect: Series::from_data(vec!["abc", "abc", ""]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "trim-both-utf8-passed",
+            columns: vec![
+                Series::from_data(vec!["年年databend年"]),
+                Series::from_data(vec!["年"]),
+            ],
+            expect: Series::from_data(vec!["databend"]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(TrimBothFunction::try_create("trim_both")?, &tests, true)
+}
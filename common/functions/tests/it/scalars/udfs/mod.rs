@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod database;
+mod python_udf;
 mod to_type_name;
 mod udf_example;
 mod version;
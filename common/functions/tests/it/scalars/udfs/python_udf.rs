@@ -0,0 +1,175 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::*;
+use once_cell::sync::Lazy;
+
+use crate::scalars::scalar_function2_test::test_eval;
+
+// The tests configure the same python UDF server.
+static PYTHON_UDF_SERVER_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+fn columns(file: &str, function: &str) -> Vec<ColumnRef> {
+    vec![
+        ConstColumn::new(Series::from_data(vec![file]), 2).arc(),
+        ConstColumn::new(Series::from_data(vec![function]), 2).arc(),
+        ConstColumn::new(Series::from_data(vec![0i64]), 2).arc(),
+        Series::from_data(vec![1i64, 2]),
+    ]
+}
+
+#[test]
+fn test_python_udf_function() -> Result<()> {
+    let _guard = PYTHON_UDF_SERVER_LOCK.lock().unwrap();
+    let function = PythonUdfFunction::try_create("python_udf")?;
+
+    // The return type is the type of the third argument, nullable.
+    let string_type = StringType::arc();
+    let int_type = Int64Type::arc();
+    let return_type = function.return_type(&[&string_type, &string_type, &int_type, &int_type])?;
+    assert_eq!(return_type, wrap_nullable(&int_type));
+
+    let result = function.return_type(&[&int_type, &string_type, &int_type]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Expected string for the file and function of python_udf, but got Int64"
+    );
+
+    let columns = |file: &str| columns(file, "add_one");
+
+    // Python UDFs are disabled without python_udf_dir.
+    let result = test_eval(&function, &columns("udfs.py"), false);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Python UDF is disabled, set python_udf_dir in the config to enable it"
+    );
+
+    let dir = std::env::temp_dir();
+    PythonUdfServer::instance().configure(PythonUdfConfig {
+        executable: "python3".to_string(),
+        dir: dir.to_string_lossy().to_string(),
+        timeout: Duration::from_millis(1000),
+    });
+
+    // The handler file must be in python_udf_dir.
+    for file in ["../udfs.py", "/tmp/udfs.py"] {
+        let result = test_eval(&function, &columns(file), false);
+        assert_eq!(
+            result.unwrap_err().message(),
+            format!(
+                "The handler file of python UDF must be a relative path in python_udf_dir, but got {:?}",
+                file
+            )
+        );
+    }
+
+    let file = format!("not_exists_{}.py", uuid::Uuid::new_v4());
+    let result = test_eval(&function, &columns(&file), false);
+    assert_eq!(
+        result.unwrap_err().message(),
+        format!(
+            "The handler file {:?} of python UDF does not exist",
+            dir.join(&file)
+        )
+    );
+
+    PythonUdfServer::instance().configure(PythonUdfConfig::default());
+    Ok(())
+}
+
+const HANDLERS: &str = r#"
+import os
+import time
+
+
+def add_one(x):
+    return x + 1
+
+
+def fail(x):
+    raise ValueError("bad value %d" % x)
+
+
+def slow(x):
+    time.sleep(5)
+    return x
+
+
+def crash(x):
+    os._exit(1)
+"#;
+
+// Runs on a tokio worker, the same as the queries, so the calls go through `block_in_place`.
+// It needs python3 with pyarrow, run it by `cargo test -- --ignored`.
+#[ignore]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_python_udf_handler() -> Result<()> {
+    let _guard = PYTHON_UDF_SERVER_LOCK.lock().unwrap();
+    let function = PythonUdfFunction::try_create("python_udf")?;
+    let dir = std::env::temp_dir().join(format!("python_udf_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("handlers.py"), HANDLERS)?;
+    PythonUdfServer::instance().configure(PythonUdfConfig {
+        executable: "python3".to_string(),
+        dir: dir.to_string_lossy().to_string(),
+        timeout: Duration::from_millis(1000),
+    });
+
+    let add_one = || -> Result<()> {
+        let result = test_eval(&function, &columns("handlers.py", "add_one"), false)?;
+        let values = (0..result.len()).map(|i| result.get(i)).collect::<Vec<_>>();
+        assert_eq!(values, vec![DataValue::Int64(2), DataValue::Int64(3)]);
+        Ok(())
+    };
+    add_one()?;
+
+    // The exceptions of the handler are returned with the traceback.
+    let result = test_eval(&function, &columns("handlers.py", "fail"), false);
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::PythonUdfErrorCode());
+    assert!(
+        err.message().contains("ValueError: bad value 1"),
+        "{}",
+        err.message()
+    );
+
+    let result = test_eval(&function, &columns("handlers.py", "slow"), false);
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TimeoutCode(), "{}", err.message());
+
+    // The python process is killed on timeout, and restarted on the next call.
+    add_one()?;
+
+    // The python process is restarted on the next call after it exits.
+    let result = test_eval(&function, &columns("handlers.py", "crash"), false);
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::PythonUdfErrorCode());
+    assert!(
+        err.message().contains("may have exited"),
+        "{}",
+        err.message()
+    );
+    add_one()?;
+
+    PythonUdfServer::instance().configure(PythonUdfConfig::default());
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod uuid_converter;
 mod uuid_creator;
 mod uuid_verifier;
@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+use crate::scalars::scalar_function2_test::test_scalar_functions;
+use crate::scalars::scalar_function2_test::ScalarFunctionTest;
+
+#[test]
+fn test_uuid_string_to_binary_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "valid-uuid-passed",
+            columns: vec![Series::from_data(vec![
+                "00000000-0000-0000-0000-000000000000",
+                "936DA01F-9ABD-4D9D-80C7-02AF85C822A8",
+            ])],
+            expect: Series::from_data(vec![vec![0u8; 16], vec![
+                0x93, 0x6d, 0xa0, 0x1f, 0x9a, 0xbd, 0x4d, 0x9d, 0x80, 0xc7, 0x02, 0xaf, 0x85, 0xc8,
+                0x22, 0xa8,
+            ]]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "invalid-uuid",
+            columns: vec![Series::from_data(vec!["5"])],
+            expect: Series::from_data(Vec::<Vec<u8>>::new()),
+            error:
+                "5 is not a valid uuid because: invalid length: expected one of [36, 32], found 1",
+        },
+    ];
+
+    test_scalar_functions(
+        UUIDStringToBinaryFunction::try_create("uuid_string_to_binary")?,
+        &tests,
+        true,
+    )
+}
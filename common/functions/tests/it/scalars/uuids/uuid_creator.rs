@@ -42,3 +42,27 @@ fn test_uuid_creator_functions() -> Result<()> {
 
     test_scalar_functions(UUIDZeroFunction::try_create("")?, &tests, true)
 }
+
+#[test]
+fn test_uuid_v4_function() -> Result<()> {
+    let func = UUIDv4Function::try_create("uuid")?;
+    let column = func.eval(&[], 3)?.convert_full_column();
+    let viewer = Vu8::try_create_viewer(&column)?;
+    let uuids = viewer
+        .iter()
+        .map(|v| String::from_utf8(v.to_vec()).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(uuids.len(), 3);
+    for uuid in uuids.iter() {
+        let parsed = uuid::Uuid::parse_str(uuid).unwrap();
+        assert_eq!(parsed.get_version_num(), 4);
+        assert_eq!(&parsed.to_hyphenated().to_string(), uuid);
+    }
+
+    // A fresh uuid is created per row.
+    assert_ne!(uuids[0], uuids[1]);
+    assert_ne!(uuids[1], uuids[2]);
+    assert_ne!(uuids[0], uuids[2]);
+    Ok(())
+}
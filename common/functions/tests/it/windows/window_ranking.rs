@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::windows::RankingFunction;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_ranking_function() -> Result<()> {
+    // Two partitions, the order values are: 1, 1, 2, 3, 3, 4 | 5, 5, 6.
+    let partition_starts = [true, false, false, false, false, false, true, false, false];
+    let peers = [false, true, false, false, true, false, false, true, false];
+
+    let tests = [
+        ("row_number", vec![1u64, 2, 3, 4, 5, 6, 1, 2, 3]),
+        ("RANK", vec![1u64, 1, 3, 4, 4, 6, 1, 1, 3]),
+        ("dense_rank", vec![1u64, 1, 2, 3, 3, 4, 1, 1, 2]),
+    ];
+    for (name, expect) in tests {
+        let func = RankingFunction::try_create(name)?;
+        assert_eq!(
+            func.return_type().data_type_id(),
+            TypeID::UInt64,
+            "{}",
+            name
+        );
+
+        let column = func.eval(&partition_starts, &peers)?;
+        let values = (0..column.len())
+            .map(|row| column.get(row))
+            .collect::<Vec<_>>();
+        let expect = expect
+            .into_iter()
+            .map(DataValue::UInt64)
+            .collect::<Vec<_>>();
+        assert_eq!(values, expect, "{}", name);
+    }
+
+    assert!(RankingFunction::check("Dense_Rank"));
+    assert!(!RankingFunction::check("sum"));
+    match RankingFunction::try_create("ntile") {
+        Ok(_) => panic!("ntile is not a ranking function"),
+        Err(e) => assert_eq!(e.message(), "Unsupported window function: ntile"),
+    }
+    Ok(())
+}
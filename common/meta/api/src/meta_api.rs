@@ -30,9 +30,13 @@ use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -60,6 +64,8 @@ pub trait MetaApi: Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply, MetaError>;
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, MetaError>;
@@ -74,5 +80,10 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply, MetaError>;
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, MetaError>;
+
     fn name(&self) -> String;
 }
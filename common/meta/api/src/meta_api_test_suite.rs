@@ -27,9 +27,12 @@ use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 
@@ -589,6 +592,53 @@ impl MetaApiTestSuite {
                 assert_eq!(table.options().get("key1"), Some(&"val1".into()));
             }
         }
+
+        tracing::info!("--- update table meta");
+        {
+            let new_schema = Arc::new(DataSchema::new(vec![
+                DataField::new("number", u64::to_data_type()),
+                DataField::new("name", Vu8::to_data_type()),
+            ]));
+
+            tracing::info!("--- update table meta with the current version");
+            {
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.schema = new_schema.clone();
+                mt.update_table_meta(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.version),
+                    new_table_meta,
+                })
+                .await?;
+
+                let got = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(got.schema(), new_schema);
+                assert!(got.ident.version > table.ident.version);
+                assert_eq!(got.options().get("key1"), Some(&"val1".into()));
+            }
+
+            tracing::info!("--- update table meta with a stale version");
+            {
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+
+                let got = mt
+                    .update_table_meta(UpdateTableMetaReq {
+                        table_id: table.ident.table_id,
+                        seq: MatchSeq::Exact(table.ident.version - 1),
+                        new_table_meta: table_meta(Utc::now()),
+                    })
+                    .await;
+
+                let err = ErrorCode::from(got.unwrap_err());
+                assert_eq!(ErrorCode::TableVersionMismatched("").code(), err.code());
+
+                // table is not affected.
+                let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                assert_eq!(table.schema(), new_schema);
+            }
+        }
         tracing::info!("--- drop table");
         {
             tracing::info!("--- drop table with if_exists = false");
@@ -711,6 +761,124 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_rename<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db1_name = "db1";
+        let db2_name = "db2";
+
+        let rename_req = |db: &str, table: &str, new_db: &str, new_table: &str| RenameTableReq {
+            if_exists: false,
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table_name: table.to_string(),
+            new_db: new_db.to_string(),
+            new_table_name: new_table.to_string(),
+        };
+
+        tracing::info!("--- rename table on unknown db");
+        {
+            let got = mt
+                .rename_table(rename_req(db1_name, "tb1", db1_name, "tb2"))
+                .await;
+            let code = ErrorCode::from(got.unwrap_err()).code();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), code);
+        }
+
+        tracing::info!("--- prepare db1, db2 and db1.tb1, db1.tb3");
+        let tb1_id = {
+            self.create_database(mt, tenant, db1_name).await?;
+            self.create_database(mt, tenant, db2_name).await?;
+
+            let mut req = CreateTableReq {
+                if_not_exists: false,
+                tenant: tenant.to_string(),
+                db: db1_name.to_string(),
+                table: "tb1".to_string(),
+                table_meta: TableMeta {
+                    schema: Arc::new(DataSchema::new(vec![DataField::new(
+                        "number",
+                        u64::to_data_type(),
+                    )])),
+                    engine: "JSON".to_string(),
+                    ..Default::default()
+                },
+            };
+            let res = mt.create_table(req.clone()).await?;
+
+            req.table = "tb3".to_string();
+            mt.create_table(req).await?;
+
+            res.table_id
+        };
+
+        tracing::info!("--- rename unknown table");
+        {
+            let got = mt
+                .rename_table(rename_req(db1_name, "unknown", db1_name, "tb2"))
+                .await;
+            let code = ErrorCode::from(got.unwrap_err()).code();
+            assert_eq!(ErrorCode::UnknownTable("").code(), code);
+
+            let mut req = rename_req(db1_name, "unknown", db1_name, "tb2");
+            req.if_exists = true;
+            mt.rename_table(req).await?;
+        }
+
+        tracing::info!("--- rename to an existing table name");
+        {
+            let got = mt
+                .rename_table(rename_req(db1_name, "tb1", db1_name, "tb3"))
+                .await;
+            let code = ErrorCode::from(got.unwrap_err()).code();
+            assert_eq!(ErrorCode::TableAlreadyExists("").code(), code);
+        }
+
+        tracing::info!("--- rename db1.tb1 to db1.tb2");
+        {
+            let before = mt.get_table((tenant, db1_name, "tb1").into()).await?;
+            mt.rename_table(rename_req(db1_name, "tb1", db1_name, "tb2"))
+                .await?;
+
+            let got = mt.get_table((tenant, db1_name, "tb1").into()).await;
+            let code = ErrorCode::from(got.unwrap_err()).code();
+            assert_eq!(ErrorCode::UnknownTable("").code(), code);
+
+            let got = mt.get_table((tenant, db1_name, "tb2").into()).await?;
+            assert_eq!(tb1_id, got.ident.table_id, "table id is kept");
+            assert!(
+                got.ident.version > before.ident.version,
+                "version is bumped"
+            );
+            assert_eq!(before.meta, got.meta);
+        }
+
+        tracing::info!("--- rename db1.tb2 to db2.tb1");
+        {
+            mt.rename_table(rename_req(db1_name, "tb2", db2_name, "tb1"))
+                .await?;
+
+            let res = mt.list_tables(ListTableReq::new(tenant, db1_name)).await?;
+            assert_eq!(
+                vec!["tb3".to_string()],
+                res.iter().map(|t| t.name.clone()).collect::<Vec<_>>()
+            );
+
+            let got = mt.get_table((tenant, db2_name, "tb1").into()).await?;
+            assert_eq!(tb1_id, got.ident.table_id);
+        }
+
+        tracing::info!("--- rename into an unknown db");
+        {
+            let got = mt
+                .rename_table(rename_req(db2_name, "tb1", "unknown", "tb1"))
+                .await;
+            let code = ErrorCode::from(got.unwrap_err()).code();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), code);
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
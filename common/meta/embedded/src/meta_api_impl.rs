@@ -31,9 +31,13 @@ use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -83,6 +87,12 @@ impl MetaApi for MetaEmbedded {
         Ok(reply)
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
+        let sm = self.inner.lock().await;
+        let reply = sm.rename_table(req).await?;
+        Ok(reply)
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         let sm = self.inner.lock().await;
         let reply = sm.get_table(req).await?;
@@ -113,6 +123,15 @@ impl MetaApi for MetaEmbedded {
         Ok(reply)
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, MetaError> {
+        let sm = self.inner.lock().await;
+        let reply = sm.update_table_meta(req).await?;
+        Ok(reply)
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
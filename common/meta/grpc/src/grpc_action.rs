@@ -34,7 +34,11 @@ use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableInfo;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -53,7 +57,9 @@ pub enum MetaGrpcWriteReq {
 
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
+    RenameTable(RenameTableReq),
     CommitTable(UpsertTableOptionReq),
+    UpdateTableMeta(UpdateTableMetaReq),
 
     UpsertKV(UpsertKVAction),
 }
@@ -193,6 +199,10 @@ impl RequestFor for DropTableReq {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for RenameTableReq {
+    type Reply = RenameTableReply;
+}
+
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
 }
@@ -209,6 +219,10 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for UpdateTableMetaReq {
+    type Reply = UpdateTableMetaReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
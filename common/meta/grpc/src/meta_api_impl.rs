@@ -30,9 +30,13 @@ use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -71,6 +75,10 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
+        self.do_write(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         self.do_read(req).await
     }
@@ -94,6 +102,13 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, MetaError> {
+        self.do_write(req).await
+    }
+
     fn name(&self) -> String {
         "MetaGrpcClient".to_string()
     }
@@ -557,6 +557,83 @@ impl StateMachine {
         Ok(Change::new_with_id(table_id, prev, result).into())
     }
 
+    /// Moves the table lookup entry to the new name, the table id is kept.
+    ///
+    /// It returns an empty Change if the table is absent,
+    /// or an unchanged state if the new name is already taken.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_rename_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        new_db_name: &str,
+        new_table_name: &str,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+        let new_db_id = self.txn_get_database_id(tenant, new_db_name, txn_tree)?;
+
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let seq_table_id = table_lookup_tree.get(&lookup_key)?;
+
+        if seq_table_id.is_none() {
+            return Ok(Change::<TableMeta>::new(None, None).into());
+        }
+
+        let table_id = seq_table_id.unwrap().data.0;
+
+        let new_lookup_key = TableLookupKey {
+            database_id: new_db_id,
+            table_name: new_table_name.to_string(),
+        };
+
+        if table_lookup_tree.get(&new_lookup_key)?.is_some() {
+            let prev = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+            return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                table_id, prev,
+            )));
+        }
+
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+
+        self.txn_sub_tree_upsert(
+            &table_lookup_tree,
+            &new_lookup_key,
+            &MatchSeq::Exact(0),
+            Operation::Update(TableLookupValue(table_id)),
+            None,
+        )?;
+
+        // Bump the seq of the table meta, a renamed table is a new version of the table.
+        let tables = txn_tree.key_space::<Tables>();
+        let (prev, result) =
+            self.txn_sub_tree_upsert(&tables, &table_id, &MatchSeq::Any, Operation::AsIs, None)?;
+
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+
+        tracing::debug!(
+            "applied rename Table: {}.{} to {}.{} {:?}",
+            db_name,
+            table_name,
+            new_db_name,
+            new_table_name,
+            result
+        );
+        Ok(Change::new_with_id(table_id, prev, result).into())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_update_kv_cmd(
         &self,
@@ -632,6 +709,43 @@ impl StateMachine {
         )))
     }
 
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_update_table_meta_cmd(
+        &self,
+        req: &common_meta_types::UpdateTableMetaReq,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&req.table_id)?;
+
+        let prev = prev.ok_or_else(|| {
+            MetaStorageError::AppError(AppError::UnknownTableId(UnknownTableId::new(
+                req.table_id,
+                "apply_update_table_meta_cmd".to_string(),
+            )))
+        })?;
+
+        if req.seq.match_seq(&prev).is_err() {
+            let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+            return Ok(res);
+        }
+
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree)?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta: prev.meta.clone(),
+            data: req.new_table_meta.clone(),
+        };
+
+        table_tree.insert(&req.table_id, &sv)?;
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            req.table_id,
+            Some(prev),
+            Some(sv),
+        )))
+    }
+
     /// Apply a `Cmd` to state machine.
     ///
     /// Already applied log should be filtered out before passing into this function.
@@ -677,6 +791,21 @@ impl StateMachine {
                 ref table_name,
             } => self.apply_drop_table_cmd(tenant, db_name, table_name, txn_tree),
 
+            Cmd::RenameTable {
+                ref tenant,
+                ref db_name,
+                ref table_name,
+                ref new_db_name,
+                ref new_table_name,
+            } => self.apply_rename_table_cmd(
+                tenant,
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+                txn_tree,
+            ),
+
             Cmd::UpsertKV {
                 key,
                 seq,
@@ -685,6 +814,8 @@ impl StateMachine {
             } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, txn_tree),
 
             Cmd::UpsertTableOptions(ref req) => self.apply_upsert_table_options_cmd(req, txn_tree),
+
+            Cmd::UpdateTableMeta(ref req) => self.apply_update_table_meta_cmd(req, txn_tree),
         }
     }
 
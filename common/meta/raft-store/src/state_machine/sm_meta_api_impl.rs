@@ -38,6 +38,8 @@ use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::MetaStorageError;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableAlreadyExists;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -46,6 +48,8 @@ use common_meta_types::TableVersionMismatched;
 use common_meta_types::UnknownDatabase;
 use common_meta_types::UnknownTable;
 use common_meta_types::UnknownTableId;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -206,6 +210,36 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
+        let cr = Cmd::RenameTable {
+            tenant: req.tenant,
+            db_name: req.db.clone(),
+            table_name: req.table_name.clone(),
+            new_db_name: req.new_db.clone(),
+            new_table_name: req.new_table_name.clone(),
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t)?;
+            Ok(r)
+        })?;
+
+        if res.prev().is_none() {
+            if req.if_exists {
+                return Ok(RenameTableReply {});
+            }
+            let ae = AppError::from(UnknownTable::new(&req.table_name, "rename_table"));
+            return Err(MetaError::from(ae));
+        }
+
+        if !res.changed() {
+            let ae = AppError::from(TableAlreadyExists::new(&req.new_table_name, "rename_table"));
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(RenameTableReply {})
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
         let tenant = &req.tenant;
         let db = &req.db_name;
@@ -320,6 +354,32 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, MetaError> {
+        let cmd = Cmd::UpdateTableMeta(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t)?;
+            Ok(r)
+        })?;
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            let ae = AppError::from(TableVersionMismatched::new(
+                req.table_id,
+                req.seq,
+                prev.seq,
+                "update_table_meta",
+            ));
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(UpdateTableMetaReply {})
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
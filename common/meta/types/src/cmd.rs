@@ -24,6 +24,7 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::UpdateTableMetaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -62,6 +63,17 @@ pub enum Cmd {
         table_name: String,
     },
 
+    /// Rename a table, possibly into another database, if the new name is absent.
+    ///
+    /// The table keeps its id, its meta is bumped to a new seq.
+    RenameTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        new_db_name: String,
+        new_table_name: String,
+    },
+
     /// Update, remove or insert table options.
     ///
     /// This Cmd requires a present table to operate on.
@@ -71,6 +83,15 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the meta of a table, e.g. to change the schema.
+    ///
+    /// This Cmd requires a present table to operate on.
+    /// Otherwise an `UnknownTableId` is returned.
+    ///
+    /// With mismatched seq, it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    /// Otherwise it returns the TableMeta before and after update.
+    UpdateTableMeta(UpdateTableMetaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -123,6 +144,19 @@ impl fmt::Display for Cmd {
             } => {
                 write!(f, "delete_table:{}/{}-{}", tenant, db_name, table_name)
             }
+            Cmd::RenameTable {
+                tenant,
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+            } => {
+                write!(
+                    f,
+                    "rename_table:{}/{}-{}=>{}-{}",
+                    tenant, db_name, table_name, new_db_name, new_table_name
+                )
+            }
             Cmd::UpsertKV {
                 key,
                 seq,
@@ -142,6 +176,13 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableMeta(req) => {
+                write!(
+                    f,
+                    "update-table-meta: table-id:{}({:?}) = {}",
+                    req.table_id, req.seq, req.new_table_meta
+                )
+            }
         }
     }
 }
@@ -138,15 +138,20 @@ pub use table::DropTableReply;
 pub use table::DropTableReq;
 pub use table::GetTableReq;
 pub use table::ListTableReq;
+pub use table::RenameTableReply;
+pub use table::RenameTableReq;
 pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIndent;
+pub use table::UpdateTableMetaReply;
+pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
+pub use user_defined_function::PythonHandler;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
@@ -213,6 +213,19 @@ pub struct DropTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct DropTableReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReq {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub db: String,
+    pub table_name: String,
+    pub new_db: String,
+    pub new_table_name: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -242,6 +255,18 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+
+    /// Replaces the whole TableMeta, e.g. to change the schema.
+    pub new_table_meta: TableMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -127,7 +127,9 @@ impl AuthInfo {
         auth_type: &Option<String>,
         auth_string: &Option<String>,
     ) -> Result<AuthInfo, String> {
-        let default = AuthType::Sha256Password;
+        // The double sha1 password is the only hash method which can be verified by the
+        // mysql_native_password handshake of the MySQL protocol.
+        let default = AuthType::DoubleShaPassword;
         let auth_type = auth_type
             .clone()
             .map(|s| AuthType::from_str(&s))
@@ -216,6 +218,21 @@ impl AuthInfo {
         Ok(s)
     }
 
+    // mysql_native_password: the client sends nothing for an empty password, otherwise
+    //     SHA1( password ) XOR SHA1( salt <concat> SHA1( SHA1( password ) ) )
+    // the SHA1( password ) restored from it is checked against the stored double sha1.
+    fn auth_double_sha1_mysql(
+        password_hash: &[u8],
+        password_input: &[u8],
+        salt: &[u8],
+    ) -> Result<bool, ErrorCode> {
+        if password_input.is_empty() {
+            return Ok(password_hash == &double_sha1(b"")[..]);
+        }
+        let password_sha1 = AuthInfo::restore_sha1_mysql(salt, password_input, password_hash)?;
+        Ok(*password_hash == calc_sha1(&password_sha1))
+    }
+
     pub fn auth_mysql(&self, password_input: &[u8], salt: &[u8]) -> Result<bool, ErrorCode> {
         match self {
             AuthInfo::None => Ok(true),
@@ -223,10 +240,12 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => match t {
-                PasswordHashMethod::PlainText => Ok(p == password_input),
+                // The client sends the scramble of the password, not the password itself.
+                PasswordHashMethod::PlainText => {
+                    AuthInfo::auth_double_sha1_mysql(&double_sha1(p), password_input, salt)
+                }
                 PasswordHashMethod::DoubleSha1 => {
-                    let password_sha1 = AuthInfo::restore_sha1_mysql(salt, password_input, p)?;
-                    Ok(*p == calc_sha1(&password_sha1))
+                    AuthInfo::auth_double_sha1_mysql(p, password_input, salt)
                 }
                 PasswordHashMethod::Sha256 => Err(ErrorCode::AuthenticateFailure(
                     "login with sha256_password user for mysql protocol not supported yet.",
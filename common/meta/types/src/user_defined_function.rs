@@ -19,6 +19,14 @@ use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
+/// The handler `function` of the python `file`, the file is relative to the `python_udf_dir`.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PythonHandler {
+    pub file: String,
+    pub function: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
 pub struct UserDefinedFunction {
@@ -26,6 +34,11 @@ pub struct UserDefinedFunction {
     pub parameters: Vec<String>,
     pub definition: String,
     pub description: String,
+    // The SQL types of the parameters and the result, empty if the UDF is not typed.
+    pub parameter_types: Vec<String>,
+    pub return_type: Option<String>,
+    // The python UDF is implemented by the python handler, its definition is empty.
+    pub python_handler: Option<PythonHandler>,
 }
 
 impl UserDefinedFunction {
@@ -35,8 +48,25 @@ impl UserDefinedFunction {
             parameters,
             definition: definition.to_string(),
             description: description.to_string(),
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         }
     }
+
+    pub fn with_types(mut self, parameter_types: Vec<String>, return_type: &str) -> Self {
+        self.parameter_types = parameter_types;
+        self.return_type = Some(return_type.to_string());
+        self
+    }
+
+    pub fn with_python_handler(mut self, file: &str, function: &str) -> Self {
+        self.python_handler = Some(PythonHandler {
+            file: file.to_string(),
+            function: function.to_string(),
+        });
+        self
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserDefinedFunction {
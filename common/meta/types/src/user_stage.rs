@@ -78,9 +78,32 @@ impl Default for StageFileCompression {
     }
 }
 
+impl FromStr for StageFileCompression {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "AUTO" => Ok(StageFileCompression::Auto),
+            "GZIP" => Ok(StageFileCompression::Gzip),
+            "BZ2" => Ok(StageFileCompression::Bz2),
+            "BROTLI" => Ok(StageFileCompression::Brotli),
+            "ZSTD" => Ok(StageFileCompression::Zstd),
+            "DEFLATE" => Ok(StageFileCompression::Deflate),
+            "RAW_DEFLATE" => Ok(StageFileCompression::RawDeflate),
+            "LZO" => Ok(StageFileCompression::Lzo),
+            "SNAPPY" => Ok(StageFileCompression::Snappy),
+            "NONE" => Ok(StageFileCompression::None),
+            _ => Err(
+                "Unknown file compression type, must one of { AUTO | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAW_DEFLATE | LZO | SNAPPY | NONE }"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum StageFileFormatType {
     Csv,
+    Tsv,
     Json,
     Avro,
     Orc,
@@ -99,13 +122,14 @@ impl FromStr for StageFileFormatType {
     fn from_str(s: &str) -> std::result::Result<Self, String> {
         match s.to_uppercase().as_str() {
             "CSV" => Ok(StageFileFormatType::Csv),
-            "JSON" => Ok(StageFileFormatType::Json),
+            "TSV" => Ok(StageFileFormatType::Tsv),
+            "JSON" | "NDJSON" => Ok(StageFileFormatType::Json),
             "AVRO" => Ok(StageFileFormatType::Avro),
             "ORC" => Ok(StageFileFormatType::Orc),
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
             _ => Err(
-                "Unknown file format type, must one of { CSV | JSON | AVRO | ORC | PARQUET | XML }"
+                "Unknown file format type, must one of { CSV | TSV | JSON | AVRO | ORC | PARQUET | XML }"
                     .to_string(),
             ),
         }
@@ -120,7 +144,15 @@ pub struct FileFormatOptions {
     pub skip_header: i32,
     pub field_delimiter: String,
     pub record_delimiter: String,
+    // Character used to enclose strings, default '"'.
+    pub quote: String,
+    // Character used to escape the quote character inside quoted fields, empty means none.
+    pub escape: String,
     pub compression: StageFileCompression,
+    // Strings to be converted to SQL NULL.
+    pub null_if: Vec<String>,
+    // Whether an empty field is converted to SQL NULL.
+    pub empty_field_as_null: bool,
 }
 
 impl Default for FileFormatOptions {
@@ -130,7 +162,11 @@ impl Default for FileFormatOptions {
             record_delimiter: "\n".to_string(),
             field_delimiter: ",".to_string(),
             skip_header: 0,
+            quote: "\"".to_string(),
+            escape: "".to_string(),
             compression: StageFileCompression::default(),
+            null_if: vec![],
+            empty_field_as_null: false,
         }
     }
 }
@@ -187,6 +223,7 @@ impl FromStr for OnErrorMode {
             "" => Ok(OnErrorMode::None),
             "CONTINUE" => Ok(OnErrorMode::Continue),
             "SKIP_FILE" => Ok(OnErrorMode::SkipFile),
+            "ABORT" | "ABORT_STATEMENT" => Ok(OnErrorMode::AbortStatement),
             v => {
                 let num_str = v.replace("SKIP_FILE_", "");
                 let nums = num_str.parse::<u64>();
@@ -194,7 +231,7 @@ impl FromStr for OnErrorMode {
                     Ok(v) => { Ok(OnErrorMode::SkipFileNum(v)) }
                     Err(_) => {
                         Err(
-                            format!("Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT_STATEMENT }}", v)
+                            format!("Unknown OnError mode:{:?}, must one of {{ CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_STATEMENT }}", v)
                         )
                     }
                 }
@@ -203,11 +240,50 @@ impl FromStr for OnErrorMode {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum MatchByColumnName {
+    None,
+    CaseSensitive,
+    CaseInsensitive,
+}
+
+impl Default for MatchByColumnName {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl FromStr for MatchByColumnName {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "" | "NONE" => Ok(MatchByColumnName::None),
+            "CASE_SENSITIVE" => Ok(MatchByColumnName::CaseSensitive),
+            "CASE_INSENSITIVE" => Ok(MatchByColumnName::CaseInsensitive),
+            v => Err(format!(
+                "Unknown match_by_column_name:{:?}, must one of {{ CASE_SENSITIVE | CASE_INSENSITIVE | NONE }}",
+                v
+            )),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
 #[serde(default)]
 pub struct CopyOptions {
     pub on_error: OnErrorMode,
+    // Stop loading new files once the loaded bytes reach the limit, 0 means no limit.
     pub size_limit: usize,
+    // Load the columns by the names in the file header instead of the positions.
+    pub match_by_column_name: MatchByColumnName,
+    // The max number of files to load, 0 means no limit.
+    pub max_files: usize,
+    // Commit after each file instead of once for all the files.
+    pub commit_per_file: bool,
+    // Load the files even if they have been loaded before.
+    pub force: bool,
+    // Delete the files from the stage after they are loaded.
+    pub purge: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
@@ -221,6 +297,13 @@ pub struct UserStageInfo {
     pub comment: String,
 }
 
+impl UserStageInfo {
+    /// Prefix of an internal stage's files in the node storage.
+    pub fn get_prefix(&self) -> String {
+        format!("/stage/{}/", self.stage_name)
+    }
+}
+
 impl TryFrom<Vec<u8>> for UserStageInfo {
     type Error = ErrorCode;
 
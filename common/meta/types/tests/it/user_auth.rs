@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::AuthType;
+use sha1::Digest;
+use sha1::Sha1;
+
+// The scramble sent by the MySQL client in the mysql_native_password handshake.
+fn scramble(password: &[u8], salt: &[u8]) -> Vec<u8> {
+    if password.is_empty() {
+        return vec![];
+    }
+    let stage1: [u8; 20] = Sha1::digest(password).into();
+    let stage2: [u8; 20] = Sha1::digest(&stage1).into();
+    let mut m = Sha1::new();
+    m.update(salt);
+    m.update(&stage2);
+    let mask: [u8; 20] = m.finalize().into();
+    stage1.iter().zip(mask.iter()).map(|(a, b)| a ^ b).collect()
+}
+
+#[test]
+fn test_auth_mysql() -> Result<()> {
+    let salt = b"0123456789abcdefghij";
+
+    // The default auth type of `IDENTIFIED BY` can be verified by the MySQL protocol.
+    let auth_info = AuthInfo::create(&None, &Some("password".to_string())).unwrap();
+    assert_eq!(auth_info.get_type(), AuthType::DoubleShaPassword);
+
+    for auth_type in [AuthType::DoubleShaPassword, AuthType::PlaintextPassword] {
+        let auth_info = AuthInfo::new(auth_type.clone(), &Some("password".to_string())).unwrap();
+        assert!(auth_info.auth_mysql(&scramble(b"password", salt), salt)?);
+        assert!(!auth_info.auth_mysql(&scramble(b"wrong", salt), salt)?);
+        assert!(!auth_info.auth_mysql(&scramble(b"", salt), salt)?);
+        assert!(auth_info.auth_mysql(b"password", salt).is_err());
+
+        let auth_info = AuthInfo::new(auth_type, &Some("".to_string())).unwrap();
+        assert!(auth_info.auth_mysql(&scramble(b"", salt), salt)?);
+        assert!(!auth_info.auth_mysql(&scramble(b"password", salt), salt)?);
+    }
+
+    let auth_info = AuthInfo::new(AuthType::Sha256Password, &Some("password".to_string())).unwrap();
+    assert!(auth_info
+        .auth_mysql(&scramble(b"password", salt), salt)
+        .is_err());
+
+    assert!(AuthInfo::None.auth_mysql(&scramble(b"any", salt), salt)?);
+    Ok(())
+}
@@ -30,3 +30,36 @@ fn test_udf() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_typed_udf() -> Result<()> {
+    let udf = UserDefinedFunction::new("add_one", vec!["x".to_string()], "(x + 1)", "")
+        .with_types(vec!["INT".to_string()], "INT");
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+
+    // The UDF stored before the types were added is not typed.
+    let ser =
+        r#"{"name":"isnotnull","parameters":["p"],"definition":"not(isnull(p))","description":""}"#;
+    let de = UserDefinedFunction::try_from(ser.as_bytes().to_vec())?;
+    assert!(de.parameter_types.is_empty());
+    assert_eq!(de.return_type, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_python_udf() -> Result<()> {
+    let udf = UserDefinedFunction::new("add_one", vec!["x".to_string()], "", "")
+        .with_types(vec!["BIGINT".to_string()], "BIGINT")
+        .with_python_handler("udfs.py", "add_one");
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert_eq!(de.python_handler.unwrap().function, "add_one");
+
+    Ok(())
+}
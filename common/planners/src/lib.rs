@@ -20,6 +20,7 @@ mod plan_copy;
 mod plan_database_create;
 mod plan_database_drop;
 mod plan_database_show_create;
+mod plan_delete;
 mod plan_empty;
 mod plan_explain;
 mod plan_expression;
@@ -56,6 +57,7 @@ mod plan_remote;
 mod plan_role_create;
 mod plan_role_drop;
 mod plan_select;
+mod plan_semi_join;
 mod plan_setting;
 mod plan_show;
 mod plan_show_databases;
@@ -70,12 +72,16 @@ mod plan_show_users;
 mod plan_sink;
 mod plan_sort;
 mod plan_subqueries_set;
+mod plan_table_alter;
 mod plan_table_create;
 mod plan_table_describe;
 mod plan_table_drop;
 mod plan_table_optimize;
+mod plan_table_rename;
 mod plan_table_show_create;
 mod plan_table_truncate;
+mod plan_unnest;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -86,20 +92,26 @@ mod plan_user_stage;
 mod plan_user_stage_create;
 mod plan_user_stage_describe;
 mod plan_user_stage_drop;
+mod plan_user_stage_list;
 mod plan_user_udf_alter;
 mod plan_user_udf_create;
 mod plan_user_udf_drop;
+mod plan_view_create;
+mod plan_view_drop;
+mod plan_window;
 
 pub use plan_admin_use_tenant::AdminUseTenantPlan;
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_broadcast::BroadcastPlan;
+pub use plan_copy::CopyIntoStagePlan;
 pub use plan_copy::CopyPlan;
 pub use plan_copy::ValidationMode;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
 pub use plan_database_show_create::ShowCreateDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
@@ -116,6 +128,8 @@ pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_window_exprs;
+pub use plan_expression_common::find_window_exprs_in_expr;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -161,6 +175,7 @@ pub use plan_remote::RemotePlan;
 pub use plan_role_create::CreateRolePlan;
 pub use plan_role_drop::DropRolePlan;
 pub use plan_select::SelectPlan;
+pub use plan_semi_join::SemiJoinPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
 pub use plan_show::PlanShowKind;
@@ -178,14 +193,21 @@ pub use plan_sink::SinkPlan;
 pub use plan_sink::SINK_SCHEMA;
 pub use plan_sort::SortPlan;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_alter::AlterTableOperation;
+pub use plan_table_alter::AlterTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_describe::DescribeTablePlan;
+pub use plan_table_drop::DropTableEntity;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
+pub use plan_table_rename::RenameTableEntity;
+pub use plan_table_rename::RenameTablePlan;
 pub use plan_table_show_create::ShowCreateTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
+pub use plan_unnest::UnnestPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -196,6 +218,10 @@ pub use plan_user_stage::UserStagePlan;
 pub use plan_user_stage_create::CreateUserStagePlan;
 pub use plan_user_stage_describe::DescribeUserStagePlan;
 pub use plan_user_stage_drop::DropUserStagePlan;
+pub use plan_user_stage_list::ListUserStagePlan;
 pub use plan_user_udf_alter::AlterUserUDFPlan;
 pub use plan_user_udf_create::CreateUserUDFPlan;
 pub use plan_user_udf_drop::DropUserUDFPlan;
+pub use plan_view_create::CreateViewPlan;
+pub use plan_view_drop::DropViewPlan;
+pub use plan_window::WindowPlan;
@@ -19,6 +19,7 @@ use std::str::FromStr;
 use common_datavalues::DataSchemaRef;
 use common_meta_types::MetaId;
 
+use crate::PlanNode;
 use crate::UserStagePlan;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone, Debug)]
@@ -61,6 +62,7 @@ pub struct CopyPlan {
     pub stage_plan: UserStagePlan,
     pub validation_mode: ValidationMode,
     pub files: Vec<String>,
+    pub pattern: String,
 }
 
 impl CopyPlan {
@@ -77,6 +79,34 @@ impl Debug for CopyPlan {
         if !self.files.is_empty() {
             write!(f, " ,files:{:?}", self.files)?;
         }
+        if !self.pattern.is_empty() {
+            write!(f, " ,pattern:{:?}", self.pattern)?;
+        }
         write!(f, " ,validation_mode:{:?}", self.validation_mode)
     }
 }
+
+// Unload the result of the query into the files of the stage.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct CopyIntoStagePlan {
+    pub stage_plan: UserStagePlan,
+    pub query: Box<PlanNode>,
+    // Split the output into files of at most this size in bytes, 0 means no limit.
+    pub max_file_size: usize,
+}
+
+impl CopyIntoStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.stage_plan.schema.clone()
+    }
+}
+
+impl Debug for CopyIntoStagePlan {
+    // Ignore the schema and the query.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let stage_name = &self.stage_plan.stage_info.stage_name;
+        write!(f, "Copy into @{:}", stage_name)?;
+        write!(f, " ,{:?}", self.stage_plan)?;
+        write!(f, " ,max_file_size:{:?}", self.max_file_size)
+    }
+}
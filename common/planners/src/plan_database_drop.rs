@@ -23,6 +23,8 @@ pub struct DropDatabasePlan {
     pub if_exists: bool,
     pub tenant: String,
     pub db: String,
+    /// Drop the tables of the database too, instead of refusing to drop a non-empty database.
+    pub cascade: bool,
 }
 
 impl DropDatabasePlan {
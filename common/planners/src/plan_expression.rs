@@ -21,6 +21,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::scalars::is_math_domain_function;
+use common_functions::windows::RankingFunction;
 use once_cell::sync::Lazy;
 
 use crate::plan_expression_common::ExpressionDataTypeVisitor;
@@ -94,6 +96,16 @@ pub enum Expression {
         args: Vec<Expression>,
     },
 
+    /// WindowFunction evaluated over the partition of each row,
+    /// such as `rank() OVER (PARTITION BY a ORDER BY b)`.
+    WindowFunction {
+        op: String,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        /// The `Expression::Sort` of the rows in each partition.
+        order_by: Vec<Expression>,
+    },
+
     /// A sort expression, that can be used to sort values.
     Sort {
         /// The expression to sort on
@@ -186,6 +198,20 @@ impl Expression {
                 match OP_SET.get(&op.to_lowercase().as_ref()) {
                     Some(_) => format!("{}()", op),
                     None => {
+                        let args = match is_math_domain_function(op) {
+                            // The trailing math_domain_error mode is from the session, not the user.
+                            true => match args.split_last() {
+                                Some((
+                                    Expression::Literal {
+                                        value: DataValue::String(_),
+                                        ..
+                                    },
+                                    rest,
+                                )) => rest,
+                                _ => args.as_slice(),
+                            },
+                            false => args.as_slice(),
+                        };
                         let args_column_name =
                             args.iter().map(Expression::column_name).collect::<Vec<_>>();
 
@@ -216,6 +242,12 @@ impl Expression {
                     false => format!("{}({})", prefix, args_column_name.join(", ")),
                 }
             }
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+            } => window_function_name(op, args, partition_by, order_by),
             Expression::Sort { expr, .. } => expr.column_name(),
             Expression::Cast {
                 expr, data_type, ..
@@ -324,6 +356,15 @@ impl Expression {
         }
     }
 
+    pub fn to_ranking_function(&self) -> Result<RankingFunction> {
+        match self {
+            Expression::WindowFunction { op, .. } => RankingFunction::try_create(op),
+            _ => Err(ErrorCode::LogicalError(
+                "Expression must be window function",
+            )),
+        }
+    }
+
     pub fn to_aggregate_function_names(&self) -> Result<Vec<String>> {
         match self {
             Expression::AggregateFunction { args, .. } => {
@@ -358,6 +399,58 @@ impl Expression {
     }
 }
 
+// `op(args) over (partition by p order by o desc)`, the null order is only named if it is not
+// the default one of the direction.
+fn window_function_name(
+    op: &str,
+    args: &[Expression],
+    partition_by: &[Expression],
+    order_by: &[Expression],
+) -> String {
+    let args_column_name = args.iter().map(Expression::column_name).collect::<Vec<_>>();
+    let mut window = vec![];
+    if !partition_by.is_empty() {
+        let partition_by = partition_by
+            .iter()
+            .map(Expression::column_name)
+            .collect::<Vec<_>>();
+        window.push(format!("partition by {}", partition_by.join(", ")));
+    }
+    if !order_by.is_empty() {
+        let order_by = order_by
+            .iter()
+            .map(|expr| match expr {
+                Expression::Sort {
+                    expr,
+                    asc,
+                    nulls_first,
+                    ..
+                } => {
+                    let mut name = expr.column_name();
+                    if !*asc {
+                        name.push_str(" desc");
+                    }
+                    if nulls_first != asc {
+                        name.push_str(match nulls_first {
+                            true => " nulls first",
+                            false => " nulls last",
+                        });
+                    }
+                    name
+                }
+                _ => expr.column_name(),
+            })
+            .collect::<Vec<_>>();
+        window.push(format!("order by {}", order_by.join(", ")));
+    }
+    format!(
+        "{}({}) over ({})",
+        op,
+        args_column_name.join(", "),
+        window.join(" ")
+    )
+}
+
 // Also used as expression column name
 impl fmt::Debug for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -413,6 +506,16 @@ impl fmt::Debug for Expression {
                 Ok(())
             }
 
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+            } => write!(
+                f,
+                "{}",
+                window_function_name(op, args, partition_by, order_by)
+            ),
             Expression::Sort { expr, .. } => write!(f, "{:?}", expr),
             Expression::Wildcard => write!(f, "*"),
             Expression::Cast {
@@ -45,6 +45,20 @@ pub fn find_aggregate_exprs(exprs: &[Expression]) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::WindowFunction`. They are returned in order of
+/// occurrence (depth first), with duplicates omitted.
+pub fn find_window_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
+pub fn find_window_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
+    find_exprs_in_expr(expr, &|nest_exprs| {
+        matches!(nest_exprs, Expression::WindowFunction { .. })
+    })
+}
+
 pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     find_exprs_in_expr(expr, &|nest_exprs| {
         matches!(nest_exprs, Expression::AggregateFunction { .. })
@@ -303,6 +317,27 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
                     .collect::<Result<Vec<Expression>>>()?,
             }),
 
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+            } => Ok(Expression::WindowFunction {
+                op: op.clone(),
+                args: args
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+                partition_by: partition_by
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+                order_by: order_by
+                    .iter()
+                    .map(|e| clone_with_replacement(e, replacement_fn))
+                    .collect::<Result<Vec<Expression>>>()?,
+            }),
+
             Expression::Sort {
                 expr: nested_expr,
                 asc,
@@ -470,6 +505,27 @@ impl ExpressionVisitor for ExpressionDataTypeVisitor {
                 self.stack.push(return_type);
                 Ok(self)
             }
+            expr @ Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                // Pop arguments, partition and order expressions.
+                let size = args.len() + partition_by.len() + order_by.len();
+                for index in 0..size {
+                    if self.stack.pop().is_none() {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "Expected {} arguments, actual {}.",
+                            size, index
+                        )));
+                    }
+                }
+
+                let ranking_function = expr.to_ranking_function()?;
+                self.stack.push(ranking_function.return_type());
+                Ok(self)
+            }
             Expression::Cast { data_type, .. } => {
                 let inner_type = match self.stack.pop() {
                     None => Err(ErrorCode::LogicalError(
@@ -124,6 +124,22 @@ pub trait ExpressionRewriter: Sized {
         })
     }
 
+    fn mutate_window_function(
+        &mut self,
+        name: &str,
+        args: Vec<Expression>,
+        partition_by: Vec<Expression>,
+        order_by: Vec<Expression>,
+        _origin_expr: &Expression,
+    ) -> Result<Expression> {
+        Ok(Expression::WindowFunction {
+            op: name.to_string(),
+            args,
+            partition_by,
+            order_by,
+        })
+    }
+
     fn mutate_cast(
         &mut self,
         typ: &DataTypePtr,
@@ -288,6 +304,39 @@ impl<T: ExpressionRewriter> ExpressionVisitor for ExpressionRewriteVisitor<T> {
                 self.stack.push(new_expr);
                 Ok(self)
             }
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+            } => {
+                let size = args.len() + partition_by.len() + order_by.len();
+                let mut args_expr = Vec::with_capacity(size);
+
+                for index in 0..size {
+                    match self.stack.pop() {
+                        None => {
+                            return Err(ErrorCode::LogicalError(format!(
+                                "Expected {} arguments, actual {}.",
+                                size, index
+                            )));
+                        }
+                        Some(arg_type) => args_expr.push(arg_type),
+                    };
+                }
+
+                let order_by_expr = args_expr.split_off(args.len() + partition_by.len());
+                let partition_by_expr = args_expr.split_off(args.len());
+                let new_expr = self.inner.mutate_window_function(
+                    op,
+                    args_expr,
+                    partition_by_expr,
+                    order_by_expr,
+                    expr,
+                )?;
+                self.stack.push(new_expr);
+                Ok(self)
+            }
             Expression::Cast {
                 data_type,
                 is_nullable,
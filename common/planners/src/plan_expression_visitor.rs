@@ -66,6 +66,16 @@ pub trait ExpressionVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                Expression::WindowFunction {
+                                    args,
+                                    partition_by,
+                                    order_by,
+                                    ..
+                                } => {
+                                    for arg in args.iter().chain(partition_by).chain(order_by) {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 Expression::Cast { expr, .. } => {
                                     stack.push(RecursionProcessing::Call(expr));
                                 }
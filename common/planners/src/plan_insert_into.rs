@@ -32,6 +32,8 @@ pub struct InsertPlan {
     pub table_id: MetaId,
     pub schema: DataSchemaRef,
     pub overwrite: bool,
+    /// REPLACE INTO, the rows having the same primary keys as the inserted ones are replaced.
+    pub replace: bool,
     pub source: InsertInputSource,
 }
 
@@ -24,6 +24,8 @@ pub struct LimitPlan {
     pub n: Option<usize>,
     /// The offset, default 0.
     pub offset: usize,
+    /// Keep the rows which tie with the last one on the sort keys, FETCH FIRST n ROWS WITH TIES.
+    pub with_ties: bool,
     /// The logical plan
     pub input: Arc<PlanNode>,
 }
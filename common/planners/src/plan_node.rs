@@ -19,9 +19,11 @@ use common_datavalues::DataSchemaRef;
 use crate::AdminUseTenantPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
 use crate::BroadcastPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -29,6 +31,8 @@ use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
@@ -37,6 +41,7 @@ use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExpressionPlan;
@@ -47,12 +52,15 @@ use crate::InsertPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListUserStagePlan;
 use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
+use crate::SemiJoinPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -62,7 +70,10 @@ use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
 use crate::TruncateTablePlan;
+use crate::UnnestPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowPlan;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -81,6 +92,9 @@ pub enum PlanNode {
     Sort(SortPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
+    Unnest(UnnestPlan),
+    Window(WindowPlan),
+    SemiJoin(SemiJoinPlan),
     ReadSource(ReadDataSourcePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Sink(SinkPlan),
@@ -94,8 +108,15 @@ pub enum PlanNode {
     // Insert.
     Insert(InsertPlan),
 
+    // Delete.
+    Delete(DeletePlan),
+
+    // Update.
+    Update(UpdatePlan),
+
     // Copy.
     Copy(CopyPlan),
+    CopyIntoStage(CopyIntoStagePlan),
 
     // Show.
     Show(ShowPlan),
@@ -108,11 +129,17 @@ pub enum PlanNode {
     // Table.
     CreateTable(CreateTablePlan),
     DropTable(DropTablePlan),
+    RenameTable(RenameTablePlan),
+    AlterTable(AlterTablePlan),
     TruncateTable(TruncateTablePlan),
     OptimizeTable(OptimizeTablePlan),
     DescribeTable(DescribeTablePlan),
     ShowCreateTable(ShowCreateTablePlan),
 
+    // View.
+    CreateView(CreateViewPlan),
+    DropView(DropViewPlan),
+
     // User.
     CreateUser(CreateUserPlan),
     AlterUser(AlterUserPlan),
@@ -128,6 +155,7 @@ pub enum PlanNode {
     CreateUserStage(CreateUserStagePlan),
     DropUserStage(DropUserStagePlan),
     DescribeUserStage(DescribeUserStagePlan),
+    ListUserStage(ListUserStagePlan),
 
     // UDF.
     CreateUserUDF(CreateUserUDFPlan),
@@ -164,6 +192,9 @@ impl PlanNode {
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
+            PlanNode::Unnest(v) => v.schema(),
+            PlanNode::Window(v) => v.schema(),
+            PlanNode::SemiJoin(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
@@ -178,8 +209,15 @@ impl PlanNode {
             // Insert.
             PlanNode::Insert(v) => v.schema(),
 
+            // Delete.
+            PlanNode::Delete(v) => v.schema(),
+
+            // Update.
+            PlanNode::Update(v) => v.schema(),
+
             // Copy.
             PlanNode::Copy(v) => v.schema(),
+            PlanNode::CopyIntoStage(v) => v.schema(),
 
             // Show.
             PlanNode::Show(v) => v.schema(),
@@ -192,11 +230,17 @@ impl PlanNode {
             // Table.
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
+            PlanNode::RenameTable(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
             PlanNode::OptimizeTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
 
+            // View.
+            PlanNode::CreateView(v) => v.schema(),
+            PlanNode::DropView(v) => v.schema(),
+
             // User.
             PlanNode::CreateUser(v) => v.schema(),
             PlanNode::AlterUser(v) => v.schema(),
@@ -212,6 +256,7 @@ impl PlanNode {
             PlanNode::CreateUserStage(v) => v.schema(),
             PlanNode::DropUserStage(v) => v.schema(),
             PlanNode::DescribeUserStage(v) => v.schema(),
+            PlanNode::ListUserStage(v) => v.schema(),
 
             // UDF.
             PlanNode::CreateUserUDF(v) => v.schema(),
@@ -247,6 +292,9 @@ impl PlanNode {
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
+            PlanNode::Unnest(_) => "UnnestPlan",
+            PlanNode::Window(_) => "WindowPlan",
+            PlanNode::SemiJoin(_) => "SemiJoinPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
@@ -261,8 +309,15 @@ impl PlanNode {
             // Insert.
             PlanNode::Insert(_) => "InsertPlan",
 
+            // Delete.
+            PlanNode::Delete(_) => "DeletePlan",
+
+            // Update.
+            PlanNode::Update(_) => "UpdatePlan",
+
             // Copy.
             PlanNode::Copy(_) => "CopyPlan",
+            PlanNode::CopyIntoStage(_) => "CopyIntoStagePlan",
 
             // Show.
             PlanNode::Show(_) => "ShowPlan",
@@ -275,11 +330,17 @@ impl PlanNode {
             // Table.
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::RenameTable(_) => "RenameTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
             PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
 
+            // View.
+            PlanNode::CreateView(_) => "CreateViewPlan",
+            PlanNode::DropView(_) => "DropViewPlan",
+
             // User.
             PlanNode::CreateUser(_) => "CreateUser",
             PlanNode::AlterUser(_) => "AlterUser",
@@ -295,6 +356,7 @@ impl PlanNode {
             PlanNode::CreateUserStage(_) => "CreateUserStagePlan",
             PlanNode::DropUserStage(_) => "DropUserStagePlan",
             PlanNode::DescribeUserStage(_) => "DescribeUserStagePlan",
+            PlanNode::ListUserStage(_) => "ListUserStagePlan",
 
             // UDF.
             PlanNode::CreateUserUDF(_) => "CreateUserUDFPlan",
@@ -326,6 +388,9 @@ impl PlanNode {
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
+            PlanNode::Unnest(v) => vec![v.input.clone()],
+            PlanNode::Window(v) => vec![v.input.clone()],
+            PlanNode::SemiJoin(v) => vec![v.input.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -36,7 +38,10 @@ use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
+use crate::SemiJoinPlan;
 use crate::SortPlan;
+use crate::UnnestPlan;
+use crate::WindowPlan;
 
 pub enum AggregateMode {
     Partial,
@@ -227,15 +232,27 @@ impl PlanBuilder {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
             n: Some(n),
             offset: 0,
+            with_ties: false,
             input: Arc::new(self.plan.clone()),
         })))
     }
 
     /// Apply a limit offset
     pub fn limit_offset(&self, n: Option<usize>, offset: usize) -> Result<Self> {
+        self.limit_offset_with_ties(n, offset, false)
+    }
+
+    /// Apply a limit offset, the rows which tie with the last one on the sort keys are kept if with ties
+    pub fn limit_offset_with_ties(
+        &self,
+        n: Option<usize>,
+        offset: usize,
+        with_ties: bool,
+    ) -> Result<Self> {
         Ok(Self::from(&PlanNode::Limit(LimitPlan {
             n,
             offset,
+            with_ties,
             input: Arc::new(self.plan.clone()),
         })))
     }
@@ -248,6 +265,82 @@ impl PlanBuilder {
         })))
     }
 
+    /// Apply a lateral unnest, one row per element of the array column
+    pub fn unnest(&self, array_column: &str, element_column: &str) -> Result<Self> {
+        let input_schema = self.plan.schema();
+        let array_field = input_schema.field_with_name(array_column)?;
+        let array_type = remove_nullable(array_field.data_type());
+        let inner_type = match array_type.as_any().downcast_ref::<ArrayType>() {
+            Some(array_type) => array_type.inner_type().clone(),
+            None => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "UNNEST argument must be Array type, but got {:?}",
+                    array_field.data_type()
+                )))
+            }
+        };
+
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(element_column, inner_type));
+
+        Ok(Self::from(&PlanNode::Unnest(UnnestPlan {
+            array_column: array_column.to_string(),
+            element_column: element_column.to_string(),
+            input: Arc::new(self.plan.clone()),
+            schema: DataSchemaRefExt::create(fields),
+        })))
+    }
+
+    /// Apply a window function, its value is appended to the input columns
+    pub fn window(&self, window_func: &Expression) -> Result<Self> {
+        let input_schema = self.plan.schema();
+        let mut fields = input_schema.fields().clone();
+        fields.push(window_func.to_data_field(&input_schema)?);
+
+        Ok(Self::from(&PlanNode::Window(WindowPlan {
+            window_func: window_func.clone(),
+            input: Arc::new(self.plan.clone()),
+            schema: DataSchemaRefExt::create(fields),
+        })))
+    }
+
+    /// Apply a semi join, or an anti join, of the keys with the first columns of the subquery
+    pub fn semi_join(&self, anti: bool, keys: &[Expression], subquery: Expression) -> Result<Self> {
+        let query_plan = match &subquery {
+            Expression::Subquery { query_plan, .. } => query_plan.clone(),
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Semi join must be with Expression::Subquery, but got: {:?}",
+                    other
+                )))
+            }
+        };
+
+        let input_schema = self.plan.schema();
+        let subquery_schema = query_plan.schema();
+        if subquery_schema.fields().len() < keys.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Semi join has {} keys, but the subquery returns {} columns",
+                keys.len(),
+                subquery_schema.fields().len()
+            )));
+        }
+
+        for (key, field) in keys.iter().zip(subquery_schema.fields()) {
+            validate_expression(key)?;
+            let key_type = remove_nullable(&key.to_data_type(&input_schema)?);
+            compare_coercion(&key_type, &remove_nullable(field.data_type()))?;
+        }
+
+        Ok(Self::from(&PlanNode::SemiJoin(SemiJoinPlan {
+            anti,
+            keys: keys.to_vec(),
+            subquery: subquery.clone(),
+            input: self.wrap_subquery_plan(&[subquery])?,
+            schema: input_schema,
+        })))
+    }
+
     pub fn select(&self) -> Result<Self> {
         Ok(Self::from(&PlanNode::Select(SelectPlan {
             input: Arc::new(self.plan.clone()),
@@ -17,20 +17,27 @@ use std::fmt::Formatter;
 
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTableOperation;
+use crate::AlterTablePlan;
 use crate::BroadcastPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
 use crate::CreateTablePlan;
+use crate::CreateViewPlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
 use crate::DropTablePlan;
+use crate::DropViewPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::RenameTablePlan;
+use crate::SemiJoinPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
@@ -68,15 +75,28 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
+            PlanNode::Window(plan) => write!(f, "Window: {:?}", plan.window_func),
+            PlanNode::SemiJoin(plan) => Self::format_semi_join(f, plan),
+            PlanNode::Unnest(plan) => write!(
+                f,
+                "Unnest: {} as {}",
+                plan.array_column, plan.element_column
+            ),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
+            PlanNode::Sink(plan) => write!(f, "Sink: table={}", plan.table_info.desc),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
             PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+            PlanNode::CreateView(plan) => Self::format_create_view(f, plan),
+            PlanNode::DropView(plan) => Self::format_drop_view(f, plan),
+            PlanNode::RenameTable(plan) => Self::format_rename_table(f, plan),
+            PlanNode::AlterTable(plan) => Self::format_alter_table(f, plan),
             PlanNode::CreateRole(plan) => Self::format_create_role(f, plan),
             PlanNode::DropRole(plan) => Self::format_drop_role(f, plan),
             PlanNode::Copy(plan) => Self::format_copy(f, plan),
+            PlanNode::CopyIntoStage(plan) => Self::format_copy_into_stage(f, plan),
             _ => {
                 let mut printed = true;
 
@@ -194,7 +214,21 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             (Some(n), 0) => write!(f, "Limit: {}", n),
             (Some(n), offset) => write!(f, "Limit: {}, {}", n, offset),
             (None, offset) => write!(f, "Limit: all, {}", offset),
+        }?;
+
+        if plan.with_ties {
+            write!(f, " with ties")?;
         }
+
+        fmt::Result::Ok(())
+    }
+
+    fn format_semi_join(f: &mut Formatter, plan: &SemiJoinPlan) -> fmt::Result {
+        let name = match plan.anti {
+            true => "AntiJoin",
+            false => "SemiJoin",
+        };
+        write!(f, "{}: keys={:?}, {:?}", name, plan.keys, plan.subquery)
     }
 
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
@@ -297,11 +331,63 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
     }
 
     fn format_drop_table(f: &mut Formatter, plan: &DropTablePlan) -> fmt::Result {
-        write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
+        write!(f, "Drop table")?;
+        for (i, entity) in plan.entities.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, " {:}.{:}", entity.db, entity.table)?;
+        }
+        write!(f, ", if_exists:{:}", plan.if_exists)
+    }
+
+    fn format_create_view(f: &mut Formatter, plan: &CreateViewPlan) -> fmt::Result {
+        write!(f, "Create view {:}.{:}", plan.db, plan.viewname)?;
+        write!(f, " {:},", plan.view_schema)?;
+        write!(f, " if_not_exists:{:},", plan.if_not_exists)?;
+        write!(f, " or_replace:{:},", plan.or_replace)?;
+        write!(f, " as: {:}", plan.subquery)
+    }
+
+    fn format_drop_view(f: &mut Formatter, plan: &DropViewPlan) -> fmt::Result {
+        write!(f, "Drop view {:}.{:},", plan.db, plan.viewname)?;
         write!(f, " if_exists:{:}", plan.if_exists)
     }
 
+    fn format_rename_table(f: &mut Formatter, plan: &RenameTablePlan) -> fmt::Result {
+        write!(f, "Rename table")?;
+        for (i, entity) in plan.entities.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(
+                f,
+                " {:}.{:} to {:}.{:}",
+                entity.db, entity.table_name, entity.new_db, entity.new_table_name
+            )?;
+        }
+        write!(f, ", if_exists:{:}", plan.if_exists)
+    }
+
+    fn format_alter_table(f: &mut Formatter, plan: &AlterTablePlan) -> fmt::Result {
+        write!(f, "Alter table {:}.{:}", plan.db, plan.table)?;
+        match &plan.operation {
+            AlterTableOperation::AddColumn(field) => write!(
+                f,
+                " add column {:} {:}",
+                field.name(),
+                field.data_type().name()
+            )?,
+            AlterTableOperation::DropColumn(name) => write!(f, " drop column {:}", name)?,
+        }
+        write!(f, ", if_exists:{:}", plan.if_exists)
+    }
+
     fn format_copy(f: &mut Formatter, plan: &CopyPlan) -> fmt::Result {
         write!(f, "{:?}", plan)
     }
+
+    fn format_copy_into_stage(f: &mut Formatter, plan: &CopyIntoStagePlan) -> fmt::Result {
+        write!(f, "{:?}", plan)
+    }
 }
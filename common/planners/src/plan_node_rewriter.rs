@@ -26,8 +26,10 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AdminUseTenantPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -35,6 +37,8 @@ use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
@@ -43,6 +47,7 @@ use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
@@ -56,14 +61,17 @@ use crate::InsertPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListUserStagePlan;
 use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
+use crate::SemiJoinPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -72,7 +80,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnnestPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowPlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
 /// By default, a `PlanRewriter` will traverse the plan tree in pre-order and return rewritten plan tree.
@@ -110,6 +121,9 @@ pub trait PlanRewriter: Sized {
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
+            PlanNode::Unnest(plan) => self.rewrite_unnest(plan),
+            PlanNode::Window(plan) => self.rewrite_window(plan),
+            PlanNode::SemiJoin(plan) => self.rewrite_semi_join(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.rewrite_sink(plan),
@@ -123,8 +137,15 @@ pub trait PlanRewriter: Sized {
             // Insert.
             PlanNode::Insert(plan) => self.rewrite_insert_into(plan),
 
+            // Delete.
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+
+            // Update.
+            PlanNode::Update(plan) => self.rewrite_update(plan),
+
             // Copy.
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.rewrite_copy_into_stage(plan),
 
             // Show.
             PlanNode::Show(plan) => self.rewrite_show(plan),
@@ -137,11 +158,17 @@ pub trait PlanRewriter: Sized {
             // Table.
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.rewrite_rename_table(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
             PlanNode::DescribeTable(plan) => self.rewrite_describe_table(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
 
+            // View.
+            PlanNode::CreateView(plan) => self.rewrite_create_view(plan),
+            PlanNode::DropView(plan) => self.rewrite_drop_view(plan),
+
             // User.
             PlanNode::CreateUser(plan) => self.create_user(plan),
             PlanNode::AlterUser(plan) => self.alter_user(plan),
@@ -157,6 +184,7 @@ pub trait PlanRewriter: Sized {
             PlanNode::CreateUserStage(plan) => self.rewrite_create_user_stage(plan),
             PlanNode::DropUserStage(plan) => self.rewrite_drop_user_stage(plan),
             PlanNode::DescribeUserStage(plan) => self.rewrite_describe_user_stage(plan),
+            PlanNode::ListUserStage(plan) => self.rewrite_list_user_stage(plan),
 
             // UDF.
             PlanNode::CreateUserUDF(plan) => self.rewrite_create_user_udf(plan),
@@ -300,7 +328,7 @@ pub trait PlanRewriter: Sized {
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         PlanBuilder::from(&new_input)
-            .limit_offset(plan.n, plan.offset)?
+            .limit_offset_with_ties(plan.n, plan.offset, plan.with_ties)?
             .build()
     }
 
@@ -311,6 +339,29 @@ pub trait PlanRewriter: Sized {
             .build()
     }
 
+    fn rewrite_unnest(&mut self, plan: &UnnestPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .unnest(&plan.array_column, &plan.element_column)?
+            .build()
+    }
+
+    fn rewrite_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .window(&plan.window_func)?
+            .build()
+    }
+
+    fn rewrite_semi_join(&mut self, plan: &SemiJoinPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_keys = self.rewrite_exprs(&new_input.schema(), &plan.keys)?;
+        let new_subquery = self.rewrite_expr(&new_input.schema(), &plan.subquery)?;
+        PlanBuilder::from(&new_input)
+            .semi_join(plan.anti, &new_keys, new_subquery)?
+            .build()
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         Ok(PlanNode::ReadSource(plan.clone()))
     }
@@ -360,10 +411,22 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DescribeUserStage(plan.clone()))
     }
 
+    fn rewrite_list_user_stage(&mut self, plan: &ListUserStagePlan) -> Result<PlanNode> {
+        Ok(PlanNode::ListUserStage(plan.clone()))
+    }
+
     fn rewrite_drop_table(&mut self, plan: &DropTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropTable(plan.clone()))
     }
 
+    fn rewrite_rename_table(&mut self, plan: &RenameTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RenameTable(plan.clone()))
+    }
+
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
     fn rewrite_drop_database(&mut self, plan: &DropDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropDatabase(plan.clone()))
     }
@@ -372,14 +435,34 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Insert(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
     fn rewrite_copy(&mut self, plan: &CopyPlan) -> Result<PlanNode> {
         Ok(PlanNode::Copy(plan.clone()))
     }
 
+    fn rewrite_copy_into_stage(&mut self, plan: &CopyIntoStagePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyIntoStage(plan.clone()))
+    }
+
     fn rewrite_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
 
+    fn rewrite_create_view(&mut self, plan: &CreateViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateView(plan.clone()))
+    }
+
+    fn rewrite_drop_view(&mut self, plan: &DropViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropView(plan.clone()))
+    }
+
     fn rewrite_truncate_table(&mut self, plan: &TruncateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
@@ -591,6 +674,27 @@ impl RewriteHelper {
                 }
             }
 
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                order_by,
+            } => {
+                let rewrite = |exprs: &[Expression], data: &mut QueryAliasData| {
+                    exprs
+                        .iter()
+                        .map(|v| RewriteHelper::expr_rewrite_alias(v, data))
+                        .collect::<Result<Vec<Expression>>>()
+                };
+
+                Ok(Expression::WindowFunction {
+                    op: op.clone(),
+                    args: rewrite(args, data)?,
+                    partition_by: rewrite(partition_by, data)?,
+                    order_by: rewrite(order_by, data)?,
+                })
+            }
+
             Expression::Alias(alias, plan) => {
                 if data.inside_aliases.contains(alias) {
                     return Result::Err(ErrorCode::SyntaxException(format!(
@@ -687,6 +791,12 @@ impl RewriteHelper {
             }
             Expression::ScalarFunction { args, .. } => args.clone(),
             Expression::AggregateFunction { args, .. } => args.clone(),
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => [args.as_slice(), partition_by, order_by].concat(),
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
@@ -725,6 +835,19 @@ impl RewriteHelper {
                 }
                 v
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                let mut v = vec![];
+                for arg in args.iter().chain(partition_by).chain(order_by) {
+                    let mut col = Self::expression_plan_columns(arg)?;
+                    v.append(&mut col);
+                }
+                v
+            }
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
@@ -797,6 +920,21 @@ impl RewriteHelper {
                 params: params.clone(),
                 args: expressions.to_vec(),
             },
+            Expression::WindowFunction {
+                op,
+                args,
+                partition_by,
+                ..
+            } => {
+                let (args, rest) = expressions.split_at(args.len());
+                let (partition_by, order_by) = rest.split_at(partition_by.len());
+                Expression::WindowFunction {
+                    op: op.clone(),
+                    args: args.to_vec(),
+                    partition_by: partition_by.to_vec(),
+                    order_by: order_by.to_vec(),
+                }
+            }
             other => other.clone(),
         }
     }
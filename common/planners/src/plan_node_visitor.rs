@@ -19,8 +19,10 @@ use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AdminUseTenantPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
 use crate::AlterUserUDFPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateRolePlan;
@@ -28,6 +30,8 @@ use crate::CreateTablePlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
+use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
 use crate::DropDatabasePlan;
@@ -36,6 +40,7 @@ use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
 use crate::DropUserUDFPlan;
+use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::Expression;
@@ -47,13 +52,16 @@ use crate::InsertPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListUserStagePlan;
 use crate::OptimizeTablePlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
+use crate::SemiJoinPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
@@ -62,7 +70,10 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UnnestPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
+use crate::WindowPlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
 ///
@@ -122,6 +133,9 @@ pub trait PlanVisitor {
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
+            PlanNode::Unnest(plan) => self.visit_unnest(plan),
+            PlanNode::Window(plan) => self.visit_window(plan),
+            PlanNode::SemiJoin(plan) => self.visit_semi_join(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Sink(plan) => self.visit_append(plan),
@@ -135,8 +149,15 @@ pub trait PlanVisitor {
             // Insert.
             PlanNode::Insert(plan) => self.visit_insert_into(plan),
 
+            // Delete.
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+
+            // Update.
+            PlanNode::Update(plan) => self.visit_update(plan),
+
             // Copy.
             PlanNode::Copy(plan) => self.visit_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.visit_copy_into_stage(plan),
 
             // Show.
             PlanNode::Show(plan) => self.visit_show(plan),
@@ -149,11 +170,17 @@ pub trait PlanVisitor {
             // Table.
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.visit_rename_table(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
             PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
 
+            // View.
+            PlanNode::CreateView(plan) => self.visit_create_view(plan),
+            PlanNode::DropView(plan) => self.visit_drop_view(plan),
+
             // User.
             PlanNode::CreateUser(plan) => self.visit_create_user(plan),
             PlanNode::AlterUser(plan) => self.visit_alter_user(plan),
@@ -169,6 +196,7 @@ pub trait PlanVisitor {
             PlanNode::CreateUserStage(plan) => self.visit_create_user_stage(plan),
             PlanNode::DropUserStage(plan) => self.visit_drop_user_stage(plan),
             PlanNode::DescribeUserStage(plan) => self.visit_describe_user_stage(plan),
+            PlanNode::ListUserStage(plan) => self.visit_list_user_stage(plan),
 
             // UDF.
             PlanNode::CreateUserUDF(plan) => self.visit_create_user_udf(plan),
@@ -281,6 +309,20 @@ pub trait PlanVisitor {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_unnest(&mut self, plan: &UnnestPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
+    fn visit_window(&mut self, plan: &WindowPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
+    fn visit_semi_join(&mut self, plan: &SemiJoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_exprs(&plan.keys)?;
+        self.visit_expr(&plan.subquery)
+    }
+
     fn visit_read_data_source(&mut self, _: &ReadDataSourcePlan) -> Result<()> {
         Ok(())
     }
@@ -345,10 +387,22 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_list_user_stage(&mut self, _: &ListUserStagePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_drop_table(&mut self, _: &DropTablePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_rename_table(&mut self, _: &RenameTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
@@ -365,14 +419,34 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_copy(&mut self, _: &CopyPlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_copy_into_stage(&mut self, _: &CopyIntoStagePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_show_create_table(&mut self, _: &ShowCreateTablePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_create_view(&mut self, _: &CreateViewPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_view(&mut self, _: &DropViewPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_truncate_table(&mut self, _: &TruncateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// Keeps the input rows which have (semi join), or have not (anti join), a row of the subquery
+/// with the same keys. The keys are compared with the first columns of the subquery, a NULL key
+/// matches nothing. Without keys, the rows are kept if the subquery is not empty (semi join).
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct SemiJoinPlan {
+    /// Whether the rows without any match are kept instead
+    pub anti: bool,
+    /// The keys evaluated on the input
+    pub keys: Vec<Expression>,
+    /// The `Expression::Subquery` to join with, its result is a column of the input
+    pub subquery: Expression,
+    /// The incoming logical plan
+    pub input: Arc<PlanNode>,
+    /// output schema
+    pub schema: DataSchemaRef,
+}
+
+impl SemiJoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
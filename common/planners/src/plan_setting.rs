@@ -26,6 +26,8 @@ pub struct VarValue {
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct SettingPlan {
     pub vars: Vec<VarValue>,
+    // SET GLOBAL, the settings are written to the metasrv as the defaults of the tenant.
+    pub is_global: bool,
 }
 
 impl SettingPlan {
@@ -12,5 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::PlanShowKind;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
-pub struct ShowSettingsPlan {}
+pub struct ShowSettingsPlan {
+    pub kind: PlanShowKind,
+}
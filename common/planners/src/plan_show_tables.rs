@@ -17,4 +17,8 @@ use crate::PlanShowKind;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct ShowTablesPlan {
     pub kind: PlanShowKind,
+    // Whether to show the engine and created_on columns.
+    pub showfull: bool,
+    // The database resolved from the FROM/IN clause or the current database.
+    pub database: String,
 }
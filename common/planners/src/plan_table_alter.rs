@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum AlterTableOperation {
+    /// Appends the column, the existing rows read its default value or NULL.
+    AddColumn(DataField),
+    DropColumn(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AlterTablePlan {
+    pub tenant: String,
+    pub if_exists: bool,
+    pub db: String,
+    pub table: String,
+    pub operation: AlterTableOperation,
+}
+
+impl AlterTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    /// Applies the operation to the table schema, returns the new schema.
+    pub fn alter_schema(&self, schema: &DataSchema) -> Result<DataSchema> {
+        let mut fields = schema.fields().clone();
+        match &self.operation {
+            AlterTableOperation::AddColumn(field) => {
+                if schema.has_field(field.name()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column {} already exists in table {}.{}",
+                        field.name(),
+                        self.db,
+                        self.table
+                    )));
+                }
+                fields.push(field.clone());
+            }
+            AlterTableOperation::DropColumn(name) => {
+                let index = schema.index_of(name).map_err(|_| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Unknown column {} in table {}.{}",
+                        name, self.db, self.table
+                    ))
+                })?;
+                if fields.len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Cannot drop the only column {} of table {}.{}",
+                        name, self.db, self.table
+                    )));
+                }
+                fields.remove(index);
+            }
+        }
+        Ok(DataSchema::new_from(fields, schema.meta().clone()))
+    }
+}
@@ -22,6 +22,12 @@ use common_meta_types::DropTableReq;
 pub struct DropTablePlan {
     pub if_exists: bool,
     pub tenant: String,
+    /// The tables, dropped in order.
+    pub entities: Vec<DropTableEntity>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DropTableEntity {
     pub db: String,
     /// The table name
     pub table: String,
@@ -31,15 +37,16 @@ impl DropTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
         Arc::new(DataSchema::empty())
     }
-}
 
-impl From<DropTablePlan> for DropTableReq {
-    fn from(p: DropTablePlan) -> Self {
-        DropTableReq {
-            if_exists: p.if_exists,
-            tenant: p.tenant,
-            db: p.db,
-            table: p.table,
-        }
+    pub fn drop_table_reqs(&self) -> Vec<DropTableReq> {
+        self.entities
+            .iter()
+            .map(|entity| DropTableReq {
+                if_exists: self.if_exists,
+                tenant: self.tenant.clone(),
+                db: entity.db.clone(),
+                table: entity.table.clone(),
+            })
+            .collect()
     }
 }
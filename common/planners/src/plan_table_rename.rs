@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_meta_types::RenameTableReq;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTablePlan {
+    pub tenant: String,
+    pub if_exists: bool,
+    /// The renames, applied in order.
+    pub entities: Vec<RenameTableEntity>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableEntity {
+    pub db: String,
+    pub table_name: String,
+    pub new_db: String,
+    pub new_table_name: String,
+}
+
+impl RenameTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    pub fn rename_table_reqs(&self) -> Vec<RenameTableReq> {
+        self.entities
+            .iter()
+            .map(|entity| RenameTableReq {
+                if_exists: self.if_exists,
+                tenant: self.tenant.clone(),
+                db: entity.db.clone(),
+                table_name: entity.table_name.clone(),
+                new_db: entity.new_db.clone(),
+                new_table_name: entity.new_table_name.clone(),
+            })
+            .collect()
+    }
+}
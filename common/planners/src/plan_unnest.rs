@@ -0,0 +1,43 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// Lateral UNNEST: each input row is expanded to one row per element of `array_column`,
+/// the other columns are repeated. Rows with an empty or NULL array produce no output.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct UnnestPlan {
+    /// The array column to expand
+    pub array_column: String,
+    /// The output column holding the array elements
+    pub element_column: String,
+    /// The incoming logical plan
+    pub input: Arc<PlanNode>,
+    /// output schema
+    pub schema: DataSchemaRef,
+}
+
+impl UnnestPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
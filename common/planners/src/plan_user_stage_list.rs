@@ -0,0 +1,36 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_meta_types::UserStageInfo;
+
+// List the files of the stage:
+// list @stage_name[/path] [pattern = '<regex_pattern>']
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListUserStagePlan {
+    // The stage with its path resolved to the location to list.
+    pub stage_info: UserStageInfo,
+    pub pattern: String,
+}
+
+impl ListUserStagePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+            DataField::new_nullable("md5", Vu8::to_data_type()),
+            DataField::new_nullable("last_modified", Vu8::to_data_type()),
+        ])
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+/// Evaluates a window function over all the input rows, its value is appended as a column.
+/// The partition and order expressions of the window are columns of the input.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct WindowPlan {
+    /// The `Expression::WindowFunction` to evaluate
+    pub window_func: Expression,
+    /// The incoming logical plan
+    pub input: Arc<PlanNode>,
+    /// output schema
+    pub schema: DataSchemaRef,
+}
+
+impl WindowPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
@@ -26,4 +26,6 @@ mod plan_limit;
 mod plan_projection;
 mod plan_rewriter;
 mod plan_select;
+mod plan_unnest;
+mod plan_window;
 mod test;
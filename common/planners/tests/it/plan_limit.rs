@@ -24,6 +24,7 @@ fn test_limit_plan() -> Result<()> {
     let limit = PlanNode::Limit(LimitPlan {
         n: Some(33),
         offset: 0,
+        with_ties: false,
         input: Arc::from(PlanBuilder::empty().build()?),
     });
     let expect = "Limit: 33";
@@ -31,3 +32,16 @@ fn test_limit_plan() -> Result<()> {
     assert_eq!(expect, actual);
     Ok(())
 }
+
+#[test]
+fn test_limit_with_ties_plan() -> Result<()> {
+    use pretty_assertions::assert_eq;
+
+    let limit = PlanBuilder::empty()
+        .limit_offset_with_ties(Some(33), 2, true)?
+        .build()?;
+    let expect = "Limit: 33, 2 with ties";
+    let actual = format!("{:?}", limit);
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_unnest_plan() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u64::to_data_type()),
+        DataField::new("arr", Arc::new(ArrayType::create(i64::to_data_type()))),
+    ]);
+
+    let plan = PlanBuilder::create(schema).unnest("arr", "x")?.build()?;
+
+    let expect = "Unnest: arr as x";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    let schema = plan.schema();
+    assert_eq!(schema.fields().len(), 3);
+    assert_eq!(schema.field(2).name(), "x");
+    assert_eq!(schema.field(2).data_type().data_type_id(), TypeID::Int64);
+
+    // Unnest a non-array column.
+    let result = PlanBuilder::from(&plan).unnest("id", "y");
+    assert_eq!(
+        result.err().unwrap().message(),
+        "UNNEST argument must be Array type, but got UInt64"
+    );
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_window_plan() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u64::to_data_type()),
+        DataField::new("b", i64::to_data_type()),
+    ]);
+    let window_func = Expression::WindowFunction {
+        op: "dense_rank".to_string(),
+        args: vec![],
+        partition_by: vec![col("a")],
+        order_by: vec![sort("b", false, true)],
+    };
+
+    let plan = PlanBuilder::create(schema).window(&window_func)?.build()?;
+
+    let expect = "Window: dense_rank() over (partition by a order by b desc nulls first)";
+    let actual = format!("{:?}", plan);
+    assert_eq!(expect, actual);
+
+    let schema = plan.schema();
+    assert_eq!(schema.fields().len(), 3);
+    assert_eq!(
+        schema.field(2).name(),
+        "dense_rank() over (partition by a order by b desc nulls first)"
+    );
+    assert_eq!(schema.field(2).data_type().data_type_id(), TypeID::UInt64);
+
+    // Not a window function.
+    let result = col("a").to_ranking_function();
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Expression must be window function"
+    );
+    Ok(())
+}
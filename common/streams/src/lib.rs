@@ -25,6 +25,7 @@ mod stream_sort;
 mod stream_source;
 mod stream_sub_queries;
 mod stream_take;
+mod stream_timeout;
 
 pub use sources::*;
 pub use stream::*;
@@ -39,3 +40,4 @@ pub use stream_sort::SortStream;
 pub use stream_source::SourceStream;
 pub use stream_sub_queries::SubQueriesStream;
 pub use stream_take::TakeStream;
+pub use stream_timeout::TimeoutStream;
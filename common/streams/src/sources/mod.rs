@@ -14,8 +14,14 @@
 
 mod source;
 mod source_csv;
+mod source_decompress;
 mod source_parquet;
 
+pub use source::ParseErrors;
 pub use source::Source;
 pub use source_csv::CsvSourceBuilder;
+pub use source_decompress::decompress_reader;
+pub use source_decompress::CompressionAlgorithm;
+pub use source_decompress::DecompressError;
+pub use source_decompress::SourceReader;
 pub use source_parquet::ParquetSourceBuilder;
@@ -12,11 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_infallible::Mutex;
 
 #[async_trait]
 pub trait Source: Send {
     async fn read(&mut self) -> Result<Option<DataBlock>>;
 }
+
+/// Row-level parse errors skipped by a source instead of failing the stream.
+#[derive(Debug, Default)]
+pub struct ParseErrors {
+    count: AtomicUsize,
+    first_error: Mutex<Option<String>>,
+}
+
+impl ParseErrors {
+    pub fn create() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&self, cause: &ErrorCode) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let mut first_error = self.first_error.lock();
+        if first_error.is_none() {
+            *first_error = Some(cause.message());
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    pub fn first_error(&self) -> Option<String> {
+        self.first_error.lock().clone()
+    }
+}
@@ -12,18 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use csv_async::AsyncReader;
 use csv_async::AsyncReaderBuilder;
+use csv_async::ByteRecord;
 use csv_async::Terminator;
 use futures::stream::StreamExt;
 use futures::AsyncRead;
 
+use crate::DecompressError;
+use crate::ParseErrors;
 use crate::Source;
 
 #[derive(Debug, Clone)]
@@ -34,6 +39,15 @@ pub struct CsvSourceBuilder {
     size_limit: usize,
     field_delimiter: u8,
     record_delimiter: Terminator,
+    quote: u8,
+    escape: Option<u8>,
+    // Match the columns by the header names, Some(case_sensitive) if enabled.
+    match_by_column_name: Option<bool>,
+    // Fields equal to one of these strings are converted to NULL.
+    null_if: Vec<Vec<u8>>,
+    empty_field_as_null: bool,
+    // Skip the rows failed to parse and collect the errors, instead of failing the stream.
+    parse_errors: Option<Arc<ParseErrors>>,
 }
 
 impl CsvSourceBuilder {
@@ -43,6 +57,12 @@ impl CsvSourceBuilder {
             skip_header: 0,
             field_delimiter: b',',
             record_delimiter: Terminator::CRLF,
+            quote: b'"',
+            escape: None,
+            match_by_column_name: None,
+            null_if: vec![],
+            empty_field_as_null: false,
+            parse_errors: None,
             block_size: 10000,
             size_limit: 0,
         }
@@ -82,6 +102,7 @@ impl CsvSourceBuilder {
                 _ => b'\n',
             };
 
+            // '\n', '\r' and '\r\n' are all treated as CRLF.
             let record_delimiter = if record_delimiter == b'\n' || record_delimiter == b'\r' {
                 Terminator::CRLF
             } else {
@@ -92,6 +113,47 @@ impl CsvSourceBuilder {
         self
     }
 
+    pub fn quote(&mut self, quote_str: &str) -> &mut Self {
+        if !quote_str.is_empty() {
+            self.quote = quote_str.as_bytes()[0];
+        }
+        self
+    }
+
+    pub fn escape(&mut self, escape_str: &str) -> &mut Self {
+        if !escape_str.is_empty() {
+            self.escape = Some(escape_str.as_bytes()[0]);
+        }
+        self
+    }
+
+    // Use the first line of the file as the header and match the columns by name.
+    pub fn match_by_column_name(&mut self, case_sensitive: bool) -> &mut Self {
+        self.match_by_column_name = Some(case_sensitive);
+        self
+    }
+
+    pub fn null_if(&mut self, null_if: &[String]) -> &mut Self {
+        self.null_if = null_if.iter().map(|v| v.as_bytes().to_vec()).collect();
+        self
+    }
+
+    pub fn empty_field_as_null(&mut self, empty_field_as_null: bool) -> &mut Self {
+        self.empty_field_as_null = empty_field_as_null;
+        self
+    }
+
+    pub fn skip_error_rows(&mut self, parse_errors: Arc<ParseErrors>) -> &mut Self {
+        self.parse_errors = Some(parse_errors);
+        self
+    }
+
+    // Whether the field should be converted to NULL.
+    fn is_null_field(&self, field: &[u8]) -> bool {
+        (self.empty_field_as_null && field.is_empty())
+            || self.null_if.iter().any(|v| v.as_slice() == field)
+    }
+
     pub fn build<R>(&self, reader: R) -> Result<CsvSource<R>>
     where R: AsyncRead + Unpin + Send {
         CsvSource::try_create(self.clone(), reader)
@@ -102,6 +164,10 @@ pub struct CsvSource<R> {
     builder: CsvSourceBuilder,
     reader: AsyncReader<R>,
     rows: usize,
+    // Number of header lines already skipped in this file.
+    skipped: i32,
+    // Positions of the schema columns in the records, None if matched by position.
+    positions: Option<Vec<Option<usize>>>,
 }
 
 impl<R> CsvSource<R>
@@ -109,17 +175,111 @@ where R: AsyncRead + Unpin + Send
 {
     fn try_create(builder: CsvSourceBuilder, reader: R) -> Result<Self> {
         let reader = AsyncReaderBuilder::new()
-            .has_headers(builder.skip_header > 0)
+            .has_headers(false)
             .delimiter(builder.field_delimiter)
             .terminator(builder.record_delimiter)
+            .quote(builder.quote)
+            .escape(builder.escape)
             .create_reader(reader);
 
         Ok(Self {
             builder,
             reader,
             rows: 0,
+            skipped: 0,
+            positions: None,
         })
     }
+
+    fn header_positions(
+        builder: &CsvSourceBuilder,
+        header: &ByteRecord,
+        case_sensitive: bool,
+    ) -> Vec<Option<usize>> {
+        let names = header
+            .iter()
+            .map(|name| String::from_utf8_lossy(name).trim().to_string())
+            .collect::<Vec<_>>();
+
+        builder
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                names.iter().position(|name| match case_sensitive {
+                    true => name == field.name(),
+                    false => name.eq_ignore_ascii_case(field.name()),
+                })
+            })
+            .collect()
+    }
+
+    // Deserialize the record into the packs as one row.
+    // If a field fails, it and the following fields are filled with default values to keep the packs aligned.
+    fn deserialize_record(
+        builder: &CsvSourceBuilder,
+        positions: Option<&[Option<usize>]>,
+        line: usize,
+        record: &ByteRecord,
+        packs: &mut [Box<dyn TypeDeserializer>],
+    ) -> Result<()> {
+        let mut error = None;
+        for (col, pack) in packs.iter_mut().enumerate() {
+            if error.is_some() {
+                pack.de_default();
+                continue;
+            }
+
+            let res = Self::deserialize_field(builder, positions, line, record, col, pack.as_mut());
+            if let Err(cause) = res {
+                pack.de_default();
+                error = Some(cause);
+            }
+        }
+
+        match error {
+            Some(cause) => Err(cause),
+            None => Ok(()),
+        }
+    }
+
+    fn deserialize_field(
+        builder: &CsvSourceBuilder,
+        positions: Option<&[Option<usize>]>,
+        line: usize,
+        record: &ByteRecord,
+        col: usize,
+        pack: &mut dyn TypeDeserializer,
+    ) -> Result<()> {
+        let position = match positions {
+            Some(positions) => positions[col],
+            None => Some(col),
+        };
+
+        // Missing columns are filled with default values.
+        let field = &builder.schema.fields()[col];
+        match position.and_then(|position| record.get(position)) {
+            Some(bytes) if builder.is_null_field(bytes) => {
+                if !pack.de_null() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Parse csv error at line {}, column {:?} is not nullable but got NULL",
+                        line,
+                        field.name()
+                    )));
+                }
+            }
+            Some(bytes) => pack.de_text(bytes).map_err(|cause| {
+                ErrorCode::BadBytes(format!(
+                    "Parse csv error at line {}, column {:?}: {}",
+                    line,
+                    field.name(),
+                    cause.message()
+                ))
+            })?,
+            None => pack.de_default(),
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -141,21 +301,74 @@ where R: AsyncRead + Unpin + Send
             .collect::<Vec<_>>();
 
         let mut rows = 0;
+        // Rows failed to parse are filtered out of the block, if skip error rows.
+        let mut valid_rows = Vec::with_capacity(self.builder.block_size);
         let mut records = self.reader.byte_records();
 
         while let Some(record) = records.next().await {
+            // The file failed to decompress is not skipped as the rows failed to parse.
+            if let Err(cause) = &record {
+                if let csv_async::ErrorKind::Io(error) = cause.kind() {
+                    if let Some(error) = DecompressError::from_io_error(error) {
+                        return Err(ErrorCode::DecompressError(error.to_string()));
+                    }
+                }
+            }
+
             let record = record.map_err_to_code(ErrorCode::BadBytes, || {
                 format!("Parse csv error at line {}", self.rows)
-            })?;
+            });
+            let record = match record {
+                Ok(record) => record,
+                Err(cause) => match &self.builder.parse_errors {
+                    Some(parse_errors) => {
+                        parse_errors.add(&cause);
+                        self.rows += 1;
+                        continue;
+                    }
+                    None => return Err(cause),
+                },
+            };
+
+            // The first line is the header if match by column name.
+            if self.skipped == 0 {
+                if let Some(case_sensitive) = self.builder.match_by_column_name {
+                    self.positions = Some(Self::header_positions(
+                        &self.builder,
+                        &record,
+                        case_sensitive,
+                    ));
+                    self.skipped += 1;
+                    continue;
+                }
+            }
+
+            // Skip the header lines, only once per file.
+            if self.skipped < self.builder.skip_header {
+                self.skipped += 1;
+                continue;
+            }
 
             if record.is_empty() {
                 break;
             }
-            for (col, pack) in packs.iter_mut().enumerate() {
-                match record.get(col) {
-                    Some(bytes) => pack.de_text(bytes)?,
-                    None => pack.de_default(),
-                }
+
+            let res = Self::deserialize_record(
+                &self.builder,
+                self.positions.as_deref(),
+                self.rows,
+                &record,
+                &mut packs,
+            );
+            match res {
+                Ok(_) => valid_rows.push(true),
+                Err(cause) => match &self.builder.parse_errors {
+                    Some(parse_errors) => {
+                        parse_errors.add(&cause);
+                        valid_rows.push(false);
+                    }
+                    None => return Err(cause),
+                },
             }
             rows += 1;
             self.rows += 1;
@@ -180,6 +393,12 @@ where R: AsyncRead + Unpin + Send
             .map(|deser| deser.finish_to_column())
             .collect::<Vec<_>>();
 
-        Ok(Some(DataBlock::create(self.builder.schema.clone(), series)))
+        let block = DataBlock::create(self.builder.schema.clone(), series);
+        if valid_rows.iter().all(|valid| *valid) {
+            return Ok(Some(block));
+        }
+
+        let predicate = Series::from_data(valid_rows);
+        Ok(Some(DataBlock::filter_block(&block, &predicate)?))
     }
 }
@@ -0,0 +1,145 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::bufread::BzDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use futures::io::BufReader;
+use futures::AsyncRead;
+
+pub type SourceReader = Box<dyn AsyncRead + Unpin + Send>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Bz2,
+    Zstd,
+}
+
+impl CompressionAlgorithm {
+    // Detect the compression algorithm by the file extension, None if not compressed.
+    pub fn from_path(path: &str) -> Option<CompressionAlgorithm> {
+        let path = path.to_lowercase();
+        if path.ends_with(".gz") || path.ends_with(".gzip") {
+            Some(CompressionAlgorithm::Gzip)
+        } else if path.ends_with(".bz2") {
+            Some(CompressionAlgorithm::Bz2)
+        } else if path.ends_with(".zst") || path.ends_with(".zstd") {
+            Some(CompressionAlgorithm::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+// Wrap the reader with a streaming decompressor, the data is decompressed chunk by chunk
+// as the source reads, so the whole file is never buffered in memory. The errors of the
+// decompressor are returned as DecompressError, naming the file and the compression.
+pub fn decompress_reader<R>(
+    reader: R,
+    algorithm: Option<CompressionAlgorithm>,
+    path: &str,
+) -> SourceReader
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    let algorithm = match algorithm {
+        None => return Box::new(reader),
+        Some(algorithm) => algorithm,
+    };
+
+    let decoder: SourceReader = match algorithm {
+        CompressionAlgorithm::Gzip => {
+            let mut decoder = GzipDecoder::new(BufReader::new(reader));
+            // A gzip file may consist of several concatenated members.
+            decoder.multiple_members(true);
+            Box::new(decoder)
+        }
+        CompressionAlgorithm::Bz2 => {
+            let mut decoder = BzDecoder::new(BufReader::new(reader));
+            decoder.multiple_members(true);
+            Box::new(decoder)
+        }
+        CompressionAlgorithm::Zstd => {
+            let mut decoder = ZstdDecoder::new(BufReader::new(reader));
+            decoder.multiple_members(true);
+            Box::new(decoder)
+        }
+    };
+
+    Box::new(DecompressReader {
+        decoder,
+        path: path.to_string(),
+        algorithm,
+    })
+}
+
+// The error of decompressing a file, carried by the io error of the reader.
+#[derive(Debug)]
+pub struct DecompressError {
+    path: String,
+    algorithm: CompressionAlgorithm,
+    cause: io::Error,
+}
+
+impl DecompressError {
+    // The decompress error carried by the io error, if any.
+    pub fn from_io_error(error: &io::Error) -> Option<&DecompressError> {
+        error.get_ref().and_then(|e| e.downcast_ref())
+    }
+}
+
+impl fmt::Display for DecompressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot decompress file {} with compression {:?}: {}",
+            self.path, self.algorithm, self.cause
+        )
+    }
+}
+
+impl std::error::Error for DecompressError {}
+
+struct DecompressReader {
+    decoder: SourceReader,
+    path: String,
+    algorithm: CompressionAlgorithm,
+}
+
+impl AsyncRead for DecompressReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        match Pin::new(&mut self.decoder).poll_read(cx, buf) {
+            Poll::Ready(Err(cause)) => {
+                let error = DecompressError {
+                    path: self.path.clone(),
+                    algorithm: self.algorithm,
+                    cause,
+                };
+                Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, error)))
+            }
+            other => other,
+        }
+    }
+}
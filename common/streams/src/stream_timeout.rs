@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Fail the stream with a timeout error once the deadline passed.
+///
+/// The deadline is checked before each block, a block being produced is not interrupted.
+pub struct TimeoutStream {
+    input: SendableDataBlockStream,
+    deadline: Instant,
+    timeout: Duration,
+    timed_out: bool,
+}
+
+impl TimeoutStream {
+    pub fn create(input: SendableDataBlockStream, deadline: Instant, timeout: Duration) -> Self {
+        TimeoutStream {
+            input,
+            deadline,
+            timeout,
+            timed_out: false,
+        }
+    }
+
+    pub fn timeout_error(timeout: Duration) -> ErrorCode {
+        ErrorCode::Timeout(format!(
+            "Query exceeded max_execution_time {} ms",
+            timeout.as_millis()
+        ))
+    }
+}
+
+impl Stream for TimeoutStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.timed_out {
+            return Poll::Ready(None);
+        }
+
+        if Instant::now() >= self.deadline {
+            self.timed_out = true;
+            return Poll::Ready(Some(Err(Self::timeout_error(self.timeout))));
+        }
+
+        self.input.poll_next_unpin(ctx)
+    }
+}
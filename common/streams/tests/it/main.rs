@@ -18,3 +18,4 @@ mod stream_datablock;
 mod stream_limit_by;
 mod stream_progress;
 mod stream_skip;
+mod stream_timeout;
@@ -14,12 +14,14 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::sync::Arc;
 
 use common_base::tokio;
 use common_datablocks::assert_blocks_eq;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_streams::CsvSourceBuilder;
+use common_streams::ParseErrors;
 use common_streams::Source;
 use opendal::services::fs;
 use opendal::Operator;
@@ -161,3 +163,443 @@ async fn test_parse_csv_text() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_quote_escape_and_skip_header() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(
+        file,
+        "a,b,c\r\nint,string,float\r\n1,\"Bei,jing\",1.1\r\n2,\"Shang\\\"hai\",2.2\r\n3,\"Guang\"\"zhou\",3.3\r\n"
+    )
+    .unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.skip_header(2);
+    builder.field_delimiter(",");
+    builder.record_delimiter("\r\n");
+    builder.quote("\"");
+    builder.escape("\\");
+    // Header must be skipped once per file, not per block.
+    builder.block_size(1);
+
+    let reader = local.object(name).reader();
+    let mut csv_source = builder.build(reader)?;
+
+    let mut blocks = vec![];
+    while let Some(block) = csv_source.read().await? {
+        blocks.push(block);
+    }
+    assert_eq!(blocks.len(), 3);
+    assert_blocks_eq(
+        vec![
+            "+---+------------+-----+",
+            "| a | b          | c   |",
+            "+---+------------+-----+",
+            "| 1 | Bei,jing   | 1.1 |",
+            "| 2 | Shang\"hai  | 2.2 |",
+            "| 3 | Guang\"zhou | 3.3 |",
+            "+---+------------+-----+",
+        ],
+        &blocks,
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_tsv() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1\t'a,b'\t1.5\n2\t\"c d\"\t2.5\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.field_delimiter("\t");
+    builder.record_delimiter("\n");
+    builder.block_size(10);
+
+    let reader = local.object(name).reader();
+    let mut csv_source = builder.build(reader)?;
+
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+-------+-----+",
+            "| a | b     | c   |",
+            "+---+-------+-----+",
+            "| 1 | 'a,b' | 1.5 |",
+            "| 2 | c d   | 2.5 |",
+            "+---+-------+-----+",
+        ],
+        &[block],
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_fewer_columns() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1,a\n2,b\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new_nullable("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.record_delimiter("\n");
+    builder.block_size(10);
+
+    let reader = local.object(name).reader();
+    let mut csv_source = builder.build(reader)?;
+
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+---+------+",
+            "| a | b | c    |",
+            "+---+---+------+",
+            "| 1 | a | NULL |",
+            "| 2 | b | NULL |",
+            "+---+---+------+",
+        ],
+        &[block],
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_match_by_column_name() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "C,x,a\n1.5,foo,1\n2.5,bar,2\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new_nullable("b", Vu8::to_data_type()),
+        DataField::new("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.record_delimiter("\n");
+    builder.match_by_column_name(false);
+    builder.block_size(10);
+
+    let reader = local.object(name).reader();
+    let mut csv_source = builder.build(reader)?;
+
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+-----+",
+            "| a | b    | c   |",
+            "+---+------+-----+",
+            "| 1 | NULL | 1.5 |",
+            "| 2 | NULL | 2.5 |",
+            "+---+------+-----+",
+        ],
+        &[block],
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_null_if() -> Result<()> {
+    struct Test {
+        null_if: Vec<String>,
+        empty_field_as_null: bool,
+        expect: Vec<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            null_if: vec![],
+            empty_field_as_null: false,
+            expect: vec![
+                "+---+------+",
+                "| a | b    |",
+                "+---+------+",
+                "| 1 | \\N   |",
+                "| 2 | NULL |",
+                "| 3 |      |",
+                "| 4 | d    |",
+                "+---+------+",
+            ],
+        },
+        Test {
+            null_if: vec!["\\N".to_string()],
+            empty_field_as_null: false,
+            expect: vec![
+                "+---+------+",
+                "| a | b    |",
+                "+---+------+",
+                "| 1 | NULL |",
+                "| 2 | NULL |",
+                "| 3 |      |",
+                "| 4 | d    |",
+                "+---+------+",
+            ],
+        },
+        Test {
+            null_if: vec![],
+            empty_field_as_null: true,
+            expect: vec![
+                "+---+------+",
+                "| a | b    |",
+                "+---+------+",
+                "| 1 | \\N   |",
+                "| 2 | NULL |",
+                "| 3 | NULL |",
+                "| 4 | d    |",
+                "+---+------+",
+            ],
+        },
+        Test {
+            null_if: vec!["\\N".to_string(), "NULL".to_string(), "d".to_string()],
+            empty_field_as_null: true,
+            expect: vec![
+                "+---+------+",
+                "| a | b    |",
+                "+---+------+",
+                "| 1 | NULL |",
+                "| 2 | NULL |",
+                "| 3 | NULL |",
+                "| 4 | NULL |",
+                "+---+------+",
+            ],
+        },
+    ];
+
+    for test in tests {
+        let dir = tempfile::tempdir().unwrap();
+        let name = "my-temporary-note.txt";
+        let file_path = dir.path().join(name);
+        let mut file = File::create(file_path).unwrap();
+
+        write!(file, "1,\\N\n2,NULL\n3,\n4,d\n").unwrap();
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("a", i8::to_data_type()),
+            DataField::new_nullable("b", Vu8::to_data_type()),
+        ]);
+
+        let local = Operator::new(
+            fs::Backend::build()
+                .root(dir.path().to_str().unwrap())
+                .finish()
+                .await
+                .unwrap(),
+        );
+
+        let mut builder = CsvSourceBuilder::create(schema);
+        builder.record_delimiter("\n");
+        builder.block_size(10);
+        builder.null_if(&test.null_if);
+        builder.empty_field_as_null(test.empty_field_as_null);
+
+        let reader = local.object(name).reader();
+        let mut csv_source = builder.build(reader)?;
+
+        let block = csv_source.read().await?.unwrap();
+        assert_blocks_eq(test.expect, &[block]);
+
+        drop(file);
+        dir.close().unwrap();
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_null_if_not_nullable() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1,a\n,b\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.record_delimiter("\n");
+    builder.block_size(10);
+    builder.empty_field_as_null(true);
+
+    let reader = local.object(name).reader();
+    let mut csv_source = builder.build(reader)?;
+
+    let result = csv_source.read().await;
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().message(),
+        "Parse csv error at line 1, column \"a\" is not nullable but got NULL"
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_skip_error_rows() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1,true\n2,maybe\n3,false\n4\n5,true\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", bool::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    // Fail on the first bad row by default.
+    {
+        let mut builder = CsvSourceBuilder::create(schema.clone());
+        builder.record_delimiter("\n");
+        builder.block_size(10);
+
+        let reader = local.object(name).reader();
+        let mut csv_source = builder.build(reader)?;
+
+        let result = csv_source.read().await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Parse csv error at line 1, column \"b\": Incorrect boolean value"
+        );
+    }
+
+    // Skip the bad rows and collect the errors.
+    {
+        let parse_errors = Arc::new(ParseErrors::create());
+        let mut builder = CsvSourceBuilder::create(schema.clone());
+        builder.record_delimiter("\n");
+        builder.block_size(10);
+        builder.skip_error_rows(parse_errors.clone());
+
+        let reader = local.object(name).reader();
+        let mut csv_source = builder.build(reader)?;
+
+        let block = csv_source.read().await?.unwrap();
+        assert_blocks_eq(
+            vec![
+                "+---+-------+",
+                "| a | b     |",
+                "+---+-------+",
+                "| 1 | true  |",
+                "| 3 | false |",
+                "| 5 | true  |",
+                "+---+-------+",
+            ],
+            &[block],
+        );
+        assert!(csv_source.read().await?.is_none());
+
+        assert_eq!(parse_errors.count(), 2);
+        assert_eq!(
+            parse_errors.first_error(),
+            Some("Parse csv error at line 1, column \"b\": Incorrect boolean value".to_string())
+        );
+    }
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::Write;
+
+use common_base::tokio;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::decompress_reader;
+use common_streams::CompressionAlgorithm;
+use common_streams::CsvSourceBuilder;
+use common_streams::Source;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use opendal::services::fs;
+use opendal::Operator;
+
+#[test]
+fn test_compression_algorithm_from_path() -> Result<()> {
+    let tests = vec![
+        ("data/file.csv.gz", Some(CompressionAlgorithm::Gzip)),
+        ("data/file.CSV.GZIP", Some(CompressionAlgorithm::Gzip)),
+        ("data/file.csv.bz2", Some(CompressionAlgorithm::Bz2)),
+        ("data/file.csv.zst", Some(CompressionAlgorithm::Zstd)),
+        ("data/file.csv.zstd", Some(CompressionAlgorithm::Zstd)),
+        ("data/file.csv", None),
+        ("data/gz", None),
+    ];
+
+    for (path, expect) in tests {
+        assert_eq!(CompressionAlgorithm::from_path(path), expect, "{}", path);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_multi_member_gzip() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.csv.gz";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    // Two gzip members concatenated into one file.
+    for data in ["1,a\n2,b\n3,c\n", "4,d\n5,e\n"] {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data.as_bytes()).unwrap();
+        file.write_all(&encoder.finish().unwrap()).unwrap();
+    }
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.record_delimiter("\n");
+    builder.block_size(2);
+
+    let reader = local.object(name).reader();
+    let reader = decompress_reader(reader, CompressionAlgorithm::from_path(name), name);
+    let mut csv_source = builder.build(reader)?;
+
+    let mut rows = 0;
+    while let Some(block) = csv_source.read().await? {
+        rows += block.num_rows();
+    }
+    assert_eq!(rows, 5);
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_wrong_compression() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.csv";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(file, "1,a\n2,b\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema);
+    builder.record_delimiter("\n");
+
+    // Plain file read as gzip.
+    let reader = local.object(name).reader();
+    let reader = decompress_reader(reader, Some(CompressionAlgorithm::Gzip), name);
+    let mut csv_source = builder.build(reader)?;
+
+    let result = csv_source.read().await;
+    let err = result.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::DecompressError("").code());
+    assert!(
+        err.message()
+            .starts_with("Cannot decompress file my-temporary-note.csv with compression Gzip"),
+        "{}",
+        err.message()
+    );
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::StreamExt;
+
+#[tokio::test]
+async fn test_timeout_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", i32::to_data_type())]);
+    let blocks = (0..3)
+        .map(|n| DataBlock::create(schema.clone(), vec![Series::from_data(vec![n])]))
+        .collect::<Vec<_>>();
+
+    // Each block takes 50ms to produce.
+    let slow_stream = futures::stream::iter(blocks).then(|block| async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Ok(block)
+    });
+
+    let timeout = Duration::from_millis(20);
+    let mut stream =
+        TimeoutStream::create(Box::pin(slow_stream), Instant::now() + timeout, timeout);
+
+    // The deadline is not passed before the first block.
+    let block = stream.next().await.unwrap()?;
+    assert_eq!(block.num_rows(), 1);
+
+    let err = stream.next().await.unwrap().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TimeoutCode());
+    assert_eq!(err.message(), "Query exceeded max_execution_time 20 ms");
+
+    // The stream ends after the timeout.
+    assert!(stream.next().await.is_none());
+
+    Ok(())
+}
@@ -67,10 +67,18 @@ impl ActionHandler {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::RenameTable(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
             MetaGrpcWriteReq::CommitTable(a) => {
                 let r = self.handle(a).await;
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::UpdateTableMeta(a) => {
+                let r = self.handle(a).await;
+                RaftReply::from(r)
+            }
         }
     }
 
@@ -23,6 +23,8 @@ use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::UpdateTableMeta;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -42,6 +44,8 @@ use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaError;
 use common_meta_types::OkOrExist;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableAlreadyExists;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
@@ -50,6 +54,8 @@ use common_meta_types::TableVersionMismatched;
 use common_meta_types::UnknownDatabase;
 use common_meta_types::UnknownTable;
 use common_meta_types::UnknownTableId;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -218,6 +224,51 @@ impl RequestHandler<DropTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<RenameTableReq> for ActionHandler {
+    async fn handle(&self, req: RenameTableReq) -> Result<RenameTableReply, MetaError> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: RenameTable {
+                tenant: req.tenant,
+                db_name: req.db.clone(),
+                table_name: req.table_name.clone(),
+                new_db_name: req.new_db.clone(),
+                new_table_name: req.new_table_name.clone(),
+            },
+        };
+
+        let res = self.meta_node.write(cr).await?;
+
+        let ch: Change<TableMeta> = res
+            .try_into()
+            .map_err(|e: &str| MetaError::MetaServiceError(e.to_string()))?;
+        let changed = ch.changed();
+        let (prev, _result) = ch.unpack();
+
+        if prev.is_none() {
+            if req.if_exists {
+                return Ok(RenameTableReply {});
+            }
+            let ae = AppError::from(UnknownTable::new(
+                req.table_name,
+                "RequestHandler: rename_table",
+            ));
+            return Err(MetaError::from(ae));
+        }
+
+        if !changed {
+            let ae = AppError::from(TableAlreadyExists::new(
+                req.new_table_name,
+                "RequestHandler: rename_table",
+            ));
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(RenameTableReply {})
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableReq> for ActionHandler {
     async fn handle(&self, req: GetTableReq) -> Result<Arc<TableInfo>, MetaError> {
@@ -297,3 +348,34 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTableMetaReq> for ActionHandler {
+    async fn handle(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply, MetaError> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTableMeta(req.clone()),
+        };
+
+        let res = self.meta_node.write(cr).await?;
+
+        if !res.changed() {
+            let ch: Change<TableMeta> = res
+                .try_into()
+                .map_err(|e: &str| MetaError::MetaServiceError(e.to_string()))?;
+            // safe unwrap: res not changed, so `prev` and `result` are not None.
+            let (prev, _result) = ch.unwrap();
+
+            let ae = AppError::from(TableVersionMismatched::new(
+                req.table_id,
+                req.seq,
+                prev.seq,
+                "RequestHandler: update_table_meta",
+            ));
+
+            return Err(MetaError::from(ae));
+        }
+
+        Ok(UpdateTableMetaReply {})
+    }
+}
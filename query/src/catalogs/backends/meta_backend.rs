@@ -32,9 +32,13 @@ use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -129,6 +133,14 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn rename_table(
+        &self,
+        req: RenameTableReq,
+    ) -> std::result::Result<RenameTableReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.rename_table(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> std::result::Result<Arc<TableInfo>, MetaError> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -158,6 +170,14 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> std::result::Result<UpdateTableMetaReply, MetaError> {
+        self.query_backend(move |cli| async move { cli.update_table_meta(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
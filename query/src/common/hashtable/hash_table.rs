@@ -82,6 +82,12 @@ impl<Key: HashTableKeyable, Entity: HashTableEntity<Key>> HashTable<Key, Entity>
         self.size == 0
     }
 
+    /// The bytes allocated for the entities, grows with the table.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        (self.grower.max_size() as usize) * mem::size_of::<Entity>()
+    }
+
     #[inline(always)]
     pub fn iter(&self) -> HashTableIter<Key, Entity> {
         HashTableIter::create(self.grower.max_size(), self.entities, self.zero_entity)
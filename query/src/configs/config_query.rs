@@ -66,6 +66,10 @@ const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB
 const QUERY_MANAGEMENT_MODE: &str = "QUERY_MANAGEMENT_MODE";
 const QUERY_JWT_KEY_FILE: &str = "QUERY_JWT_KEY_FILE";
 
+const QUERY_PYTHON_UDF_EXECUTABLE: &str = "QUERY_PYTHON_UDF_EXECUTABLE";
+const QUERY_PYTHON_UDF_DIR: &str = "QUERY_PYTHON_UDF_DIR";
+const QUERY_PYTHON_UDF_TIMEOUT_MILLIS: &str = "QUERY_PYTHON_UDF_TIMEOUT_MILLIS";
+
 /// Query config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
 #[serde(default)]
@@ -217,6 +221,18 @@ pub struct QueryConfig {
 
     #[clap(long, env = QUERY_JWT_KEY_FILE, default_value = "")]
     pub jwt_key_file: String,
+
+    /// The python interpreter to run the python UDFs
+    #[clap(long, env = QUERY_PYTHON_UDF_EXECUTABLE, default_value = "python3")]
+    pub python_udf_executable: String,
+
+    /// The directory of the handler files of python UDFs, python UDFs are disabled if it's empty
+    #[clap(long, env = QUERY_PYTHON_UDF_DIR, default_value = "")]
+    pub python_udf_dir: String,
+
+    /// The timeout of a call to python UDF
+    #[clap(long, env = QUERY_PYTHON_UDF_TIMEOUT_MILLIS, default_value = "10000")]
+    pub python_udf_timeout_millis: u64,
 }
 
 impl Default for QueryConfig {
@@ -261,6 +277,9 @@ impl Default for QueryConfig {
             table_disk_cache_mb_size: 1024,
             management_mode: false,
             jwt_key_file: "".to_string(),
+            python_udf_executable: "python3".to_string(),
+            python_udf_dir: "".to_string(),
+            python_udf_timeout_millis: 10000,
         }
     }
 }
@@ -508,5 +527,26 @@ impl QueryConfig {
             QUERY_MANAGEMENT_MODE
         );
         env_helper!(mut_config, query, management_mode, bool, QUERY_JWT_KEY_FILE);
+        env_helper!(
+            mut_config,
+            query,
+            python_udf_executable,
+            String,
+            QUERY_PYTHON_UDF_EXECUTABLE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            python_udf_dir,
+            String,
+            QUERY_PYTHON_UDF_DIR
+        );
+        env_helper!(
+            mut_config,
+            query,
+            python_udf_timeout_millis,
+            u64,
+            QUERY_PYTHON_UDF_TIMEOUT_MILLIS
+        );
     }
 }
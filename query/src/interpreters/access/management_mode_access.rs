@@ -57,6 +57,10 @@ impl ManagementModeAccess {
                 | PlanNode::DescribeTable(_)
                 | PlanNode::ShowCreateTable(_)
 
+                // View.
+                | PlanNode::CreateView(_)
+                | PlanNode::DropView(_)
+
                 // User.
                 | PlanNode::CreateUser(_)
                 | PlanNode::DropUser(_)
@@ -12,86 +12,331 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::OnErrorMode;
 use common_meta_types::StageFileFormatType;
-use common_meta_types::StageType;
+use common_meta_types::StageStorage;
+use common_meta_types::UserPrivilegeType;
 use common_planners::CopyPlan;
 use common_streams::DataBlockStream;
+use common_streams::ParseErrors;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 use futures::TryStreamExt;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::AddOnStream;
 use crate::pipelines::transforms::CsvSourceTransform;
+use crate::pipelines::transforms::DataAccessor;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::TBL_OPT_KEY_COPIED_FILES;
+use crate::storages::Table;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
     plan: CopyPlan,
+    // The number of the files being loaded, and the peak of it.
+    loading: AtomicUsize,
+    max_loading: AtomicUsize,
+}
+
+// The file loaded into the table, kept in the table options to skip loading it again.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+struct CopiedFile {
+    size: u64,
+    // The etag of the file if it is listed from the stage.
+    #[serde(default)]
+    etag: Option<String>,
+    // Unix timestamp in seconds.
+    copied_on: i64,
+}
+
+impl CopiedFile {
+    // A file is the same one if its size is not changed, nor its etag if both are known.
+    fn is_same(&self, size: u64, etag: &Option<String>) -> bool {
+        match (&self.etag, etag) {
+            (Some(a), Some(b)) => self.size == size && a == b,
+            _ => self.size == size,
+        }
+    }
+}
+
+// The copied files of the table, keyed by the bucket and the path of the file.
+type CopiedFiles = BTreeMap<String, CopiedFile>;
+
+// The load result of one file.
+struct CopyFileResult {
+    rows_parsed: u64,
+    rows_loaded: u64,
+    error_count: u64,
+    first_error: String,
+    // The appended data to commit, empty if the file is skipped.
+    operations: Vec<DataBlock>,
 }
 
 impl CopyInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CopyPlan) -> Result<InterpreterPtr> {
-        Ok(Arc::new(CopyInterpreter { ctx, plan }))
+        Ok(Arc::new(Self::create(ctx, plan)))
+    }
+
+    pub fn create(ctx: Arc<QueryContext>, plan: CopyPlan) -> CopyInterpreter {
+        CopyInterpreter {
+            ctx,
+            plan,
+            loading: AtomicUsize::new(0),
+            max_loading: AtomicUsize::new(0),
+        }
     }
 
-    // Read a file and commit it to the table.
+    // The maximum number of the files loaded at the same time.
+    pub fn max_concurrent_loads(&self) -> usize {
+        self.max_loading.load(Ordering::Relaxed)
+    }
+
+    fn on_error(&self) -> &OnErrorMode {
+        &self.plan.stage_plan.stage_info.copy_options.on_error
+    }
+
+    // Whether a file failed to load should be skipped instead of aborting the copy.
+    fn skip_file_on_error(&self) -> bool {
+        !matches!(
+            self.on_error(),
+            OnErrorMode::None | OnErrorMode::AbortStatement
+        )
+    }
+
+    // Whether the file should be committed with the number of the rows failed to parse.
+    fn commit_with_errors(&self, error_count: u64) -> bool {
+        match self.on_error() {
+            OnErrorMode::SkipFile => error_count == 0,
+            OnErrorMode::SkipFileNum(num) => error_count < *num,
+            _ => true,
+        }
+    }
+
+    // The copied files in the table options, the expired ones are removed.
+    fn copied_files(&self, options: &HashMap<String, String>) -> Result<CopiedFiles> {
+        let mut copied_files = match options.get(TBL_OPT_KEY_COPIED_FILES) {
+            None => CopiedFiles::new(),
+            Some(v) => serde_json::from_str::<CopiedFiles>(v).map_err(|e| {
+                ErrorCode::LogicalError(format!("Invalid copied files of the table: {}", e))
+            })?,
+        };
+
+        let retention_days = self.ctx.get_settings().get_copy_history_retention_days()?;
+        let expire_on = chrono::Utc::now().timestamp() - (retention_days * 24 * 3600) as i64;
+        copied_files.retain(|_, file| file.copied_on > expire_on);
+        Ok(copied_files)
+    }
+
+    // Commit the appended data with the loaded files. The loaded files are added to the copied
+    // files of the table version committed on in the same commit, so the files loaded by the
+    // concurrent copies are kept.
+    async fn commit(
+        &self,
+        table: &dyn Table,
+        operations: Vec<DataBlock>,
+        loaded_files: &CopiedFiles,
+    ) -> Result<()> {
+        // Nothing is appended, e.g. all the files are skipped.
+        if operations.is_empty() {
+            return Ok(());
+        }
+
+        let update_options =
+            |options: &HashMap<String, String>| -> Result<HashMap<String, Option<String>>> {
+                let mut copied_files = self.copied_files(options)?;
+                copied_files.extend(loaded_files.clone());
+                let value = serde_json::to_string(&copied_files)?;
+                let mut options = HashMap::new();
+                options.insert(TBL_OPT_KEY_COPIED_FILES.to_string(), Some(value));
+                Ok(options)
+            };
+        table
+            .commit_insertion_with_options(self.ctx.clone(), operations, false, &update_options)
+            .await
+    }
+
+    // The key of the file in the copied files: {bucket}/{path}.
+    fn copied_file_key(&self, file: &Option<String>) -> String {
+        let stage_info = &self.plan.stage_plan.stage_info;
+        let path = DataAccessor::get_file_path(file.as_deref(), stage_info);
+        match &stage_info.stage_params.storage {
+            StageStorage::S3(s3) => format!("{}/{}", s3.bucket, path.trim_start_matches('/')),
+        }
+    }
+
+    // Delete the loaded file from the stage, a failure is returned as the error message
+    // instead of failing the copy, since the data has been committed.
+    async fn purge_file(&self, file: Option<String>) -> String {
+        let stage_info = &self.plan.stage_plan.stage_info;
+        match DataAccessor::delete_file(&self.ctx, file.clone(), stage_info).await {
+            Ok(_) => "".to_string(),
+            Err(cause) => {
+                tracing::warn!("Copy purge file {:?} failed, cause: {}", file, cause);
+                cause.message()
+            }
+        }
+    }
+
+    // The files to copy with their etags if listed: the explicit files, or the files under the
+    // stage path matching the pattern.
+    async fn list_files(&self) -> Result<Vec<(String, Option<String>)>> {
+        if self.plan.pattern.is_empty() {
+            return Ok(self.plan.files.iter().map(|v| (v.clone(), None)).collect());
+        }
+
+        let stage_info = &self.plan.stage_plan.stage_info;
+        let files = DataAccessor::list_stage_files(&self.ctx, stage_info).await?;
+        let matched = DataAccessor::match_stage_files(files, &self.plan.pattern)?;
+        tracing::info!(
+            "Copy pattern {:?} matched files: {:?}",
+            self.plan.pattern,
+            matched
+        );
+        Ok(matched.into_iter().map(|v| (v.path, v.md5)).collect())
+    }
+
+    // Load a file unless it is loaded previously, returns the copied file with the result.
+    async fn load_one_file(
+        &self,
+        table: Arc<dyn Table>,
+        file: Option<String>,
+        etag: Option<String>,
+        copied_files: &CopiedFiles,
+    ) -> Result<Option<(CopiedFile, CopyFileResult)>> {
+        let stage_info = &self.plan.stage_plan.stage_info;
+        let size = DataAccessor::get_file_size(&self.ctx, file.clone(), stage_info).await?;
+
+        let copied = copied_files.get(&self.copied_file_key(&file));
+        if !stage_info.copy_options.force && matches!(copied, Some(v) if v.is_same(size, &etag)) {
+            return Ok(None);
+        }
+
+        let loading = self.loading.fetch_add(1, Ordering::Relaxed) + 1;
+        self.max_loading.fetch_max(loading, Ordering::Relaxed);
+        let res = self.write_one_file(table, file).await;
+        self.loading.fetch_sub(1, Ordering::Relaxed);
+
+        let copied = CopiedFile {
+            size,
+            etag,
+            copied_on: chrono::Utc::now().timestamp(),
+        };
+        res.map(|res| Some((copied, res)))
+    }
+
+    // Read a file and append it to the table, the caller commits the appended data.
     // If the file_name is empty, we will read it {path}/{file_name}.
-    async fn write_one_file(&self, file_name: Option<String>, commit: bool) -> Result<()> {
+    async fn write_one_file(
+        &self,
+        table: Arc<dyn Table>,
+        file_name: Option<String>,
+    ) -> Result<CopyFileResult> {
         let ctx = self.ctx.clone();
         let stage_plan = self.plan.stage_plan.clone();
 
-        let source_stream = match stage_plan.stage_info.stage_type {
-            StageType::External => {
-                match stage_plan.stage_info.file_format_options.format {
-                    // CSV.
-                    StageFileFormatType::Csv => {
-                        CsvSourceTransform::try_create(
-                            self.ctx.clone(),
-                            file_name,
-                            stage_plan.clone(),
-                        )?
-                        .execute()
-                        .await
-                    }
-                    // Unsupported.
-                    format => Err(ErrorCode::LogicalError(format!(
-                        "Unsupported file format: {:?}",
-                        format
-                    ))),
-                }
-            }
+        // The rows failed to parse are skipped and collected unless abort on error.
+        let parse_errors = match self.skip_file_on_error() {
+            true => Some(Arc::new(ParseErrors::create())),
+            false => None,
+        };
 
-            StageType::Internal => Err(ErrorCode::LogicalError(
-                "Unsupported copy from internal stage",
-            )),
+        // Internal and external stages are read the same way, by the stage operator.
+        let source_stream = match stage_plan.stage_info.file_format_options.format {
+            // CSV and TSV.
+            StageFileFormatType::Csv | StageFileFormatType::Tsv => {
+                CsvSourceTransform::try_create(
+                    self.ctx.clone(),
+                    file_name,
+                    stage_plan.clone(),
+                    parse_errors.clone(),
+                )?
+                .execute()
+                .await
+            }
+            // Unsupported.
+            format => Err(ErrorCode::LogicalError(format!(
+                "Unsupported file format: {:?}",
+                format
+            ))),
         }?;
 
+        // Reorder the columns and fill the missing ones to match the table schema.
+        let source_stream = if table.schema() != self.plan.schema() {
+            Box::pin(AddOnStream::try_create(
+                source_stream,
+                self.plan.schema(),
+                table.schema(),
+            )?)
+        } else {
+            source_stream
+        };
+
+        // A large file is stopped between the blocks once the query times out.
+        let source_stream = self.ctx.try_create_timeout(source_stream)?;
+
+        let rows = Arc::new(AtomicU64::new(0));
+        let rows_loaded = rows.clone();
+        let source_stream = Box::pin(source_stream.inspect_ok(move |block| {
+            rows_loaded.fetch_add(block.num_rows() as u64, Ordering::Relaxed);
+        }));
+
         let progress_stream = Box::pin(ProgressStream::try_create(
             source_stream,
             ctx.get_scan_progress(),
         )?);
 
-        let table = ctx
-            .get_table(&self.plan.db_name, &self.plan.tbl_name)
-            .await?;
-        let r = table
+        let operations = table
             .append_data(ctx.clone(), progress_stream)
             .await?
             .try_collect()
             .await?;
 
-        if commit {
-            table.commit_insertion(ctx.clone(), r, false).await?;
+        let (error_count, first_error) = match &parse_errors {
+            Some(parse_errors) => (
+                parse_errors.count() as u64,
+                parse_errors.first_error().unwrap_or_default(),
+            ),
+            None => (0, "".to_string()),
+        };
+
+        let rows_parsed = rows.load(Ordering::Relaxed) + error_count;
+
+        // Skip the file if too many errors.
+        if !self.commit_with_errors(error_count) {
+            return Ok(CopyFileResult {
+                rows_parsed,
+                rows_loaded: 0,
+                error_count,
+                first_error,
+                operations: vec![],
+            });
         }
 
-        Ok(())
+        Ok(CopyFileResult {
+            rows_parsed,
+            rows_loaded: rows.load(Ordering::Relaxed),
+            error_count,
+            first_error,
+            operations,
+        })
     }
 }
 
@@ -101,28 +346,209 @@ impl Interpreter for CopyInterpreter {
         "CopyInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("status", Vu8::to_data_type()),
+            DataField::new("rows_parsed", u64::to_data_type()),
+            DataField::new("rows_loaded", u64::to_data_type()),
+            DataField::new("first_error", Vu8::to_data_type()),
+            DataField::new("error_count", u64::to_data_type()),
+            DataField::new("purge_error", Vu8::to_data_type()),
+        ])
+    }
+
     async fn execute(
         &self,
         mut _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         tracing::info!("Plan:{:?}", self.plan);
 
-        // Commit after each file write.
-        let commit = true;
-        let files = self.plan.files.clone();
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(self.plan.db_name.clone(), self.plan.tbl_name.clone()),
+                UserPrivilegeType::Insert,
+            )
+            .await?;
 
-        if files.is_empty() {
-            self.write_one_file(None, commit).await?;
-        } else {
-            for file in files {
-                self.write_one_file(Some(file), commit).await?;
+        let copy_options = &self.plan.stage_plan.stage_info.copy_options;
+        let mut files = match self.plan.files.is_empty() && self.plan.pattern.is_empty() {
+            true => vec![(None, None)],
+            false => self
+                .list_files()
+                .await?
+                .into_iter()
+                .map(|(file, etag)| (Some(file), etag))
+                .collect(),
+        };
+        // The same file is loaded once.
+        let mut seen = HashSet::new();
+        files.retain(|(file, _)| seen.insert(file.clone()));
+        if copy_options.max_files > 0 {
+            files.truncate(copy_options.max_files);
+        }
+
+        let table = self
+            .ctx
+            .get_table(&self.plan.db_name, &self.plan.tbl_name)
+            .await?;
+
+        // The files loaded before are skipped unless force.
+        let previous_files = self.copied_files(table.options())?;
+        // The files loaded by this copy, added to the copied files by the commit.
+        let mut loaded_files = CopiedFiles::new();
+
+        // The committed files to delete from the stage if purge, with the index of the result row.
+        let mut purge_files = vec![];
+
+        let mut operations = vec![];
+        let mut bytes_loaded = 0;
+
+        let mut file_names = Vec::with_capacity(files.len());
+        let mut statuses = Vec::with_capacity(files.len());
+        let mut rows_parsed = Vec::with_capacity(files.len());
+        let mut rows_loaded = Vec::with_capacity(files.len());
+        let mut first_errors = Vec::with_capacity(files.len());
+        let mut error_counts = Vec::with_capacity(files.len());
+        let mut purge_errors = Vec::with_capacity(files.len());
+
+        // The files are loaded concurrently, each by its own source and append stream, and
+        // the results are handled in the order of the files. Returning before all the files
+        // are handled drops the stream, which cancels the loads in progress, their appended
+        // data is never committed.
+        let concurrency = self.ctx.get_settings().get_max_copy_concurrency()?.max(1) as usize;
+        let mut results = futures::stream::iter(files)
+            .map(|(file, etag)| {
+                let table = table.clone();
+                let previous_files = &previous_files;
+                async move {
+                    let res = self
+                        .load_one_file(table, file.clone(), etag, previous_files)
+                        .await;
+                    (file, res)
+                }
+            })
+            .buffered(concurrency);
+
+        while let Some((file, res)) = results.next().await {
+            // Stop once the query times out or is killed, the files not committed yet are dropped.
+            self.ctx.check_execution_time()?;
+            self.ctx.check_aborting()?;
+
+            let file_name = match &file {
+                Some(v) => v.clone(),
+                None => self.plan.stage_plan.stage_info.stage_name.clone(),
+            };
+
+            // Stop once the size limit is reached, the files loaded after are not committed.
+            if copy_options.size_limit > 0 && bytes_loaded >= copy_options.size_limit as u64 {
+                tracing::info!(
+                    "Copy size limit {} reached, stop before file {}",
+                    copy_options.size_limit,
+                    file_name
+                );
+                break;
+            }
+
+            let file_key = self.copied_file_key(&file);
+            match res {
+                Ok(None) => {
+                    tracing::info!("Copy skipped file {}, loaded previously", file_name);
+                    file_names.push(file_name);
+                    statuses.push("LOADED_PREVIOUSLY".to_string());
+                    rows_parsed.push(0);
+                    rows_loaded.push(0);
+                    first_errors.push("".to_string());
+                    error_counts.push(0);
+                    purge_errors.push("".to_string());
+                    continue;
+                }
+                Ok(Some((copied, res))) => {
+                    // The file is loaded unless skipped by too many errors.
+                    let committed = self.commit_with_errors(res.error_count);
+                    if committed {
+                        // The size limit is of the source files, not the parsed data.
+                        bytes_loaded += copied.size;
+                        loaded_files.insert(file_key, copied);
+                        // The files skipped by errors are never purged.
+                        if copy_options.purge {
+                            purge_files.push((file_names.len(), file));
+                        }
+                    }
+
+                    if copy_options.commit_per_file {
+                        self.commit(table.as_ref(), res.operations, &loaded_files)
+                            .await?;
+                        loaded_files.clear();
+                    } else {
+                        operations.extend(res.operations);
+                    }
+
+                    statuses.push(match (committed, res.error_count) {
+                        (false, _) => "LOAD_FAILED".to_string(),
+                        (true, 0) => "LOADED".to_string(),
+                        (true, _) => "PARTIALLY_LOADED".to_string(),
+                    });
+
+                    if res.error_count > 0 {
+                        tracing::warn!(
+                            "Copy file {} with {} errors, first error: {}",
+                            file_name,
+                            res.error_count,
+                            res.first_error
+                        );
+                    }
+                    rows_parsed.push(res.rows_parsed);
+                    rows_loaded.push(res.rows_loaded);
+                    first_errors.push(res.first_error);
+                    error_counts.push(res.error_count);
+                }
+                // Skip the file and continue with the next one.
+                Err(cause) if self.skip_file_on_error() => {
+                    tracing::warn!("Copy skipped file {}, cause: {}", file_name, cause);
+                    statuses.push("LOAD_FAILED".to_string());
+                    rows_parsed.push(0);
+                    rows_loaded.push(0);
+                    first_errors.push(cause.message());
+                    error_counts.push(1);
+                }
+                Err(cause) => {
+                    let msg = format!(" (while in copy file {})", file_name);
+                    return Err(cause.add_message_back(msg));
+                }
+            }
+            file_names.push(file_name);
+            purge_errors.push("".to_string());
+
+            if copy_options.commit_per_file {
+                for (i, file) in purge_files.drain(..) {
+                    purge_errors[i] = self.purge_file(file).await;
+                }
+            }
+        }
+
+        drop(results);
+
+        // Commit all the files at once, nothing is loaded if any file fails.
+        if !copy_options.commit_per_file {
+            self.commit(table.as_ref(), operations, &loaded_files)
+                .await?;
+            for (i, file) in purge_files.drain(..) {
+                purge_errors[i] = self.purge_file(file).await;
             }
         }
 
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        let schema = self.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(file_names),
+            Series::from_data(statuses),
+            Series::from_data(rows_parsed),
+            Series::from_data(rows_loaded),
+            Series::from_data(first_errors),
+            Series::from_data(error_counts),
+            Series::from_data(purge_errors),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
     }
 }
@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::FileFormatOptions;
+use common_meta_types::StageFileFormatType;
+use common_planners::CopyIntoStagePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::transforms::DataAccessor;
+use crate::sessions::QueryContext;
+
+pub struct CopyIntoStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CopyIntoStagePlan,
+}
+
+// The unloaded file, written to the stage once it is full or the query is done.
+struct UnloadFile {
+    rows: u64,
+    data: Vec<u8>,
+}
+
+impl CopyIntoStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CopyIntoStagePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoStageInterpreter { ctx, plan }))
+    }
+
+    fn file_extension(&self) -> &str {
+        match self.plan.stage_plan.stage_info.file_format_options.format {
+            StageFileFormatType::Json => "ndjson",
+            _ => "csv",
+        }
+    }
+
+    // Serialize the rows of the block, one record per row.
+    fn serialize_block(&self, block: &DataBlock) -> Result<Vec<Vec<u8>>> {
+        let options = &self.plan.stage_plan.stage_info.file_format_options;
+        match options.format {
+            StageFileFormatType::Csv => serialize_csv(block, options),
+            StageFileFormatType::Json => serialize_ndjson(block),
+            format => Err(ErrorCode::UnImplement(format!(
+                "Unsupported file format for copy into stage: {:?}",
+                format
+            ))),
+        }
+    }
+
+    // Write the file to the stage, returns the name of the file.
+    async fn write_file(&self, index: usize, file: UnloadFile) -> Result<String> {
+        let stage_info = &self.plan.stage_plan.stage_info;
+        let name = format!(
+            "data_{}_{}.{}",
+            self.ctx.get_id(),
+            index,
+            self.file_extension()
+        );
+        let path = DataAccessor::get_file_path(Some(&name), stage_info);
+
+        let operator = DataAccessor::get_operator(&self.ctx, stage_info).await?;
+        operator
+            .object(&path)
+            .writer()
+            .write_bytes(file.data)
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+
+        tracing::info!("Copy into stage file {}, rows:{}", path, file.rows);
+        Ok(name)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoStageInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoStageInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", Vu8::to_data_type()),
+            DataField::new("rows_unloaded", u64::to_data_type()),
+            DataField::new("file_size", u64::to_data_type()),
+        ])
+    }
+
+    async fn execute(
+        &self,
+        mut _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        tracing::info!("Plan:{:?}", self.plan);
+
+        let query = InterpreterFactory::get(self.ctx.clone(), *self.plan.query.clone())?;
+        let mut stream = query.execute(None).await?;
+
+        let max_file_size = self.plan.max_file_size;
+        let mut file_names = vec![];
+        let mut rows_unloaded = vec![];
+        let mut file_sizes = vec![];
+
+        let mut file = UnloadFile {
+            rows: 0,
+            data: vec![],
+        };
+        while let Some(block) = stream.try_next().await? {
+            for record in self.serialize_block(&block)? {
+                // Start a new file if the record makes the file exceed the max size.
+                let full = max_file_size > 0 && file.data.len() + record.len() > max_file_size;
+                if full && file.rows > 0 {
+                    let full_file = std::mem::replace(&mut file, UnloadFile {
+                        rows: 0,
+                        data: vec![],
+                    });
+                    rows_unloaded.push(full_file.rows);
+                    file_sizes.push(full_file.data.len() as u64);
+                    file_names.push(self.write_file(file_names.len(), full_file).await?);
+                }
+
+                file.rows += 1;
+                file.data.extend_from_slice(&record);
+            }
+        }
+
+        // The last file, nothing is written if the query returns no rows.
+        if file.rows > 0 {
+            rows_unloaded.push(file.rows);
+            file_sizes.push(file.data.len() as u64);
+            file_names.push(self.write_file(file_names.len(), file).await?);
+        }
+
+        let schema = self.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(file_names),
+            Series::from_data(rows_unloaded),
+            Series::from_data(file_sizes),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
+
+// Serialize the rows as CSV records, the fields with the special characters are quoted.
+fn serialize_csv(block: &DataBlock, options: &FileFormatOptions) -> Result<Vec<Vec<u8>>> {
+    let field_delimiter = match options.field_delimiter.is_empty() {
+        true => ",",
+        false => options.field_delimiter.as_str(),
+    };
+    let record_delimiter = match options.record_delimiter.is_empty() {
+        true => "\n",
+        false => options.record_delimiter.as_str(),
+    };
+    let quote = match options.quote.is_empty() {
+        true => "\"",
+        false => options.quote.as_str(),
+    };
+
+    let columns = serialize_columns(block, |serializer, column| {
+        serializer.serialize_column(column)
+    })?;
+
+    let mut records = Vec::with_capacity(block.num_rows());
+    for row in 0..block.num_rows() {
+        let mut record = String::new();
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                record.push_str(field_delimiter);
+            }
+
+            let field = &column[row];
+            let need_quote = field.contains(field_delimiter)
+                || field.contains(quote)
+                || field.contains('\n')
+                || field.contains('\r');
+            match need_quote {
+                true => {
+                    let escaped = field.replace(quote, &format!("{}{}", quote, quote));
+                    record.push_str(quote);
+                    record.push_str(&escaped);
+                    record.push_str(quote);
+                }
+                false => record.push_str(field),
+            }
+        }
+        record.push_str(record_delimiter);
+        records.push(record.into_bytes());
+    }
+    Ok(records)
+}
+
+// Serialize the rows as newline delimited JSON objects, keyed by the column names.
+fn serialize_ndjson(block: &DataBlock) -> Result<Vec<Vec<u8>>> {
+    let columns = serialize_columns(block, |serializer, column| {
+        serializer.serialize_json(column)
+    })?;
+    let names = block
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| serde_json::to_string(f.name()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut records = Vec::with_capacity(block.num_rows());
+    for row in 0..block.num_rows() {
+        let mut record = vec![b'{'];
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                record.push(b',');
+            }
+            record.extend_from_slice(names[i].as_bytes());
+            record.push(b':');
+            serde_json::to_writer(&mut record, &column[row])?;
+        }
+        record.extend_from_slice(b"}\n");
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn serialize_columns<T, F>(block: &DataBlock, serialize: F) -> Result<Vec<Vec<T>>>
+where F: Fn(&dyn TypeSerializer, &ColumnRef) -> Result<Vec<T>> {
+    block
+        .columns()
+        .iter()
+        .zip(block.schema().fields())
+        .map(|(column, field)| {
+            let serializer = field.data_type().create_serializer();
+            serialize(serializer.as_ref(), column)
+        })
+        .collect()
+}
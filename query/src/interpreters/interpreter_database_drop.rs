@@ -14,7 +14,9 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::DropTableReq;
 use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
 use common_planners::DropDatabasePlan;
@@ -52,9 +54,48 @@ impl Interpreter for DropDatabaseInterpreter {
             .validate_privilege(&GrantObject::Global, UserPrivilegeType::Drop)
             .await?;
 
+        let tenant = self.plan.tenant.as_str();
+        let db_name = self.plan.db.as_str();
         let catalog = self.ctx.get_catalog();
+
+        // A missing database is left to `drop_database`, which honors IF EXISTS.
+        if catalog.exists_database(tenant, db_name).await? {
+            let tables = catalog.list_tables(tenant, db_name).await?;
+            if !tables.is_empty() && !self.plan.cascade {
+                let mut table_names = tables.iter().map(|t| t.name()).collect::<Vec<_>>();
+                table_names.sort_unstable();
+                return Err(ErrorCode::DatabaseNotEmpty(format!(
+                    "Cannot drop database '{}', it still contains tables: {}. Use DROP DATABASE ... CASCADE to drop them too",
+                    db_name,
+                    table_names.join(", ")
+                )));
+            }
+
+            for table in tables {
+                catalog
+                    .drop_table(DropTableReq {
+                        if_exists: true,
+                        tenant: tenant.to_string(),
+                        db: db_name.to_string(),
+                        table: table.name().to_string(),
+                    })
+                    .await?;
+
+                // Purge the storage data of the table, the same as DROP TABLE does.
+                let keep_last_snapshot = false;
+                table.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            }
+        }
+
         catalog.drop_database(self.plan.clone().into()).await?;
 
+        // The session can't stay in a database which doesn't exist any more.
+        if self.ctx.get_current_database() == db_name {
+            self.ctx
+                .get_current_session()
+                .set_current_database("default".to_string());
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -56,19 +57,16 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         let name = db.name();
         let mut info = format!("CREATE DATABASE `{}`", name);
         if !db.engine().is_empty() {
-            let engine = format!(" ENGINE={}", db.engine().to_uppercase());
-            let engine_options = db
-                .engine_options()
-                .iter()
-                .map(|(k, v)| format!("{}='{}'", k, v))
-                .collect::<Vec<_>>()
-                .join(", ");
-            if !engine_options.is_empty() {
-                info.push_str(&format!("{}({})", engine, engine_options));
-            } else {
-                info.push_str(&engine);
+            info.push_str(&format!(" ENGINE={}", db.engine().to_uppercase()));
+            if !db.engine_options().is_empty() {
+                let engine_options = Self::format_options(db.engine_options(), false);
+                info.push_str(&format!("({})", engine_options.join(" ")));
             }
         }
+        for option in Self::format_options(db.options(), true) {
+            info.push_str(&format!(" {}", option));
+        }
+
         let schema = self.plan.schema();
         let block = DataBlock::create(schema.clone(), vec![
             Series::from_data(vec![name.as_bytes()]),
@@ -77,3 +75,21 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
     }
 }
+
+impl ShowCreateDatabaseInterpreter {
+    // Sorted by key to keep the output stable, the values are quoted to be parsed back.
+    fn format_options(options: &HashMap<String, String>, uppercase_key: bool) -> Vec<String> {
+        let mut options = options.iter().collect::<Vec<_>>();
+        options.sort();
+        options
+            .into_iter()
+            .map(|(k, v)| {
+                let key = match uppercase_key {
+                    true => k.to_uppercase(),
+                    false => k.to_string(),
+                };
+                format!("{}='{}'", key, v.replace('\'', "''"))
+            })
+            .collect()
+    }
+}
@@ -13,21 +13,32 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::InsertInputSource;
+use common_planners::InsertPlan;
+use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
 
 use crate::interpreters::plan_schedulers;
+use crate::interpreters::plan_schedulers::InsertWithPlan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
 use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::PlanNodeProfiles;
 use crate::sessions::QueryContext;
+use crate::storages::Table;
 
 pub struct ExplainInterpreter {
     ctx: Arc<QueryContext>,
@@ -50,6 +61,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -110,4 +122,118 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create(schema, vec![formatted_pipeline]))
     }
+
+    // Execute the query and discard the results, show the plan with the actual statistics.
+    // The rows of INSERT INTO ... SELECT are inserted, the same as the statement without EXPLAIN.
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let schema = self.schema();
+        let optimizer = Optimizers::without_scatters(self.ctx.clone());
+        let (plan, insertion) = match self.explain.input.as_ref() {
+            PlanNode::Insert(insert) => {
+                let table = self.get_insert_table(insert).await?;
+                let plan = match &insert.source {
+                    InsertInputSource::SelectPlan(plan_node) => {
+                        let with_plan = InsertWithPlan::new(&self.ctx, &insert.schema, plan_node);
+                        with_plan.build_plan(optimizer, table.get_table_info())?
+                    }
+                    _ => {
+                        return Err(ErrorCode::SyntaxException(
+                            "EXPLAIN ANALYZE only supports INSERT INTO ... SELECT",
+                        ))
+                    }
+                };
+                (plan, Some((table, insert.overwrite)))
+            }
+            input => (plan_schedulers::apply_plan_rewrite(optimizer, input)?, None),
+        };
+
+        let start = Instant::now();
+        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
+        let (mut pipeline, profiles) = pipeline_builder.build_with_profiles(&plan)?;
+        let mut stream = pipeline.execute().await?;
+        match insertion {
+            Some((table, overwrite)) => {
+                // The output of the sink is the append operation logs.
+                let append_logs = stream.try_collect().await?;
+                table
+                    .commit_insertion(self.ctx.clone(), append_logs, overwrite)
+                    .await?;
+            }
+            None => while stream.try_next().await?.is_some() {},
+        }
+        let elapsed = start.elapsed();
+
+        let mut lines = vec![];
+        Self::format_analyze(&plan, &profiles, 0, &mut lines);
+        lines.push(format!(
+            "Total time: {:.3} ms",
+            elapsed.as_secs_f64() * 1000.0
+        ));
+
+        let formatted_plan =
+            Series::from_data(lines.iter().map(|s| s.as_bytes()).collect::<Vec<_>>());
+        Ok(DataBlock::create(schema, vec![formatted_plan]))
+    }
+
+    async fn get_insert_table(&self, insert: &InsertPlan) -> Result<Arc<dyn Table>> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(insert.database_name.clone(), insert.table_name.clone()),
+                UserPrivilegeType::Insert,
+            )
+            .await?;
+
+        self.ctx
+            .get_table(&insert.database_name, &insert.table_name)
+            .await
+    }
+
+    fn format_analyze(
+        node: &PlanNode,
+        profiles: &PlanNodeProfiles,
+        indent: usize,
+        lines: &mut Vec<String>,
+    ) {
+        let mut indent = indent;
+        if !matches!(node, PlanNode::Select(_) | PlanNode::Empty(_)) {
+            let formatted_node = format!("{:?}", node);
+            let mut line = format!(
+                "{}{}",
+                "  ".repeat(indent),
+                formatted_node.lines().next().unwrap_or_default()
+            );
+
+            match profiles.get(node) {
+                // The output of the sink is the append operation logs, the rows are of its input.
+                Some(profile) if matches!(node, PlanNode::Sink(_)) => {
+                    line.push_str(&format!(
+                        " (time: {:.3} ms)",
+                        profile.elapsed().as_secs_f64() * 1000.0
+                    ));
+                }
+                Some(profile) => {
+                    line.push_str(&format!(
+                        " (actual rows: {}, blocks: {}, bytes: {}, time: {:.3} ms",
+                        profile.rows(),
+                        profile.blocks(),
+                        profile.bytes(),
+                        profile.elapsed().as_secs_f64() * 1000.0
+                    ));
+                    if let PlanNode::ReadSource(plan) = node {
+                        line.push_str(&format!(", estimated rows: {}", plan.statistics.read_rows));
+                    }
+                    line.push(')');
+                }
+                None => {}
+            }
+
+            lines.push(line);
+            indent += 1;
+        }
+
+        for input in node.inputs() {
+            Self::format_analyze(&input, profiles, indent, lines);
+        }
+    }
 }
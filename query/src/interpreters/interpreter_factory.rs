@@ -20,27 +20,37 @@ use common_planners::PlanNode;
 use common_planners::ShowPlan;
 
 use crate::interpreters::interpreter_show_engines::ShowEnginesInterpreter;
+use crate::interpreters::AlterTableInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::AlterUserUDFInterpreter;
 use crate::interpreters::CopyInterpreter;
+use crate::interpreters::CopyIntoStageInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
 use crate::interpreters::CreateRoleInterpreter;
 use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
+use crate::interpreters::CreateUserStageInterpreter;
 use crate::interpreters::CreateUserUDFInterpreter;
+use crate::interpreters::CreateViewInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
+use crate::interpreters::DescribeUserStageInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
 use crate::interpreters::DropRoleInterpreter;
 use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::DropUserStageInterpreter;
 use crate::interpreters::DropUserUDFInterpreter;
+use crate::interpreters::DropViewInterpreter;
 use crate::interpreters::ExplainInterpreter;
 use crate::interpreters::GrantPrivilegeInterpreter;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
+use crate::interpreters::ListUserStageInterpreter;
 use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::RenameTableInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
@@ -55,6 +65,7 @@ use crate::interpreters::ShowSettingsInterpreter;
 use crate::interpreters::ShowTablesInterpreter;
 use crate::interpreters::ShowUsersInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
 use crate::interpreters::UseTenantInterpreter;
 use crate::sessions::QueryContext;
@@ -73,9 +84,12 @@ impl InterpreterFactory {
 
             // Insert.
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx_clone, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx_clone, v),
 
             // Copy.
             PlanNode::Copy(v) => CopyInterpreter::try_create(ctx_clone, v),
+            PlanNode::CopyIntoStage(v) => CopyIntoStageInterpreter::try_create(ctx_clone, v),
 
             // Show.
             PlanNode::Show(ShowPlan::ShowDatabases(v)) => {
@@ -116,10 +130,14 @@ impl InterpreterFactory {
             // Table.
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::RenameTable(v) => RenameTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterTable(v) => AlterTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::CreateView(v) => CreateViewInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropView(v) => DropViewInterpreter::try_create(ctx_clone, v),
 
             // User.
             PlanNode::CreateUser(v) => CreateUserInterpreter::try_create(ctx_clone, v),
@@ -132,6 +150,14 @@ impl InterpreterFactory {
             PlanNode::CreateRole(v) => CreateRoleInterpreter::try_create(ctx_clone, v),
             PlanNode::DropRole(v) => DropRoleInterpreter::try_create(ctx_clone, v),
 
+            // Stage.
+            PlanNode::CreateUserStage(v) => CreateUserStageInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropUserStage(v) => DropUserStageInterpreter::try_create(ctx_clone, v),
+            PlanNode::DescribeUserStage(v) => {
+                DescribeUserStageInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::ListUserStage(v) => ListUserStageInterpreter::try_create(ctx_clone, v),
+
             // UDF.
             PlanNode::CreateUserUDF(v) => CreateUserUDFInterpreter::try_create(ctx_clone, v),
             PlanNode::DropUserUDF(v) => DropUserUDFInterpreter::try_create(ctx_clone, v),
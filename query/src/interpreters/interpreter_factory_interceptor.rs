@@ -59,6 +59,7 @@ impl Interpreter for InterceptorInterpreter {
         self.management_mode_access.check(&self.plan)?;
 
         let result_stream = self.inner.execute(input_stream).await?;
+        let result_stream = self.ctx.try_create_timeout(result_stream)?;
         let metric_stream =
             ProgressStream::try_create(result_stream, self.ctx.get_result_progress())?;
         Ok(Box::pin(metric_stream))
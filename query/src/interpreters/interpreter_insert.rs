@@ -63,6 +63,17 @@ impl Interpreter for InsertInterpreter {
             )
             .await?;
 
+        // The rows having the same primary keys are deleted by REPLACE INTO.
+        if plan.replace {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(
+                    &GrantObject::Table(plan.database_name.clone(), plan.table_name.clone()),
+                    UserPrivilegeType::Delete,
+                )
+                .await?;
+        }
+
         let table = self
             .ctx
             .get_table(&plan.database_name, &plan.table_name)
@@ -70,6 +81,41 @@ impl Interpreter for InsertInterpreter {
 
         let need_fill_missing_columns = table.schema() != self.plan.schema();
 
+        if self.plan.replace {
+            let stream = match &self.plan.source {
+                InsertInputSource::Expressions(values_exprs) => {
+                    values_exprs.to_stream(self.plan.schema.clone())?
+                }
+                InsertInputSource::StreamingWithFormat(_) => input_stream
+                    .take()
+                    .ok_or_else(|| ErrorCode::EmptyData("input stream not exist or consumed"))?,
+                InsertInputSource::SelectPlan(_) => {
+                    return Err(ErrorCode::SyntaxException(
+                        "Unsupported replace into ... select statement.",
+                    ));
+                }
+            };
+
+            let stream = if need_fill_missing_columns {
+                Box::pin(AddOnStream::try_create(
+                    stream,
+                    self.plan.schema(),
+                    table.schema(),
+                )?)
+            } else {
+                stream
+            };
+
+            // The rows are committed by the table along with the replaced ones.
+            let with_stream = InsertWithStream::new(&self.ctx, &table);
+            with_stream.replace_stream(stream).await?;
+            return Ok(Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
         let append_logs = match &self.plan.source {
             InsertInputSource::SelectPlan(plan_node) => {
                 let with_plan = InsertWithPlan::new(&self.ctx, &self.plan.schema, plan_node);
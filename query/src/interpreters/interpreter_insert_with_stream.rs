@@ -44,10 +44,29 @@ impl<'a> InsertWithStream<'a> {
             input,
             self.ctx.get_scan_progress(),
         )?);
+        let progress_stream = Box::pin(ProgressStream::try_create(
+            progress_stream,
+            self.ctx.get_write_progress(),
+        )?);
         self.table
             .append_data(self.ctx.clone(), progress_stream)
             .await
     }
+
+    pub async fn replace_stream(
+        &self,
+        input: SendableDataBlockStream,
+    ) -> common_exception::Result<()> {
+        let progress_stream = Box::pin(ProgressStream::try_create(
+            input,
+            self.ctx.get_scan_progress(),
+        )?);
+        let progress_stream = Box::pin(ProgressStream::try_create(
+            progress_stream,
+            self.ctx.get_write_progress(),
+        )?);
+        self.table.replace(self.ctx.clone(), progress_stream).await
+    }
 }
 
 pub trait SendableWithSchema {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
@@ -22,6 +23,7 @@ use common_meta_types::UserPrivilegeType;
 use common_planners::KillPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -54,21 +56,46 @@ impl Interpreter for KillInterpreter {
             .await?;
 
         let id = &self.plan.id;
-        match self.ctx.get_session_by_id(id) {
-            None => Err(ErrorCode::UnknownSession(format!(
-                "Not found session id {}",
-                id
-            ))),
-            Some(kill_session) if self.plan.kill_connection => {
-                kill_session.force_kill_session();
-                let schema = Arc::new(DataSchema::empty());
-                Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+        let kill_session = match self.ctx.get_session_by_id(id) {
+            Some(kill_session) => kill_session,
+            None if self.plan.kill_connection => {
+                return Err(ErrorCode::UnknownSession(format!(
+                    "Not found session id {}",
+                    id
+                )));
             }
-            Some(kill_session) => {
-                kill_session.force_kill_query();
-                let schema = Arc::new(DataSchema::empty());
-                Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+            // The running query is killed by its query id as well.
+            None => match self.ctx.get_session_by_query_id(id) {
+                Some(kill_session) => kill_session,
+                None => {
+                    return Err(ErrorCode::UnknownQuery(format!(
+                        "Not found query id {}",
+                        id
+                    )));
+                }
+            },
+        };
+
+        if self.plan.kill_connection {
+            kill_session.force_kill_session();
+        } else if let Some(context_shared) = kill_session.force_kill_query() {
+            // The KILL itself would be waited for if it kills the query of its own session.
+            if kill_session.get_id() != self.ctx.get_current_session().get_id() {
+                let timeout = self.ctx.get_settings().get_kill_query_timeout_ms()?;
+                if !context_shared
+                    .wait_finished(Duration::from_millis(timeout))
+                    .await
+                {
+                    tracing::warn!(
+                        "The killed query {} is not finished in {} ms",
+                        context_shared.get_query_id(),
+                        timeout
+                    );
+                }
             }
         }
+
+        let schema = Arc::new(DataSchema::empty());
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
     }
 }
@@ -83,7 +83,10 @@ impl Interpreter for SelectInterpreter {
             new_pipeline.set_max_threads(settings.get_max_threads()? as usize);
             let executor = PipelinePullingExecutor::try_create(new_pipeline)?;
 
-            Ok(Box::pin(NewProcessorStreamWrap::create(executor)?))
+            Ok(Box::pin(NewProcessorStreamWrap::create(
+                self.ctx.clone(),
+                executor,
+            )?))
         } else {
             let optimized_plan = self.rewrite_plan()?;
             plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
@@ -92,13 +95,14 @@ impl Interpreter for SelectInterpreter {
 }
 
 struct NewProcessorStreamWrap {
+    ctx: Arc<QueryContext>,
     executor: PipelinePullingExecutor,
 }
 
 impl NewProcessorStreamWrap {
-    pub fn create(mut executor: PipelinePullingExecutor) -> Result<Self> {
+    pub fn create(ctx: Arc<QueryContext>, mut executor: PipelinePullingExecutor) -> Result<Self> {
         executor.start()?;
-        Ok(Self { executor })
+        Ok(Self { ctx, executor })
     }
 }
 
@@ -107,6 +111,10 @@ impl Stream for NewProcessorStreamWrap {
 
     fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let self_ = Pin::get_mut(self);
+        if let Err(cause) = self_.ctx.check_aborting() {
+            return Poll::Ready(Some(Err(cause)));
+        }
+
         match self_.executor.pull_data() {
             Some(data) => Poll::Ready(Some(Ok(data))),
             None => match self_.executor.finish() {
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::SettingPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -46,14 +48,23 @@ impl Interpreter for SettingInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.set.clone();
+        if plan.is_global {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+                .await?;
+        }
+
         for var in plan.vars {
             match var.variable.to_lowercase().as_str() {
                 // To be compatible with some drivers
                 "sql_mode" | "autocommit" => {}
                 _ => {
-                    self.ctx
-                        .get_settings()
-                        .set_settings(var.variable, var.value, false)?;
+                    self.ctx.get_settings().set_settings(
+                        var.variable,
+                        var.value,
+                        plan.is_global,
+                    )?;
                 }
             }
         }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
+use common_planners::PlanShowKind;
 use common_planners::ShowSettingsPlan;
 use common_streams::SendableDataBlockStream;
 
@@ -29,7 +30,6 @@ use crate::sql::PlanParser;
 
 pub struct ShowSettingsInterpreter {
     ctx: Arc<QueryContext>,
-    #[allow(dead_code)]
     plan: ShowSettingsPlan,
 }
 
@@ -39,7 +39,18 @@ impl ShowSettingsInterpreter {
     }
 
     fn build_query(&self) -> Result<String> {
-        Ok("SELECT name, value, default, level, description, type FROM system.settings ORDER BY name".to_string())
+        let select = "SELECT name, value, default, level, description, type FROM system.settings";
+        match &self.plan.kind {
+            PlanShowKind::All => Ok(format!("{} ORDER BY name", select)),
+            PlanShowKind::Like(expr) => {
+                Ok(format!("{} WHERE name LIKE {} ORDER BY name", select, expr))
+            }
+            PlanShowKind::Where(expr) => Ok(format!("{} WHERE {} ORDER BY name", select, expr)),
+            kind => Err(ErrorCode::UnImplement(format!(
+                "Show settings unsupported: {:?}",
+                kind
+            ))),
+        }
     }
 }
 
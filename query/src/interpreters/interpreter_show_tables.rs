@@ -39,19 +39,20 @@ impl ShowTablesInterpreter {
     }
 
     fn build_query(&self) -> Result<String> {
-        let database = self.ctx.get_current_database();
+        let columns = match self.plan.showfull {
+            true => "name, engine, created_on",
+            false => "name",
+        };
+        let database = self.plan.database.replace('\'', "''");
         return match &self.plan.kind {
-            PlanShowKind::All => {
-                Ok(format!("SELECT name FROM system.tables WHERE database = '{}' ORDER BY database, name", database))
+            PlanShowKind::All | PlanShowKind::FromOrIn(_) => {
+                Ok(format!("SELECT {} FROM system.tables WHERE database = '{}' ORDER BY database, name", columns, database))
             }
             PlanShowKind::Like(v) => {
-                Ok(format!("SELECT name FROM system.tables WHERE database = '{}' AND name LIKE {} ORDER BY database, name", database, v))
+                Ok(format!("SELECT {} FROM system.tables WHERE database = '{}' AND name LIKE {} ORDER BY database, name", columns, database, v))
             }
             PlanShowKind::Where(v) => {
-                Ok(format!("SELECT name FROM system.tables WHERE database = '{}' AND ({}) ORDER BY database, name", database, v))
-            }
-            PlanShowKind::FromOrIn(v) => {
-                Ok(format!("SELECT name FROM system.tables WHERE database = '{}' ORDER BY database, name", v))
+                Ok(format!("SELECT {} FROM system.tables WHERE database = '{}' AND ({}) ORDER BY database, name", columns, database, v))
             }
         };
     }
@@ -16,7 +16,10 @@ use std::sync::Arc;
 
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::DropTableReq;
 use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
 use common_planners::CreateTablePlan;
@@ -25,6 +28,7 @@ use common_planners::InsertPlan;
 use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 
 use super::InsertInterpreter;
 use crate::catalogs::Catalog;
@@ -80,10 +84,63 @@ impl CreateTableInterpreter {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog();
 
-        // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.plan.clone().into()).await?;
+        // The table is created by this query or not at all, IF NOT EXISTS skips both the creation
+        // and the population of an existing table.
+        let mut req: CreateTableReq = self.plan.clone().into();
+        req.if_not_exists = false;
+        match catalog.create_table(req).await {
+            Err(cause)
+                if self.plan.if_not_exists
+                    && cause.code() == ErrorCode::TableAlreadyExistsCode() =>
+            {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.plan.schema(),
+                    None,
+                    vec![],
+                )));
+            }
+            res => res?,
+        };
+
+        // The table creation and insertion is not a transaction, the table is dropped if the
+        // insertion fails.
+        if let Err(cause) = self
+            .insert_select(input_stream, select_plan_node, &tenant)
+            .await
+        {
+            let drop_req = DropTableReq {
+                if_exists: true,
+                tenant,
+                db: self.plan.db.clone(),
+                table: self.plan.table.clone(),
+            };
+            if let Err(drop_cause) = catalog.drop_table(drop_req).await {
+                tracing::warn!(
+                    "Drop table {}.{} failed after create table as select failed, cause: {}",
+                    self.plan.db,
+                    self.plan.table,
+                    drop_cause
+                );
+            }
+            return Err(cause);
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+
+    async fn insert_select(
+        &self,
+        input_stream: Option<SendableDataBlockStream>,
+        select_plan_node: Box<PlanNode>,
+        tenant: &str,
+    ) -> Result<()> {
+        let catalog = self.ctx.get_catalog();
         let table = catalog
-            .get_table(tenant.as_str(), &self.plan.db, &self.plan.table)
+            .get_table(tenant, &self.plan.db, &self.plan.table)
             .await?;
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
@@ -110,16 +167,12 @@ impl CreateTableInterpreter {
             table_id: table.get_id(),
             schema,
             overwrite: false,
+            replace: false,
             source: InsertInputSource::SelectPlan(select_plan_node),
         };
         let insert_interpreter = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?;
         insert_interpreter.execute(input_stream).await?;
-
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        Ok(())
     }
 
     async fn create_table(&self) -> Result<SendableDataBlockStream> {
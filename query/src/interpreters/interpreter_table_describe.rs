@@ -18,6 +18,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::DescribeTablePlan;
+use common_planners::Expression;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
@@ -54,6 +55,7 @@ impl Interpreter for DescribeTableInterpreter {
         let mut names: Vec<String> = vec![];
         let mut types: Vec<String> = vec![];
         let mut nulls: Vec<String> = vec![];
+        let mut default_exprs: Vec<Option<String>> = vec![];
         for field in schema.fields().iter() {
             names.push(field.name().to_string());
             types.push(format!("{:?}", remove_nullable(field.data_type())));
@@ -62,10 +64,20 @@ impl Interpreter for DescribeTableInterpreter {
             } else {
                 "NO".to_string()
             });
+            default_exprs.push(match field.default_expr() {
+                Some(expr) => Some(serde_json::from_slice::<Expression>(expr)?.column_name()),
+                None => None,
+            });
         }
         let names: Vec<&[u8]> = names.iter().map(|x| x.as_bytes()).collect();
         let types: Vec<&[u8]> = types.iter().map(|x| x.as_bytes()).collect();
         let nulls: Vec<&[u8]> = nulls.iter().map(|x| x.as_bytes()).collect();
+        let keys: Vec<&[u8]> = vec!["".as_bytes(); schema.num_fields()];
+        let default_exprs: Vec<Option<&[u8]>> = default_exprs
+            .iter()
+            .map(|x| x.as_ref().map(|x| x.as_bytes()))
+            .collect();
+        let extras: Vec<&[u8]> = vec!["".as_bytes(); schema.num_fields()];
 
         let desc_schema = self.plan.schema();
 
@@ -73,6 +85,9 @@ impl Interpreter for DescribeTableInterpreter {
             Series::from_data(names),
             Series::from_data(types),
             Series::from_data(nulls),
+            Series::from_data(keys),
+            Series::from_data(default_exprs),
+            Series::from_data(extras),
         ]);
 
         Ok(Box::pin(DataBlockStream::create(desc_schema, None, vec![
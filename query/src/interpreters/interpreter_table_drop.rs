@@ -47,26 +47,29 @@ impl Interpreter for DropTableInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let db_name = self.plan.db.as_str();
-        let tbl_name = self.plan.table.as_str();
-        let tbl = self.ctx.get_table(db_name, tbl_name).await.ok();
-
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Database(db_name.into()),
-                UserPrivilegeType::Drop,
-            )
-            .await?;
+        for entity in &self.plan.entities {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(
+                    &GrantObject::Database(entity.db.clone()),
+                    UserPrivilegeType::Drop,
+                )
+                .await?;
+        }
 
+        // The tables are dropped in order, a failed one leaves the preceding ones dropped.
+        // With IF EXISTS, the missing tables are skipped by the meta service.
         let catalog = self.ctx.get_catalog();
-        catalog.drop_table(self.plan.clone().into()).await?;
+        for req in self.plan.drop_table_reqs() {
+            let tbl = self.ctx.get_table(&req.db, &req.table).await.ok();
+            catalog.drop_table(req).await?;
 
-        // `drop_table` throws several types of exceptions
-        // thus `optimize` operation is executed after it.
-        if let Some(tbl) = tbl {
-            let keep_last_snapshot = false;
-            tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            // `drop_table` throws several types of exceptions
+            // thus `optimize` operation is executed after it.
+            if let Some(tbl) = tbl {
+                let keep_last_snapshot = false;
+                tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
+            }
         }
 
         Ok(Box::pin(DataBlockStream::create(
@@ -12,20 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::ShowCreateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use sqlparser::keywords::ALL_KEYWORDS;
 
 use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::is_internal_opt_key;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -56,29 +61,8 @@ impl Interpreter for ShowCreateTableInterpreter {
             .await?;
 
         let name = table.name();
-        let engine = table.engine();
-        let schema = table.schema();
-
-        let mut table_info = format!("CREATE TABLE `{}` (\n", name);
-        for field in schema.fields().iter() {
-            let column = format!(
-                "  `{}` {},\n",
-                field.name(),
-                format_data_type_sql(field.data_type())
-            );
-            table_info.push_str(column.as_str());
-        }
-        let table_engine = format!(") ENGINE={}", engine);
-        table_info.push_str(table_engine.as_str());
-        table_info.push_str(
-            table
-                .options()
-                .iter()
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
-                .collect::<Vec<_>>()
-                .join("")
-                .as_str(),
-        );
+        let table_info =
+            Self::create_table_sql(name, table.engine(), &table.schema(), table.options())?;
 
         let show_fields = vec![
             DataField::new("Table", Vu8::to_data_type()),
@@ -97,3 +81,109 @@ impl Interpreter for ShowCreateTableInterpreter {
         ])))
     }
 }
+
+impl ShowCreateTableInterpreter {
+    // Renders the CREATE TABLE statement from the current schema and options rather than the
+    // original text, so that it keeps up with ALTER TABLE.
+    fn create_table_sql(
+        name: &str,
+        engine: &str,
+        schema: &DataSchemaRef,
+        options: &HashMap<String, String>,
+    ) -> Result<String> {
+        let mut sql = format!("CREATE TABLE {} (\n", quote_ident(name));
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields().iter() {
+            let mut column = format!(
+                "  {} {}",
+                quote_ident(field.name()),
+                format_data_type_sql(field.data_type())
+            );
+            if let Some(default_expr) = field.default_expr() {
+                let expr = serde_json::from_slice::<Expression>(default_expr)?;
+                column.push_str(&format!(" DEFAULT {}", format_expr_sql(&expr)?));
+            }
+            columns.push(column);
+        }
+        sql.push_str(&columns.join(",\n"));
+        sql.push_str(&format!("\n) ENGINE={}", engine));
+
+        // The options maintained by the table itself are not part of its definition.
+        let mut options = options
+            .iter()
+            .filter(|(k, _)| !is_internal_opt_key(k))
+            .collect::<Vec<_>>();
+        options.sort();
+        for (k, v) in options {
+            sql.push_str(&format!(
+                " {}='{}'",
+                k.to_uppercase(),
+                v.replace('\'', "''")
+            ));
+        }
+        Ok(sql)
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    let is_plain = ident
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_lowercase() || c == '_')
+        && ident
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !ALL_KEYWORDS.contains(&ident.to_uppercase().as_str());
+
+    match is_plain {
+        true => ident.to_string(),
+        false => format!("`{}`", ident),
+    }
+}
+
+fn format_expr_sql(expr: &Expression) -> Result<String> {
+    match expr {
+        Expression::Alias(_, expr) => format_expr_sql(expr),
+        Expression::Column(name) => Ok(quote_ident(name)),
+        Expression::Literal { value, .. } => Ok(match value {
+            DataValue::Null => "NULL".to_string(),
+            DataValue::String(v) => format!("'{}'", String::from_utf8_lossy(v).replace('\'', "''")),
+            other => format!("{}", other),
+        }),
+        Expression::UnaryExpression { op, expr } => match op.to_lowercase().as_str() {
+            "negate" => Ok(format!("(- {})", format_expr_sql(expr)?)),
+            _ => Ok(format!(
+                "({} {})",
+                op.to_uppercase(),
+                format_expr_sql(expr)?
+            )),
+        },
+        Expression::BinaryExpression { left, op, right } => Ok(format!(
+            "({} {} {})",
+            format_expr_sql(left)?,
+            op,
+            format_expr_sql(right)?
+        )),
+        Expression::ScalarFunction { op, args } => {
+            let args = args
+                .iter()
+                .map(format_expr_sql)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{}({})", op, args.join(", ")))
+        }
+        Expression::Cast {
+            expr,
+            data_type,
+            is_nullable,
+        } => Ok(format!(
+            "{}({} AS {:?})",
+            if *is_nullable { "TRY_CAST" } else { "CAST" },
+            format_expr_sql(expr)?,
+            remove_nullable(data_type)
+        )),
+        other => Err(ErrorCode::UnImplement(format!(
+            "Cannot format the default expression {:?} as SQL",
+            other
+        ))),
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
@@ -57,8 +58,11 @@ impl Interpreter for TruncateTableInterpreter {
             )
             .await?;
 
-        let tbl = self.ctx.get_table(db_name, tbl_name).await?;
-        tbl.truncate(self.ctx.clone(), self.plan.clone()).await?;
+        match self.ctx.get_table(db_name, tbl_name).await {
+            Ok(tbl) => tbl.truncate(self.ctx.clone(), self.plan.clone()).await?,
+            Err(e) if self.plan.if_exists && e.code() == ErrorCode::unknown_table_code() => {}
+            Err(e) => return Err(e),
+        }
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::FileFormatOptions;
+use common_meta_types::OnErrorMode;
+use common_meta_types::StageFileCompression;
+use common_meta_types::StageStorage;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use common_planners::DescribeUserStagePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+// The value shown instead of a credential.
+const REDACTED: &str = "********";
+
+#[derive(Debug)]
+pub struct DescribeUserStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescribeUserStagePlan,
+}
+
+// One row of the describe result.
+struct StageProperty {
+    parent: &'static str,
+    name: &'static str,
+    kind: &'static str,
+    value: String,
+    default: String,
+}
+
+impl StageProperty {
+    fn create(
+        parent: &'static str,
+        name: &'static str,
+        kind: &'static str,
+        value: String,
+        default: String,
+    ) -> StageProperty {
+        StageProperty {
+            parent,
+            name,
+            kind,
+            value,
+            default,
+        }
+    }
+
+    // A credential is never shown, only whether it is set.
+    fn credential(name: &'static str, value: &str) -> StageProperty {
+        let value = match value.is_empty() {
+            true => "".to_string(),
+            false => REDACTED.to_string(),
+        };
+        Self::create("STAGE_CREDENTIALS", name, "String", value, "".to_string())
+    }
+}
+
+impl DescribeUserStageInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: DescribeUserStagePlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DescribeUserStageInterpreter { ctx, plan }))
+    }
+
+    fn stage_properties(stage: &UserStageInfo) -> Vec<StageProperty> {
+        let mut properties = vec![];
+
+        let stage_type = match stage.stage_type {
+            StageType::Internal => "INTERNAL",
+            StageType::External => "EXTERNAL",
+        };
+        properties.push(StageProperty::create(
+            "STAGE_LOCATION",
+            "TYPE",
+            "String",
+            stage_type.to_string(),
+            "EXTERNAL".to_string(),
+        ));
+
+        let StageStorage::S3(s3) = &stage.stage_params.storage;
+        let url = match stage.stage_type {
+            StageType::Internal => "".to_string(),
+            StageType::External => format!("s3://{}{}", s3.bucket, s3.path),
+        };
+        properties.push(StageProperty::create(
+            "STAGE_LOCATION",
+            "URL",
+            "String",
+            url,
+            "".to_string(),
+        ));
+        properties.push(StageProperty::credential(
+            "AWS_KEY_ID",
+            &s3.credentials_aws_key_id,
+        ));
+        properties.push(StageProperty::credential(
+            "AWS_SECRET_KEY",
+            &s3.credentials_aws_secret_key,
+        ));
+        properties.push(StageProperty::credential(
+            "MASTER_KEY",
+            &s3.encryption_master_key,
+        ));
+
+        let format = &stage.file_format_options;
+        let default_format = FileFormatOptions::default();
+        let file_format = [
+            (
+                "TYPE",
+                "String",
+                format!("{:?}", format.format).to_uppercase(),
+                format!("{:?}", default_format.format).to_uppercase(),
+            ),
+            (
+                "FIELD_DELIMITER",
+                "String",
+                format.field_delimiter.clone(),
+                default_format.field_delimiter.clone(),
+            ),
+            (
+                "RECORD_DELIMITER",
+                "String",
+                format.record_delimiter.clone(),
+                default_format.record_delimiter.clone(),
+            ),
+            (
+                "SKIP_HEADER",
+                "Integer",
+                format.skip_header.to_string(),
+                default_format.skip_header.to_string(),
+            ),
+            (
+                "QUOTE",
+                "String",
+                format.quote.clone(),
+                default_format.quote.clone(),
+            ),
+            (
+                "ESCAPE",
+                "String",
+                format.escape.clone(),
+                default_format.escape.clone(),
+            ),
+            (
+                "COMPRESSION",
+                "String",
+                compression_name(&format.compression).to_string(),
+                compression_name(&default_format.compression).to_string(),
+            ),
+            (
+                "NULL_IF",
+                "List",
+                format!("{:?}", format.null_if),
+                format!("{:?}", default_format.null_if),
+            ),
+            (
+                "EMPTY_FIELD_AS_NULL",
+                "Boolean",
+                format.empty_field_as_null.to_string(),
+                default_format.empty_field_as_null.to_string(),
+            ),
+        ];
+        for (name, kind, value, default) in file_format {
+            properties.push(StageProperty::create(
+                "STAGE_FILE_FORMAT",
+                name,
+                kind,
+                value,
+                default,
+            ));
+        }
+
+        let copy_options = &stage.copy_options;
+        properties.push(StageProperty::create(
+            "STAGE_COPY_OPTIONS",
+            "ON_ERROR",
+            "String",
+            on_error_name(&copy_options.on_error),
+            on_error_name(&OnErrorMode::default()),
+        ));
+        properties.push(StageProperty::create(
+            "STAGE_COPY_OPTIONS",
+            "SIZE_LIMIT",
+            "Integer",
+            copy_options.size_limit.to_string(),
+            "0".to_string(),
+        ));
+
+        properties
+    }
+}
+
+fn compression_name(compression: &StageFileCompression) -> &'static str {
+    match compression {
+        StageFileCompression::Auto => "AUTO",
+        StageFileCompression::Gzip => "GZIP",
+        StageFileCompression::Bz2 => "BZ2",
+        StageFileCompression::Brotli => "BROTLI",
+        StageFileCompression::Zstd => "ZSTD",
+        StageFileCompression::Deflate => "DEFLATE",
+        StageFileCompression::RawDeflate => "RAW_DEFLATE",
+        StageFileCompression::Lzo => "LZO",
+        StageFileCompression::Snappy => "SNAPPY",
+        StageFileCompression::None => "NONE",
+    }
+}
+
+fn on_error_name(on_error: &OnErrorMode) -> String {
+    match on_error {
+        OnErrorMode::None | OnErrorMode::AbortStatement => "ABORT_STATEMENT".to_string(),
+        OnErrorMode::Continue => "CONTINUE".to_string(),
+        OnErrorMode::SkipFile => "SKIP_FILE".to_string(),
+        OnErrorMode::SkipFileNum(num) => format!("SKIP_FILE_{}", num),
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeUserStageInterpreter {
+    fn name(&self) -> &str {
+        "DescribeUserStageInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = self.ctx.get_user_manager();
+        let stage = user_mgr.get_stage(&tenant, &self.plan.name).await?;
+        let properties = Self::stage_properties(&stage);
+
+        let mut parents = Vec::with_capacity(properties.len());
+        let mut names = Vec::with_capacity(properties.len());
+        let mut kinds = Vec::with_capacity(properties.len());
+        let mut values = Vec::with_capacity(properties.len());
+        let mut defaults = Vec::with_capacity(properties.len());
+        let mut changed = Vec::with_capacity(properties.len());
+        for property in properties {
+            parents.push(property.parent);
+            names.push(property.name);
+            kinds.push(property.kind);
+            changed.push(property.value != property.default);
+            values.push(property.value);
+            defaults.push(property.default);
+        }
+
+        let schema = self.plan.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(parents),
+            Series::from_data(names),
+            Series::from_data(kinds),
+            Series::from_data(values),
+            Series::from_data(defaults),
+            Series::from_data(changed),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
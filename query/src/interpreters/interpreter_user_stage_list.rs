@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::ListUserStagePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::transforms::DataAccessor;
+use crate::pipelines::transforms::StageFile;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct ListUserStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ListUserStagePlan,
+}
+
+impl ListUserStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ListUserStagePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ListUserStageInterpreter { ctx, plan }))
+    }
+
+    // The files matching the pattern, the same files as the copy with the pattern picks up.
+    async fn list_files(&self) -> Result<Vec<StageFile>> {
+        let stage_info = &self.plan.stage_info;
+        let mut files = DataAccessor::list_stage_files(&self.ctx, stage_info).await?;
+        match self.plan.pattern.is_empty() {
+            true => {
+                files.sort_by(|a, b| a.path.cmp(&b.path));
+                Ok(files)
+            }
+            false => DataAccessor::match_stage_files(files, &self.plan.pattern),
+        }
+    }
+
+    fn to_block(&self, files: &[StageFile]) -> DataBlock {
+        let names = files.iter().map(|f| f.path.as_str()).collect::<Vec<_>>();
+        let sizes = files.iter().map(|f| f.size).collect::<Vec<_>>();
+        let md5s = files.iter().map(|f| f.md5.as_deref()).collect::<Vec<_>>();
+        let last_modifieds = files
+            .iter()
+            .map(|f| {
+                f.last_modified
+                    .map(|v| v.format("%Y-%m-%d %H:%M:%S%.3f %z").to_string())
+            })
+            .collect::<Vec<_>>();
+
+        DataBlock::create(self.plan.schema(), vec![
+            Series::from_data(names),
+            Series::from_data(sizes),
+            Series::from_data(md5s),
+            Series::from_data(last_modifieds),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ListUserStageInterpreter {
+    fn name(&self) -> &str {
+        "ListUserStageInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let files = self.list_files().await?;
+        tracing::info!(
+            "List stage {:?} pattern {:?} files: {}",
+            self.plan.stage_info.stage_name,
+            self.plan.pattern,
+            files.len()
+        );
+
+        // Split the files into blocks, a large stage may have lots of files.
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let blocks = files
+            .chunks(max_block_size.max(1))
+            .map(|chunk| self.to_block(chunk))
+            .collect::<Vec<_>>();
+
+        let schema = self.plan.schema();
+        Ok(Box::pin(DataBlockStream::create(schema, None, blocks)))
+    }
+}
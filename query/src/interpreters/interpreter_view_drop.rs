@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::DropTableReq;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::DropViewPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::view::VIEW_ENGINE;
+
+pub struct DropViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropViewPlan,
+}
+
+impl DropViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropViewPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DropViewInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropViewInterpreter {
+    fn name(&self) -> &str {
+        "DropViewInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Database(self.plan.db.clone()),
+                UserPrivilegeType::Drop,
+            )
+            .await?;
+
+        let tenant = self.plan.tenant.clone();
+        let db = self.plan.db.clone();
+        let viewname = self.plan.viewname.clone();
+        let catalog = self.ctx.get_catalog();
+
+        if let Ok(table) = catalog.get_table(&tenant, &db, &viewname).await {
+            if table.engine() != VIEW_ENGINE {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "'{}.{}' is not a view, please use DROP TABLE",
+                    db, viewname
+                )));
+            }
+        }
+
+        catalog
+            .drop_table(DropTableReq {
+                if_exists: self.plan.if_exists,
+                tenant,
+                db,
+                table: viewname,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -17,9 +17,11 @@ mod interpreter;
 mod interpreter_admin_use_tenant;
 mod interpreter_common;
 mod interpreter_copy;
+mod interpreter_copy_into_stage;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_show_create;
+mod interpreter_delete;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_factory_interceptor;
@@ -40,30 +42,41 @@ mod interpreter_show_processlist;
 mod interpreter_show_settings;
 mod interpreter_show_tables;
 mod interpreter_show_users;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_optimize;
+mod interpreter_table_rename;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
 mod interpreter_user_drop;
 mod interpreter_user_privilege_grant;
 mod interpreter_user_privilege_revoke;
+mod interpreter_user_stage_create;
+mod interpreter_user_stage_describe;
+mod interpreter_user_stage_drop;
+mod interpreter_user_stage_list;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
+mod interpreter_view_create;
+mod interpreter_view_drop;
 mod plan_schedulers;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_admin_use_tenant::UseTenantInterpreter;
 pub use interpreter_copy::CopyInterpreter;
+pub use interpreter_copy_into_stage::CopyIntoStageInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_factory_interceptor::InterceptorInterpreter;
@@ -84,19 +97,28 @@ pub use interpreter_show_processlist::ShowProcessListInterpreter;
 pub use interpreter_show_settings::ShowSettingsInterpreter;
 pub use interpreter_show_tables::ShowTablesInterpreter;
 pub use interpreter_show_users::ShowUsersInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
+pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
 pub use interpreter_user_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_user_privilege_revoke::RevokePrivilegeInterpreter;
+pub use interpreter_user_stage_create::CreateUserStageInterpreter;
+pub use interpreter_user_stage_describe::DescribeUserStageInterpreter;
+pub use interpreter_user_stage_drop::DropUserStageInterpreter;
+pub use interpreter_user_stage_list::ListUserStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFInterpreter;
 pub use interpreter_user_udf_create::CreateUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
+pub use interpreter_view_create::CreateViewInterpreter;
+pub use interpreter_view_drop::DropViewInterpreter;
 pub use plan_schedulers::PlanScheduler;
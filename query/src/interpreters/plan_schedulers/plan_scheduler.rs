@@ -37,11 +37,14 @@ use common_planners::ProjectionPlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::RemotePlan;
 use common_planners::SelectPlan;
+use common_planners::SemiJoinPlan;
 use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnnestPlan;
+use common_planners::WindowPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -299,6 +302,9 @@ impl PlanScheduler {
             PlanNode::Empty(plan) => self.visit_empty(plan, tasks),
             PlanNode::Projection(plan) => self.visit_projection(plan, tasks),
             PlanNode::Filter(plan) => self.visit_filter(plan, tasks),
+            PlanNode::Unnest(plan) => self.visit_unnest(plan, tasks),
+            PlanNode::Window(plan) => self.visit_window(plan, tasks),
+            PlanNode::SemiJoin(plan) => self.visit_semi_join(plan, tasks),
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
@@ -672,6 +678,35 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_unnest(&mut self, plan: &UnnestPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_unnest(plan),
+            RunningMode::Standalone => self.visit_local_unnest(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_unnest(&mut self, plan: &UnnestPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::Unnest(UnnestPlan {
+            array_column: plan.array_column.clone(),
+            element_column: plan.element_column.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_unnest(&mut self, plan: &UnnestPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Unnest(UnnestPlan {
+                array_column: plan.array_column.clone(),
+                element_column: plan.element_column.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
     fn visit_having(&mut self, plan: &HavingPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -739,6 +774,7 @@ impl PlanScheduler {
         self.nodes_plan[self.local_pos] = PlanNode::Limit(LimitPlan {
             n: plan.n,
             offset: plan.offset,
+            with_ties: plan.with_ties,
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
         });
     }
@@ -748,6 +784,7 @@ impl PlanScheduler {
             self.nodes_plan[index] = PlanNode::Limit(LimitPlan {
                 n: plan.n,
                 offset: plan.offset,
+                with_ties: plan.with_ties,
                 input: Arc::new(self.nodes_plan[index].clone()),
             });
         }
@@ -780,6 +817,64 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_window(&mut self, plan: &WindowPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_window(plan),
+            RunningMode::Standalone => self.visit_local_window(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_window(&mut self, plan: &WindowPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::Window(WindowPlan {
+            window_func: plan.window_func.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_window(&mut self, plan: &WindowPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::Window(WindowPlan {
+                window_func: plan.window_func.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
+    fn visit_semi_join(&mut self, plan: &SemiJoinPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_semi_join(plan),
+            RunningMode::Standalone => self.visit_local_semi_join(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_semi_join(&mut self, plan: &SemiJoinPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::SemiJoin(SemiJoinPlan {
+            anti: plan.anti,
+            keys: plan.keys.clone(),
+            subquery: plan.subquery.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+            schema: plan.schema.clone(),
+        });
+    }
+
+    fn visit_cluster_semi_join(&mut self, plan: &SemiJoinPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::SemiJoin(SemiJoinPlan {
+                anti: plan.anti,
+                keys: plan.keys.clone(),
+                subquery: plan.subquery.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+                schema: plan.schema.clone(),
+            });
+        }
+    }
+
     fn visit_data_source(&mut self, plan: &ReadDataSourcePlan, _: &mut Tasks) -> Result<()> {
         let table = self.query_context.build_table_from_source_plan(plan)?;
 
@@ -52,9 +52,20 @@ impl<'a> InsertWithPlan<'a> {
         &self,
         table: &dyn Table,
     ) -> common_exception::Result<SendableDataBlockStream> {
+        let optimizer = Optimizers::create(self.ctx.clone());
+        let optimized_plan = self.build_plan(optimizer, table.get_table_info())?;
+        plan_schedulers::schedule_query(self.ctx, &optimized_plan).await
+    }
+
+    /// Optimize the select plan and sink its output into the table, the output of the plan built
+    /// is the append operation logs, which are committed by the caller.
+    pub fn build_plan(
+        &self,
+        optimizer: Optimizers,
+        table_info: &TableInfo,
+    ) -> common_exception::Result<PlanNode> {
         if let PlanNode::Select(sel) = self.plan_node {
-            let optimized_plan = self.rewrite_plan(sel, table.get_table_info())?;
-            plan_schedulers::schedule_query(self.ctx, &optimized_plan).await
+            self.rewrite_plan(optimizer, sel, table_info)
         } else {
             Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Unsupported select query plan for insert_into interpreter, {}",
@@ -65,6 +76,7 @@ impl<'a> InsertWithPlan<'a> {
 
     fn rewrite_plan(
         &self,
+        optimizer: Optimizers,
         select_plan: &SelectPlan,
         table_info: &TableInfo,
     ) -> common_exception::Result<PlanNode> {
@@ -75,10 +87,7 @@ impl<'a> InsertWithPlan<'a> {
         };
 
         // optimize and rewrite the SelectPlan.input
-        let optimized_plan = plan_schedulers::apply_plan_rewrite(
-            Optimizers::create(self.ctx.clone()),
-            &select_plan.input,
-        )?;
+        let optimized_plan = plan_schedulers::apply_plan_rewrite(optimizer, &select_plan.input)?;
 
         // rewrite the optimized the plan
         let rewritten_plan = match optimized_plan {
@@ -338,7 +338,7 @@ impl PlanRewriter for ExprTransformImpl {
         };
 
         PlanBuilder::from(&new_input)
-            .limit_offset(plan.n, plan.offset)?
+            .limit_offset_with_ties(plan.n, plan.offset, plan.with_ties)?
             .build()
     }
 
@@ -31,6 +31,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::WindowPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::QueryContext;
@@ -137,7 +138,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Cluster limit input is None")),
             Some(input) => Self::convergent_shuffle_stage_builder(input)
-                .limit_offset(plan.n, plan.offset)?
+                .limit_offset_with_ties(plan.n, plan.offset, plan.with_ties)?
                 .build(),
         }
     }
@@ -146,7 +147,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Standalone limit input is None")),
             Some(input) => PlanBuilder::from(input.as_ref())
-                .limit_offset(plan.n, plan.offset)?
+                .limit_offset_with_ties(plan.n, plan.offset, plan.with_ties)?
                 .build(),
         }
     }
@@ -174,6 +175,27 @@ impl ScattersOptimizerImpl {
         }
     }
 
+    fn cluster_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        // The partitions of the window need all the rows, we convergent them in local node
+        self.running_mode = RunningMode::Standalone;
+
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Cluster window input is None.")),
+            Some(input) => Self::convergent_shuffle_stage_builder(input)
+                .window(&plan.window_func)?
+                .build(),
+        }
+    }
+
+    fn standalone_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        match self.input.take() {
+            None => Err(ErrorCode::LogicalError("Standalone window input is None.")),
+            Some(input) => PlanBuilder::from(input.as_ref())
+                .window(&plan.window_func)?
+                .build(),
+        }
+    }
+
     fn convergent_shuffle_stage_builder(input: Arc<PlanNode>) -> PlanBuilder {
         PlanBuilder::from(&PlanNode::Stage(StagePlan {
             kind: StageKind::Convergent,
@@ -278,6 +300,15 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        self.input = Some(Arc::new(self.rewrite_plan_node(plan.input.as_ref())?));
+
+        match self.running_mode {
+            RunningMode::Cluster => self.cluster_window(plan),
+            RunningMode::Standalone => self.standalone_window(plan),
+        }
+    }
+
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
 
@@ -75,18 +75,41 @@ impl PlanRewriter for TopNPushDownImpl {
         }
     }
 
+    fn rewrite_window(&mut self, plan: &WindowPlan) -> Result<PlanNode> {
+        // The window function needs all the rows of its partition, we clear the top n option.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .window(&plan.window_func)?
+            .build()
+    }
+
+    fn rewrite_semi_join(&mut self, plan: &SemiJoinPlan) -> Result<PlanNode> {
+        // The rows without a match are unknown to the source, we clear the top n option.
+        self.limit = None;
+
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_keys = self.rewrite_exprs(&new_input.schema(), &plan.keys)?;
+        let new_subquery = self.rewrite_expr(&new_input.schema(), &plan.subquery)?;
+        PlanBuilder::from(&new_input)
+            .semi_join(plan.anti, &new_keys, new_subquery)?
+            .build()
+    }
+
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
         let current_limit = self.limit;
         let current_order_by = self.order_by.clone();
 
+        // The ties beyond the limit are unknown until the sort, the limit can't be pushed down.
         match plan.n {
-            Some(limit) if limit > 0 => self.limit = Some(limit + plan.offset),
+            Some(limit) if limit > 0 && !plan.with_ties => self.limit = Some(limit + plan.offset),
             _ => {}
         }
 
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let plan_node = PlanBuilder::from(&new_input)
-            .limit_offset(plan.n, plan.offset)?
+            .limit_offset_with_ties(plan.n, plan.offset, plan.with_ties)?
             .build();
 
         self.limit = current_limit; // recover back to previous state
@@ -51,6 +51,7 @@ pub struct QueryPipelineBuilder {
     pipeline: NewPipeline,
     limit: Option<usize>,
     offset: usize,
+    limit_with_ties: bool,
 }
 
 impl QueryPipelineBuilder {
@@ -60,6 +61,7 @@ impl QueryPipelineBuilder {
             pipeline: NewPipeline::create(),
             limit: None,
             offset: 0,
+            limit_with_ties: false,
         }
     }
 
@@ -185,13 +187,20 @@ impl PlanVisitor for QueryPipelineBuilder {
     fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
         self.limit = plan.n;
         self.offset = plan.offset;
+        self.limit_with_ties = plan.with_ties;
         self.visit_plan_node(&plan.input)?;
 
+        // With ties, the sort has already cut the rows after the ties, only skip the offset.
+        let n = match plan.with_ties {
+            true => None,
+            false => plan.n,
+        };
+
         self.pipeline.resize(1)?;
         self.pipeline
             .add_transform(|transform_input_port, transform_output_port| {
                 TransformLimit::try_create(
-                    plan.n,
+                    n,
                     plan.offset,
                     transform_input_port,
                     transform_output_port,
@@ -212,6 +221,13 @@ impl PlanVisitor for QueryPipelineBuilder {
         // 'select * from numbers(100) order by number desc limit 10 offset 5', the
         // sort pipeline should return at least 15 rows.
         let rows_limit = self.limit.map(|limit| limit + self.offset);
+        // With ties, the partial sort can't cut the rows which tie with the last one,
+        // the merge keeps the first rows_limit rows and the ties.
+        let partial_rows_limit = match self.limit_with_ties {
+            true => None,
+            false => rows_limit,
+        };
+        let with_ties = self.limit_with_ties;
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
@@ -221,7 +237,7 @@ impl PlanVisitor for QueryPipelineBuilder {
                 TransformSortPartial::try_create(
                     transform_input_port,
                     transform_output_port,
-                    rows_limit,
+                    partial_rows_limit,
                     get_sort_descriptions(&plan.schema, &plan.order_by)?,
                 )
             })?;
@@ -235,6 +251,7 @@ impl PlanVisitor for QueryPipelineBuilder {
                     transform_input_port,
                     transform_output_port,
                     rows_limit,
+                    with_ties,
                     get_sort_descriptions(&plan.schema, &plan.order_by)?,
                 )
             })?;
@@ -251,6 +268,7 @@ impl PlanVisitor for QueryPipelineBuilder {
                     transform_input_port,
                     transform_output_port,
                     rows_limit,
+                    with_ties,
                     get_sort_descriptions(&plan.schema, &plan.order_by)?,
                 )
             })
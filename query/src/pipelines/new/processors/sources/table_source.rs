@@ -76,6 +76,9 @@ impl AsyncSource for TableSource {
                 self.initialize().await?;
             }
 
+            // Stop reading the table once the query is killed.
+            self.ctx.check_aborting()?;
+
             match &mut self.wrap_stream {
                 None => Err(ErrorCode::LogicalError("")),
                 Some(stream) => match stream.next().await {
@@ -37,11 +37,13 @@ impl TransformSortMerge {
         input_port: Arc<InputPort>,
         output_port: Arc<OutputPort>,
         limit: Option<usize>,
+        with_ties: bool,
         sort_columns_descriptions: Vec<SortColumnDescription>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(TransformSortMerge::Consume(
             ConsumeState {
                 limit,
+                with_ties,
                 input_port,
                 output_port,
                 sort_columns_descriptions,
@@ -59,6 +61,7 @@ impl TransformSortMerge {
                     output_port: state.output_port,
                     blocks: state.input_data_blocks,
                     limit: state.limit,
+                    with_ties: state.with_ties,
                     sort_columns_descriptions: state.sort_columns_descriptions,
                 }))
             }
@@ -149,11 +152,19 @@ impl Processor for TransformSortMerge {
                 true => None,
                 false => {
                     let desc = &state.sort_columns_descriptions;
-                    Some(DataBlock::merge_sort_blocks(
-                        &state.blocks,
-                        desc,
-                        state.limit,
-                    )?)
+                    match state.limit {
+                        Some(limit) if state.with_ties => {
+                            let block = DataBlock::merge_sort_blocks(&state.blocks, desc, None)?;
+                            Some(DataBlock::limit_sorted_block_with_ties(
+                                &block, desc, limit,
+                            )?)
+                        }
+                        _ => Some(DataBlock::merge_sort_blocks(
+                            &state.blocks,
+                            desc,
+                            state.limit,
+                        )?),
+                    }
                 }
             };
 
@@ -180,6 +191,7 @@ pub struct ConsumeState {
     output_port: Arc<OutputPort>,
     input_data_blocks: Vec<DataBlock>,
     limit: Option<usize>,
+    with_ties: bool,
     sort_columns_descriptions: Vec<SortColumnDescription>,
 }
 
@@ -189,5 +201,6 @@ pub struct SortingBlockState {
     blocks: Vec<DataBlock>,
 
     limit: Option<usize>,
+    with_ties: bool,
     sort_columns_descriptions: Vec<SortColumnDescription>,
 }
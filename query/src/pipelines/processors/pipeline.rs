@@ -23,6 +23,8 @@ use super::MixedProcessor;
 use crate::pipelines::processors::MergeProcessor;
 use crate::pipelines::processors::Pipe;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProcessorProfile;
+use crate::pipelines::processors::ProfilingProcessor;
 use crate::sessions::QueryContext;
 
 pub struct Pipeline {
@@ -151,6 +153,27 @@ impl Pipeline {
         Ok(())
     }
 
+    /// Whether the processors of the last pipe are wrapped to collect their statistics.
+    pub fn is_last_pipe_profiled(&self) -> Result<bool> {
+        let last_pipe = self.last_pipe()?;
+        Ok(last_pipe
+            .processors()
+            .iter()
+            .any(|x| x.as_any().is::<ProfilingProcessor>()))
+    }
+
+    /// Wrap the processors of the last pipe to collect their statistics into the profile.
+    pub fn profile_last_pipe(&mut self, profile: Arc<ProcessorProfile>) -> Result<()> {
+        let mut new_pipe = Pipe::create();
+        for x in self.last_pipe()?.processors() {
+            new_pipe.add(Arc::new(ProfilingProcessor::create(x, profile.clone())));
+        }
+
+        let last = self.pipes.len() - 1;
+        self.pipes[last] = new_pipe;
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", name="pipeline_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub async fn execute(&mut self) -> Result<SendableDataBlockStream> {
         if self.last_pipe()?.nums() > 1 {
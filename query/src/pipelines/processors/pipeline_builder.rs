@@ -28,14 +28,18 @@ use common_planners::ProjectionPlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::RemotePlan;
 use common_planners::SelectPlan;
+use common_planners::SemiJoinPlan;
 use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnnestPlan;
+use common_planners::WindowPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
 use crate::pipelines::processors::Pipeline;
+use crate::pipelines::processors::PlanNodeProfiles;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
@@ -47,12 +51,15 @@ use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
 use crate::pipelines::transforms::ProjectionTransform;
 use crate::pipelines::transforms::RemoteTransform;
+use crate::pipelines::transforms::SemiJoinTransform;
 use crate::pipelines::transforms::SinkTransform;
 use crate::pipelines::transforms::SortMergeTransform;
 use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
+use crate::pipelines::transforms::UnnestTransform;
 use crate::pipelines::transforms::WhereTransform;
+use crate::pipelines::transforms::WindowTransform;
 use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
@@ -60,6 +67,9 @@ pub struct PipelineBuilder {
 
     limit: Option<usize>,
     offset: usize,
+    limit_with_ties: bool,
+    // Only set by EXPLAIN ANALYZE.
+    profiles: Option<PlanNodeProfiles>,
 }
 
 impl PipelineBuilder {
@@ -68,6 +78,8 @@ impl PipelineBuilder {
             ctx,
             limit: None,
             offset: 0,
+            limit_with_ties: false,
+            profiles: None,
         }
     }
 
@@ -79,7 +91,27 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    /// Build the pipeline, the processors of each plan node collect their actual statistics.
+    pub fn build_with_profiles(mut self, node: &PlanNode) -> Result<(Pipeline, PlanNodeProfiles)> {
+        self.profiles = Some(PlanNodeProfiles::default());
+        let pipeline = self.visit(node)?;
+        let profiles = self.profiles.take().unwrap_or_default();
+        Ok((pipeline, profiles))
+    }
+
     fn visit(&mut self, node: &PlanNode) -> Result<Pipeline> {
+        let mut pipeline = self.visit_plan(node)?;
+        if let Some(profiles) = &mut self.profiles {
+            // The select node, and the node merged into its input, e.g. the limit of the sort,
+            // add no processors, their statistics are the ones of the input.
+            if !matches!(node, PlanNode::Select(_)) && !pipeline.is_last_pipe_profiled()? {
+                pipeline.profile_last_pipe(profiles.get_or_create(node))?;
+            }
+        }
+        Ok(pipeline)
+    }
+
+    fn visit_plan(&mut self, node: &PlanNode) -> Result<Pipeline> {
         match node {
             PlanNode::Select(node) => self.visit_select(node),
             PlanNode::Stage(node) => self.visit_stage(node),
@@ -94,6 +126,9 @@ impl PipelineBuilder {
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
+            PlanNode::Unnest(node) => self.visit_unnest(node),
+            PlanNode::Window(node) => self.visit_window(node),
+            PlanNode::SemiJoin(node) => self.visit_semi_join(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             PlanNode::Sink(node) => self.visit_sink(node),
@@ -176,6 +211,7 @@ impl PipelineBuilder {
         } else {
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(GroupByPartialTransform::create(
+                    self.ctx.clone(),
                     node.schema(),
                     node.input.schema(),
                     node.aggr_expr.clone(),
@@ -202,6 +238,7 @@ impl PipelineBuilder {
             let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
             pipeline.add_simple_transform(|| {
                 Ok(Box::new(GroupByFinalTransform::create(
+                    self.ctx.clone(),
                     node.schema(),
                     max_block_size,
                     node.schema_before_group_by.clone(),
@@ -225,6 +262,17 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_unnest(&mut self, node: &UnnestPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(UnnestTransform::create(
+                node.schema(),
+                node.array_column.clone(),
+            )))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_having(&mut self, node: &HavingPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.add_simple_transform(|| {
@@ -243,6 +291,13 @@ impl PipelineBuilder {
         // 'select * from numbers(100) order by number desc limit 10 offset 5', the
         // sort pipeline should return at least 15 rows.
         let rows_limit = self.limit.map(|limit| limit + self.offset);
+        // With ties, the partial sort can't cut the rows which tie with the last one,
+        // the merge keeps the first rows_limit rows and the ties.
+        let partial_rows_limit = match self.limit_with_ties {
+            true => None,
+            false => rows_limit,
+        };
+        let with_ties = self.limit_with_ties;
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
@@ -251,7 +306,7 @@ impl PipelineBuilder {
             Ok(Box::new(SortPartialTransform::try_create(
                 plan.schema(),
                 plan.order_by.clone(),
-                rows_limit,
+                partial_rows_limit,
             )?))
         })?;
 
@@ -263,6 +318,7 @@ impl PipelineBuilder {
                 plan.schema(),
                 plan.order_by.clone(),
                 rows_limit,
+                with_ties,
             )?))
         })?;
 
@@ -278,6 +334,7 @@ impl PipelineBuilder {
                     plan.schema(),
                     plan.order_by.clone(),
                     rows_limit,
+                    with_ties,
                 )?))
             })?;
         }
@@ -287,12 +344,18 @@ impl PipelineBuilder {
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
         self.limit = node.n;
         self.offset = node.offset;
+        self.limit_with_ties = node.with_ties;
 
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.merge_processor()?;
-        pipeline.add_simple_transform(|| {
-            Ok(Box::new(LimitTransform::try_create(node.n, node.offset)?))
-        })?;
+
+        // With ties, the sort has already cut the rows after the ties, only skip the offset.
+        let n = match node.with_ties {
+            true => None,
+            false => node.n,
+        };
+        pipeline
+            .add_simple_transform(|| Ok(Box::new(LimitTransform::try_create(n, node.offset)?)))?;
         Ok(pipeline)
     }
 
@@ -308,6 +371,31 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_window(&mut self, node: &WindowPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(WindowTransform::create(
+                node.schema(),
+                node.window_func.clone(),
+            )))
+        })?;
+        Ok(pipeline)
+    }
+
+    fn visit_semi_join(&mut self, node: &SemiJoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(SemiJoinTransform::try_create(
+                node.schema(),
+                node.anti,
+                node.keys.clone(),
+                node.subquery.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<Pipeline> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
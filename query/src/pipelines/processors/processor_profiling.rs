@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::pipelines::processors::Processor;
+
+/// The actual statistics of the processors built from one plan node, shown by EXPLAIN ANALYZE.
+///
+/// The time is inclusive, it contains the time spent by the inputs to produce the blocks,
+/// and is summed over the parallel processors.
+#[derive(Default)]
+pub struct ProcessorProfile {
+    rows: AtomicUsize,
+    blocks: AtomicUsize,
+    bytes: AtomicUsize,
+    elapsed_nanos: AtomicU64,
+}
+
+impl ProcessorProfile {
+    pub fn rows(&self) -> usize {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    pub fn blocks(&self) -> usize {
+        self.blocks.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+
+    fn add_elapsed(&self, elapsed: Duration) {
+        self.elapsed_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn add_block(&self, block: &DataBlock) {
+        self.rows.fetch_add(block.num_rows(), Ordering::Relaxed);
+        self.blocks.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(block.memory_size(), Ordering::Relaxed);
+    }
+}
+
+/// The profiles of the plan nodes, keyed by the address of the node in the plan built.
+#[derive(Default)]
+pub struct PlanNodeProfiles {
+    profiles: HashMap<usize, Arc<ProcessorProfile>>,
+}
+
+impl PlanNodeProfiles {
+    pub fn get(&self, node: &PlanNode) -> Option<Arc<ProcessorProfile>> {
+        self.profiles.get(&Self::key(node)).cloned()
+    }
+
+    pub fn get_or_create(&mut self, node: &PlanNode) -> Arc<ProcessorProfile> {
+        self.profiles.entry(Self::key(node)).or_default().clone()
+    }
+
+    fn key(node: &PlanNode) -> usize {
+        node as *const PlanNode as usize
+    }
+}
+
+/// Wrap a connected processor, collect the statistics of its output stream.
+///
+/// The processors are wrapped only by EXPLAIN ANALYZE, the other queries don't pay for it.
+pub struct ProfilingProcessor {
+    inner: Arc<dyn Processor>,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl ProfilingProcessor {
+    pub fn create(inner: Arc<dyn Processor>, profile: Arc<ProcessorProfile>) -> Self {
+        ProfilingProcessor { inner, profile }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProfilingProcessor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::IllegalTransformConnectionState(
+            "Cannot call ProfilingProcessor connect_to, the wrapped processor is connected",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        self.inner.inputs()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let start = Instant::now();
+        let input = self.inner.execute().await?;
+        self.profile.add_elapsed(start.elapsed());
+
+        Ok(Box::pin(ProfilingStream {
+            input,
+            profile: self.profile.clone(),
+        }))
+    }
+}
+
+struct ProfilingStream {
+    input: SendableDataBlockStream,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl Stream for ProfilingStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let start = Instant::now();
+        let poll = self.input.poll_next_unpin(ctx);
+        self.profile.add_elapsed(start.elapsed());
+
+        if let Poll::Ready(Some(Ok(block))) = &poll {
+            self.profile.add_block(block);
+        }
+        poll
+    }
+}
@@ -26,6 +26,8 @@ use futures::StreamExt;
 use crate::pipelines::transforms::group_by::aggregator_keys_builder::KeysColumnBuilder;
 use crate::pipelines::transforms::group_by::aggregator_params::AggregatorParams;
 use crate::pipelines::transforms::group_by::aggregator_params::AggregatorParamsRef;
+use crate::pipelines::transforms::group_by::aggregator_spill::AggregatorSpiller;
+use crate::pipelines::transforms::group_by::aggregator_spill::SPILL_PARTITIONS;
 use crate::pipelines::transforms::group_by::aggregator_state::AggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state_entity::StateEntity;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
@@ -83,6 +85,105 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         Ok(state)
     }
 
+    /// Aggregate like [Aggregator::aggregate], but the keys are split into partitions,
+    /// once the states take more than `spill_threshold` bytes, the least recently used
+    /// partitions are spilled to disk by the spiller, and merged back on finalization.
+    #[inline(never)]
+    pub async fn aggregate_spillable(
+        &self,
+        group_cols: Vec<String>,
+        mut stream: SendableDataBlockStream,
+        spill_threshold: usize,
+        mut spiller: AggregatorSpiller,
+        schema: DataSchemaRef,
+        max_block_size: usize,
+    ) -> Result<SendableDataBlockStream> {
+        let hash_method = &self.method;
+        let aggregator_params = self.params.as_ref();
+
+        let mut partitions: Vec<Method::State> = (0..SPILL_PARTITIONS)
+            .map(|_| hash_method.aggregate_state())
+            .collect();
+        // The sequence of the last block which touched the partition.
+        let mut partitions_last_used = vec![0usize; SPILL_PARTITIONS];
+        let mut block_seq = 0;
+
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            block_seq += 1;
+
+            let group_columns = Self::group_columns(&group_cols, &block)?;
+            let group_keys = hash_method.build_keys(&group_columns, block.num_rows())?;
+
+            let mut places = Vec::with_capacity(group_keys.len());
+            for key in group_keys.iter() {
+                let partition = hash_method.spill_partition(key, SPILL_PARTITIONS);
+                partitions_last_used[partition] = block_seq;
+                places.push(self.lookup_partition_state(key, &mut partitions[partition]));
+            }
+
+            if !aggregator_params.aggregate_functions.is_empty() {
+                Self::execute(aggregator_params, &block, &places)?;
+            }
+
+            let mut allocated_bytes: usize = partitions.iter().map(|p| p.allocated_bytes()).sum();
+            while allocated_bytes > spill_threshold {
+                let lru_partition = (0..SPILL_PARTITIONS)
+                    .filter(|partition| partitions[*partition].len() > 0)
+                    .min_by_key(|partition| partitions_last_used[*partition]);
+
+                let partition = match lru_partition {
+                    None => break,
+                    Some(partition) => partition,
+                };
+
+                let state =
+                    std::mem::replace(&mut partitions[partition], hash_method.aggregate_state());
+                allocated_bytes -= state.allocated_bytes();
+                allocated_bytes += partitions[partition].allocated_bytes();
+
+                if let Some(block) = self.finalized_block(&state, schema.clone())? {
+                    spiller.spill(&block)?;
+                }
+            }
+        }
+
+        let mut blocks = Vec::with_capacity(SPILL_PARTITIONS);
+        for partition in &partitions {
+            if let Some(block) = self.finalized_block(partition, schema.clone())? {
+                blocks.push(block);
+            }
+        }
+
+        match spiller.is_empty() {
+            true => Ok(Box::pin(DataBlockStream::create(schema, None, blocks))),
+            false => spiller.merge(blocks, schema, max_block_size),
+        }
+    }
+
+    #[inline(always)]
+    fn lookup_partition_state(
+        &self,
+        key: &Method::HashKey,
+        state: &mut Method::State,
+    ) -> StateAddr {
+        let mut inserted = true;
+        let entity = state.entity(key, &mut inserted);
+
+        if self.params.aggregate_functions.is_empty() {
+            return StateAddr::new(0);
+        }
+
+        match inserted {
+            true => {
+                let place = state.alloc_layout(self.params.as_ref());
+                entity.set_state_value(place.addr());
+                place
+            }
+            false => (*entity.get_state_value()).into(),
+        }
+    }
+
     #[inline(always)]
     #[allow(clippy::ptr_arg)] // &[StateAddr] slower than &StateAddrs ~20%
     fn execute(params: &AggregatorParams, block: &DataBlock, places: &StateAddrs) -> Result<()> {
@@ -175,12 +276,24 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         groups: &Method::State,
         schema: DataSchemaRef,
     ) -> Result<SendableDataBlockStream> {
-        if groups.len() == 0 {
-            return Ok(Box::pin(DataBlockStream::create(
+        match self.finalized_block(groups, schema.clone())? {
+            None => Ok(Box::pin(DataBlockStream::create(
                 DataSchemaRefExt::create(vec![]),
                 None,
                 vec![],
-            )));
+            ))),
+            Some(block) => Ok(Box::pin(DataBlockStream::create(schema, None, vec![block]))),
+        }
+    }
+
+    // Serialize the states and the keys of the groups into a block.
+    fn finalized_block(
+        &self,
+        groups: &Method::State,
+        schema: DataSchemaRef,
+    ) -> Result<Option<DataBlock>> {
+        if groups.len() == 0 {
+            return Ok(None);
         }
 
         let aggregator_params = self.params.as_ref();
@@ -215,7 +328,6 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         }
 
         columns.push(group_key_builder.finish());
-        let block = DataBlock::create(schema.clone(), columns);
-        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+        Ok(Some(DataBlock::create(schema, columns)))
     }
 }
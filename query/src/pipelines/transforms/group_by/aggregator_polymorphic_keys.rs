@@ -23,6 +23,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 
 use crate::common::HashTable;
+use crate::common::HashTableKeyable;
 use crate::pipelines::new::processors::AggregatorParams;
 use crate::pipelines::transforms::group_by::aggregator_groups_builder::FixedKeysGroupColumnsBuilder;
 use crate::pipelines::transforms::group_by::aggregator_groups_builder::GroupColumnsBuilder;
@@ -36,6 +37,7 @@ use crate::pipelines::transforms::group_by::aggregator_keys_iter::SerializedKeys
 use crate::pipelines::transforms::group_by::aggregator_state::LongerFixedKeysAggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state::SerializedKeysAggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state::ShortFixedKeysAggregatorState;
+use crate::pipelines::transforms::group_by::keys_ref::KeysRef;
 use crate::pipelines::transforms::group_by::AggregatorState;
 
 // Provide functions for all HashMethod to help implement polymorphic group by key
@@ -90,6 +92,12 @@ pub trait PolymorphicKeysHelper<Method: HashMethod> {
         capacity: usize,
         params: &AggregatorParams,
     ) -> Self::GroupColumnsBuilder;
+
+    // The partition of the key when the aggregate state spills to disk, see [AggregatorSpiller].
+    // The short fixed keys have a bounded number of groups, they all stay in the first partition.
+    fn spill_partition(&self, _key: &Method::HashKey, _partitions: usize) -> usize {
+        0
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU8> for HashMethodKeysU8 {
@@ -177,6 +185,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU32> for HashMethodKeysU32 {
     ) -> Self::GroupColumnsBuilder {
         FixedKeysGroupColumnsBuilder::<u32>::create(capacity, params)
     }
+
+    fn spill_partition(&self, key: &u32, partitions: usize) -> usize {
+        (key.fast_hash() >> 32) as usize % partitions
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU64> for HashMethodKeysU64 {
@@ -208,6 +220,10 @@ impl PolymorphicKeysHelper<HashMethodKeysU64> for HashMethodKeysU64 {
     ) -> Self::GroupColumnsBuilder {
         FixedKeysGroupColumnsBuilder::<u64>::create(capacity, params)
     }
+
+    fn spill_partition(&self, key: &u64, partitions: usize) -> usize {
+        (key.fast_hash() >> 32) as usize % partitions
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodSerializer> for HashMethodSerializer {
@@ -240,4 +256,9 @@ impl PolymorphicKeysHelper<HashMethodSerializer> for HashMethodSerializer {
     ) -> Self::GroupColumnsBuilder {
         SerializedKeysGroupColumnsBuilder::create(capacity, params)
     }
+
+    fn spill_partition(&self, key: &Vec<u8>, partitions: usize) -> usize {
+        let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+        (keys_ref.fast_hash() >> 32) as usize % partitions
+    }
 }
@@ -0,0 +1,388 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+
+use bumpalo::Bump;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::aggregates::StateAddr;
+use common_io::prelude::BinaryRead;
+use common_io::prelude::BinaryWrite;
+use common_io::prelude::BytesMut;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::pipelines::transforms::group_by::AggregatorParamsRef;
+
+/// The number of partitions the group by keys are split into when the spilling is enabled.
+pub const SPILL_PARTITIONS: usize = 16;
+
+/// Spill the partitions of the aggregate state to the local disk.
+///
+/// Each spilled partition is a run of the serialized states sorted by the group by key:
+///     rows: uvarint
+///     row: key binary, state binary of each aggregate function
+///
+/// On finalization, the runs are merged with the in-memory partitions by an external sort-merge,
+/// the states of the same key are merged, so each key is output only once.
+pub struct AggregatorSpiller {
+    dir: PathBuf,
+    params: AggregatorParamsRef,
+    runs: Vec<PathBuf>,
+}
+
+impl AggregatorSpiller {
+    pub fn create(dir: PathBuf, params: AggregatorParamsRef) -> AggregatorSpiller {
+        AggregatorSpiller {
+            dir,
+            params,
+            runs: vec![],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    /// Write the finalized block of a partition, the states columns and the key column, as a run.
+    pub fn spill(&mut self, block: &DataBlock) -> Result<()> {
+        if self.runs.is_empty() {
+            std::fs::create_dir_all(&self.dir)?;
+        }
+
+        let path = self.dir.join(format!("run_{}", self.runs.len()));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        self.runs.push(path);
+
+        let aggr_len = self.params.aggregate_functions.len();
+        Self::write_run(block, aggr_len, &mut writer)?;
+        writer.flush()?;
+
+        tracing::debug!(
+            "Group by spilled {} rows to {:?}",
+            block.num_rows(),
+            self.runs.last()
+        );
+        Ok(())
+    }
+
+    /// Merge the spilled runs with the finalized blocks of the in-memory partitions.
+    pub fn merge(
+        self,
+        in_memory: Vec<DataBlock>,
+        schema: DataSchemaRef,
+        max_block_size: usize,
+    ) -> Result<SendableDataBlockStream> {
+        let aggr_len = self.params.aggregate_functions.len();
+
+        let mut runs = Vec::with_capacity(self.runs.len() + in_memory.len());
+        for path in &self.runs {
+            let reader = BufReader::new(File::open(path)?);
+            runs.push(SpillRun::try_create(Box::new(reader), aggr_len)?);
+        }
+
+        // The in-memory partitions are sorted in the same way, but never written to the disk.
+        for block in &in_memory {
+            let mut buffer = vec![];
+            Self::write_run(block, aggr_len, &mut buffer)?;
+            runs.push(SpillRun::try_create(
+                Box::new(Cursor::new(buffer)),
+                aggr_len,
+            )?);
+        }
+
+        let merger = SpillMerger::try_create(self, runs, schema, max_block_size)?;
+        Ok(Box::pin(futures::stream::iter(merger)))
+    }
+
+    fn write_run(block: &DataBlock, aggr_len: usize, writer: &mut impl Write) -> Result<()> {
+        let key_column = block.column(aggr_len);
+        let mut rows = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            rows.push((encode_key(key_column, row)?, row));
+        }
+        rows.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+
+        let mut states_columns = Vec::with_capacity(aggr_len);
+        for index in 0..aggr_len {
+            let states_column: &StringColumn = Series::check_get(block.column(index))?;
+            states_columns.push(states_column);
+        }
+
+        writer.write_uvarint(rows.len() as u64)?;
+        for (key, row) in rows {
+            writer.write_binary(&key)?;
+            for states_column in &states_columns {
+                writer.write_binary(states_column.get_data(row))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AggregatorSpiller {
+    fn drop(&mut self) {
+        if !self.runs.is_empty() {
+            if let Err(cause) = std::fs::remove_dir_all(&self.dir) {
+                tracing::warn!("Cannot remove group by spill dir {:?}: {}", self.dir, cause);
+            }
+        }
+    }
+}
+
+// The fixed keys are encoded in big endian, so the encoded keys sort as the numbers.
+fn encode_key(column: &ColumnRef, row: usize) -> Result<Vec<u8>> {
+    match column.get(row) {
+        DataValue::String(key) => Ok(key),
+        value => Ok(value.as_u64()?.to_be_bytes().to_vec()),
+    }
+}
+
+fn decode_key(key_type: &DataTypePtr, key: Vec<u8>) -> DataValue {
+    match key_type.data_type_id() {
+        TypeID::String => DataValue::String(key),
+        _ => {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&key);
+            DataValue::UInt64(u64::from_be_bytes(bytes))
+        }
+    }
+}
+
+struct SpillRow {
+    key: Vec<u8>,
+    states: Vec<Vec<u8>>,
+}
+
+struct SpillRun {
+    reader: Box<dyn Read + Send>,
+    remaining: u64,
+    aggr_len: usize,
+}
+
+impl SpillRun {
+    fn try_create(mut reader: Box<dyn Read + Send>, aggr_len: usize) -> Result<SpillRun> {
+        let remaining = reader.read_uvarint()?;
+        Ok(SpillRun {
+            reader,
+            remaining,
+            aggr_len,
+        })
+    }
+
+    fn next_row(&mut self) -> Result<Option<SpillRow>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        let key = self.read_binary()?;
+        let mut states = Vec::with_capacity(self.aggr_len);
+        for _ in 0..self.aggr_len {
+            states.push(self.read_binary()?);
+        }
+        Ok(Some(SpillRow { key, states }))
+    }
+
+    fn read_binary(&mut self) -> Result<Vec<u8>> {
+        let length = self.reader.read_uvarint()? as usize;
+        let mut buffer = vec![0u8; length];
+        self.reader.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+// The head row of a run, ordered by the smallest key first in the BinaryHeap.
+struct SpillHead {
+    row: SpillRow,
+    run: usize,
+}
+
+impl Ord for SpillHead {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .row
+            .key
+            .cmp(&self.row.key)
+            .then_with(|| other.run.cmp(&self.run))
+    }
+}
+
+impl PartialOrd for SpillHead {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SpillHead {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SpillHead {}
+
+struct SpillMerger {
+    // Keep the spilled runs on the disk until the merge is done.
+    spiller: AggregatorSpiller,
+    schema: DataSchemaRef,
+    key_type: DataTypePtr,
+    max_block_size: usize,
+    runs: Vec<SpillRun>,
+    heads: BinaryHeap<SpillHead>,
+    arena: Bump,
+}
+
+impl SpillMerger {
+    fn try_create(
+        spiller: AggregatorSpiller,
+        mut runs: Vec<SpillRun>,
+        schema: DataSchemaRef,
+        max_block_size: usize,
+    ) -> Result<SpillMerger> {
+        let aggr_len = spiller.params.aggregate_functions.len();
+        let key_type = schema.field(aggr_len).data_type().clone();
+
+        let mut heads = BinaryHeap::with_capacity(runs.len());
+        for (run, spill_run) in runs.iter_mut().enumerate() {
+            if let Some(row) = spill_run.next_row()? {
+                heads.push(SpillHead { row, run });
+            }
+        }
+
+        Ok(SpillMerger {
+            spiller,
+            schema,
+            key_type,
+            max_block_size,
+            runs,
+            heads,
+            arena: Bump::new(),
+        })
+    }
+
+    fn pop_head(&mut self) -> Result<Option<SpillHead>> {
+        match self.heads.pop() {
+            None => Ok(None),
+            Some(head) => {
+                if let Some(row) = self.runs[head.run].next_row()? {
+                    self.heads.push(SpillHead { row, run: head.run });
+                }
+                Ok(Some(head))
+            }
+        }
+    }
+
+    fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.heads.is_empty() {
+            return Ok(None);
+        }
+
+        let aggr_len = self.spiller.params.aggregate_functions.len();
+        let mut state_builders: Vec<MutableStringColumn> = (0..aggr_len)
+            .map(|_| MutableStringColumn::with_capacity(self.max_block_size))
+            .collect();
+        let mut key_builder = self.key_type.create_mutable(self.max_block_size);
+
+        let mut rows = 0;
+        while rows < self.max_block_size {
+            let head = match self.pop_head()? {
+                None => break,
+                Some(head) => head,
+            };
+
+            // The same key may be in several runs, merge their states.
+            let mut others = vec![];
+            while self
+                .heads
+                .peek()
+                .map_or(false, |next| next.row.key == head.row.key)
+            {
+                if let Some(next) = self.pop_head()? {
+                    others.push(next.row.states);
+                }
+            }
+
+            let states = match others.is_empty() {
+                true => head.row.states,
+                false => self.merge_states(head.row.states, others)?,
+            };
+
+            for (builder, state) in state_builders.iter_mut().zip(states.iter()) {
+                builder.append_value(state);
+            }
+            key_builder.append_data_value(decode_key(&self.key_type, head.row.key))?;
+            rows += 1;
+        }
+
+        // The merged states are serialized, their memory is no longer needed.
+        self.arena.reset();
+
+        let mut columns: Vec<ColumnRef> = Vec::with_capacity(aggr_len + 1);
+        for mut builder in state_builders {
+            columns.push(builder.to_column());
+        }
+        columns.push(key_builder.to_column());
+        Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+    }
+
+    fn merge_states(
+        &self,
+        states: Vec<Vec<u8>>,
+        others: Vec<Vec<Vec<u8>>>,
+    ) -> Result<Vec<Vec<u8>>> {
+        let funcs = &self.spiller.params.aggregate_functions;
+        let mut merged = Vec::with_capacity(funcs.len());
+
+        let mut bytes = BytesMut::new();
+        for (idx, func) in funcs.iter().enumerate() {
+            let place: StateAddr = self.arena.alloc_layout(func.state_layout()).into();
+            let mut data = states[idx].as_slice();
+            func.init_state(place);
+            func.deserialize(place, &mut data)?;
+
+            for other in &others {
+                let temp: StateAddr = self.arena.alloc_layout(func.state_layout()).into();
+                let mut data = other[idx].as_slice();
+                func.init_state(temp);
+                func.deserialize(temp, &mut data)?;
+                func.merge(place, temp)?;
+            }
+
+            func.serialize(place, &mut bytes)?;
+            merged.push(bytes[..].to_vec());
+            bytes.clear();
+        }
+
+        Ok(merged)
+    }
+}
+
+impl Iterator for SpillMerger {
+    type Item = Result<DataBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_block().transpose()
+    }
+}
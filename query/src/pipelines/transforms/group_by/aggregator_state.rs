@@ -49,6 +49,9 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
 
     fn len(&self) -> usize;
 
+    /// The memory held by the keys and the aggregate function states.
+    fn allocated_bytes(&self) -> usize;
+
     fn iter(&self) -> Self::Iterator;
 
     fn alloc_layout(&self, params: &AggregatorParams) -> StateAddr;
@@ -123,6 +126,12 @@ where
         self.size
     }
 
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        let entities_size = self.max_size * std::mem::size_of::<ShortFixedKeysStateEntity<T>>();
+        entities_size + self.area.allocated_bytes()
+    }
+
     #[inline(always)]
     fn iter(&self) -> Self::Iterator {
         Self::Iterator::create(self.data, self.max_size as isize)
@@ -209,6 +218,11 @@ where
         self.data.len()
     }
 
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes() + self.area.allocated_bytes()
+    }
+
     #[inline(always)]
     fn iter(&self) -> Self::Iterator {
         self.data.iter()
@@ -276,6 +290,12 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
         self.data_state_map.len()
     }
 
+    fn allocated_bytes(&self) -> usize {
+        self.data_state_map.allocated_bytes()
+            + self.keys_area.allocated_bytes()
+            + self.state_area.allocated_bytes()
+    }
+
     fn iter(&self) -> Self::Iterator {
         self.data_state_map.iter()
     }
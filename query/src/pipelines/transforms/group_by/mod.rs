@@ -18,6 +18,7 @@ mod aggregator_keys_builder;
 mod aggregator_keys_iter;
 mod aggregator_params;
 mod aggregator_polymorphic_keys;
+mod aggregator_spill;
 mod aggregator_state;
 mod aggregator_state_entity;
 mod aggregator_state_iterator;
@@ -30,5 +31,7 @@ pub use aggregator_keys_iter::KeysColumnIter;
 pub use aggregator_params::AggregatorParams;
 pub use aggregator_params::AggregatorParamsRef;
 pub use aggregator_polymorphic_keys::PolymorphicKeysHelper;
+pub use aggregator_spill::AggregatorSpiller;
+pub use aggregator_spill::SPILL_PARTITIONS;
 pub use aggregator_state::AggregatorState;
 pub use aggregator_state_entity::StateEntity;
@@ -24,9 +24,12 @@ mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
 mod transform_remote;
+mod transform_semi_join;
 mod transform_sort_merge;
 mod transform_sort_partial;
 mod transform_source;
+mod transform_unnest;
+mod transform_window;
 
 pub mod group_by;
 mod sources;
@@ -34,6 +37,8 @@ mod streams;
 mod transform_sink;
 
 pub use sources::CsvSourceTransform;
+pub use sources::DataAccessor;
+pub use sources::StageFile;
 pub use streams::AddOnStream;
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
@@ -49,8 +54,11 @@ pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
 pub use transform_remote::RemoteTransform;
+pub use transform_semi_join::SemiJoinTransform;
 pub use transform_sink::SinkTransform;
 pub use transform_sort_merge::SortMergeTransform;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::SortPartialTransform;
 pub use transform_source::SourceTransform;
+pub use transform_unnest::UnnestTransform;
+pub use transform_window::WindowTransform;
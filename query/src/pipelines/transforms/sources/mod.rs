@@ -17,3 +17,4 @@ mod source_dal;
 
 pub use source_csv::CsvSourceTransform;
 pub use source_dal::DataAccessor;
+pub use source_dal::StageFile;
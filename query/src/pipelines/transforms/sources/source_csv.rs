@@ -17,8 +17,10 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::MatchByColumnName;
 use common_planners::UserStagePlan;
 use common_streams::CsvSourceBuilder;
+use common_streams::ParseErrors;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_streams::SourceStream;
@@ -33,6 +35,8 @@ pub struct CsvSourceTransform {
     ctx: Arc<QueryContext>,
     file_name: Option<String>,
     stage_plan: UserStagePlan,
+    // Collect the rows failed to parse instead of failing, if set.
+    parse_errors: Option<Arc<ParseErrors>>,
 }
 
 impl CsvSourceTransform {
@@ -40,11 +44,13 @@ impl CsvSourceTransform {
         ctx: Arc<QueryContext>,
         file_name: Option<String>,
         stage_plan: UserStagePlan,
+        parse_errors: Option<Arc<ParseErrors>>,
     ) -> Result<Self> {
         Ok(CsvSourceTransform {
             ctx,
             file_name,
             stage_plan,
+            parse_errors,
         })
     }
 
@@ -53,14 +59,6 @@ impl CsvSourceTransform {
         let stage_info = &self.stage_plan.stage_info;
         let schema = self.stage_plan.schema.clone();
         let mut builder = CsvSourceBuilder::create(schema);
-        let size_limit = stage_info.copy_options.size_limit;
-
-        // Size limit.
-        {
-            if size_limit > 0 {
-                builder.size_limit(size_limit);
-            }
-        }
 
         // Block size.
         {
@@ -85,8 +83,52 @@ impl CsvSourceTransform {
             builder.record_delimiter(record_delimiter);
         }
 
+        // Quote, default '"'.
+        {
+            let quote = &stage_info.file_format_options.quote;
+            builder.quote(quote);
+        }
+
+        // Escape, default none.
+        {
+            let escape = &stage_info.file_format_options.escape;
+            builder.escape(escape);
+        }
+
+        // Null if, default none.
+        {
+            let null_if = &stage_info.file_format_options.null_if;
+            builder.null_if(null_if);
+        }
+
+        // Empty field as null, default false.
+        {
+            let empty_field_as_null = stage_info.file_format_options.empty_field_as_null;
+            builder.empty_field_as_null(empty_field_as_null);
+        }
+
+        // Match by column name, default by position.
+        {
+            match stage_info.copy_options.match_by_column_name {
+                MatchByColumnName::None => {}
+                MatchByColumnName::CaseSensitive => {
+                    builder.match_by_column_name(true);
+                }
+                MatchByColumnName::CaseInsensitive => {
+                    builder.match_by_column_name(false);
+                }
+            }
+        }
+
+        // Skip error rows, default fail.
+        {
+            if let Some(parse_errors) = &self.parse_errors {
+                builder.skip_error_rows(parse_errors.clone());
+            }
+        }
+
         let reader =
-            DataAccessor::get_file_reader(&self.ctx, file_name.clone(), stage_info).await?;
+            DataAccessor::get_source_reader(&self.ctx, file_name.clone(), stage_info).await?;
         let source = builder.build(reader)?;
 
         Ok(SourceStream::new(Box::new(source)))
@@ -119,6 +161,8 @@ impl Processor for CsvSourceTransform {
         let input_stream = csv_stream.execute().await?;
         let progress_stream =
             ProgressStream::try_create(input_stream, self.ctx.get_scan_progress())?;
-        Ok(Box::pin(progress_stream))
+        Ok(Box::pin(
+            self.ctx.try_create_abortable(Box::pin(progress_stream))?,
+        ))
     }
 }
@@ -12,26 +12,150 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use aws_sdk_s3::Credentials;
+use aws_sdk_s3::Endpoint;
+use aws_sdk_s3::Region;
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageStorage;
+use common_meta_types::StageType;
 use common_meta_types::UserStageInfo;
+use common_streams::decompress_reader;
+use common_streams::CompressionAlgorithm;
+use common_streams::SourceReader;
 use common_tracing::tracing;
 use opendal::credential::Credential;
+use opendal::error::Kind as DalErrorKind;
 use opendal::Reader;
+use opendal::Scheme as DalSchema;
+use regex::Regex;
+use walkdir::WalkDir;
 
 use crate::sessions::QueryContext;
 
+// The file of the stage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StageFile {
+    // The path relative to the stage path.
+    pub path: String,
+    pub size: u64,
+    // The md5 of the file content if the storage provides it, e.g. the etag of s3.
+    pub md5: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
 pub struct DataAccessor {}
 
 impl DataAccessor {
+    // Get the file reader, decompressed by the compression of the file format options.
+    pub async fn get_source_reader(
+        ctx: &Arc<QueryContext>,
+        file_name: Option<String>,
+        stage_info: &UserStageInfo,
+    ) -> Result<SourceReader> {
+        let path = match (&file_name, &stage_info.stage_params.storage) {
+            (Some(name), _) => name.clone(),
+            (None, StageStorage::S3(s3)) => s3.path.clone(),
+        };
+
+        let algorithm = match &stage_info.file_format_options.compression {
+            StageFileCompression::Auto => CompressionAlgorithm::from_path(&path),
+            StageFileCompression::Gzip => Some(CompressionAlgorithm::Gzip),
+            StageFileCompression::Bz2 => Some(CompressionAlgorithm::Bz2),
+            StageFileCompression::Zstd => Some(CompressionAlgorithm::Zstd),
+            StageFileCompression::None => None,
+            other => {
+                return Err(ErrorCode::UnImplement(format!(
+                    "Unsupported file compression: {:?}, must one of {{ AUTO | GZIP | BZ2 | ZSTD | NONE }}",
+                    other
+                )));
+            }
+        };
+
+        let reader = Self::get_file_reader(ctx, file_name, stage_info).await?;
+        Ok(decompress_reader(reader, algorithm, &path))
+    }
+
     pub async fn get_file_reader(
         ctx: &Arc<QueryContext>,
         file_name: Option<String>,
         stage_info: &UserStageInfo,
     ) -> Result<Reader> {
+        let operator = Self::get_operator(ctx, stage_info).await?;
+        let path = Self::get_file_path(file_name.as_deref(), stage_info);
+        Ok(operator.object(&path).reader())
+    }
+
+    // Get the size of the file in bytes.
+    pub async fn get_file_size(
+        ctx: &Arc<QueryContext>,
+        file_name: Option<String>,
+        stage_info: &UserStageInfo,
+    ) -> Result<u64> {
+        let operator = Self::get_operator(ctx, stage_info).await?;
+        let path = Self::get_file_path(file_name.as_deref(), stage_info);
+        let meta = operator
+            .object(&path)
+            .metadata()
+            .await
+            .map_err(|e| match e.kind() {
+                DalErrorKind::ObjectNotExist => ErrorCode::DalPathNotFound(e.to_string()),
+                _ => ErrorCode::DalTransportError(e.to_string()),
+            })?;
+        Ok(meta.content_length())
+    }
+
+    // Delete the file from the stage.
+    pub async fn delete_file(
+        ctx: &Arc<QueryContext>,
+        file_name: Option<String>,
+        stage_info: &UserStageInfo,
+    ) -> Result<()> {
+        let operator = Self::get_operator(ctx, stage_info).await?;
+        let path = Self::get_file_path(file_name.as_deref(), stage_info);
+        operator
+            .object(&path)
+            .delete()
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))
+    }
+
+    // The path of the file in the bucket, the stage path itself if the file name is none.
+    pub fn get_file_path(file_name: Option<&str>, stage_info: &UserStageInfo) -> String {
+        match &stage_info.stage_params.storage {
+            StageStorage::S3(s3) => match file_name {
+                None => s3.path.clone(),
+                Some(v) => {
+                    let mut path = s3.path.clone();
+                    if path.starts_with('/') {
+                        path.remove(0);
+                    }
+                    if path.ends_with('/') {
+                        path.pop();
+                    }
+                    format!("{}/{}", path, v)
+                }
+            },
+        }
+    }
+
+    pub async fn get_operator(
+        ctx: &Arc<QueryContext>,
+        stage_info: &UserStageInfo,
+    ) -> Result<opendal::Operator> {
+        // The files of internal stage are in the storage of the node.
+        if stage_info.stage_type == StageType::Internal {
+            return ctx.get_storage_operator().await;
+        }
+
         match &stage_info.stage_params.storage {
             StageStorage::S3(s3) => {
                 let mut builder = opendal::services::s3::Backend::build();
@@ -56,31 +180,202 @@ impl DataAccessor {
                     .finish()
                     .await
                     .map_err(|e| ErrorCode::DalS3Error(format!("s3 dal build error:{:?}", e)))?;
-                let operator = opendal::Operator::new(accessor);
-
-                let path = match file_name {
-                    None => s3.path.clone(),
-                    Some(v) => {
-                        let mut path = s3.path.clone();
-                        if path.starts_with('/') {
-                            path.remove(0);
-                        }
-                        if path.ends_with('/') {
-                            path.pop();
-                        }
-                        format!("{}/{}", path, v)
-                    }
-                };
 
                 tracing::info!(
-                    "get_source_reader: endpoint url:{}, bucket:{}, path:{}",
+                    "get_operator: endpoint url:{}, bucket:{}, path:{}",
                     endpoint,
                     bucket,
-                    path
+                    s3.path
                 );
 
-                Ok(operator.object(&path).reader())
+                Ok(opendal::Operator::new(accessor))
+            }
+        }
+    }
+
+    // List all the files under the stage path, including the nested prefixes.
+    // The returned file names are relative to the stage path.
+    pub async fn list_files(
+        ctx: &Arc<QueryContext>,
+        stage_info: &UserStageInfo,
+    ) -> Result<Vec<String>> {
+        let files = Self::list_stage_files(ctx, stage_info).await?;
+        Ok(files.into_iter().map(|file| file.path).collect())
+    }
+
+    // List all the files under the stage path with their metadata.
+    pub async fn list_stage_files(
+        ctx: &Arc<QueryContext>,
+        stage_info: &UserStageInfo,
+    ) -> Result<Vec<StageFile>> {
+        let StageStorage::S3(s3) = &stage_info.stage_params.storage;
+        let conf = ctx.get_config().storage;
+
+        if stage_info.stage_type == StageType::External {
+            return Self::list_s3_files(
+                &conf.s3.endpoint_url,
+                &conf.s3.region,
+                &s3.bucket,
+                &s3.credentials_aws_key_id,
+                &s3.credentials_aws_secret_key,
+                &s3.path,
+            )
+            .await;
+        }
+
+        let schema = DalSchema::from_str(&conf.storage_type)
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+        match schema {
+            DalSchema::S3 => {
+                let path = format!("{}/{}", conf.s3.root.trim_end_matches('/'), s3.path);
+                Self::list_s3_files(
+                    &conf.s3.endpoint_url,
+                    &conf.s3.region,
+                    &conf.s3.bucket,
+                    &conf.s3.access_key_id,
+                    &conf.s3.secret_access_key,
+                    &path,
+                )
+                .await
+            }
+            DalSchema::Fs => {
+                let dir = Path::new(&conf.disk.data_path).join(s3.path.trim_start_matches('/'));
+                Self::list_fs_files(&dir)
+            }
+            other => Err(ErrorCode::UnImplement(format!(
+                "Unsupported storage for internal stage: {:?}",
+                other
+            ))),
+        }
+    }
+
+    async fn list_s3_files(
+        endpoint_url: &str,
+        region: &str,
+        bucket: &str,
+        key_id: &str,
+        secret_key: &str,
+        path: &str,
+    ) -> Result<Vec<StageFile>> {
+        let endpoint = endpoint_url
+            .parse::<http::Uri>()
+            .map_err(|e| ErrorCode::DalS3Error(format!("s3 endpoint url error:{:?}", e)))?;
+        let region = match region.is_empty() {
+            true => "us-east-1".to_string(),
+            false => region.to_string(),
+        };
+
+        let mut builder = aws_sdk_s3::Config::builder()
+            .endpoint_resolver(Endpoint::immutable(endpoint))
+            .region(Region::new(region));
+
+        // Credentials.
+        if !key_id.is_empty() {
+            let credentials = Credentials::from_keys(key_id, secret_key, None);
+            builder = builder.credentials_provider(credentials);
+        }
+        let client = aws_sdk_s3::Client::from_conf(builder.build());
+
+        let mut prefix = path.trim_start_matches('/').to_string();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+
+        // The keys are listed by pages, continue until the last page.
+        let mut files = vec![];
+        let mut continuation_token = None;
+        loop {
+            let output = client
+                .list_objects_v2()
+                .bucket(bucket)
+                .prefix(&prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .map_err(|e| ErrorCode::DalS3Error(format!("s3 list objects error:{:?}", e)))?;
+
+            for object in output.contents().unwrap_or_default() {
+                if let Some(file) = object.key().and_then(|key| key.strip_prefix(&prefix)) {
+                    // Skip the directory placeholders.
+                    if !file.is_empty() && !file.ends_with('/') {
+                        files.push(StageFile {
+                            path: file.to_string(),
+                            size: object.size().max(0) as u64,
+                            md5: object.e_tag().map(|v| v.trim_matches('"').to_string()),
+                            last_modified: object
+                                .last_modified()
+                                .map(|v| Utc.timestamp(v.secs(), v.subsec_nanos())),
+                        });
+                    }
+                }
+            }
+
+            continuation_token = output.next_continuation_token().map(|v| v.to_string());
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        tracing::info!(
+            "list_files: bucket:{}, prefix:{}, files:{}",
+            bucket,
+            prefix,
+            files.len()
+        );
+        Ok(files)
+    }
+
+    fn list_fs_files(dir: &Path) -> Result<Vec<StageFile>> {
+        // The stage has no files yet.
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut files = vec![];
+        for entry in WalkDir::new(dir) {
+            let entry = entry.map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            if let Ok(file) = entry.path().strip_prefix(dir) {
+                let metadata = entry
+                    .metadata()
+                    .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+                files.push(StageFile {
+                    path: file.to_string_lossy().to_string(),
+                    size: metadata.len(),
+                    md5: None,
+                    last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                });
             }
         }
+
+        tracing::info!("list_files: dir:{:?}, files:{}", dir, files.len());
+        Ok(files)
+    }
+
+    // Filter the files by the regex pattern, which must match the whole file name.
+    // The matched files are sorted to make the load order deterministic.
+    pub fn match_files(files: Vec<String>, pattern: &str) -> Result<Vec<String>> {
+        Self::match_by_path(files, pattern, |file| file.as_str())
+    }
+
+    // Same as match_files, for the files with metadata.
+    pub fn match_stage_files(files: Vec<StageFile>, pattern: &str) -> Result<Vec<StageFile>> {
+        Self::match_by_path(files, pattern, |file| file.path.as_str())
+    }
+
+    fn match_by_path<T>(files: Vec<T>, pattern: &str, path: impl Fn(&T) -> &str) -> Result<Vec<T>> {
+        let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+            ErrorCode::SyntaxException(format!("Invalid pattern {:?}: {}", pattern, e))
+        })?;
+
+        let mut matched = files
+            .into_iter()
+            .filter(|file| regex.is_match(path(file)))
+            .collect::<Vec<_>>();
+        matched.sort_by(|a, b| path(a).cmp(path(b)));
+        Ok(matched)
     }
 }
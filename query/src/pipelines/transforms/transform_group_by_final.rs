@@ -32,11 +32,16 @@ use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::stream::StreamExt;
+use uuid::Uuid;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::AggregatorParams;
+use crate::pipelines::transforms::group_by::AggregatorSpiller;
+use crate::sessions::QueryContext;
 
 pub struct GroupByFinalTransform {
+    ctx: Arc<QueryContext>,
     max_block_size: usize,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
@@ -47,6 +52,7 @@ pub struct GroupByFinalTransform {
 
 impl GroupByFinalTransform {
     pub fn create(
+        ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         max_block_size: usize,
         schema_before_group_by: DataSchemaRef,
@@ -54,6 +60,7 @@ impl GroupByFinalTransform {
         group_exprs: Vec<Expression>,
     ) -> Self {
         Self {
+            ctx,
             max_block_size,
             aggr_exprs,
             group_exprs,
@@ -62,6 +69,56 @@ impl GroupByFinalTransform {
             input: Arc::new(EmptyProcessor::create()),
         }
     }
+
+    /// Merge the partial states of the same keys by an external sort-merge instead of a hash table.
+    /// The input blocks are buffered until they take more than `spill_threshold` bytes, then
+    /// sorted by the keys and spilled to disk as a run. The runs and the buffered blocks are
+    /// merged into the blocks of the partial states, in which each key is output only once.
+    async fn merge_spillable(
+        &self,
+        mut stream: SendableDataBlockStream,
+        group_cols: &[String],
+        spill_threshold: usize,
+    ) -> Result<Option<SendableDataBlockStream>> {
+        let aggregator_params = AggregatorParams::try_create(
+            &self.schema,
+            &self.schema_before_group_by,
+            &self.aggr_exprs,
+            group_cols,
+        )?;
+        let spill_dir = std::env::temp_dir()
+            .join("databend_group_by_spill")
+            .join(format!("{}-{}", self.ctx.get_id(), Uuid::new_v4()));
+        let mut spiller = AggregatorSpiller::create(spill_dir, aggregator_params);
+
+        let mut input_schema = None;
+        let mut buffered = vec![];
+        let mut buffered_bytes = 0;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if block.num_rows() == 0 {
+                continue;
+            }
+
+            input_schema.get_or_insert_with(|| block.schema().clone());
+            buffered_bytes += block.memory_size();
+            buffered.push(block);
+            if buffered_bytes > spill_threshold {
+                spiller.spill(&DataBlock::concat_blocks(&buffered)?)?;
+                buffered.clear();
+                buffered_bytes = 0;
+            }
+        }
+
+        match input_schema {
+            None => Ok(None),
+            Some(schema) => Ok(Some(spiller.merge(
+                buffered,
+                schema,
+                self.max_block_size,
+            )?)),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,8 +172,75 @@ impl Processor for GroupByFinalTransform {
 
         let (layout, offsets_aggregate_states) = unsafe { get_layout_offsets(&funcs) };
 
+        // The same as the partial stage, the short fixed keys have at most 65536 groups, they
+        // are never spilled.
+        let spill_threshold = self.ctx.get_settings().get_group_by_spill_threshold()? as usize;
+
         macro_rules! apply {
-            ($hash_method: ident, $key_column_type: ty, $group_func_table: ty) => {{
+            ($hash_method: ident, $key_column_type: ty, $group_func_table: ty, $spill_threshold: expr) => {{
+                let spill_threshold: usize = $spill_threshold;
+                if spill_threshold > 0 {
+                    let merged = self
+                        .merge_spillable(stream, &group_cols, spill_threshold)
+                        .await?;
+                    let merged = match merged {
+                        None => {
+                            return Ok(Box::pin(DataBlockStream::create(
+                                self.schema.clone(),
+                                None,
+                                vec![],
+                            )))
+                        }
+                        Some(merged) => merged,
+                    };
+
+                    // Each key is in one row of the merged blocks, which are finalized one by one.
+                    let schema = self.schema.clone();
+                    let stream = merged.map(move |block| {
+                        let block = block?;
+                        let arena = Bump::new();
+
+                        let key_array = block.column(aggr_funcs_len);
+                        let key_array: $key_column_type = Series::check_get(key_array)?;
+
+                        let mut states_binary_columns = Vec::with_capacity(aggr_funcs_len);
+                        let mut aggr_builders = Vec::with_capacity(aggr_funcs_len);
+                        for (idx, func) in funcs.iter().enumerate() {
+                            let aggr_column: &StringColumn = Series::check_get(block.column(idx))?;
+                            states_binary_columns.push(aggr_column);
+                            aggr_builders
+                                .push(func.return_type()?.create_mutable(block.num_rows()));
+                        }
+
+                        let mut keys = Vec::with_capacity(block.num_rows());
+                        for row in 0..block.num_rows() {
+                            keys.push($hash_method.get_key(&key_array, row));
+
+                            for (idx, func) in funcs.iter().enumerate() {
+                                let place: StateAddr =
+                                    arena.alloc_layout(func.state_layout()).into();
+                                let mut data = states_binary_columns[idx].get_data(row);
+                                func.init_state(place);
+                                func.deserialize(place, &mut data)?;
+
+                                let builder: &mut dyn MutableColumn =
+                                    aggr_builders[idx].borrow_mut();
+                                func.merge_result(place, builder)?;
+                            }
+                        }
+
+                        let mut columns: Vec<ColumnRef> =
+                            Vec::with_capacity(aggr_funcs_len + group_expr_len);
+                        for mut builder in aggr_builders {
+                            columns.push(builder.to_column());
+                        }
+                        let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
+                        columns.extend_from_slice(&group_columns);
+                        Ok(DataBlock::create(schema.clone(), columns))
+                    });
+                    return Ok(Box::pin(stream));
+                }
+
                 type GroupFuncTable = $group_func_table;
                 let groups_locker = GroupFuncTable::default();
 
@@ -231,19 +355,19 @@ impl Processor for GroupByFinalTransform {
             ($method: ident, $apply: ident) => {{
                 match $method {
                     HashMethodKind::Serializer(hash_method) => {
-                        apply! { hash_method,  &StringColumn, RwLock<HashMap<Vec<u8>, usize, ahash::RandomState>>}
+                        apply! { hash_method,  &StringColumn, RwLock<HashMap<Vec<u8>, usize, ahash::RandomState>>, spill_threshold }
                     }
                     HashMethodKind::KeysU8(hash_method) => {
-                        apply! { hash_method , &UInt8Column, RwLock<HashMap<u8, usize, ahash::RandomState>> }
+                        apply! { hash_method , &UInt8Column, RwLock<HashMap<u8, usize, ahash::RandomState>>, 0 }
                     }
                     HashMethodKind::KeysU16(hash_method) => {
-                        apply! { hash_method , &UInt16Column, RwLock<HashMap<u16, usize, ahash::RandomState>> }
+                        apply! { hash_method , &UInt16Column, RwLock<HashMap<u16, usize, ahash::RandomState>>, 0 }
                     }
                     HashMethodKind::KeysU32(hash_method) => {
-                        apply! { hash_method , &UInt32Column, RwLock<HashMap<u32, usize, ahash::RandomState>> }
+                        apply! { hash_method , &UInt32Column, RwLock<HashMap<u32, usize, ahash::RandomState>>, spill_threshold }
                     }
                     HashMethodKind::KeysU64(hash_method) => {
-                        apply! { hash_method , &UInt64Column, RwLock<HashMap<u64, usize, ahash::RandomState>> }
+                        apply! { hash_method , &UInt64Column, RwLock<HashMap<u64, usize, ahash::RandomState>>, spill_threshold }
                     }
                 }
             }};
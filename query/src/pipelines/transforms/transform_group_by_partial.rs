@@ -24,14 +24,18 @@ use common_exception::Result;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use uuid::Uuid;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::transforms::group_by::Aggregator;
 use crate::pipelines::transforms::group_by::AggregatorParams;
+use crate::pipelines::transforms::group_by::AggregatorSpiller;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+use crate::sessions::QueryContext;
 
 pub struct GroupByPartialTransform {
+    ctx: Arc<QueryContext>,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
 
@@ -42,12 +46,14 @@ pub struct GroupByPartialTransform {
 
 impl GroupByPartialTransform {
     pub fn create(
+        ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
         schema_before_group_by: DataSchemaRef,
         aggr_exprs: Vec<Expression>,
         group_exprs: Vec<Expression>,
     ) -> Self {
         Self {
+            ctx,
             aggr_exprs,
             group_exprs,
             schema,
@@ -68,6 +74,7 @@ impl GroupByPartialTransform {
         &self,
         method: Method,
         group_cols: Vec<String>,
+        spill_threshold: usize,
     ) -> Result<SendableDataBlockStream> {
         let start = Instant::now();
 
@@ -80,7 +87,31 @@ impl GroupByPartialTransform {
             &group_cols,
         )?;
 
-        let aggregator = Aggregator::create(method, aggregator_params);
+        let aggregator = Aggregator::create(method, aggregator_params.clone());
+        if spill_threshold > 0 {
+            let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+            let spill_dir = std::env::temp_dir()
+                .join("databend_group_by_spill")
+                .join(format!("{}-{}", self.ctx.get_id(), Uuid::new_v4()));
+            let spiller = AggregatorSpiller::create(spill_dir, aggregator_params);
+
+            let finalized_schema = self.schema.clone();
+            let stream = aggregator
+                .aggregate_spillable(
+                    group_cols,
+                    stream,
+                    spill_threshold,
+                    spiller,
+                    finalized_schema,
+                    max_block_size,
+                )
+                .await?;
+
+            let delta = start.elapsed();
+            tracing::debug!("Group by partial with spilling cost: {:?}", delta);
+            return Ok(stream);
+        }
+
         let state = aggregator.aggregate(group_cols, stream).await?;
 
         let delta = start.elapsed();
@@ -135,13 +166,21 @@ impl Processor for GroupByPartialTransform {
         let group_cols = self.extract_group_columns();
         let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
         let hash_method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
+        let spill_threshold = self.ctx.get_settings().get_group_by_spill_threshold()? as usize;
 
+        // The short fixed keys have at most 65536 groups, they are never spilled.
         match hash_method {
-            HashMethodKind::KeysU8(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU16(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU32(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::KeysU64(method) => self.aggregate(method, group_cols).await,
-            HashMethodKind::Serializer(method) => self.aggregate(method, group_cols).await,
+            HashMethodKind::KeysU8(method) => self.aggregate(method, group_cols, 0).await,
+            HashMethodKind::KeysU16(method) => self.aggregate(method, group_cols, 0).await,
+            HashMethodKind::KeysU32(method) => {
+                self.aggregate(method, group_cols, spill_threshold).await
+            }
+            HashMethodKind::KeysU64(method) => {
+                self.aggregate(method, group_cols, spill_threshold).await
+            }
+            HashMethodKind::Serializer(method) => {
+                self.aggregate(method, group_cols, spill_threshold).await
+            }
         }
     }
 }
@@ -0,0 +1,255 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::compare_coercion;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::default_column_cast;
+use common_planners::Expression;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use tokio_stream::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::ExpressionExecutor;
+
+/// Keep the rows of which the keys have (semi join), or have not (anti join), a match in the
+/// result of the subquery. The result is read from the subquery column of the input blocks.
+pub struct SemiJoinTransform {
+    schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+    semi_join: Arc<SemiJoin>,
+}
+
+struct SemiJoin {
+    anti: bool,
+    subquery_name: String,
+    // The types of the subquery columns compared with the keys.
+    subquery_types: Vec<DataTypePtr>,
+    // The types the keys and the subquery columns are compared as.
+    common_types: Vec<DataTypePtr>,
+    // None if there is no key.
+    executor: Option<ExpressionExecutor>,
+}
+
+// The serialized keys of the subquery rows, and whether the subquery returns any row.
+struct SubqueryKeys {
+    keys: HashSet<Vec<u8>>,
+    has_rows: bool,
+}
+
+impl SemiJoinTransform {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        anti: bool,
+        keys: Vec<Expression>,
+        subquery: Expression,
+    ) -> Result<Self> {
+        let (subquery_name, query_plan) = match subquery {
+            Expression::Subquery { name, query_plan } => (name, query_plan),
+            other => {
+                return Err(ErrorCode::BadTransformType(format!(
+                    "Semi join must be with Expression::Subquery, but got: {:?}",
+                    other
+                )));
+            }
+        };
+
+        let subquery_schema = query_plan.schema();
+        let mut subquery_types = Vec::with_capacity(keys.len());
+        let mut common_types = Vec::with_capacity(keys.len());
+        for (key, field) in keys.iter().zip(subquery_schema.fields()) {
+            let key_type = remove_nullable(&key.to_data_type(&schema)?);
+            let common_type = compare_coercion(&key_type, &remove_nullable(field.data_type()))?;
+            subquery_types.push(field.data_type().clone());
+            common_types.push(common_type);
+        }
+
+        let executor = match keys.is_empty() {
+            true => None,
+            false => {
+                let fields = keys
+                    .iter()
+                    .map(|key| key.to_data_field(&schema))
+                    .collect::<Result<Vec<_>>>()?;
+                let executor = ExpressionExecutor::try_create(
+                    "semi join keys executor",
+                    schema.clone(),
+                    DataSchemaRefExt::create(fields),
+                    keys,
+                    false,
+                )?;
+                executor.validate()?;
+                Some(executor)
+            }
+        };
+
+        Ok(SemiJoinTransform {
+            schema,
+            input: Arc::new(EmptyProcessor::create()),
+            semi_join: Arc::new(SemiJoin {
+                anti,
+                subquery_name,
+                subquery_types,
+                common_types,
+                executor,
+            }),
+        })
+    }
+}
+
+impl SemiJoin {
+    fn subquery_keys(&self, block: &DataBlock) -> Result<SubqueryKeys> {
+        let subquery_data = block.try_column_by_name(&self.subquery_name)?.get(0);
+        let columns_data = match subquery_data {
+            DataValue::Array(values) => vec![values],
+            DataValue::Struct(fields) => fields
+                .into_iter()
+                .map(|field| match field {
+                    DataValue::Array(values) => Ok(values),
+                    other => Err(ErrorCode::LogicalError(format!(
+                        "Subquery column must be Array, but got: {:?}",
+                        other
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Subquery result must be Array or Struct, but got: {:?}",
+                    other
+                )));
+            }
+        };
+
+        let rows = columns_data.first().map(|values| values.len()).unwrap_or(0);
+        let mut columns = Vec::with_capacity(self.subquery_types.len());
+        for (data_type, values) in self.subquery_types.iter().zip(&columns_data) {
+            columns.push(data_type.create_column(values)?);
+        }
+
+        let keys = self.serialize_keys(&columns, rows)?;
+        Ok(SubqueryKeys {
+            keys: keys.into_iter().flatten().collect(),
+            has_rows: rows > 0,
+        })
+    }
+
+    // The keys compared as the common types, None if any of them is NULL.
+    fn serialize_keys(&self, columns: &[ColumnRef], rows: usize) -> Result<Vec<Option<Vec<u8>>>> {
+        let mut keys = vec![Vec::new(); rows];
+        let mut valid = vec![true; rows];
+        for (column, common_type) in columns.iter().zip(&self.common_types) {
+            let column = column.convert_full_column();
+            let column = match column.is_nullable() {
+                true => default_column_cast(&column, &wrap_nullable(common_type))?,
+                false => default_column_cast(&column, common_type)?,
+            };
+
+            for (row, valid) in valid.iter_mut().enumerate() {
+                *valid = *valid && !column.null_at(row);
+            }
+            Series::serialize(&column, &mut keys)?;
+        }
+
+        Ok(keys
+            .into_iter()
+            .zip(valid)
+            .map(|(key, valid)| valid.then(|| key))
+            .collect())
+    }
+
+    fn filter(&self, subquery_keys: &SubqueryKeys, block: DataBlock) -> Result<DataBlock> {
+        let executor = match &self.executor {
+            Some(executor) => executor,
+            None => match subquery_keys.has_rows != self.anti {
+                true => return Ok(block),
+                false => return Ok(DataBlock::empty_with_schema(block.schema().clone())),
+            },
+        };
+
+        let keys_block = executor.execute(&block)?;
+        let keys = self.serialize_keys(keys_block.columns(), block.num_rows())?;
+        let predicate = keys
+            .iter()
+            .map(|key| {
+                let matched = matches!(key, Some(key) if subquery_keys.keys.contains(key));
+                matched != self.anti
+            })
+            .collect::<Vec<_>>();
+        DataBlock::filter_block(&block, &Series::from_data(predicate))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for SemiJoinTransform {
+    fn name(&self) -> &str {
+        "SemiJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "semi_join_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let input_stream = self.input.execute().await?;
+        let semi_join = self.semi_join.clone();
+
+        // The subquery column is the same in all the blocks, its keys are built once.
+        let mut subquery_keys = None;
+        let stream = input_stream.filter_map(move |data_block| {
+            let data_block = match data_block {
+                Ok(data_block) if data_block.is_empty() => return None,
+                Ok(data_block) => data_block,
+                Err(fail) => return Some(Err(fail)),
+            };
+
+            if subquery_keys.is_none() {
+                match semi_join.subquery_keys(&data_block) {
+                    Ok(keys) => subquery_keys = Some(keys),
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+
+            match semi_join.filter(subquery_keys.as_ref()?, data_block) {
+                Ok(data_block) if data_block.is_empty() => None,
+                res => Some(res),
+            }
+        });
+
+        Ok(Box::pin(CorrectWithSchemaStream::new(
+            Box::pin(stream),
+            self.schema.clone(),
+        )))
+    }
+}
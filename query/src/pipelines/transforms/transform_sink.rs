@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_functions::scalars::CastFunction;
 use common_meta_types::TableInfo;
 use common_streams::CastStream;
+use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
@@ -99,9 +100,13 @@ impl Processor for SinkTransform {
             )?);
         }
 
-        let input_schema = self.input_schema.clone();
+        // The casted columns are named by the insert columns, the omitted columns are filled.
+        let input_schema = match &self.cast_schema {
+            None => self.input_schema.clone(),
+            Some(cast_schema) => cast_schema.clone(),
+        };
         let output_schema = self.table_info.schema();
-        if self.input_schema != output_schema {
+        if input_schema != output_schema {
             input_stream = Box::pin(AddOnStream::try_create(
                 input_stream,
                 input_schema,
@@ -109,6 +114,10 @@ impl Processor for SinkTransform {
             )?)
         }
 
-        tbl.append_data(self.ctx.clone(), input_stream).await
+        let progress_stream = Box::pin(ProgressStream::try_create(
+            input_stream,
+            self.ctx.get_write_progress(),
+        )?);
+        tbl.append_data(self.ctx.clone(), progress_stream).await
     }
 }
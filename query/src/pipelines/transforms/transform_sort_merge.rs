@@ -34,6 +34,7 @@ pub struct SortMergeTransform {
     schema: DataSchemaRef,
     exprs: Vec<Expression>,
     limit: Option<usize>,
+    with_ties: bool,
     input: Arc<dyn Processor>,
 }
 
@@ -42,11 +43,13 @@ impl SortMergeTransform {
         schema: DataSchemaRef,
         exprs: Vec<Expression>,
         limit: Option<usize>,
+        with_ties: bool,
     ) -> Result<Self> {
         Ok(SortMergeTransform {
             schema,
             exprs,
             limit,
+            with_ties,
             input: Arc::new(EmptyProcessor::create()),
         })
     }
@@ -83,8 +86,18 @@ impl Processor for SortMergeTransform {
             blocks.push(block?);
         }
 
-        let results = match blocks.len() {
-            0 => vec![],
+        let results = match (blocks.len(), self.limit) {
+            (0, _) => vec![],
+            // Merge all the rows, then keep the rows which tie with the last one within the limit.
+            (_, Some(limit)) if self.with_ties => {
+                let block =
+                    DataBlock::merge_sort_blocks(&blocks, &sort_columns_descriptions, None)?;
+                vec![DataBlock::limit_sorted_block_with_ties(
+                    &block,
+                    &sort_columns_descriptions,
+                    limit,
+                )?]
+            }
             _ => vec![DataBlock::merge_sort_blocks(
                 &blocks,
                 &sort_columns_descriptions,
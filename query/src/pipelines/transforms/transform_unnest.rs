@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use tokio_stream::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// Expand each row to one row per element of the array column, like an inner lateral join.
+pub struct UnnestTransform {
+    schema: DataSchemaRef,
+    array_column: String,
+    input: Arc<dyn Processor>,
+}
+
+impl UnnestTransform {
+    pub fn create(schema: DataSchemaRef, array_column: String) -> Self {
+        UnnestTransform {
+            schema,
+            array_column,
+            input: Arc::new(EmptyProcessor::create()),
+        }
+    }
+
+    fn unnest(schema: &DataSchemaRef, array_column: &str, block: DataBlock) -> Result<DataBlock> {
+        let column = block
+            .try_column_by_name(array_column)?
+            .convert_full_column();
+        let array_column = Series::remove_nullable(&column);
+        let array_column: &ArrayColumn = Series::check_get(&array_column)?;
+
+        // Rows with NULL or empty array produce no output rows.
+        let mut offsets = Vec::with_capacity(block.num_rows());
+        let mut indices: Vec<u32> = Vec::with_capacity(array_column.values().len());
+        for row in 0..block.num_rows() {
+            if !column.null_at(row) {
+                let start = array_column.offsets()[row] as u32;
+                let end = array_column.offsets()[row + 1] as u32;
+                indices.extend(start..end);
+            }
+            offsets.push(indices.len());
+        }
+
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for column in block.columns() {
+            columns.push(column.replicate(&offsets));
+        }
+        columns.push(Series::take(array_column.values(), &indices)?);
+
+        Ok(DataBlock::create(schema.clone(), columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for UnnestTransform {
+    fn name(&self) -> &str {
+        "UnnestTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "unnest_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let schema = self.schema.clone();
+        let array_column = self.array_column.clone();
+        let input_stream = self.input.execute().await?;
+
+        let stream = input_stream.filter_map(move |block| match block {
+            Err(cause) => Some(Err(cause)),
+            Ok(block) => match Self::unnest(&schema, &array_column, block) {
+                Ok(block) if block.num_rows() == 0 => None,
+                other => Some(other),
+            },
+        });
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::transform_sort_partial::get_sort_descriptions;
+
+/// Evaluate a window function over all the input rows, the rows are returned sorted by
+/// the partition and the order columns of the window with the function value appended.
+pub struct WindowTransform {
+    schema: DataSchemaRef,
+    window_func: Expression,
+    input: Arc<dyn Processor>,
+}
+
+impl WindowTransform {
+    pub fn create(schema: DataSchemaRef, window_func: Expression) -> Self {
+        WindowTransform {
+            schema,
+            window_func,
+            input: Arc::new(EmptyProcessor::create()),
+        }
+    }
+
+    fn eval(&self, block: DataBlock) -> Result<DataBlock> {
+        let (partition_by, order_by) = match &self.window_func {
+            Expression::WindowFunction {
+                partition_by,
+                order_by,
+                ..
+            } => (partition_by, order_by),
+            other => {
+                return Err(ErrorCode::BadTransformType(format!(
+                    "Window expression must be Expression::WindowFunction, but got: {:?}",
+                    other
+                )));
+            }
+        };
+        let function = self.window_func.to_ranking_function()?;
+
+        let partition_descriptions = partition_by
+            .iter()
+            .map(|expr| SortColumnDescription {
+                column_name: expr.column_name(),
+                asc: true,
+                nulls_first: true,
+            })
+            .collect::<Vec<_>>();
+        let order_descriptions = get_sort_descriptions(&self.schema, order_by)?;
+        let descriptions = [partition_descriptions.as_slice(), &order_descriptions].concat();
+        let block = match descriptions.is_empty() {
+            true => block,
+            false => DataBlock::sort_block(&block, &descriptions, None)?,
+        };
+
+        let columns = |descriptions: &[SortColumnDescription]| {
+            descriptions
+                .iter()
+                .map(|d| block.try_column_by_name(&d.column_name))
+                .collect::<Result<Vec<_>>>()
+        };
+        let partition_columns = columns(&partition_descriptions)?;
+        let order_columns = columns(&order_descriptions)?;
+        let same_as_previous = |columns: &[&ColumnRef], row: usize| {
+            columns
+                .iter()
+                .all(|column| column.get(row) == column.get(row - 1))
+        };
+
+        let mut partition_starts = Vec::with_capacity(block.num_rows());
+        let mut peers = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            let partition_start = row == 0 || !same_as_previous(&partition_columns, row);
+            partition_starts.push(partition_start);
+            peers.push(!partition_start && same_as_previous(&order_columns, row));
+        }
+
+        let mut columns = block.columns().to_vec();
+        columns.push(function.eval(&partition_starts, &peers)?);
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+}
+
+#[async_trait]
+impl Processor for WindowTransform {
+    fn name(&self) -> &str {
+        "WindowTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "window_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let mut blocks = vec![];
+        let mut stream = self.input.execute().await?;
+        while let Some(block) = stream.next().await {
+            blocks.push(block?);
+        }
+
+        // The partitions may span all the blocks.
+        let results = match blocks.is_empty() {
+            true => vec![],
+            false => vec![self.eval(DataBlock::concat_blocks(&blocks)?)?],
+        };
+
+        Ok(Box::pin(CorrectWithSchemaStream::new(
+            Box::pin(DataBlockStream::create(self.schema.clone(), None, results)),
+            self.schema.clone(),
+        )))
+    }
+}
@@ -31,6 +31,7 @@ use crate::servers::http::v1::middleware::HTTPSessionMiddleware;
 use crate::servers::http::v1::query_route;
 use crate::servers::http::v1::statement_router;
 use crate::servers::http::v1::streaming_load;
+use crate::servers::http::v1::upload_to_stage;
 use crate::servers::Server;
 use crate::sessions::SessionManager;
 
@@ -66,6 +67,7 @@ curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' -
             .nest("/v1/statement", statement_router())
             .nest("/v1/query", query_route())
             .at("/v1/streaming_load", put(streaming_load))
+            .at("/v1/upload_to_stage", put(upload_to_stage))
             .with(HTTPSessionMiddleware {
                 session_manager: self.session_manager.clone(),
             })
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Component;
+use std::path::Path;
 use std::sync::Arc;
 
 use async_compat::CompatExt;
 use async_stream::stream;
 use common_base::ProgressValues;
+use common_meta_types::StageType;
 use common_meta_types::UserInfo;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
@@ -226,3 +229,131 @@ pub async fn streaming_load(
         error: None,
     }))
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UploadToStageResponse {
+    pub id: String,
+    pub stage_name: String,
+    pub state: String,
+    pub files: Vec<String>,
+}
+
+// Upload the files of the multipart request into the internal stage, which can be loaded by COPY later.
+#[poem::handler]
+pub async fn upload_to_stage(
+    req: &Request,
+    mut multipart: Multipart,
+    user_info: Data<&UserInfo>,
+    sessions_extension: Data<&Arc<SessionManager>>,
+) -> PoemResult<Json<UploadToStageResponse>> {
+    let session_manager = sessions_extension.0;
+    let session = session_manager
+        .create_session("Upload to stage")
+        .map_err(InternalServerError)?;
+    session.set_current_user(user_info.0.clone());
+
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+
+    let stage_name = req
+        .headers()
+        .get("stage_name")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_string();
+    if stage_name.is_empty() {
+        return Err(poem::Error::from_string(
+            "Header stage_name must be specified",
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+
+    let stage_info = context
+        .get_user_manager()
+        .get_stage(&context.get_tenant(), &stage_name)
+        .await
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST))?;
+    if stage_info.stage_type != StageType::Internal {
+        return Err(poem::Error::from_string(
+            format!(
+                "Only supports uploading to internal stage, got {}",
+                stage_name
+            ),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+
+    // The files are put under {stage prefix}/{relative_path}.
+    let relative_path = req
+        .headers()
+        .get("relative_path")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .trim_end_matches('/');
+    let prefix = match relative_path.is_empty() {
+        true => stage_info.get_prefix(),
+        false => {
+            let relative_path = check_stage_path("relative_path", relative_path, false)?;
+            format!("{}{}/", stage_info.get_prefix(), relative_path)
+        }
+    };
+
+    let operator = context
+        .get_storage_operator()
+        .await
+        .map_err(InternalServerError)?;
+
+    let mut files = vec![];
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let name = match field.file_name() {
+            Some(name) => check_stage_path("file name", name, true)?,
+            None => continue,
+        };
+        let bytes = field.bytes().await.map_err(InternalServerError)?;
+        operator
+            .object(&format!("{}{}", prefix, name))
+            .writer()
+            .write_bytes(bytes)
+            .await
+            .map_err(InternalServerError)?;
+        files.push(name);
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    Ok(Json(UploadToStageResponse {
+        id,
+        stage_name,
+        state: "SUCCESS".to_string(),
+        files,
+    }))
+}
+
+/// Checks the path given by the client is relative to the stage, returns it with the
+/// separators normalized. The files are written into the same storage as the table data,
+/// so the paths out of the stage are rejected.
+fn check_stage_path(what: &str, path: &str, is_file_name: bool) -> PoemResult<String> {
+    let components = Path::new(path).components().collect::<Vec<_>>();
+    let valid = !components.is_empty()
+        && !path.contains('\\')
+        && (!is_file_name || !path.contains('/'))
+        && components
+            .iter()
+            .all(|component| matches!(component, Component::Normal(_)));
+    if !valid {
+        return Err(poem::Error::from_string(
+            format!(
+                "The {} must be a relative path in the stage, but got {:?}",
+                what, path
+            ),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+
+    Ok(components
+        .iter()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
@@ -29,7 +29,9 @@ pub use http_query_handlers::query_route;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub use load::streaming_load;
+pub use load::upload_to_stage;
 pub use load::LoadResponse;
+pub use load::UploadToStageResponse;
 pub use query::ExecuteStateName;
 pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
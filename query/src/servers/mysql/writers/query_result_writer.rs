@@ -84,6 +84,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
                 TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
@@ -156,7 +157,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                             .to_string(),
                                     )?
                                 }
-                                (TypeID::String, DataValue::String(v)) => {
+                                (TypeID::String | TypeID::Variant, DataValue::String(v)) => {
                                     row_writer.write_col(v)?
                                 }
                                 (TypeID::Struct, DataValue::Struct(_)) => {
@@ -18,6 +18,7 @@ use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::tokio::task::JoinHandle;
 use common_base::Progress;
@@ -36,6 +37,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_streams::AbortStream;
 use common_streams::SendableDataBlockStream;
+use common_streams::TimeoutStream;
 use common_tracing::tracing;
 use opendal::Operator;
 
@@ -116,6 +118,14 @@ impl QueryContext {
         self.shared.result_progress.as_ref().get_values()
     }
 
+    pub fn get_write_progress(&self) -> Arc<Progress> {
+        self.shared.write_progress.clone()
+    }
+
+    pub fn get_write_progress_value(&self) -> ProgressValues {
+        self.shared.write_progress.as_ref().get_values()
+    }
+
     // Steal n partitions from the partition pool by the pipeline worker.
     // This also can steal the partitions from distributed node.
     pub fn try_get_partitions(&self, num: u64) -> Result<Partitions> {
@@ -190,6 +200,47 @@ impl QueryContext {
         Ok(abort_stream)
     }
 
+    // Fail with an aborted error if the query is killed, the pipelines check it between blocks.
+    pub fn check_aborting(&self) -> Result<()> {
+        match self.shared.is_aborting() {
+            true => Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed",
+            )),
+            false => Ok(()),
+        }
+    }
+
+    // Fail with a timeout error if the query has run beyond max_execution_time.
+    pub fn check_execution_time(&self) -> Result<()> {
+        if let Some(timeout) = self.get_execution_timeout()? {
+            if self.shared.created_time.elapsed() >= timeout {
+                return Err(TimeoutStream::timeout_error(timeout));
+            }
+        }
+        Ok(())
+    }
+
+    // Wrap the stream to fail once the query has run beyond max_execution_time.
+    pub fn try_create_timeout(
+        &self,
+        input: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        match self.get_execution_timeout()? {
+            None => Ok(input),
+            Some(timeout) => {
+                let deadline = self.shared.created_time + timeout;
+                Ok(Box::pin(TimeoutStream::create(input, deadline, timeout)))
+            }
+        }
+    }
+
+    fn get_execution_timeout(&self) -> Result<Option<Duration>> {
+        match self.get_settings().get_max_execution_time()? {
+            0 => Ok(None),
+            millis => Ok(Some(Duration::from_millis(millis))),
+        }
+    }
+
     pub fn get_current_database(&self) -> String {
         self.shared.get_current_database()
     }
@@ -262,6 +313,13 @@ impl QueryContext {
             .get_session_by_id(id)
     }
 
+    pub fn get_session_by_query_id(self: &Arc<Self>, query_id: &str) -> Option<SessionRef> {
+        self.shared
+            .session
+            .get_session_manager()
+            .get_session_by_query_id(query_id)
+    }
+
     // Get all the processes list info.
     pub fn get_processes_info(self: &Arc<Self>) -> Vec<ProcessInfo> {
         self.shared.session.get_session_manager().processes_info()
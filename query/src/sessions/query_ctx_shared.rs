@@ -14,8 +14,12 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_base::Progress;
 use common_base::Runtime;
@@ -53,11 +57,13 @@ pub struct QueryContextShared {
     pub conf: Config,
     pub(in crate::sessions) scan_progress: Arc<Progress>,
     pub(in crate::sessions) result_progress: Arc<Progress>,
+    pub(in crate::sessions) write_progress: Arc<Progress>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) sources_abort_handle: Arc<RwLock<Vec<AbortHandle>>>,
+    pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) ref_count: Arc<AtomicUsize>,
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
@@ -65,6 +71,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
+    pub(in crate::sessions) created_time: Instant,
 }
 
 impl QueryContextShared {
@@ -80,8 +87,10 @@ impl QueryContextShared {
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             scan_progress: Arc::new(Progress::create()),
             result_progress: Arc::new(Progress::create()),
+            write_progress: Arc::new(Progress::create()),
             runtime: Arc::new(RwLock::new(None)),
             sources_abort_handle: Arc::new(RwLock::new(Vec::new())),
+            aborting: Arc::new(AtomicBool::new(false)),
             ref_count: Arc::new(AtomicUsize::new(0)),
             subquery_index: Arc::new(AtomicUsize::new(1)),
             running_query: Arc::new(RwLock::new(None)),
@@ -89,10 +98,14 @@ impl QueryContextShared {
             running_plan: Arc::new(RwLock::new(None)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             dal_ctx: Arc::new(Default::default()),
+            created_time: Instant::now(),
         }))
     }
 
     pub fn kill(&self) {
+        // The sources created after the kill are aborted as soon as they are added.
+        self.aborting.store(true, Ordering::Release);
+
         let mut sources_abort_handle = self.sources_abort_handle.write();
 
         while let Some(source_abort_handle) = sources_abort_handle.pop() {
@@ -103,8 +116,26 @@ impl QueryContextShared {
         if let Some(handle) = &*http_query {
             handle.abort();
         }
+    }
 
-        // TODO: Wait for the query to be processed (write out the last error)
+    pub fn is_aborting(&self) -> bool {
+        self.aborting.load(Ordering::Acquire)
+    }
+
+    /// Wait until all the contexts of the query are dropped, false if the timeout elapses first.
+    pub async fn wait_finished(&self, timeout: Duration) -> bool {
+        let start = Instant::now();
+        while self.ref_count.load(Ordering::Acquire) > 0 {
+            if start.elapsed() >= timeout {
+                return false;
+            }
+            common_base::tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        true
+    }
+
+    pub fn get_query_id(&self) -> String {
+        self.init_query_id.read().clone()
     }
 
     pub fn get_cluster(&self) -> Arc<Cluster> {
@@ -207,7 +238,10 @@ impl QueryContextShared {
 
     pub fn add_source_abort_handle(&self, handle: AbortHandle) {
         let mut sources_abort_handle = self.sources_abort_handle.write();
-        sources_abort_handle.push(handle);
+        match self.is_aborting() {
+            true => handle.abort(),
+            false => sources_abort_handle.push(handle),
+        }
     }
 }
 
@@ -103,12 +103,22 @@ impl Session {
         self.kill(/* shutdown io stream */);
     }
 
-    pub fn force_kill_query(self: &Arc<Self>) {
+    /// Kill the running query, its shared context is returned to wait for the query to finish.
+    pub fn force_kill_query(self: &Arc<Self>) -> Option<Arc<QueryContextShared>> {
         let session_ctx = self.session_ctx.clone();
 
-        if let Some(context_shared) = session_ctx.take_query_context_shared() {
+        let context_shared = session_ctx.take_query_context_shared();
+        if let Some(context_shared) = &context_shared {
             context_shared.kill(/* shutdown executing query */);
         }
+        context_shared
+    }
+
+    /// The id of the running query, None if the session is idle.
+    pub fn get_running_query_id(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx
+            .get_query_context_shared()
+            .map(|context_shared| context_shared.get_query_id())
     }
 
     /// Create a query context for query.
@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::ProgressValues;
 use common_contexts::DalMetrics;
@@ -35,6 +36,8 @@ pub struct ProcessInfo {
     pub memory_usage: i64,
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    pub elapsed: Option<Duration>,
+    pub query_id: Option<String>,
 }
 
 impl Session {
@@ -66,6 +69,8 @@ impl Session {
             memory_usage,
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
+            elapsed: Session::query_elapsed(status),
+            query_id: Session::query_id(status),
         }
     }
 
@@ -110,4 +115,18 @@ impl Session {
             .as_ref()
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
+
+    fn query_elapsed(status: &SessionContext) -> Option<Duration> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.created_time.elapsed())
+    }
+
+    fn query_id(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.get_query_id())
+    }
 }
@@ -24,6 +24,8 @@ use common_base::tokio;
 use common_base::SignalStream;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::PythonUdfConfig;
+use common_functions::scalars::PythonUdfServer;
 use common_infallible::RwLock;
 use common_metrics::label_counter;
 use common_tracing::tracing;
@@ -80,6 +82,13 @@ impl SessionManager {
         let max_sessions = conf.query.max_active_sessions as usize;
         let active_sessions = Arc::new(RwLock::new(HashMap::with_capacity(max_sessions)));
 
+        // The python process of python UDFs is started by the first call.
+        PythonUdfServer::instance().configure(PythonUdfConfig {
+            executable: conf.query.python_udf_executable.clone(),
+            dir: conf.query.python_udf_dir.clone(),
+            timeout: Duration::from_millis(conf.query.python_udf_timeout_millis),
+        });
+
         Ok(Arc::new(SessionManager {
             catalog,
             conf,
@@ -193,6 +202,14 @@ impl SessionManager {
             .map(|session| SessionRef::create(session.clone()))
     }
 
+    pub fn get_session_by_query_id(self: &Arc<Self>, query_id: &str) -> Option<SessionRef> {
+        let sessions = self.active_sessions.read();
+        sessions
+            .values()
+            .find(|session| session.get_running_query_id().as_deref() == Some(query_id))
+            .map(|session| SessionRef::create(session.clone()))
+    }
+
     #[allow(clippy::ptr_arg)]
     pub fn destroy_session(self: &Arc<Self>, session_id: &String) {
         label_counter(
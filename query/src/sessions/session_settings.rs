@@ -15,11 +15,13 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::MathDomainErrorMode;
 use common_infallible::RwLock;
 use common_meta_types::UserSetting;
 
@@ -143,6 +145,70 @@ impl Settings {
                 level: ScopeLevel::Session,
                 desc: "Enable new processor framework if value != 0, default value: 0",
             },
+
+            // copy_history_retention_days
+            SettingValue {
+                default_value: DataValue::UInt64(64),
+                user_setting: UserSetting::create("copy_history_retention_days", DataValue::UInt64(64)),
+                level: ScopeLevel::Session,
+                desc: "The days to keep the files loaded by COPY to skip them. By default, it is 64 days.",
+            },
+
+            // max_copy_concurrency
+            SettingValue {
+                default_value: DataValue::UInt64(4),
+                user_setting: UserSetting::create("max_copy_concurrency", DataValue::UInt64(4)),
+                level: ScopeLevel::Session,
+                desc: "The maximum number of files loaded concurrently by COPY. By default, it is 4.",
+            },
+
+            // max_listagg_length
+            SettingValue {
+                default_value: DataValue::UInt64(1024 * 1024),
+                user_setting: UserSetting::create("max_listagg_length", DataValue::UInt64(1024 * 1024)),
+                level: ScopeLevel::Session,
+                desc: "The maximum length in bytes of the LISTAGG result, beyond which ON OVERFLOW applies. By default, it is 1MB.",
+            },
+
+            // group_by_spill_threshold
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("group_by_spill_threshold", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The memory in bytes of the GROUP BY hash table, beyond which it spills to disk. By default, it is 0, never spill.",
+            },
+
+            // math_domain_error
+            SettingValue {
+                default_value: DataValue::String("null".as_bytes().to_vec()),
+                user_setting: UserSetting::create("math_domain_error", DataValue::String("null".as_bytes().to_vec())),
+                level: ScopeLevel::Session,
+                desc: "How math functions like SQRT(-1) treat out-of-domain arguments: null, nan or error. By default, it is null.",
+            },
+
+            // max_execution_time
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_execution_time", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "The maximum execution time of a query in milliseconds, beyond which it fails with a timeout error. By default, it is 0, no limit.",
+            },
+
+            // kill_query_timeout_ms
+            SettingValue {
+                default_value: DataValue::UInt64(5000),
+                user_setting: UserSetting::create("kill_query_timeout_ms", DataValue::UInt64(5000)),
+                level: ScopeLevel::Session,
+                desc: "The time in milliseconds KILL QUERY waits for the killed query to finish. By default, it is 5000 ms.",
+            },
+
+            // unquoted_ident_case_sensitive
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("unquoted_ident_case_sensitive", DataValue::UInt64(1)),
+                level: ScopeLevel::Session,
+                desc: "Unquoted identifiers are converted to lowercase if value == 0, quoted identifiers always keep their case. By default, it is 1.",
+            },
         ];
 
         let settings = Arc::new(RwLock::new(HashMap::default()));
@@ -173,6 +239,9 @@ impl Settings {
             ret.set_max_threads(cpus)?;
         }
 
+        // Overwrite settings from the GLOBAL settings in metasrv.
+        ret.load_global_settings()?;
+
         Ok(ret)
     }
 
@@ -235,6 +304,54 @@ impl Settings {
         self.try_get_u64(key)
     }
 
+    // Get the days to keep the copied files history.
+    pub fn get_copy_history_retention_days(&self) -> Result<u64> {
+        let key = "copy_history_retention_days";
+        self.try_get_u64(key)
+    }
+
+    // Get the maximum number of files loaded concurrently by copy.
+    pub fn get_max_copy_concurrency(&self) -> Result<u64> {
+        let key = "max_copy_concurrency";
+        self.try_get_u64(key)
+    }
+
+    // Get the maximum length of the LISTAGG result.
+    pub fn get_max_listagg_length(&self) -> Result<u64> {
+        let key = "max_listagg_length";
+        self.try_get_u64(key)
+    }
+
+    // Get the memory threshold of the GROUP BY spilling.
+    pub fn get_group_by_spill_threshold(&self) -> Result<u64> {
+        let key = "group_by_spill_threshold";
+        self.try_get_u64(key)
+    }
+
+    // Get the behavior of math functions on out-of-domain arguments.
+    pub fn get_math_domain_error(&self) -> Result<MathDomainErrorMode> {
+        let key = "math_domain_error";
+        MathDomainErrorMode::from_str(&self.try_get_string(key)?)
+    }
+
+    // Get whether the unquoted identifiers keep their case.
+    pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
+        let key = "unquoted_ident_case_sensitive";
+        Ok(self.try_get_u64(key)? != 0)
+    }
+
+    // Get the maximum execution time of a query in milliseconds, 0 is no limit.
+    pub fn get_max_execution_time(&self) -> Result<u64> {
+        let key = "max_execution_time";
+        self.try_get_u64(key)
+    }
+
+    // Get the time in milliseconds KILL QUERY waits for the killed query to finish.
+    pub fn get_kill_query_timeout_ms(&self) -> Result<u64> {
+        let key = "kill_query_timeout_ms";
+        self.try_get_u64(key)
+    }
+
     fn check_and_get_setting_value(&self, key: &str) -> Result<SettingValue> {
         let settings = self.settings.read();
         let setting = settings
@@ -249,13 +366,29 @@ impl Settings {
         setting.user_setting.value.as_u64()
     }
 
+    // Get string value, we don't get from the metasrv.
+    fn try_get_string(&self, key: &str) -> Result<String> {
+        let setting = self.check_and_get_setting_value(key)?;
+        let value = setting.user_setting.value.as_string()?;
+        Ok(String::from_utf8(value)?)
+    }
+
     // Set u64 value to settings map, if is_global will write to metasrv.
     fn try_set_u64(&self, key: &str, val: u64, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::UInt64(val), is_global)
+    }
+
+    // Set string value to settings map, if is_global will write to metasrv.
+    fn try_set_string(&self, key: &str, val: String, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::String(val.into_bytes()), is_global)
+    }
+
+    fn try_set_value(&self, key: &str, val: DataValue, is_global: bool) -> Result<()> {
         let mut settings = self.settings.write();
         let mut setting = settings
             .get_mut(key)
             .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-        setting.user_setting.value = DataValue::UInt64(val);
+        setting.user_setting.value = val;
 
         if is_global {
             let tenant = self.session_ctx.get_current_tenant();
@@ -270,6 +403,21 @@ impl Settings {
         Ok(())
     }
 
+    // Load the settings written by SET GLOBAL, the unknown ones are ignored.
+    fn load_global_settings(&self) -> Result<()> {
+        let tenant = self.session_ctx.get_current_tenant();
+        let global_settings = futures::executor::block_on(self.user_api.get_settings(&tenant))?;
+
+        let mut settings = self.settings.write();
+        for global_setting in global_settings {
+            if let Some(setting) = settings.get_mut(&global_setting.name) {
+                setting.user_setting.value = global_setting.value;
+                setting.level = ScopeLevel::Global;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_setting_values(&self) -> Vec<DataValue> {
         let settings = self.settings.read();
 
@@ -297,9 +445,14 @@ impl Settings {
 
         match setting.user_setting.value.max_data_type().data_type_id() {
             TypeID::UInt64 => {
-                let u64_val = val.parse::<u64>()?;
+                let u64_val = Self::parse_u64_value(&key, &val)?;
+                Self::check_u64_value(&key, u64_val)?;
                 self.try_set_u64(&key, u64_val, is_global)?;
             }
+            TypeID::String => {
+                let val = Self::normalize_string_value(&key, val)?;
+                self.try_set_string(&key, val, is_global)?;
+            }
             v => {
                 return Err(ErrorCode::UnknownVariable(format!(
                     "Unsupported variable:{:?} type:{:?} when set_settings().",
@@ -310,4 +463,57 @@ impl Settings {
 
         Ok(())
     }
+
+    // The valid range of a u64 setting, the settings of [0, 1] are booleans.
+    fn u64_value_range(key: &str) -> Option<(u64, u64)> {
+        match key {
+            "max_block_size" | "max_threads" | "parallel_read_threads" | "max_copy_concurrency" => {
+                Some((1, u64::MAX))
+            }
+            "enable_new_processor_framework" | "unquoted_ident_case_sensitive" => Some((0, 1)),
+            _ => None,
+        }
+    }
+
+    // The boolean settings also take true/false and on/off.
+    fn parse_u64_value(key: &str, val: &str) -> Result<u64> {
+        if Self::u64_value_range(key) == Some((0, 1)) {
+            match val.to_lowercase().as_str() {
+                "true" | "on" => return Ok(1),
+                "false" | "off" => return Ok(0),
+                _ => {}
+            }
+        }
+
+        val.parse::<u64>().map_err(|_| {
+            ErrorCode::BadArguments(format!(
+                "Invalid value '{}' of setting {}, expected an unsigned integer",
+                val, key
+            ))
+        })
+    }
+
+    // Reject the values out of the valid range of a u64 setting.
+    fn check_u64_value(key: &str, val: u64) -> Result<()> {
+        let (min, max) = match Self::u64_value_range(key) {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+
+        if val < min || val > max {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid value {} of setting {}, expected in range [{}, {}]",
+                val, key, min, max
+            )));
+        }
+        Ok(())
+    }
+
+    // Reject the values a string setting can't be parsed from, and keep the others in canonical form.
+    fn normalize_string_value(key: &str, val: String) -> Result<String> {
+        match key {
+            "math_domain_error" => Ok(MathDomainErrorMode::from_str(&val)?.to_string()),
+            _ => Ok(val),
+        }
+    }
 }
@@ -15,14 +15,23 @@
 mod parser_admin;
 mod parser_copy;
 mod parser_database;
+mod parser_delete;
 mod parser_explain;
+mod parser_exponent;
 mod parser_insert;
 mod parser_kill;
 mod parser_optimize;
+mod parser_ordered_aggregate;
+mod parser_qualify;
 mod parser_query;
 mod parser_set;
 mod parser_show;
+mod parser_stage;
 mod parser_table;
 mod parser_udf;
+mod parser_update;
 mod parser_use;
 mod parser_user;
+mod parser_view;
+
+pub(crate) use parser_qualify::QUALIFY_ALIAS;
@@ -22,7 +22,9 @@ use sqlparser::parser::IsOptional;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
+use crate::sql::statements::CopyIntoStageSource;
 use crate::sql::statements::DfCopy;
+use crate::sql::statements::DfCopyIntoStage;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -30,6 +32,12 @@ impl<'a> DfParser<'a> {
     // copy into table from [?] ...
     pub(crate) fn parse_copy(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.expect_keyword(Keyword::INTO)?;
+
+        // copy into '@stage/path' from ...
+        if self.is_copy_location() {
+            return self.parse_copy_into_stage();
+        }
+
         let name = self.parser.parse_object_name()?;
         let columns = self
             .parser
@@ -66,19 +74,24 @@ impl<'a> DfParser<'a> {
             self.expect_token(")")?;
         }
 
-        // file_format = (type = csv field_delimiter = '|' skip_header = 1)
-        let mut file_format_options = HashMap::default();
-        if self.consume_token("FILE_FORMAT") {
+        // PATTERN = '<regex_pattern>'
+        let mut pattern = "".to_string();
+        if self.consume_token("PATTERN") {
             self.expect_token("=")?;
-            self.expect_token("(")?;
-            file_format_options = self.parse_options()?;
-            self.expect_token(")")?;
+            pattern = self.parser.parse_literal_string()?;
         }
 
+        // file_format = (type = csv field_delimiter = '|' skip_header = 1 null_if = ('\N', 'NULL'))
+        let (file_format_options, null_if) = self.parse_copy_file_format()?;
+
         /*
          copyOptions ::=
          ON_ERROR = { CONTINUE | SKIP_FILE | SKIP_FILE_<num> | SKIP_FILE_<num>% | ABORT_STATEMENT }
          SIZE_LIMIT = <num>
+         MATCH_BY_COLUMN_NAME = { CASE_SENSITIVE | CASE_INSENSITIVE | NONE }
+         MAX_FILES = <num>
+         COMMIT_PER_FILE = TRUE | FALSE
+         FORCE = TRUE | FALSE
         */
         let mut on_error = "".to_string();
         if self.consume_token("ON_ERROR") {
@@ -92,6 +105,36 @@ impl<'a> DfParser<'a> {
             size_limit = self.parse_value_or_ident()?;
         }
 
+        let mut match_by_column_name = "".to_string();
+        if self.consume_token("MATCH_BY_COLUMN_NAME") {
+            self.expect_token("=")?;
+            match_by_column_name = self.parse_value_or_ident()?;
+        }
+
+        let mut max_files = "".to_string();
+        if self.consume_token("MAX_FILES") {
+            self.expect_token("=")?;
+            max_files = self.parse_value_or_ident()?;
+        }
+
+        let mut commit_per_file = "".to_string();
+        if self.consume_token("COMMIT_PER_FILE") {
+            self.expect_token("=")?;
+            commit_per_file = self.parse_value_or_ident()?;
+        }
+
+        let mut force = "".to_string();
+        if self.consume_token("FORCE") {
+            self.expect_token("=")?;
+            force = self.parse_value_or_ident()?;
+        }
+
+        let mut purge = "".to_string();
+        if self.consume_token("PURGE") {
+            self.expect_token("=")?;
+            purge = self.parse_value_or_ident()?;
+        }
+
         // VALIDATION_MODE = RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS
         let mut validation_mode = "".to_string();
         if self.consume_token("VALIDATION_MODE") {
@@ -106,10 +149,92 @@ impl<'a> DfParser<'a> {
             credential_options,
             encryption_options,
             file_format_options,
+            null_if,
             files,
+            pattern,
             on_error,
             size_limit,
+            match_by_column_name,
+            max_files,
+            commit_per_file,
+            force,
+            purge,
             validation_mode,
         }))
     }
+
+    // copy into @stage/path from { table | (query) } ...
+    fn parse_copy_into_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let location = match self.parser.peek_token() {
+            Token::SingleQuotedString(_) => self.parser.parse_literal_string()?,
+            // Unquoted location, e.g. @stage/path.
+            _ => self.consume_token_until_or_end(vec!["FROM"]).concat(),
+        };
+
+        // from mytable | from (select * from mytable)
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let source = match self.parser.consume_token(&Token::LParen) {
+            true => {
+                let query = self.parser.parse_query()?;
+                self.parser.expect_token(&Token::RParen)?;
+                CopyIntoStageSource::Query(Box::new(query))
+            }
+            false => CopyIntoStageSource::Table(self.parser.parse_object_name()?),
+        };
+
+        // file_format = (type = csv field_delimiter = '|')
+        let (file_format_options, null_if) = self.parse_copy_file_format()?;
+
+        // MAX_FILE_SIZE = <num>
+        let mut max_file_size = "".to_string();
+        if self.consume_token("MAX_FILE_SIZE") {
+            self.expect_token("=")?;
+            max_file_size = self.parse_value_or_ident()?;
+        }
+
+        Ok(DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location,
+            source,
+            file_format_options,
+            null_if,
+            max_file_size,
+        }))
+    }
+
+    // The target of the copy is a location instead of a table.
+    fn is_copy_location(&mut self) -> bool {
+        match self.parser.peek_token() {
+            Token::SingleQuotedString(_) => true,
+            token => token.to_string() == "@",
+        }
+    }
+
+    pub(crate) fn parse_copy_file_format(
+        &mut self,
+    ) -> Result<(HashMap<String, String>, Vec<String>), ParserError> {
+        let mut file_format_options = HashMap::default();
+        let mut null_if: Vec<String> = vec![];
+        if self.consume_token("FILE_FORMAT") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            loop {
+                let mut options = self.parse_options()?;
+                file_format_options.extend(options.drain());
+
+                // NULL_IF = ( '<string>' [ , '<string>' ... ] )
+                if !self.consume_token("NULL_IF") {
+                    break;
+                }
+                self.expect_token("=")?;
+                self.expect_token("(")?;
+                if !self.consume_token(")") {
+                    null_if = self.parse_list(&Token::Comma)?;
+                    self.expect_token(")")?;
+                }
+            }
+            self.expect_token(")")?;
+        }
+
+        Ok((file_format_options, null_if))
+    }
 }
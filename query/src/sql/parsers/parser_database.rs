@@ -36,12 +36,15 @@ impl<'a> DfParser<'a> {
         let name = self.parser.parse_object_name()?;
         let (engine, engine_options) = self.parse_database_engine()?;
 
+        // parse database options, e.g. COMMENT = 'xxx'
+        let options = self.parse_options()?;
+
         let create = DfCreateDatabase {
             if_not_exists,
             name,
             engine,
             engine_options,
-            options: HashMap::new(),
+            options,
         };
 
         Ok(DfStatement::CreateDatabase(create))
@@ -51,10 +54,17 @@ impl<'a> DfParser<'a> {
     pub(crate) fn parse_drop_database(&mut self) -> Result<DfStatement, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let db_name = self.parser.parse_object_name()?;
+        // RESTRICT is the default.
+        let cascade = matches!(
+            self.parser
+                .parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]),
+            Some(Keyword::CASCADE)
+        );
 
         let drop = DfDropDatabase {
             if_exists,
             name: db_name,
+            cascade,
         };
 
         Ok(DfStatement::DropDatabase(drop))
@@ -16,6 +16,7 @@
 // See notice.md
 
 use common_planners::ExplainType;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 
@@ -38,12 +39,22 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
         };
 
-        let statement = Box::new(self.parse_query()?);
+        // EXPLAIN ANALYZE executes the statement, INSERT is allowed to see where the time goes.
+        let statement = match self.parser.peek_token() {
+            Token::Word(w) if typ == ExplainType::Analyze && w.keyword == Keyword::INSERT => {
+                Box::new(self.parse_insert()?)
+            }
+            _ => Box::new(self.parse_query()?),
+        };
         Ok(DfStatement::Explain(DfExplain { typ, statement }))
     }
 }
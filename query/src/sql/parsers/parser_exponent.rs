@@ -0,0 +1,188 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::parser_err;
+use crate::sql::DfParser;
+
+impl<'a> DfParser<'a> {
+    // The exponent operator `a ** b` is unknown to sqlparser, which tokenizes it as two `*`.
+    // The tokens are rewritten into `pow(a, b)` before parsing. The operands are primary
+    // expressions, so `**` binds tighter than `*` and `/`, and it is right-associative:
+    // `2 ** 3 ** 2` is `pow(2, pow(3, 2))`.
+    pub(crate) fn rewrite_exponent_operator(tokens: Vec<Token>) -> Result<Vec<Token>, ParserError> {
+        if !(0..tokens.len()).any(|i| is_exponent(&tokens, i)) {
+            return Ok(tokens);
+        }
+
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+        while i < tokens.len() {
+            if !is_exponent(&tokens, i) {
+                output.push(tokens[i].clone());
+                i += 1;
+                continue;
+            }
+
+            let start = match last_operand_start(&output) {
+                Some(start) => start,
+                None => return parser_err!("Expected an operand before **"),
+            };
+            let mut operands = vec![output.split_off(start)];
+            loop {
+                let (operand, next) = read_operand(&tokens, skip_whitespace(&tokens, i + 2))?;
+                operands.push(Self::rewrite_exponent_operator(operand)?);
+                i = next;
+
+                let next = skip_whitespace(&tokens, i);
+                if !is_exponent(&tokens, next) {
+                    break;
+                }
+                i = next;
+            }
+
+            let mut operands = operands.into_iter().rev();
+            let last = operands.next().unwrap_or_default();
+            output.extend(operands.fold(last, |exponent, base| {
+                let mut call = vec![Token::make_word("pow", None), Token::LParen];
+                call.extend(base);
+                call.push(Token::Comma);
+                call.extend(exponent);
+                call.push(Token::RParen);
+                call
+            }));
+        }
+        Ok(output)
+    }
+}
+
+fn is_exponent(tokens: &[Token], i: usize) -> bool {
+    matches!(
+        (tokens.get(i), tokens.get(i + 1)),
+        (Some(Token::Mul), Some(Token::Mul))
+    )
+}
+
+fn skip_whitespace(tokens: &[Token], mut i: usize) -> usize {
+    while let Some(Token::Whitespace(_)) = tokens.get(i) {
+        i += 1;
+    }
+    i
+}
+
+fn is_operand(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Word(_)
+            | Token::Number(_, _)
+            | Token::SingleQuotedString(_)
+            | Token::NationalStringLiteral(_)
+            | Token::HexStringLiteral(_)
+    )
+}
+
+// The start of the primary expression which ends the tokens, e.g. `a`, `t.a`, `f(a)` or `(a)`.
+fn last_operand_start(tokens: &[Token]) -> Option<usize> {
+    let mut end = tokens.len();
+    while end > 0 && matches!(tokens[end - 1], Token::Whitespace(_)) {
+        end -= 1;
+    }
+    if end == 0 {
+        return None;
+    }
+
+    let mut start = end - 1;
+    match &tokens[start] {
+        Token::RParen => {
+            let mut depth = 0;
+            loop {
+                match tokens[start] {
+                    Token::RParen => depth += 1,
+                    Token::LParen => depth -= 1,
+                    _ => {}
+                }
+                if depth == 0 {
+                    break;
+                }
+                if start == 0 {
+                    return None;
+                }
+                start -= 1;
+            }
+            // A function call.
+            if start > 0 && matches!(tokens[start - 1], Token::Word(_)) {
+                start -= 1;
+            }
+        }
+        token if is_operand(token) => {}
+        _ => return None,
+    }
+
+    // A qualified name.
+    while start >= 2
+        && matches!(tokens[start], Token::Word(_))
+        && matches!(tokens[start - 1], Token::Period)
+        && matches!(tokens[start - 2], Token::Word(_))
+    {
+        start -= 2;
+    }
+    Some(start)
+}
+
+// Reads a signed primary expression from `start`, returns it and the index after it.
+fn read_operand(tokens: &[Token], start: usize) -> Result<(Vec<Token>, usize), ParserError> {
+    let mut i = start;
+    if matches!(tokens.get(i), Some(Token::Plus) | Some(Token::Minus)) {
+        i = skip_whitespace(tokens, i + 1);
+    }
+
+    match tokens.get(i) {
+        Some(Token::LParen) => i = skip_parens(tokens, i)?,
+        Some(Token::Word(_)) => {
+            i += 1;
+            while matches!(tokens.get(i), Some(Token::Period))
+                && matches!(tokens.get(i + 1), Some(Token::Word(_)))
+            {
+                i += 2;
+            }
+            if matches!(tokens.get(i), Some(Token::LParen)) {
+                i = skip_parens(tokens, i)?;
+            }
+        }
+        Some(token) if is_operand(token) => i += 1,
+        Some(token) => {
+            return parser_err!(format!("Expected an operand after **, found: {}", token))
+        }
+        None => return parser_err!("Expected an operand after **, found: EOF"),
+    }
+    Ok((tokens[start..i].to_vec(), i))
+}
+
+// Returns the index after the parenthesis matching the one at `start`.
+fn skip_parens(tokens: &[Token], start: usize) -> Result<usize, ParserError> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return Ok(i + 1);
+        }
+    }
+    parser_err!("Expected ), found: EOF")
+}
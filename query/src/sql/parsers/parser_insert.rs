@@ -15,7 +15,9 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use sqlparser::ast::SqliteOnConflict;
 use sqlparser::ast::Statement;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
 
 use crate::parser_err;
@@ -26,9 +28,26 @@ use crate::sql::DfStatement;
 impl<'a> DfParser<'a> {
     pub(crate) fn parse_insert(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
+        // INSERT OR REPLACE INTO
+        let or = match self.parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]) {
+            true => Some(SqliteOnConflict::Replace),
+            false => None,
+        };
+        self.parse_insert_statement(or)
+    }
+
+    // REPLACE INTO, the same as INSERT OR REPLACE INTO.
+    pub(crate) fn parse_replace(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        self.parse_insert_statement(Some(SqliteOnConflict::Replace))
+    }
+
+    fn parse_insert_statement(
+        &mut self,
+        or: Option<SqliteOnConflict>,
+    ) -> Result<DfStatement, ParserError> {
         match self.parser.parse_insert()? {
             Statement::Insert {
-                or,
                 table_name,
                 columns,
                 overwrite,
@@ -38,6 +57,7 @@ impl<'a> DfParser<'a> {
                 after_columns,
                 table,
                 on,
+                ..
             } => Ok(DfStatement::InsertQuery(DfInsertStatement {
                 or,
                 table_name,
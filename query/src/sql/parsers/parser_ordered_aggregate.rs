@@ -0,0 +1,288 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Whitespace;
+
+use crate::parser_err;
+use crate::sql::DfParser;
+
+// The aggregate functions taking the ORDER BY and the IGNORE NULLS options.
+const ORDERED_AGGREGATES: [&str; 3] = ["ARRAY_AGG", "FIRST_VALUE", "LAST_VALUE"];
+
+impl<'a> DfParser<'a> {
+    // The options of `ARRAY_AGG(expr [IGNORE NULLS | RESPECT NULLS] [ORDER BY key [ASC | DESC]])
+    // [IGNORE NULLS | RESPECT NULLS]`, and the same of the other ordered aggregates, are unknown
+    // to sqlparser. The tokens are rewritten to pass the options as the parameters and the sort
+    // key as the last argument, e.g. `array_agg('DESC', 'IGNORE NULLS')(expr, key)`.
+    pub(crate) fn rewrite_ordered_aggregates(
+        tokens: Vec<Token>,
+    ) -> Result<Vec<Token>, ParserError> {
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+        while i < tokens.len() {
+            match ordered_aggregate(&tokens, i)? {
+                None => {
+                    output.push(tokens[i].clone());
+                    i += 1;
+                }
+                Some(aggregate) => {
+                    output.extend(aggregate.rewrite(&tokens)?);
+                    i = aggregate.end;
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+// The call of an ordered aggregate, the indexes are of the tokens of the query.
+struct OrderedAggregate {
+    name: usize,
+    args: (usize, usize),
+    order_by: Option<(usize, usize)>,
+    ignore_nulls: Option<bool>,
+    end: usize,
+}
+
+impl OrderedAggregate {
+    fn rewrite(&self, tokens: &[Token]) -> Result<Vec<Token>, ParserError> {
+        let name = &tokens[self.name];
+        let args = &tokens[self.args.0..self.args.1];
+        if is_blank(args) {
+            return parser_err!(format!("Expected an argument of {}", name));
+        }
+
+        let mut params = vec![];
+        let mut key = None;
+        if let Some((start, end)) = self.order_by {
+            let (key_tokens, desc) = sort_key(name, &tokens[start..end])?;
+            if desc {
+                params.push(Token::SingleQuotedString(String::from("DESC")));
+            }
+            key = Some(key_tokens);
+        }
+        if self.ignore_nulls == Some(true) {
+            params.push(Token::SingleQuotedString(String::from("IGNORE NULLS")));
+        }
+
+        let mut output = vec![name.clone()];
+        if !params.is_empty() {
+            output.push(Token::LParen);
+            for (i, param) in params.into_iter().enumerate() {
+                if i > 0 {
+                    output.extend([Token::Comma, space()]);
+                }
+                output.push(param);
+            }
+            output.push(Token::RParen);
+        }
+
+        output.push(Token::LParen);
+        output.extend(DfParser::rewrite_ordered_aggregates(args.to_vec())?);
+        if let Some(key) = key {
+            output.extend([Token::Comma, space()]);
+            output.extend(DfParser::rewrite_ordered_aggregates(key)?);
+        }
+        output.extend([Token::RParen, space()]);
+        Ok(output)
+    }
+}
+
+// The call of an ordered aggregate at the token, None if it's another token, or if the call has
+// no option or has the parameters already.
+fn ordered_aggregate(tokens: &[Token], i: usize) -> Result<Option<OrderedAggregate>, ParserError> {
+    if !is_any(&tokens[i], &ORDERED_AGGREGATES)
+        || matches!(previous_token(tokens, i), Some(Token::Period))
+    {
+        return Ok(None);
+    }
+
+    let open = skip_whitespace(tokens, i + 1);
+    if !matches!(tokens.get(open), Some(Token::LParen)) {
+        return Ok(None);
+    }
+    let close = skip_parens(tokens, open)? - 1;
+    if matches!(
+        tokens.get(skip_whitespace(tokens, close + 1)),
+        Some(Token::LParen)
+    ) {
+        return Ok(None);
+    }
+
+    let mut args_end = close;
+    let mut order_by = None;
+    let mut ignore_nulls = None;
+    let mut depth = 0usize;
+    let mut j = open + 1;
+    while j < close {
+        match &tokens[j] {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            token if depth == 0 && is_any(token, &["ORDER"]) => {
+                let by = skip_whitespace(tokens, j + 1);
+                if by < close && is_any(&tokens[by], &["BY"]) {
+                    args_end = args_end.min(j);
+                    order_by = Some((by + 1, close));
+                    break;
+                }
+            }
+            _ if depth == 0 => {
+                if let Some((ignore, next)) = nulls_option(tokens, j) {
+                    args_end = args_end.min(j);
+                    ignore_nulls = Some(ignore);
+                    j = next;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+
+    let mut end = close + 1;
+    if let Some((ignore, next)) = nulls_option(tokens, skip_whitespace(tokens, end)) {
+        if ignore_nulls.is_some() {
+            return parser_err!(format!(
+                "Expected only one IGNORE NULLS or RESPECT NULLS of {}",
+                tokens[i]
+            ));
+        }
+        ignore_nulls = Some(ignore);
+        end = next;
+    }
+
+    if order_by.is_none() && ignore_nulls.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(OrderedAggregate {
+        name: i,
+        args: (open + 1, args_end),
+        order_by,
+        ignore_nulls,
+        end,
+    }))
+}
+
+// The sort key of the ORDER BY option and whether it is descending.
+fn sort_key(name: &Token, tokens: &[Token]) -> Result<(Vec<Token>, bool), ParserError> {
+    // The indexes of the words, the whitespaces excluded.
+    let words = (0..tokens.len())
+        .filter(|i| !matches!(tokens[*i], Token::Whitespace(_)))
+        .collect::<Vec<_>>();
+    if let [.., nulls, first] = words.as_slice() {
+        if is_any(&tokens[*nulls], &["NULLS"]) && is_any(&tokens[*first], &["FIRST", "LAST"]) {
+            return parser_err!(format!(
+                "NULLS FIRST and NULLS LAST are not supported in {}",
+                name
+            ));
+        }
+    }
+
+    let (key, desc) = match words.last() {
+        Some(last) if is_any(&tokens[*last], &["ASC", "DESC"]) => {
+            (&tokens[..*last], is_any(&tokens[*last], &["DESC"]))
+        }
+        _ => (tokens, false),
+    };
+
+    if is_blank(key) {
+        return parser_err!(format!("Expected a sort key after ORDER BY of {}", name));
+    }
+    if has_top_level_comma(key) {
+        return parser_err!(format!(
+            "Expected only one sort key in ORDER BY of {}",
+            name
+        ));
+    }
+    Ok((key.to_vec(), desc))
+}
+
+// `IGNORE NULLS` or `RESPECT NULLS` at the token, returns whether NULLs are ignored and the
+// index after it.
+fn nulls_option(tokens: &[Token], i: usize) -> Option<(bool, usize)> {
+    let ignore = match tokens.get(i) {
+        Some(token) if is_any(token, &["IGNORE"]) => true,
+        Some(token) if is_any(token, &["RESPECT"]) => false,
+        _ => return None,
+    };
+
+    let nulls = skip_whitespace(tokens, i + 1);
+    match tokens.get(nulls) {
+        Some(token) if is_any(token, &["NULLS"]) => Some((ignore, nulls + 1)),
+        _ => None,
+    }
+}
+
+fn space() -> Token {
+    Token::Whitespace(Whitespace::Space)
+}
+
+fn is_any(token: &Token, keywords: &[&str]) -> bool {
+    match token {
+        Token::Word(word) if word.quote_style.is_none() => keywords
+            .iter()
+            .any(|keyword| word.value.eq_ignore_ascii_case(keyword)),
+        _ => false,
+    }
+}
+
+fn is_blank(tokens: &[Token]) -> bool {
+    tokens.iter().all(|t| matches!(t, Token::Whitespace(_)))
+}
+
+fn has_top_level_comma(tokens: &[Token]) -> bool {
+    let mut depth = 0isize;
+    for token in tokens {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            Token::Comma if depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn previous_token(tokens: &[Token], i: usize) -> Option<&Token> {
+    tokens[..i]
+        .iter()
+        .rev()
+        .find(|t| !matches!(t, Token::Whitespace(_)))
+}
+
+fn skip_whitespace(tokens: &[Token], mut i: usize) -> usize {
+    while let Some(Token::Whitespace(_)) = tokens.get(i) {
+        i += 1;
+    }
+    i
+}
+
+// Returns the index after the parenthesis matching the one at `start`.
+fn skip_parens(tokens: &[Token], start: usize) -> Result<usize, ParserError> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return Ok(i + 1);
+        }
+    }
+    parser_err!("Expected ), found: EOF")
+}
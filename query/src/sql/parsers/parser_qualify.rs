@@ -0,0 +1,212 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Whitespace;
+
+use crate::sql::DfParser;
+
+/// The alias of the projection item carrying the QUALIFY predicate, see
+/// `DfParser::rewrite_qualify_clause`.
+pub(crate) const QUALIFY_ALIAS: &str = "__qualify";
+
+// The clauses which may follow QUALIFY in the same query.
+const QUALIFY_FOLLOWING: [&str; 8] = [
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "FETCH",
+    "UNION",
+    "EXCEPT",
+    "INTERSECT",
+    "FORMAT",
+];
+
+// The keywords followed by an operand, QUALIFY following them is an identifier.
+const OPERAND_EXPECTED: [&str; 22] = [
+    "SELECT", "FROM", "WHERE", "AND", "OR", "NOT", "ON", "BY", "HAVING", "AS", "JOIN", "WHEN",
+    "THEN", "ELSE", "CASE", "IN", "IS", "LIKE", "BETWEEN", "DISTINCT", "ALL", "USING",
+];
+
+// The keywords following an operand, QUALIFY followed by them is an identifier.
+const OPERAND_FOLLOWING: [&str; 23] = [
+    "AND",
+    "OR",
+    "IS",
+    "IN",
+    "LIKE",
+    "BETWEEN",
+    "AS",
+    "WHERE",
+    "GROUP",
+    "HAVING",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "FETCH",
+    "UNION",
+    "EXCEPT",
+    "INTERSECT",
+    "FORMAT",
+    "JOIN",
+    "INNER",
+    "LEFT",
+    "RIGHT",
+    "CROSS",
+];
+
+impl<'a> DfParser<'a> {
+    // The QUALIFY clause `SELECT ... FROM ... [HAVING ...] QUALIFY <predicate>` is unknown to
+    // sqlparser. The tokens are rewritten to move the predicate to the last projection item
+    // `(<predicate>) AS __qualify`, which is taken back as the QUALIFY clause of the query by
+    // `DfQueryStatement::try_from`. QUALIFY is a reserved word after the FROM clause, it has to
+    // be quoted to be used as an alias there.
+    pub(crate) fn rewrite_qualify_clause(
+        mut tokens: Vec<Token>,
+    ) -> Result<Vec<Token>, ParserError> {
+        while let Some((from, qualify)) = find_qualify_clause(&tokens) {
+            let end = clause_end(&tokens, qualify);
+
+            let predicate = &tokens[qualify + 1..end];
+
+            let mut output = Vec::with_capacity(tokens.len() + 8);
+            output.extend_from_slice(&tokens[..from]);
+            output.extend([Token::Comma, space(), Token::LParen]);
+            output.extend_from_slice(predicate);
+            output.extend([
+                Token::RParen,
+                space(),
+                Token::make_keyword("AS"),
+                space(),
+                Token::make_word(QUALIFY_ALIAS, Some('`')),
+                space(),
+            ]);
+            output.extend_from_slice(&tokens[from..qualify]);
+            output.push(space());
+            output.extend_from_slice(&tokens[end..]);
+            tokens = output;
+        }
+        Ok(tokens)
+    }
+}
+
+fn space() -> Token {
+    Token::Whitespace(Whitespace::Space)
+}
+
+fn is_any(token: &Token, keywords: &[&str]) -> bool {
+    match token {
+        Token::Word(word) if word.quote_style.is_none() => keywords
+            .iter()
+            .any(|keyword| word.value.eq_ignore_ascii_case(keyword)),
+        _ => false,
+    }
+}
+
+fn previous_token(tokens: &[Token], i: usize) -> Option<&Token> {
+    tokens[..i]
+        .iter()
+        .rev()
+        .find(|t| !matches!(t, Token::Whitespace(_)))
+}
+
+// Whether the tokens from `start` to `end` are in the same parentheses.
+fn is_same_depth(tokens: &[Token], start: usize, end: usize) -> bool {
+    let mut depth = 0isize;
+    for token in &tokens[start..end] {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+        if depth < 0 {
+            return false;
+        }
+    }
+    depth == 0
+}
+
+// The nearest SELECT of the same query before the token.
+fn find_select(tokens: &[Token], before: usize) -> Option<usize> {
+    (0..before)
+        .rev()
+        .find(|i| is_any(&tokens[*i], &["SELECT"]) && is_same_depth(tokens, *i, before))
+}
+
+// The FROM clause of the query between the SELECT and the token, not `IS DISTINCT FROM`.
+fn find_from(tokens: &[Token], select: usize, before: usize) -> Option<usize> {
+    (select..before).find(|i| {
+        is_any(&tokens[*i], &["FROM"])
+            && !matches!(previous_token(tokens, *i), Some(token) if is_any(token, &["DISTINCT"]))
+            && is_same_depth(tokens, select, *i)
+    })
+}
+
+// QUALIFY is an identifier where an operand is expected, e.g. `WHERE qualify > 1`, or where it
+// is followed by an operator or another clause, e.g. `FROM t qualify WHERE ...`.
+fn is_clause_keyword(tokens: &[Token], i: usize) -> bool {
+    let ends_operand = match previous_token(tokens, i) {
+        Some(token @ Token::Word(_)) => !is_any(token, &OPERAND_EXPECTED),
+        Some(Token::Number(_, _)) | Some(Token::SingleQuotedString(_)) | Some(Token::RParen) => {
+            true
+        }
+        _ => false,
+    };
+
+    let starts_operand = match tokens[i + 1..]
+        .iter()
+        .find(|t| !matches!(t, Token::Whitespace(_)))
+    {
+        Some(token @ Token::Word(_)) => !is_any(token, &OPERAND_FOLLOWING),
+        Some(Token::Number(_, _))
+        | Some(Token::SingleQuotedString(_))
+        | Some(Token::LParen)
+        | Some(Token::Minus)
+        | Some(Token::Plus) => true,
+        _ => false,
+    };
+
+    ends_operand && starts_operand
+}
+
+// The first QUALIFY keyword following the FROM clause of its query, and that FROM.
+fn find_qualify_clause(tokens: &[Token]) -> Option<(usize, usize)> {
+    (0..tokens.len())
+        .filter(|i| is_any(&tokens[*i], &["QUALIFY"]))
+        .find_map(|i| {
+            if !is_clause_keyword(tokens, i) {
+                return None;
+            }
+            let select = find_select(tokens, i)?;
+            find_from(tokens, select, i).map(|from| (from, i))
+        })
+}
+
+// The end of the QUALIFY clause: the following clause, the end of the enclosing parentheses
+// or of the statement.
+fn clause_end(tokens: &[Token], qualify: usize) -> usize {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate().skip(qualify + 1) {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen if depth == 0 => return i,
+            Token::RParen => depth -= 1,
+            Token::SemiColon if depth == 0 => return i,
+            token if depth == 0 && is_any(token, &QUALIFY_FOLLOWING) => return i,
+            _ => {}
+        }
+    }
+    tokens.len()
+}
@@ -27,6 +27,7 @@ impl<'a> DfParser<'a> {
     // Set.
     pub(crate) fn parse_set(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
+        let global = self.consume_token("GLOBAL");
         match self.parser.parse_set()? {
             Statement::SetVariable {
                 local,
@@ -34,6 +35,7 @@ impl<'a> DfParser<'a> {
                 variable,
                 value,
             } => Ok(DfStatement::SetVariable(DfSetVariable {
+                global,
                 local,
                 hivevar,
                 variable,
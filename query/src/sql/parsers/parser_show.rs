@@ -22,32 +22,37 @@ use sqlparser::tokenizer::Token;
 use crate::sql::statements::DfShowDatabases;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowKind;
+use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
-    // parse show tables.
-    pub(crate) fn parse_show_tables(&mut self) -> Result<DfStatement, ParserError> {
+    // parse show [full] tables [from|in db] [like 'pattern' | where expr].
+    pub(crate) fn parse_show_tables(&mut self, showfull: bool) -> Result<DfStatement, ParserError> {
+        let mut fromdb = None;
+        if self
+            .parser
+            .parse_one_of_keywords(&[Keyword::FROM, Keyword::IN])
+            .is_some()
+        {
+            fromdb = Some(self.parser.parse_identifier()?.value);
+        }
+
         let tok = self.parser.next_token();
-        match &tok {
-            Token::EOF | Token::SemiColon => Ok(DfStatement::ShowTables(DfShowTables::create(
-                DfShowKind::All,
-            ))),
+        let kind = match &tok {
+            Token::EOF | Token::SemiColon => DfShowKind::All,
             Token::Word(w) => match w.keyword {
-                Keyword::LIKE => Ok(DfStatement::ShowTables(DfShowTables::create(
-                    DfShowKind::Like(self.parser.parse_identifier()?),
-                ))),
-                Keyword::WHERE => Ok(DfStatement::ShowTables(DfShowTables::create(
-                    DfShowKind::Where(self.parser.parse_expr()?),
-                ))),
-                Keyword::FROM | Keyword::IN => Ok(DfStatement::ShowTables(DfShowTables::create(
-                    DfShowKind::FromOrIn(self.parser.parse_object_name()?),
-                ))),
-                _ => self.expected("like or where", tok),
+                Keyword::LIKE => DfShowKind::Like(self.parser.parse_identifier()?),
+                Keyword::WHERE => DfShowKind::Where(self.parser.parse_expr()?),
+                _ => return self.expected("like or where", tok),
             },
-            _ => self.expected("like or where", tok),
-        }
+            _ => return self.expected("like or where", tok),
+        };
+
+        Ok(DfStatement::ShowTables(DfShowTables::create(
+            kind, showfull, fromdb,
+        )))
     }
 
     // parse show databases where database = xxx or where database
@@ -92,4 +97,20 @@ impl<'a> DfParser<'a> {
             _ => self.expected("like or where", tok),
         }
     }
+
+    // parse show settings [like 'pattern' | where expr]
+    pub(crate) fn parse_show_settings(&mut self) -> Result<DfStatement, ParserError> {
+        let tok = self.parser.next_token();
+        let kind = match &tok {
+            Token::EOF | Token::SemiColon => DfShowKind::All,
+            Token::Word(w) => match w.keyword {
+                Keyword::LIKE => DfShowKind::Like(self.parser.parse_identifier()?),
+                Keyword::WHERE => DfShowKind::Where(self.parser.parse_expr()?),
+                _ => return self.expected("like or where", tok),
+            },
+            _ => return self.expected("like or where", tok),
+        };
+
+        Ok(DfStatement::ShowSettings(DfShowSettings::create(kind)))
+    }
 }
@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
+// See notice.md
+
+use std::collections::HashMap;
+
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfCreateStage;
+use crate::sql::statements::DfDescribeStage;
+use crate::sql::statements::DfDropStage;
+use crate::sql::statements::DfListStage;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // create stage [if not exists] mystage
+    // [url = 's3://mybucket/path' [credentials = (...)] [encryption = (...)]]
+    // [file_format = (...)] [copy_options = (...)] [comment = '...']
+    pub(crate) fn parse_create_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let stage_name = self.parser.parse_literal_string()?;
+
+        // url = 's3://mybucket/path', internal stage if not specified.
+        let mut location = "".to_string();
+        let mut credential_options = HashMap::default();
+        let mut encryption_options = HashMap::default();
+        if self.consume_token("URL") {
+            self.expect_token("=")?;
+            location = self.parser.parse_literal_string()?;
+
+            // credentials = (aws_key_id = '...' aws_secret_key = '...')
+            if self.consume_token("CREDENTIALS") {
+                credential_options = self.parse_parenthesized_options()?;
+            }
+
+            // encryption = (master_key = '...')
+            if self.consume_token("ENCRYPTION") {
+                encryption_options = self.parse_parenthesized_options()?;
+            }
+        }
+
+        // file_format = (type = csv field_delimiter = '|' skip_header = 1)
+        let (file_format_options, null_if) = self.parse_copy_file_format()?;
+
+        // copy_options = (on_error = continue size_limit = 10)
+        let mut copy_options = HashMap::default();
+        if self.consume_token("COPY_OPTIONS") {
+            copy_options = self.parse_parenthesized_options()?;
+        }
+
+        // comment = '...'
+        let mut comment = "".to_string();
+        if self.consume_token("COMMENT") {
+            self.expect_token("=")?;
+            comment = self.parser.parse_literal_string()?;
+        }
+
+        Ok(DfStatement::CreateStage(DfCreateStage {
+            if_not_exists,
+            stage_name,
+            location,
+            credential_options,
+            encryption_options,
+            file_format_options,
+            null_if,
+            copy_options,
+            comment,
+        }))
+    }
+
+    // drop stage [if exists] mystage
+    pub(crate) fn parse_drop_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let stage_name = self.parser.parse_literal_string()?;
+
+        Ok(DfStatement::DropStage(DfDropStage {
+            if_exists,
+            stage_name,
+        }))
+    }
+
+    // desc stage mystage
+    pub(crate) fn parse_describe_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let stage_name = self.parser.parse_literal_string()?;
+
+        Ok(DfStatement::DescribeStage(DfDescribeStage { stage_name }))
+    }
+
+    // list @mystage[/path] [pattern = '<regex_pattern>']
+    pub(crate) fn parse_list_stage(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("LIST")?;
+        let location = match self.parser.peek_token() {
+            Token::SingleQuotedString(_) => self.parser.parse_literal_string()?,
+            // Unquoted location, e.g. @stage/path.
+            _ => self.consume_token_until_or_end(vec!["PATTERN"]).concat(),
+        };
+
+        // pattern = '<regex_pattern>'
+        let mut pattern = "".to_string();
+        if self.consume_token("PATTERN") {
+            self.expect_token("=")?;
+            pattern = self.parser.parse_literal_string()?;
+        }
+
+        Ok(DfStatement::ListStage(DfListStage { location, pattern }))
+    }
+
+    // = (name = value ...)
+    fn parse_parenthesized_options(&mut self) -> Result<HashMap<String, String>, ParserError> {
+        self.expect_token("=")?;
+        self.expect_token("(")?;
+        let options = self.parse_options()?;
+        self.expect_token(")")?;
+        Ok(options)
+    }
+}
@@ -16,6 +16,7 @@
 // See notice.md
 
 use sqlparser::ast::ColumnDef;
+use sqlparser::ast::ColumnOption;
 use sqlparser::ast::ColumnOptionDef;
 use sqlparser::ast::TableConstraint;
 use sqlparser::keywords::Keyword;
@@ -24,14 +25,18 @@ use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Word;
 
 use crate::parser_err;
+use crate::sql::statements::AlterTableAction;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfShowCreateTable;
 use crate::sql::statements::DfTruncateTable;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::storages::fuse::TBL_OPT_KEY_PRIMARY_KEY;
 
 impl<'a> DfParser<'a> {
     // Create table.
@@ -48,7 +53,7 @@ impl<'a> DfParser<'a> {
             table_like = Some(self.parser.parse_object_name()?);
         }
 
-        let (columns, _) = self.parse_columns()?;
+        let (columns, constraints) = self.parse_columns()?;
         if !columns.is_empty() && table_like.is_some() {
             return parser_err!("mix create table like statement and column definition.");
         }
@@ -56,7 +61,13 @@ impl<'a> DfParser<'a> {
         let engine = self.parse_table_engine()?;
 
         // parse table options: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
-        let options = self.parse_options()?;
+        let mut options = self.parse_options()?;
+        if let Some(primary_key) = Self::primary_key(&columns, &constraints)? {
+            if options.contains_key(TBL_OPT_KEY_PRIMARY_KEY) {
+                return parser_err!("multiple primary keys defined");
+            }
+            options.insert(TBL_OPT_KEY_PRIMARY_KEY.to_string(), primary_key);
+        }
 
         let mut query = None;
         if let Token::Word(Word { keyword, .. }) = self.parser.peek_token() {
@@ -84,24 +95,112 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateTable(create))
     }
 
+    // The primary key given by the column option or the table constraint, the column names are
+    // joined by comma as the table option.
+    fn primary_key(
+        columns: &[ColumnDef],
+        constraints: &[TableConstraint],
+    ) -> Result<Option<String>, ParserError> {
+        let mut primary_keys = vec![];
+        for column in columns {
+            for option in &column.options {
+                if let ColumnOption::Unique { is_primary: true } = option.option {
+                    primary_keys.push(vec![column.name.value.clone()]);
+                }
+            }
+        }
+        for constraint in constraints {
+            if let TableConstraint::Unique {
+                columns,
+                is_primary: true,
+                ..
+            } = constraint
+            {
+                primary_keys.push(columns.iter().map(|c| c.value.clone()).collect());
+            }
+        }
+
+        match primary_keys.len() {
+            0 => Ok(None),
+            1 => Ok(Some(primary_keys[0].join(","))),
+            _ => parser_err!("multiple primary keys defined"),
+        }
+    }
+
     // Drop table.
+    // Drop table, with one or more table names.
     pub(crate) fn parse_drop_table(&mut self) -> Result<DfStatement, ParserError> {
         let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
-        let table_name = self.parser.parse_object_name()?;
+        let names = self
+            .parser
+            .parse_comma_separated(|parser| parser.parse_object_name())?;
 
-        let drop = DfDropTable {
+        let drop = DfDropTable { if_exists, names };
+
+        Ok(DfStatement::DropTable(drop))
+    }
+
+    // Rename table, with one or more `old TO new` pairs.
+    pub(crate) fn parse_rename_table(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let mut name_map = vec![];
+        loop {
+            let name = self.parser.parse_object_name()?;
+            self.parser.expect_keyword(Keyword::TO)?;
+            let new_name = self.parser.parse_object_name()?;
+            name_map.push((name, new_name));
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+
+        Ok(DfStatement::RenameTable(DfRenameTable {
             if_exists,
-            name: table_name,
+            name_map,
+        }))
+    }
+
+    // Alter table: `RENAME TO new`, `ADD [COLUMN] column_def` or `DROP [COLUMN] name`.
+    pub(crate) fn parse_alter_table(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name()?;
+
+        if self.parser.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            let new_name = self.parser.parse_object_name()?;
+            return Ok(DfStatement::RenameTable(DfRenameTable {
+                if_exists,
+                name_map: vec![(name, new_name)],
+            }));
+        }
+
+        let action = if self.parser.parse_keyword(Keyword::ADD) {
+            let _ = self.parser.parse_keyword(Keyword::COLUMN);
+            AlterTableAction::AddColumn(self.parse_column_def()?)
+        } else if self.parser.parse_keyword(Keyword::DROP) {
+            let _ = self.parser.parse_keyword(Keyword::COLUMN);
+            AlterTableAction::DropColumn(self.parser.parse_identifier()?)
+        } else {
+            return self.expected(
+                "RENAME TO, ADD COLUMN or DROP COLUMN",
+                self.parser.peek_token(),
+            );
         };
 
-        Ok(DfStatement::DropTable(drop))
+        Ok(DfStatement::AlterTable(DfAlterTable {
+            if_exists,
+            table_name: name,
+            action,
+        }))
     }
 
     // Truncate table.
     pub(crate) fn parse_truncate_table(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let table_name = self.parser.parse_object_name()?;
         let purge = self.parser.parse_keyword(Keyword::PURGE);
         let statement = DfTruncateTable {
+            if_exists,
             name: table_name,
             purge,
         };
@@ -15,6 +15,8 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use common_meta_types::PythonHandler;
+use sqlparser::ast::DataType;
 use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
@@ -107,12 +109,90 @@ impl<'a> DfParser<'a> {
         Ok(definition)
     }
 
+    // The typed parameters, e.g. (x INT, y INT).
+    fn parse_udf_typed_parameters(&mut self) -> Result<(Vec<String>, Vec<DataType>), ParserError> {
+        let mut params = vec![];
+        let mut types = vec![];
+
+        self.parser.expect_token(&Token::LParen)?;
+        if self.parser.consume_token(&Token::RParen) {
+            return Ok((params, types));
+        }
+
+        loop {
+            let param = self.parser.parse_identifier()?;
+            if let Some(quote) = param.quote_style {
+                return parser_err!(format!(
+                    "Quote is not allowed in parameters, remove: {}",
+                    quote
+                ));
+            }
+            if params.contains(&param.value) {
+                return parser_err!(format!(
+                    "Duplicate parameter is not allowed, keep only one: {}",
+                    param.value
+                ));
+            }
+            params.push(param.value);
+            types.push(self.parser.parse_data_type()?);
+
+            if !self.parser.consume_token(&Token::Comma) {
+                self.parser.expect_token(&Token::RParen)?;
+                break;
+            }
+        }
+
+        Ok((params, types))
+    }
+
+    // CREATE FUNCTION <name>(<param> <type>, ...) RETURNS <type> AS (<expr>)
+    // CREATE FUNCTION <name>(<param> <type>, ...) RETURNS <type> LANGUAGE python
+    //     FILE = '<file>' HANDLER = '<function>'
+    fn parse_create_typed_udf(
+        &mut self,
+        if_not_exists: bool,
+        udf_name: String,
+    ) -> Result<DfStatement, ParserError> {
+        let (parameters, parameter_types) = self.parse_udf_typed_parameters()?;
+        self.expect_token("RETURNS")?;
+        let return_type = self.parser.parse_data_type()?;
+
+        let mut definition = String::new();
+        let mut python_handler = None;
+        if self.consume_token("LANGUAGE") {
+            self.expect_token("PYTHON")?;
+            let file = self.parse_udf_option("FILE")?;
+            let function = self.parse_udf_option("HANDLER")?;
+            python_handler = Some(PythonHandler { file, function });
+        } else {
+            self.parser.expect_keyword(Keyword::AS)?;
+            definition = self.parser.parse_expr()?.to_string();
+        }
+
+        let description = self.parse_udf_desc("DESC")?;
+        let create_udf = DfCreateUDF {
+            if_not_exists,
+            udf_name,
+            parameters,
+            definition,
+            description,
+            parameter_types,
+            return_type: Some(return_type),
+            python_handler,
+        };
+
+        Ok(DfStatement::CreateUDF(create_udf))
+    }
+
     pub(crate) fn parse_create_udf(&mut self) -> Result<DfStatement, ParserError> {
         let if_not_exists =
             self.parser
                 .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
 
         let udf_name = self.parser.parse_literal_string()?;
+        if self.parser.peek_token() == Token::LParen {
+            return self.parse_create_typed_udf(if_not_exists, udf_name);
+        }
         self.parser.expect_keyword(Keyword::AS)?;
 
         let desc_token = "DESC";
@@ -126,6 +206,9 @@ impl<'a> DfParser<'a> {
             parameters,
             definition,
             description,
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         };
 
         Ok(DfStatement::CreateUDF(create_udf))
@@ -162,6 +245,13 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropUDF(drop_udf))
     }
 
+    // <name> = '<value>'
+    fn parse_udf_option(&mut self, name: &str) -> Result<String, ParserError> {
+        self.expect_token(name)?;
+        self.parser.expect_token(&Token::Eq)?;
+        self.parser.parse_literal_string()
+    }
+
     fn parse_udf_desc(&mut self, desc_token: &str) -> Result<String, ParserError> {
         if self.consume_token(desc_token) {
             self.parser.expect_token(&Token::Eq)?;
@@ -0,0 +1,45 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfUpdate;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // syntax: "UPDATE t SET a = expr [, b = expr] [WHERE expr]"
+    pub(crate) fn parse_update(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        let table_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::SET)?;
+        let assignments = self.parser.parse_comma_separated(|parser| {
+            let column = parser.parse_identifier()?;
+            parser.expect_token(&Token::Eq)?;
+            Ok((column, parser.parse_expr()?))
+        })?;
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Update(DfUpdate {
+            table_name,
+            assignments,
+            selection,
+        }))
+    }
+}
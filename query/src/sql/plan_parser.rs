@@ -72,11 +72,24 @@ impl PlanParser {
 
     pub fn build_query_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
         let from = Self::build_from_plan(data)?;
-        let filter = Self::build_filter_plan(from, data)?;
-        let group_by = Self::build_group_by_plan(filter, data)?;
-        let before_order = Self::build_before_order(group_by, data)?;
-        let having = Self::build_having_plan(before_order, data)?;
-        let order_by = Self::build_order_by_plan(having, data)?;
+        let unnest = Self::build_unnest_plan(from, data)?;
+        let filter = Self::build_filter_plan(unnest, data)?;
+        let semi_join = Self::build_semi_join_plan(filter, data)?;
+        let group_by = Self::build_group_by_plan(semi_join, data)?;
+        let before_qualify = match data.window_expressions.is_empty() {
+            true => {
+                let before_order = Self::build_before_order(group_by, data)?;
+                Self::build_having_plan(before_order, data)?
+            }
+            false => {
+                let before_window = Self::build_before_window(group_by, data)?;
+                let having = Self::build_having_plan(before_window, data)?;
+                let window = Self::build_window_plan(having, data)?;
+                Self::build_before_order(window, data)?
+            }
+        };
+        let qualify = Self::build_qualify_plan(before_qualify, data)?;
+        let order_by = Self::build_order_by_plan(qualify, data)?;
         let projection = Self::build_projection_plan(order_by, data)?;
         let limit = Self::build_limit_plan(projection, data)?;
 
@@ -93,6 +106,16 @@ impl PlanParser {
         }
     }
 
+    /// Apply a lateral unnest to the plan
+    fn build_unnest_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match &data.unnest {
+            None => Ok(plan),
+            Some((array_column, element_column)) => PlanBuilder::from(&plan)
+                .unnest(array_column, element_column)?
+                .build(),
+        }
+    }
+
     /// Apply a filter to the plan
     fn build_filter_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         match &data.filter {
@@ -105,6 +128,16 @@ impl PlanParser {
         }
     }
 
+    /// Apply the semi joins of the [NOT] EXISTS subqueries to the plan
+    fn build_semi_join_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        let mut builder = PlanBuilder::from(&plan);
+        for semi_join in &data.semi_joins {
+            builder =
+                builder.semi_join(semi_join.anti, &semi_join.keys, semi_join.subquery.clone())?;
+        }
+        builder.build()
+    }
+
     fn build_group_by_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         // S0: Apply a partial aggregator plan.
         // S1: Apply a fragment plan for distributed planners split.
@@ -149,6 +182,39 @@ impl PlanParser {
         }
     }
 
+    fn build_before_window(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        fn is_all_column(exprs: &[Expression]) -> bool {
+            exprs
+                .iter()
+                .all(|expr| matches!(expr, Expression::Column(_)))
+        }
+
+        match data.before_window_expressions.is_empty() {
+            true => Ok(plan),
+            // if all expression is column expression expression, we skip this expression
+            false if is_all_column(&data.before_window_expressions) => Ok(plan),
+            false => PlanBuilder::from(&plan)
+                .expression(&data.before_window_expressions, "Before Window")?
+                .build(),
+        }
+    }
+
+    /// Apply a window plan for each window function
+    fn build_window_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        let mut builder = PlanBuilder::from(&plan);
+        for window_func in &data.window_expressions {
+            builder = builder.window(window_func)?;
+        }
+        builder.build()
+    }
+
+    fn build_qualify_plan(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
+        match &data.qualify {
+            None => Ok(plan),
+            Some(predicate) => PlanBuilder::from(&plan).filter(predicate.clone())?.build(),
+        }
+    }
+
     fn build_before_order(plan: PlanNode, data: &QueryAnalyzeState) -> Result<PlanNode> {
         fn is_all_column(exprs: &[Expression]) -> bool {
             exprs
@@ -190,7 +256,7 @@ impl PlanParser {
         match (&data.limit, &data.offset) {
             (None, None) => Ok(input),
             (limit, offset) => PlanBuilder::from(&input)
-                .limit_offset(*limit, offset.unwrap_or(0))?
+                .limit_offset_with_ties(*limit, offset.unwrap_or(0), data.limit_with_ties)?
                 .build(),
         }
     }
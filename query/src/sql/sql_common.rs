@@ -16,6 +16,8 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 
 pub struct SQLCommon;
 
@@ -58,4 +60,35 @@ impl SQLCommon {
             ))),
         }
     }
+
+    /// Quoted identifiers keep their case, unquoted ones are lower-cased unless
+    /// `unquoted_ident_case_sensitive` is enabled.
+    pub fn normalize_identifier(ident: &Ident, unquoted_ident_case_sensitive: bool) -> String {
+        match ident.quote_style {
+            None if !unquoted_ident_case_sensitive => ident.value.to_lowercase(),
+            _ => ident.value.clone(),
+        }
+    }
+
+    /// Resolves the database name of `statement`, which must be a single non-empty identifier.
+    pub fn resolve_database_name(
+        statement: &str,
+        name: &ObjectName,
+        unquoted_ident_case_sensitive: bool,
+    ) -> Result<String> {
+        match name.0.as_slice() {
+            [ident] if !ident.value.is_empty() => Ok(Self::normalize_identifier(
+                ident,
+                unquoted_ident_case_sensitive,
+            )),
+            [] | [_] => Err(ErrorCode::SyntaxException(format!(
+                "{} database name is empty",
+                statement
+            ))),
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "{} database name must not be qualified, but got {}",
+                statement, name
+            ))),
+        }
+    }
 }
@@ -33,7 +33,6 @@ use sqlparser::tokenizer::Whitespace;
 use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowMetrics;
 use crate::sql::statements::DfShowProcessList;
-use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::DfHint;
 use crate::sql::DfStatement;
@@ -61,7 +60,9 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = Self::rewrite_exponent_operator(tokenizer.tokenize()?)?;
+        let tokens = Self::rewrite_qualify_clause(tokens)?;
+        let tokens = Self::rewrite_ordered_aggregates(tokens)?;
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
@@ -157,11 +158,18 @@ impl<'a> DfParser<'a> {
                     Keyword::SHOW => {
                         self.parser.next_token();
                         if self.consume_token("TABLES") {
-                            self.parse_show_tables()
+                            self.parse_show_tables(false)
+                        } else if self.consume_token("FULL") {
+                            if self.consume_token("TABLES") {
+                                self.parse_show_tables(true)
+                            } else {
+                                self.expected("tables", self.parser.peek_token())
+                            }
                         } else if self.consume_token("DATABASES") {
                             self.parse_show_databases()
-                        } else if self.consume_token("SETTINGS") {
-                            Ok(DfStatement::ShowSettings(DfShowSettings))
+                        } else if self.consume_token("SETTINGS") || self.consume_token("VARIABLES")
+                        {
+                            self.parse_show_settings()
                         } else if self.consume_token("CREATE") {
                             self.parse_show_create()
                         } else if self.consume_token("PROCESSLIST") {
@@ -181,8 +189,12 @@ impl<'a> DfParser<'a> {
                         }
                     }
                     Keyword::TRUNCATE => self.parse_truncate(),
+                    Keyword::RENAME => self.parse_rename(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::REPLACE => self.parse_replace(),
+                    Keyword::DELETE => self.parse_delete(),
+                    Keyword::UPDATE => self.parse_update(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -196,6 +208,8 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_copy()
                     }
+                    // LIST may not be a keyword of the sql parser.
+                    _ if w.value.to_uppercase() == "LIST" => self.parse_list_stage(),
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
@@ -215,13 +229,20 @@ impl<'a> DfParser<'a> {
     fn parse_create(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => {
-                //TODO:make stage to sql parser keyword
                 match w.keyword {
                     Keyword::TABLE => self.parse_create_table(),
+                    Keyword::VIEW => self.parse_create_view(false),
+                    Keyword::OR => {
+                        self.parser
+                            .expect_keywords(&[Keyword::REPLACE, Keyword::VIEW])?;
+                        self.parse_create_view(true)
+                    }
                     Keyword::DATABASE => self.parse_create_database(),
                     Keyword::USER => self.parse_create_user(),
                     Keyword::ROLE => self.parse_create_role(),
                     Keyword::FUNCTION => self.parse_create_udf(),
+                    // STAGE is not a keyword of the sql parser.
+                    _ if w.value.to_uppercase() == "STAGE" => self.parse_create_stage(),
                     _ => self.expected("create statement", Token::Word(w)),
                 }
             }
@@ -283,13 +304,17 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => match w.keyword {
                 Keyword::USER => self.parse_alter_user(),
                 Keyword::FUNCTION => self.parse_alter_udf(),
-                _ => self.expected("keyword USER or FUNCTION", Token::Word(w)),
+                Keyword::TABLE => self.parse_alter_table(),
+                _ => self.expected("keyword USER, FUNCTION or TABLE", Token::Word(w)),
             },
             unexpected => self.expected("alter statement", unexpected),
         }
     }
 
     fn parse_describe(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("stage") {
+            return self.parse_describe_stage();
+        }
         self.consume_token("table");
         self.parse_desc_table()
     }
@@ -300,9 +325,11 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => match w.keyword {
                 Keyword::DATABASE => self.parse_drop_database(),
                 Keyword::TABLE => self.parse_drop_table(),
+                Keyword::VIEW => self.parse_drop_view(),
                 Keyword::USER => self.parse_drop_user(),
                 Keyword::ROLE => self.parse_drop_role(),
                 Keyword::FUNCTION => self.parse_drop_udf(),
+                _ if w.value.to_uppercase() == "STAGE" => self.parse_drop_stage(),
                 _ => self.expected("drop statement", Token::Word(w)),
             },
             unexpected => self.expected("drop statement", unexpected),
@@ -331,6 +358,17 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_rename(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::TABLE => self.parse_rename_table(),
+                _ => self.expected("rename statement", Token::Word(w)),
+            },
+            unexpected => self.expected("rename statement", unexpected),
+        }
+    }
+
     pub(crate) fn parse_options(&mut self) -> Result<HashMap<String, String>, ParserError> {
         let mut options = HashMap::new();
         loop {
@@ -345,6 +383,12 @@ impl<'a> DfParser<'a> {
                 self.parser.prev_token();
                 break;
             }
+            if self.parser.peek_token() == Token::LParen {
+                // list values are left to the caller
+                self.parser.prev_token();
+                self.parser.prev_token();
+                break;
+            }
             let value = self.parse_value_or_ident()?;
 
             options.insert(name.to_string().to_lowercase(), value);
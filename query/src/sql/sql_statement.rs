@@ -20,25 +20,35 @@ use nom::character::complete::multispace1;
 use nom::IResult;
 
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoStage;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCreateDatabase;
 use crate::sql::statements::DfCreateRole;
+use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDelete;
+use crate::sql::statements::DfDescribeStage;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropRole;
+use crate::sql::statements::DfDropStage;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantPrivilegeStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
+use crate::sql::statements::DfListStage;
 use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
@@ -53,6 +63,7 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUpdate;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::statements::DfUseTenant;
 
@@ -77,9 +88,15 @@ pub enum DfStatement {
     CreateTable(DfCreateTable),
     DescribeTable(DfDescribeTable),
     DropTable(DfDropTable),
+    RenameTable(DfRenameTable),
+    AlterTable(DfAlterTable),
     TruncateTable(DfTruncateTable),
     OptimizeTable(DfOptimizeTable),
 
+    // Views.
+    CreateView(DfCreateView),
+    DropView(DfDropView),
+
     // Settings.
     ShowSettings(DfShowSettings),
 
@@ -101,6 +118,12 @@ pub enum DfStatement {
     // Insert
     InsertQuery(DfInsertStatement),
 
+    // Delete
+    Delete(DfDelete),
+
+    // Update
+    Update(DfUpdate),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
@@ -111,8 +134,15 @@ pub enum DfStatement {
     CreateRole(DfCreateRole),
     DropRole(DfDropRole),
 
+    // Stage
+    CreateStage(DfCreateStage),
+    DropStage(DfDropStage),
+    DescribeStage(DfDescribeStage),
+    ListStage(DfListStage),
+
     // Copy
     Copy(DfCopy),
+    CopyIntoStage(DfCopyIntoStage),
 
     // Grant
     GrantPrivilege(DfGrantPrivilegeStatement),
@@ -27,11 +27,19 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::is_math_domain_function;
+use common_functions::scalars::FunctionFactory;
+use common_functions::windows::RankingFunction;
+use common_planners::lit;
 use common_planners::Expression;
 use sqlparser::ast::Expr;
+use sqlparser::ast::Function;
 use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ListAgg;
+use sqlparser::ast::ListAggOnOverflow;
 use sqlparser::ast::Query;
+use sqlparser::ast::TrimWhereField;
 use sqlparser::ast::UnaryOperator;
 use sqlparser::ast::Value;
 
@@ -63,11 +71,15 @@ impl ExpressionAnalyzer {
                 ExprRPNItem::Identifier(v) => self.analyze_identifier(v, &mut stack)?,
                 ExprRPNItem::QualifiedIdentifier(v) => self.analyze_identifiers(v, &mut stack)?,
                 ExprRPNItem::Function(v) => self.analyze_function(v, &mut stack)?,
+                ExprRPNItem::WindowFunction(v) => self.analyze_window_function(v, &mut stack)?,
                 ExprRPNItem::Wildcard => self.analyze_wildcard(&mut stack)?,
                 ExprRPNItem::Exists(v) => self.analyze_exists(v, &mut stack).await?,
                 ExprRPNItem::Subquery(v) => self.analyze_scalar_subquery(v, &mut stack).await?,
                 ExprRPNItem::Cast(v) => self.analyze_cast(v, &mut stack)?,
                 ExprRPNItem::Between(negated) => self.analyze_between(*negated, &mut stack)?,
+                ExprRPNItem::DistinctFrom(negated) => {
+                    self.analyze_distinct_from(*negated, &mut stack)?
+                }
                 ExprRPNItem::InList(v) => self.analyze_inlist(v, &mut stack)?,
             }
         }
@@ -150,6 +162,39 @@ impl ExpressionAnalyzer {
         Ok(())
     }
 
+    fn analyze_window_function(
+        &self,
+        info: &WindowFunctionExprInfo,
+        args: &mut Vec<Expression>,
+    ) -> Result<()> {
+        let size = info.args_count + info.partition_by_count + info.order_by.len();
+        if args.len() < size {
+            return Err(ErrorCode::LogicalError("It's a bug."));
+        }
+
+        let order_by = args
+            .split_off(args.len() - info.order_by.len())
+            .into_iter()
+            .zip(info.order_by.iter())
+            .map(|(expr, (asc, nulls_first))| Expression::Sort {
+                expr: Box::new(expr.clone()),
+                asc: *asc,
+                nulls_first: *nulls_first,
+                origin_expr: Box::new(expr),
+            })
+            .collect();
+        let partition_by = args.split_off(args.len() - info.partition_by_count);
+        let arguments = args.split_off(args.len() - info.args_count);
+
+        args.push(Expression::WindowFunction {
+            op: info.name.clone(),
+            args: arguments,
+            partition_by,
+            order_by,
+        });
+        Ok(())
+    }
+
     fn unary_function(info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         match args.is_empty() {
             true => Err(ErrorCode::LogicalError("Unary operator must be one child.")),
@@ -182,7 +227,20 @@ impl ExpressionAnalyzer {
         match context_args.is_empty() {
             true => {
                 let op = info.name.clone();
-                let arguments = args.to_owned();
+                let mut arguments = args.to_owned();
+
+                // Out-of-domain math functions follow the session's `math_domain_error`.
+                if is_math_domain_function(&op) {
+                    let mode = self.context.get_settings().get_math_domain_error()?;
+                    arguments.push(Expression::create_literal(DataValue::String(
+                        mode.to_string().into_bytes(),
+                    )));
+                }
+
+                if op.eq_ignore_ascii_case("python_udf") {
+                    Self::python_udf_return_type(&mut arguments)?;
+                }
+
                 Ok(Expression::ScalarFunction {
                     op,
                     args: arguments,
@@ -198,6 +256,26 @@ impl ExpressionAnalyzer {
         }
     }
 
+    // The return type of python UDF is given as the type name, it's replaced by a NULL literal of
+    // the type, see `PythonUdfFunction`.
+    fn python_udf_return_type(arguments: &mut [Expression]) -> Result<()> {
+        if let Some(Expression::Literal {
+            value: DataValue::String(type_name),
+            ..
+        }) = arguments.get(2)
+        {
+            let sql_type =
+                UDFParser::default().parse_data_type(&String::from_utf8(type_name.clone())?)?;
+            let data_type = SQLCommon::make_data_type(&sql_type)?;
+            arguments[2] = Expression::Literal {
+                value: DataValue::Null,
+                column_name: None,
+                data_type: wrap_nullable(&data_type),
+            };
+        }
+        Ok(())
+    }
+
     fn aggr_function(&self, info: &FunctionExprInfo, args: &[Expression]) -> Result<Expression> {
         let mut parameters = Vec::with_capacity(info.parameters.len());
 
@@ -215,6 +293,30 @@ impl ExpressionAnalyzer {
             };
         }
 
+        // PERCENTILE(expr, level) is the same as percentile(level)(expr).
+        if info.name.eq_ignore_ascii_case("percentile") && parameters.is_empty() && args.len() == 2
+        {
+            return match &args[1] {
+                Expression::Literal { value, .. } => Ok(Expression::AggregateFunction {
+                    op: info.name.clone(),
+                    distinct: info.distinct,
+                    args: vec![args[0].clone()],
+                    params: vec![value.clone()],
+                }),
+                _ => Err(ErrorCode::SyntaxException(format!(
+                    "The level of {} must be a constant, but got {:?}",
+                    info.name, args[1]
+                ))),
+            };
+        }
+
+        if (info.name.eq_ignore_ascii_case("group_concat")
+            || info.name.eq_ignore_ascii_case("string_agg"))
+            && parameters.is_empty()
+        {
+            return self.string_agg_function(info, args);
+        }
+
         if info.name.eq_ignore_ascii_case("count")
             && !args.is_empty()
             && matches!(args[0], Expression::Wildcard)
@@ -235,6 +337,50 @@ impl ExpressionAnalyzer {
         }
     }
 
+    // GROUP_CONCAT(expr [, separator]) and STRING_AGG(expr, separator) are LISTAGG without the
+    // WITHIN GROUP and ON OVERFLOW clauses, the separator of GROUP_CONCAT is ',' by default.
+    fn string_agg_function(
+        &self,
+        info: &FunctionExprInfo,
+        args: &[Expression],
+    ) -> Result<Expression> {
+        let separator = match args {
+            [_] if info.name.eq_ignore_ascii_case("group_concat") => {
+                DataValue::String(b",".to_vec())
+            }
+            [_, Expression::Literal {
+                value: value @ DataValue::String(_),
+                ..
+            }] => value.clone(),
+            [_, other] => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The separator of {} must be a constant string, but got: {:?}",
+                    info.name, other
+                )));
+            }
+            _ => {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "{} expect to have the value and the separator arguments, but got {}",
+                    info.name,
+                    args.len()
+                )));
+            }
+        };
+
+        let max_length = self.context.get_settings().get_max_listagg_length()?;
+        Ok(Expression::AggregateFunction {
+            op: info.name.clone(),
+            distinct: info.distinct,
+            args: vec![args[0].clone()],
+            params: vec![
+                separator,
+                DataValue::UInt64(max_length),
+                DataValue::Null,
+                DataValue::Boolean(false),
+            ],
+        })
+    }
+
     fn analyze_identifier(&self, ident: &Ident, arguments: &mut Vec<Expression>) -> Result<()> {
         let column_name = ident.clone().value;
         arguments.push(Expression::Column(column_name));
@@ -253,7 +399,17 @@ impl ExpressionAnalyzer {
     }
 
     async fn analyze_exists(&self, subquery: &Query, args: &mut Vec<Expression>) -> Result<()> {
-        let subquery = vec![self.analyze_subquery(subquery).await?];
+        // The [NOT] EXISTS ANDed in WHERE are semi joins, see `ExistsSubquery`. Elsewhere the
+        // subquery is evaluated once into a set, so it can't reference the outer columns.
+        let subquery = match self.analyze_subquery(subquery).await {
+            Ok(subquery) => vec![subquery],
+            Err(cause) if cause.code() == ErrorCode::UnknownColumnCode() => {
+                return Err(cause.add_message_back(
+                    " (while in EXISTS subquery, correlated subqueries are only supported in [NOT] EXISTS conditions ANDed in WHERE)",
+                ));
+            }
+            Err(cause) => return Err(cause),
+        };
         args.push(Expression::ScalarFunction {
             op: "EXISTS".to_lowercase(),
             args: subquery,
@@ -357,6 +513,70 @@ impl ExpressionAnalyzer {
 
         Ok(())
     }
+
+    // `a IS [NOT] DISTINCT FROM b` compares NULLs as equal values. A NULL literal operand
+    // is lowered to `isnull`/`isnotnull`, a non-NULL literal operand to
+    // `if(isnull(a), false, a = b)`, both of which can be used by the range filter.
+    fn analyze_distinct_from(&self, negated: bool, args: &mut Vec<Expression>) -> Result<()> {
+        if args.len() < 2 {
+            return Err(ErrorCode::SyntaxException(
+                "Distinct from must be a binary expression.",
+            ));
+        }
+
+        let s_args = args.split_off(args.len() - 2);
+        let left = s_args[0].clone();
+        let right = s_args[1].clone();
+
+        let is_null =
+            |expr: &Expression| Expression::create_scalar_function("isnull", vec![expr.clone()]);
+        let is_not_null =
+            |expr: &Expression| Expression::create_scalar_function("isnotnull", vec![expr.clone()]);
+        let compare = |left: &Expression, right: &Expression| match negated {
+            true => left.eq(right.clone()),
+            false => left.not_eq(right.clone()),
+        };
+
+        let expression = match (Self::null_literal(&left), Self::null_literal(&right)) {
+            (Some(true), Some(true)) => lit(negated),
+            (Some(true), _) if negated => is_null(&right),
+            (Some(true), _) => is_not_null(&right),
+            (_, Some(true)) if negated => is_null(&left),
+            (_, Some(true)) => is_not_null(&left),
+            (Some(false), _) => Expression::create_scalar_function("if", vec![
+                is_null(&right),
+                lit(!negated),
+                compare(&right, &left),
+            ]),
+            (_, Some(false)) => Expression::create_scalar_function("if", vec![
+                is_null(&left),
+                lit(!negated),
+                compare(&left, &right),
+            ]),
+            (None, None) => {
+                let nulls = match negated {
+                    true => is_null(&left).and(is_null(&right)),
+                    false => is_not_null(&left).or(is_not_null(&right)),
+                };
+                Expression::create_scalar_function("if", vec![
+                    is_null(&left).or(is_null(&right)),
+                    nulls,
+                    compare(&left, &right),
+                ])
+            }
+        };
+
+        args.push(expression);
+        Ok(())
+    }
+
+    // Returns whether the expression is a NULL literal, or None if it is not a literal.
+    fn null_literal(expr: &Expression) -> Option<bool> {
+        match expr {
+            Expression::Literal { value, .. } => Some(value.is_null()),
+            _ => None,
+        }
+    }
 }
 
 enum OperatorKind {
@@ -373,6 +593,14 @@ struct FunctionExprInfo {
     parameters: Vec<Value>,
 }
 
+struct WindowFunctionExprInfo {
+    name: String,
+    args_count: usize,
+    partition_by_count: usize,
+    // The direction and the null order of each sort key.
+    order_by: Vec<(bool, bool)>,
+}
+
 struct InListInfo {
     list_size: usize,
     negated: bool,
@@ -383,11 +611,13 @@ enum ExprRPNItem {
     Identifier(Ident),
     QualifiedIdentifier(Vec<Ident>),
     Function(FunctionExprInfo),
+    WindowFunction(WindowFunctionExprInfo),
     Wildcard,
     Exists(Box<Query>),
     Subquery(Box<Query>),
     Cast(DataTypePtr),
     Between(bool),
+    DistinctFrom(bool),
     InList(InListInfo),
 }
 
@@ -477,7 +707,18 @@ impl ExprRPNBuilder {
             Expr::Subquery(subquery) => {
                 self.rpn.push(ExprRPNItem::Subquery(subquery.clone()));
             }
+            Expr::Function(function) if function.over.is_some() => {
+                let info = Self::window_function(function)?;
+                self.rpn.push(ExprRPNItem::WindowFunction(info));
+            }
             Expr::Function(function) => {
+                if RankingFunction::check(&function.name.to_string()) {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Window function {} must have an OVER clause",
+                        function.name
+                    )));
+                }
+
                 self.rpn.push(ExprRPNItem::Function(FunctionExprInfo {
                     name: function.name.to_string(),
                     distinct: function.distinct,
@@ -519,9 +760,28 @@ impl ExprRPNBuilder {
                     }
                 }
             }
+            Expr::Trim { trim_where, .. } => match trim_where {
+                None => self
+                    .rpn
+                    .push(ExprRPNItem::function(String::from("trim"), 1)),
+                Some((trim_where, _)) => {
+                    let name = match trim_where {
+                        TrimWhereField::Leading => "trim_leading",
+                        TrimWhereField::Trailing => "trim_trailing",
+                        TrimWhereField::Both => "trim_both",
+                    };
+                    self.rpn.push(ExprRPNItem::function(name.to_string(), 2));
+                }
+            },
             Expr::Between { negated, .. } => {
                 self.rpn.push(ExprRPNItem::Between(*negated));
             }
+            Expr::IsDistinctFrom(_, _) => {
+                self.rpn.push(ExprRPNItem::DistinctFrom(false));
+            }
+            Expr::IsNotDistinctFrom(_, _) => {
+                self.rpn.push(ExprRPNItem::DistinctFrom(true));
+            }
             Expr::Tuple(exprs) => {
                 let len = exprs.len();
 
@@ -538,6 +798,10 @@ impl ExprRPNBuilder {
                 list_size: list.len(),
                 negated: *negated,
             })),
+            Expr::ListAgg(list_agg) => {
+                let info = self.listagg_function(list_agg)?;
+                self.rpn.push(ExprRPNItem::Function(info));
+            }
             _ => (),
         }
 
@@ -545,6 +809,117 @@ impl ExprRPNBuilder {
     }
 }
 
+impl ExprRPNBuilder {
+    // Only the ranking functions are supported over a window, the window is the whole partition.
+    fn window_function(function: &Function) -> Result<WindowFunctionExprInfo> {
+        let name = function.name.to_string();
+        if !RankingFunction::check(&name) {
+            return Err(match AggregateFunctionFactory::instance().check(&name) {
+                true => ErrorCode::UnImplement(format!(
+                    "Aggregate function {} OVER (...) is not yet implemented",
+                    name
+                )),
+                false => ErrorCode::SyntaxException(format!(
+                    "Function {} is not a window function",
+                    name
+                )),
+            });
+        }
+
+        if function.distinct || !function.params.is_empty() || !function.args.is_empty() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Window function {} expect to have no arguments",
+                name
+            )));
+        }
+
+        let window = function.over.as_ref().ok_or_else(|| {
+            ErrorCode::LogicalError(format!("Window function {} has no window", name))
+        })?;
+        if window.window_frame.is_some() {
+            return Err(ErrorCode::UnImplement(format!(
+                "The window frame of {} is not yet implemented",
+                name
+            )));
+        }
+
+        let order_by = window
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let asc = order_by.asc.unwrap_or(true);
+                (asc, order_by.nulls_first.unwrap_or(asc))
+            })
+            .collect();
+        Ok(WindowFunctionExprInfo {
+            name,
+            args_count: function.args.len(),
+            partition_by_count: window.partition_by.len(),
+            order_by,
+        })
+    }
+
+    // LISTAGG is planned as the aggregate function `listagg`, the sort keys follow the value in
+    // the arguments and the clauses are passed as the parameters.
+    fn listagg_function(&self, list_agg: &ListAgg) -> Result<FunctionExprInfo> {
+        let separator = match list_agg.separator.as_deref() {
+            None => Value::SingleQuotedString(String::new()),
+            Some(Expr::Value(value @ Value::SingleQuotedString(_))) => value.clone(),
+            Some(other) => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The separator of LISTAGG must be a constant string, but got: {}",
+                    other
+                )));
+            }
+        };
+
+        let max_length = self.context.get_settings().get_max_listagg_length()?;
+        let (filler, with_count) = match &list_agg.on_overflow {
+            None | Some(ListAggOnOverflow::Error) => (Value::Null, false),
+            Some(ListAggOnOverflow::Truncate { filler, with_count }) => {
+                let filler = match filler.as_deref() {
+                    None => Value::SingleQuotedString(String::from("...")),
+                    Some(Expr::Value(value @ Value::SingleQuotedString(_))) => value.clone(),
+                    Some(other) => {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "The filler of LISTAGG must be a constant string, but got: {}",
+                            other
+                        )));
+                    }
+                };
+                (filler, *with_count)
+            }
+        };
+
+        let mut parameters = vec![
+            separator,
+            Value::Number(max_length.to_string(), false),
+            filler,
+            Value::Boolean(with_count),
+        ];
+        for order_by in &list_agg.within_group {
+            if order_by.nulls_first.is_some() {
+                return Err(ErrorCode::SyntaxException(
+                    "NULLS FIRST and NULLS LAST are not supported in LISTAGG",
+                ));
+            }
+            let order = match order_by.asc {
+                Some(false) => "DESC",
+                _ => "ASC",
+            };
+            parameters.push(Value::SingleQuotedString(order.to_string()));
+        }
+
+        Ok(FunctionExprInfo {
+            name: String::from("listagg"),
+            distinct: list_agg.distinct,
+            args_count: 1 + list_agg.within_group.len(),
+            kind: OperatorKind::Other,
+            parameters,
+        })
+    }
+}
+
 #[async_trait]
 impl UDFFetcher for ExprRPNBuilder {
     async fn get_udf_definition(&self, name: &str) -> Result<UDFDefinition> {
@@ -555,11 +930,53 @@ impl UDFFetcher for ExprRPNBuilder {
             .get_udf(&tenant, name)
             .await?;
         let mut udf_parser = UDFParser::default();
+        if let Some(handler) = &udf.python_handler {
+            // The python UDF calls the handler by `python_udf`, which returns the nullable type.
+            // The file, the function and the type are quoted as string literals.
+            let quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+            let definition = format!(
+                "python_udf({}, {}, {}{})",
+                quote(&handler.file),
+                quote(&handler.function),
+                quote(&udf.return_type.clone().unwrap_or_default()),
+                udf.parameters
+                    .iter()
+                    .map(|parameter| format!(", {}", parameter))
+                    .collect::<String>()
+            );
+            let definition = udf_parser
+                .parse(&udf.name, &udf.parameters, &definition)
+                .await?;
+            let parameter_types = udf
+                .parameter_types
+                .iter()
+                .map(|data_type| udf_parser.parse_data_type(data_type))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(UDFDefinition {
+                parameters: udf.parameters,
+                expr: definition,
+                parameter_types,
+                return_type: None,
+            });
+        }
+
         let definition = udf_parser
             .parse(&udf.name, &udf.parameters, &udf.definition)
             .await?;
 
-        Ok(UDFDefinition::new(udf.parameters, definition))
+        let definition = UDFDefinition::new(udf.parameters, definition);
+        match &udf.return_type {
+            None => Ok(definition),
+            Some(return_type) => {
+                let parameter_types = udf
+                    .parameter_types
+                    .iter()
+                    .map(|data_type| udf_parser.parse_data_type(data_type))
+                    .collect::<Result<Vec<_>>>()?;
+                let return_type = udf_parser.parse_data_type(return_type)?;
+                Ok(definition.with_types(parameter_types, return_type))
+            }
+        }
     }
 }
 
@@ -567,9 +984,21 @@ impl UDFFetcher for ExprRPNBuilder {
 impl ExprVisitor for ExprRPNBuilder {
     async fn pre_visit(&mut self, expr: &Expr) -> Result<Expr> {
         if let Expr::Function(function) = expr {
-            if !is_builtin_function(&function.name.to_string()) {
+            let name = function.name.to_string();
+            if !is_builtin_function(&name) {
                 return UDFTransformer::transform_function(function, self).await;
             }
+
+            // The internal functions are only called by the rewritten UDFs, which aren't visited
+            // by `pre_visit` again.
+            if let Ok(features) = FunctionFactory::instance().get_features(&name) {
+                if features.is_internal {
+                    return Err(ErrorCode::UnknownFunction(format!(
+                        "Unsupported Function: {}",
+                        name
+                    )));
+                }
+            }
         }
 
         Ok(expr.clone())
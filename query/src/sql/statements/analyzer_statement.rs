@@ -25,6 +25,7 @@ use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 
 use crate::sessions::QueryContext;
+use crate::sql::statements::query::QuerySemiJoin;
 use crate::sql::DfStatement;
 
 #[allow(clippy::enum_variant_names)]
@@ -44,7 +45,10 @@ pub enum QueryRelation {
 #[derive(Clone)]
 pub struct QueryAnalyzeState {
     pub filter: Option<Expression>,
+    // The [NOT] EXISTS subqueries of the filter, evaluated after it.
+    pub semi_joins: Vec<QuerySemiJoin>,
     pub having: Option<Expression>,
+    pub qualify: Option<Expression>,
     pub order_by_expressions: Vec<Expression>,
     // before order or before projection expression plan
     pub expressions: Vec<Expression>,
@@ -54,8 +58,17 @@ pub struct QueryAnalyzeState {
     pub aggregate_expressions: Vec<Expression>,
     pub before_group_by_expressions: Vec<Expression>,
 
+    // The window functions, evaluated one by one after the before window expressions.
+    pub window_expressions: Vec<Expression>,
+    pub before_window_expressions: Vec<Expression>,
+
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    // FETCH FIRST n ROWS WITH TIES, the limit keeps the rows which tie with the last one.
+    pub limit_with_ties: bool,
+
+    // Lateral unnest of the relation: (array column, element column).
+    pub unnest: Option<(String, String)>,
 
     pub relation: QueryRelation,
     pub finalize_schema: DataSchemaRef,
@@ -73,21 +86,33 @@ impl QueryAnalyzeState {
             self.before_group_by_expressions.push(expr.clone());
         }
     }
+
+    pub fn add_before_window_expression(&mut self, expr: &Expression) {
+        if !self.before_window_expressions.contains(expr) {
+            self.before_window_expressions.push(expr.clone());
+        }
+    }
 }
 
 impl Default for QueryAnalyzeState {
     fn default() -> Self {
         QueryAnalyzeState {
             filter: None,
+            semi_joins: vec![],
             having: None,
+            qualify: None,
             order_by_expressions: vec![],
             expressions: vec![],
             projection_expressions: vec![],
             group_by_expressions: vec![],
             aggregate_expressions: vec![],
             before_group_by_expressions: vec![],
+            window_expressions: vec![],
+            before_window_expressions: vec![],
             limit: None,
             offset: None,
+            limit_with_ties: false,
+            unnest: None,
             relation: QueryRelation::None,
             finalize_schema: Arc::new(DataSchema::empty()),
         }
@@ -98,10 +123,18 @@ impl Debug for QueryAnalyzeState {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut debug_struct = f.debug_struct("QueryAnalyzeState");
 
+        if let Some((array_column, element_column)) = &self.unnest {
+            debug_struct.field("unnest", &format!("{} as {}", array_column, element_column));
+        }
+
         if let Some(predicate) = &self.filter {
             debug_struct.field("filter", predicate);
         }
 
+        if !self.semi_joins.is_empty() {
+            debug_struct.field("semi_joins", &self.semi_joins);
+        }
+
         if !self.before_group_by_expressions.is_empty() {
             debug_struct.field("before_group_by", &self.before_group_by_expressions);
         }
@@ -114,6 +147,14 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
 
+        if !self.before_window_expressions.is_empty() {
+            debug_struct.field("before_window", &self.before_window_expressions);
+        }
+
+        if !self.window_expressions.is_empty() {
+            debug_struct.field("window", &self.window_expressions);
+        }
+
         if !self.expressions.is_empty() {
             match self.order_by_expressions.is_empty() {
                 true => debug_struct.field("before_projection", &self.expressions),
@@ -125,6 +166,10 @@ impl Debug for QueryAnalyzeState {
             debug_struct.field("having", predicate);
         }
 
+        if let Some(predicate) = &self.qualify {
+            debug_struct.field("qualify", predicate);
+        }
+
         if !self.order_by_expressions.is_empty() {
             debug_struct.field("order_by", &self.order_by_expressions);
         }
@@ -155,8 +200,12 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::CreateTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeTable(v) => v.analyze(ctx).await,
             DfStatement::DropTable(v) => v.analyze(ctx).await,
+            DfStatement::RenameTable(v) => v.analyze(ctx).await,
+            DfStatement::AlterTable(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
             DfStatement::OptimizeTable(v) => v.analyze(ctx).await,
+            DfStatement::CreateView(v) => v.analyze(ctx).await,
+            DfStatement::DropView(v) => v.analyze(ctx).await,
             DfStatement::UseDatabase(v) => v.analyze(ctx).await,
             DfStatement::UseTenant(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
@@ -167,6 +216,8 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
+            DfStatement::Update(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
@@ -174,7 +225,12 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::GrantPrivilege(v) => v.analyze(ctx).await,
             DfStatement::RevokePrivilege(v) => v.analyze(ctx).await,
             DfStatement::DropUser(v) => v.analyze(ctx).await,
+            DfStatement::CreateStage(v) => v.analyze(ctx).await,
+            DfStatement::DropStage(v) => v.analyze(ctx).await,
+            DfStatement::DescribeStage(v) => v.analyze(ctx).await,
+            DfStatement::ListStage(v) => v.analyze(ctx).await,
             DfStatement::Copy(v) => v.analyze(ctx).await,
+            DfStatement::CopyIntoStage(v) => v.analyze(ctx).await,
             DfStatement::ShowFunctions(v) => v.analyze(ctx).await,
             DfStatement::CreateUDF(v) => v.analyze(ctx).await,
             DfStatement::DropUDF(v) => v.analyze(ctx).await,
@@ -18,6 +18,7 @@ mod query_normalizer;
 mod query_qualified_rewriter;
 mod query_schema_joined;
 mod query_schema_joined_analyzer;
+mod query_semi_join;
 
 pub use query_ast_ir::QueryASTIR;
 pub use query_ast_ir::QueryASTIRVisitor;
@@ -28,3 +29,5 @@ pub use query_schema_joined::JoinedColumnDesc;
 pub use query_schema_joined::JoinedSchema;
 pub use query_schema_joined::JoinedTableDesc;
 pub use query_schema_joined_analyzer::JoinedSchemaAnalyzer;
+pub use query_semi_join::ExistsSubquery;
+pub use query_semi_join::QuerySemiJoin;
@@ -18,16 +18,21 @@ use std::fmt::Formatter;
 use common_exception::Result;
 use common_planners::Expression;
 
+use crate::sql::statements::query::QuerySemiJoin;
+
 // Intermediate representation for query AST(after normalize)
 pub struct QueryASTIR {
     pub filter_predicate: Option<Expression>,
+    pub semi_joins: Vec<QuerySemiJoin>,
     pub group_by_expressions: Vec<Expression>,
     pub having_predicate: Option<Expression>,
+    pub qualify_predicate: Option<Expression>,
     pub aggregate_expressions: Vec<Expression>,
     pub order_by_expressions: Vec<Expression>,
     pub projection_expressions: Vec<Expression>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    pub limit_with_ties: bool,
 }
 
 pub trait QueryASTIRVisitor<Data> {
@@ -36,10 +41,18 @@ pub trait QueryASTIRVisitor<Data> {
             Self::visit_filter(predicate, data)?;
         }
 
+        for semi_join in &mut ir.semi_joins {
+            Self::visit_semi_join(semi_join, data)?;
+        }
+
         if let Some(predicate) = &mut ir.having_predicate {
             Self::visit_having(predicate, data)?;
         }
 
+        if let Some(predicate) = &mut ir.qualify_predicate {
+            Self::visit_qualify(predicate, data)?;
+        }
+
         Self::visit_group_by(&mut ir.group_by_expressions, data)?;
         Self::visit_order_by(&mut ir.order_by_expressions, data)?;
         Self::visit_aggregates(&mut ir.aggregate_expressions, data)?;
@@ -73,6 +86,18 @@ pub trait QueryASTIRVisitor<Data> {
 
                 Ok(())
             }
+            Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                for arg in args.iter_mut().chain(partition_by).chain(order_by) {
+                    Self::visit_recursive_expr(arg, data)?;
+                }
+
+                Ok(())
+            }
             Expression::Sort {
                 expr, origin_expr, ..
             } => {
@@ -88,10 +113,22 @@ pub trait QueryASTIRVisitor<Data> {
         Self::visit_recursive_expr(predicate, data)
     }
 
+    fn visit_semi_join(semi_join: &mut QuerySemiJoin, data: &mut Data) -> Result<()> {
+        for key in &mut semi_join.keys {
+            Self::visit_recursive_expr(key, data)?;
+        }
+
+        Ok(())
+    }
+
     fn visit_having(predicate: &mut Expression, data: &mut Data) -> Result<()> {
         Self::visit_recursive_expr(predicate, data)
     }
 
+    fn visit_qualify(predicate: &mut Expression, data: &mut Data) -> Result<()> {
+        Self::visit_recursive_expr(predicate, data)
+    }
+
     fn visit_group_by(exprs: &mut Vec<Expression>, data: &mut Data) -> Result<()> {
         for expr in exprs {
             Self::visit_recursive_expr(expr, data)?;
@@ -133,6 +170,10 @@ impl Debug for QueryASTIR {
             debug_struct.field("filter", predicate);
         }
 
+        if !self.semi_joins.is_empty() {
+            debug_struct.field("semi joins", &self.semi_joins);
+        }
+
         if !self.group_by_expressions.is_empty() {
             debug_struct.field("group by", &self.group_by_expressions);
         }
@@ -141,6 +182,10 @@ impl Debug for QueryASTIR {
             debug_struct.field("having", predicate);
         }
 
+        if let Some(predicate) = &self.qualify_predicate {
+            debug_struct.field("qualify", predicate);
+        }
+
         if !self.aggregate_expressions.is_empty() {
             debug_struct.field("aggregate", &self.aggregate_expressions);
         }
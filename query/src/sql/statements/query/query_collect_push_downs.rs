@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 
 use common_exception::Result;
+use common_planners::find_window_exprs;
 use common_planners::Expression;
 use common_planners::Extras;
 
@@ -59,6 +60,17 @@ impl QueryCollectPushDowns {
     }
 
     fn collect_push_downs(mut self, ir: &QueryASTIR, schema: &mut JoinedSchema) -> Result<()> {
+        // The unnest changes the rows of the table, filters and limit cannot be pushed down.
+        let has_lateral_unnest = schema.get_lateral_unnest().is_some();
+        // The window functions and QUALIFY need all the rows, the limit cannot be pushed down.
+        // Neither can it with the semi joins, which drop rows after the source.
+        let has_window = ir.qualify_predicate.is_some()
+            || !find_window_exprs(&ir.projection_expressions).is_empty()
+            || !find_window_exprs(&ir.order_by_expressions).is_empty();
+        if let Some((array_column, _)) = schema.get_lateral_unnest() {
+            self.require_columns.insert(array_column.clone());
+        }
+
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
             let projection = self.collect_table_require_columns(table_desc);
@@ -66,16 +78,27 @@ impl QueryCollectPushDowns {
             let mut limit = None;
             let mut order_by = vec![];
             if schema.get_tables_desc().len() == 1
+                && !has_lateral_unnest
+                && !has_window
+                && ir.semi_joins.is_empty()
                 && ir.group_by_expressions.is_empty()
                 && ir.aggregate_expressions.is_empty()
             {
-                limit = ir.limit.map(|c| c + ir.offset.unwrap_or(0));
+                // The ties beyond the limit are unknown until the sort.
+                if !ir.limit_with_ties {
+                    limit = ir.limit.map(|c| c + ir.offset.unwrap_or(0));
+                }
                 order_by = ir.order_by_expressions.clone();
             }
 
+            let filters = match has_lateral_unnest {
+                true => vec![],
+                false => self.require_filters.clone(),
+            };
+
             schema.set_table_push_downs(index, Extras {
                 projection: Some(projection),
-                filters: self.require_filters.clone(),
+                filters,
                 limit,
                 order_by,
             });
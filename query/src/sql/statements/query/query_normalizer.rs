@@ -19,18 +19,24 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::extract_aliases;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::Expression;
 use sqlparser::ast::Expr;
-use sqlparser::ast::OffsetRows;
 use sqlparser::ast::SelectItem;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::ExistsSubquery;
 use crate::sql::statements::query::QueryASTIR;
+use crate::sql::statements::query::QuerySemiJoin;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::PlanParser;
 
 pub struct QueryNormalizer {
+    ctx: Arc<QueryContext>,
     query_ast_ir: QueryASTIR,
     expression_analyzer: ExpressionAnalyzer,
     aliases_map: HashMap<String, Expression>,
@@ -40,17 +46,21 @@ pub struct QueryNormalizer {
 impl QueryNormalizer {
     fn create(ctx: Arc<QueryContext>) -> QueryNormalizer {
         QueryNormalizer {
+            ctx: ctx.clone(),
             expression_analyzer: ExpressionAnalyzer::create(ctx),
             aliases_map: HashMap::new(),
             query_ast_ir: QueryASTIR {
                 filter_predicate: None,
+                semi_joins: vec![],
                 group_by_expressions: vec![],
                 having_predicate: None,
+                qualify_predicate: None,
                 aggregate_expressions: vec![],
                 order_by_expressions: vec![],
                 projection_expressions: vec![],
                 limit: None,
                 offset: None,
+                limit_with_ties: false,
             },
         }
     }
@@ -77,6 +87,10 @@ impl QueryNormalizer {
             return Err(cause.add_message_back(" (while in analyze select having)"));
         }
 
+        if let Err(cause) = self.analyze_qualify(query).await {
+            return Err(cause.add_message_back(" (while in analyze select qualify)"));
+        }
+
         if let Err(cause) = self.analyze_order_by(query).await {
             return Err(cause.add_message_back(" (while in analyze select order by)"));
         }
@@ -88,15 +102,59 @@ impl QueryNormalizer {
         Ok(self.query_ast_ir)
     }
 
+    // The [NOT] EXISTS subqueries ANDed in WHERE are taken out of the filter as semi joins.
     async fn visit_filter(&mut self, query: &DfQueryStatement) -> Result<()> {
-        if let Some(predicate) = &query.selection {
+        let (predicate, exists_subqueries) = ExistsSubquery::extract(query).await?;
+
+        if let Some(predicate) = &predicate {
             let analyzer = &self.expression_analyzer;
-            self.query_ast_ir.filter_predicate = Some(analyzer.analyze(predicate).await?);
+            let expression = analyzer.analyze(predicate).await?;
+
+            Self::check_no_window_function(&expression, "WHERE")?;
+            self.query_ast_ir.filter_predicate = Some(expression);
+        }
+
+        for exists_subquery in exists_subqueries {
+            let semi_join = self.analyze_semi_join(exists_subquery).await?;
+            self.query_ast_ir.semi_joins.push(semi_join);
         }
 
         Ok(())
     }
 
+    async fn analyze_semi_join(&self, exists_subquery: ExistsSubquery) -> Result<QuerySemiJoin> {
+        let mut keys = Vec::with_capacity(exists_subquery.keys.len());
+        for key in &exists_subquery.keys {
+            let expression = self.expression_analyzer.analyze(key).await?;
+
+            Self::check_no_window_function(&expression, "WHERE")?;
+            keys.push(expression);
+        }
+
+        let subquery_ctx = QueryContext::create_from(self.ctx.clone());
+        let subquery = match exists_subquery.subquery.analyze(subquery_ctx).await? {
+            AnalyzedResult::SelectQuery(state) => {
+                let query_plan = PlanParser::build_query_plan(&state)?;
+                Expression::Subquery {
+                    name: self.ctx.get_subquery_name(&query_plan),
+                    query_plan: Arc::new(query_plan),
+                }
+            }
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported subquery type {:?}",
+                    exists_subquery.subquery
+                )));
+            }
+        };
+
+        Ok(QuerySemiJoin {
+            anti: exists_subquery.anti,
+            keys,
+            subquery,
+        })
+    }
+
     async fn analyze_projection(&mut self, query: &DfQueryStatement) -> Result<()> {
         let projection_expressions = self.projection_exprs(query).await?;
         self.aliases_map = extract_aliases(&projection_expressions);
@@ -112,6 +170,8 @@ impl QueryNormalizer {
     async fn analyze_group_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for group_by_expr in &query.group_by {
             let expression = self.resolve_aliases(group_by_expr).await?;
+
+            Self::check_no_window_function(&expression, "GROUP BY")?;
             self.query_ast_ir.group_by_expressions.push(expression);
         }
 
@@ -122,12 +182,24 @@ impl QueryNormalizer {
         if let Some(predicate) = &query.having {
             let expression = self.resolve_aliases(predicate).await?;
 
+            Self::check_no_window_function(&expression, "HAVING")?;
             self.add_aggregate_function(&expression)?;
             self.query_ast_ir.having_predicate = Some(expression);
         }
         Ok(())
     }
 
+    // The window functions are evaluated after the aggregation, QUALIFY filters their results.
+    async fn analyze_qualify(&mut self, query: &DfQueryStatement) -> Result<()> {
+        if let Some(predicate) = &query.qualify {
+            let expression = self.resolve_aliases(predicate).await?;
+
+            self.add_aggregate_function(&expression)?;
+            self.query_ast_ir.qualify_predicate = Some(expression);
+        }
+        Ok(())
+    }
+
     async fn analyze_order_by(&mut self, query: &DfQueryStatement) -> Result<()> {
         for order_by_expr in &query.order_by {
             let expression = self.resolve_aliases(&order_by_expr.expr).await?;
@@ -148,36 +220,38 @@ impl QueryNormalizer {
 
     async fn analyze_limit(&mut self, query: &DfQueryStatement) -> Result<()> {
         if let Some(limit) = &query.limit {
-            let expression_analyzer = &self.expression_analyzer;
-            let limit_literal = match expression_analyzer.analyze(limit).await? {
-                Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
-                _ => Err(ErrorCode::SyntaxException(format!(
-                    "Unexpected expression for LIMIT clause: {:?}",
-                    limit
-                ))),
-            }?;
-            self.query_ast_ir.limit = Some(limit_literal);
+            self.query_ast_ir.limit = Some(self.analyze_limit_literal(limit, "LIMIT").await?);
         }
 
+        // OFFSET m ROW|ROWS is the same as OFFSET m.
         if let Some(offset) = &query.offset {
-            if !matches!(offset.rows, OffsetRows::None) {
-                return Err(ErrorCode::SyntaxException("Unimplemented limit n ROW|ROWS"));
-            }
-
-            let expression_analyzer = &self.expression_analyzer;
-            let offset_literal = match expression_analyzer.analyze(&offset.value).await? {
-                Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
-                _ => Err(ErrorCode::SyntaxException(format!(
-                    "Unexpected expression for LIMIT clause: {:?}",
-                    offset
-                ))),
-            }?;
+            let offset_literal = self.analyze_limit_literal(&offset.value, "OFFSET").await?;
             self.query_ast_ir.offset = Some(offset_literal);
         }
 
+        // FETCH FIRST n ROWS ONLY is the same as LIMIT n, FETCH FIRST ROW ONLY fetches one row.
+        if let Some(fetch) = &query.fetch {
+            let fetch_literal = match &fetch.quantity {
+                None => 1,
+                Some(quantity) => self.analyze_limit_literal(quantity, "FETCH").await?,
+            };
+            self.query_ast_ir.limit = Some(fetch_literal);
+            self.query_ast_ir.limit_with_ties = fetch.with_ties;
+        }
+
         Ok(())
     }
 
+    async fn analyze_limit_literal(&self, expr: &Expr, clause: &str) -> Result<usize> {
+        match self.expression_analyzer.analyze(expr).await? {
+            Expression::Literal { value, .. } => Ok(value.as_u64()? as usize),
+            _ => Err(ErrorCode::SyntaxException(format!(
+                "Unexpected expression for {} clause: {:?}",
+                clause, expr
+            ))),
+        }
+    }
+
     async fn projection_exprs(&self, query: &DfQueryStatement) -> Result<Vec<Expression>> {
         let mut output_columns = Vec::with_capacity(query.projection.len());
 
@@ -213,8 +287,19 @@ impl QueryNormalizer {
         resolve_aliases_to_exprs(&expression_analyzer.analyze(expr).await?, aliases_map)
     }
 
+    fn check_no_window_function(expr: &Expression, clause: &str) -> Result<()> {
+        match find_window_exprs_in_expr(expr).first() {
+            None => Ok(()),
+            Some(window_expr) => Err(ErrorCode::SyntaxException(format!(
+                "Window function {:?} is not allowed in {}",
+                window_expr, clause
+            ))),
+        }
+    }
+
     fn add_aggregate_function(&mut self, expr: &Expression) -> Result<()> {
         for aggregate_expr in find_aggregate_exprs_in_expr(expr) {
+            Self::check_no_window_function(&aggregate_expr, "aggregate functions")?;
             if !self
                 .query_ast_ir
                 .aggregate_expressions
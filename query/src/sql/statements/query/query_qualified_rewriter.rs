@@ -95,6 +95,10 @@ impl QualifiedRewriter {
                 }
             }
         }
+
+        if let Some((_, column_desc)) = self.tables_schema.get_lateral_unnest() {
+            columns_expression.push(Expression::Column(column_desc.short_name.clone()));
+        }
     }
 
     fn rewrite_column(&self, name: &str) -> Result<Expression> {
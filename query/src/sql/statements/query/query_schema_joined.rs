@@ -32,6 +32,8 @@ pub struct JoinedSchema {
     short_name_columns: HashMap<String, JoinedColumnDesc>,
     // Reference by full name, short name may be ambiguous.
     tables_long_name_columns: Vec<JoinedTableDesc>,
+    // Lateral unnest of the tables: (array column, element column).
+    lateral_unnest: Option<(String, JoinedColumnDesc)>,
 }
 
 impl JoinedSchema {
@@ -39,6 +41,7 @@ impl JoinedSchema {
         JoinedSchema {
             short_name_columns: HashMap::new(),
             tables_long_name_columns: Vec::new(),
+            lateral_unnest: None,
         }
     }
 
@@ -75,9 +78,48 @@ impl JoinedSchema {
         Ok(JoinedSchema {
             short_name_columns,
             tables_long_name_columns: vec![table_desc],
+            lateral_unnest: None,
         })
     }
 
+    pub fn lateral_unnest(
+        mut self,
+        array_column: &str,
+        element_column: JoinedColumnDesc,
+    ) -> Result<JoinedSchema> {
+        if self.lateral_unnest.is_some() {
+            return Err(ErrorCode::UnImplement(
+                "Only one UNNEST is supported in FROM clause",
+            ));
+        }
+
+        match self
+            .short_name_columns
+            .entry(element_column.short_name.clone())
+        {
+            Entry::Vacant(v) => {
+                v.insert(element_column.clone());
+            }
+            Entry::Occupied(_) => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "UNNEST column name {} is ambiguous, please use an alias",
+                    element_column.short_name
+                )));
+            }
+        };
+
+        self.lateral_unnest = Some((array_column.to_string(), element_column));
+        Ok(self)
+    }
+
+    pub fn get_lateral_unnest(&self) -> &Option<(String, JoinedColumnDesc)> {
+        &self.lateral_unnest
+    }
+
+    pub fn get_column_desc(&self, column_name: &str) -> Option<&JoinedColumnDesc> {
+        self.short_name_columns.get(column_name)
+    }
+
     pub fn contains_column(&self, column_name: &str) -> bool {
         self.short_name_columns.contains_key(column_name)
     }
@@ -118,6 +160,13 @@ impl JoinedSchema {
             }
         }
 
+        if let Some((_, column_desc)) = &self.lateral_unnest {
+            fields.push(DataField::new(
+                &column_desc.short_name,
+                column_desc.data_type.clone(),
+            ));
+        }
+
         Arc::new(DataSchema::new(fields))
     }
 
@@ -145,6 +194,10 @@ impl Debug for JoinedSchema {
             }
         }
 
+        if let Some((_, column_desc)) = &self.lateral_unnest {
+            short_names.push(column_desc.short_name.clone());
+        }
+
         let mut debug_struct = f.debug_struct("QuerySchema");
         if !short_names.is_empty() {
             debug_struct.field("short_names", &short_names);
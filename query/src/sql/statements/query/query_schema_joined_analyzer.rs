@@ -12,26 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::aggregate_types;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::Expression;
+use sqlparser::ast::Cte;
+use sqlparser::ast::Expr;
 use sqlparser::ast::FunctionArg;
+use sqlparser::ast::FunctionArgExpr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
 use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Values;
 
 use crate::catalogs::Catalog;
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
+use crate::sql::statements::query::JoinedColumnDesc;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::storages::view::ViewTable;
+use crate::storages::view::VIEW_ENGINE;
+use crate::storages::Table;
+use crate::table_functions::VALUES_FUNC;
+
+const PUBLIC_SYSTEM_TABLES: [&str; 10] = [
+    "one",
+    "databases",
+    "tables",
+    "columns",
+    "settings",
+    "functions",
+    "engines",
+    "metrics",
+    "contributors",
+    "credits",
+];
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -44,14 +77,31 @@ impl JoinedSchemaAnalyzer {
 
     pub async fn analyze(&self, query: &DfQueryStatement) -> Result<JoinedSchema> {
         let mut analyzed_tables = Vec::new();
+        let mut lateral_unnest = None;
 
         // Build RPN for tables. because async function unsupported recursion
-        let rpn = RelationRPNBuilder::build(&query.from)?;
+        let rpn = RelationRPNBuilder::build(&query.from, &query.ctes)?;
         for rpn_item in &rpn {
             match rpn_item {
+                RelationRPNItem::Join(JoinOperator::CrossJoin) if lateral_unnest.is_some() => {
+                    // t CROSS JOIN UNNEST(t.arr), the UNNEST is lateral to the left side.
+                    let left = analyzed_tables.pop().ok_or_else(|| {
+                        ErrorCode::LogicalError("Logical error: this is relation rpn bug.")
+                    })?;
+                    let unnest = lateral_unnest.take().unwrap();
+                    analyzed_tables.push(self.unnest(left, unnest)?);
+                }
                 RelationRPNItem::Join(_) => {
                     return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
                 }
+                RelationRPNItem::Unnest(v) => {
+                    if analyzed_tables.is_empty() || lateral_unnest.is_some() {
+                        return Err(ErrorCode::SyntaxException(
+                            "UNNEST in FROM clause must be cross joined with a table, e.g. FROM t CROSS JOIN UNNEST(t.arr)",
+                        ));
+                    }
+                    lateral_unnest = Some(v);
+                }
                 RelationRPNItem::Table(v) => {
                     let schema = self.table(v);
                     analyzed_tables.push(schema.await?);
@@ -64,6 +114,10 @@ impl JoinedSchemaAnalyzer {
                     let schema = self.subquery(v);
                     analyzed_tables.push(schema.await?);
                 }
+                RelationRPNItem::Values(v) => {
+                    let schema = self.values(v);
+                    analyzed_tables.push(schema.await?);
+                }
             }
         }
 
@@ -76,9 +130,58 @@ impl JoinedSchemaAnalyzer {
         Ok(analyzed_tables.remove(0))
     }
 
+    fn unnest(&self, left: JoinedSchema, item: &UnnestRPNItem) -> Result<JoinedSchema> {
+        let array_column = match item.args.as_slice() {
+            [FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(ident)))] => {
+                ident.value.clone()
+            }
+            [FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::CompoundIdentifier(idents)))] => {
+                idents[idents.len() - 1].value.clone()
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "UNNEST in FROM clause expects exactly one array column argument",
+                ))
+            }
+        };
+
+        let array_type = match left.get_column_desc(&array_column) {
+            None => {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Unknown column {}",
+                    array_column
+                )))
+            }
+            Some(column_desc) => remove_nullable(&column_desc.data_type),
+        };
+
+        let inner_type = match array_type.as_any().downcast_ref::<ArrayType>() {
+            Some(array_type) => array_type.inner_type().clone(),
+            None => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "UNNEST argument must be Array type, but got {:?}",
+                    array_type
+                )))
+            }
+        };
+
+        // UNNEST(arr) AS u(x) or UNNEST(arr) AS x
+        let element_column = match &item.alias {
+            None => "unnest".to_string(),
+            Some(alias) if alias.columns.is_empty() => alias.name.value.clone(),
+            Some(alias) => alias.columns[0].value.clone(),
+        };
+
+        let nullable = inner_type.is_nullable();
+        let element_desc = JoinedColumnDesc::create(&element_column, inner_type, nullable);
+        left.lateral_unnest(&array_column, element_desc)
+    }
+
     async fn subquery(&self, v: &DerivedRPNItem) -> Result<JoinedSchema> {
         let subquery = &(*v.subquery);
-        let subquery = DfQueryStatement::try_from(subquery.clone())?;
+        let mut subquery = DfQueryStatement::try_from(subquery.clone())?;
+        // The CTEs of the outer queries are visible to the subquery, unless shadowed by its own.
+        subquery.ctes = v.ctes.iter().chain(subquery.ctes.iter()).cloned().collect();
         match subquery.analyze(self.ctx.clone()).await? {
             AnalyzedResult::SelectQuery(state) => match &v.alias {
                 None => JoinedSchema::from_subquery(state, Vec::new()),
@@ -93,11 +196,132 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    // The VALUES list is evaluated into rows of constants, and read by the `values` table function.
+    async fn values(&self, item: &ValuesRPNItem) -> Result<JoinedSchema> {
+        let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+        let mut rows = Vec::with_capacity(item.values.0.len());
+        for row in &item.values.0 {
+            let mut exprs = Vec::with_capacity(row.len());
+            for value in row {
+                exprs.push(analyzer.analyze(value).await?);
+            }
+            rows.push(exprs);
+        }
+
+        let columns_len = rows.first().map(|row| row.len()).unwrap_or(0);
+        if rows.iter().any(|row| row.len() != columns_len) {
+            return Err(ErrorCode::SyntaxException(
+                "All rows of VALUES list must have the same number of columns",
+            ));
+        }
+
+        // (VALUES ...) AS t(a, b) or the default column1, column2, ...
+        let names = match &item.alias {
+            Some(alias) if !alias.columns.is_empty() => {
+                if alias.columns.len() != columns_len {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "VALUES list has {} columns, but {} column aliases are specified",
+                        columns_len,
+                        alias.columns.len()
+                    )));
+                }
+
+                let mut unique_names = HashSet::with_capacity(columns_len);
+                for column in &alias.columns {
+                    if !unique_names.insert(column.value.as_str()) {
+                        return Err(ErrorCode::SyntaxException(format!(
+                            "Duplicate column alias {} of VALUES list",
+                            column.value
+                        )));
+                    }
+                }
+
+                alias.columns.iter().map(|v| v.value.clone()).collect()
+            }
+            _ => (1..=columns_len)
+                .map(|index| format!("column{}", index))
+                .collect::<Vec<_>>(),
+        };
+
+        // The type of each column is the common type of the values in all rows, e.g. Int + NULL.
+        let dummy = DataSchemaRefExt::create(vec![DataField::new("dummy", u8::to_data_type())]);
+        let mut types = Vec::with_capacity(columns_len);
+        for index in 0..columns_len {
+            let column_types = rows
+                .iter()
+                .map(|row| row[index].to_data_type(&dummy))
+                .collect::<Result<Vec<_>>>()?;
+            types.push(aggregate_types(&column_types)?);
+        }
+
+        let fields = names
+            .iter()
+            .zip(types.iter())
+            .map(|(name, data_type)| DataField::new(name, data_type.clone()))
+            .collect::<Vec<_>>();
+        let schema = DataSchemaRefExt::create(fields);
+        let row_type: DataTypePtr = Arc::new(StructType::create(names.clone(), types.clone()));
+
+        let one_row_block = DataBlock::create(dummy.clone(), vec![Series::from_data(vec![1u8])]);
+        let mut table_args = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut exprs = Vec::with_capacity(columns_len);
+            for (index, expr) in row.into_iter().enumerate() {
+                let expr = match expr.to_data_type(&dummy)? == types[index] {
+                    true => expr,
+                    false => Expression::Cast {
+                        expr: Box::new(expr),
+                        data_type: types[index].clone(),
+                        is_nullable: false,
+                    },
+                };
+                exprs.push(Expression::Alias(names[index].clone(), Box::new(expr)));
+            }
+
+            let executor = ExpressionExecutor::try_create(
+                "VALUES list",
+                dummy.clone(),
+                schema.clone(),
+                exprs,
+                true,
+            )?;
+            let block = executor.execute(&one_row_block)?;
+            let values = block.columns().iter().map(|column| column.get(0)).collect();
+            table_args.push(Expression::Literal {
+                value: DataValue::Struct(values),
+                column_name: None,
+                data_type: row_type.clone(),
+            });
+        }
+
+        let catalog = self.ctx.get_catalog();
+        let table_function = catalog.get_table_function(VALUES_FUNC, Some(table_args))?;
+        match &item.alias {
+            None => JoinedSchema::from_table(table_function.as_table(), Vec::new()),
+            Some(table_alias) => {
+                let name_prefix = vec![table_alias.name.value.clone()];
+                JoinedSchema::from_table(table_function.as_table(), name_prefix)
+            }
+        }
+    }
+
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
+        self.validate_select_privilege(&database, &table).await?;
         let read_table = self.ctx.get_table(&database, &table).await?;
 
+        if read_table.engine() == VIEW_ENGINE {
+            let name_prefix = match &item.alias {
+                None => vec![database.clone(), table.clone()],
+                Some(table_alias) => vec![table_alias.name.value.clone()],
+            };
+            let schema = self.view(read_table.as_ref(), name_prefix).await;
+            return schema.map_err(|cause| {
+                cause.add_message_back(format!(" (while in view '{}.{}')", database, table))
+            });
+        }
+
         match &item.alias {
             None => {
                 let name_prefix = vec![database, table];
@@ -110,6 +334,48 @@ impl JoinedSchemaAnalyzer {
         }
     }
 
+    // The system tables backing `SELECT` without FROM and the SHOW statements are readable by
+    // everyone, the others, e.g. `users` and `query_log`, expose the data of the other users.
+    async fn validate_select_privilege(&self, database: &str, table: &str) -> Result<()> {
+        let is_public = |name: &&str| name.eq_ignore_ascii_case(table);
+        if database.eq_ignore_ascii_case("system") && PUBLIC_SYSTEM_TABLES.iter().any(is_public) {
+            return Ok(());
+        }
+
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(database.to_string(), table.to_string()),
+                UserPrivilegeType::Select,
+            )
+            .await
+    }
+
+    // The view is expanded into its query, just like a subquery in FROM clause.
+    async fn view(&self, view: &dyn Table, name_prefix: Vec<String>) -> Result<JoinedSchema> {
+        let view = view.as_any().downcast_ref::<ViewTable>().ok_or_else(|| {
+            ErrorCode::LogicalError("Logical error, the table must be a view, it's a bug.")
+        })?;
+
+        let (mut statements, _) = DfParser::parse_sql(view.query())?;
+        let subquery = match statements.pop() {
+            Some(DfStatement::Query(subquery)) if statements.is_empty() => subquery,
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "The query of view must be a SELECT statement, but got: {}",
+                    view.query()
+                )));
+            }
+        };
+
+        match subquery.analyze(self.ctx.clone()).await? {
+            AnalyzedResult::SelectQuery(state) => JoinedSchema::from_subquery(state, name_prefix),
+            _ => Err(ErrorCode::LogicalError(
+                "Logical error, subquery analyzed data must be SelectQuery, it's a bug.",
+            )),
+        }
+    }
+
     async fn table_function(&self, item: &TableFunctionRPNItem) -> Result<JoinedSchema> {
         if item.name.0.len() >= 2 {
             return Result::Err(ErrorCode::BadArguments(
@@ -123,7 +389,11 @@ impl JoinedSchemaAnalyzer {
 
         for table_arg in &item.args {
             table_args.push(match table_arg {
-                FunctionArg::Named { arg, .. } => analyzer.analyze_function_arg(arg).await?,
+                // Keep the name of the argument, e.g. FLATTEN(input => '[1, 2]')
+                FunctionArg::Named { name, arg } => Expression::Alias(
+                    name.value.clone(),
+                    Box::new(analyzer.analyze_function_arg(arg).await?),
+                ),
                 FunctionArg::Unnamed(arg) => analyzer.analyze_function_arg(arg).await?,
             });
         }
@@ -159,6 +429,17 @@ struct TableRPNItem {
 struct DerivedRPNItem {
     subquery: Box<Query>,
     alias: Option<TableAlias>,
+    ctes: Vec<Cte>,
+}
+
+struct ValuesRPNItem {
+    values: Values,
+    alias: Option<TableAlias>,
+}
+
+struct UnnestRPNItem {
+    args: Vec<FunctionArg>,
+    alias: Option<TableAlias>,
 }
 
 struct TableFunctionRPNItem {
@@ -170,17 +451,23 @@ struct TableFunctionRPNItem {
 enum RelationRPNItem {
     Table(TableRPNItem),
     TableFunction(TableFunctionRPNItem),
+    Unnest(UnnestRPNItem),
     Derived(DerivedRPNItem),
+    Values(ValuesRPNItem),
     Join(JoinOperator),
 }
 
-struct RelationRPNBuilder {
+struct RelationRPNBuilder<'a> {
+    ctes: &'a [Cte],
     rpn: Vec<RelationRPNItem>,
 }
 
-impl RelationRPNBuilder {
-    pub fn build(exprs: &[TableWithJoins]) -> Result<Vec<RelationRPNItem>> {
-        let mut builder = RelationRPNBuilder { rpn: Vec::new() };
+impl<'a> RelationRPNBuilder<'a> {
+    pub fn build(exprs: &[TableWithJoins], ctes: &'a [Cte]) -> Result<Vec<RelationRPNItem>> {
+        let mut builder = RelationRPNBuilder {
+            ctes,
+            rpn: Vec::new(),
+        };
         match exprs.is_empty() {
             true => builder.visit_dummy_table(),
             false => builder.visit(exprs)?,
@@ -241,6 +528,7 @@ impl RelationRPNBuilder {
 
                 match args.is_empty() {
                     true => self.visit_table(name, alias),
+                    false if Self::is_unnest(name) => self.visit_unnest(args, alias),
                     false => self.visit_table_function(name, args, alias),
                 }
             }
@@ -253,9 +541,18 @@ impl RelationRPNBuilder {
                     return Err(ErrorCode::UnImplement("Cannot SELECT LATERAL subquery."));
                 }
 
+                if let SetExpr::Values(values) = &subquery.body {
+                    self.rpn.push(RelationRPNItem::Values(ValuesRPNItem {
+                        values: values.clone(),
+                        alias: alias.clone(),
+                    }));
+                    return Ok(());
+                }
+
                 self.rpn.push(RelationRPNItem::Derived(DerivedRPNItem {
                     subquery: subquery.clone(),
                     alias: alias.clone(),
+                    ctes: self.ctes.to_vec(),
                 }));
                 Ok(())
             }
@@ -266,7 +563,23 @@ impl RelationRPNBuilder {
         }
     }
 
+    fn is_unnest(name: &ObjectName) -> bool {
+        name.0.len() == 1 && name.0[0].value.to_lowercase() == "unnest"
+    }
+
+    fn visit_unnest(&mut self, args: &[FunctionArg], alias: &Option<TableAlias>) -> Result<()> {
+        self.rpn.push(RelationRPNItem::Unnest(UnnestRPNItem {
+            args: args.to_owned(),
+            alias: alias.clone(),
+        }));
+        Ok(())
+    }
+
     fn visit_table(&mut self, name: &ObjectName, alias: &Option<TableAlias>) -> Result<()> {
+        if let Some(index) = self.find_cte(name) {
+            return self.visit_cte(index, alias);
+        }
+
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: name.clone(),
             alias: alias.clone(),
@@ -274,6 +587,36 @@ impl RelationRPNBuilder {
         Ok(())
     }
 
+    // The unqualified table name may refer to a CTE, the innermost one shadows the others and tables.
+    fn find_cte(&self, name: &ObjectName) -> Option<usize> {
+        match name.0.len() {
+            1 => self
+                .ctes
+                .iter()
+                .rposition(|cte| cte.alias.name.value == name.0[0].value),
+            _ => None,
+        }
+    }
+
+    // The CTE is inlined as a derived table, only the CTEs defined before it are visible to its query.
+    fn visit_cte(&mut self, index: usize, alias: &Option<TableAlias>) -> Result<()> {
+        let cte = &self.ctes[index];
+        let alias = match alias {
+            Some(alias) => alias.clone(),
+            None => TableAlias {
+                name: cte.alias.name.clone(),
+                columns: vec![],
+            },
+        };
+
+        self.rpn.push(RelationRPNItem::Derived(DerivedRPNItem {
+            subquery: Box::new(cte.query.clone()),
+            alias: Some(alias),
+            ctes: self.ctes[..index].to_vec(),
+        }));
+        Ok(())
+    }
+
     fn visit_table_function(
         &mut self,
         name: &ObjectName,
@@ -0,0 +1,313 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use async_trait::async_trait;
+use common_ast::parser::expr::ExprTraverser;
+use common_ast::parser::expr::ExprVisitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_planners::Expression;
+use sqlparser::ast::BinaryOperator;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Query;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::UnaryOperator;
+
+use crate::sql::statements::DfQueryStatement;
+
+/// A `[NOT] EXISTS` subquery ANDed in the WHERE clause, evaluated as a semi join (anti join).
+/// The keys are compared with the first columns of the subquery, there is no key if the
+/// subquery is not correlated.
+#[derive(Clone, Debug)]
+pub struct QuerySemiJoin {
+    pub anti: bool,
+    pub keys: Vec<Expression>,
+    pub subquery: Expression,
+}
+
+/// A `[NOT] EXISTS` subquery taken out of the WHERE clause. The equalities between the outer
+/// and the inner columns are taken out of the subquery: the outer sides are the keys, the inner
+/// sides are the projection of the subquery.
+pub struct ExistsSubquery {
+    pub anti: bool,
+    pub keys: Vec<Expr>,
+    pub subquery: DfQueryStatement,
+}
+
+impl ExistsSubquery {
+    /// Split the WHERE clause of the query into the remaining predicate and the EXISTS subqueries.
+    pub async fn extract(query: &DfQueryStatement) -> Result<(Option<Expr>, Vec<ExistsSubquery>)> {
+        let mut predicates = vec![];
+        let mut exists_subqueries = vec![];
+
+        if let Some(selection) = &query.selection {
+            let outer_tables = tables_name(&query.from);
+
+            for conjunct in split_conjuncts(selection) {
+                match exists_subquery(conjunct) {
+                    None => predicates.push(conjunct.clone()),
+                    Some((anti, subquery)) => {
+                        let subquery = DfQueryStatement::try_from(subquery.clone())?;
+                        let exists = Self::decorrelate(anti, subquery, &outer_tables).await?;
+                        exists_subqueries.push(exists);
+                    }
+                }
+            }
+        }
+
+        Ok((conjoin(predicates), exists_subqueries))
+    }
+
+    async fn decorrelate(
+        anti: bool,
+        mut subquery: DfQueryStatement,
+        outer_tables: &HashSet<String>,
+    ) -> Result<Self> {
+        // The tables of the subquery shadow the outer tables of the same name.
+        let inner_tables = tables_name(&subquery.from);
+        let outer_tables = outer_tables
+            .difference(&inner_tables)
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let mut keys = vec![];
+        let mut projection = vec![];
+        let mut predicates = vec![];
+        if let Some(selection) = &subquery.selection {
+            for conjunct in split_conjuncts(selection) {
+                let references = ColumnReferences::collect(conjunct).await?;
+                if !references.refer_to_any(&outer_tables) {
+                    predicates.push(conjunct.clone());
+                    continue;
+                }
+
+                match outer_equality(conjunct, &outer_tables).await? {
+                    Some((outer, inner)) => {
+                        keys.push(outer.clone());
+                        projection.push(SelectItem::UnnamedExpr(inner.clone()));
+                    }
+                    None => {
+                        return Err(ErrorCode::UnImplement(format!(
+                            "Correlated EXISTS subquery only supports the equalities between outer and inner columns ANDed in WHERE, but got: {}",
+                            conjunct
+                        )));
+                    }
+                }
+            }
+        }
+
+        if keys.is_empty() {
+            return Ok(ExistsSubquery {
+                anti,
+                keys,
+                subquery,
+            });
+        }
+
+        let unsupported_clauses = [
+            ("GROUP BY", !subquery.group_by.is_empty()),
+            ("HAVING", subquery.having.is_some()),
+            ("QUALIFY", subquery.qualify.is_some()),
+            ("LIMIT", subquery.limit.is_some()),
+            ("OFFSET", subquery.offset.is_some()),
+            ("FETCH", subquery.fetch.is_some()),
+        ];
+        for (clause, exists) in unsupported_clauses {
+            if exists {
+                return Err(ErrorCode::UnImplement(format!(
+                    "Correlated EXISTS subquery with {} is unimplemented yet",
+                    clause
+                )));
+            }
+        }
+
+        for item in &subquery.projection {
+            let references = match item {
+                SelectItem::UnnamedExpr(expr) => ColumnReferences::collect(expr).await?,
+                SelectItem::ExprWithAlias { expr, .. } => ColumnReferences::collect(expr).await?,
+                _ => continue,
+            };
+
+            if references.has_aggregate {
+                return Err(ErrorCode::UnImplement(
+                    "Correlated EXISTS subquery with aggregate functions is unimplemented yet",
+                ));
+            }
+        }
+
+        // The subquery returns the inner sides of the equalities, its order is meaningless.
+        subquery.projection = projection;
+        subquery.selection = conjoin(predicates);
+        subquery.order_by.clear();
+
+        Ok(ExistsSubquery {
+            anti,
+            keys,
+            subquery,
+        })
+    }
+}
+
+// The identifiers and the aggregate functions of an expression.
+#[derive(Default)]
+struct ColumnReferences {
+    qualifiers: Vec<String>,
+    has_unqualified: bool,
+    has_aggregate: bool,
+}
+
+impl ColumnReferences {
+    async fn collect(expr: &Expr) -> Result<ColumnReferences> {
+        let mut references = ColumnReferences::default();
+        ExprTraverser::accept(expr, &mut references).await?;
+        Ok(references)
+    }
+
+    fn refer_to_any(&self, tables: &HashSet<String>) -> bool {
+        self.qualifiers.iter().any(|name| tables.contains(name))
+    }
+
+    fn refer_only_to(&self, tables: &HashSet<String>) -> bool {
+        !self.has_unqualified
+            && !self.qualifiers.is_empty()
+            && self.qualifiers.iter().all(|name| tables.contains(name))
+    }
+}
+
+#[async_trait]
+impl ExprVisitor for ColumnReferences {
+    async fn post_visit(&mut self, expr: &Expr) -> Result<()> {
+        match expr {
+            Expr::Identifier(_) => self.has_unqualified = true,
+            Expr::CompoundIdentifier(idents) if idents.len() >= 2 => {
+                self.qualifiers.push(idents[idents.len() - 2].value.clone());
+            }
+            Expr::Function(function) if function.over.is_none() => {
+                let name = function.name.to_string();
+                self.has_aggregate |= AggregateFunctionFactory::instance().check(&name);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+// The outer and the inner sides of an equality, one side refers only to the outer tables, the
+// other one refers to none of them.
+async fn outer_equality<'a>(
+    expr: &'a Expr,
+    outer_tables: &HashSet<String>,
+) -> Result<Option<(&'a Expr, &'a Expr)>> {
+    let (left, right) = match unwrap_nested(expr) {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => (left.as_ref(), right.as_ref()),
+        _ => return Ok(None),
+    };
+
+    let left_references = ColumnReferences::collect(left).await?;
+    let right_references = ColumnReferences::collect(right).await?;
+    if left_references.refer_only_to(outer_tables) && !right_references.refer_to_any(outer_tables) {
+        return Ok(Some((left, right)));
+    }
+
+    if right_references.refer_only_to(outer_tables) && !left_references.refer_to_any(outer_tables) {
+        return Ok(Some((right, left)));
+    }
+
+    Ok(None)
+}
+
+// The subquery of `[NOT] EXISTS (subquery)`, and whether it is negated.
+fn exists_subquery(expr: &Expr) -> Option<(bool, &Query)> {
+    match unwrap_nested(expr) {
+        Expr::Exists(subquery) => Some((false, subquery.as_ref())),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr,
+        } => exists_subquery(expr).map(|(anti, subquery)| (!anti, subquery)),
+        _ => None,
+    }
+}
+
+fn unwrap_nested(mut expr: &Expr) -> &Expr {
+    while let Expr::Nested(nested) = expr {
+        expr = nested;
+    }
+    expr
+}
+
+fn split_conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match unwrap_nested(expr) {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjuncts(left);
+            conjuncts.extend(split_conjuncts(right));
+            conjuncts
+        }
+        _ => vec![expr],
+    }
+}
+
+fn conjoin(predicates: Vec<Expr>) -> Option<Expr> {
+    predicates.into_iter().reduce(|left, right| Expr::BinaryOp {
+        left: Box::new(left),
+        op: BinaryOperator::And,
+        right: Box::new(right),
+    })
+}
+
+// The names the columns of the tables may be qualified with.
+fn tables_name(from: &[TableWithJoins]) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for table in from {
+        collect_tables_name(table, &mut names);
+    }
+    names
+}
+
+fn collect_tables_name(table: &TableWithJoins, names: &mut HashSet<String>) {
+    let relations = std::iter::once(&table.relation).chain(table.joins.iter().map(|j| &j.relation));
+    for relation in relations {
+        match relation {
+            TableFactor::Table {
+                alias: Some(alias), ..
+            }
+            | TableFactor::Derived {
+                alias: Some(alias), ..
+            } => {
+                names.insert(alias.name.value.clone());
+            }
+            TableFactor::Table { name, .. } => {
+                if let Some(ident) = name.0.last() {
+                    names.insert(ident.value.clone());
+                }
+            }
+            TableFactor::NestedJoin(table) => collect_tables_name(table, names),
+            _ => {}
+        }
+    }
+}
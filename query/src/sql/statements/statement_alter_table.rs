@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use super::analyzer_expr::ExpressionAnalyzer;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfCreateTable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterTableAction {
+    AddColumn(ColumnDef),
+    DropColumn(Ident),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    pub if_exists: bool,
+    pub table_name: ObjectName,
+    pub action: AlterTableAction,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTable {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let tenant = ctx.get_tenant();
+        let (db, table) = self.resolve_table(&ctx)?;
+        let operation = match &self.action {
+            AlterTableAction::AddColumn(column) => {
+                let expr_analyzer = ExpressionAnalyzer::create(ctx.clone());
+                let field = DfCreateTable::column_field(&expr_analyzer, column).await?;
+                AlterTableOperation::AddColumn(field)
+            }
+            AlterTableAction::DropColumn(name) => {
+                AlterTableOperation::DropColumn(name.value.clone())
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterTable(
+            AlterTablePlan {
+                tenant,
+                if_exists: self.if_exists,
+                db,
+                table,
+                operation,
+            },
+        ))))
+    }
+}
+
+impl DfAlterTable {
+    fn resolve_table(&self, ctx: &QueryContext) -> Result<(String, String)> {
+        let idents = &self.table_name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
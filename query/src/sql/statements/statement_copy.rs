@@ -20,7 +20,9 @@ use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::FileFormatOptions;
+use common_meta_types::MatchByColumnName;
 use common_meta_types::OnErrorMode;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageParams;
 use common_meta_types::StageS3Storage;
@@ -31,6 +33,7 @@ use common_planners::CopyPlan;
 use common_planners::PlanNode;
 use common_planners::UserStagePlan;
 use common_planners::ValidationMode;
+use regex::Regex;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
 
@@ -46,9 +49,16 @@ pub struct DfCopy {
     pub credential_options: HashMap<String, String>,
     pub encryption_options: HashMap<String, String>,
     pub file_format_options: HashMap<String, String>,
+    pub null_if: Vec<String>,
     pub files: Vec<String>,
+    pub pattern: String,
     pub on_error: String,
     pub size_limit: String,
+    pub match_by_column_name: String,
+    pub max_files: String,
+    pub commit_per_file: String,
+    pub force: String,
+    pub purge: String,
     pub validation_mode: String,
 }
 
@@ -79,7 +89,7 @@ impl AnalyzableStatement for DfCopy {
 
         // Stage info.
         let mut stage_info = if self.location.starts_with('@') {
-            self.analyze_internal().await?
+            self.analyze_internal(&ctx).await?
         } else {
             self.analyze_external().await?
         };
@@ -102,6 +112,80 @@ impl AnalyzableStatement for DfCopy {
                 })?;
                 stage_info.copy_options.size_limit = size_limit;
             }
+
+            // match_by_column_name.
+            if !self.match_by_column_name.is_empty() {
+                let match_by_column_name = MatchByColumnName::from_str(&self.match_by_column_name)
+                    .map_err(ErrorCode::SyntaxException)?;
+
+                if match_by_column_name != MatchByColumnName::None && !self.columns.is_empty() {
+                    return Err(ErrorCode::SyntaxException(
+                        "match_by_column_name cannot be used together with a column list",
+                    ));
+                }
+                stage_info.copy_options.match_by_column_name = match_by_column_name;
+            }
+
+            // max_files.
+            if !self.max_files.is_empty() {
+                let max_files = self.max_files.parse::<usize>().map_err(|_e| {
+                    ErrorCode::SyntaxException(format!(
+                        "max_files must be number, got: {}",
+                        self.max_files
+                    ))
+                })?;
+                stage_info.copy_options.max_files = max_files;
+            }
+
+            // commit_per_file.
+            if !self.commit_per_file.is_empty() {
+                let commit_per_file = self
+                    .commit_per_file
+                    .to_lowercase()
+                    .parse::<bool>()
+                    .map_err(|_e| {
+                        ErrorCode::SyntaxException(format!(
+                            "commit_per_file must be true or false, got: {}",
+                            self.commit_per_file
+                        ))
+                    })?;
+                stage_info.copy_options.commit_per_file = commit_per_file;
+            }
+
+            // force.
+            if !self.force.is_empty() {
+                let force = self.force.to_lowercase().parse::<bool>().map_err(|_e| {
+                    ErrorCode::SyntaxException(format!(
+                        "force must be true or false, got: {}",
+                        self.force
+                    ))
+                })?;
+                stage_info.copy_options.force = force;
+            }
+
+            // purge.
+            if !self.purge.is_empty() {
+                let purge = self.purge.to_lowercase().parse::<bool>().map_err(|_e| {
+                    ErrorCode::SyntaxException(format!(
+                        "purge must be true or false, got: {}",
+                        self.purge
+                    ))
+                })?;
+                stage_info.copy_options.purge = purge;
+            }
+        }
+
+        // Pattern.
+        if !self.pattern.is_empty() {
+            if !self.files.is_empty() {
+                return Err(ErrorCode::SyntaxException(
+                    "files and pattern cannot be used together",
+                ));
+            }
+
+            Regex::new(&self.pattern).map_err(|e| {
+                ErrorCode::SyntaxException(format!("Invalid pattern {:?}: {}", self.pattern, e))
+            })?;
         }
 
         // Validation mode.
@@ -123,6 +207,7 @@ impl AnalyzableStatement for DfCopy {
             stage_plan,
             validation_mode,
             files: self.files.clone(),
+            pattern: self.pattern.clone(),
         };
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Copy(
@@ -133,14 +218,18 @@ impl AnalyzableStatement for DfCopy {
 
 impl DfCopy {
     // Internal stage(start with `@`):
-    // copy into mytable from @my_ext_stage
+    // copy into mytable from @my_int_stage/path/to/files
     // file_format = (type = csv);
-    async fn analyze_internal(&self) -> Result<UserStageInfo> {
-        // TODO(bohu): get stage info from metasrv by stage name.
-        Ok(UserStageInfo {
-            stage_type: StageType::Internal,
-            ..Default::default()
-        })
+    async fn analyze_internal(&self, ctx: &Arc<QueryContext>) -> Result<UserStageInfo> {
+        let mut stage_info = analyze_named_stage(ctx, &self.location).await?;
+
+        // File format of the statement overrides the one of the stage.
+        if !self.file_format_options.is_empty() {
+            stage_info.file_format_options =
+                analyze_file_format(&self.file_format_options, &self.null_if)?;
+        }
+
+        Ok(stage_info)
     }
 
     // External stage(location starts without `@`):
@@ -149,91 +238,13 @@ impl DfCopy {
     // encryption=(master_key = 'my_master_key')
     // file_format = (type = csv field_delimiter = '|' skip_header = 1)"
     async fn analyze_external(&self) -> Result<UserStageInfo> {
-        // File format type.
-        let format = self
-            .file_format_options
-            .get("type")
-            .ok_or_else(|| ErrorCode::SyntaxException("File format type must be specified"))?;
-        let file_format = StageFileFormatType::from_str(format)
-            .map_err(|e| ErrorCode::SyntaxException(format!("File format type error:{:?}", e)))?;
-
-        // Skip header.
-        let skip_header = self
-            .file_format_options
-            .get("skip_header")
-            .unwrap_or(&"0".to_string())
-            .parse::<i32>()?;
-
-        // Field delimiter.
-        let field_delimiter = self
-            .file_format_options
-            .get("field_delimiter")
-            .unwrap_or(&"".to_string())
-            .clone();
-
-        // Record delimiter.
-        let record_delimiter = self
-            .file_format_options
-            .get("record_delimiter")
-            .unwrap_or(&"".to_string())
-            .clone();
-
-        let file_format_options = FileFormatOptions {
-            format: file_format,
-            skip_header,
-            field_delimiter,
-            record_delimiter,
-            compression: Default::default(),
-        };
-
-        // Parse uri.
-        let uri = self
-            .location
-            .as_str()
-            .parse::<http::Uri>()
-            .map_err(|_e| ErrorCode::SyntaxException("File location uri must be specified"))?;
-        let bucket = uri.host().unwrap_or("").to_string();
-        let path = uri.path().to_string();
-
-        // File storage plan.
-        let stage_storage = match uri.scheme_str() {
-            None => Err(ErrorCode::SyntaxException(
-                "File location scheme must be specified",
-            )),
-            Some(v) => match v {
-                // AWS s3 plan.
-                "s3" => {
-                    let credentials_aws_key_id = self
-                        .credential_options
-                        .get("aws_key_id")
-                        .unwrap_or(&"".to_string())
-                        .clone();
-                    let credentials_aws_secret_key = self
-                        .credential_options
-                        .get("aws_secret_key")
-                        .unwrap_or(&"".to_string())
-                        .clone();
-                    let encryption_master_key = self
-                        .encryption_options
-                        .get("master_key")
-                        .unwrap_or(&"".to_string())
-                        .clone();
-
-                    Ok(StageStorage::S3(StageS3Storage {
-                        bucket,
-                        path,
-                        credentials_aws_key_id,
-                        credentials_aws_secret_key,
-                        encryption_master_key,
-                    }))
-                }
+        let file_format_options = analyze_file_format(&self.file_format_options, &self.null_if)?;
 
-                // Others.
-                _ => Err(ErrorCode::SyntaxException(
-                    "File location uri unsupported, must be one of [s3, @stage]",
-                )),
-            },
-        }?;
+        let stage_storage = analyze_stage_storage(
+            &self.location,
+            &self.credential_options,
+            &self.encryption_options,
+        )?;
 
         // Stage params.
         let stage_params = StageParams {
@@ -250,3 +261,158 @@ impl DfCopy {
         })
     }
 }
+
+// The named stage `@stage_name[/path]`, the path is resolved in the location of the stage.
+pub(crate) async fn analyze_named_stage(
+    ctx: &Arc<QueryContext>,
+    location: &str,
+) -> Result<UserStageInfo> {
+    let location = location.trim_start_matches('@');
+    let (name, sub_path) = match location.split_once('/') {
+        Some((name, path)) => (name, path),
+        None => (location, ""),
+    };
+    if name.is_empty() {
+        return Err(ErrorCode::SyntaxException("Stage name must be specified"));
+    }
+
+    let mut stage_info = ctx
+        .get_user_manager()
+        .get_stage(&ctx.get_tenant(), name)
+        .await?;
+
+    // Resolve the files path of the stage.
+    let path = match stage_info.stage_type {
+        StageType::Internal => format!("{}{}", stage_info.get_prefix(), sub_path),
+        StageType::External => {
+            let StageStorage::S3(ref s3) = stage_info.stage_params.storage;
+            format!("{}/{}", s3.path.trim_end_matches('/'), sub_path)
+        }
+    };
+    let StageStorage::S3(ref mut s3) = stage_info.stage_params.storage;
+    s3.path = path;
+
+    Ok(stage_info)
+}
+
+// The storage of the external location, e.g. 's3://mybucket/data/files'.
+pub(crate) fn analyze_stage_storage(
+    location: &str,
+    credential_options: &HashMap<String, String>,
+    encryption_options: &HashMap<String, String>,
+) -> Result<StageStorage> {
+    // Parse uri.
+    let uri = location
+        .parse::<http::Uri>()
+        .map_err(|_e| ErrorCode::SyntaxException("File location uri must be specified"))?;
+    let bucket = uri.host().unwrap_or("").to_string();
+    let path = uri.path().to_string();
+
+    // File storage plan.
+    match uri.scheme_str() {
+        None => Err(ErrorCode::SyntaxException(
+            "File location scheme must be specified",
+        )),
+        Some(v) => match v {
+            // AWS s3 plan.
+            "s3" => {
+                let credentials_aws_key_id = credential_options
+                    .get("aws_key_id")
+                    .unwrap_or(&"".to_string())
+                    .clone();
+                let credentials_aws_secret_key = credential_options
+                    .get("aws_secret_key")
+                    .unwrap_or(&"".to_string())
+                    .clone();
+                let encryption_master_key = encryption_options
+                    .get("master_key")
+                    .unwrap_or(&"".to_string())
+                    .clone();
+
+                Ok(StageStorage::S3(StageS3Storage {
+                    bucket,
+                    path,
+                    credentials_aws_key_id,
+                    credentials_aws_secret_key,
+                    encryption_master_key,
+                }))
+            }
+
+            // Others.
+            _ => Err(ErrorCode::SyntaxException(
+                "File location uri unsupported, must be one of [s3, @stage]",
+            )),
+        },
+    }
+}
+
+pub(crate) fn analyze_file_format(
+    options: &HashMap<String, String>,
+    null_if: &[String],
+) -> Result<FileFormatOptions> {
+    // File format type.
+    let format = options
+        .get("type")
+        .ok_or_else(|| ErrorCode::SyntaxException("File format type must be specified"))?;
+    let file_format = StageFileFormatType::from_str(format)
+        .map_err(|e| ErrorCode::SyntaxException(format!("File format type error:{:?}", e)))?;
+
+    // Skip header.
+    let skip_header = options
+        .get("skip_header")
+        .unwrap_or(&"0".to_string())
+        .parse::<i32>()?;
+
+    // Field delimiter, TSV defaults to tab.
+    let default_field_delimiter = match file_format {
+        StageFileFormatType::Tsv => "\t".to_string(),
+        _ => "".to_string(),
+    };
+    let field_delimiter = options
+        .get("field_delimiter")
+        .unwrap_or(&default_field_delimiter)
+        .clone();
+
+    // Record delimiter.
+    let record_delimiter = options
+        .get("record_delimiter")
+        .unwrap_or(&"".to_string())
+        .clone();
+
+    // Quote.
+    let quote = options.get("quote").unwrap_or(&"".to_string()).clone();
+
+    // Escape.
+    let escape = options.get("escape").unwrap_or(&"".to_string()).clone();
+
+    // Compression, detected by the file extension if auto.
+    let compression = match options.get("compression") {
+        None => StageFileCompression::Auto,
+        Some(v) => StageFileCompression::from_str(v).map_err(|e| {
+            ErrorCode::SyntaxException(format!("File compression type error:{:?}", e))
+        })?,
+    };
+
+    // Empty field as null.
+    let empty_field_as_null = match options.get("empty_field_as_null") {
+        None => false,
+        Some(v) => v.to_lowercase().parse::<bool>().map_err(|_e| {
+            ErrorCode::SyntaxException(format!(
+                "empty_field_as_null must be true or false, got: {}",
+                v
+            ))
+        })?,
+    };
+
+    Ok(FileFormatOptions {
+        format: file_format,
+        skip_header,
+        field_delimiter,
+        record_delimiter,
+        quote,
+        escape,
+        compression,
+        null_if: null_if.to_vec(),
+        empty_field_as_null,
+    })
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::StageFileFormatType;
+use common_planners::CopyIntoStagePlan;
+use common_planners::PlanNode;
+use common_planners::UserStagePlan;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::statement_copy::analyze_file_format;
+use crate::sql::statements::statement_copy::analyze_named_stage;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopyIntoStageSource {
+    Table(ObjectName),
+    Query(Box<Query>),
+}
+
+// Unload the table or the query result into the stage:
+// copy into @my_stage/path/to/files
+// from (select * from mytable)
+// file_format = (type = csv)
+// max_file_size = 1048576
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopyIntoStage {
+    pub location: String,
+    pub source: CopyIntoStageSource,
+    pub file_format_options: HashMap<String, String>,
+    pub null_if: Vec<String>,
+    pub max_file_size: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCopyIntoStage {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if !self.location.starts_with('@') {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Copy into location must be a stage, got: {}",
+                self.location
+            )));
+        }
+
+        let mut stage_info = analyze_named_stage(&ctx, &self.location).await?;
+
+        // File format of the statement overrides the one of the stage.
+        if !self.file_format_options.is_empty() {
+            stage_info.file_format_options =
+                analyze_file_format(&self.file_format_options, &self.null_if)?;
+        }
+        match stage_info.file_format_options.format {
+            StageFileFormatType::Csv | StageFileFormatType::Json => {}
+            format => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported file format for copy into stage: {:?}, must be one of [csv, json]",
+                    format
+                )));
+            }
+        }
+
+        // max_file_size.
+        let max_file_size = match self.max_file_size.is_empty() {
+            true => 0,
+            false => self.max_file_size.parse::<usize>().map_err(|_e| {
+                ErrorCode::SyntaxException(format!(
+                    "max_file_size must be number, got: {}",
+                    self.max_file_size
+                ))
+            })?,
+        };
+
+        let query = match &self.source {
+            CopyIntoStageSource::Table(name) => {
+                let query = format!("SELECT * FROM {}", name);
+                PlanParser::parse(ctx.clone(), &query).await?
+            }
+            CopyIntoStageSource::Query(query) => {
+                let statement = DfQueryStatement::try_from(*query.clone())?;
+                PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?
+            }
+        };
+
+        let stage_plan = UserStagePlan {
+            schema: query.schema(),
+            stage_info,
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CopyIntoStage(CopyIntoStagePlan {
+                stage_plan,
+                query: Box::new(query),
+                max_file_size,
+            }),
+        )))
+    }
+}
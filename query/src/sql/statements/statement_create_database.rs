@@ -15,7 +15,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::DatabaseMeta;
 use common_planners::CreateDatabasePlan;
@@ -26,6 +25,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateDatabase {
@@ -41,7 +41,7 @@ impl AnalyzableStatement for DfCreateDatabase {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let tenant = ctx.get_tenant();
-        let db = self.database_name()?;
+        let db = self.database_name(&ctx)?;
         let if_not_exists = self.if_not_exists;
         let meta = self.database_meta()?;
 
@@ -57,12 +57,9 @@ impl AnalyzableStatement for DfCreateDatabase {
 }
 
 impl DfCreateDatabase {
-    fn database_name(&self) -> Result<String> {
-        if self.name.0.is_empty() {
-            return Result::Err(ErrorCode::SyntaxException("Create database name is empty"));
-        }
-
-        Ok(self.name.0[0].value.clone())
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
+        let case_sensitive = ctx.get_settings().get_unquoted_ident_case_sensitive()?;
+        SQLCommon::resolve_database_name("Create", &self.name, case_sensitive)
     }
 
     fn database_meta(&self) -> Result<DatabaseMeta> {
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::CopyOptions;
+use common_meta_types::OnErrorMode;
+use common_meta_types::StageParams;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use common_planners::CreateUserStagePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+
+use super::statement_copy::analyze_file_format;
+use super::statement_copy::analyze_stage_storage;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateStage {
+    pub if_not_exists: bool,
+    pub stage_name: String,
+    // The url of the external stage, empty for an internal stage.
+    pub location: String,
+    pub credential_options: HashMap<String, String>,
+    pub encryption_options: HashMap<String, String>,
+    pub file_format_options: HashMap<String, String>,
+    pub null_if: Vec<String>,
+    pub copy_options: HashMap<String, String>,
+    pub comment: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateStage {
+    #[tracing::instrument(level = "info", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
+    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (stage_type, stage_params) = match self.location.is_empty() {
+            true => (StageType::Internal, StageParams::default()),
+            false => {
+                let storage = analyze_stage_storage(
+                    &self.location,
+                    &self.credential_options,
+                    &self.encryption_options,
+                )?;
+                (StageType::External, StageParams { storage })
+            }
+        };
+
+        // The default file format of the copy from the stage.
+        let file_format_options = match self.file_format_options.is_empty() {
+            true => Default::default(),
+            false => analyze_file_format(&self.file_format_options, &self.null_if)?,
+        };
+
+        let user_stage_info = UserStageInfo {
+            stage_name: self.stage_name.clone(),
+            stage_type,
+            stage_params,
+            file_format_options,
+            copy_options: self.analyze_copy_options()?,
+            comment: self.comment.clone(),
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateUserStage(CreateUserStagePlan {
+                if_not_exists: self.if_not_exists,
+                user_stage_info,
+            }),
+        )))
+    }
+}
+
+impl DfCreateStage {
+    // The default copy options of the copy from the stage, overridden by the ones of the copy.
+    fn analyze_copy_options(&self) -> Result<CopyOptions> {
+        let mut copy_options = CopyOptions::default();
+        for (name, value) in &self.copy_options {
+            match name.as_str() {
+                "on_error" => {
+                    copy_options.on_error =
+                        OnErrorMode::from_str(value).map_err(ErrorCode::SyntaxException)?;
+                }
+                "size_limit" => {
+                    copy_options.size_limit = value.parse::<usize>().map_err(|_e| {
+                        ErrorCode::SyntaxException(format!(
+                            "size_limit must be number, got: {}",
+                            value
+                        ))
+                    })?;
+                }
+                other => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Unknown stage copy option: {}, must be one of [on_error, size_limit]",
+                        other
+                    )));
+                }
+            }
+        }
+        Ok(copy_options)
+    }
+}
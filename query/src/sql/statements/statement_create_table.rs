@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_datavalues::remove_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
@@ -36,6 +38,8 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::fuse::TBL_OPT_KEY_PRIMARY_KEY;
+use crate::storages::view::VIEW_ENGINE;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -57,6 +61,12 @@ pub struct DfCreateTable {
 impl AnalyzableStatement for DfCreateTable {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if self.engine.to_uppercase() == VIEW_ENGINE {
+            return Err(ErrorCode::SyntaxException(
+                "Can't create table with VIEW engine, use CREATE VIEW instead",
+            ));
+        }
+
         let mut table_meta = self.table_meta(ctx.clone()).await?;
         let if_not_exists = self.if_not_exists;
         let tenant = ctx.get_tenant();
@@ -74,9 +84,19 @@ impl AnalyzableStatement for DfCreateTable {
                     .iter()
                     .map(|f| (f.name().clone(), f.clone()))
                     .collect::<HashMap<_, _>>();
+                let mut select_names = HashSet::new();
                 for field in select_plan.schema().fields() {
+                    if !select_names.insert(field.name()) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Duplicate column name {} in the select of create table",
+                            field.name()
+                        )));
+                    }
+
+                    // The column has the type of the select output, it's nullable unless the
+                    // expression can't be NULL, the default expression of the source is dropped.
                     if fields_map.get(field.name()).is_none() {
-                        fields.push(field.clone());
+                        fields.push(DataField::new(field.name(), field.data_type().clone()));
                     }
                 }
                 table_meta.schema = DataSchemaRefExt::create(fields);
@@ -85,6 +105,7 @@ impl AnalyzableStatement for DfCreateTable {
             // Query doesn't contain 'As Select' statement
             None => None,
         };
+        Self::check_primary_key(&mut table_meta)?;
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateTable(CreateTablePlan {
@@ -112,6 +133,31 @@ impl DfCreateTable {
         }
     }
 
+    // The primary key columns must exist, and they are not nullable.
+    fn check_primary_key(table_meta: &mut TableMeta) -> Result<()> {
+        let primary_key = match table_meta.options.get(TBL_OPT_KEY_PRIMARY_KEY) {
+            None => return Ok(()),
+            Some(primary_key) => primary_key,
+        };
+
+        let mut fields = table_meta.schema.fields().clone();
+        for name in primary_key.split(',').map(|name| name.trim()) {
+            let index = table_meta.schema.index_of(name).map_err(|_| {
+                ErrorCode::BadArguments(format!(
+                    "Primary key column '{}' does not exist in the table",
+                    name
+                ))
+            })?;
+            let field = fields[index].clone();
+            if field.is_nullable() {
+                fields[index] = DataField::new(name, remove_nullable(field.data_type()))
+                    .with_default_expr(field.default_expr().clone());
+            }
+        }
+        table_meta.schema = DataSchemaRefExt::create(fields);
+        Ok(())
+    }
+
     async fn table_meta(&self, ctx: Arc<QueryContext>) -> Result<TableMeta> {
         let engine = self.engine.clone();
         let schema = self.table_schema(ctx).await?;
@@ -141,33 +187,39 @@ impl DfCreateTable {
                 let mut fields = Vec::with_capacity(self.columns.len());
 
                 for column in &self.columns {
-                    let mut nullable = true;
-                    let mut default_expr = None;
-                    for opt in &column.options {
-                        match &opt.option {
-                            ColumnOption::NotNull => {
-                                nullable = false;
-                            }
-                            ColumnOption::Default(expr) => {
-                                let expr = expr_analyzer.analyze(expr).await?;
-                                default_expr = Some(serde_json::to_vec(&expr)?);
-                            }
-                            _ => {}
-                        }
-                    }
-                    let field = SQLCommon::make_data_type(&column.data_type).map(|data_type| {
-                        if nullable {
-                            DataField::new_nullable(&column.name.value, data_type)
-                                .with_default_expr(default_expr)
-                        } else {
-                            DataField::new(&column.name.value, data_type)
-                                .with_default_expr(default_expr)
-                        }
-                    })?;
-                    fields.push(field);
+                    fields.push(Self::column_field(&expr_analyzer, column).await?);
                 }
                 Ok(DataSchemaRefExt::create(fields))
             }
         }
     }
+
+    /// Converts a column definition into a field, columns are nullable unless NOT NULL is given.
+    pub(crate) async fn column_field(
+        expr_analyzer: &ExpressionAnalyzer,
+        column: &ColumnDef,
+    ) -> Result<DataField> {
+        let mut nullable = true;
+        let mut default_expr = None;
+        for opt in &column.options {
+            match &opt.option {
+                ColumnOption::NotNull | ColumnOption::Unique { is_primary: true } => {
+                    nullable = false;
+                }
+                ColumnOption::Default(expr) => {
+                    let expr = expr_analyzer.analyze(expr).await?;
+                    default_expr = Some(serde_json::to_vec(&expr)?);
+                }
+                _ => {}
+            }
+        }
+        SQLCommon::make_data_type(&column.data_type).map(|data_type| {
+            if nullable {
+                DataField::new_nullable(&column.name.value, data_type)
+                    .with_default_expr(default_expr)
+            } else {
+                DataField::new(&column.name.value, data_type).with_default_expr(default_expr)
+            }
+        })
+    }
 }
@@ -14,15 +14,24 @@
 
 use std::sync::Arc;
 
+use common_ast::udfs::UDFParser;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::is_builtin_function;
+use common_functions::scalars::cast_column_field;
+use common_meta_types::PythonHandler;
 use common_meta_types::UserDefinedFunction;
 use common_planners::CreateUserUDFPlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
+use sqlparser::ast::DataType;
 
+use super::analyzer_expr::ExpressionAnalyzer;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateUDF {
@@ -31,22 +40,111 @@ pub struct DfCreateUDF {
     pub parameters: Vec<String>,
     pub definition: String,
     pub description: String,
+    // The types of the typed UDF, e.g. `CREATE FUNCTION add_one(x INT) RETURNS INT AS (x + 1)`.
+    pub parameter_types: Vec<DataType>,
+    pub return_type: Option<DataType>,
+    // The python UDF is typed, and has no definition.
+    pub python_handler: Option<PythonHandler>,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCreateUDF {
-    #[tracing::instrument(level = "info", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if is_builtin_function(&self.udf_name) {
+            return Err(ErrorCode::UdfAlreadyExists(format!(
+                "It's a builtin function: {}",
+                self.udf_name
+            )));
+        }
+
+        let mut udf = UserDefinedFunction::new(
+            self.udf_name.as_str(),
+            self.parameters.clone(),
+            self.definition.as_str(),
+            self.description.as_str(),
+        );
+
+        if let Some(return_type) = &self.return_type {
+            match &self.python_handler {
+                None => self.check_types(ctx, return_type).await?,
+                Some(handler) => {
+                    self.check_python_handler(handler)?;
+                    udf = udf.with_python_handler(&handler.file, &handler.function);
+                }
+            }
+
+            let parameter_types = self
+                .parameter_types
+                .iter()
+                .map(|data_type| data_type.to_string())
+                .collect();
+            udf = udf.with_types(parameter_types, &return_type.to_string());
+        }
+
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateUserUDF(CreateUserUDFPlan {
                 if_not_exists: self.if_not_exists,
-                udf: UserDefinedFunction::new(
-                    self.udf_name.as_str(),
-                    self.parameters.clone(),
-                    self.definition.as_str(),
-                    self.description.as_str(),
-                ),
+                udf,
             }),
         )))
     }
 }
+
+impl DfCreateUDF {
+    // The handler is called by `python_udf('<file>', '<function>', ...)`.
+    fn check_python_handler(&self, handler: &PythonHandler) -> Result<()> {
+        let is_identifier = |s: &str| {
+            !s.is_empty()
+                && !s.starts_with(|c: char| c.is_ascii_digit())
+                && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !is_identifier(&handler.function) {
+            return Err(ErrorCode::SyntaxException(format!(
+                "The handler of python function {} must be an identifier, but got: {}",
+                self.udf_name, handler.function
+            )));
+        }
+        if handler.file.is_empty() || handler.file.contains(|c| c == '\'' || c == '\\') {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Illegal file of python function {}: {}",
+                self.udf_name, handler.file
+            )));
+        }
+        Ok(())
+    }
+
+    // Type check the definition of the typed UDF with the parameter types, the result must be
+    // able to cast to the return type.
+    async fn check_types(&self, ctx: Arc<QueryContext>, return_type: &DataType) -> Result<()> {
+        let mut fields = Vec::with_capacity(self.parameters.len());
+        for (parameter, data_type) in self.parameters.iter().zip(self.parameter_types.iter()) {
+            fields.push(DataField::new(
+                parameter,
+                SQLCommon::make_data_type(data_type)?,
+            ));
+        }
+        let schema = DataSchemaRefExt::create(fields);
+        let return_type = SQLCommon::make_data_type(return_type)?;
+
+        let mut udf_parser = UDFParser::default();
+        let definition_expr = udf_parser
+            .parse(&self.udf_name, &self.parameters, &self.definition)
+            .await?;
+        let expr_analyzer = ExpressionAnalyzer::create(ctx);
+        let expression = expr_analyzer.analyze(&definition_expr).await?;
+        let definition_type = expression.to_data_type(&schema)?;
+
+        let column = definition_type.create_mutable(0).to_column();
+        let column = ColumnWithField::new(column, DataField::new("", definition_type.clone()));
+        cast_column_field(&column, &return_type).map_err(|_| {
+            ErrorCode::IllegalDataType(format!(
+                "The definition of function {} returns {}, can not cast to {}",
+                self.udf_name,
+                definition_type.name(),
+                return_type.name()
+            ))
+        })?;
+        Ok(())
+    }
+}
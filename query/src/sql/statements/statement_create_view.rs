@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CreateViewPlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateView {
+    pub if_not_exists: bool,
+    pub or_replace: bool,
+    /// View name
+    pub name: ObjectName,
+    // The query of "create view .. as select" statement.
+    pub query: Box<Query>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateView {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if self.if_not_exists && self.or_replace {
+            return Err(ErrorCode::SyntaxException(
+                "CREATE OR REPLACE VIEW and IF NOT EXISTS can't be used together",
+            ));
+        }
+
+        let tenant = ctx.get_tenant();
+        let (db, viewname) = self.resolve_view(ctx.clone())?;
+
+        // Plan the query to validate it, the schema of the view is the output of the query.
+        let statement = DfQueryStatement::try_from(*self.query.clone())?;
+        let statements = vec![DfStatement::Query(Box::new(statement))];
+        let select_plan = PlanParser::build_plan(statements, ctx).await?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::CreateView(
+            CreateViewPlan {
+                if_not_exists: self.if_not_exists,
+                or_replace: self.or_replace,
+                tenant,
+                db,
+                viewname,
+                view_schema: select_plan.schema(),
+                subquery: self.query.to_string(),
+            },
+        ))))
+    }
+}
+
+impl DfCreateView {
+    fn resolve_view(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let DfCreateView {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Create view name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Create view name must be [`db`].`view`",
+            )),
+        }
+    }
+}
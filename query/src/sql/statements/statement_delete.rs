@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::DeletePlan;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::PlanNode;
+use common_planners::Recursion;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDelete {
+    pub table_name: ObjectName,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfDelete {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(&ctx)?;
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => {
+                let schema = ctx.get_table(&db, &table).await?.schema();
+                let expr_analyzer = ExpressionAnalyzer::create(ctx.clone());
+                let selection = expr_analyzer.analyze(expr).await?;
+                verify_row_expression(&selection, "the WHERE clause of DELETE")?;
+
+                // Fail early if the selection refers to unknown columns.
+                selection.to_data_type(&schema)?;
+                Some(selection)
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Delete(
+            DeletePlan {
+                db,
+                table,
+                selection,
+            },
+        ))))
+    }
+}
+
+impl DfDelete {
+    fn resolve_table(&self, ctx: &QueryContext) -> Result<(String, String)> {
+        let idents = &self.table_name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Delete table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Delete table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
+
+// The expression is evaluated block by block, on the rows of the table only.
+pub(super) fn verify_row_expression(expr: &Expression, clause: &str) -> Result<()> {
+    if !find_aggregate_exprs_in_expr(expr).is_empty() {
+        return Err(ErrorCode::SyntaxException(format!(
+            "Aggregate functions are not allowed in {}",
+            clause
+        )));
+    }
+
+    if expr.accept(SubqueryFinder { found: false })?.found {
+        return Err(ErrorCode::UnImplement(format!(
+            "Subqueries in {} are unimplemented yet",
+            clause
+        )));
+    }
+    Ok(())
+}
+
+struct SubqueryFinder {
+    found: bool,
+}
+
+impl ExpressionVisitor for SubqueryFinder {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. } => {
+                self.found = true;
+                Ok(Recursion::Stop(self))
+            }
+            _ => Ok(Recursion::Continue(self)),
+        }
+    }
+}
@@ -65,6 +65,9 @@ impl DfDescribeTable {
             DataField::new("Field", Vu8::to_data_type()),
             DataField::new("Type", Vu8::to_data_type()),
             DataField::new("Null", Vu8::to_data_type()),
+            DataField::new("Key", Vu8::to_data_type()),
+            DataField::new_nullable("Default", Vu8::to_data_type()),
+            DataField::new("Extra", Vu8::to_data_type()),
         ])
     }
 }
@@ -14,7 +14,6 @@
 
 use std::sync::Arc;
 
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::DropDatabasePlan;
 use common_planners::PlanNode;
@@ -24,19 +23,22 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfDropDatabase {
     pub if_exists: bool,
     pub name: ObjectName,
+    pub cascade: bool,
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfDropDatabase {
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
-        let db = self.database_name()?;
+        let db = self.database_name(&ctx)?;
         let if_exists = self.if_exists;
+        let cascade = self.cascade;
         let tenant = ctx.get_tenant();
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(
@@ -44,17 +46,15 @@ impl AnalyzableStatement for DfDropDatabase {
                 tenant,
                 db,
                 if_exists,
+                cascade,
             }),
         )))
     }
 }
 
 impl DfDropDatabase {
-    fn database_name(&self) -> Result<String> {
-        if self.name.0.is_empty() {
-            return Result::Err(ErrorCode::SyntaxException("Create database name is empty"));
-        }
-
-        Ok(self.name.0[0].value.clone())
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
+        let case_sensitive = ctx.get_settings().get_unquoted_ident_case_sensitive()?;
+        SQLCommon::resolve_database_name("Drop", &self.name, case_sensitive)
     }
 }
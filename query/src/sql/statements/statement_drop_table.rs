@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::DropTableEntity;
 use common_planners::DropTablePlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
@@ -28,7 +29,8 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfDropTable {
     pub if_exists: bool,
-    pub name: ObjectName,
+    /// The tables, dropped in order.
+    pub names: Vec<ObjectName>,
 }
 
 #[async_trait::async_trait]
@@ -37,25 +39,25 @@ impl AnalyzableStatement for DfDropTable {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let if_exists = self.if_exists;
         let tenant = ctx.get_tenant();
-        let (db, table) = self.resolve_table(ctx)?;
+        let mut entities = Vec::with_capacity(self.names.len());
+        for name in &self.names {
+            let (db, table) = Self::resolve_table(&ctx, name)?;
+            entities.push(DropTableEntity { db, table });
+        }
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::DropTable(
             DropTablePlan {
                 if_exists,
                 tenant,
-                db,
-                table,
+                entities,
             },
         ))))
     }
 }
 
 impl DfDropTable {
-    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
-        let DfDropTable {
-            name: ObjectName(idents),
-            ..
-        } = self;
+    fn resolve_table(ctx: &QueryContext, name: &ObjectName) -> Result<(String, String)> {
+        let idents = &name.0;
         match idents.len() {
             0 => Err(ErrorCode::SyntaxException("Drop table name is empty")),
             1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
@@ -16,7 +16,9 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNode;
 use common_tracing::tracing;
 
 use crate::sessions::QueryContext;
@@ -45,7 +47,22 @@ impl AnalyzableStatement for DfExplain {
                     explain_query_state,
                 )))
             }
-            _ => Err(ErrorCode::SyntaxException("Only support EXPLAIN SELECT")),
+            DfStatement::InsertQuery(v) if self.typ == ExplainType::Analyze => {
+                match v.analyze(ctx).await? {
+                    AnalyzedResult::SimpleQuery(plan) => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                        PlanNode::Explain(ExplainPlan {
+                            typ: self.typ,
+                            input: Arc::new(*plan),
+                        }),
+                    ))),
+                    _ => Err(ErrorCode::LogicalError(
+                        "Logical error: analyze insert must be return simple query analyze result.",
+                    )),
+                }
+            }
+            _ => Err(ErrorCode::SyntaxException(
+                "Only support EXPLAIN SELECT and EXPLAIN ANALYZE INSERT",
+            )),
         }
     }
 }
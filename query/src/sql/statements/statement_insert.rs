@@ -14,8 +14,8 @@
 
 use std::sync::Arc;
 
-use common_datavalues::DataSchemaRef;
-use common_datavalues::DataSchemaRefExt;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
@@ -32,6 +32,7 @@ use sqlparser::ast::SetExpr;
 use sqlparser::ast::SqliteOnConflict;
 use sqlparser::ast::Values;
 
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::AnalyzableStatement;
@@ -73,13 +74,21 @@ impl AnalyzableStatement for DfInsertStatement {
         let (database_name, table_name) = self.resolve_table(&ctx)?;
         let write_table = ctx.get_table(&database_name, &table_name).await?;
         let table_id = write_table.get_id();
+        let table_schema = write_table.schema();
         let schema = self.insert_schema(write_table)?;
 
         let input_source = match &self.source {
             None => self.analyze_insert_without_source().await,
             Some(source) => match &source.body {
                 SetExpr::Values(v) => self.analyze_insert_values(ctx.clone(), v, &schema).await,
-                SetExpr::Select(_) => self.analyze_insert_select(ctx.clone(), source).await,
+                SetExpr::Select(_) if self.is_replace() => Err(ErrorCode::SyntaxException(
+                    "Unsupported replace into ... select statement.",
+                )),
+                SetExpr::Select(_) => {
+                    Self::check_omitted_columns(&table_schema, &schema)?;
+                    self.analyze_insert_select(ctx.clone(), source, &schema)
+                        .await
+                }
                 _ => Err(ErrorCode::SyntaxException(
                     "Insert must be have values or select source.",
                 )),
@@ -93,6 +102,7 @@ impl AnalyzableStatement for DfInsertStatement {
                 table_id,
                 schema,
                 overwrite: self.overwrite,
+                replace: self.is_replace(),
                 source: input_source,
             },
         ))))
@@ -117,7 +127,25 @@ impl DfInsertStatement {
         }
     }
 
+    fn is_replace(&self) -> bool {
+        matches!(self.or, Some(SqliteOnConflict::Replace))
+    }
+
     fn is_supported(&self) -> Result<()> {
+        if let Some(or) = &self.or {
+            if !self.is_replace() {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported insert or {} statement.",
+                    or
+                )));
+            }
+            if self.overwrite {
+                return Err(ErrorCode::SyntaxException(
+                    "Unsupported replace overwrite statement.",
+                ));
+            }
+        }
+
         if self.partitioned.is_some() {
             return Err(ErrorCode::SyntaxException(
                 "Unsupported insert ... partition statement.",
@@ -143,11 +171,26 @@ impl DfInsertStatement {
 
         let expression_analyzer = ExpressionAnalyzer::create(ctx);
         let mut value_exprs = Vec::with_capacity(values.0.len());
-        for value in &values.0 {
+        for (row, value) in values.0.iter().enumerate() {
+            if value.len() != schema.fields().len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Insert values expects {} columns, but the row {} has {} values",
+                    schema.fields().len(),
+                    row + 1,
+                    value.len()
+                )));
+            }
+
             let mut exprs = Vec::with_capacity(value.len());
             for (i, v) in value.iter().enumerate() {
                 let expr = expression_analyzer.analyze(v).await?;
-                let expr = if &expr.to_data_type(schema)? != schema.field(i).data_type() {
+                let expr_type = expr.to_data_type(schema)?;
+                let field_type = remove_nullable(schema.field(i).data_type());
+                if expr_type.data_type_id().is_floating() && field_type.data_type_id().is_integer()
+                {
+                    Self::check_integral_value(&expr, schema.field(i))?;
+                }
+                let expr = if &expr_type != schema.field(i).data_type() {
                     Expression::Cast {
                         expr: Box::new(expr),
                         data_type: schema.field(i).data_type().clone(),
@@ -167,6 +210,39 @@ impl DfInsertStatement {
         Ok(InsertInputSource::Expressions(value_exprs))
     }
 
+    // The cast of a float to an integer truncates the fractional part, which is lost silently,
+    // so the float values are checked to be integral before they are inserted.
+    fn check_integral_value(expr: &Expression, field: &DataField) -> Result<()> {
+        let dummy = DataSchemaRefExt::create(vec![DataField::new("dummy", u8::to_data_type())]);
+        let one_row_block = DataBlock::create(dummy.clone(), vec![Series::from_data(vec![1u8])]);
+        let value_field = DataField::new(field.name(), expr.to_data_type(&dummy)?);
+        let executor = ExpressionExecutor::try_create(
+            "Insert into from values",
+            dummy,
+            DataSchemaRefExt::create(vec![value_field]),
+            vec![Expression::Alias(
+                field.name().to_string(),
+                Box::new(expr.clone()),
+            )],
+            true,
+        )?;
+
+        let value = executor.execute(&one_row_block)?.column(0).get(0);
+        if value.is_null() {
+            return Ok(());
+        }
+        let value = value.as_f64()?;
+        match value.is_finite() && value.fract() == 0.0 {
+            true => Ok(()),
+            false => Err(ErrorCode::BadDataValueType(format!(
+                "Cannot insert {} into column '{}' of type {}, the fractional part would be lost",
+                value,
+                field.name(),
+                field.data_type().name()
+            ))),
+        }
+    }
+
     async fn analyze_insert_without_source(&self) -> Result<InsertInputSource> {
         let format = self.format.as_ref().ok_or_else(|| {
             ErrorCode::SyntaxException("FORMAT must be specified in streaming insertion")
@@ -178,13 +254,76 @@ impl DfInsertStatement {
         &self,
         ctx: Arc<QueryContext>,
         source: &Query,
+        schema: &DataSchemaRef,
     ) -> Result<InsertInputSource> {
         let statement = DfQueryStatement::try_from(source.clone())?;
         let select_plan =
             PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx).await?;
+        Self::check_insert_select_schema(&select_plan.schema(), schema)?;
         Ok(InsertInputSource::SelectPlan(Box::new(select_plan)))
     }
 
+    // The columns of select are cast to the insert columns by position when the rows are written,
+    // check the casts by a dry run, so the incompatible types fail the query before it runs.
+    fn check_insert_select_schema(
+        select_schema: &DataSchemaRef,
+        schema: &DataSchemaRef,
+    ) -> Result<()> {
+        if select_schema.fields().len() != schema.fields().len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Insert select expects {} columns, but the select statement has {} columns",
+                schema.fields().len(),
+                select_schema.fields().len()
+            )));
+        }
+
+        let exprs = select_schema
+            .fields()
+            .iter()
+            .zip(schema.fields().iter())
+            .map(|(select_field, field)| {
+                Expression::Alias(
+                    field.name().to_string(),
+                    Box::new(Expression::Cast {
+                        expr: Box::new(Expression::Column(select_field.name().to_string())),
+                        data_type: field.data_type().clone(),
+                        is_nullable: false,
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let executor = ExpressionExecutor::try_create(
+            "Insert into from select",
+            select_schema.clone(),
+            schema.clone(),
+            exprs,
+            true,
+        )?;
+        let empty_block = DataBlock::empty_with_schema(select_schema.clone());
+        match executor.execute(&empty_block) {
+            Ok(_) => Ok(()),
+            Err(cause) => Err(cause.add_message_back(" (while in insert select)")),
+        }
+    }
+
+    // The columns omitted from the column list are filled with NULL or the default values,
+    // a non-nullable column without default has no value to fill.
+    fn check_omitted_columns(table_schema: &DataSchemaRef, schema: &DataSchemaRef) -> Result<()> {
+        for field in table_schema.fields() {
+            if !schema.has_field(field.name())
+                && !field.is_nullable()
+                && field.default_expr().is_none()
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column '{}' is not nullable and has no default value, it must be in the column list of insert select",
+                    field.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     fn insert_schema(&self, read_table: Arc<dyn Table>) -> Result<DataSchemaRef> {
         match self.columns.is_empty() {
             true => Ok(read_table.schema()),
@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ListUserStagePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::statement_copy::analyze_named_stage;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+// list @stage_name[/path] [pattern = '<regex_pattern>']
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfListStage {
+    pub location: String,
+    pub pattern: String,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfListStage {
+    #[tracing::instrument(level = "info", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if !self.location.starts_with('@') {
+            return Err(ErrorCode::SyntaxException(format!(
+                "List location must be a stage, got: {}",
+                self.location
+            )));
+        }
+
+        // The files are listed under the path of the stage, the same as the copy.
+        let stage_info = analyze_named_stage(&ctx, &self.location).await?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::ListUserStage(ListUserStagePlan {
+                stage_info,
+                pattern: self.pattern.clone(),
+            }),
+        )))
+    }
+}
@@ -21,10 +21,15 @@ use common_exception::Result;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::find_aggregate_exprs;
 use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_window_exprs;
+use common_planners::find_window_exprs_in_expr;
 use common_planners::rebase_expr;
+use common_planners::sort_to_inner_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
+use sqlparser::ast::Cte;
 use sqlparser::ast::Expr;
+use sqlparser::ast::Fetch;
 use sqlparser::ast::Offset;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::SelectItem;
@@ -51,9 +56,13 @@ pub struct DfQueryStatement {
     pub selection: Option<Expr>,
     pub group_by: Vec<Expr>,
     pub having: Option<Expr>,
+    pub qualify: Option<Expr>,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Offset>,
+    pub fetch: Option<Fetch>,
+    // The CTEs visible to the query, the inner ones come last and shadow the outer ones.
+    pub ctes: Vec<Cte>,
 }
 
 #[async_trait::async_trait]
@@ -77,9 +86,11 @@ impl DfQueryStatement {
     async fn analyze_query(&self, ir: QueryASTIR) -> Result<QueryAnalyzeState> {
         let limit = ir.limit;
         let offset = ir.offset;
+        let limit_with_ties = ir.limit_with_ties;
         let mut analyze_state = QueryAnalyzeState {
             limit,
             offset,
+            limit_with_ties,
             ..Default::default()
         };
 
@@ -88,6 +99,13 @@ impl DfQueryStatement {
             analyze_state.filter = Some(predicate.clone());
         }
 
+        for semi_join in &ir.semi_joins {
+            for key in &semi_join.keys {
+                Self::verify_no_aggregate(key, "filter")?;
+            }
+            analyze_state.semi_joins.push(semi_join.clone());
+        }
+
         Self::analyze_projection(&ir.projection_expressions, &mut analyze_state)?;
 
         // Allow `SELECT name FROM system.databases HAVING name = 'xxx'`
@@ -95,6 +113,12 @@ impl DfQueryStatement {
             analyze_state.having = Some(rebase_expr(predicate, &analyze_state.expressions)?);
         }
 
+        // The QUALIFY predicate is evaluated with the projection, after the window functions.
+        if let Some(predicate) = &ir.qualify_predicate {
+            analyze_state.add_expression(predicate);
+            analyze_state.qualify = Some(rebase_expr(predicate, &analyze_state.expressions)?);
+        }
+
         for item in &ir.order_by_expressions {
             match item {
                 Expression::Sort {
@@ -143,9 +167,64 @@ impl DfQueryStatement {
             Self::analyze_aggregate(&ir.aggregate_expressions, &mut analyze_state)?;
         }
 
+        Self::analyze_window(&mut analyze_state)?;
         Ok(analyze_state)
     }
 
+    // The window functions are evaluated after the aggregation and HAVING. Their arguments and
+    // the expressions without window functions are evaluated before them, the expressions with
+    // window functions after them.
+    fn analyze_window(state: &mut QueryAnalyzeState) -> Result<()> {
+        let window_functions = find_window_exprs(&state.expressions);
+        if window_functions.is_empty() {
+            return Ok(());
+        }
+
+        let without_window = state
+            .expressions
+            .iter()
+            .filter(|expr| find_window_exprs_in_expr(expr).is_empty())
+            .cloned()
+            .collect::<Vec<_>>();
+        for expression in &without_window {
+            state.add_before_window_expression(expression);
+        }
+
+        for window_function in &window_functions {
+            if let Expression::WindowFunction {
+                args,
+                partition_by,
+                order_by,
+                ..
+            } = window_function
+            {
+                for arg in args.iter().chain(partition_by) {
+                    state.add_before_window_expression(arg);
+                }
+
+                for sort in order_by {
+                    state.add_before_window_expression(&sort_to_inner_expr(sort));
+                }
+            }
+        }
+
+        for window_function in &window_functions {
+            let base_exprs = &state.before_window_expressions;
+            state
+                .window_expressions
+                .push(rebase_expr(window_function, base_exprs)?);
+        }
+
+        let mut expressions = Vec::with_capacity(state.expressions.len());
+        for expression in &state.expressions {
+            let expression = rebase_expr(expression, &window_functions)?;
+            expressions.push(rebase_expr(&expression, &state.before_window_expressions)?);
+        }
+
+        state.expressions = expressions;
+        Ok(())
+    }
+
     fn analyze_aggregate(exprs: &[Expression], state: &mut QueryAnalyzeState) -> Result<()> {
         let aggregate_functions = find_aggregate_exprs(exprs);
         let aggregate_functions_args = expand_aggregate_arg_exprs(&aggregate_functions);
@@ -199,6 +278,10 @@ impl DfQueryStatement {
         let dry_run_res = Self::verify_with_dry_run(&schema, &state)?;
         state.finalize_schema = dry_run_res.schema().clone();
 
+        if let Some((array_column, element_column)) = schema.get_lateral_unnest() {
+            state.unnest = Some((array_column.clone(), element_column.short_name.clone()));
+        }
+
         let mut tables_desc = schema.take_tables_desc();
 
         if tables_desc.len() != 1 {
@@ -233,6 +316,16 @@ impl DfQueryStatement {
             }
         }
 
+        for semi_join in &state.semi_joins {
+            if semi_join.keys.is_empty() {
+                continue;
+            }
+
+            if let Err(cause) = Self::dry_run_exprs(&semi_join.keys, &data_block) {
+                return Err(cause.add_message_back(" (while in select semi join)"));
+            }
+        }
+
         if !state.before_group_by_expressions.is_empty() {
             match Self::dry_run_exprs(&state.before_group_by_expressions, &data_block) {
                 Ok(res) => {
@@ -266,6 +359,27 @@ impl DfQueryStatement {
             }
         }
 
+        if !state.window_expressions.is_empty() {
+            // The expressions after the window functions may refer to all the columns before.
+            match Self::dry_run_exprs_append(&state.before_window_expressions, &data_block) {
+                Ok(res) => {
+                    data_block = res;
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(" (while in select before window)"));
+                }
+            }
+
+            match Self::dry_run_exprs_append(&state.window_expressions, &data_block) {
+                Ok(res) => {
+                    data_block = res;
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(" (while in select window)"));
+                }
+            }
+        }
+
         if !state.expressions.is_empty() {
             match Self::dry_run_exprs(&state.expressions, &data_block) {
                 Ok(res) => {
@@ -286,6 +400,12 @@ impl DfQueryStatement {
             }
         }
 
+        if let Some(predicate) = &state.qualify {
+            if let Err(cause) = Self::dry_run_expr(predicate, &data_block) {
+                return Err(cause.add_message_back(" (while in select qualify)"));
+            }
+        }
+
         if !state.order_by_expressions.is_empty() {
             if let Err(cause) = Self::dry_run_exprs(&state.order_by_expressions, &data_block) {
                 return Err(cause.add_message_back(" (while in select order by)"));
@@ -327,6 +447,22 @@ impl DfQueryStatement {
         )))
     }
 
+    fn dry_run_exprs_append(exprs: &[Expression], data: &DataBlock) -> Result<DataBlock> {
+        let schema = data.schema();
+        let mut new_data_fields = schema.fields().clone();
+
+        for expr in exprs {
+            let data_field = expr.to_data_field(schema)?;
+            if !schema.has_field(data_field.name()) {
+                new_data_fields.push(data_field);
+            }
+        }
+
+        Ok(DataBlock::empty_with_schema(DataSchemaRefExt::create(
+            new_data_fields,
+        )))
+    }
+
     fn dry_run_exprs_ref(exprs: &[&Expression], data: &DataBlock) -> Result<DataBlock> {
         let schema = data.schema();
         let mut new_data_fields = Vec::with_capacity(exprs.len());
@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::convert::TryFrom;
 
+use sqlparser::ast::Cte;
+use sqlparser::ast::Expr;
 use sqlparser::ast::Query;
 use sqlparser::ast::Select;
+use sqlparser::ast::SelectItem;
 use sqlparser::ast::SetExpr;
+use sqlparser::ast::With;
 use sqlparser::parser::ParserError;
 
+use crate::sql::parsers::QUALIFY_ALIAS;
 use crate::sql::statements::DfQueryStatement;
 
 impl TryFrom<Query> for DfQueryStatement {
@@ -27,16 +33,29 @@ impl TryFrom<Query> for DfQueryStatement {
     fn try_from(query: Query) -> Result<Self, Self::Error> {
         let query_body = Self::get_body(&query)?;
 
-        if query.with.is_some() {
-            return Err(ParserError::ParserError(String::from(
-                "CTE is not yet implement",
-            )));
-        }
+        let ctes = match &query.with {
+            None => vec![],
+            Some(with) => Self::get_ctes(with)?,
+        };
 
-        if query.fetch.is_some() {
-            return Err(ParserError::ParserError(String::from(
-                "FETCH is not yet implement",
-            )));
+        if let Some(fetch) = &query.fetch {
+            if fetch.percent {
+                return Err(ParserError::ParserError(String::from(
+                    "FETCH FIRST n PERCENT is not yet implement",
+                )));
+            }
+
+            if query.limit.is_some() {
+                return Err(ParserError::ParserError(String::from(
+                    "Cannot use both LIMIT and FETCH in the same query",
+                )));
+            }
+
+            if fetch.with_ties && query.order_by.is_empty() {
+                return Err(ParserError::ParserError(String::from(
+                    "FETCH FIRST n ROWS WITH TIES requires ORDER BY",
+                )));
+            }
         }
 
         if query_body.top.is_some() {
@@ -63,15 +82,32 @@ impl TryFrom<Query> for DfQueryStatement {
             )));
         }
 
+        // The QUALIFY clause is parsed as the last projection item, see `rewrite_qualify_clause`.
+        let mut projection = query_body.projection.clone();
+        let qualify = match projection.last() {
+            Some(SelectItem::ExprWithAlias { expr, alias }) if alias.value == QUALIFY_ALIAS => {
+                let expr = match expr {
+                    Expr::Nested(expr) => expr.as_ref().clone(),
+                    expr => expr.clone(),
+                };
+                projection.pop();
+                Some(expr)
+            }
+            _ => None,
+        };
+
         Ok(DfQueryStatement {
             from: query_body.from.clone(),
-            projection: query_body.projection.clone(),
+            projection,
             selection: query_body.selection.clone(),
             group_by: query_body.group_by.clone(),
             having: query_body.having.clone(),
+            qualify,
             order_by: query.order_by.clone(),
             limit: query.limit.clone(),
             offset: query.offset.clone(),
+            fetch: query.fetch.clone(),
+            ctes,
         })
     }
 }
@@ -86,4 +122,31 @@ impl DfQueryStatement {
             ))),
         }
     }
+
+    fn get_ctes(with: &With) -> Result<Vec<Cte>, ParserError> {
+        if with.recursive {
+            return Err(ParserError::ParserError(String::from(
+                "WITH RECURSIVE is not yet implement",
+            )));
+        }
+
+        let mut names = HashSet::with_capacity(with.cte_tables.len());
+        for cte in &with.cte_tables {
+            if !cte.alias.columns.is_empty() {
+                return Err(ParserError::ParserError(format!(
+                    "Column list of CTE {} is not yet implement",
+                    cte.alias.name
+                )));
+            }
+
+            if !names.insert(cte.alias.name.value.as_str()) {
+                return Err(ParserError::ParserError(format!(
+                    "Duplicate CTE name {}",
+                    cte.alias.name
+                )));
+            }
+        }
+
+        Ok(with.cte_tables.clone())
+    }
 }
@@ -22,6 +22,7 @@ use common_planners::VarValue;
 use common_tracing::tracing;
 use sqlparser::ast::Ident;
 use sqlparser::ast::SetVariableValue;
+use sqlparser::ast::Value;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
@@ -29,6 +30,7 @@ use crate::sql::statements::AnalyzedResult;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfSetVariable {
+    pub global: bool,
     pub local: bool,
     pub hivevar: bool,
     pub variable: Ident,
@@ -48,7 +50,10 @@ impl AnalyzableStatement for DfSetVariable {
         // TODO: session variable and local variable
         let vars = self.mapping_set_vars();
         Ok(AnalyzedResult::SimpleQuery(Box::new(
-            PlanNode::SetVariable(SettingPlan { vars }),
+            PlanNode::SetVariable(SettingPlan {
+                vars,
+                is_global: self.global,
+            }),
         )))
     }
 }
@@ -59,6 +64,9 @@ impl DfSetVariable {
             variable,
             value: match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
+                sqlparser::ast::SetVariableValue::Literal(Value::SingleQuotedString(v)) => {
+                    v.clone()
+                }
                 sqlparser::ast::SetVariableValue::Literal(v) => v.to_string(),
             },
         }
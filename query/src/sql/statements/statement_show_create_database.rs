@@ -15,7 +15,6 @@
 use std::sync::Arc;
 
 use common_datavalues::prelude::*;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::ShowCreateDatabasePlan;
@@ -24,6 +23,7 @@ use sqlparser::ast::ObjectName;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfShowCreateDatabase {
@@ -32,10 +32,10 @@ pub struct DfShowCreateDatabase {
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfShowCreateDatabase {
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::ShowCreateDatabase(ShowCreateDatabasePlan {
-                db: self.database_name()?,
+                db: self.database_name(&ctx)?,
                 schema: Self::schema(),
             }),
         )))
@@ -50,13 +50,8 @@ impl DfShowCreateDatabase {
         ])
     }
 
-    fn database_name(&self) -> Result<String> {
-        if self.name.0.is_empty() {
-            return Err(ErrorCode::SyntaxException(
-                "Show create database name is empty",
-            ));
-        }
-
-        Ok(self.name.0[0].value.clone())
+    fn database_name(&self, ctx: &QueryContext) -> Result<String> {
+        let case_sensitive = ctx.get_settings().get_unquoted_ident_case_sensitive()?;
+        SQLCommon::resolve_database_name("Show create", &self.name, case_sensitive)
     }
 }
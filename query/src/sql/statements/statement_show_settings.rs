@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_planners::PlanNode;
+use common_planners::PlanShowKind;
 use common_planners::ShowPlan;
 use common_planners::ShowSettingsPlan;
 use common_tracing::tracing;
@@ -23,16 +24,32 @@ use common_tracing::tracing;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfShowKind;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct DfShowSettings;
+pub struct DfShowSettings {
+    pub kind: DfShowKind,
+}
+
+impl DfShowSettings {
+    pub fn create(kind: DfShowKind) -> DfShowSettings {
+        DfShowSettings { kind }
+    }
+}
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfShowSettings {
     #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
     async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let kind = match &self.kind {
+            DfShowKind::All => PlanShowKind::All,
+            DfShowKind::Like(v) => PlanShowKind::Like(format!("{}", v)),
+            DfShowKind::Where(v) => PlanShowKind::Where(format!("{}", v)),
+            DfShowKind::FromOrIn(v) => PlanShowKind::FromOrIn(v.0[0].value.clone()),
+        };
+
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Show(
-            ShowPlan::ShowSettings(ShowSettingsPlan {}),
+            ShowPlan::ShowSettings(ShowSettingsPlan { kind }),
         ))))
     }
 }
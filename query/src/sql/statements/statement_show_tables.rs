@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::PlanShowKind;
@@ -29,18 +30,41 @@ use crate::sql::statements::DfShowKind;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfShowTables {
     pub kind: DfShowKind,
+    pub showfull: bool,
+    pub fromdb: Option<String>,
 }
 
 impl DfShowTables {
-    pub fn create(kind: DfShowKind) -> DfShowTables {
-        DfShowTables { kind }
+    pub fn create(kind: DfShowKind, showfull: bool, fromdb: Option<String>) -> DfShowTables {
+        DfShowTables {
+            kind,
+            showfull,
+            fromdb,
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfShowTables {
-    #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let database = match &self.fromdb {
+            Some(database) => database.clone(),
+            None => ctx.get_current_database(),
+        };
+
+        let tenant = ctx.get_tenant();
+        if !ctx
+            .get_catalog()
+            .exists_database(tenant.as_str(), &database)
+            .await?
+        {
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "Cannot SHOW TABLES FROM '{}', because the '{}' doesn't exist",
+                database, database
+            )));
+        }
+
         let mut kind = PlanShowKind::All;
         match &self.kind {
             DfShowKind::All => {}
@@ -56,7 +80,11 @@ impl AnalyzableStatement for DfShowTables {
         }
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Show(
-            ShowPlan::ShowTables(ShowTablesPlan { kind }),
+            ShowPlan::ShowTables(ShowTablesPlan {
+                kind,
+                showfull: self.showfull,
+                database,
+            }),
         ))))
     }
 }
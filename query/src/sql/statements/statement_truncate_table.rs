@@ -27,6 +27,7 @@ use crate::sql::statements::AnalyzedResult;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfTruncateTable {
+    pub if_exists: bool,
     pub name: ObjectName,
     pub purge: bool,
 }
@@ -38,6 +39,7 @@ impl AnalyzableStatement for DfTruncateTable {
         let (db, table) = self.resolve_table(ctx)?;
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::TruncateTable(TruncateTablePlan {
+                if_exists: self.if_exists,
                 db,
                 table,
                 purge: self.purge,
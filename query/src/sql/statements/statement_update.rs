@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::merge_types;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::UpdatePlan;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use super::statement_delete::verify_row_expression;
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUpdate {
+    pub table_name: ObjectName,
+    pub assignments: Vec<(Ident, Expr)>,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfUpdate {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (db, table) = self.resolve_table(&ctx)?;
+        let schema = ctx.get_table(&db, &table).await?.schema();
+        let expr_analyzer = ExpressionAnalyzer::create(ctx.clone());
+
+        let mut assigned = HashSet::with_capacity(self.assignments.len());
+        let mut assignments = Vec::with_capacity(self.assignments.len());
+        for (column, value) in &self.assignments {
+            let field = schema.field_with_name(&column.value)?;
+            if !assigned.insert(field.name().as_str()) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Column {} is assigned more than once in UPDATE",
+                    field.name()
+                )));
+            }
+
+            let value = expr_analyzer.analyze(value).await?;
+            verify_row_expression(&value, "the SET clause of UPDATE")?;
+            let value = Self::coerce_assignment(value, field, &schema)?;
+            assignments.push((field.name().clone(), value));
+        }
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => {
+                let selection = expr_analyzer.analyze(expr).await?;
+                verify_row_expression(&selection, "the WHERE clause of UPDATE")?;
+
+                // Fail early if the selection refers to unknown columns.
+                selection.to_data_type(&schema)?;
+                Some(selection)
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Update(
+            UpdatePlan {
+                db,
+                table,
+                assignments,
+                selection,
+            },
+        ))))
+    }
+}
+
+impl DfUpdate {
+    fn resolve_table(&self, ctx: &QueryContext) -> Result<(String, String)> {
+        let idents = &self.table_name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Update table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Update table name must be [`db`].`table`",
+            )),
+        }
+    }
+
+    // The value is cast to the type of the column, only if no value can be changed by the cast,
+    // e.g. Int32 to Int64 or Int32 to Nullable(Int32), otherwise CAST is required explicitly.
+    fn coerce_assignment(
+        value: Expression,
+        field: &DataField,
+        schema: &DataSchemaRef,
+    ) -> Result<Expression> {
+        let column_type = field.data_type();
+        let value_type = value.to_data_type(schema)?;
+        if &value_type == column_type {
+            return Ok(value);
+        }
+
+        match merge_types(column_type, &value_type) {
+            Ok(merged) if &merged == column_type => Ok(Expression::Cast {
+                expr: Box::new(value),
+                data_type: column_type.clone(),
+                is_nullable: false,
+            }),
+            _ => Err(ErrorCode::IllegalDataType(format!(
+                "Cannot assign the value of type {} to the column {} of type {} without loss, use CAST to convert it explicitly",
+                value_type.name(),
+                field.name(),
+                column_type.name()
+            ))),
+        }
+    }
+}
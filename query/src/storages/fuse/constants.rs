@@ -19,6 +19,23 @@ pub const TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "block_size_threshold"
 
 pub const TBL_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const TBL_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
+// The comma separated columns identifying the rows replaced by REPLACE INTO.
+pub const TBL_OPT_KEY_PRIMARY_KEY: &str = "primary_key";
+// The files loaded by COPY, cleared by truncate.
+pub const TBL_OPT_KEY_COPIED_FILES: &str = "copied_files";
+// Bumped by every ALTER TABLE which changes the schema.
+pub const TBL_OPT_KEY_SCHEMA_VERSION: &str = "schema_version";
+
+/// Whether the option is maintained by the table itself instead of being given by the user.
+pub fn is_internal_opt_key(key: &str) -> bool {
+    [
+        TBL_OPT_KEY_SNAPSHOT_LOC,
+        TBL_OPT_KEY_COPIED_FILES,
+        TBL_OPT_KEY_SCHEMA_VERSION,
+    ]
+    .contains(&key)
+}
+
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
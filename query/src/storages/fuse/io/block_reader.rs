@@ -22,12 +22,14 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_tracing::tracing;
 use common_tracing::tracing::debug_span;
 use common_tracing::tracing::Instrument;
 use futures::future::BoxFuture;
 use opendal::Operator;
 
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::storages::fuse::io::meta_readers::BlockMetaReader;
 
 pub struct BlockReader {
@@ -81,20 +83,30 @@ impl BlockReader {
         let arrow_fields = &self.arrow_table_schema.fields;
         let stream_len = self.file_len;
         let parquet_fields = metadata.schema().fields();
+        let num_rows = row_group.num_rows() as usize;
 
+        // The block may be written before the table schema was altered, the columns are
+        // matched by name: the added columns are missing in the block, and the dropped
+        // columns are never projected.
+        let mut read_fields = Vec::with_capacity(self.projection.len());
         // read_columns_many_async use field name to filter columns
-        let fields_to_read = self
-            .projection
-            .clone()
-            .into_iter()
-            .map(|idx| {
-                let origin = arrow_fields[idx].clone();
-                Field {
-                    name: parquet_fields[idx].name().to_string(),
-                    ..origin
-                }
-            })
-            .collect();
+        let mut fields_to_read = Vec::with_capacity(self.projection.len());
+        for (idx, field) in self.projection.iter().zip(self.block_schema.fields()) {
+            if let Some(parquet_field) = parquet_fields
+                .iter()
+                .find(|f| f.name() == field.name().as_str())
+            {
+                read_fields.push(field.clone());
+                fields_to_read.push(Field {
+                    name: parquet_field.name().to_string(),
+                    ..arrow_fields[*idx].clone()
+                });
+            }
+        }
+
+        if fields_to_read.is_empty() {
+            return self.fill_missing_columns(DataBlock::empty(), num_rows);
+        }
 
         let factory = || {
             let data_accessor = self.data_accessor.clone();
@@ -112,8 +124,7 @@ impl BlockReader {
             .await
             .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
-        let mut chunks =
-            RowGroupDeserializer::new(column_chunks, row_group.num_rows() as usize, None);
+        let mut chunks = RowGroupDeserializer::new(column_chunks, num_rows, None);
 
         // expect exact one chunk
         let chunk = match chunks.next() {
@@ -121,7 +132,58 @@ impl BlockReader {
             Some(chunk) => chunk.map_err(|e| ErrorCode::ParquetError(e.to_string()))?,
         };
 
-        let block = DataBlock::from_chunk(&self.block_schema, &chunk)?;
-        Ok(block)
+        if read_fields.len() == self.block_schema.fields().len() {
+            return DataBlock::from_chunk(&self.block_schema, &chunk);
+        }
+
+        let block = DataBlock::from_chunk(&DataSchemaRefExt::create(read_fields), &chunk)?;
+        self.fill_missing_columns(block, num_rows)
+    }
+
+    /// Assembles the projected columns, the columns not in the block are filled with
+    /// their default values, or NULL if the column is nullable and has no default.
+    fn fill_missing_columns(&self, block: DataBlock, num_rows: usize) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.block_schema.fields().len());
+        for field in self.block_schema.fields() {
+            let column = match block.schema().has_field(field.name()) {
+                true => block.try_column_by_name(field.name())?.clone(),
+                false => {
+                    let value = Self::default_value(field)?;
+                    field.data_type().create_constant_column(&value, num_rows)?
+                }
+            };
+            columns.push(column);
+        }
+        Ok(DataBlock::create(self.block_schema.clone(), columns))
+    }
+
+    fn default_value(field: &DataField) -> Result<DataValue> {
+        let expr = match field.default_expr() {
+            None => return Ok(field.data_type().default_value()),
+            Some(expr) => serde_json::from_slice::<Expression>(expr)?,
+        };
+        let expression = Expression::Alias(
+            field.name().to_string(),
+            Box::new(Expression::Cast {
+                expr: Box::new(expr),
+                data_type: field.data_type().clone(),
+                is_nullable: field.is_nullable(),
+            }),
+        );
+
+        // The default expression does not refer to any column, evaluate it on a single row.
+        let dummy_schema =
+            DataSchemaRefExt::create(vec![DataField::new("_dummy", u8::to_data_type())]);
+        let dummy_block =
+            DataBlock::create(dummy_schema.clone(), vec![Series::from_data(vec![0u8])]);
+        let executor = ExpressionExecutor::try_create(
+            "block_reader_default_value",
+            dummy_schema,
+            DataSchemaRefExt::create(vec![field.clone()]),
+            vec![expression],
+            true,
+        )?;
+        let block = executor.execute(&dummy_block)?;
+        Ok(block.column(0).get(0))
     }
 }
@@ -115,7 +115,13 @@ impl BlockStreamWriter {
     pub async fn write_block(&mut self, block: DataBlock) -> Result<Option<SegmentInfo>> {
         let mut acc = self.statistics_accumulator.take().unwrap_or_default();
         let partial_acc = acc.begin(&block)?;
-        let schema = block.schema().to_arrow();
+        let mut schema = block.schema().to_arrow();
+        // The columns are read by name, name them after the table schema.
+        if schema.fields.len() == self.data_schema.fields().len() {
+            for (field, table_field) in schema.fields.iter_mut().zip(self.data_schema.fields()) {
+                field.name = table_field.name().clone();
+            }
+        }
         let location = gen_block_location();
         let file_size =
             block_writer::write_block(&schema, block, self.data_accessor.clone(), &location)
@@ -133,6 +139,7 @@ impl BlockStreamWriter {
                     compressed_byte_size: acc.file_size,
                     col_stats: summary,
                 },
+                schema_version: 0,
             };
 
             // Reset state
@@ -192,6 +199,7 @@ impl Regulator<DataBlock, SegmentInfo> for BlockStreamWriter {
                         compressed_byte_size: acc.file_size,
                         col_stats: summary,
                     },
+                    schema_version: 0,
                 };
                 Ok(Some(seg))
             }
@@ -26,4 +26,9 @@ pub struct SegmentInfo {
 
     /// summary statistics
     pub summary: Statistics,
+
+    /// the table schema version the blocks are written with, the column ids of the
+    /// statistics are only valid for this version
+    #[serde(default)]
+    pub schema_version: u64,
 }
@@ -0,0 +1,66 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchSeq;
+use common_meta_types::UpdateTableMetaReq;
+use common_planners::AlterTablePlan;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_SCHEMA_VERSION;
+
+impl FuseTable {
+    /// Only the table meta is changed, the existing blocks are reconciled with the new schema
+    /// when they are read.
+    #[inline]
+    pub async fn do_alter_schema(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: AlterTablePlan,
+    ) -> Result<()> {
+        let table_info = &self.table_info;
+        let schema = plan.alter_schema(table_info.schema().as_ref())?;
+        if let Some(column) = self
+            .primary_key()
+            .iter()
+            .find(|column| !schema.has_field(column))
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Cannot drop the primary key column {} of table {}",
+                column, table_info.name
+            )));
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.schema = Arc::new(schema);
+        new_table_meta.options.insert(
+            TBL_OPT_KEY_SCHEMA_VERSION.to_string(),
+            (self.schema_version() + 1).to_string(),
+        );
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.version),
+            new_table_meta,
+        };
+        ctx.get_catalog().update_table_meta(req).await?;
+        Ok(())
+    }
+}
@@ -31,6 +31,7 @@ use crate::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
 use crate::storages::fuse::TBL_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
+use crate::storages::fuse::TBL_OPT_KEY_SCHEMA_VERSION;
 
 pub type AppendOperationLogEntryStream =
     std::pin::Pin<Box<dyn futures::stream::Stream<Item = Result<AppendOperationLogEntry>> + Send>>;
@@ -47,6 +48,8 @@ impl FuseTable {
         let block_per_seg =
             self.get_option(TBL_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
 
+        let schema_version = self.get_option(TBL_OPT_KEY_SCHEMA_VERSION, 0u64);
+
         let da = ctx.get_storage_operator().await?;
 
         let mut segment_stream = BlockStreamWriter::write_block_stream(
@@ -61,7 +64,8 @@ impl FuseTable {
         let log_entries = stream! {
             while let Some(segment) = segment_stream.next().await {
                 let log_entry_res = match segment {
-                    Ok(seg) => {
+                    Ok(mut seg) => {
+                        seg.schema_version = schema_version;
                         let seg_loc = io::gen_segment_info_location();
                         let bytes = serde_json::to_vec(&seg)?;
                         da.object(&seg_loc)
@@ -80,7 +84,7 @@ impl FuseTable {
         Ok(Box::pin(log_entries))
     }
 
-    fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
+    pub(crate) fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
             .get(opt_key)
@@ -18,6 +18,7 @@ use std::time::Duration;
 use std::time::Instant;
 
 use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
 use backoff::ExponentialBackoffBuilder;
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
@@ -40,6 +41,7 @@ use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::Table;
+use crate::storages::TableOptionsUpdater;
 
 impl FuseTable {
     pub async fn do_commit(
@@ -47,6 +49,7 @@ impl FuseTable {
         ctx: Arc<QueryContext>,
         operation_log: TableOperationLog,
         overwrite: bool,
+        update_options: Option<&TableOptionsUpdater>,
     ) -> Result<()> {
         let tid = self.table_info.ident.table_id;
 
@@ -54,35 +57,11 @@ impl FuseTable {
         let mut latest: Arc<dyn Table>;
 
         let mut retry_times = 0;
-
-        let settings = ctx.get_settings();
-
-        // The initial retry delay in millisecond. By default,  it is 5 ms.
-        let init_delay = Duration::from_millis(settings.get_storage_occ_backoff_init_delay_ms()?);
-
-        // The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing.
-        // By default, it is 20 seconds.
-        let max_delay = Duration::from_millis(settings.get_storage_occ_backoff_max_delay_ms()?);
-
-        // The maximum elapsed time after the occ starts, beyond which there will be no more retries.
-        // By default, it is 2 minutes
-        let max_elapsed = Duration::from_millis(settings.get_storage_occ_backoff_max_elapsed_ms()?);
-
-        // see https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/ for more
-        // informations. (The strategy that crate backoff implements is “Equal Jitter”)
-
-        // To simplify the settings, using fixed common values for randomization_factor and multiplier
-        let mut backoff = ExponentialBackoffBuilder::new()
-            .with_initial_interval(init_delay)
-            .with_max_interval(max_delay)
-            .with_randomization_factor(0.5)
-            .with_multiplier(2.0)
-            .with_max_elapsed_time(Some(max_elapsed))
-            .build();
+        let mut backoff = Self::occ_backoff(ctx.as_ref())?;
 
         loop {
             match tbl
-                .try_commit(ctx.as_ref(), &operation_log, overwrite)
+                .try_commit(ctx.as_ref(), &operation_log, overwrite, update_options)
                 .await
             {
                 Ok(_) => break Ok(()),
@@ -98,21 +77,8 @@ impl FuseTable {
                             );
                             common_base::tokio::time::sleep(d).await;
 
-                            let catalog = ctx.get_catalog();
-                            let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
-                            let table_info: TableInfo = TableInfo {
-                                ident,
-                                desc: "".to_owned(),
-                                name,
-                                meta: meta.as_ref().clone(),
-                            };
-                            latest = catalog.get_table_by_info(&table_info)?;
-                            tbl = latest.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
-                                ErrorCode::LogicalError(format!(
-                                    "expects table engine FUSE, but got {}",
-                                    latest.engine()
-                                ))
-                            })?;
+                            latest = Self::latest_table(ctx.as_ref(), tid, name).await?;
+                            tbl = Self::try_from_table(latest.as_ref())?;
                             retry_times += 1;
                             continue;
                         }
@@ -132,12 +98,67 @@ impl FuseTable {
         }
     }
 
+    /// The backoff of the optimistic concurrency control, the transaction is retried on the
+    /// latest version of the table if it conflicts with the concurrent ones.
+    pub(crate) fn occ_backoff(ctx: &QueryContext) -> Result<ExponentialBackoff> {
+        let settings = ctx.get_settings();
+
+        // The initial retry delay in millisecond. By default,  it is 5 ms.
+        let init_delay = Duration::from_millis(settings.get_storage_occ_backoff_init_delay_ms()?);
+
+        // The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing.
+        // By default, it is 20 seconds.
+        let max_delay = Duration::from_millis(settings.get_storage_occ_backoff_max_delay_ms()?);
+
+        // The maximum elapsed time after the occ starts, beyond which there will be no more retries.
+        // By default, it is 2 minutes
+        let max_elapsed = Duration::from_millis(settings.get_storage_occ_backoff_max_elapsed_ms()?);
+
+        // see https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/ for more
+        // informations. (The strategy that crate backoff implements is “Equal Jitter”)
+
+        // To simplify the settings, using fixed common values for randomization_factor and multiplier
+        Ok(ExponentialBackoffBuilder::new()
+            .with_initial_interval(init_delay)
+            .with_max_interval(max_delay)
+            .with_randomization_factor(0.5)
+            .with_multiplier(2.0)
+            .with_max_elapsed_time(Some(max_elapsed))
+            .build())
+    }
+
+    pub(crate) async fn latest_table(
+        ctx: &QueryContext,
+        table_id: u64,
+        name: String,
+    ) -> Result<Arc<dyn Table>> {
+        let catalog = ctx.get_catalog();
+        let (ident, meta) = catalog.get_table_meta_by_id(table_id).await?;
+        let table_info: TableInfo = TableInfo {
+            ident,
+            desc: "".to_owned(),
+            name,
+            meta: meta.as_ref().clone(),
+        };
+        catalog.get_table_by_info(&table_info)
+    }
+
+    pub(crate) fn try_from_table(table: &dyn Table) -> Result<&FuseTable> {
+        table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "expects table engine FUSE, but got {}",
+                table.engine()
+            ))
+        })
+    }
+
     #[inline]
     pub async fn try_commit(
         &self,
         ctx: &QueryContext,
         operation_log: &TableOperationLog,
         overwrite: bool,
+        update_options: Option<&TableOptionsUpdater>,
     ) -> Result<()> {
         let prev = self.read_table_snapshot(ctx).await?;
         let schema = self.table_info.meta.schema.as_ref().clone();
@@ -171,7 +192,18 @@ impl FuseTable {
             .await
             .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
 
-        Self::commit_to_meta_server(ctx, &self.get_table_info().ident, snapshot_loc).await?;
+        let mut req = UpsertTableOptionReq::new(
+            &self.table_info.ident,
+            TBL_OPT_KEY_SNAPSHOT_LOC,
+            snapshot_loc,
+        );
+        // The options are updated in the same commit as the snapshot, from the options of the
+        // table version committed on.
+        if let Some(update_options) = update_options {
+            req.options
+                .extend(update_options(&self.table_info.meta.options)?);
+        }
+        ctx.get_catalog().upsert_table_option(req).await?;
         ctx.get_dal_context()
             .get_metrics()
             .inc_write_rows(rows_written);
@@ -186,8 +218,12 @@ impl FuseTable {
     ) -> Result<TableSnapshot> {
         // 1. merge stats with previous snapshot, if any
         let stats = if let Some(snapshot) = &previous {
-            let summary = &snapshot.summary;
-            statistics::merge_statistics(schema, &statistics, summary)?
+            let mut summary = snapshot.summary.clone();
+            if snapshot.schema != *schema {
+                // The column ids of the previous statistics refer to the previous schema.
+                summary.col_stats.clear();
+            }
+            statistics::merge_statistics(schema, &statistics, &summary)?
         } else {
             statistics
         };
@@ -209,7 +245,7 @@ impl FuseTable {
         Ok(new_snapshot)
     }
 
-    async fn commit_to_meta_server(
+    pub(crate) async fn commit_to_meta_server(
         ctx: &QueryContext,
         tbl_id: &TableIdent,
         new_snapshot_location: String,
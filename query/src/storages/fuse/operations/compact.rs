@@ -0,0 +1,189 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use backoff::backoff::Backoff;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::io::Regulator;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
+use crate::storages::Table;
+
+impl FuseTable {
+    /// Merges the small blocks of the current snapshot into the blocks of about `row_per_block`
+    /// rows or `block_size_threshold` bytes, and commits them as a new snapshot.
+    ///
+    /// Nothing is removed, the previous snapshots can still be read until they are purged.
+    pub async fn do_compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let tid = self.table_info.ident.table_id;
+
+        let mut tbl = self;
+        let mut latest: Arc<dyn Table>;
+
+        let mut retry_times = 0;
+        let mut backoff = Self::occ_backoff(ctx.as_ref())?;
+
+        // The compaction is done again on the latest snapshot if a concurrent transaction
+        // committed first.
+        loop {
+            match tbl.try_compact(&ctx).await {
+                Ok(_) => break Ok(()),
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            let name = tbl.table_info.name.clone();
+                            tracing::error!(
+                                "got error TableVersionMismatched, compaction will be retried {} ms later. table name {}, identity {}",
+                                d.as_millis(),
+                                name.as_str(),
+                                tbl.table_info.ident
+                            );
+                            common_base::tokio::time::sleep(d).await;
+
+                            latest = Self::latest_table(ctx.as_ref(), tid, name).await?;
+                            tbl = Self::try_from_table(latest.as_ref())?;
+                            retry_times += 1;
+                            continue;
+                        }
+                        None => {
+                            break Err(ErrorCode::OCCRetryFailure(format!(
+                                "can not fulfill the compaction after retries({} times, {} ms), aborted. table name {}, identity {}",
+                                retry_times,
+                                Instant::now().duration_since(backoff.start_time).as_millis(),
+                                tbl.table_info.name.as_str(),
+                                tbl.table_info.ident,
+                            )));
+                        }
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        }
+    }
+
+    async fn try_compact(&self, ctx: &Arc<QueryContext>) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            None => return Ok(()),
+            Some(snapshot) => snapshot,
+        };
+
+        let row_per_block = self.get_option(TBL_OPT_KEY_ROW_PER_BLOCK, DEFAULT_ROW_PER_BLOCK);
+        let block_size = self.get_option(
+            TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+            DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+        );
+        let block_per_segment =
+            self.get_option(TBL_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let is_small = |block: &BlockMeta| {
+            (block.row_count as usize) < row_per_block && (block.block_size as usize) < block_size
+        };
+
+        let schema_version = self.schema_version();
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+
+        // The segments without small blocks are kept as they are, the others are split into
+        // the blocks kept and the blocks to merge.
+        let mut kept_segments = Vec::new();
+        let mut kept_blocks = Vec::new();
+        let mut small_blocks = Vec::new();
+        for location in &snapshot.segments {
+            let segment = segment_reader.read(location).await?;
+            if !segment.blocks.iter().any(is_small) {
+                kept_segments.push((location.clone(), segment));
+            } else if segment.schema_version == schema_version {
+                let (small, kept): (Vec<_>, Vec<_>) =
+                    segment.blocks.iter().cloned().partition(is_small);
+                small_blocks.extend(small);
+                kept_blocks.extend(kept);
+            } else {
+                // The statistics of the blocks refer to the previous schema, all of them are
+                // rewritten with the current schema.
+                small_blocks.extend(segment.blocks.iter().cloned());
+            }
+        }
+
+        if small_blocks.len() < 2 {
+            return Ok(());
+        }
+
+        let operator = ctx.get_storage_operator().await?;
+        let schema = self.table_info.schema();
+        let mut writer = BlockStreamWriter::new(usize::MAX, operator.clone(), schema.clone());
+        let mut accumulated = Vec::new();
+        let mut accumulated_rows = 0;
+        let mut accumulated_size = 0;
+        for block_meta in &small_blocks {
+            let block = self.read_block(ctx, operator.clone(), block_meta).await?;
+            accumulated_rows += block.num_rows();
+            accumulated_size += block.memory_size();
+            accumulated.push(block);
+            if accumulated_rows < row_per_block && accumulated_size < block_size {
+                continue;
+            }
+
+            // The rows beyond `row_per_block` are merged with the following blocks.
+            let merged = DataBlock::concat_blocks(&accumulated)?;
+            accumulated.clear();
+            for block in DataBlock::split_block_by_size(&merged, row_per_block)? {
+                if block.num_rows() < row_per_block && block.memory_size() < block_size {
+                    accumulated.push(block);
+                } else {
+                    writer.write_block(block).await?;
+                }
+            }
+            accumulated_rows = accumulated.iter().map(|b| b.num_rows()).sum();
+            accumulated_size = accumulated.iter().map(|b| b.memory_size()).sum();
+        }
+        if !accumulated.is_empty() {
+            writer
+                .write_block(DataBlock::concat_blocks(&accumulated)?)
+                .await?;
+        }
+        if let Some(segment) = writer.seal()? {
+            kept_blocks.extend(segment.blocks);
+        }
+
+        // The compacted segments are the latest ones, the same as the segments of insertion.
+        let mut segments = Vec::with_capacity(kept_segments.len() + 1);
+        for blocks in kept_blocks.chunks(block_per_segment) {
+            let segment = SegmentInfo {
+                summary: Self::blocks_summary(blocks, schema.as_ref())?,
+                blocks: blocks.to_vec(),
+                schema_version,
+            };
+            let location = Self::write_segment(operator.clone(), &segment).await?;
+            segments.push((location, Arc::new(segment)));
+        }
+        segments.extend(kept_segments);
+
+        self.commit_segments(ctx.as_ref(), operator, Some(snapshot.snapshot_id), segments)
+            .await
+    }
+}
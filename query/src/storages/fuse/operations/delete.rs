@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::TruncateTablePlan;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::invert;
+use crate::storages::fuse::operations::mutation::BlockMutator;
+use crate::storages::fuse::operations::mutation::Matched;
+use crate::storages::fuse::operations::mutation::SelectionFilter;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::BlockStatistics;
+
+impl FuseTable {
+    /// Deletes the rows matching the selection. The blocks that all rows match are dropped,
+    /// the other matched blocks are rewritten without the matching rows.
+    pub async fn do_delete(&self, ctx: Arc<QueryContext>, plan: DeletePlan) -> Result<()> {
+        let selection = match &plan.selection {
+            Some(selection) => selection,
+            None => {
+                // Delete all the rows, the same as TRUNCATE.
+                let truncate_plan = TruncateTablePlan {
+                    if_exists: false,
+                    db: plan.db,
+                    table: plan.table,
+                    purge: false,
+                };
+                return self.do_truncate(ctx, truncate_plan).await;
+            }
+        };
+
+        self.do_mutate(ctx, "delete", |schema| {
+            Ok(DeletionMutator {
+                filter: SelectionFilter::try_create(selection, schema)?,
+            })
+        })
+        .await
+    }
+}
+
+struct DeletionMutator {
+    filter: SelectionFilter,
+}
+
+impl BlockMutator for DeletionMutator {
+    fn check(&self, stats: &BlockStatistics) -> Result<Matched> {
+        self.filter.check(stats)
+    }
+
+    fn drop_all_matched(&self) -> bool {
+        true
+    }
+
+    fn mutate(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let (matched, deleted) = self.filter.eval(block)?;
+        if deleted == 0 {
+            return Ok(None);
+        }
+        Ok(Some(DataBlock::filter_block(block, &invert(&matched))?))
+    }
+}
@@ -12,14 +12,20 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod alter;
 mod append;
 mod commit;
+mod compact;
+mod delete;
+mod mutation;
 mod operation_log;
 mod optimize;
 mod part_info;
 mod read;
 mod read_partitions;
+mod replace;
 mod truncate;
+mod update;
 
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
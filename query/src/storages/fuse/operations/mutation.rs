@@ -0,0 +1,467 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use backoff::backoff::Backoff;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::RequireColumnsVisitor;
+use common_tracing::tracing;
+use opendal::Operator;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::io::Regulator;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::BlockStatistics;
+use crate::storages::index::RangeFilter;
+use crate::storages::Table;
+
+/// Rewrites the rows of the blocks matching a selection, e.g. DELETE and UPDATE.
+pub(crate) trait BlockMutator: Send + Sync {
+    /// Checks the rows of a block or a segment by the statistics.
+    fn check(&self, stats: &BlockStatistics) -> Result<Matched>;
+
+    /// Whether the blocks that all rows match are dropped without being read.
+    fn drop_all_matched(&self) -> bool;
+
+    /// Returns the block after the mutation, None if no row of the block is mutated.
+    fn mutate(&self, block: &DataBlock) -> Result<Option<DataBlock>>;
+
+    /// The segments committed along with the mutation, e.g. the rows of REPLACE INTO.
+    fn appended_segments(&self) -> &[(String, Arc<SegmentInfo>)] {
+        &[]
+    }
+}
+
+impl FuseTable {
+    pub(crate) async fn do_mutate<M, F>(
+        &self,
+        ctx: Arc<QueryContext>,
+        operation: &str,
+        build_mutator: F,
+    ) -> Result<()>
+    where
+        M: BlockMutator,
+        F: Fn(DataSchemaRef) -> Result<M> + Send + Sync,
+    {
+        let tid = self.table_info.ident.table_id;
+
+        let mut tbl = self;
+        let mut latest: Arc<dyn Table>;
+
+        let mut retry_times = 0;
+        let mut backoff = Self::occ_backoff(ctx.as_ref())?;
+
+        // The mutation is evaluated again on the latest snapshot if a concurrent transaction
+        // committed first.
+        loop {
+            let mutator = build_mutator(tbl.table_info.schema())?;
+            match tbl.try_mutate(&ctx, &mutator).await {
+                Ok(_) => break Ok(()),
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => {
+                    match backoff.next_backoff() {
+                        Some(d) => {
+                            let name = tbl.table_info.name.clone();
+                            tracing::error!(
+                                "got error TableVersionMismatched, {} will be retried {} ms later. table name {}, identity {}",
+                                operation,
+                                d.as_millis(),
+                                name.as_str(),
+                                tbl.table_info.ident
+                            );
+                            common_base::tokio::time::sleep(d).await;
+
+                            latest = Self::latest_table(ctx.as_ref(), tid, name).await?;
+                            tbl = Self::try_from_table(latest.as_ref())?;
+                            retry_times += 1;
+                            continue;
+                        }
+                        None => {
+                            break Err(ErrorCode::OCCRetryFailure(format!(
+                                "can not fulfill the {} after retries({} times, {} ms), aborted. table name {}, identity {}",
+                                operation,
+                                retry_times,
+                                Instant::now().duration_since(backoff.start_time).as_millis(),
+                                tbl.table_info.name.as_str(),
+                                tbl.table_info.ident,
+                            )));
+                        }
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        }
+    }
+
+    /// Mutates the blocks of the current snapshot, and commits the result as a new snapshot,
+    /// so the previous one can still be read by time travel.
+    ///
+    /// The blocks that no row matches are kept as they are, the others are rewritten.
+    async fn try_mutate<M: BlockMutator>(
+        &self,
+        ctx: &Arc<QueryContext>,
+        mutator: &M,
+    ) -> Result<()> {
+        let snapshot = self.read_table_snapshot(ctx.as_ref()).await?;
+        let appended = mutator.appended_segments();
+        let (prev_snapshot_id, locations) = match &snapshot {
+            Some(snapshot) => (Some(snapshot.snapshot_id), snapshot.segments.as_slice()),
+            None if appended.is_empty() => return Ok(()),
+            None => (None, &[][..]),
+        };
+
+        let schema = self.table_info.schema();
+        let schema_version = self.schema_version();
+        let operator = ctx.get_storage_operator().await?;
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+
+        let mut mutated = false;
+        // The appended segments are the latest ones, the same as the segments of insertion.
+        let mut segments = appended.to_vec();
+        for location in locations {
+            let segment = segment_reader.read(location).await?;
+            let blocks = match self
+                .mutate_segment(ctx, operator.clone(), mutator, &segment, schema_version)
+                .await?
+            {
+                None => {
+                    segments.push((location.clone(), segment));
+                    continue;
+                }
+                Some(blocks) => blocks,
+            };
+
+            mutated = true;
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let new_segment = SegmentInfo {
+                summary: Self::blocks_summary(&blocks, schema.as_ref())?,
+                blocks,
+                schema_version,
+            };
+            let new_location = Self::write_segment(operator.clone(), &new_segment).await?;
+            segments.push((new_location, Arc::new(new_segment)));
+        }
+
+        if !mutated && appended.is_empty() {
+            return Ok(());
+        }
+        self.commit_segments(ctx.as_ref(), operator, prev_snapshot_id, segments)
+            .await
+    }
+
+    /// Commits the segments as a new snapshot following the previous one.
+    pub(crate) async fn commit_segments(
+        &self,
+        ctx: &QueryContext,
+        operator: Operator,
+        prev_snapshot_id: Option<Uuid>,
+        segments: Vec<(String, Arc<SegmentInfo>)>,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let schema_version = self.schema_version();
+
+        let mut summary = Statistics::default();
+        let mut col_stats = Vec::with_capacity(segments.len());
+        for (_, segment) in &segments {
+            summary.row_count += segment.summary.row_count;
+            summary.block_count += segment.summary.block_count;
+            summary.uncompressed_byte_size += segment.summary.uncompressed_byte_size;
+            summary.compressed_byte_size += segment.summary.compressed_byte_size;
+            // The column ids of the statistics refer to the schema the segment is written with.
+            if segment.schema_version == schema_version {
+                col_stats.push(&segment.summary.col_stats);
+            }
+        }
+        summary.col_stats = statistics::reduce_block_stats(&col_stats, schema.as_ref())?;
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id,
+            schema: schema.as_ref().clone(),
+            summary,
+            segments: segments.into_iter().map(|(location, _)| location).collect(),
+        };
+        let new_snapshot_loc = io::snapshot_location(&new_snapshot.snapshot_id);
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        operator
+            .object(&new_snapshot_loc)
+            .writer()
+            .write_bytes(bytes)
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+
+        Self::commit_to_meta_server(ctx, &self.table_info.ident, new_snapshot_loc).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn write_segment(operator: Operator, segment: &SegmentInfo) -> Result<String> {
+        let location = io::gen_segment_info_location();
+        let bytes = serde_json::to_vec(segment)?;
+        operator
+            .object(&location)
+            .writer()
+            .write_bytes(bytes)
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+        Ok(location)
+    }
+
+    /// Returns the blocks of the segment after the mutation, None if no row of the segment is mutated.
+    async fn mutate_segment<M: BlockMutator>(
+        &self,
+        ctx: &Arc<QueryContext>,
+        operator: Operator,
+        mutator: &M,
+        segment: &SegmentInfo,
+        schema_version: u64,
+    ) -> Result<Option<Vec<BlockMeta>>> {
+        let mut blocks = Vec::with_capacity(segment.blocks.len());
+        let mut rewritten = Vec::new();
+
+        if segment.schema_version == schema_version {
+            match mutator.check(&segment.summary.col_stats)? {
+                Matched::None => return Ok(None),
+                Matched::All if mutator.drop_all_matched() => return Ok(Some(vec![])),
+                _ => {}
+            }
+
+            let mut mutated = false;
+            for block_meta in &segment.blocks {
+                match mutator.check(&block_meta.col_stats)? {
+                    Matched::None => blocks.push(block_meta.clone()),
+                    Matched::All if mutator.drop_all_matched() => mutated = true,
+                    _ => {
+                        let block = self.read_block(ctx, operator.clone(), block_meta).await?;
+                        match mutator.mutate(&block)? {
+                            None => blocks.push(block_meta.clone()),
+                            Some(block) => {
+                                mutated = true;
+                                rewritten.push(block);
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !mutated {
+                return Ok(None);
+            }
+        } else {
+            // The statistics of the segment refer to the previous schema, the blocks are read
+            // to know the rows to mutate. Once a row is mutated, all the blocks are rewritten
+            // with the current schema, the segment is then of the current schema version.
+            let mut mutated = false;
+            for block_meta in &segment.blocks {
+                let block = self.read_block(ctx, operator.clone(), block_meta).await?;
+                match mutator.mutate(&block)? {
+                    None => rewritten.push(block),
+                    Some(block) => {
+                        mutated = true;
+                        rewritten.push(block);
+                    }
+                }
+            }
+
+            if !mutated {
+                return Ok(None);
+            }
+        }
+
+        let schema = self.table_info.schema();
+        let mut writer = BlockStreamWriter::new(usize::MAX, operator, schema);
+        for block in rewritten {
+            if block.num_rows() > 0 {
+                writer.write_block(block).await?;
+            }
+        }
+        if let Some(segment) = writer.seal()? {
+            blocks.extend(segment.blocks);
+        }
+        Ok(Some(blocks))
+    }
+
+    pub(crate) async fn read_block(
+        &self,
+        ctx: &Arc<QueryContext>,
+        operator: Operator,
+        block_meta: &BlockMeta,
+    ) -> Result<DataBlock> {
+        let schema = self.table_info.schema();
+        let projection = (0..schema.fields().len()).collect::<Vec<usize>>();
+        let mut reader = BlockReader::new(
+            operator,
+            block_meta.location.path.clone(),
+            schema,
+            projection,
+            block_meta.file_size,
+            MetaReaders::block_meta_reader(ctx.clone()),
+        );
+        reader.read().await
+    }
+
+    pub(crate) fn blocks_summary(blocks: &[BlockMeta], schema: &DataSchema) -> Result<Statistics> {
+        let mut summary = Statistics::default();
+        for block in blocks {
+            summary.row_count += block.row_count;
+            summary.block_count += 1;
+            summary.uncompressed_byte_size += block.block_size;
+            summary.compressed_byte_size += block.file_size;
+        }
+        let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+        summary.col_stats = statistics::reduce_block_stats(&col_stats, schema)?;
+        Ok(summary)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Matched {
+    /// No row matches the selection.
+    None,
+    /// All rows match the selection.
+    All,
+    /// Unknown by the statistics, the rows need to be evaluated.
+    Some,
+}
+
+pub(crate) struct SelectionFilter {
+    selection: RangeFilter,
+    /// The negation of the selection and the ids of the columns it refers to, None if the
+    /// selection can't be negated exactly.
+    negation: Option<(RangeFilter, Vec<ColumnId>)>,
+    executor: ExpressionExecutor,
+}
+
+impl SelectionFilter {
+    pub(crate) fn try_create(selection: &Expression, schema: DataSchemaRef) -> Result<Self> {
+        let negation = match negate(selection) {
+            None => None,
+            Some(negation) => {
+                let columns = RequireColumnsVisitor::collect_columns_from_expr(selection)?
+                    .iter()
+                    .map(|name| schema.index_of(name).map(|idx| idx as ColumnId))
+                    .collect::<Result<Vec<_>>>()?;
+                Some((RangeFilter::try_create(&negation, schema.clone())?, columns))
+            }
+        };
+
+        let expr_field = selection.to_data_field(&schema)?;
+        let executor = ExpressionExecutor::try_create(
+            "selection expression executor",
+            schema.clone(),
+            DataSchemaRefExt::create(vec![expr_field]),
+            vec![selection.clone()],
+            false,
+        )?;
+
+        Ok(SelectionFilter {
+            selection: RangeFilter::try_create(selection, schema)?,
+            negation,
+            executor,
+        })
+    }
+
+    pub(crate) fn check(&self, stats: &BlockStatistics) -> Result<Matched> {
+        if !self.selection.eval(stats)? {
+            return Ok(Matched::None);
+        }
+
+        // No row satisfies the negation, and the selection is neither NULL for any row.
+        if let Some((negation, columns)) = &self.negation {
+            let no_nulls = columns
+                .iter()
+                .all(|id| stats.get(id).map_or(false, |s| s.null_count == 0));
+            if no_nulls && !negation.eval(stats)? {
+                return Ok(Matched::All);
+            }
+        }
+        Ok(Matched::Some)
+    }
+
+    /// Returns the mask of the rows matching the selection, and the number of them. The rows
+    /// that the selection is NULL for don't match.
+    pub(crate) fn eval(&self, block: &DataBlock) -> Result<(BooleanColumn, usize)> {
+        let matched = self.executor.execute(block)?;
+        let matched = DataBlock::cast_to_nonull_boolean(matched.column(0))?.convert_full_column();
+        let matched: &BooleanColumn = Series::check_get(&matched)?;
+        let count = matched.len() - matched.values().null_count();
+        Ok((matched.clone(), count))
+    }
+}
+
+/// Returns the mask of the rows not matching.
+pub(crate) fn invert(mask: &BooleanColumn) -> ColumnRef {
+    Series::from_data(mask.values().iter().map(|v| !v).collect::<Vec<_>>())
+}
+
+/// Negates the selection made of the comparisons between columns and non-null literals, the
+/// negation is exact for the rows without NULL in these columns.
+fn negate(expr: &Expression) -> Option<Expression> {
+    match expr {
+        Expression::UnaryExpression { op, expr } if op.to_lowercase() == "not" => {
+            negate(expr).map(|_| expr.as_ref().clone())
+        }
+        Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
+            "and" => Some(negate(left)?.or(negate(right)?)),
+            "or" => Some(negate(left)?.and(negate(right)?)),
+            op => {
+                let negated_op = match op {
+                    "=" => "!=",
+                    "!=" | "<>" => "=",
+                    "<" => ">=",
+                    "<=" => ">",
+                    ">" => "<=",
+                    ">=" => "<",
+                    _ => return None,
+                };
+                if !is_comparison_operand(left) || !is_comparison_operand(right) {
+                    return None;
+                }
+                Some(Expression::BinaryExpression {
+                    left: left.clone(),
+                    op: negated_op.to_string(),
+                    right: right.clone(),
+                })
+            }
+        },
+        _ => None,
+    }
+}
+
+fn is_comparison_operand(expr: &Expression) -> bool {
+    match expr {
+        Expression::Column(_) => true,
+        Expression::Literal { value, .. } => !value.is_null(),
+        _ => false,
+    }
+}
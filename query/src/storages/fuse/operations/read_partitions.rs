@@ -40,6 +40,7 @@ impl FuseTable {
             Some(snapshot) => {
                 let schema = self.table_info.schema();
                 let block_metas = BlockPruner::new(&snapshot)
+                    .with_schema_version(self.schema_version())
                     .apply(schema, &push_downs, ctx.as_ref())
                     .await?;
 
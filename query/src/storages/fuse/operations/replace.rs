@@ -0,0 +1,212 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::operations::mutation::BlockMutator;
+use crate::storages::fuse::operations::mutation::Matched;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::BlockStatistics;
+
+impl FuseTable {
+    /// Appends the rows, and deletes the rows of the table having the same primary keys as them
+    /// in the same snapshot. Of the rows having the same primary key, the last one is kept.
+    ///
+    /// The rows are appended once, only the deletion is evaluated again if a concurrent
+    /// transaction committed first, so the rows appended concurrently are replaced as well.
+    ///
+    /// All the rows are collected in memory to keep the last one of each primary key before
+    /// they are appended, the size of a replace is bounded by the memory.
+    pub async fn do_replace(
+        &self,
+        ctx: Arc<QueryContext>,
+        stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        let primary_key = self.primary_key();
+        if primary_key.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "REPLACE INTO needs the primary key of table {}",
+                self.table_info.name
+            )));
+        }
+
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let blocks = blocks
+            .into_iter()
+            .filter(|block| block.num_rows() > 0)
+            .collect::<Vec<_>>();
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        let schema = self.table_info.schema();
+        let rows = DataBlock::concat_blocks(&blocks)?;
+        let columns = Self::primary_key_columns(&primary_key, &schema)?;
+        let (rows, keys) = Self::dedup_by_primary_key(&rows, &columns)?;
+        let bounds = Self::primary_key_bounds(&rows, columns[0]);
+
+        let rows = Box::pin(DataBlockStream::create(schema, None, vec![rows]));
+        let appended = self
+            .append_trunks(ctx.clone(), rows)
+            .await?
+            .map_ok(|entry| (entry.segment_location, Arc::new(entry.segment_info)))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        self.do_mutate(ctx, "replace", |schema| {
+            Ok(ReplaceMutator {
+                columns: Self::primary_key_columns(&primary_key, &schema)?,
+                keys: keys.clone(),
+                bounds: bounds.clone(),
+                appended: appended.clone(),
+            })
+        })
+        .await
+    }
+
+    fn primary_key_columns(primary_key: &[String], schema: &DataSchemaRef) -> Result<Vec<usize>> {
+        primary_key
+            .iter()
+            .map(|name| {
+                schema.index_of(name).map_err(|_| {
+                    ErrorCode::BadArguments(format!(
+                        "Primary key column '{}' does not exist in the table",
+                        name
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Keeps the last one of the rows having the same primary key, returns the rows kept and
+    /// their keys.
+    fn dedup_by_primary_key(
+        rows: &DataBlock,
+        columns: &[usize],
+    ) -> Result<(DataBlock, HashSet<Vec<u8>>)> {
+        let key_columns = columns
+            .iter()
+            .map(|column| rows.column(*column).convert_full_column())
+            .collect::<Vec<_>>();
+        for (column, key_column) in columns.iter().zip(key_columns.iter()) {
+            if (0..key_column.len()).any(|row| key_column.null_at(row)) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Primary key column '{}' can't be NULL in REPLACE INTO",
+                    rows.schema().field(*column).name()
+                )));
+            }
+        }
+
+        let key_columns = key_columns.iter().collect::<Vec<_>>();
+        let row_keys = HashMethodSerializer::default().build_keys(&key_columns, rows.num_rows())?;
+        let mut keys = HashSet::with_capacity(row_keys.len());
+        let mut indices = Vec::with_capacity(row_keys.len());
+        for (row, key) in row_keys.into_iter().enumerate().rev() {
+            if keys.insert(key) {
+                indices.push(row as u32);
+            }
+        }
+        indices.reverse();
+
+        if indices.len() == rows.num_rows() {
+            return Ok((rows.clone(), keys));
+        }
+        Ok((DataBlock::block_take_by_indices(rows, &indices)?, keys))
+    }
+
+    /// The min and max values of the first primary key column, to skip the blocks by statistics.
+    fn primary_key_bounds(rows: &DataBlock, column: usize) -> (DataValue, DataValue) {
+        let column = rows.column(column);
+        let mut min = column.get(0);
+        let mut max = min.clone();
+        for row in 1..column.len() {
+            let value = column.get(row);
+            if value < min {
+                min = value;
+            } else if value > max {
+                max = value;
+            }
+        }
+        (min, max)
+    }
+}
+
+struct ReplaceMutator {
+    /// The indexes of the primary key columns.
+    columns: Vec<usize>,
+    /// The serialized primary keys of the rows replacing.
+    keys: HashSet<Vec<u8>>,
+    bounds: (DataValue, DataValue),
+    appended: Vec<(String, Arc<SegmentInfo>)>,
+}
+
+impl BlockMutator for ReplaceMutator {
+    fn check(&self, stats: &BlockStatistics) -> Result<Matched> {
+        let (min, max) = &self.bounds;
+        match stats.get(&(self.columns[0] as ColumnId)) {
+            Some(stats) if &stats.max < min || &stats.min > max => Ok(Matched::None),
+            _ => Ok(Matched::Some),
+        }
+    }
+
+    fn drop_all_matched(&self) -> bool {
+        false
+    }
+
+    fn mutate(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let key_columns = self
+            .columns
+            .iter()
+            .map(|column| block.column(*column).convert_full_column())
+            .collect::<Vec<_>>();
+        let key_columns = key_columns.iter().collect::<Vec<_>>();
+        let row_keys =
+            HashMethodSerializer::default().build_keys(&key_columns, block.num_rows())?;
+
+        let mut replaced = 0;
+        let mut kept = Vec::with_capacity(row_keys.len());
+        for key in &row_keys {
+            let replacing = self.keys.contains(key);
+            if replacing {
+                replaced += 1;
+            }
+            kept.push(!replacing);
+        }
+        if replaced == 0 {
+            return Ok(None);
+        }
+        Ok(Some(DataBlock::filter_block(
+            block,
+            &Series::from_data(kept),
+        )?))
+    }
+
+    fn appended_segments(&self) -> &[(String, Arc<SegmentInfo>)] {
+        &self.appended
+    }
+}
@@ -26,6 +26,7 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_COPIED_FILES;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 
 impl FuseTable {
@@ -55,13 +56,15 @@ impl FuseTable {
                 let keep_last_snapshot = false;
                 self.do_optimize(ctx.clone(), keep_last_snapshot).await?
             }
-            ctx.get_catalog()
-                .upsert_table_option(UpsertTableOptionReq::new(
-                    &self.table_info.ident,
-                    TBL_OPT_KEY_SNAPSHOT_LOC,
-                    new_snapshot_loc,
-                ))
-                .await?;
+            // The copied files history is removed too, so they can be copied again.
+            let mut req = UpsertTableOptionReq::new(
+                &self.table_info.ident,
+                TBL_OPT_KEY_SNAPSHOT_LOC,
+                new_snapshot_loc,
+            );
+            req.options
+                .insert(TBL_OPT_KEY_COPIED_FILES.to_string(), None);
+            ctx.get_catalog().upsert_table_option(req).await?;
         }
 
         Ok(())
@@ -0,0 +1,128 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::UpdatePlan;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::invert;
+use crate::storages::fuse::operations::mutation::BlockMutator;
+use crate::storages::fuse::operations::mutation::Matched;
+use crate::storages::fuse::operations::mutation::SelectionFilter;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::BlockStatistics;
+
+impl FuseTable {
+    /// Assigns the values to the columns of the rows matching the selection. The matched blocks
+    /// are rewritten, with the updated rows followed by the rows not matching.
+    pub async fn do_update(&self, ctx: Arc<QueryContext>, plan: UpdatePlan) -> Result<()> {
+        self.do_mutate(ctx, "update", |schema| {
+            UpdateMutator::try_create(&plan.assignments, plan.selection.as_ref(), schema)
+        })
+        .await
+    }
+}
+
+struct UpdateMutator {
+    filter: Option<SelectionFilter>,
+    /// The indexes of the assigned columns, in the order of the values evaluated by the executor.
+    columns: Vec<usize>,
+    executor: ExpressionExecutor,
+}
+
+impl UpdateMutator {
+    fn try_create(
+        assignments: &[(String, Expression)],
+        selection: Option<&Expression>,
+        schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let filter = match selection {
+            None => None,
+            Some(selection) => Some(SelectionFilter::try_create(selection, schema.clone())?),
+        };
+
+        let mut columns = Vec::with_capacity(assignments.len());
+        let mut fields = Vec::with_capacity(assignments.len());
+        let mut values = Vec::with_capacity(assignments.len());
+        for (column, value) in assignments {
+            columns.push(schema.index_of(column)?);
+            fields.push(value.to_data_field(&schema)?);
+            values.push(value.clone());
+        }
+
+        let executor = ExpressionExecutor::try_create(
+            "update expression executor",
+            schema,
+            DataSchemaRefExt::create(fields),
+            values,
+            false,
+        )?;
+
+        Ok(UpdateMutator {
+            filter,
+            columns,
+            executor,
+        })
+    }
+}
+
+impl BlockMutator for UpdateMutator {
+    fn check(&self, stats: &BlockStatistics) -> Result<Matched> {
+        match &self.filter {
+            None => Ok(Matched::All),
+            Some(filter) => filter.check(stats),
+        }
+    }
+
+    fn drop_all_matched(&self) -> bool {
+        false
+    }
+
+    fn mutate(&self, block: &DataBlock) -> Result<Option<DataBlock>> {
+        let (matched, unmatched) = match &self.filter {
+            None => (block.clone(), None),
+            Some(filter) => {
+                let (mask, updated) = filter.eval(block)?;
+                if updated == 0 {
+                    return Ok(None);
+                }
+                if updated == block.num_rows() {
+                    (block.clone(), None)
+                } else {
+                    let matched = DataBlock::filter_block(block, &mask.arc())?;
+                    let unmatched = DataBlock::filter_block(block, &invert(&mask))?;
+                    (matched, Some(unmatched))
+                }
+            }
+        };
+
+        let values = self.executor.execute(&matched)?;
+        let mut columns = matched.columns().to_vec();
+        for (i, column) in self.columns.iter().enumerate() {
+            columns[*column] = values.column(i).convert_full_column();
+        }
+        let updated = DataBlock::create(block.schema().clone(), columns);
+
+        match unmatched {
+            None => Ok(Some(updated)),
+            Some(unmatched) => Ok(Some(DataBlock::concat_blocks(&[updated, unmatched])?)),
+        }
+    }
+}
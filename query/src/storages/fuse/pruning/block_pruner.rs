@@ -31,6 +31,7 @@ use crate::storages::index::RangeFilter;
 
 pub struct BlockPruner {
     table_snapshot_location: String,
+    schema_version: u64,
 }
 
 type Pred = Box<dyn Fn(&BlockStatistics) -> Result<bool> + Send + Sync + Unpin>;
@@ -38,9 +39,16 @@ impl BlockPruner {
     pub fn new(table_snapshot: &TableSnapshot) -> Self {
         Self {
             table_snapshot_location: snapshot_location(&table_snapshot.snapshot_id),
+            schema_version: 0,
         }
     }
 
+    /// The schema version of the table, segments written with another version are not pruned.
+    pub fn with_schema_version(mut self, schema_version: u64) -> Self {
+        self.schema_version = schema_version;
+        self
+    }
+
     #[tracing::instrument(level = "debug", skip_all, fields(ctx.id = ctx.get_id().as_str()))]
     pub async fn apply(
         &self,
@@ -70,6 +78,10 @@ impl BlockPruner {
             .map(|seg_loc| async {
                 let reader = MetaReaders::segment_info_reader(ctx);
                 let segment_info = reader.read(seg_loc).await?;
+                if segment_info.schema_version != self.schema_version {
+                    // The column ids of the statistics refer to the previous schema.
+                    return Ok(segment_info.blocks.clone());
+                }
                 Self::filter_segment(segment_info.as_ref(), &block_pred)
             })
             // configuration of the max size of buffered futures
@@ -21,11 +21,14 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::StreamExt;
@@ -34,10 +37,13 @@ use crate::sessions::QueryContext;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::TBL_OPT_KEY_PRIMARY_KEY;
+use crate::storages::fuse::TBL_OPT_KEY_SCHEMA_VERSION;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
+use crate::storages::TableOptionsUpdater;
 
 pub struct FuseTable {
     pub(crate) table_info: TableInfo,
@@ -118,7 +124,27 @@ impl Table for FuseTable {
             .iter()
             .map(AppendOperationLogEntry::try_from)
             .collect::<Result<Vec<AppendOperationLogEntry>>>()?;
-        self.do_commit(ctx, append_log_entries, overwrite).await
+        self.do_commit(ctx, append_log_entries, overwrite, None)
+            .await
+    }
+
+    async fn commit_insertion_with_options(
+        &self,
+        ctx: Arc<QueryContext>,
+        operations: Vec<DataBlock>,
+        overwrite: bool,
+        update_options: &TableOptionsUpdater,
+    ) -> Result<()> {
+        let append_log_entries = operations
+            .iter()
+            .map(AppendOperationLogEntry::try_from)
+            .collect::<Result<Vec<AppendOperationLogEntry>>>()?;
+        self.do_commit(ctx, append_log_entries, overwrite, Some(update_options))
+            .await
+    }
+
+    async fn replace(&self, ctx: Arc<QueryContext>, stream: SendableDataBlockStream) -> Result<()> {
+        self.do_replace(ctx, stream).await
     }
 
     async fn truncate(
@@ -129,9 +155,25 @@ impl Table for FuseTable {
         self.do_truncate(ctx, truncate_plan).await
     }
 
+    async fn delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, delete_plan).await
+    }
+
+    async fn update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, update_plan).await
+    }
+
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.do_optimize(ctx, keep_last_snapshot).await
     }
+
+    async fn compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.do_compact(ctx).await
+    }
+
+    async fn alter_schema(&self, ctx: Arc<QueryContext>, plan: AlterTablePlan) -> Result<()> {
+        self.do_alter_schema(ctx, plan).await
+    }
 }
 
 impl FuseTable {
@@ -142,6 +184,27 @@ impl FuseTable {
             .cloned()
     }
 
+    /// The version of the table schema, 0 if the schema has never been altered.
+    pub(crate) fn schema_version(&self) -> u64 {
+        self.table_info
+            .options()
+            .get(TBL_OPT_KEY_SCHEMA_VERSION)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(0)
+    }
+
+    /// The names of the primary key columns, empty if the table has no primary key.
+    pub(crate) fn primary_key(&self) -> Vec<String> {
+        match self.table_info.options().get(TBL_OPT_KEY_PRIMARY_KEY) {
+            None => vec![],
+            Some(columns) => columns
+                .split(',')
+                .map(|column| column.trim().to_string())
+                .filter(|column| !column.is_empty())
+                .collect(),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub(crate) async fn read_table_snapshot(
         &self,
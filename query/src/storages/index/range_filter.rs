@@ -108,6 +108,15 @@ pub fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
+        Expression::ScalarFunction { op, args }
+            if op.to_lowercase().as_str() == "if" && args.len() == 3 =>
+        {
+            // if(cond, a, b) => (cond and a) or b
+            let cond = build_verifiable_expr(&args[0], schema, stat_columns);
+            let left = build_verifiable_expr(&args[1], schema, stat_columns);
+            let right = build_verifiable_expr(&args[2], schema, stat_columns);
+            return cond.and(left).or(right);
+        }
         Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
@@ -138,10 +147,9 @@ fn inverse_operator(op: &str) -> Result<&str> {
         "<=" => Ok(">="),
         ">" => Ok("<"),
         ">=" => Ok("<="),
-        "like" | "not like" | "ilike" | "not ilike" => Err(ErrorCode::UnknownException(format!(
-            "cannot inverse the operator: {:?}",
-            op
-        ))),
+        "like" | "not like" | "ilike" | "not ilike" | "startswith" => Err(
+            ErrorCode::UnknownException(format!("cannot inverse the operator: {:?}", op)),
+        ),
         _ => Ok(op),
     }
 }
@@ -465,20 +473,28 @@ impl<'a> VerifiableExprBuilder<'a> {
                     // e.g. col like 'a%' => max_col >= 'a' and min_col < 'b'
                     let left = left_bound_for_like_pattern(v);
                     if !left.is_empty() {
-                        let right = right_bound_for_like_pattern(left.clone());
-                        let max_expr = self.max_column_expr(0)?;
-                        if right.is_empty() {
-                            return Ok(max_expr.gt_eq(lit(left)));
-                        } else {
-                            let min_expr = self.min_column_expr(0)?;
-                            return Ok(max_expr.gt_eq(lit(left)).and(min_expr.lt(lit(right))));
-                        }
+                        return self.prefix_expr(left);
                     }
                 }
                 Err(ErrorCode::UnknownException(
                     "Cannot build atom expression by the operator: like",
                 ))
             }
+            "startswith" => {
+                if let Expression::Literal {
+                    value: DataValue::String(v),
+                    ..
+                } = &self.args[1]
+                {
+                    // e.g. startswith(col, 'a') => max_col >= 'a' and min_col < 'b'
+                    if !v.is_empty() {
+                        return self.prefix_expr(v.clone());
+                    }
+                }
+                Err(ErrorCode::UnknownException(
+                    "Cannot build atom expression by the operator: startswith",
+                ))
+            }
             "not like" => {
                 if let Expression::Literal {
                     value: DataValue::String(v),
@@ -526,6 +542,18 @@ impl<'a> VerifiableExprBuilder<'a> {
         }
     }
 
+    // The values with the prefix are in the range [prefix, next prefix).
+    fn prefix_expr(&mut self, prefix: Vec<u8>) -> Result<Expression> {
+        let right = right_bound_for_like_pattern(prefix.clone());
+        let max_expr = self.max_column_expr(0)?;
+        if right.is_empty() {
+            Ok(max_expr.gt_eq(lit(prefix)))
+        } else {
+            let min_expr = self.min_column_expr(0)?;
+            Ok(max_expr.gt_eq(lit(prefix)).and(min_expr.lt(lit(right))))
+        }
+    }
+
     fn stat_column_expr(&mut self, stat_type: StatType, index: usize) -> Result<Expression> {
         let (data_field, column_fields) = self.fields[index].clone();
         let stat_col = StatColumn::create(
@@ -19,6 +19,7 @@ pub mod index;
 pub mod memory;
 pub mod null;
 pub mod system;
+pub mod view;
 
 mod storage_context;
 mod storage_factory;
@@ -30,4 +31,5 @@ pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageDescription;
 pub use storage_factory::StorageFactory;
 pub use storage_table::Table;
+pub use storage_table::TableOptionsUpdater;
 pub use storage_table_read_plan::ToReadDataSourcePlan;
@@ -20,8 +20,12 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::MatchSeq;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_meta_types::UpsertTableOptionReq;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Part;
@@ -29,11 +33,18 @@ use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 
+use crate::catalogs::Catalog;
 use crate::pipelines::new::NewPipeline;
 use crate::sessions::QueryContext;
 
+/// Computes the table options to update from the current options of the table,
+/// `None` removes the option.
+pub type TableOptionsUpdater =
+    dyn Fn(&HashMap<String, String>) -> Result<HashMap<String, Option<String>>> + Send + Sync;
+
 #[async_trait::async_trait]
 pub trait Table: Sync + Send {
     fn name(&self) -> &str {
@@ -122,18 +133,89 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Commits the insertion and updates the table options, the updated options are computed from
+    /// the options of the table version committed on, which may be changed by the concurrent commits.
+    ///
+    /// The engines not committing the data by the table meta update the options after the data.
+    async fn commit_insertion_with_options(
+        &self,
+        ctx: Arc<QueryContext>,
+        operations: Vec<DataBlock>,
+        overwrite: bool,
+        update_options: &TableOptionsUpdater,
+    ) -> Result<()> {
+        self.commit_insertion(ctx.clone(), operations, overwrite)
+            .await?;
+
+        let catalog = ctx.get_catalog();
+        loop {
+            let (ident, meta) = catalog.get_table_meta_by_id(self.get_id()).await?;
+            let req = UpsertTableOptionReq {
+                table_id: ident.table_id,
+                seq: MatchSeq::Exact(ident.version),
+                options: update_options(&meta.options)?,
+            };
+            match catalog.upsert_table_option(req).await {
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => continue,
+                res => return res.map(|_| ()),
+            }
+        }
+    }
+
+    /// Appends the rows, replacing the rows having the same primary keys as them.
+    async fn replace(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _stream: SendableDataBlockStream,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "replace for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.engine()
+        )))
+    }
+
     async fn truncate(
         &self,
         _ctx: Arc<QueryContext>,
         _truncate_plan: TruncateTablePlan,
     ) -> Result<()> {
         Err(ErrorCode::UnImplement(format!(
-            "truncate for table {} is not implemented",
-            self.name()
+            "truncate for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.engine()
+        )))
+    }
+
+    async fn delete(&self, _ctx: Arc<QueryContext>, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.engine()
+        )))
+    }
+
+    async fn update(&self, _ctx: Arc<QueryContext>, _update_plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.engine()
         )))
     }
 
     async fn optimize(&self, _ctx: Arc<QueryContext>, _keep_last_snapshot: bool) -> Result<()> {
         Ok(())
     }
+
+    async fn compact(&self, _ctx: Arc<QueryContext>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn alter_schema(&self, _ctx: Arc<QueryContext>, _plan: AlterTablePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "alter schema for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.engine()
+        )))
+    }
 }
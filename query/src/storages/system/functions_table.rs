@@ -64,13 +64,39 @@ impl AsyncSystemTable for FunctionsTable {
             .map(|i| i >= func_names.len() && i < builtin_func_len)
             .collect::<Vec<bool>>();
 
+        // The typed UDF shows its signature, e.g. (x INT) RETURNS INT AS (x + 1).
+        let udf_definitions = udfs
+            .iter()
+            .map(|udf| match &udf.return_type {
+                None => udf.definition.clone(),
+                Some(return_type) => format!(
+                    "({}) RETURNS {} {}",
+                    udf.parameters
+                        .iter()
+                        .zip(udf.parameter_types.iter())
+                        .map(|(parameter, data_type)| format!("{} {}", parameter, data_type))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    return_type,
+                    match &udf.python_handler {
+                        None => format!("AS {}", udf.definition),
+                        Some(handler) => format!(
+                            "LANGUAGE python FILE = '{}' HANDLER = '{}'",
+                            handler.file, handler.function
+                        ),
+                    }
+                ),
+            })
+            .collect::<Vec<_>>();
+
         let definitions = (0..names.len())
             .map(|i| {
                 if i < builtin_func_len {
                     ""
                 } else {
-                    udfs.get(i - builtin_func_len)
-                        .map_or("", |udf| udf.definition.as_str())
+                    udf_definitions
+                        .get(i - builtin_func_len)
+                        .map_or("", |definition| definition.as_str())
                 }
             })
             .collect::<Vec<&str>>();
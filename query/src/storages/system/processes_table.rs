@@ -56,6 +56,10 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_dal_metrics_write_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_time = Vec::with_capacity(processes_info.len());
+        let mut processes_command = Vec::with_capacity(processes_info.len());
+        let mut processes_info_text = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
@@ -76,6 +80,12 @@ impl SyncSystemTable for ProcessesTable {
                 ProcessesTable::process_scan_progress_values(&process_info.scan_progress_value);
             processes_scan_progress_read_rows.push(scan_progress_read_rows);
             processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
+            processes_query_id.push(process_info.query_id.clone().map(|s| s.into_bytes()));
+            processes_time.push(process_info.elapsed.map(|d| d.as_secs()));
+            processes_command.push(ProcessesTable::process_command(&process_info.state));
+            processes_info_text.push(ProcessesTable::process_info_text(
+                &process_info.session_extra_info,
+            ));
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -91,6 +101,10 @@ impl SyncSystemTable for ProcessesTable {
             Series::from_data(processes_dal_metrics_write_bytes),
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
+            Series::from_data(processes_query_id),
+            Series::from_data(processes_time),
+            Series::from_data(processes_command),
+            Series::from_data(processes_info_text),
         ]))
     }
 }
@@ -110,6 +124,10 @@ impl ProcessesTable {
             DataField::new_nullable("dal_metrics_write_bytes", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
+            DataField::new_nullable("query_id", Vu8::to_data_type()),
+            DataField::new_nullable("time", u64::to_data_type()),
+            DataField::new("command", Vu8::to_data_type()),
+            DataField::new_nullable("info", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -139,6 +157,21 @@ impl ProcessesTable {
         session_extra_info.clone().map(|s| s.into_bytes())
     }
 
+    // The command of the session in the MySQL style, `Sleep` if it's idle.
+    fn process_command(state: &str) -> Vec<u8> {
+        match state {
+            "Idle" => b"Sleep".to_vec(),
+            _ => b"Query".to_vec(),
+        }
+    }
+
+    // The text of the query, truncated to the first 100 characters.
+    fn process_info_text(session_extra_info: &Option<String>) -> Option<Vec<u8>> {
+        session_extra_info
+            .as_ref()
+            .map(|s| s.chars().take(100).collect::<String>().into_bytes())
+    }
+
     fn process_dal_metrics(dal_metrics_opt: &Option<DalMetrics>) -> (Option<u64>, Option<u64>) {
         if dal_metrics_opt.is_some() {
             let dal_metrics = dal_metrics_opt.as_ref().unwrap();
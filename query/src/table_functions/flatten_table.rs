@@ -0,0 +1,353 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::extract_value_by_path;
+use common_functions::scalars::parse_json_path;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use serde_json::Value;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FLATTEN_FUNC: &str = "flatten";
+
+// The positional order of the arguments.
+const FLATTEN_ARGS: [&str; 5] = ["input", "path", "recursive", "mode", "outer"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+impl FromStr for FlattenMode {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "OBJECT" => Ok(FlattenMode::Object),
+            "ARRAY" => Ok(FlattenMode::Array),
+            "BOTH" => Ok(FlattenMode::Both),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown FLATTEN mode: {:?}, must be one of {{ OBJECT | ARRAY | BOTH }}",
+                s
+            ))),
+        }
+    }
+}
+
+impl FlattenMode {
+    fn expand_object(&self) -> bool {
+        *self != FlattenMode::Array
+    }
+
+    fn expand_array(&self) -> bool {
+        *self != FlattenMode::Object
+    }
+
+    // Whether the value is a non-empty container expanded by this mode.
+    fn is_expandable(&self, value: &Value) -> bool {
+        match value {
+            Value::Array(values) => self.expand_array() && !values.is_empty(),
+            Value::Object(values) => self.expand_object() && !values.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+struct FlattenRow {
+    key: Option<String>,
+    path: String,
+    index: Option<u64>,
+    value: Option<String>,
+    this: String,
+}
+
+/// FLATTEN(input => '<json>', path => '<path>', recursive => <bool>, mode => '<mode>', outer => <bool>)
+/// explodes the JSON arrays and objects into rows.
+pub struct FlattenTable {
+    table_info: TableInfo,
+    table_args: Vec<Expression>,
+    input: Value,
+    path: String,
+    recursive: bool,
+    mode: FlattenMode,
+    outer: bool,
+}
+
+impl FlattenTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let table_args = table_args.unwrap_or_default();
+
+        let mut args: [Option<DataValue>; 5] = Default::default();
+        for (position, arg) in table_args.iter().enumerate() {
+            let (name, expr) = match arg {
+                Expression::Alias(name, expr) => (name.to_lowercase(), expr.as_ref()),
+                expr if position < FLATTEN_ARGS.len() => (FLATTEN_ARGS[position].to_string(), expr),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Table function {} expects at most {} arguments, but got {}",
+                        table_func_name,
+                        FLATTEN_ARGS.len(),
+                        table_args.len()
+                    )))
+                }
+            };
+
+            let index = FLATTEN_ARGS
+                .iter()
+                .position(|v| *v == name)
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Unknown argument {} of table function {}",
+                        name, table_func_name
+                    ))
+                })?;
+
+            if args[index].is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicate argument {} of table function {}",
+                    name, table_func_name
+                )));
+            }
+
+            args[index] = match expr {
+                Expression::Literal { value, .. } => Some(value.clone()),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Argument {} of table function {} must be a constant, but got {:?}",
+                        name, table_func_name, expr
+                    )))
+                }
+            };
+        }
+
+        let [input, path, recursive, mode, outer] = args;
+        let input = match input {
+            None => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Table function {} requires the input argument",
+                    table_func_name
+                )))
+            }
+            Some(value) if value.is_null() => Value::Null,
+            Some(value) => serde_json::from_slice(&value.as_string()?).map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Table function {} got invalid JSON input: {}",
+                    table_func_name, e
+                ))
+            })?,
+        };
+
+        let path = match path {
+            None => "".to_string(),
+            Some(value) => String::from_utf8(value.as_string()?)
+                .map_err(|e| ErrorCode::BadArguments(format!("Invalid path. {}", e)))?,
+        };
+        let recursive = match recursive {
+            None => false,
+            Some(value) => value.as_bool()?,
+        };
+        let mode = match mode {
+            None => FlattenMode::Both,
+            Some(value) => FlattenMode::from_str(&String::from_utf8_lossy(&value.as_string()?))?,
+        };
+        let outer = match outer {
+            None => false,
+            Some(value) => value.as_bool()?,
+        };
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("seq", u64::to_data_type()),
+            DataField::new_nullable("key", Vu8::to_data_type()),
+            DataField::new("path", Vu8::to_data_type()),
+            DataField::new_nullable("index", u64::to_data_type()),
+            DataField::new_nullable("value", VariantType::arc()),
+            DataField::new("this", VariantType::arc()),
+        ]);
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: FLATTEN_FUNC.to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(FlattenTable {
+            table_info,
+            table_args,
+            input,
+            path,
+            recursive,
+            mode,
+            outer,
+        }))
+    }
+
+    fn flatten(&self, this: &Value, path: &str, rows: &mut Vec<FlattenRow>) {
+        let mut expand = |key: Option<&String>, index: Option<usize>, value: &Value| {
+            let child_path = match (key, index) {
+                (Some(key), _) if path.is_empty() => key.clone(),
+                (Some(key), _) => format!("{}.{}", path, key),
+                (None, Some(index)) => format!("{}[{}]", path, index),
+                (None, None) => unreachable!(),
+            };
+
+            match self.recursive && self.mode.is_expandable(value) {
+                true => self.flatten(value, &child_path, rows),
+                false => rows.push(FlattenRow {
+                    key: key.cloned(),
+                    path: child_path,
+                    index: index.map(|v| v as u64),
+                    value: Some(value.to_string()),
+                    this: this.to_string(),
+                }),
+            }
+        };
+
+        match this {
+            Value::Array(values) if self.mode.expand_array() => {
+                for (index, value) in values.iter().enumerate() {
+                    expand(None, Some(index), value);
+                }
+            }
+            Value::Object(values) if self.mode.expand_object() => {
+                for (key, value) in values.iter() {
+                    expand(Some(key), None, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn flatten_to_block(&self) -> Result<DataBlock> {
+        let (this, path) = match self.path.is_empty() {
+            true => (Some(&self.input), "".to_string()),
+            false => {
+                let elements = parse_json_path(self.path.as_bytes())?;
+                let path = self.path.trim_start_matches('$').trim_start_matches('.');
+                (
+                    extract_value_by_path(&self.input, &elements),
+                    path.to_string(),
+                )
+            }
+        };
+
+        let mut rows = vec![];
+        if let Some(this) = this {
+            self.flatten(this, &path, &mut rows);
+        }
+
+        // A row with NULL key, index and value for the zero-row expansions.
+        if rows.is_empty() && self.outer {
+            rows.push(FlattenRow {
+                key: None,
+                path,
+                index: None,
+                value: None,
+                this: this.unwrap_or(&Value::Null).to_string(),
+            });
+        }
+
+        let len = rows.len();
+        let mut keys: Vec<Option<Vec<u8>>> = Vec::with_capacity(len);
+        let mut paths: Vec<String> = Vec::with_capacity(len);
+        let mut indexes: Vec<Option<u64>> = Vec::with_capacity(len);
+        let mut values: Vec<Option<Vec<u8>>> = Vec::with_capacity(len);
+        let mut thises: Vec<String> = Vec::with_capacity(len);
+        for row in rows {
+            keys.push(row.key.map(String::into_bytes));
+            paths.push(row.path);
+            indexes.push(row.index);
+            values.push(row.value.map(String::into_bytes));
+            thises.push(row.this);
+        }
+
+        // There is only one input, the sequence number is always 1.
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(vec![1u64; len]),
+            Series::from_data(keys),
+            Series::from_data(paths),
+            Series::from_data(indexes),
+            Series::from_data(values),
+            Series::from_data(thises),
+        ]))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FlattenTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(self.table_args.clone())
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let blocks = vec![self.flatten_to_block()?];
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+}
+
+impl TableFunction for FlattenTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
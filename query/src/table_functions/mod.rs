@@ -13,14 +13,20 @@
 //  limitations under the License.
 //
 
+mod flatten_table;
 mod memory_block_part;
 mod numbers_stream;
 mod numbers_table;
 mod table_function;
 mod table_function_factory;
+mod values_table;
 
+pub use flatten_table::FlattenTable;
+pub use flatten_table::FLATTEN_FUNC;
 pub use memory_block_part::generate_block_parts;
 pub use numbers_table::NumbersTable;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableArgs;
 pub use table_function_factory::TableFunctionFactory;
+pub use values_table::ValuesTable;
+pub use values_table::VALUES_FUNC;
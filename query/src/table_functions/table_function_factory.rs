@@ -26,8 +26,12 @@ use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
 use crate::storages::fuse::FuseHistoryTable;
 use crate::storages::fuse::FUSE_FUNC_HIST;
+use crate::table_functions::FlattenTable;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
+use crate::table_functions::ValuesTable;
+use crate::table_functions::FLATTEN_FUNC;
+use crate::table_functions::VALUES_FUNC;
 
 pub type TableArgs = Option<Vec<Expression>>;
 type TableFunctionCreators = RwLock<HashMap<String, (MetaId, Arc<dyn TableFunctionCreator>)>>;
@@ -100,6 +104,16 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseHistoryTable::create)),
         );
 
+        creators.insert(
+            FLATTEN_FUNC.to_string(),
+            (next_id(), Arc::new(FlattenTable::create)),
+        );
+
+        creators.insert(
+            VALUES_FUNC.to_string(),
+            (next_id(), Arc::new(ValuesTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -0,0 +1,177 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const VALUES_FUNC: &str = "values";
+
+/// The rows of a VALUES list in FROM clause, e.g. `(VALUES (1, 'a'), (2, 'b')) AS t(id, name)`.
+///
+/// Each row is an argument of struct literal, the names and types of the struct are the columns.
+pub struct ValuesTable {
+    table_info: TableInfo,
+    table_args: Vec<Expression>,
+    rows: Vec<Vec<DataValue>>,
+}
+
+impl ValuesTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let table_args = table_args.unwrap_or_default();
+
+        let mut struct_type = None;
+        let mut rows = Vec::with_capacity(table_args.len());
+        for table_arg in &table_args {
+            match table_arg {
+                Expression::Literal {
+                    value: DataValue::Struct(values),
+                    data_type,
+                    ..
+                } if data_type.data_type_id() == TypeID::Struct => {
+                    let row_type = data_type.as_any().downcast_ref::<StructType>().unwrap();
+                    let first_type = struct_type.get_or_insert_with(|| row_type.clone());
+                    if first_type.names() != row_type.names()
+                        || first_type.types() != row_type.types()
+                    {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The rows of table function {} must have the same columns",
+                            table_func_name
+                        )));
+                    }
+                    rows.push(values.clone());
+                }
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                    "Argument of table function {} must be a row of struct literal, but got {:?}",
+                    table_func_name, table_arg
+                )))
+                }
+            }
+        }
+
+        let struct_type = struct_type.ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Table function {} requires at least one row",
+                table_func_name
+            ))
+        })?;
+
+        let fields = struct_type
+            .names()
+            .iter()
+            .zip(struct_type.types().iter())
+            .map(|(name, data_type)| DataField::new(name, data_type.clone()))
+            .collect::<Vec<_>>();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: DataSchemaRefExt::create(fields),
+                engine: VALUES_FUNC.to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(ValuesTable {
+            table_info,
+            table_args,
+            rows,
+        }))
+    }
+
+    fn values_to_block(&self) -> Result<DataBlock> {
+        let schema = self.table_info.schema();
+        let mut builders = schema
+            .fields()
+            .iter()
+            .map(|field| field.data_type().create_mutable(self.rows.len()))
+            .collect::<Vec<_>>();
+
+        for row in &self.rows {
+            for (builder, value) in builders.iter_mut().zip(row.iter()) {
+                builder.append_data_value(value.clone())?;
+            }
+        }
+
+        let columns = builders
+            .iter_mut()
+            .map(|builder| builder.to_column())
+            .collect::<Vec<_>>();
+        Ok(DataBlock::create(schema, columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ValuesTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(self.table_args.clone())
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let blocks = vec![self.values_to_block()?];
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+}
+
+impl TableFunction for ValuesTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
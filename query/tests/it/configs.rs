@@ -75,6 +75,9 @@ table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
 management_mode = false
 jwt_key_file = \"\"
+python_udf_executable = \"python3\"
+python_udf_dir = \"\"
+python_udf_timeout_millis = 10000
 
 [log]
 log_level = \"INFO\"
@@ -0,0 +1,660 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_sorted_eq;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use common_planners::PlanNode;
+use databend_query::interpreters::CopyInterpreter;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_copy_from_internal_stage_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    // Create the internal stage.
+    let stage_info = UserStageInfo {
+        stage_name: "mystage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    let prefix = stage_info.get_prefix();
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    // Put the files into the stage.
+    let operator = ctx.get_storage_operator().await?;
+    let files = vec![
+        ("data/a.csv", "1,a\n2,b\n"),
+        ("data/b.csv", "3,c\n"),
+        ("data/c.txt", "4,d\n"),
+    ];
+    for (file, content) in files {
+        operator
+            .object(&format!("{}{}", prefix, file))
+            .writer()
+            .write_bytes(content.as_bytes().to_vec())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b String)", db),
+    )
+    .await?;
+
+    execute_command(
+        ctx.clone(),
+        &format!(
+            "copy into {}.t from '@mystage/data' pattern = '.*[.]csv' file_format = (type = csv)",
+            db
+        ),
+    )
+    .await?;
+
+    let stream = execute_query(ctx.clone(), &format!("select * from {}.t", db)).await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | a |",
+        "| 2 | b |",
+        "| 3 | c |",
+        "+---+---+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_into_stage_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let stage_info = UserStageInfo {
+        stage_name: "unload_stage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b String)", db),
+    )
+    .await?;
+    execute_command(
+        ctx.clone(),
+        &format!(
+            "insert into {}.t values(1, 'a'), (2, 'b,c'), (3, 'd\"e')",
+            db
+        ),
+    )
+    .await?;
+
+    // Export the table to the stage, split into files of at most 10 bytes.
+    {
+        let query = format!(
+            "copy into '@unload_stage/csv' from {}.t file_format = (type = csv) max_file_size = 10",
+            db
+        );
+        let stream = execute_query(ctx.clone(), &query).await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let files = result.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(files, 3);
+    }
+
+    // Import the files back and compare.
+    {
+        execute_command(
+            ctx.clone(),
+            &format!("create table {}.t2(a Int32, b String)", db),
+        )
+        .await?;
+        execute_command(
+            ctx.clone(),
+            &format!(
+                "copy into {}.t2 from '@unload_stage/csv' pattern = '.*[.]csv' file_format = (type = csv)",
+                db
+            ),
+        )
+        .await?;
+
+        let stream = execute_query(ctx.clone(), &format!("select * from {}.t2", db)).await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let expected = vec![
+            "+---+-----+",
+            "| a | b   |",
+            "+---+-----+",
+            "| 1 | a   |",
+            "| 2 | b,c |",
+            "| 3 | d\"e |",
+            "+---+-----+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Export the query result as NDJSON.
+    {
+        let query = format!(
+            "copy into '@unload_stage/json' from (select a, b from {}.t where a = 1) file_format = (type = json)",
+            db
+        );
+        execute_command(ctx.clone(), &query).await?;
+
+        let dir = std::path::Path::new(&ctx.get_config().storage.disk.data_path)
+            .join("stage/unload_stage/json");
+        let files = std::fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        assert_eq!(files.len(), 1);
+        let content = std::fs::read_to_string(files[0].path())?;
+        assert_eq!(content, "{\"a\":1,\"b\":\"a\"}\n");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_concurrently_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let stage_info = UserStageInfo {
+        stage_name: "many_stage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    let prefix = stage_info.get_prefix();
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    // Put many small files into the stage, the file i has i + 1 rows.
+    let operator = ctx.get_storage_operator().await?;
+    let file_num = 20;
+    for i in 0..file_num {
+        let content = (0..=i)
+            .map(|j| format!("{},{}\n", i, j))
+            .collect::<String>();
+        operator
+            .object(&format!("{}data/{:02}.csv", prefix, i))
+            .writer()
+            .write_bytes(content.into_bytes())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b Int32)", db),
+    )
+    .await?;
+
+    ctx.get_settings()
+        .set_settings("max_copy_concurrency".to_string(), "4".to_string(), false)?;
+    let query = format!(
+        "copy into {}.t from '@many_stage/data' pattern = '.*[.]csv' file_format = (type = csv)",
+        db
+    );
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let plan = match plan {
+        PlanNode::Copy(plan) => plan,
+        other => panic!("Expected copy plan, got: {:?}", other),
+    };
+
+    let interpreter = CopyInterpreter::create(ctx.clone(), plan);
+    let stream = interpreter.execute(None).await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let files = result.iter().map(|b| b.num_rows()).sum::<usize>();
+    assert_eq!(files, file_num);
+
+    // The loads overlap, but never more than the concurrency.
+    let max_loads = interpreter.max_concurrent_loads();
+    assert!(max_loads > 1, "max concurrent loads: {}", max_loads);
+    assert!(max_loads <= 4, "max concurrent loads: {}", max_loads);
+
+    // All the files are committed at once, by one snapshot.
+    let stream = execute_query(
+        ctx.clone(),
+        &format!("select count(*) as count from {}.t", db),
+    )
+    .await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 210   |",
+        "+-------+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    let stream = execute_query(
+        ctx.clone(),
+        &format!("select count(*) as count from fuse_history('{}', 't')", db),
+    )
+    .await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 1     |",
+        "+-------+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_concurrent_copies_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let stage_info = UserStageInfo {
+        stage_name: "shared_stage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    let prefix = stage_info.get_prefix();
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    let operator = ctx.get_storage_operator().await?;
+    let files = vec![("a.csv", "1,a\n"), ("b.csv", "2,b\n3,c\n")];
+    for (file, content) in files {
+        operator
+            .object(&format!("{}{}", prefix, file))
+            .writer()
+            .write_bytes(content.as_bytes().to_vec())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b String)", db),
+    )
+    .await?;
+
+    // The copies commit on the same table version, the one committing later is retried.
+    let copy = |file: &str| {
+        format!(
+            "copy into {}.t from '@shared_stage' files = ('{}') file_format = (type = csv)",
+            db, file
+        )
+    };
+    let (copy_a, copy_b) = (copy("a.csv"), copy("b.csv"));
+    futures::try_join!(
+        execute_command(ctx.clone(), &copy_a),
+        execute_command(ctx.clone(), &copy_b)
+    )?;
+
+    // The files copied by both are kept, none of them is loaded again.
+    execute_command(
+        ctx.clone(),
+        &format!(
+            "copy into {}.t from '@shared_stage' pattern = '.*[.]csv' file_format = (type = csv)",
+            db
+        ),
+    )
+    .await?;
+
+    let stream = execute_query(ctx.clone(), &format!("select * from {}.t", db)).await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | a |",
+        "| 2 | b |",
+        "| 3 | c |",
+        "+---+---+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_timeout_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let stage_info = UserStageInfo {
+        stage_name: "slow_stage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    let prefix = stage_info.get_prefix();
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    let operator = ctx.get_storage_operator().await?;
+    for i in 0..10 {
+        let content = (0..1000)
+            .map(|j| format!("{},{}\n", i, j))
+            .collect::<String>();
+        operator
+            .object(&format!("{}data/{:02}.csv", prefix, i))
+            .writer()
+            .write_bytes(content.into_bytes())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b Int32)", db),
+    )
+    .await?;
+
+    // The query context is created before the setup, the copy has already run beyond the timeout.
+    ctx.get_settings()
+        .set_settings("max_execution_time".to_string(), "1".to_string(), false)?;
+    let query = format!(
+        "copy into {}.t from '@slow_stage/data' pattern = '.*[.]csv' file_format = (type = csv)",
+        db
+    );
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let err = match interpreter.execute(None).await {
+        Ok(stream) => stream.try_collect::<Vec<DataBlock>>().await.unwrap_err(),
+        Err(err) => err,
+    };
+    assert_eq!(err.code(), ErrorCode::TimeoutCode());
+
+    // Nothing is loaded.
+    ctx.get_settings()
+        .set_settings("max_execution_time".to_string(), "0".to_string(), false)?;
+    let stream = execute_query(
+        ctx.clone(),
+        &format!("select count(*) as count from {}.t", db),
+    )
+    .await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 0     |",
+        "+-------+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_from_named_stage_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    // The stage with its own file format.
+    execute_command(
+        ctx.clone(),
+        "create stage pipe_stage file_format = (type = csv field_delimiter = '|')",
+    )
+    .await?;
+    let stage_info = ctx
+        .get_user_manager()
+        .get_stage(&ctx.get_tenant(), "pipe_stage")
+        .await?;
+    let prefix = stage_info.get_prefix();
+
+    let operator = ctx.get_storage_operator().await?;
+    let files = vec![("pipe.csv", "1|a\n2|b\n"), ("comma.csv", "3,c\n")];
+    for (file, content) in files {
+        operator
+            .object(&format!("{}{}", prefix, file))
+            .writer()
+            .write_bytes(content.as_bytes().to_vec())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {}.t(a Int32, b String)", db),
+    )
+    .await?;
+
+    // The file format of the stage is used.
+    execute_command(
+        ctx.clone(),
+        &format!("copy into {}.t from '@pipe_stage' files = ('pipe.csv')", db),
+    )
+    .await?;
+
+    // The file format of the copy overrides the one of the stage.
+    execute_command(
+        ctx.clone(),
+        &format!(
+            "copy into {}.t from '@pipe_stage' files = ('comma.csv') file_format = (type = csv)",
+            db
+        ),
+    )
+    .await?;
+
+    let stream = execute_query(ctx.clone(), &format!("select * from {}.t", db)).await?;
+    let result = stream.try_collect::<Vec<DataBlock>>().await?;
+    let expected = vec![
+        "+---+---+",
+        "| a | b |",
+        "+---+---+",
+        "| 1 | a |",
+        "| 2 | b |",
+        "| 3 | c |",
+        "+---+---+",
+    ];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_on_error_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    let stage_info = UserStageInfo {
+        stage_name: "error_stage".to_string(),
+        stage_type: StageType::Internal,
+        ..Default::default()
+    };
+    let prefix = stage_info.get_prefix();
+    ctx.get_user_manager()
+        .add_stage(&ctx.get_tenant(), stage_info, false)
+        .await?;
+
+    // The second row of mixed.csv is not a boolean, and fails to parse.
+    let operator = ctx.get_storage_operator().await?;
+    let files = vec![
+        ("data/good.csv", "1,true\n2,false\n"),
+        ("data/mixed.csv", "3,true\n4,not_bool\n5,false\n"),
+    ];
+    for (file, content) in files {
+        operator
+            .object(&format!("{}{}", prefix, file))
+            .writer()
+            .write_bytes(content.as_bytes().to_vec())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    // The (file, status, rows_parsed, rows_loaded, error_count) of the copy, ordered by the file.
+    async fn copy_results(
+        ctx: Arc<QueryContext>,
+        query: &str,
+    ) -> Result<Vec<(String, String, u64, u64, u64)>> {
+        let stream = execute_query(ctx, query).await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let mut rows = vec![];
+        for block in result {
+            for row in 0..block.num_rows() {
+                let first_error = block.column(4).get(row).as_string()?;
+                let error_count = block.column(5).get(row).as_u64()?;
+                // The first error is reported along with the number of errors.
+                assert_eq!(first_error.is_empty(), error_count == 0);
+                if error_count > 0 {
+                    let first_error = String::from_utf8(first_error)?;
+                    assert!(
+                        first_error.contains("Incorrect boolean value"),
+                        "{}",
+                        first_error
+                    );
+                }
+                rows.push((
+                    String::from_utf8(block.column(0).get(row).as_string()?)?,
+                    String::from_utf8(block.column(1).get(row).as_string()?)?,
+                    block.column(2).get(row).as_u64()?,
+                    block.column(3).get(row).as_u64()?,
+                    error_count,
+                ));
+            }
+        }
+        rows.sort();
+        Ok(rows)
+    }
+
+    let copy_query = |table: &str, on_error: &str| {
+        format!(
+            "copy into {}.{} from '@error_stage/data' pattern = '.*[.]csv' file_format = (type = csv) on_error = {}",
+            db, table, on_error
+        )
+    };
+
+    // ABORT: the copy fails, nothing is loaded.
+    {
+        execute_command(
+            ctx.clone(),
+            &format!("create table {}.t_abort(a Int32, b Boolean)", db),
+        )
+        .await?;
+        let err = match execute_query(ctx.clone(), &copy_query("t_abort", "abort")).await {
+            Ok(stream) => stream.try_collect::<Vec<DataBlock>>().await.unwrap_err(),
+            Err(err) => err,
+        };
+        assert_eq!(err.code(), ErrorCode::bad_bytes_code());
+        assert!(err.message().contains("mixed.csv"), "{}", err.message());
+
+        let stream = execute_query(
+            ctx.clone(),
+            &format!("select count(*) as count from {}.t_abort", db),
+        )
+        .await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let expected = vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 0     |",
+            "+-------+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // CONTINUE: the bad row is skipped, the other rows of the file are loaded.
+    {
+        execute_command(
+            ctx.clone(),
+            &format!("create table {}.t_continue(a Int32, b Boolean)", db),
+        )
+        .await?;
+        let results = copy_results(ctx.clone(), &copy_query("t_continue", "continue")).await?;
+        assert_eq!(results, vec![
+            ("good.csv".to_string(), "LOADED".to_string(), 2, 2, 0),
+            (
+                "mixed.csv".to_string(),
+                "PARTIALLY_LOADED".to_string(),
+                3,
+                2,
+                1
+            ),
+        ]);
+
+        let stream =
+            execute_query(ctx.clone(), &format!("select * from {}.t_continue", db)).await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let expected = vec![
+            "+---+-------+",
+            "| a | b     |",
+            "+---+-------+",
+            "| 1 | true  |",
+            "| 2 | false |",
+            "| 3 | true  |",
+            "| 5 | false |",
+            "+---+-------+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // SKIP_FILE: the file with the bad row is skipped as a whole.
+    {
+        execute_command(
+            ctx.clone(),
+            &format!("create table {}.t_skip(a Int32, b Boolean)", db),
+        )
+        .await?;
+        let results = copy_results(ctx.clone(), &copy_query("t_skip", "skip_file")).await?;
+        assert_eq!(results, vec![
+            ("good.csv".to_string(), "LOADED".to_string(), 2, 2, 0),
+            ("mixed.csv".to_string(), "LOAD_FAILED".to_string(), 3, 0, 1),
+        ]);
+
+        let stream = execute_query(ctx.clone(), &format!("select * from {}.t_skip", db)).await?;
+        let result = stream.try_collect::<Vec<DataBlock>>().await?;
+        let expected = vec![
+            "+---+-------+",
+            "| a | b     |",
+            "+---+-------+",
+            "| 1 | true  |",
+            "| 2 | false |",
+            "+---+-------+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
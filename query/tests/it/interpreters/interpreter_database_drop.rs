@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use databend_query::catalogs::Catalog;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -33,3 +38,74 @@ async fn test_drop_database_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_drop_database_interpreter_restrict_and_cascade() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "create database db1",
+        "create table db1.t2(a int) Engine = Memory",
+        "create table db1.t1(a int) Engine = Memory",
+    ] {
+        execute_command(ctx.clone(), query).await?;
+    }
+
+    // RESTRICT is the default, a non-empty database is refused.
+    for query in ["drop database db1", "drop database db1 restrict"] {
+        let res = execute_command(ctx.clone(), query).await;
+        assert!(res.is_err());
+        let err = res.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::DatabaseNotEmptyCode());
+        assert_eq!(
+            err.message(),
+            "Cannot drop database 'db1', it still contains tables: t1, t2. Use DROP DATABASE ... CASCADE to drop them too"
+        );
+    }
+    let catalog = ctx.get_catalog();
+    let tenant = ctx.get_tenant();
+    assert!(catalog.exists_table(&tenant, "db1", "t1").await?);
+
+    // CASCADE drops the tables first.
+    execute_command(ctx.clone(), "drop database db1 cascade").await?;
+    assert!(!catalog.exists_database(&tenant, "db1").await?);
+
+    // IF EXISTS with CASCADE on a missing database.
+    execute_command(ctx.clone(), "drop database if exists db1 cascade").await?;
+    let res = execute_command(ctx.clone(), "drop database db1 cascade").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownDatabaseCode());
+
+    // An empty database is dropped without CASCADE.
+    execute_command(ctx.clone(), "create database db2").await?;
+    execute_command(ctx.clone(), "drop database db2").await?;
+    assert!(!catalog.exists_database(&tenant, "db2").await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drop_current_database_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    execute_command(ctx.clone(), "create database db1").await?;
+    execute_command(ctx.clone(), "create table db1.t1(a int) Engine = Memory").await?;
+    execute_command(ctx.clone(), "use db1").await?;
+    assert_eq!(ctx.get_current_database(), "db1");
+
+    // The session goes back to "default" once its database is dropped.
+    execute_command(ctx.clone(), "drop database db1 cascade").await?;
+    assert_eq!(ctx.get_current_database(), "default");
+
+    Ok(())
+}
+
+async fn execute_command(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
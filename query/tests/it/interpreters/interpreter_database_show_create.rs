@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datavalues::prelude::*;
 use common_exception::Result;
+use databend_query::catalogs::Catalog;
+use databend_query::databases::Database;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -57,5 +63,57 @@ async fn test_show_create_database_interpreter() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // show create database with options, the output creates the same database
+    {
+        execute_command(
+            ctx.clone(),
+            "CREATE DATABASE db1 COMMENT = 'it''s' OWNER = 'u1'",
+        )
+        .await?;
+        let create_sql = show_create_database(ctx.clone(), "db1").await?;
+        assert_eq!(
+            create_sql,
+            "CREATE DATABASE `db1` COMMENT='it''s' OWNER='u1'"
+        );
+
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+        let options = catalog
+            .get_database(&tenant, "db1")
+            .await?
+            .options()
+            .clone();
+        execute_command(ctx.clone(), "DROP DATABASE db1").await?;
+        execute_command(ctx.clone(), &create_sql).await?;
+
+        let db = catalog.get_database(&tenant, "db1").await?;
+        assert_eq!(db.options(), &options);
+        assert_eq!(show_create_database(ctx.clone(), "db1").await?, create_sql);
+    }
+
     Ok(())
 }
+
+async fn execute_command(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+async fn show_create_database(ctx: Arc<QueryContext>, db: &str) -> Result<String> {
+    let query = format!("SHOW CREATE DATABASE {}", db);
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    let blocks = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let create_sql = Vu8::try_create_viewer(blocks[0].column(1))?;
+    Ok(String::from_utf8_lossy(create_sql.value_at(0)).to_string())
+}
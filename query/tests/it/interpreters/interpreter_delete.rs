@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_delete_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "CREATE TABLE default.a(a bigint, b varchar) Engine = Memory";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+    let _ = executor.execute(None).await?;
+
+    // The engine doesn't support delete.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DELETE FROM a WHERE a > 1").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "DeleteInterpreter");
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnImplementCode());
+    }
+
+    // The selection refers to an unknown column.
+    {
+        let res = PlanParser::parse(ctx.clone(), "DELETE FROM a WHERE c > 1").await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::BadArgumentsCode());
+    }
+
+    // Aggregate functions and subqueries are not allowed in the selection.
+    {
+        let res = PlanParser::parse(ctx.clone(), "DELETE FROM a WHERE sum(a) > 1").await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::SyntaxExceptionCode());
+
+        let query = "DELETE FROM a WHERE a IN (SELECT number FROM numbers(3))";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnImplementCode());
+    }
+
+    Ok(())
+}
@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -52,3 +58,88 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "EXPLAIN ANALYZE SELECT number FROM numbers_mt(10) WHERE number > 5";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreter");
+
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let column: &StringColumn = Series::check_get(result[0].column(0))?;
+    let lines = column
+        .iter()
+        .map(|x| String::from_utf8_lossy(x).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+
+    // The time is not stable, only check the plan nodes and the rows.
+    assert!(lines[0].starts_with("Projection: number:UInt64 (actual rows: 4, blocks: "));
+    assert!(lines[1].starts_with("  Filter: (number > 5) (actual rows: 4, "));
+    assert!(lines[2].starts_with("    ReadDataSource: scan schema: [number:UInt64]"));
+    assert!(lines[2].contains("(actual rows: 10, "));
+    assert!(lines[2].ends_with(", estimated rows: 10)"));
+    assert!(lines[3].starts_with("Total time: "));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_insert_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let query = "CREATE TABLE default.t(a BIGINT) Engine = Memory";
+    execute_query(ctx.clone(), query).await?;
+
+    let query = "EXPLAIN ANALYZE INSERT INTO t SELECT number FROM numbers_mt(10) WHERE number > 5";
+    let result = execute_query(ctx.clone(), query).await?;
+    let column: &StringColumn = Series::check_get(result[0].column(0))?;
+    let lines = column
+        .iter()
+        .map(|x| String::from_utf8_lossy(x).to_string())
+        .collect::<Vec<_>>();
+
+    // The rows of the sink are the ones of its input.
+    assert!(lines[0].starts_with("Sink: table="));
+    assert!(lines[0].contains(" (time: "));
+    assert!(lines
+        .iter()
+        .any(|x| x.contains("Filter: (number > 5) (actual rows: 4, ")));
+    assert!(lines.iter().any(|x| x.contains("(actual rows: 10, ")));
+    assert!(lines.last().unwrap().starts_with("Total time: "));
+
+    // The rows are inserted.
+    let result = execute_query(ctx.clone(), "SELECT a FROM t").await?;
+    let expected = vec![
+        "+---+", //
+        "| a |", //
+        "+---+", //
+        "| 6 |", //
+        "| 7 |", //
+        "| 8 |", //
+        "| 9 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // Only INSERT INTO ... SELECT is analyzed.
+    let query = "EXPLAIN ANALYZE INSERT INTO t VALUES (1)";
+    let res = execute_query(ctx.clone(), query).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SyntaxExceptionCode());
+
+    let query = "EXPLAIN INSERT INTO t SELECT 1";
+    let res = execute_query(ctx.clone(), query).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SyntaxExceptionCode());
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -115,3 +118,24 @@ async fn test_interpreter_interceptor_for_insert() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_timeout() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    ctx.get_settings()
+        .set_settings("max_block_size".to_string(), "1000".to_string(), false)?;
+    ctx.get_settings()
+        .set_settings("max_execution_time".to_string(), "1".to_string(), false)?;
+    // The query runs beyond the timeout before the first block.
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let query = "select number from numbers_mt(100000000)";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::TimeoutCode());
+    assert_eq!(err.message(), "Query exceeded max_execution_time 1 ms");
+
+    Ok(())
+}
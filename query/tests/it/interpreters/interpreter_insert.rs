@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -120,3 +121,136 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_select_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "create table default.src(a Int32, b String) Engine = Memory",
+        "create table default.dst(x Int64 NOT NULL, y String, z Int64 DEFAULT 10, w Int64) Engine = Memory",
+        "insert into default.src values(1, 'a'), (2, 'b'), (3, 'c')",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    let write_rows = ctx.get_write_progress_value().read_rows;
+
+    // Implicit cast of Int32 into Int64 column.
+    {
+        let query = "insert into default.dst select a, b, a * 10, a from default.src where a > 1";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Reordered column list, the omitted columns are filled by the default value or NULL.
+    {
+        let query = "insert into default.dst(y, x) select b, a from default.src where a = 1";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+
+        // The written rows of both inserts are counted.
+        let progress = ctx.get_write_progress_value();
+        assert_eq!(progress.read_rows, write_rows + 3);
+    }
+
+    // select.
+    {
+        let query = "select * from default.dst";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+---+----+------+",
+            "| x | y | z  | w    |",
+            "+---+---+----+------+",
+            "| 1 | a | 10 | NULL |",
+            "| 2 | b | 20 | 2    |",
+            "| 3 | c | 30 | 3    |",
+            "+---+---+----+------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The incompatible types and the mismatched columns fail at plan time.
+    {
+        let query = "insert into default.dst(x, y) select NULL, b from default.src";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        let err = res.unwrap_err();
+        assert!(err.message().ends_with(" (while in insert select)"));
+
+        let query = "insert into default.dst(x, y) select a from default.src";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::BadArgumentsCode());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_into_values_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    {
+        let query = "create table default.t(a Int32, b Float64, c String NULL) Engine = Memory";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // The integers are coerced to Float64, and the integral floats to Int32.
+    {
+        let query = "insert into default.t values(1, 2, 'a'), (2.0, 3.5, NULL), (-3, -1, 'c')";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+    }
+
+    {
+        let query = "select a, b + 0.5, c from default.t";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----+-----------+------+",
+            "| a  | (b + 0.5) | c    |",
+            "+----+-----------+------+",
+            "| -3 | -0.5      | c    |",
+            "| 1  | 2.5       | a    |",
+            "| 2  | 4         | NULL |",
+            "+----+-----------+------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The fractional part of the floats would be lost in the integer column.
+    for value in ["1.5", "-2.5", "3 / 2"] {
+        let query = format!(
+            "insert into default.t values(1, 1, 'a'), ({}, 1, 'b')",
+            value
+        );
+        let res = PlanParser::parse(ctx.clone(), &query).await;
+        let err = res.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BadDataValueTypeCode());
+        assert!(err.message().starts_with("Cannot insert "), "{}", err);
+    }
+
+    let query = "insert into default.t values(1, 1, 'a'), (2, 2)";
+    let res = PlanParser::parse(ctx.clone(), query).await;
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Insert values expects 3 columns, but the row 2 has 2 values"
+    );
+
+    Ok(())
+}
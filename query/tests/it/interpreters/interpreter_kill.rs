@@ -0,0 +1,150 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_kill_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // No session or query of the id.
+    {
+        let res = execute_query(ctx.clone(), "KILL QUERY 'unknown'").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownQueryCode());
+
+        let res = execute_query(ctx.clone(), "KILL CONNECTION 'unknown'").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownSessionCode());
+    }
+
+    // Kill the query running in another session by its query id.
+    let session_manager = ctx.get_current_session().get_session_manager();
+    let session = session_manager.create_session("TestSession")?;
+    {
+        let query_ctx = session.create_query_context().await?;
+        let query_id = query_ctx.get_id();
+        assert_eq!(session.get_running_query_id(), Some(query_id.clone()));
+
+        // The query finishes a while after it is killed, KILL waits for it.
+        let finished = Arc::downgrade(&query_ctx);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(query_ctx);
+        });
+
+        let query = format!("KILL QUERY '{}'", query_id);
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "KillInterpreter");
+        executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert!(finished.upgrade().is_none());
+        assert_eq!(session.get_running_query_id(), None);
+
+        // The query is not running anymore.
+        let res = execute_query(ctx.clone(), &query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownQueryCode());
+    }
+
+    // The query not finished in kill_query_timeout_ms is still killed.
+    {
+        ctx.get_settings().set_settings(
+            "kill_query_timeout_ms".to_string(),
+            "10".to_string(),
+            false,
+        )?;
+
+        let query_ctx = session.create_query_context().await?;
+        let query = format!("KILL QUERY '{}'", query_ctx.get_id());
+        execute_query(ctx.clone(), &query).await?;
+        assert_eq!(session.get_running_query_id(), None);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_kill_interpreter_running_query() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let session_manager = ctx.get_current_session().get_session_manager();
+
+    // A query scanning the rows for a long time.
+    let slow_query = "SELECT sum(number) FROM numbers(1000000000000)";
+
+    // KILL QUERY fails the running query, the session is still usable.
+    {
+        let session = session_manager.create_session("TestSession")?;
+        session.set_current_user(ctx.get_current_user()?);
+
+        let query_ctx = session.create_query_context().await?;
+        let query_id = query_ctx.get_id();
+        let running = tokio::spawn(execute_query(query_ctx, slow_query));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        execute_query(ctx.clone(), &format!("KILL QUERY '{}'", query_id)).await?;
+        let res = running.await.unwrap();
+        assert_eq!(res.unwrap_err().code(), ErrorCode::AbortedQueryCode());
+        assert!(!session.is_aborting());
+
+        let query_ctx = session.create_query_context().await?;
+        execute_query(query_ctx, "SELECT sum(number) FROM numbers(10)").await?;
+    }
+
+    // KILL CONNECTION fails the running query and closes the session.
+    {
+        let session = session_manager.create_session("TestSession")?;
+        session.set_current_user(ctx.get_current_user()?);
+
+        let query_ctx = session.create_query_context().await?;
+        let running = tokio::spawn(execute_query(query_ctx, slow_query));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        execute_query(
+            ctx.clone(),
+            &format!("KILL CONNECTION '{}'", session.get_id()),
+        )
+        .await?;
+        let res = running.await.unwrap();
+        assert_eq!(res.unwrap_err().code(), ErrorCode::AbortedQueryCode());
+        assert!(session.is_aborting());
+    }
+
+    // The killing session is not affected.
+    execute_query(ctx.clone(), "SELECT sum(number) FROM numbers(10)").await?;
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
@@ -74,5 +74,22 @@ async fn test_select_interpreter() -> Result<()> {
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
+
+    {
+        let query = "select 2 ** 3 ** 2 as a, 2 * 3 ** 2 as b, -2 ** 2 as c, (2 ** 3) ** 2 as d";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-----+----+----+----+",
+            "| a   | b  | c  | d  |",
+            "+-----+----+----+----+",
+            "| 512 | 18 | -4 | 64 |",
+            "+-----+----+----+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
     Ok(())
 }
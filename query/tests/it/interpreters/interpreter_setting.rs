@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -46,3 +53,222 @@ async fn test_setting_interpreter_error() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_math_domain_error() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await
+    }
+
+    let query = "SELECT SQRT(-1) AS s, ASIN(2) AS a, SQRT(4) AS v";
+
+    // The default is null.
+    {
+        let result = execute(&ctx, query).await?;
+        let expected = vec![
+            "+------+------+---+",
+            "| s    | a    | v |",
+            "+------+------+---+",
+            "| NULL | NULL | 2 |",
+            "+------+------+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    {
+        execute(&ctx, "SET math_domain_error = 'nan'").await?;
+        let result = execute(&ctx, query).await?;
+        let expected = vec![
+            "+-----+-----+---+",
+            "| s   | a   | v |",
+            "+-----+-----+---+",
+            "| NaN | NaN | 2 |",
+            "+-----+-----+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    {
+        execute(&ctx, "SET math_domain_error = 'error'").await?;
+        let result = execute(&ctx, "SELECT SQRT(-1)").await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Function SQRT got an argument out of its domain"
+        );
+
+        let result = execute(&ctx, "SELECT ASIN(2)").await;
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Function ASIN got an argument out of its domain"
+        );
+
+        let result = execute(&ctx, "SELECT SQRT(4) AS v").await?;
+        let expected = vec!["+---+", "| v |", "+---+", "| 2 |", "+---+"];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    {
+        let result = execute(&ctx, "SET math_domain_error = 'zero'").await;
+        assert!(result.is_err());
+        assert_eq!(
+            ctx.get_settings().get_math_domain_error()?.to_string(),
+            "error"
+        );
+
+        execute(&ctx, "SET math_domain_error = 'NULL'").await?;
+        assert_eq!(
+            ctx.get_settings().get_math_domain_error()?.to_string(),
+            "null"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_value_range() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "SET max_block_size = 0",
+        "SET max_threads = 0",
+        "SET unquoted_ident_case_sensitive = 2",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::BadArgumentsCode());
+    }
+
+    let res = ctx
+        .get_settings()
+        .set_settings("max_block_size".to_string(), "0".to_string(), false);
+    assert_eq!(
+        res.unwrap_err().message(),
+        format!(
+            "Invalid value 0 of setting max_block_size, expected in range [1, {}]",
+            u64::MAX
+        )
+    );
+    assert_eq!(ctx.get_settings().get_max_block_size()?, 10000);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_global() -> Result<()> {
+    // The global settings are loaded by the new sessions of the tenant, use a tenant of its own.
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.tenant_id = "test_setting_interpreter_global".to_string();
+    let ctx = crate::tests::create_query_context_with_config(conf.clone())?;
+
+    let plan = PlanParser::parse(ctx.clone(), "SET GLOBAL max_block_size = 100").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(ctx.get_settings().get_max_block_size()?, 100);
+
+    // The setting is written to the metasrv, and shown in the GLOBAL level.
+    let tenant = ctx.get_tenant();
+    let user_settings = ctx.get_user_manager().get_settings(&tenant).await?;
+    assert!(user_settings
+        .iter()
+        .any(|x| x.name == "max_block_size" && x.value == DataValue::UInt64(100)));
+
+    let plan = PlanParser::parse(ctx.clone(), "SHOW VARIABLES").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let result = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let names: &StringColumn = Series::check_get(result[0].column(0))?;
+    let levels: &StringColumn = Series::check_get(result[0].column(3))?;
+    let level = names
+        .iter()
+        .zip(levels.iter())
+        .find(|(name, _)| *name == b"max_block_size")
+        .map(|(_, level)| level);
+    assert_eq!(level, Some(&b"GLOBAL"[..]));
+
+    // The new sessions of the tenant start with the global setting.
+    let ctx = crate::tests::create_query_context_with_config(conf)?;
+    assert_eq!(ctx.get_settings().get_max_block_size()?, 100);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_value_parsing() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    async fn execute(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await
+    }
+
+    let res = execute(&ctx, "SET max_block_size = 'abc'").await;
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid value 'abc' of setting max_block_size, expected an unsigned integer"
+    );
+
+    let res = execute(&ctx, "SET no_such_setting = 1").await;
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Unknown variable: \"no_such_setting\""
+    );
+
+    // The boolean settings take true/false and on/off.
+    execute(&ctx, "SET unquoted_ident_case_sensitive = false").await?;
+    assert!(!ctx.get_settings().get_unquoted_ident_case_sensitive()?);
+    execute(&ctx, "SET unquoted_ident_case_sensitive = 'ON'").await?;
+    assert!(ctx.get_settings().get_unquoted_ident_case_sensitive()?);
+
+    let res = execute(&ctx, "SET unquoted_ident_case_sensitive = 'yes'").await;
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Invalid value 'yes' of setting unquoted_ident_case_sensitive, expected an unsigned integer"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_max_block_size() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    async fn block_sizes(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<usize>> {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let mut sizes = blocks.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        sizes.sort_unstable();
+        Ok(sizes)
+    }
+
+    // One partition, split into the blocks of max_block_size.
+    block_sizes(&ctx, "SET max_threads = 1").await?;
+    let query = "SELECT number FROM numbers(10)";
+    assert_eq!(block_sizes(&ctx, query).await?, vec![10]);
+
+    // The subsequent queries of the session read the blocks of the new size, the remainder is
+    // put in the last block.
+    block_sizes(&ctx, "SET max_block_size = 3").await?;
+    assert_eq!(block_sizes(&ctx, query).await?, vec![3, 3, 4]);
+
+    Ok(())
+}
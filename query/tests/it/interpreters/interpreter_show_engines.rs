@@ -38,6 +38,7 @@ async fn test_show_engines_interpreter() -> Result<()> {
             "| GITHUB | GITHUB Storage Engine |",
             "| MEMORY | MEMORY Storage Engine |",
             "| NULL   | NULL Storage Engine   |",
+            "| VIEW   | VIEW Storage Engine   |",
             "+--------+-----------------------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
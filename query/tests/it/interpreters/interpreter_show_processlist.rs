@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_show_processlist_interpreter() -> Result<()> {
@@ -31,3 +36,74 @@ async fn test_show_processlist_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_show_processlist_running_query() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let session_manager = ctx.get_current_session().get_session_manager();
+    let session = session_manager.create_session("TestSession")?;
+    session.set_current_user(ctx.get_current_user()?);
+
+    let query = "SELECT sum(number) FROM numbers(1000) WHERE number >= 0 AND number < 1000 AND (number % 2 = 0 OR number % 2 = 1)";
+    let query_ctx = session.create_query_context().await?;
+    let query_id = query_ctx.get_id();
+    query_ctx.attach_query_str(query);
+    execute_query(query_ctx.clone(), query).await?;
+
+    // The query is listed until its context is destroyed.
+    let processes = format!(
+        "SELECT state, command, length(extra_info), scan_progress_read_rows FROM system.processes WHERE query_id = '{}'",
+        query_id
+    );
+    let blocks = execute_query(ctx.clone(), &processes).await?;
+    let expected = vec![
+        "+-------+---------+--------------------+-------------------------+",
+        "| state | command | length(extra_info) | scan_progress_read_rows |",
+        "+-------+---------+--------------------+-------------------------+",
+        "| Query | Query   | 112                | 1000                    |",
+        "+-------+---------+--------------------+-------------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &blocks);
+
+    // The info is the query text truncated to 100 characters.
+    let info = format!(
+        "SELECT info FROM system.processes WHERE query_id = '{}'",
+        query_id
+    );
+    let blocks = execute_query(ctx.clone(), &info).await?;
+    let expected = vec![
+        "+------------------------------------------------------------------------------------------------------+",
+        "| info                                                                                                 |",
+        "+------------------------------------------------------------------------------------------------------+",
+        "| SELECT sum(number) FROM numbers(1000) WHERE number >= 0 AND number < 1000 AND (number % 2 = 0 OR num |",
+        "+------------------------------------------------------------------------------------------------------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &blocks);
+
+    let session_processes = format!(
+        "SELECT state, query_id, command, info FROM system.processes WHERE id = '{}'",
+        session.get_id()
+    );
+    drop(query_ctx);
+    let blocks = execute_query(ctx.clone(), &processes).await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    // The session is still listed, but idle.
+    let blocks = execute_query(ctx.clone(), &session_processes).await?;
+    let expected = vec![
+        "+-------+----------+---------+------+",
+        "| state | query_id | command | info |",
+        "+-------+----------+---------+------+",
+        "| Idle  | NULL     | Sleep   | NULL |",
+        "+-------+----------+---------+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &blocks);
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
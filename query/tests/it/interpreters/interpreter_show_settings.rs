@@ -34,19 +34,60 @@ async fn test_show_settings_interpreter() -> Result<()> {
             "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
             "| name                               | value   | default | level   | description                                                                                                                                | type   |",
             "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
+            "| copy_history_retention_days        | 64      | 64      | SESSION | The days to keep the files loaded by COPY to skip them. By default, it is 64 days.                                                         | UInt64 |",
             "| enable_new_processor_framework     | 0       | 0       | SESSION | Enable new processor framework if value != 0, default value: 0                                                                             | UInt64 |",
             "| flight_client_timeout              | 60      | 60      | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds                                         | UInt64 |",
+            "| group_by_spill_threshold           | 0       | 0       | SESSION | The memory in bytes of the GROUP BY hash table, beyond which it spills to disk. By default, it is 0, never spill.                          | UInt64 |",
+            "| kill_query_timeout_ms              | 5000    | 5000    | SESSION | The time in milliseconds KILL QUERY waits for the killed query to finish. By default, it is 5000 ms.                                       | UInt64 |",
+            "| math_domain_error                  | null    | null    | SESSION | How math functions like SQRT(-1) treat out-of-domain arguments: null, nan or error. By default, it is null.                                | String |",
             "| max_block_size                     | 10000   | 10000   | SESSION | Maximum block size for reading                                                                                                             | UInt64 |",
+            "| max_copy_concurrency               | 4       | 4       | SESSION | The maximum number of files loaded concurrently by COPY. By default, it is 4.                                                              | UInt64 |",
+            "| max_execution_time                 | 0       | 0       | SESSION | The maximum execution time of a query in milliseconds, beyond which it fails with a timeout error. By default, it is 0, no limit.          | UInt64 |",
+            "| max_listagg_length                 | 1048576 | 1048576 | SESSION | The maximum length in bytes of the LISTAGG result, beyond which ON OVERFLOW applies. By default, it is 1MB.                                | UInt64 |",
             "| max_threads                        | 8       | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.                                          | UInt64 |",
             "| parallel_read_threads              | 1       | 1       | SESSION | The maximum number of parallelism for reading data. By default, it is 1.                                                                   | UInt64 |",
             "| storage_occ_backoff_init_delay_ms  | 5       | 5       | SESSION | The initial retry delay in millisecond. By default, it is 5 ms.                                                                            | UInt64 |",
             "| storage_occ_backoff_max_delay_ms   | 20000   | 20000   | SESSION | The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds. | UInt64 |",
             "| storage_occ_backoff_max_elapsed_ms | 120000  | 120000  | SESSION | The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes.                    | UInt64 |",
             "| storage_read_buffer_size           | 1048576 | 1048576 | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                                                             | UInt64 |",
+            "| unquoted_ident_case_sensitive      | 1       | 1       | SESSION | Unquoted identifiers are converted to lowercase if value == 0, quoted identifiers always keep their case. By default, it is 1.             | UInt64 |",
             "+------------------------------------+---------+---------+---------+--------------------------------------------------------------------------------------------------------------------------------------------+--------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // show settings like.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show settings like 'max_b%'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------------+-------+---------+---------+--------------------------------+--------+",
+            "| name           | value | default | level   | description                    | type   |",
+            "+----------------+-------+---------+---------+--------------------------------+--------+",
+            "| max_block_size | 10000 | 10000   | SESSION | Maximum block size for reading | UInt64 |",
+            "+----------------+-------+---------+---------+--------------------------------+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // show settings where.
+    {
+        let plan =
+            PlanParser::parse(ctx.clone(), "show settings where name = 'max_threads'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------------+-------+---------+---------+---------------------------------------------------------------------------------------------------+--------+",
+            "| name        | value | default | level   | description                                                                                       | type   |",
+            "+-------------+-------+---------+---------+---------------------------------------------------------------------------------------------------+--------+",
+            "| max_threads | 8     | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically. | UInt64 |",
+            "+-------------+-------+---------+---------+---------------------------------------------------------------------------------------------------+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
     Ok(())
 }
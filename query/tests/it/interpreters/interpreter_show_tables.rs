@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
@@ -116,7 +117,100 @@ async fn test_show_tables_interpreter() -> Result<()> {
 
     // Teardown.
     {
-        let plan = PlanParser::parse(ctx.clone(), "drop database db1").await?;
+        let plan = PlanParser::parse(ctx.clone(), "drop database db1 cascade").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_show_tables_from_like_where_full_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Setup.
+    for query in [
+        "create database db2",
+        "create table db2.data_1(a Int)",
+        "create table db2.datax1(a Int)",
+        "create table db2.data11(a Int) Engine = Memory",
+        "create table db2.bend(a Int) Engine = Null",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // show tables from db2 like 'data_1', the underscore matches any character.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show tables from db2 like 'data_1'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "ShowTablesInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| name   |",
+            "+--------+",
+            "| data11 |",
+            "| data_1 |",
+            "| datax1 |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // show tables in db2 where engine = 'FUSE'.
+    {
+        let query = "show tables in db2 where engine = 'FUSE'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| name   |",
+            "+--------+",
+            "| data_1 |",
+            "| datax1 |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // show full tables from db2 like 'b%'.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "show full tables from db2 like 'b%'").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].num_rows(), 1);
+
+        let schema = result[0].schema();
+        let names = schema.fields().iter().map(|f| f.name().as_str());
+        assert_eq!(names.collect::<Vec<_>>(), vec![
+            "name",
+            "engine",
+            "created_on"
+        ]);
+    }
+
+    // show tables from a database which doesn't exist.
+    {
+        let result = PlanParser::parse(ctx.clone(), "show tables from db3").await;
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnknownDatabaseCode());
+        assert_eq!(
+            err.message(),
+            "Cannot SHOW TABLES FROM 'db3', because the 'db3' doesn't exist"
+        );
+    }
+
+    // Teardown.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "drop database db2 cascade").await?;
         let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
         let _ = executor.execute(None).await?;
     }
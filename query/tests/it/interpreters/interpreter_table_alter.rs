@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_alter_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    {
+        let query = "CREATE TABLE default.a(a bigint) Engine = Null";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Alter a missing table with IF EXISTS.
+    {
+        let plan =
+            PlanParser::parse(ctx.clone(), "ALTER TABLE IF EXISTS b ADD COLUMN c int").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "AlterTableInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Alter a missing table.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "ALTER TABLE b DROP COLUMN c").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), 1025);
+    }
+
+    // Only the FUSE engine supports schema changes.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "ALTER TABLE a ADD COLUMN c int").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), 1002);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alter_table_add_column_interpreter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(ctx.clone(), &format!("CREATE TABLE {}.t(a Int32)", db)).await?;
+    execute_command(ctx.clone(), &format!("INSERT INTO {}.t VALUES(1)", db)).await?;
+
+    // The existing block is not rewritten, its rows read the defaults of the new columns.
+    {
+        let query = format!("ALTER TABLE {}.t ADD COLUMN b Int64 DEFAULT 7", db);
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "AlterTableInterpreter");
+        executor.execute(None).await?;
+    }
+    execute_command(ctx.clone(), &format!("ALTER TABLE {}.t ADD c String", db)).await?;
+    execute_command(
+        ctx.clone(),
+        &format!("INSERT INTO {}.t VALUES(2, 20, 'x')", db),
+    )
+    .await?;
+
+    {
+        let query = format!("SELECT * FROM {}.t", db);
+        let stream = execute_query(ctx.clone(), &query).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+----+------+",
+            "| a | b  | c    |",
+            "+---+----+------+",
+            "| 1 | 7  | NULL |",
+            "| 2 | 20 | x    |",
+            "+---+----+------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    {
+        let query = format!("SELECT b FROM {}.t WHERE a = 1", db);
+        let stream = execute_query(ctx.clone(), &query).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+", //
+            "| b |", //
+            "+---+", //
+            "| 7 |", //
+            "+---+", //
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_create_table_interpreter() -> Result<()> {
@@ -93,3 +100,108 @@ async fn test_create_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_as_select_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    execute_query(
+        ctx.clone(),
+        "CREATE TABLE default.src(a bigint not null, b int)",
+    )
+    .await?;
+    execute_query(
+        ctx.clone(),
+        "INSERT INTO default.src VALUES(1, 10), (2, NULL)",
+    )
+    .await?;
+
+    // The schema is derived from the select output, by the aliases.
+    {
+        let query = "CREATE TABLE default.t1 AS SELECT a AS x, a + 1 AS y, b AS z FROM default.src";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let schema = plan.schema();
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| format!("{:?}", f))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec![
+            r#"DataField { name: "x", data_type: Int64, nullable: false }"#,
+            r#"DataField { name: "y", data_type: Int64, nullable: false }"#,
+            r#"DataField { name: "z", data_type: Int32, nullable: true }"#,
+        ]);
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let result = execute_query(ctx.clone(), "SELECT * FROM default.t1 ORDER BY x").await?;
+        let expected = vec![
+            "+---+---+------+",
+            "| x | y | z    |",
+            "+---+---+------+",
+            "| 1 | 2 | 10   |",
+            "| 2 | 3 | NULL |",
+            "+---+---+------+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    // IF NOT EXISTS skips both the creation and the population.
+    {
+        let query = "CREATE TABLE IF NOT EXISTS default.t1 AS SELECT 5 AS x";
+        execute_query(ctx.clone(), query).await?;
+
+        let result = execute_query(ctx.clone(), "SELECT count(*) AS c FROM default.t1").await?;
+        let expected = vec!["+---+", "| c |", "+---+", "| 2 |", "+---+"];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+
+        let res = execute_query(ctx.clone(), "CREATE TABLE default.t1 AS SELECT 5 AS x").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::TableAlreadyExistsCode());
+    }
+
+    // The select returns no rows, the table is created empty.
+    {
+        let query = "CREATE TABLE default.t2 AS SELECT a FROM default.src WHERE a > 100";
+        execute_query(ctx.clone(), query).await?;
+
+        let result = execute_query(ctx.clone(), "SELECT count(*) AS c FROM default.t2").await?;
+        let expected = vec!["+---+", "| c |", "+---+", "| 0 |", "+---+"];
+        common_datablocks::assert_blocks_eq(expected, result.as_slice());
+    }
+
+    // The select fails at execution, the table created is dropped.
+    {
+        execute_query(ctx.clone(), "SET math_domain_error = 'error'").await?;
+        let query = "CREATE TABLE default.t3 AS SELECT sqrt(a - 2) AS x FROM default.src";
+        let res = execute_query(ctx.clone(), query).await;
+        let err = res.unwrap_err();
+        assert!(err
+            .message()
+            .contains("Function SQRT got an argument out of its domain"));
+
+        let res = execute_query(ctx.clone(), "SELECT * FROM default.t3").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownTableCode());
+    }
+
+    // The same column name can't be selected twice.
+    {
+        let query = "CREATE TABLE default.t4 AS SELECT a, a FROM default.src";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(
+            res.unwrap_err().message(),
+            "Duplicate column name a in the select of create table"
+        );
+    }
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
@@ -45,15 +45,61 @@ async fn interpreter_describe_table_test() -> Result<()> {
         let stream = executor.execute(None).await?;
         let result = stream.try_collect::<Vec<_>>().await?;
         let expected = vec![
-            "+-------+--------+------+",
-            "| Field | Type   | Null |",
-            "+-------+--------+------+",
-            "| a     | Int64  | YES  |",
-            "| b     | Int32  | YES  |",
-            "| c     | String | YES  |",
-            "| d     | Int16  | YES  |",
-            "| e     | Date16 | YES  |",
-            "+-------+--------+------+",
+            "+-------+--------+------+-----+---------+-------+",
+            "| Field | Type   | Null | Key | Default | Extra |",
+            "+-------+--------+------+-----+---------+-------+",
+            "| a     | Int64  | YES  |     | NULL    |       |",
+            "| b     | Int32  | YES  |     | NULL    |       |",
+            "| c     | String | YES  |     | NULL    |       |",
+            "| d     | Int16  | YES  |     | NULL    |       |",
+            "| e     | Date16 | YES  |     | NULL    |       |",
+            "+-------+--------+------+-----+---------+-------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Describe a table with defaults in another database.
+    {
+        let query = "\
+            CREATE TABLE db1.b(\
+                a bigint NOT NULL, b int DEFAULT 1, c varchar NOT NULL DEFAULT 'c', d bigint DEFAULT 1 + 2\
+            ) Engine = Null\
+        ";
+        for query in ["CREATE DATABASE db1", query] {
+            let plan = PlanParser::parse(ctx.clone(), query).await?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+            let _ = interpreter.execute(None).await?;
+        }
+
+        let plan = PlanParser::parse(ctx.clone(), "DESC db1.b").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+--------+------+-----+---------+-------+",
+            "| Field | Type   | Null | Key | Default | Extra |",
+            "+-------+--------+------+-----+---------+-------+",
+            "| a     | Int64  | NO   |     | NULL    |       |",
+            "| b     | Int32  | YES  |     | 1       |       |",
+            "| c     | String | NO   |     | 'c'     |       |",
+            "| d     | Int64  | YES  |     | (1 + 2) |       |",
+            "+-------+--------+------+-----+---------+-------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Describe a system table.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DESCRIBE system.databases").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+--------+------+-----+---------+-------+",
+            "| Field | Type   | Null | Key | Default | Extra |",
+            "+-------+--------+------+-----+---------+-------+",
+            "| name  | String | NO   |     | NULL    |       |",
+            "+-------+--------+------+-----+---------+-------+",
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use databend_query::catalogs::Catalog;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
@@ -49,3 +51,46 @@ async fn test_drop_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_drop_multiple_tables_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "CREATE TABLE default.a(a bigint) Engine = Null",
+        "CREATE TABLE default.b(b bigint) Engine = Null",
+        "CREATE TABLE default.c(c bigint) Engine = Null",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Without IF EXISTS, the missing table fails the drop, the preceding tables are dropped.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DROP TABLE a, missing, b").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+    }
+
+    // With IF EXISTS, the missing tables are skipped.
+    {
+        let query = "DROP TABLE IF EXISTS a, missing, b, c";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // All the tables are dropped.
+    for table in ["a", "b", "c"] {
+        let catalog = ctx.get_catalog();
+        let res = catalog.get_table(&ctx.get_tenant(), "default", table).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_rename_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "CREATE DATABASE db2",
+        "CREATE TABLE default.a(a bigint) Engine = Null",
+        "CREATE TABLE default.b(b bigint) Engine = Null",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Rename into another database.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "RENAME TABLE a TO db2.c").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "RenameTableInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        assert!(ctx.get_table("default", "a").await.is_err());
+        assert!(ctx.get_table("db2", "c").await.is_ok());
+    }
+
+    // Rename a missing table.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "RENAME TABLE a TO d").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().code(), 1025);
+    }
+
+    // Rename a missing table with IF EXISTS.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "RENAME TABLE IF EXISTS a TO d").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+        assert!(ctx.get_table("default", "d").await.is_err());
+    }
+
+    // Rename onto an existing table.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "ALTER TABLE db2.c RENAME TO default.b").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert!(res.is_err());
+        assert_eq!(res.err().unwrap().code(), 2302);
+        assert!(ctx.get_table("db2", "c").await.is_ok());
+    }
+
+    Ok(())
+}
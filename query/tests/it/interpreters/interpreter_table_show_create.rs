@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datavalues::prelude::*;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -47,12 +51,12 @@ async fn interpreter_show_create_table_test() -> Result<()> {
             "+-------+-------------------------------------+",
             "| Table | Create Table                        |",
             "+-------+-------------------------------------+",
-            "| a     | CREATE TABLE `a` (                  |",
-            "|       |   `a` Int64,                        |",
-            "|       |   `b` Int32,                        |",
-            "|       |   `c` String,                       |",
-            "|       |   `d` Int16,                        |",
-            "|       |   `e` Date16,                       |",
+            "| a     | CREATE TABLE a (                    |",
+            "|       |   a Int64,                          |",
+            "|       |   b Int32,                          |",
+            "|       |   c String,                         |",
+            "|       |   d Int16,                          |",
+            "|       |   e Date16                          |",
             "|       | ) ENGINE=Null COMMENT='test create' |",
             "+-------+-------------------------------------+",
         ];
@@ -61,3 +65,84 @@ async fn interpreter_show_create_table_test() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn interpreter_show_create_table_round_trip_test() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    execute_command(
+        ctx.clone(),
+        "CREATE TABLE default.`Round Trip`(\
+            id bigint NOT NULL, `select` int DEFAULT 1, `Name` varchar DEFAULT 'it''s', flag boolean\
+        ) Engine = Null COMMENT = 'it''s a test'",
+    )
+    .await?;
+    let create_sql = show_create_table(ctx.clone(), "`Round Trip`").await?;
+    assert_eq!(
+        create_sql,
+        "CREATE TABLE `Round Trip` (\n  \
+            id Int64 NOT NULL,\n  \
+            `select` Int32 DEFAULT 1,\n  \
+            `Name` String DEFAULT 'it''s',\n  \
+            flag Boolean\n\
+        ) ENGINE=Null COMMENT='it''s a test'"
+    );
+
+    // Executing the emitted DDL in another database gives back the same table.
+    execute_command(ctx.clone(), "CREATE DATABASE db2").await?;
+    ctx.set_current_database("db2".to_string()).await?;
+    execute_command(ctx.clone(), &create_sql).await?;
+    assert_eq!(
+        show_create_table(ctx.clone(), "`Round Trip`").await?,
+        create_sql
+    );
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog();
+    let source = catalog.get_table(&tenant, "default", "Round Trip").await?;
+    let target = catalog.get_table(&tenant, "db2", "Round Trip").await?;
+    assert_eq!(target.engine(), source.engine());
+    assert_eq!(target.options(), source.options());
+    let source_schema = source.schema();
+    let target_schema = target.schema();
+    assert_eq!(target_schema.num_fields(), source_schema.num_fields());
+    for (source, target) in source_schema
+        .fields()
+        .iter()
+        .zip(target_schema.fields().iter())
+    {
+        assert_eq!(target.name(), source.name());
+        assert_eq!(target.data_type(), source.data_type());
+        assert_eq!(target.is_nullable(), source.is_nullable());
+        assert_eq!(
+            target.default_expr().is_some(),
+            source.default_expr().is_some()
+        );
+    }
+
+    Ok(())
+}
+
+async fn execute_command(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+async fn show_create_table(ctx: Arc<QueryContext>, table: &str) -> Result<String> {
+    let query = format!("SHOW CREATE TABLE {}", table);
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    let blocks = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let create_sql = Vu8::try_create_viewer(blocks[0].column(1))?;
+    Ok(String::from_utf8_lossy(create_sql.value_at(0)).to_string())
+}
@@ -87,3 +87,31 @@ async fn test_truncate_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_truncate_table_interpreter_if_exists() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Truncate an unknown table.
+    {
+        let query = "TRUNCATE TABLE default.t_not_exists";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let result = interpreter.execute(None).await;
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap().code(), 1025);
+    }
+
+    // Truncate an unknown table with if exists.
+    {
+        let query = "TRUNCATE TABLE IF EXISTS default.t_not_exists";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
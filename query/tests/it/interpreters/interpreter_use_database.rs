@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
 #[tokio::test]
@@ -38,12 +45,44 @@ async fn test_use_database_interpreter_error() -> Result<()> {
     let ctx = crate::tests::create_query_context()?;
 
     let plan = PlanParser::parse(ctx.clone(), "USE xx").await?;
-    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
 
-    if let Err(e) = interpreter.execute(None).await {
-        let expect = "Code: 1003, displayText = Cannot USE 'xx', because the 'xx' doesn't exist.";
-        assert_eq!(expect, format!("{}", e));
-    }
+    let err = interpreter.execute(None).await.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::UnknownDatabaseCode());
+    assert_eq!(
+        err.message(),
+        "Cannot USE 'xx', because the 'xx' doesn't exist"
+    );
+    // The current database is unchanged.
+    assert_eq!(ctx.get_current_database(), "default");
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_use_database_resolve_unqualified_names() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    execute_query(ctx.clone(), "CREATE DATABASE db2").await?;
+    execute_query(ctx.clone(), "CREATE TABLE db2.t(a INT) Engine = Memory").await?;
+    execute_query(ctx.clone(), "USE db2").await?;
+    assert_eq!(ctx.get_current_database(), "db2");
+
+    // The unqualified names are resolved against the current database.
+    execute_query(ctx.clone(), "INSERT INTO t VALUES (1), (2)").await?;
+    let result = execute_query(ctx.clone(), "SELECT a FROM t ORDER BY a").await?;
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    assert_blocks_eq(expected, result.as_slice());
+
+    execute_query(ctx.clone(), "DROP TABLE t").await?;
+    let res = execute_query(ctx.clone(), "SELECT a FROM db2.t").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownTableCode());
+
+    Ok(())
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
 use common_meta_types::GrantObject;
@@ -23,8 +27,10 @@ use common_meta_types::UserGrantSet;
 use common_meta_types::UserInfo;
 use common_meta_types::UserPrivilegeType;
 use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
 use databend_query::sql::PlanParser;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -154,3 +160,101 @@ async fn test_grant_privilege_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_privilege_enforcement() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+
+    for query in [
+        "CREATE DATABASE db_grant_enforce",
+        "CREATE TABLE db_grant_enforce.t(a bigint) Engine = Memory",
+        "INSERT INTO db_grant_enforce.t VALUES(1), (2)",
+        "CREATE STAGE grant_enforce_stage",
+        "CREATE USER 'grant_enforce'@'localhost' IDENTIFIED BY 'password'",
+        "GRANT SELECT ON db_grant_enforce.* TO 'grant_enforce'@'localhost'",
+    ] {
+        execute(ctx.clone(), query).await?;
+    }
+
+    let root = user_mgr.get_user(&tenant, "root", "127.0.0.1").await?;
+
+    // A user with SELECT on the database can query its tables, but not drop them.
+    {
+        let user = user_mgr
+            .get_user(&tenant, "grant_enforce", "localhost")
+            .await?;
+        ctx.get_current_session().set_current_user(user);
+
+        let result = execute(ctx.clone(), "SELECT a FROM db_grant_enforce.t").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let res = execute(ctx.clone(), "DROP TABLE db_grant_enforce.t").await;
+        assert_eq!(
+            "Code: 1063, displayText = Permission denied, user 'grant_enforce'@'localhost' requires DROP privilege on 'db_grant_enforce'.*.",
+            res.unwrap_err().to_string()
+        );
+
+        // COPY INTO loads the table, OPTIMIZE rewrites its blocks.
+        let query =
+            "COPY INTO db_grant_enforce.t FROM '@grant_enforce_stage' FILE_FORMAT = (type = 'CSV')";
+        let res = execute(ctx.clone(), query).await;
+        assert_eq!(
+            "Code: 1063, displayText = Permission denied, user 'grant_enforce'@'localhost' requires INSERT privilege on 'db_grant_enforce'.'t'.",
+            res.unwrap_err().to_string()
+        );
+
+        let res = execute(ctx.clone(), "OPTIMIZE TABLE db_grant_enforce.t").await;
+        assert_eq!(
+            "Code: 1063, displayText = Permission denied, user 'grant_enforce'@'localhost' requires ALTER privilege on 'db_grant_enforce'.'t'.",
+            res.unwrap_err().to_string()
+        );
+    }
+
+    // The revoked privilege is checked by the queries of a new session.
+    {
+        ctx.get_current_session().set_current_user(root.clone());
+        let query = "REVOKE SELECT ON db_grant_enforce.* FROM 'grant_enforce'@'localhost'";
+        execute(ctx.clone(), query).await?;
+
+        let user = user_mgr
+            .get_user(&tenant, "grant_enforce", "localhost")
+            .await?;
+        ctx.get_current_session().set_current_user(user);
+
+        let res = execute(ctx.clone(), "SELECT a FROM db_grant_enforce.t").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::PermissionDeniedCode());
+
+        // The system tables backing SHOW statements stay readable, but not the users.
+        execute(ctx.clone(), "SELECT 1").await?;
+        execute(ctx.clone(), "SELECT name FROM system.databases").await?;
+        for query in [
+            "SELECT name FROM system.users",
+            "SELECT query_text FROM system.query_log",
+            "SELECT id FROM system.processes",
+        ] {
+            let res = execute(ctx.clone(), query).await;
+            assert_eq!(res.unwrap_err().code(), ErrorCode::PermissionDeniedCode());
+        }
+    }
+
+    // The built-in root user is not restricted.
+    {
+        ctx.get_current_session().set_current_user(root);
+        execute(ctx.clone(), "SELECT a FROM db_grant_enforce.t").await?;
+        execute(ctx.clone(), "DROP TABLE db_grant_enforce.t").await?;
+    }
+
+    Ok(())
+}
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::OnErrorMode;
+use common_meta_types::StageFileFormatType;
+use common_meta_types::StageStorage;
+use common_meta_types::StageType;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn execute(ctx: &std::sync::Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute(None).await?.try_collect().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_stage_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let tenant = ctx.get_tenant();
+
+    static CREATE_STAGE: &str = "CREATE STAGE mystage url='s3://load/files/' \
+        credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') \
+        file_format=(type=csv field_delimiter='|') copy_options=(on_error=continue) \
+        comment='test'";
+
+    {
+        let plan = PlanParser::parse(ctx.clone(), CREATE_STAGE).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "CreateUserStageInterpreter");
+        let _: Vec<DataBlock> = executor.execute(None).await?.try_collect().await?;
+
+        let stage = ctx.get_user_manager().get_stage(&tenant, "mystage").await?;
+        assert_eq!(stage.stage_type, StageType::External);
+        let StageStorage::S3(s3) = &stage.stage_params.storage;
+        assert_eq!(s3.bucket, "load");
+        assert_eq!(s3.path, "/files/");
+        assert_eq!(s3.credentials_aws_key_id, "1a2b3c");
+        assert_eq!(s3.credentials_aws_secret_key, "4x5y6z");
+        assert_eq!(stage.file_format_options.format, StageFileFormatType::Csv);
+        assert_eq!(stage.file_format_options.field_delimiter, "|");
+        assert_eq!(stage.copy_options.on_error, OnErrorMode::Continue);
+        assert_eq!(stage.comment, "test");
+    }
+
+    // Create again.
+    {
+        let res = execute(&ctx, CREATE_STAGE).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::stage_already_exists_code()
+        );
+
+        let query = "CREATE STAGE IF NOT EXISTS mystage";
+        execute(&ctx, query).await?;
+
+        // The existing stage is kept.
+        let stage = ctx.get_user_manager().get_stage(&tenant, "mystage").await?;
+        assert_eq!(stage.stage_type, StageType::External);
+    }
+
+    // Internal stage.
+    {
+        execute(&ctx, "CREATE STAGE internal_stage").await?;
+        let stage = ctx
+            .get_user_manager()
+            .get_stage(&tenant, "internal_stage")
+            .await?;
+        assert_eq!(stage.stage_type, StageType::Internal);
+    }
+
+    // Unknown copy option.
+    {
+        let res = execute(&ctx, "CREATE STAGE bad_stage copy_options=(purge=true)").await;
+        assert_eq!(
+            res.unwrap_err().message(),
+            "Unknown stage copy option: purge, must be one of [on_error, size_limit]"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_describe_stage_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    execute(
+        &ctx,
+        "CREATE STAGE mystage url='s3://load/files/' \
+         credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') \
+         file_format=(type=csv field_delimiter='|')",
+    )
+    .await?;
+
+    let plan = PlanParser::parse(ctx.clone(), "DESC STAGE mystage").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    assert_eq!(executor.name(), "DescribeUserStageInterpreter");
+    let blocks: Vec<DataBlock> = executor.execute(None).await?.try_collect().await?;
+
+    // property -> (value, changed)
+    let mut properties = HashMap::new();
+    for block in &blocks {
+        let names = Vu8::try_create_viewer(block.column(1))?;
+        let values = Vu8::try_create_viewer(block.column(3))?;
+        let changed = bool::try_create_viewer(block.column(5))?;
+        for row in 0..block.num_rows() {
+            let name = String::from_utf8_lossy(names.value_at(row)).to_string();
+            let value = String::from_utf8_lossy(values.value_at(row)).to_string();
+            properties.insert(name, (value, changed.value_at(row)));
+        }
+    }
+
+    let property = |name: &str| properties.get(name).cloned().unwrap();
+    assert_eq!(property("TYPE").0, "CSV");
+    assert_eq!(property("URL"), ("s3://load/files/".to_string(), true));
+    assert_eq!(property("FIELD_DELIMITER"), ("|".to_string(), true));
+    assert_eq!(property("SKIP_HEADER"), ("0".to_string(), false));
+    assert_eq!(property("ON_ERROR"), ("ABORT_STATEMENT".to_string(), false));
+
+    // The credentials are redacted.
+    assert_eq!(property("AWS_KEY_ID"), ("********".to_string(), true));
+    assert_eq!(property("AWS_SECRET_KEY"), ("********".to_string(), true));
+    assert_eq!(property("MASTER_KEY"), ("".to_string(), false));
+    for (value, _) in properties.values() {
+        assert!(!value.contains("1a2b3c") && !value.contains("4x5y6z"));
+    }
+
+    // Unknown stage.
+    let res = execute(&ctx, "DESC STAGE unknown_stage").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::unknown_stage_code());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_stage_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let tenant = ctx.get_tenant();
+    execute(&ctx, "CREATE STAGE mystage").await?;
+
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DROP STAGE mystage").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DropUserStageInterpreter");
+        let _: Vec<DataBlock> = executor.execute(None).await?.try_collect().await?;
+
+        let stages = ctx.get_user_manager().get_stages(&tenant).await?;
+        assert!(stages.is_empty());
+    }
+
+    {
+        let res = execute(&ctx, "DROP STAGE mystage").await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::unknown_stage_code());
+
+        execute(&ctx, "DROP STAGE IF EXISTS mystage").await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_list_stage_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let tenant = ctx.get_tenant();
+    execute(&ctx, "CREATE STAGE mystage").await?;
+
+    // Put the files into the stage, with nested prefixes.
+    let stage_info = ctx.get_user_manager().get_stage(&tenant, "mystage").await?;
+    let prefix = stage_info.get_prefix();
+    let operator = ctx.get_storage_operator().await?;
+    let files = vec![
+        ("readme.txt", "readme"),
+        ("data/a.csv", "1,a\n"),
+        ("data/2022/b.csv", "2,b\n3,c\n"),
+        ("data/2022/c.json", "{}"),
+    ];
+    for (file, content) in files {
+        operator
+            .object(&format!("{}{}", prefix, file))
+            .writer()
+            .write_bytes(content.as_bytes().to_vec())
+            .await
+            .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+    }
+
+    // The names and sizes of the listed files.
+    let list = |query: &'static str| {
+        let ctx = ctx.clone();
+        async move {
+            let plan = PlanParser::parse(ctx.clone(), query).await?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "ListUserStageInterpreter");
+            let blocks: Vec<DataBlock> = executor.execute(None).await?.try_collect().await?;
+
+            let mut files = vec![];
+            for block in &blocks {
+                let names = Vu8::try_create_viewer(block.column(0))?;
+                let sizes = u64::try_create_viewer(block.column(1))?;
+                for row in 0..block.num_rows() {
+                    let name = String::from_utf8_lossy(names.value_at(row)).to_string();
+                    files.push((name, sizes.value_at(row)));
+                }
+            }
+            Result::Ok(files)
+        }
+    };
+    let file = |name: &str, size: u64| (name.to_string(), size);
+
+    assert_eq!(list("LIST @mystage").await?, vec![
+        file("data/2022/b.csv", 8),
+        file("data/2022/c.json", 2),
+        file("data/a.csv", 4),
+        file("readme.txt", 6),
+    ]);
+
+    // The names are relative to the listed path.
+    assert_eq!(list("LIST @mystage/data/2022/").await?, vec![
+        file("b.csv", 8),
+        file("c.json", 2),
+    ]);
+
+    // The pattern must match the whole name, the same as the copy.
+    assert_eq!(list("LIST @mystage PATTERN = '.*[.]csv'").await?, vec![
+        file("data/2022/b.csv", 8),
+        file("data/a.csv", 4),
+    ]);
+    assert_eq!(
+        list("LIST '@mystage/data/' PATTERN = '[^/]*[.]csv'").await?,
+        vec![file("a.csv", 4)]
+    );
+
+    // No files.
+    assert_eq!(list("LIST @mystage/unknown/").await?, vec![]);
+
+    // Unknown stage.
+    let res = execute(&ctx, "LIST @unknown_stage").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::unknown_stage_code());
+
+    Ok(())
+}
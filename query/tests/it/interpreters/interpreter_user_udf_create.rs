@@ -84,5 +84,58 @@ async fn test_create_udf_interpreter() -> Result<()> {
         assert_eq!(udf.definition, "not(isnull(p))");
         assert_eq!(udf.description, "This is a description")
     }
+
+    {
+        let query = "CREATE FUNCTION abs AS (p) -> not(isnull(p))";
+        let r = PlanParser::parse(ctx.clone(), query).await;
+        assert!(r.is_err());
+        assert_eq!(
+            r.unwrap_err().to_string(),
+            "Code: 2603, displayText = It's a builtin function: abs."
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_typed_udf_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let ctx = crate::tests::create_query_context()?;
+    let tenant = ctx.get_tenant();
+
+    {
+        let query = "CREATE FUNCTION add_one(x INT) RETURNS BIGINT AS (x + 1) DESC = 'Add one'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+
+        let udf = ctx.get_user_manager().get_udf(&tenant, "add_one").await?;
+        assert_eq!(udf.parameters, vec!["x".to_string()]);
+        assert_eq!(udf.parameter_types, vec!["INT".to_string()]);
+        assert_eq!(udf.return_type, Some("BIGINT".to_string()));
+        assert_eq!(udf.definition, "(x + 1)");
+        assert_eq!(udf.description, "Add one");
+    }
+
+    // The definition is type checked on creation.
+    {
+        let query = "CREATE FUNCTION bad_add(x INT) RETURNS INT AS (x + 'a')";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert!(res.is_err());
+
+        let query = "CREATE FUNCTION bad_cast(x VARCHAR) RETURNS INT AS (parse_json(x))";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::IllegalDataTypeCode());
+
+        let query = "CREATE FUNCTION bad_param(x INT) RETURNS INT AS (x + y)";
+        let res = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::SyntaxExceptionCode());
+
+        let res = ctx.get_user_manager().get_udf(&tenant, "bad_add").await;
+        assert!(res.is_err());
+    }
+
     Ok(())
 }
@@ -0,0 +1,121 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::clusters::Cluster;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_create_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "CREATE TABLE default.a(a bigint, b varchar) Engine = Memory",
+        "INSERT INTO default.a VALUES(1, 'x'), (2, 'y'), (3, 'z'), (4, 'w')",
+    ] {
+        execute_query(ctx.clone(), query).await?;
+    }
+
+    // Create view.
+    {
+        let query = "CREATE VIEW default.v AS SELECT a, b FROM default.a WHERE a > 1";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "CreateViewInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Query the view with additional filters.
+    {
+        let query = "SELECT a, b FROM v WHERE a < 4 AND b != 'y'";
+        let result = execute_query(ctx.clone(), query).await?;
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 3 | z |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The view can't be created twice, unless IF NOT EXISTS or OR REPLACE.
+    {
+        let query = "CREATE VIEW v AS SELECT a FROM a";
+        let res = execute_query(ctx.clone(), query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::TableAlreadyExistsCode());
+
+        execute_query(
+            ctx.clone(),
+            "CREATE VIEW IF NOT EXISTS v AS SELECT a FROM a",
+        )
+        .await?;
+        execute_query(ctx.clone(), "CREATE OR REPLACE VIEW v AS SELECT b FROM a").await?;
+
+        // A new query context, the replaced view is not cached.
+        let ctx = new_query_context(ctx.clone())?;
+        let result = execute_query(ctx, "SELECT * FROM v WHERE b = 'x'").await?;
+        let expected = vec!["+---+", "| b |", "+---+", "| x |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // A table can't be replaced by a view.
+    {
+        let query = "CREATE OR REPLACE VIEW a AS SELECT 1";
+        let res = execute_query(ctx.clone(), query).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::TableAlreadyExistsCode());
+    }
+
+    // Drop the underlying table, the view fails at query time.
+    {
+        execute_query(ctx.clone(), "DROP TABLE a").await?;
+
+        let ctx = new_query_context(ctx.clone())?;
+        let res = execute_query(ctx, "SELECT * FROM v").await;
+        let err = res.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnknownTableCode());
+        assert!(err.message().ends_with(" (while in view 'default.v')"));
+    }
+
+    Ok(())
+}
+
+fn new_query_context(ctx: Arc<QueryContext>) -> Result<Arc<QueryContext>> {
+    Ok(QueryContext::create_from_shared(
+        QueryContextShared::try_create(
+            ctx.get_config(),
+            ctx.get_current_session(),
+            Cluster::empty(),
+        )?,
+    ))
+}
+
+async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan)?;
+    executor.execute(None).await?.try_collect::<Vec<_>>().await
+}
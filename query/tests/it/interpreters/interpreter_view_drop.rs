@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn test_drop_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    for query in [
+        "CREATE TABLE default.a(a bigint) Engine = Null",
+        "CREATE VIEW default.v AS SELECT a FROM default.a",
+    ] {
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // A table can't be dropped by DROP VIEW.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DROP VIEW a").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+    }
+
+    // Drop view.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DROP VIEW v").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "DropViewInterpreter");
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Drop a missing view.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "DROP VIEW v").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let res = executor.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::UnknownTableCode());
+
+        let plan = PlanParser::parse(ctx.clone(), "DROP VIEW IF EXISTS v").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    Ok(())
+}
@@ -14,12 +14,15 @@
 
 mod access;
 mod interpreter_admin_use_tenant;
+mod interpreter_copy;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_show_create;
+mod interpreter_delete;
 mod interpreter_explain;
 mod interpreter_factory_interceptor;
 mod interpreter_insert;
+mod interpreter_kill;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_databases;
@@ -30,9 +33,11 @@ mod interpreter_show_processlist;
 mod interpreter_show_settings;
 mod interpreter_show_tables;
 mod interpreter_show_users;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
+mod interpreter_table_rename;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_use_database;
@@ -41,7 +46,10 @@ mod interpreter_user_create;
 mod interpreter_user_drop;
 mod interpreter_user_previlege_revoke;
 mod interpreter_user_privilege_grant;
+mod interpreter_user_stage;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
+mod interpreter_view_create;
+mod interpreter_view_drop;
 mod plan_schedulers;
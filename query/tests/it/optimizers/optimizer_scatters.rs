@@ -157,22 +157,22 @@ async fn test_scatter_optimizer() -> Result<()> {
             query: "SELECT * FROM numbers_local(1) WHERE EXISTS(SELECT * FROM numbers_local(1))",
             expect: "\
             Projection: number:UInt64\
-            \n  Filter: exists(subquery(_subquery_1))\
+            \n  SemiJoin: keys=[], subquery(_subquery_1)\
             \n    Create sub queries sets: [_subquery_1]\
             \n      Projection: number:UInt64\
             \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
-            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
         },
         Test {
             name: "Standalone query with cluster subquery",
             query: "SELECT * FROM numbers_local(1) WHERE EXISTS(SELECT * FROM numbers(1))",
             expect: "Projection: number:UInt64\
-            \n  Filter: exists(subquery(_subquery_1))\
+            \n  SemiJoin: keys=[], subquery(_subquery_1)\
             \n    Create sub queries sets: [_subquery_1]\
             \n      RedistributeStage[expr: 0]\
             \n        Projection: number:UInt64\
             \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
-            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
         },
         Test {
             name: "Cluster query with standalone subquery",
@@ -180,12 +180,12 @@ async fn test_scatter_optimizer() -> Result<()> {
             expect: "\
             RedistributeStage[expr: 0]\
             \n  Projection: number:UInt64\
-            \n    Filter: exists(subquery(_subquery_1))\
+            \n    SemiJoin: keys=[], subquery(_subquery_1)\
             \n      Create sub queries sets: [_subquery_1]\
             \n        Broadcast in cluster\
             \n          Projection: number:UInt64\
             \n            ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
-            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
         },
         Test {
             name: "Cluster query with cluster subquery",
@@ -193,12 +193,12 @@ async fn test_scatter_optimizer() -> Result<()> {
             expect: "\
             RedistributeStage[expr: 0]\
             \n  Projection: number:UInt64\
-            \n    Filter: exists(subquery(_subquery_1))\
+            \n    SemiJoin: keys=[], subquery(_subquery_1)\
             \n      Create sub queries sets: [_subquery_1]\
             \n        Broadcast in cluster\
             \n          Projection: number:UInt64\
             \n            ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
-            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [exists(subquery(_subquery_1))]]",
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
         },
     ];
 
@@ -59,6 +59,50 @@ async fn test_simple_with_offset() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fetch_first() -> Result<()> {
+    let query =
+        "select number from numbers(1000) order by number offset 5 rows fetch first 10 rows only;";
+    let ctx = crate::tests::create_query_context()?;
+
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+
+    let mut optimizer = TopNPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    let expect = "\
+    Limit: 10, 5\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 15, order_by: [number]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fetch_first_with_ties() -> Result<()> {
+    let query = "select number from numbers(1000) order by number fetch first 10 rows with ties;";
+    let ctx = crate::tests::create_query_context()?;
+
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+
+    let mut optimizer = TopNPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    // The limit is not pushed down, the ties beyond it are unknown until the sort.
+    let expect = "\
+    Limit: 10 with ties\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1000, read_bytes: 8000, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_nested_projection() -> Result<()> {
     let query =
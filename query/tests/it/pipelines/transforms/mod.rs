@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod source_dal;
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_expression;
@@ -21,5 +22,8 @@ mod transform_group_by_partial;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
+mod transform_semi_join;
 mod transform_sort;
 mod transform_source;
+mod transform_unnest;
+mod transform_window;
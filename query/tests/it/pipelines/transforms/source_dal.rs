@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::pipelines::transforms::DataAccessor;
+use databend_query::pipelines::transforms::StageFile;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_data_accessor_match_files() -> Result<()> {
+    let files = vec![
+        "2022/02/b.csv".to_string(),
+        "2022/01/a.csv".to_string(),
+        "2022/01/a.csv.gz".to_string(),
+        "2021/12/c.json".to_string(),
+        "readme.txt".to_string(),
+    ];
+
+    struct Test {
+        pattern: &'static str,
+        expect: Vec<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            pattern: ".*[.]csv",
+            expect: vec!["2022/01/a.csv", "2022/02/b.csv"],
+        },
+        Test {
+            pattern: "2022/01/.*",
+            expect: vec!["2022/01/a.csv", "2022/01/a.csv.gz"],
+        },
+        Test {
+            pattern: ".*[.](csv|json)",
+            expect: vec!["2021/12/c.json", "2022/01/a.csv", "2022/02/b.csv"],
+        },
+        // The pattern must match the whole file name.
+        Test {
+            pattern: "a[.]csv",
+            expect: vec![],
+        },
+        Test {
+            pattern: ".*[.]parquet",
+            expect: vec![],
+        },
+    ];
+
+    for test in tests {
+        let matched = DataAccessor::match_files(files.clone(), test.pattern)?;
+        assert_eq!(matched, test.expect, "pattern: {}", test.pattern);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_accessor_match_files_invalid_pattern() -> Result<()> {
+    let result = DataAccessor::match_files(vec!["a.csv".to_string()], "[");
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), 1005);
+
+    Ok(())
+}
+
+#[test]
+fn test_data_accessor_match_stage_files() -> Result<()> {
+    let file = |path: &str, size: u64| StageFile {
+        path: path.to_string(),
+        size,
+        md5: None,
+        last_modified: None,
+    };
+    let files = vec![
+        file("2022/02/b.csv", 2),
+        file("2022/01/a.csv", 1),
+        file("readme.txt", 3),
+    ];
+
+    let matched = DataAccessor::match_stage_files(files, ".*[.]csv")?;
+    assert_eq!(matched, vec![
+        file("2022/01/a.csv", 1),
+        file("2022/02/b.csv", 2)
+    ]);
+
+    Ok(())
+}
@@ -15,6 +15,12 @@
 use std::sync::Arc;
 
 use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::Series;
+use common_datavalues::SeriesFrom;
+use common_datavalues::ToDataType;
 use common_exception::Result;
 use common_planners::*;
 use common_planners::{self};
@@ -23,6 +29,8 @@ use databend_query::pipelines::transforms::*;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
+use crate::tests::BlocksSource;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_transform_final_group_by() -> Result<()> {
     let ctx = crate::tests::create_query_context()?;
@@ -50,6 +58,7 @@ async fn test_transform_final_group_by() -> Result<()> {
     pipeline.add_source(Arc::new(source))?;
     pipeline.add_simple_transform(|| {
         Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
             aggr_partial.schema(),
             source_schema.clone(),
             aggr_exprs.to_vec(),
@@ -61,6 +70,7 @@ async fn test_transform_final_group_by() -> Result<()> {
     let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
     pipeline.add_simple_transform(|| {
         Ok(Box::new(GroupByFinalTransform::create(
+            ctx.clone(),
             aggr_final.schema(),
             max_block_size,
             source_schema.clone(),
@@ -91,3 +101,150 @@ async fn test_transform_final_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_with_spill() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    // Spill all the partitions after each block.
+    ctx.get_settings().set_settings(
+        "group_by_spill_threshold".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+
+    let aggr_final = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_final(
+            test_source.number_schema_for_test()?,
+            aggr_exprs,
+            group_exprs,
+        )?
+        .build()?;
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(5)?;
+    let source_schema = test_source.number_schema_for_test()?;
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByPartialTransform::create(
+            ctx.clone(),
+            aggr_partial.schema(),
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+    pipeline.merge_processor()?;
+
+    let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+    pipeline.add_simple_transform(|| {
+        Ok(Box::new(GroupByFinalTransform::create(
+            ctx.clone(),
+            aggr_final.schema(),
+            max_block_size,
+            source_schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        )))
+    })?;
+
+    // Result.
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    // The spilled result is the same as the in-memory one.
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 1           | 1           | 1      |",
+        "| 2           | 2           | 2      |",
+        "| 3           | 3           | 3      |",
+        "| 4           | 4           | 4      |",
+        "+-------------+-------------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_final_group_by_merge_spilled_states() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("number", u64::to_data_type())]);
+
+    // sum(number), avg(number)
+    let aggr_exprs = &[sum(col("number")), avg(col("number"))];
+    let group_exprs = &[col("number")];
+    let aggr_partial = PlanBuilder::create(schema.clone())
+        .aggregate_partial(aggr_exprs, group_exprs)?
+        .build()?;
+    let aggr_final = PlanBuilder::create(schema.clone())
+        .aggregate_final(schema.clone(), aggr_exprs, group_exprs)?
+        .build()?;
+
+    // The partial states of three blocks, the same keys are in several blocks.
+    let mut partial_blocks = vec![];
+    for numbers in [vec![0u64, 1, 2, 0], vec![2u64, 1, 0], vec![1u64, 1, 3]] {
+        let block = DataBlock::create(schema.clone(), vec![Series::from_data(numbers)]);
+        let mut partial = GroupByPartialTransform::create(
+            ctx.clone(),
+            aggr_partial.schema(),
+            schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        );
+        partial.connect_to(BlocksSource::create(schema.clone(), vec![block]))?;
+        let result = partial.execute().await?.try_collect::<Vec<_>>().await?;
+        partial_blocks.extend(result);
+    }
+    assert_eq!(partial_blocks.len(), 3);
+
+    let expected = vec![
+        "+-------------+-------------+--------+",
+        "| sum(number) | avg(number) | number |",
+        "+-------------+-------------+--------+",
+        "| 0           | 0           | 0      |",
+        "| 3           | 1           | 1      |",
+        "| 3           | 3           | 3      |",
+        "| 4           | 2           | 2      |",
+        "+-------------+-------------+--------+",
+    ];
+
+    // Spill each input block to disk, or merge the input blocks in memory, the states of the
+    // same key in the different blocks are merged, the key is output only once.
+    for spill_threshold in ["1", "1048576"] {
+        ctx.get_settings().set_settings(
+            "group_by_spill_threshold".to_string(),
+            spill_threshold.to_string(),
+            false,
+        )?;
+
+        let mut transform = GroupByFinalTransform::create(
+            ctx.clone(),
+            aggr_final.schema(),
+            ctx.get_settings().get_max_block_size()? as usize,
+            schema.clone(),
+            aggr_exprs.to_vec(),
+            group_exprs.to_vec(),
+        );
+        transform.connect_to(BlocksSource::create(
+            aggr_partial.schema(),
+            partial_blocks.clone(),
+        ))?;
+        let result = transform.execute().await?.try_collect::<Vec<_>>().await?;
+        assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 4);
+        common_datablocks::assert_blocks_sorted_eq(expected.clone(), result.as_slice());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::pipelines::processors::*;
+use databend_query::pipelines::transforms::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::BlocksSource;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_semi_join() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new_nullable("a", i64::to_data_type())]);
+    let subquery_schema =
+        DataSchemaRefExt::create(vec![DataField::new_nullable("b", u64::to_data_type())]);
+    let subquery = Expression::Subquery {
+        name: "_subquery_1".to_string(),
+        query_plan: Arc::new(PlanNode::Empty(EmptyPlan::create_with_schema(
+            subquery_schema,
+        ))),
+    };
+
+    // The input blocks carry the result of the subquery, a NULL matches nothing.
+    let subquery_field = subquery.to_data_field(&schema)?;
+    let input_schema =
+        DataSchemaRefExt::create(vec![schema.field(0).clone(), subquery_field.clone()]);
+    let subquery_data = DataValue::Array(vec![
+        DataValue::UInt64(1),
+        DataValue::UInt64(3),
+        DataValue::Null,
+    ]);
+    let block = |values: Vec<Option<i64>>| -> Result<DataBlock> {
+        let rows = values.len();
+        Ok(DataBlock::create(input_schema.clone(), vec![
+            Series::from_data(values),
+            subquery_field
+                .data_type()
+                .create_constant_column(&subquery_data, rows)?,
+        ]))
+    };
+
+    let tests = vec![
+        (false, vec![Some(1), Some(3)]),
+        (true, vec![Some(2), None, Some(4)]),
+    ];
+
+    for (anti, expect) in tests {
+        let blocks = vec![
+            block(vec![Some(1), Some(2), None])?,
+            block(vec![Some(3), Some(4)])?,
+        ];
+
+        let mut transform =
+            SemiJoinTransform::try_create(schema.clone(), anti, vec![col("a")], subquery.clone())?;
+        transform.connect_to(BlocksSource::create(input_schema.clone(), blocks))?;
+        let result = transform.execute().await?.try_collect::<Vec<_>>().await?;
+
+        let mut values = vec![];
+        for block in &result {
+            assert_eq!(block.schema(), &schema);
+            for row in 0..block.num_rows() {
+                values.push(block.column(0).get(row));
+            }
+        }
+
+        let expect = expect
+            .into_iter()
+            .map(|v| v.map(DataValue::Int64).unwrap_or(DataValue::Null))
+            .collect::<Vec<_>>();
+        assert_eq!(values, expect, "anti: {}", anti);
+    }
+
+    Ok(())
+}
@@ -51,6 +51,7 @@ async fn test_transform_sort() -> Result<()> {
             plan.schema(),
             sort_expression.to_vec(),
             None,
+            false,
         )?))
     })?;
 
@@ -61,6 +62,7 @@ async fn test_transform_sort() -> Result<()> {
                 plan.schema(),
                 sort_expression.to_vec(),
                 None,
+                false,
             )?))
         })?;
     }
@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::pipelines::processors::*;
+use databend_query::pipelines::transforms::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::BlocksSource;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_unnest() -> Result<()> {
+    let array_type = ArrayType::create(Int64Type::arc());
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u64::to_data_type()),
+        DataField::new_nullable("arr", Arc::new(array_type.clone())),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u64::to_data_type()),
+        DataField::new_nullable("arr", Arc::new(array_type.clone())),
+        DataField::new("x", i64::to_data_type()),
+    ]);
+
+    // [10, 11], [], NULL (with the elements [98, 99] underneath), [12]
+    let arrays = ArrayColumn::from_data(
+        Arc::new(array_type.clone()),
+        vec![0, 2, 2, 4, 5].into(),
+        Series::from_data(vec![10i64, 11, 98, 99, 12]),
+    );
+    let validity = [true, true, false, true]
+        .into_iter()
+        .collect::<MutableBitmap>();
+    let block = DataBlock::create(input_schema.clone(), vec![
+        Series::from_data(vec![1u64, 2, 3, 4]),
+        NullableColumn::new(arrays.arc(), validity.into()).arc(),
+    ]);
+
+    // The block of which no row has any element is dropped.
+    let empty_arrays = ArrayColumn::from_data(
+        Arc::new(array_type),
+        vec![0, 0].into(),
+        Series::from_data(Vec::<i64>::new()),
+    );
+    let validity = [true].into_iter().collect::<MutableBitmap>();
+    let empty_block = DataBlock::create(input_schema.clone(), vec![
+        Series::from_data(vec![5u64]),
+        NullableColumn::new(empty_arrays.arc(), validity.into()).arc(),
+    ]);
+
+    let mut transform = UnnestTransform::create(output_schema.clone(), "arr".to_string());
+    transform.connect_to(BlocksSource::create(input_schema, vec![block, empty_block]))?;
+    let result = transform.execute().await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(result.len(), 1);
+
+    // The outer columns are repeated for each element, the empty and NULL arrays produce no rows.
+    let block = &result[0];
+    assert_eq!(block.schema(), &output_schema);
+    assert_eq!(block.num_rows(), 3);
+    let column = |idx: usize| {
+        (0..block.num_rows())
+            .map(|row| block.column(idx).get(row))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(column(0), vec![
+        DataValue::UInt64(1),
+        DataValue::UInt64(1),
+        DataValue::UInt64(4)
+    ]);
+    let array =
+        |values: &[i64]| DataValue::Array(values.iter().map(|v| DataValue::Int64(*v)).collect());
+    assert_eq!(column(1), vec![
+        array(&[10, 11]),
+        array(&[10, 11]),
+        array(&[12])
+    ]);
+    assert_eq!(column(2), vec![
+        DataValue::Int64(10),
+        DataValue::Int64(11),
+        DataValue::Int64(12)
+    ]);
+    Ok(())
+}
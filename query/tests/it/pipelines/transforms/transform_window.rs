@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::pipelines::processors::*;
+use databend_query::pipelines::transforms::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::tests::BlocksSource;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_window() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("g", u64::to_data_type()),
+        DataField::new("v", i64::to_data_type()),
+    ]);
+    let window_func = Expression::WindowFunction {
+        op: "rank".to_string(),
+        args: vec![],
+        partition_by: vec![col("g")],
+        order_by: vec![sort("v", false, false)],
+    };
+    let output_schema = DataSchemaRefExt::create(vec![
+        DataField::new("g", u64::to_data_type()),
+        DataField::new("v", i64::to_data_type()),
+        window_func.to_data_field(&input_schema)?,
+    ]);
+
+    // The partitions span both blocks.
+    let blocks = vec![
+        DataBlock::create(input_schema.clone(), vec![
+            Series::from_data(vec![1u64, 2, 1]),
+            Series::from_data(vec![3i64, 5, 3]),
+        ]),
+        DataBlock::create(input_schema.clone(), vec![
+            Series::from_data(vec![2u64, 1]),
+            Series::from_data(vec![1i64, 4]),
+        ]),
+    ];
+
+    let mut transform = WindowTransform::create(output_schema.clone(), window_func);
+    transform.connect_to(BlocksSource::create(input_schema, blocks))?;
+    let result = transform.execute().await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(result.len(), 1);
+
+    // The rows are sorted by the partition and the order columns, the peers share the rank.
+    let block = &result[0];
+    assert_eq!(block.schema(), &output_schema);
+    let column = |idx: usize| {
+        (0..block.num_rows())
+            .map(|row| block.column(idx).get(row))
+            .collect::<Vec<_>>()
+    };
+    let values = |values: &[u64]| {
+        values
+            .iter()
+            .map(|v| DataValue::UInt64(*v))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(column(0), values(&[1, 1, 1, 2, 2]));
+    assert_eq!(column(1), vec![
+        DataValue::Int64(4),
+        DataValue::Int64(3),
+        DataValue::Int64(3),
+        DataValue::Int64(5),
+        DataValue::Int64(1)
+    ]);
+    assert_eq!(column(2), values(&[1, 2, 2, 1, 2]));
+    Ok(())
+}
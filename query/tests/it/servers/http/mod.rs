@@ -15,3 +15,4 @@
 mod block_to_json;
 mod http_query_handlers;
 mod statement;
+mod upload_to_stage;
@@ -0,0 +1,153 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::servers::http::v1::middleware::HTTPSessionEndpoint;
+use databend_query::servers::http::v1::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::upload_to_stage;
+use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::UploadToStageResponse;
+use databend_query::sessions::SessionManager;
+use hyper::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::put;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+use crate::tests::SessionManagerBuilder;
+
+const BOUNDARY: &str = "upload-to-stage-boundary";
+
+fn create_endpoint(session_manager: Arc<SessionManager>) -> HTTPSessionEndpoint<Route> {
+    Route::new()
+        .nest("/v1/query", query_route())
+        .at("/v1/upload_to_stage", put(upload_to_stage))
+        .with(HTTPSessionMiddleware { session_manager })
+}
+
+async fn execute_sql(ep: &HTTPSessionEndpoint<Route>, sql: &str) -> Result<QueryResponse> {
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 5}});
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    Ok(serde_json::from_str::<QueryResponse>(&body)?)
+}
+
+async fn upload(
+    ep: &HTTPSessionEndpoint<Route>,
+    relative_path: &str,
+    file_name: &str,
+) -> (StatusCode, String) {
+    let body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"upload\"; filename=\"{file_name}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n\
+         1,a\r\n\
+         --{boundary}--\r\n",
+        boundary = BOUNDARY,
+        file_name = file_name
+    );
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/upload_to_stage".parse().unwrap())
+                .method(Method::PUT)
+                .header(
+                    header::CONTENT_TYPE,
+                    format!("multipart/form-data; boundary={}", BOUNDARY),
+                )
+                .header("stage_name", "test_upload_stage")
+                .header("relative_path", relative_path)
+                .body(body),
+        )
+        .await
+        .unwrap_or_else(|err| err.as_response());
+    let status = response.status();
+    (status, response.into_body().into_string().await.unwrap())
+}
+
+async fn object_exists(session_manager: &Arc<SessionManager>, path: &str) -> Result<bool> {
+    let ctx = session_manager
+        .create_session("test")?
+        .create_query_context()
+        .await?;
+    let operator = ctx.get_storage_operator().await?;
+    Ok(operator.object(path).metadata().await.is_ok())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_upload_to_stage() -> Result<()> {
+    let session_manager = SessionManagerBuilder::create().build()?;
+    let ep = create_endpoint(session_manager.clone());
+    let result = execute_sql(&ep, "CREATE STAGE IF NOT EXISTS test_upload_stage").await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let (status, body) = upload(&ep, "a/b/", "books.csv").await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let response = serde_json::from_str::<UploadToStageResponse>(&body)?;
+    assert_eq!(response.stage_name, "test_upload_stage");
+    assert_eq!(response.files, vec!["books.csv".to_string()]);
+    assert!(object_exists(&session_manager, "/stage/test_upload_stage/a/b/books.csv").await?);
+
+    // The paths out of the stage are rejected, nothing is written.
+    let traversals = [
+        ("../evil", "books.csv", "/stage/evil/books.csv"),
+        ("a/../../evil", "books.csv", "/stage/evil/books.csv"),
+        ("a/../../../_b", "books.csv", "/_b/books.csv"),
+        ("/_b", "books.csv", "/_b/books.csv"),
+        ("./a", "books.csv", "/stage/test_upload_stage/a/books.csv"),
+        ("", "../evil.csv", "/stage/evil.csv"),
+        ("", "../../_ss/evil.csv", "/_ss/evil.csv"),
+        ("a", "b/evil.csv", "/stage/test_upload_stage/a/b/evil.csv"),
+        ("a", "..", "/stage/test_upload_stage"),
+    ];
+    for (relative_path, file_name, target) in traversals {
+        let (status, body) = upload(&ep, relative_path, file_name).await;
+        assert_eq!(
+            status,
+            StatusCode::BAD_REQUEST,
+            "{} {} {}",
+            relative_path,
+            file_name,
+            body
+        );
+        assert!(
+            !object_exists(&session_manager, target).await?,
+            "{}",
+            target
+        );
+    }
+
+    let result = execute_sql(&ep, "DROP STAGE test_upload_stage").await?;
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    Ok(())
+}
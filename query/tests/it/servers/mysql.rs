@@ -139,6 +139,37 @@ async fn test_rejected_session_with_parallel() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_authenticate_with_created_user() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(8).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let port = listening.port();
+
+    let mut root = create_connection(port).await?;
+    root.query_drop("CREATE USER 'mysql_auth_user'@'%' IDENTIFIED BY 'password'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create user")?;
+
+    // The scramble of the password is verified against the stored double sha1.
+    let conn = create_connection_with_user(port, "mysql_auth_user", "password").await?;
+    drop(conn);
+    let res = create_connection_with_user(port, "mysql_auth_user", "wrong").await;
+    assert!(res.is_err());
+    let res = create_connection_with_user(port, "mysql_auth_user", "").await;
+    assert!(res.is_err());
+
+    root.query_drop("DROP USER 'mysql_auth_user'@'%'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Drop user")?;
+    let res = create_connection_with_user(port, "mysql_auth_user", "password").await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 async fn create_connection(port: u16) -> Result<mysql_async::Conn> {
     let uri = &format!("mysql://127.0.0.1:{}", port);
     let opts = mysql_async::Opts::from_url(uri).unwrap();
@@ -147,6 +178,18 @@ async fn create_connection(port: u16) -> Result<mysql_async::Conn> {
         .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
 }
 
+async fn create_connection_with_user(
+    port: u16,
+    user: &str,
+    password: &str,
+) -> Result<mysql_async::Conn> {
+    let uri = &format!("mysql://{}:{}@127.0.0.1:{}", user, password, port);
+    let opts = mysql_async::Opts::from_url(uri).unwrap();
+    mysql_async::Conn::new(opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
+}
+
 struct EmptyRow;
 
 impl FromRow for EmptyRow {
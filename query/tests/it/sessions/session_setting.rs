@@ -19,7 +19,9 @@ use databend_query::sessions::SessionManager;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_session_setting() -> Result<()> {
-    let conf = crate::tests::ConfigBuilder::create().config();
+    // The global setting is loaded by the new sessions of the tenant, use a tenant of its own.
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.tenant_id = "test_session_setting".to_string();
 
     let session_manager = SessionManager::from_conf(conf.clone()).await.unwrap();
 
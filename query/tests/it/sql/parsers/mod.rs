@@ -16,8 +16,11 @@ mod parser_admin;
 mod parser_copy;
 mod parser_database;
 mod parser_optimize;
+mod parser_query;
 mod parser_show;
+mod parser_stage;
 mod parser_table;
 mod parser_udf;
 mod parser_use;
 mod parser_user;
+mod parser_view;
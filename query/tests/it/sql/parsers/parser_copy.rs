@@ -13,10 +13,15 @@
 // limitations under the License.
 
 use common_exception::Result;
+use databend_query::sql::statements::CopyIntoStageSource;
 use databend_query::sql::statements::DfCopy;
+use databend_query::sql::statements::DfCopyIntoStage;
 use databend_query::sql::DfStatement;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
+use sqlparser::ast::Statement;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
 
 use crate::sql::sql_parser::expect_parse_err;
 use crate::sql::sql_parser::expect_parse_ok;
@@ -29,36 +34,157 @@ fn copy_from_external_test() -> Result<()> {
         expect: Option<DfCopy>,
     }
 
-    let tests = vec![Test {
-        query: "copy into mytable
+    let tests = vec![
+        Test {
+            query: "copy into mytable
         from 's3://mybucket/data/files'
         credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1);",
-        err: "",
-        expect: Some(DfCopy {
-            name: ObjectName(vec![Ident::new("mytable")]),
-            columns: vec![],
-            location: "s3://mybucket/data/files".to_string(),
-            credential_options: maplit::hashmap! {
-                   "aws_key_id".into() => "my_key_id".into(),
-                   "aws_secret_key".into() => "my_secret_key".into(),
-            },
-            encryption_options: maplit::hashmap! {
-                   "master_key".into() => "my_master_key".into(),
-            },
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: maplit::hashmap! {
+                       "aws_key_id".into() => "my_key_id".into(),
+                       "aws_secret_key".into() => "my_secret_key".into(),
+                },
+                encryption_options: maplit::hashmap! {
+                       "master_key".into() => "my_master_key".into(),
+                },
 
-            file_format_options: maplit::hashmap! {
-                   "type".into() => "csv".into(),
-                   "field_delimiter".into() => "|".into(),
-                   "skip_header".into() => "1".into(),
-            },
-            files: vec![],
-            on_error: "".to_string(),
-            size_limit: "".to_string(),
-            validation_mode: "".to_string(),
-        }),
-    }];
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                       "field_delimiter".into() => "|".into(),
+                       "skip_header".into() => "1".into(),
+                },
+                null_if: vec![],
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "".to_string(),
+                max_files: "".to_string(),
+                commit_per_file: "".to_string(),
+                force: "".to_string(),
+                purge: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+        Test {
+            query: "copy into mytable (c3, c1)
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)
+        match_by_column_name = case_insensitive;",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![Ident::new("c3"), Ident::new("c1")],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                },
+                null_if: vec![],
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "case_insensitive".to_string(),
+                max_files: "".to_string(),
+                commit_per_file: "".to_string(),
+                force: "".to_string(),
+                purge: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+        Test {
+            query: "copy into mytable
+        from 's3://mybucket/data/files'
+        pattern = '.*[.]csv'
+        file_format = (type = csv);",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                },
+                null_if: vec![],
+                files: vec![],
+                pattern: ".*[.]csv".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "".to_string(),
+                max_files: "".to_string(),
+                commit_per_file: "".to_string(),
+                force: "".to_string(),
+                purge: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+        Test {
+            query: "copy into mytable
+        from 's3://mybucket/data/files'
+        file_format = (type = csv null_if = ('NULL', '-', '') empty_field_as_null = true);",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                       "empty_field_as_null".into() => "true".into(),
+                },
+                null_if: vec!["NULL".to_string(), "-".to_string(), "".to_string()],
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "".to_string(),
+                max_files: "".to_string(),
+                commit_per_file: "".to_string(),
+                force: "".to_string(),
+                purge: "".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+        Test {
+            query: "copy into mytable
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)
+        size_limit = 1024 max_files = 3 commit_per_file = true force = true purge = true;",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::hashmap! {
+                       "type".into() => "csv".into(),
+                },
+                null_if: vec![],
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "1024".to_string(),
+                match_by_column_name: "".to_string(),
+                max_files: "3".to_string(),
+                commit_per_file: "true".to_string(),
+                force: "true".to_string(),
+                purge: "true".to_string(),
+                validation_mode: "".to_string(),
+            }),
+        },
+    ];
 
     for test in tests {
         if test.err.is_empty() {
@@ -70,3 +196,50 @@ fn copy_from_external_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn copy_into_stage_test() -> Result<()> {
+    let query = match Parser::parse_sql(&GenericDialect {}, "select a, b from mytable")?.remove(0) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+
+    let tests = vec![
+        (
+            "copy into '@mystage/data' from mytable file_format = (type = csv);",
+            DfCopyIntoStage {
+                location: "@mystage/data".to_string(),
+                source: CopyIntoStageSource::Table(ObjectName(vec![Ident::new("mytable")])),
+                file_format_options: maplit::hashmap! {
+                    "type".into() => "csv".into(),
+                },
+                null_if: vec![],
+                max_file_size: "".to_string(),
+            },
+        ),
+        (
+            "copy into '@mystage' from (select a, b from mytable)
+            file_format = (type = json) max_file_size = 1024;",
+            DfCopyIntoStage {
+                location: "@mystage".to_string(),
+                source: CopyIntoStageSource::Query(query),
+                file_format_options: maplit::hashmap! {
+                    "type".into() => "json".into(),
+                },
+                null_if: vec![],
+                max_file_size: "1024".to_string(),
+            },
+        ),
+    ];
+
+    for (sql, expect) in tests {
+        expect_parse_ok(sql, DfStatement::CopyIntoStage(expect))?;
+    }
+
+    expect_parse_err(
+        "copy into '@mystage' mytable",
+        "sql parser error: Expected FROM, found: mytable".to_string(),
+    )?;
+
+    Ok(())
+}
@@ -49,6 +49,18 @@ fn create_database() -> Result<()> {
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "CREATE DATABASE db1 ENGINE = github(token = 'xxx') COMMENT = 'it''s'";
+        let expected = DfStatement::CreateDatabase(DfCreateDatabase {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+            engine: "github".to_string(),
+            engine_options: [("token".to_string(), "xxx".to_string())].into(),
+            options: [("comment".to_string(), "it's".to_string())].into(),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     {
         let sql = "CREATE DATABASE IF NOT EXISTS db1";
         let expected = DfStatement::CreateDatabase(DfCreateDatabase {
@@ -71,6 +83,7 @@ fn drop_database() -> Result<()> {
         let expected = DfStatement::DropDatabase(DfDropDatabase {
             if_exists: false,
             name: ObjectName(vec![Ident::new("db1")]),
+            cascade: false,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -79,6 +92,34 @@ fn drop_database() -> Result<()> {
         let expected = DfStatement::DropDatabase(DfDropDatabase {
             if_exists: true,
             name: ObjectName(vec![Ident::new("db1")]),
+            cascade: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "DROP DATABASE db1 RESTRICT";
+        let expected = DfStatement::DropDatabase(DfDropDatabase {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+            cascade: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "DROP DATABASE db1 CASCADE";
+        let expected = DfStatement::DropDatabase(DfDropDatabase {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1")]),
+            cascade: true,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    {
+        let sql = "DROP DATABASE IF EXISTS db1 CASCADE";
+        let expected = DfStatement::DropDatabase(DfDropDatabase {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1")]),
+            cascade: true,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::*;
+use pretty_assertions::assert_eq;
+
+use crate::sql::sql_parser::*;
+
+fn expect_same_parse(sql: &str, expected_sql: &str) -> Result<()> {
+    let (statements, _) = DfParser::parse_sql(sql)?;
+    let (expected, _) = DfParser::parse_sql(expected_sql)?;
+    assert_eq!(
+        statements, expected,
+        "'{}' SHOULD BE '{}'",
+        sql, expected_sql
+    );
+    Ok(())
+}
+
+#[test]
+fn exponent_operator() -> Result<()> {
+    expect_same_parse("SELECT 2 ** 3", "SELECT pow(2, 3)")?;
+    expect_same_parse("SELECT 2**3", "SELECT pow(2, 3)")?;
+
+    // right-associative
+    expect_same_parse("SELECT 2 ** 3 ** 2", "SELECT pow(2, pow(3, 2))")?;
+
+    // binds tighter than `*`, `/` and the unary minus of the base
+    expect_same_parse("SELECT 2 * 3 ** 2", "SELECT 2 * pow(3, 2)")?;
+    expect_same_parse("SELECT 2 ** 3 / 4", "SELECT pow(2, 3) / 4")?;
+    expect_same_parse("SELECT -2 ** 2", "SELECT -pow(2, 2)")?;
+    expect_same_parse("SELECT 2 ** -1", "SELECT pow(2, -1)")?;
+
+    // parenthesized, qualified and function call operands
+    expect_same_parse("SELECT (1 + 1) ** 2", "SELECT pow((1 + 1), 2)")?;
+    expect_same_parse("SELECT (2 ** 3) ** 2", "SELECT pow((pow(2, 3)), 2)")?;
+    expect_same_parse("SELECT 2 ** (1 + 2 ** 2)", "SELECT pow(2, (1 + pow(2, 2)))")?;
+    expect_same_parse(
+        "SELECT t.a ** abs(t.b) FROM t",
+        "SELECT pow(t.a, abs(t.b)) FROM t",
+    )?;
+
+    // the wildcard is untouched
+    expect_same_parse("SELECT * FROM t", "SELECT * FROM t")?;
+    expect_same_parse("SELECT count(*) * 2 FROM t", "SELECT count(*) * 2 FROM t")?;
+
+    expect_parse_err_contains(
+        "SELECT 2 ** FROM t",
+        "Expected an operand after **, found: FROM".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT 2 **",
+        "Expected an operand after **, found: EOF".to_string(),
+    )?;
+
+    Ok(())
+}
+
+fn expect_qualify(sql: &str, expected: Option<&str>) -> Result<()> {
+    let (statements, _) = DfParser::parse_sql(sql)?;
+    match &statements[0] {
+        DfStatement::Query(query) => {
+            let qualify = query.qualify.as_ref().map(|expr| expr.to_string());
+            assert_eq!(qualify.as_deref(), expected, "{}", sql);
+        }
+        other => panic!("'{}' SHOULD BE a query, but got: {:?}", sql, other),
+    }
+    Ok(())
+}
+
+#[test]
+fn qualify_clause() -> Result<()> {
+    expect_qualify("SELECT a FROM t QUALIFY b = 1", Some("b = 1"))?;
+    expect_qualify(
+        "SELECT a, row_number() OVER (ORDER BY a) AS rn FROM t WHERE a > 1 QUALIFY rn <= 2 ORDER BY a LIMIT 3",
+        Some("rn <= 2"),
+    )?;
+    expect_qualify(
+        "SELECT a FROM t GROUP BY a HAVING count(*) > 1 qualify rank() OVER (ORDER BY a) = 1;",
+        Some("rank() OVER (ORDER BY a) = 1"),
+    )?;
+
+    // the predicate of a subquery is moved to its projection, taken back at its analysis
+    expect_same_parse(
+        "SELECT * FROM (SELECT a FROM t QUALIFY (b + 1) = 2) AS s ORDER BY a",
+        "SELECT * FROM (SELECT a, ((b + 1) = 2) AS `__qualify` FROM t) AS s ORDER BY a",
+    )?;
+
+    // QUALIFY is an identifier before the FROM clause and after a period or AS
+    expect_qualify("SELECT qualify FROM t", None)?;
+    expect_qualify("SELECT t.qualify FROM t", None)?;
+    expect_qualify("SELECT a FROM t AS qualify", None)?;
+    expect_qualify("SELECT a IS DISTINCT FROM qualify FROM t", None)?;
+
+    // QUALIFY is an identifier where an operand is expected, or followed by an operator
+    expect_qualify("SELECT a FROM t WHERE qualify > 1", None)?;
+    expect_qualify(
+        "SELECT a FROM t WHERE b = qualify AND qualify IN (1, 2)",
+        None,
+    )?;
+    expect_qualify("SELECT a FROM t GROUP BY a, qualify", None)?;
+    expect_qualify("SELECT a FROM t qualify WHERE qualify.a > 1", None)?;
+    expect_qualify("SELECT a FROM t QUALIFY ORDER BY a", None)?;
+
+    Ok(())
+}
+
+#[test]
+fn ordered_aggregates() -> Result<()> {
+    expect_same_parse(
+        "SELECT ARRAY_AGG(a ORDER BY b) FROM t",
+        "SELECT ARRAY_AGG(a, b) FROM t",
+    )?;
+    expect_same_parse(
+        "SELECT array_agg(a ORDER BY b DESC) IGNORE NULLS FROM t",
+        "SELECT array_agg('DESC', 'IGNORE NULLS')(a, b) FROM t",
+    )?;
+    expect_same_parse(
+        "SELECT array_agg(a IGNORE NULLS ORDER BY t.b + 1 ASC) FROM t",
+        "SELECT array_agg('IGNORE NULLS')(a, t.b + 1) FROM t",
+    )?;
+    expect_same_parse(
+        "SELECT k, ARRAY_AGG(v ORDER BY o) RESPECT NULLS FROM t GROUP BY k",
+        "SELECT k, ARRAY_AGG(v, o) FROM t GROUP BY k",
+    )?;
+    expect_same_parse(
+        "SELECT k, FIRST_VALUE(v ORDER BY o) RESPECT NULLS, LAST_VALUE(v) IGNORE NULLS FROM t GROUP BY k",
+        "SELECT k, FIRST_VALUE(v, o), LAST_VALUE('IGNORE NULLS')(v) FROM t GROUP BY k",
+    )?;
+    expect_same_parse(
+        "SELECT array_agg(first_value(v ORDER BY o) ORDER BY k DESC) FROM t",
+        "SELECT array_agg('DESC')(first_value(v, o), k) FROM t",
+    )?;
+
+    // nested calls, the calls without option and with the parameters are untouched
+    expect_same_parse(
+        "SELECT array_agg(array_agg(v ORDER BY o) ORDER BY k DESC) FROM t",
+        "SELECT array_agg('DESC')(array_agg(v, o), k) FROM t",
+    )?;
+    expect_same_parse(
+        "SELECT array_agg(a), array_agg('DESC')(a, b) FROM t",
+        "SELECT array_agg(a), array_agg('DESC')(a, b) FROM t",
+    )?;
+    expect_same_parse(
+        "SELECT array_agg(DISTINCT a ORDER BY a) FROM t",
+        "SELECT array_agg(DISTINCT a, a) FROM t",
+    )?;
+
+    expect_parse_err_contains(
+        "SELECT array_agg(a ORDER BY b NULLS FIRST) FROM t",
+        "NULLS FIRST and NULLS LAST are not supported in array_agg".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT array_agg(a ORDER BY b, c) FROM t",
+        "Expected only one sort key in ORDER BY of array_agg".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT array_agg(a ORDER BY) FROM t",
+        "Expected a sort key after ORDER BY of array_agg".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT array_agg(ORDER BY b) FROM t",
+        "Expected an argument of array_agg".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT last_value(ORDER BY b) FROM t",
+        "Expected an argument of last_value".to_string(),
+    )?;
+    expect_parse_err_contains(
+        "SELECT array_agg(a IGNORE NULLS) RESPECT NULLS FROM t",
+        "Expected only one IGNORE NULLS or RESPECT NULLS of array_agg".to_string(),
+    )?;
+
+    Ok(())
+}
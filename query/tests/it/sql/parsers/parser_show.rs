@@ -29,51 +29,74 @@ fn show_queries() -> Result<()> {
     // positive case
     expect_parse_ok(
         "SHOW TABLES",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All)),
+        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All, false, None)),
     )?;
     expect_parse_ok(
         "SHOW TABLES;",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All)),
+        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All, false, None)),
     )?;
-    expect_parse_ok("SHOW SETTINGS", DfStatement::ShowSettings(DfShowSettings))?;
     expect_parse_ok(
-        "SHOW TABLES LIKE 'aaa'",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::Like(Ident::with_quote(
-            '\'', "aaa",
+        "SHOW SETTINGS",
+        DfStatement::ShowSettings(DfShowSettings::create(DfShowKind::All)),
+    )?;
+    expect_parse_ok(
+        "SHOW VARIABLES",
+        DfStatement::ShowSettings(DfShowSettings::create(DfShowKind::All)),
+    )?;
+    expect_parse_ok(
+        "SHOW SETTINGS LIKE 'max%'",
+        DfStatement::ShowSettings(DfShowSettings::create(DfShowKind::Like(Ident::with_quote(
+            '\'', "max%",
         )))),
     )?;
+    expect_parse_ok(
+        "SHOW TABLES LIKE 'aaa'",
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Like(Ident::with_quote('\'', "aaa")),
+            false,
+            None,
+        )),
+    )?;
 
     expect_parse_ok(
         "SHOW TABLES --comments should not in sql case1",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All)),
+        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All, false, None)),
     )?;
 
     expect_parse_ok(
         "SHOW TABLES LIKE 'aaa' --comments should not in sql case2",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::Like(Ident::with_quote(
-            '\'', "aaa",
-        )))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Like(Ident::with_quote('\'', "aaa")),
+            false,
+            None,
+        )),
     )?;
 
     expect_parse_ok(
         "SHOW TABLES WHERE t LIKE 'aaa'",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::Where(parse_sql_to_expr(
-            "t LIKE 'aaa'",
-        )))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Where(parse_sql_to_expr("t LIKE 'aaa'")),
+            false,
+            None,
+        )),
     )?;
 
     expect_parse_ok(
         "SHOW TABLES LIKE 'aaa' --comments should not in sql case2",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::Like(Ident::with_quote(
-            '\'', "aaa",
-        )))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Like(Ident::with_quote('\'', "aaa")),
+            false,
+            None,
+        )),
     )?;
 
     expect_parse_ok(
         "SHOW TABLES WHERE t LIKE 'aaa' AND t LIKE 'a%'",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::Where(parse_sql_to_expr(
-            "t LIKE 'aaa' AND t LIKE 'a%'",
-        )))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Where(parse_sql_to_expr("t LIKE 'aaa' AND t LIKE 'a%'")),
+            false,
+            None,
+        )),
     )?;
 
     Ok(())
@@ -81,19 +104,50 @@ fn show_queries() -> Result<()> {
 
 #[test]
 fn show_tables_test() -> Result<()> {
-    let mut ident = Ident::new("ss");
-    ident.quote_style = Some('`');
-    let v = vec![ident];
-    let name = ObjectName(v);
-    let name_two = name.clone();
-
     expect_parse_ok(
         "SHOW TABLES FROM `ss`",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::FromOrIn(name))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::All,
+            false,
+            Some("ss".to_string()),
+        )),
     )?;
     expect_parse_ok(
         "SHOW TABLES IN `ss`",
-        DfStatement::ShowTables(DfShowTables::create(DfShowKind::FromOrIn(name_two))),
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::All,
+            false,
+            Some("ss".to_string()),
+        )),
+    )?;
+    expect_parse_ok(
+        "SHOW TABLES FROM db LIKE 'ontime%'",
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Like(Ident::with_quote('\'', "ontime%")),
+            false,
+            Some("db".to_string()),
+        )),
+    )?;
+    expect_parse_ok(
+        "SHOW FULL TABLES IN db WHERE engine = 'FUSE'",
+        DfStatement::ShowTables(DfShowTables::create(
+            DfShowKind::Where(parse_sql_to_expr("engine = 'FUSE'")),
+            true,
+            Some("db".to_string()),
+        )),
+    )?;
+    expect_parse_ok(
+        "SHOW FULL TABLES",
+        DfStatement::ShowTables(DfShowTables::create(DfShowKind::All, true, None)),
+    )?;
+
+    expect_parse_err(
+        "SHOW FULL DATABASES",
+        "sql parser error: Expected tables, found: DATABASES".to_string(),
+    )?;
+    expect_parse_err(
+        "SHOW TABLES LIKE 'a%' FROM db",
+        "sql parser error: Expected end of statement, found: FROM".to_string(),
     )?;
 
     Ok(())
@@ -0,0 +1,145 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use databend_query::sql::statements::DfCreateStage;
+use databend_query::sql::statements::DfDescribeStage;
+use databend_query::sql::statements::DfDropStage;
+use databend_query::sql::statements::DfListStage;
+use databend_query::sql::*;
+
+use crate::sql::sql_parser::*;
+
+fn options(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn create_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "CREATE STAGE mystage",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: false,
+            stage_name: "mystage".to_string(),
+            location: "".to_string(),
+            credential_options: HashMap::new(),
+            encryption_options: HashMap::new(),
+            file_format_options: HashMap::new(),
+            null_if: vec![],
+            copy_options: HashMap::new(),
+            comment: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE STAGE IF NOT EXISTS mystage url='s3://load/files/' \
+         credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') \
+         encryption=(master_key='abc') \
+         file_format=(type=csv field_delimiter='|' null_if=('NULL')) \
+         copy_options=(on_error=continue size_limit=10) \
+         comment='my stage'",
+        DfStatement::CreateStage(DfCreateStage {
+            if_not_exists: true,
+            stage_name: "mystage".to_string(),
+            location: "s3://load/files/".to_string(),
+            credential_options: options(&[("aws_key_id", "1a2b3c"), ("aws_secret_key", "4x5y6z")]),
+            encryption_options: options(&[("master_key", "abc")]),
+            file_format_options: options(&[("type", "csv"), ("field_delimiter", "|")]),
+            null_if: vec!["NULL".to_string()],
+            copy_options: options(&[("on_error", "continue"), ("size_limit", "10")]),
+            comment: "my stage".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE STAGE mystage url='s3://load/files/' credentials=aws_key_id='1a2b3c'",
+        "Expected (".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn drop_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "DROP STAGE mystage",
+        DfStatement::DropStage(DfDropStage {
+            if_exists: false,
+            stage_name: "mystage".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP STAGE IF EXISTS mystage",
+        DfStatement::DropStage(DfDropStage {
+            if_exists: true,
+            stage_name: "mystage".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn describe_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "DESC STAGE mystage",
+        DfStatement::DescribeStage(DfDescribeStage {
+            stage_name: "mystage".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DESCRIBE STAGE mystage",
+        DfStatement::DescribeStage(DfDescribeStage {
+            stage_name: "mystage".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn list_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "LIST @mystage",
+        DfStatement::ListStage(DfListStage {
+            location: "@mystage".to_string(),
+            pattern: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "LIST @mystage/data/2022/ PATTERN = '.*[.]csv'",
+        DfStatement::ListStage(DfListStage {
+            location: "@mystage/data/2022/".to_string(),
+            pattern: ".*[.]csv".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "list '@mystage/data/' pattern = 'a.*'",
+        DfStatement::ListStage(DfListStage {
+            location: "@mystage/data/".to_string(),
+            pattern: "a.*".to_string(),
+        }),
+    )?;
+
+    Ok(())
+}
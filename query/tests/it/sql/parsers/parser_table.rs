@@ -13,12 +13,17 @@
 // limitations under the License.
 
 use common_exception::Result;
+use databend_query::sql::statements::AlterTableAction;
+use databend_query::sql::statements::DfAlterTable;
 use databend_query::sql::statements::DfCreateTable;
+use databend_query::sql::statements::DfDelete;
 use databend_query::sql::statements::DfDescribeTable;
 use databend_query::sql::statements::DfDropTable;
 use databend_query::sql::statements::DfQueryStatement;
+use databend_query::sql::statements::DfRenameTable;
 use databend_query::sql::statements::DfShowCreateTable;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUpdate;
 use databend_query::sql::*;
 use sqlparser::ast::*;
 
@@ -101,9 +106,12 @@ fn create_table() -> Result<()> {
             selection: None,
             group_by: vec![],
             having: None,
+            qualify: None,
             order_by: vec![],
             limit: None,
             offset: None,
+            fetch: None,
+            ctes: vec![],
         })),
     });
     expect_parse_ok(sql, expected)?;
@@ -142,13 +150,38 @@ fn create_table_select() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn create_table_primary_key() -> Result<()> {
+    let sql = "CREATE TABLE t(c1 int, c2 int, PRIMARY KEY (c1, c2))";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def("c1", DataType::Int(None)),
+            make_column_def("c2", DataType::Int(None)),
+        ],
+        engine: "FUSE".to_string(),
+        options: maplit::hashmap! {"primary_key".into() => "c1,c2".into()},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    expect_parse_err_contains(
+        "CREATE TABLE t(c1 int PRIMARY KEY, c2 int, PRIMARY KEY (c2))",
+        "multiple primary keys defined".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn drop_table() -> Result<()> {
     {
         let sql = "DROP TABLE t1";
         let expected = DfStatement::DropTable(DfDropTable {
             if_exists: false,
-            name: ObjectName(vec![Ident::new("t1")]),
+            names: vec![ObjectName(vec![Ident::new("t1")])],
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -157,11 +190,41 @@ fn drop_table() -> Result<()> {
         let sql = "DROP TABLE IF EXISTS t1";
         let expected = DfStatement::DropTable(DfDropTable {
             if_exists: true,
-            name: ObjectName(vec![Ident::new("t1")]),
+            names: vec![ObjectName(vec![Ident::new("t1")])],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP TABLE IF EXISTS t1, db1.t2, t3";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: true,
+            names: vec![
+                ObjectName(vec![Ident::new("t1")]),
+                ObjectName(vec![Ident::new("db1"), Ident::new("t2")]),
+                ObjectName(vec![Ident::new("t3")]),
+            ],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP TABLE t1, t2";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            names: vec![
+                ObjectName(vec![Ident::new("t1")]),
+                ObjectName(vec![Ident::new("t2")]),
+            ],
         });
         expect_parse_ok(sql, expected)?;
     }
 
+    expect_parse_err(
+        "DROP TABLE t1,",
+        "sql parser error: Expected identifier, found: EOF".to_string(),
+    )?;
+
     Ok(())
 }
 
@@ -202,6 +265,7 @@ fn truncate_table() -> Result<()> {
     {
         let sql = "TRUNCATE TABLE t1";
         let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             purge: false,
         });
@@ -211,11 +275,207 @@ fn truncate_table() -> Result<()> {
     {
         let sql = "TRUNCATE TABLE t1 purge";
         let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             purge: true,
         });
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "TRUNCATE TABLE IF EXISTS db1.t1";
+        let expected = DfStatement::TruncateTable(DfTruncateTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            purge: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn delete_from() -> Result<()> {
+    {
+        let sql = "DELETE FROM t1";
+        let expected = DfStatement::Delete(DfDelete {
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DELETE FROM db1.t1 WHERE a > 1";
+        let expected = DfStatement::Delete(DfDelete {
+            table_name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains("DELETE t1", "Expected FROM, found: t1".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn update() -> Result<()> {
+    {
+        let sql = "UPDATE t1 SET a = 1";
+        let expected = DfStatement::Update(DfUpdate {
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![(
+                Ident::new("a"),
+                Expr::Value(Value::Number("1".to_string(), false)),
+            )],
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE db1.t1 SET a = a + 1, b = 'x' WHERE a > 1";
+        let expected = DfStatement::Update(DfUpdate {
+            table_name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![
+                (Ident::new("a"), Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+                }),
+                (
+                    Ident::new("b"),
+                    Expr::Value(Value::SingleQuotedString("x".to_string())),
+                ),
+            ],
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains("UPDATE t1 a = 1", "Expected SET, found: a".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn rename_table() -> Result<()> {
+    {
+        let sql = "RENAME TABLE t1 TO t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: false,
+            name_map: vec![(
+                ObjectName(vec![Ident::new("t1")]),
+                ObjectName(vec![Ident::new("t2")]),
+            )],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "RENAME TABLE IF EXISTS db1.t1 TO db2.t2, t3 TO t4";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: true,
+            name_map: vec![
+                (
+                    ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+                    ObjectName(vec![Ident::new("db2"), Ident::new("t2")]),
+                ),
+                (
+                    ObjectName(vec![Ident::new("t3")]),
+                    ObjectName(vec![Ident::new("t4")]),
+                ),
+            ],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 RENAME TO db2.t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: false,
+            name_map: vec![(
+                ObjectName(vec![Ident::new("t1")]),
+                ObjectName(vec![Ident::new("db2"), Ident::new("t2")]),
+            )],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains("RENAME TABLE t1 t2", "Expected TO, found: t2".to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn alter_table() -> Result<()> {
+    {
+        let sql = "ALTER TABLE t1 ADD COLUMN c int";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::AddColumn(make_column_def("c", DataType::Int(None))),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE IF EXISTS db1.t1 ADD c varchar NOT NULL DEFAULT 'a'";
+        let mut column = make_column_def("c", DataType::Varchar(None));
+        column.options = vec![
+            ColumnOptionDef {
+                name: None,
+                option: ColumnOption::NotNull,
+            },
+            ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Default(Expr::Value(Value::SingleQuotedString(
+                    "a".to_string(),
+                ))),
+            },
+        ];
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: true,
+            table_name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            action: AlterTableAction::AddColumn(column),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP COLUMN c";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::DropColumn(Ident::new("c")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP c";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            if_exists: false,
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            action: AlterTableAction::DropColumn(Ident::new("c")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 MODIFY c int",
+        "Expected RENAME TO, ADD COLUMN or DROP COLUMN, found: MODIFY".to_string(),
+    )?;
+
     Ok(())
 }
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_meta_types::PythonHandler;
 use databend_query::sql::statements::DfAlterUDF;
 use databend_query::sql::statements::DfCreateUDF;
 use databend_query::sql::statements::DfDropUDF;
 use databend_query::sql::*;
+use sqlparser::ast::DataType;
 
 use crate::sql::sql_parser::*;
 
@@ -65,6 +67,9 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string()],
             definition: "not(isnotnull(p))".to_string(),
             description: "".to_string(),
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         }),
     )?;
 
@@ -76,6 +81,9 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
             description: "".to_string(),
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         }),
     )?;
 
@@ -97,6 +105,9 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
             description: "this is a description".to_string(),
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         }),
     )?;
 
@@ -108,12 +119,85 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(isnotnull(p,d))".to_string(),
             description: "this is a description".to_string(),
+            parameter_types: vec![],
+            return_type: None,
+            python_handler: None,
         }),
     )?;
 
     Ok(())
 }
 
+#[test]
+fn test_create_typed_udf() -> Result<()> {
+    expect_parse_ok(
+        "CREATE FUNCTION add_one(x INT) RETURNS INT AS (x + 1)",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "add_one".to_string(),
+            parameters: vec!["x".to_string()],
+            definition: "(x + 1)".to_string(),
+            description: "".to_string(),
+            parameter_types: vec![DataType::Int(None)],
+            return_type: Some(DataType::Int(None)),
+            python_handler: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE FUNCTION IF NOT EXISTS concat_ab(a VARCHAR, b BIGINT) RETURNS VARCHAR AS (concat(a, to_string(b))) DESC = 'concat'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: true,
+            udf_name: "concat_ab".to_string(),
+            parameters: vec!["a".to_string(), "b".to_string()],
+            definition: "(concat(a, to_string(b)))".to_string(),
+            description: "concat".to_string(),
+            parameter_types: vec![DataType::Varchar(None), DataType::BigInt(None)],
+            return_type: Some(DataType::Varchar(None)),
+            python_handler: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE FUNCTION add_one(x BIGINT) RETURNS BIGINT LANGUAGE python FILE = 'udfs.py' HANDLER = 'add_one' DESC = 'python'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "add_one".to_string(),
+            parameters: vec!["x".to_string()],
+            definition: "".to_string(),
+            description: "python".to_string(),
+            parameter_types: vec![DataType::BigInt(None)],
+            return_type: Some(DataType::BigInt(None)),
+            python_handler: Some(PythonHandler {
+                file: "udfs.py".to_string(),
+                function: "add_one".to_string(),
+            }),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION add_one(x BIGINT) RETURNS BIGINT LANGUAGE python HANDLER = 'add_one'",
+        "Expected FILE".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION add_one(x INT, x INT) RETURNS INT AS (x + 1)",
+        "Duplicate parameter is not allowed, keep only one: x".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION add_one(x INT) AS (x + 1)",
+        "Expected RETURNS".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION add_one(x) RETURNS INT AS (x + 1)",
+        "Expected a data type name".to_string(),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn test_drop_udf() -> Result<()> {
     expect_parse_ok(
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfCreateView;
+use databend_query::sql::statements::DfDropView;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+use crate::sql::sql_parser::*;
+
+fn native_query(sql: &str) -> Box<Query> {
+    match Parser::parse_sql(&GenericDialect {}, sql)
+        .unwrap()
+        .remove(0)
+    {
+        Statement::Query(query) => query,
+        statement => panic!("Expect query statement, but got: {}", statement),
+    }
+}
+
+#[test]
+fn create_view() -> Result<()> {
+    expect_parse_ok(
+        "CREATE VIEW v AS SELECT a, b FROM t WHERE a > 1",
+        DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            or_replace: false,
+            name: ObjectName(vec![Ident::new("v")]),
+            query: native_query("SELECT a, b FROM t WHERE a > 1"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE VIEW IF NOT EXISTS db1.v AS SELECT 1",
+        DfStatement::CreateView(DfCreateView {
+            if_not_exists: true,
+            or_replace: false,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v")]),
+            query: native_query("SELECT 1"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE OR REPLACE VIEW v AS SELECT count(*) FROM t GROUP BY a",
+        DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            or_replace: true,
+            name: ObjectName(vec![Ident::new("v")]),
+            query: native_query("SELECT count(*) FROM t GROUP BY a"),
+        }),
+    )?;
+
+    expect_parse_err(
+        "CREATE VIEW v SELECT 1",
+        "sql parser error: Expected AS, found: SELECT".to_string(),
+    )?;
+    expect_parse_err(
+        "CREATE OR VIEW v AS SELECT 1",
+        "sql parser error: Expected REPLACE, found: VIEW".to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn drop_view() -> Result<()> {
+    expect_parse_ok(
+        "DROP VIEW v",
+        DfStatement::DropView(DfDropView {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("v")]),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "DROP VIEW IF EXISTS db1.v",
+        DfStatement::DropView(DfDropView {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v")]),
+        }),
+    )?;
+
+    Ok(())
+}
@@ -93,6 +93,18 @@ async fn test_plan_parser() -> Result<()> {
             expect: "Drop table db1.t1, if_exists:true",
             error: "",
         },
+        Test {
+            name: "drop-multiple-tables-passed",
+            sql: "DROP TABLE IF EXISTS t1, db1.t2",
+            expect: "Drop table default.t1, db1.t2, if_exists:true",
+            error: "",
+        },
+        Test {
+            name: "drop-table-invalid-name",
+            sql: "DROP TABLE t1, a.b.c",
+            expect: "",
+            error: "Code: 1005, displayText = Drop table name must be [`db`].`table`.",
+        },
         Test {
             name: "describe-table-passed",
             sql: "DESCRIBE t1",
@@ -196,10 +208,57 @@ async fn test_plan_parser() -> Result<()> {
             error: "",
         },
         Test {
-            name: "unimplemented-cte",
-            sql: "with t as ( select sum(number) n from numbers_mt(1000) )select * from t",
+            name: "unimplemented-recursive-cte",
+            sql: "with recursive t as ( select sum(number) n from numbers_mt(1000) )select * from t",
             expect: "",
-            error: "Code: 1005, displayText = sql parser error: CTE is not yet implement.",
+            error: "Code: 1005, displayText = sql parser error: WITH RECURSIVE is not yet implement.",
+        },
+        Test {
+            name: "window-function",
+            sql: "select number, row_number() over (partition by number % 3 order by number) from numbers(10)",
+            expect: "\
+            Projection: number:UInt64, row_number() over (partition by (number % 3) order by number):UInt64\
+            \n  Window: row_number() over (partition by (number % 3) order by number)\
+            \n    Expression: number:UInt64, (number % 3):UInt8 (Before Window)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "window-function-qualify-alias",
+            sql: "select number, rank() over (order by number % 3 desc) as r from numbers(10) qualify r = 1 limit 2",
+            expect: "\
+            Limit: 2\
+            \n  Projection: number:UInt64, rank() over (order by (number % 3) desc) as r:UInt64\
+            \n    Filter: (rank() over (order by (number % 3) desc) = 1)\
+            \n      Expression: number:UInt64, rank() over (order by (number % 3) desc):UInt64, (rank() over (order by (number % 3) desc) = 1):Boolean (Before Projection)\
+            \n        Window: rank() over (order by (number % 3) desc)\
+            \n          Expression: number:UInt64, (number % 3):UInt8 (Before Window)\
+            \n            ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+            error: "",
+        },
+        Test {
+            name: "window-function-in-where",
+            sql: "select number from numbers(10) where row_number() over () > 1",
+            expect: "",
+            error: "Code: 1005, displayText = Window function row_number() over () is not allowed in WHERE (while in analyze select filter).",
+        },
+        Test {
+            name: "window-function-without-over",
+            sql: "select row_number() from numbers(10)",
+            expect: "",
+            error: "Code: 1005, displayText = Window function row_number must have an OVER clause (while in analyze select projection).",
+        },
+        Test {
+            name: "unimplemented-aggregate-window-function",
+            sql: "select sum(number) over (partition by number % 3) from numbers(10)",
+            expect: "",
+            error: "Code: 1002, displayText = Aggregate function sum OVER (...) is not yet implemented (while in analyze select projection).",
+        },
+        Test {
+            name: "duplicate-cte-name",
+            sql: "with t as (select 1), t as (select 2) select * from t",
+            expect: "",
+            error: "Code: 1005, displayText = sql parser error: Duplicate CTE name t.",
         },
         Test {
             name: "kleene-logic-null",
@@ -259,3 +318,65 @@ async fn test_plan_parser() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plan_parser_cte() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        sql: &'static str,
+        // The equivalent query with the CTEs written as subqueries.
+        expect_sql: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "cte",
+            sql: "with t as (select number from numbers(10) where number > 1) select * from t where number < 5",
+            expect_sql: "select * from (select number from numbers(10) where number > 1) as t where number < 5",
+        },
+        Test {
+            name: "cte-with-alias",
+            sql: "with t as (select number % 3 as n from numbers(10)) select a.n from t as a",
+            expect_sql: "select a.n from (select number % 3 as n from numbers(10)) as a",
+        },
+        Test {
+            name: "cte-refers-to-previous-cte",
+            sql: "with a as (select number from numbers(10)), b as (select number + 1 as n from a) select max(n) from b",
+            expect_sql: "select max(n) from (select number + 1 as n from (select number from numbers(10)) as a) as b",
+        },
+        Test {
+            name: "cte-in-subquery",
+            sql: "with t as (select number from numbers(10)) select * from (select number from t) as s",
+            expect_sql: "select * from (select number from (select number from numbers(10)) as t) as s",
+        },
+        Test {
+            name: "nested-with-in-cte",
+            sql: "with t as (with u as (select number from numbers(10)) select number from u) select * from t",
+            expect_sql: "select * from (select number from (select number from numbers(10)) as u) as t",
+        },
+        Test {
+            name: "cte-shadows-table",
+            sql: "with one as (select number as dummy from numbers(3)) select * from one",
+            expect_sql: "select * from (select number as dummy from numbers(3)) as one",
+        },
+        Test {
+            name: "inner-cte-shadows-outer-cte",
+            sql: "with t as (select number from numbers(10)) select * from (with t as (select number from numbers(3)) select * from t) as s",
+            expect_sql: "select * from (select * from (select number from numbers(3)) as t) as s",
+        },
+        Test {
+            name: "qualified-name-is-not-cte",
+            sql: "with one as (select number as dummy from numbers(3)) select * from system.one",
+            expect_sql: "select * from system.one",
+        },
+    ];
+
+    let ctx = crate::tests::create_query_context()?;
+    for t in tests {
+        let plan = PlanParser::parse(ctx.clone(), t.sql).await?;
+        let expect = PlanParser::parse(ctx.clone(), t.expect_sql).await?;
+        assert_eq!(format!("{:?}", expect), format!("{:?}", plan), "{}", t.name);
+    }
+
+    Ok(())
+}
@@ -14,4 +14,5 @@
 
 mod query;
 mod statement_copy;
+mod statement_database;
 mod statement_select;
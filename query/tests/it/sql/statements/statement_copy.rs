@@ -15,6 +15,8 @@
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
 use databend_query::sql::statements::AnalyzableStatement;
 use databend_query::sql::statements::AnalyzedResult;
 use databend_query::sql::DfParser;
@@ -38,7 +40,7 @@ async fn test_statement_copy() -> Result<()> {
         credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1)",
-            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" } ,validation_mode:None"#,
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
             err: "",
         },
 
@@ -51,7 +53,7 @@ async fn test_statement_copy() -> Result<()> {
         file_format = (type = csv field_delimiter = '|' skip_header = 1)
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" } ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
@@ -65,7 +67,7 @@ async fn test_statement_copy() -> Result<()> {
         file_format = (type = csv field_delimiter = '|' skip_header = 1)
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
@@ -80,10 +82,237 @@ async fn test_statement_copy() -> Result<()> {
         on_error = CONTINUE size_limit = 10
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: Continue, size_limit: 10 }, comment: "" } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "my_master_key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: Continue, size_limit: 10, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
+        TestCase {
+            name: "copy-external-on-error-abort-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        on_error = ABORT",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: AbortStatement, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-on-error-skip-file-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        on_error = SKIP_FILE",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: SkipFile, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-on-error-skip-file-num-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        on_error = SKIP_FILE_3",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: SkipFileNum(3), size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-on-error-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        on_error = IGNORE",
+            expect: "",
+            err: r#"Code: 1005, displayText = Unknown OnError mode:"IGNORE", must one of { CONTINUE | SKIP_FILE | SKIP_FILE_<num> | ABORT | ABORT_STATEMENT }."#,
+        },
+
+        TestCase {
+            name: "copy-external-file-limits-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        size_limit = 1024 max_files = 3 commit_per_file = TRUE",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 1024, match_by_column_name: None, max_files: 3, commit_per_file: true, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-max-files-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        max_files = a",
+            expect: "",
+            err: "Code: 1005, displayText = max_files must be number, got: a.",
+        },
+
+        TestCase {
+            name: "copy-external-commit-per-file-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        commit_per_file = 1",
+            expect: "",
+            err: "Code: 1005, displayText = commit_per_file must be true or false, got: 1.",
+        },
+
+        TestCase {
+            name: "copy-external-force-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        force = true",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: true, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-force-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        force = yes",
+            expect: "",
+            err: "Code: 1005, displayText = force must be true or false, got: yes.",
+        },
+
+        TestCase {
+            name: "copy-external-purge-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        purge = true",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: true }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-purge-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv)
+        purge = 1",
+            expect: "",
+            err: "Code: 1005, displayText = purge must be true or false, got: 1.",
+        },
+
+        TestCase {
+            name: "copy-external-tsv-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = tsv record_delimiter = '\r\n' quote = '`' escape = '#' skip_header = 2)",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Tsv, skip_header: 2, field_delimiter: "\t", record_delimiter: "\r\n", quote: "`", escape: "#", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-match-by-column-name-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv skip_header = 1)
+        match_by_column_name = case_insensitive",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: CaseInsensitive, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-match-by-column-name-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv skip_header = 1)
+        match_by_column_name = by_name",
+            expect: "",
+            err: r#"Code: 1005, displayText = Unknown match_by_column_name:"BY_NAME", must one of { CASE_SENSITIVE | CASE_INSENSITIVE | NONE }."#,
+        },
+
+        TestCase {
+            name: "copy-external-match-by-column-name-with-columns-error",
+            query: "copy into system.configs (value, name)
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv skip_header = 1)
+        match_by_column_name = case_sensitive",
+            expect: "",
+            err: "Code: 1005, displayText = match_by_column_name cannot be used together with a column list.",
+        },
+
+        TestCase {
+            name: "copy-external-compression-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files.csv.gz'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv compression = gzip)",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files.csv.gz", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files.csv.gz", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Gzip, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-compression-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files.csv.gz'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv compression = gz)",
+            expect: "",
+            err: r#"Code: 1005, displayText = File compression type error:"Unknown file compression type, must one of { AUTO | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAW_DEFLATE | LZO | SNAPPY | NONE }"."#,
+        },
+
+        TestCase {
+            name: "copy-external-pattern-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        pattern = '.*[.]csv'
+        file_format = (type = csv)",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,pattern:".*[.]csv" ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-files-and-pattern-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        files = ('file1.csv', 'file2.csv')
+        pattern = '.*[.]csv'
+        file_format = (type = csv)",
+            expect: "",
+            err: "Code: 1005, displayText = files and pattern cannot be used together.",
+        },
+
+        TestCase {
+            name: "copy-external-null-if-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv null_if = ('NULL', '') empty_field_as_null = TRUE)",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "mybucket", path: "/data/files", credentials_aws_key_id: "my_key_id", credentials_aws_secret_key: "my_secret_key", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: ["NULL", ""], empty_field_as_null: true }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-empty-field-as-null-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv empty_field_as_null = yes)",
+            expect: "",
+            err: "Code: 1005, displayText = empty_field_as_null must be true or false, got: yes.",
+        },
+
         TestCase {
             name: "copy-external-size-limit-error",
             query: "copy into system.configs
@@ -149,13 +378,37 @@ async fn test_statement_copy() -> Result<()> {
             query: "copy into system.configs
         from '@mystage'
         file_format = (type = csv field_delimiter = '|' skip_header = 1)",
-            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "", stage_type: Internal, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "", path: "", credentials_aws_key_id: "", credentials_aws_secret_key: "", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" } ,validation_mode:None"#,
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "mystage", stage_type: Internal, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "", path: "/stage/mystage/", credentials_aws_key_id: "", credentials_aws_secret_key: "", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
             err: "",
         },
+        TestCase {
+            name: "copy-internal-path-stage-format-ok",
+            query: "copy into system.configs
+        from '@mystage/data/files'",
+            expect: r#"Copy into system.configs ,UserStageInfo { stage_name: "mystage", stage_type: Internal, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "", path: "/stage/mystage/data/files", credentials_aws_key_id: "", credentials_aws_secret_key: "", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", quote: "\"", escape: "", compression: None, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,validation_mode:None"#,
+            err: "",
+        },
+        TestCase {
+            name: "copy-internal-unknown-stage",
+            query: "copy into system.configs
+        from '@unknown_stage'
+        file_format = (type = csv)",
+            expect: "",
+            err: "Code: 2501, displayText = Unknown stage unknown_stage.",
+        },
     ];
 
     for test in &tests {
         let ctx = create_query_context()?;
+        let stage_info = UserStageInfo {
+            stage_name: "mystage".to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        };
+        ctx.get_user_manager()
+            .add_stage(&ctx.get_tenant(), stage_info, false)
+            .await?;
+
         let (mut statements, _) = DfParser::parse_sql(test.query)?;
         let statement = statements.remove(0);
         if test.err.is_empty() {
@@ -184,3 +437,157 @@ async fn test_statement_copy() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement_copy_columns() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: Vec<&'static str>,
+        err: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "copy-columns-reordered-ok",
+            query: "copy into system.configs (value, name)
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)",
+            expect: vec!["value", "name"],
+            err: "",
+        },
+        TestCase {
+            name: "copy-columns-all-ok",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)",
+            expect: vec!["name", "value", "group", "description"],
+            err: "",
+        },
+        TestCase {
+            name: "copy-columns-unknown-column-error",
+            query: "copy into system.configs (value, unknown)
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)",
+            expect: vec![],
+            err: "Code: 1006, displayText = Unable to get field named \"unknown\". Valid fields: [\"name\", \"value\", \"group\", \"description\"].",
+        },
+    ];
+
+    for test in &tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(test.query)?;
+        let statement = statements.remove(0);
+        let result = statement.analyze(ctx).await;
+        if test.err.is_empty() {
+            match result? {
+                AnalyzedResult::SimpleQuery(v) => {
+                    let schema = v.schema();
+                    let names = schema
+                        .fields()
+                        .iter()
+                        .map(|f| f.name().as_str())
+                        .collect::<Vec<_>>();
+                    assert_eq!(test.expect, names, "{}", test.name)
+                }
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Query analyzed must be return SimpleQuery: {:}",
+                        test.name
+                    )));
+                }
+            }
+        } else {
+            assert_eq!(
+                test.err,
+                format!("{:}", result.err().unwrap()),
+                "{}",
+                test.name
+            )
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_copy_into_stage() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+        err: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "copy-into-stage-table-ok",
+            query: "copy into '@mystage/data' from system.configs file_format = (type = csv)",
+            expect: r#"Copy into @mystage ,UserStageInfo { stage_name: "mystage", stage_type: Internal, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "", path: "/stage/mystage/data", credentials_aws_key_id: "", credentials_aws_secret_key: "", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,max_file_size:0"#,
+            err: "",
+        },
+        TestCase {
+            name: "copy-into-stage-query-ok",
+            query: "copy into '@mystage' from (select name from system.configs)
+        file_format = (type = json) max_file_size = 1024",
+            expect: r#"Copy into @mystage ,UserStageInfo { stage_name: "mystage", stage_type: Internal, stage_params: StageParams { storage: S3(StageS3Storage { bucket: "", path: "/stage/mystage/", credentials_aws_key_id: "", credentials_aws_secret_key: "", encryption_master_key: "" }) }, file_format_options: FileFormatOptions { format: Json, skip_header: 0, field_delimiter: "", record_delimiter: "", quote: "", escape: "", compression: Auto, null_if: [], empty_field_as_null: false }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None, max_files: 0, commit_per_file: false, force: false, purge: false }, comment: "" } ,max_file_size:1024"#,
+            err: "",
+        },
+        TestCase {
+            name: "copy-into-external-location-error",
+            query: "copy into 's3://mybucket/data' from system.configs file_format = (type = csv)",
+            expect: "",
+            err: "Code: 1005, displayText = Copy into location must be a stage, got: s3://mybucket/data.",
+        },
+        TestCase {
+            name: "copy-into-stage-format-error",
+            query: "copy into '@mystage' from system.configs file_format = (type = parquet)",
+            expect: "",
+            err: "Code: 1005, displayText = Unsupported file format for copy into stage: Parquet, must be one of [csv, json].",
+        },
+        TestCase {
+            name: "copy-into-stage-max-file-size-error",
+            query: "copy into '@mystage' from system.configs file_format = (type = csv) max_file_size = abc",
+            expect: "",
+            err: "Code: 1005, displayText = max_file_size must be number, got: abc.",
+        },
+    ];
+
+    for test in &tests {
+        let ctx = create_query_context()?;
+        let stage_info = UserStageInfo {
+            stage_name: "mystage".to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        };
+        ctx.get_user_manager()
+            .add_stage(&ctx.get_tenant(), stage_info, false)
+            .await?;
+
+        let (mut statements, _) = DfParser::parse_sql(test.query)?;
+        let statement = statements.remove(0);
+        let result = statement.analyze(ctx).await;
+        if test.err.is_empty() {
+            match result? {
+                AnalyzedResult::SimpleQuery(v) => {
+                    assert_eq!(test.expect, format!("{:?}", v), "{}", test.name)
+                }
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Query analyzed must be return SimpleQuery: {:}",
+                        test.name
+                    )));
+                }
+            }
+        } else {
+            assert_eq!(
+                test.err,
+                format!("{:}", result.err().unwrap()),
+                "{}",
+                test.name
+            )
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use databend_query::sql::statements::AnalyzableStatement;
+use databend_query::sql::statements::AnalyzedResult;
+use databend_query::sql::DfParser;
+
+use crate::tests::create_query_context;
+
+#[tokio::test]
+async fn test_statement_database_name() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        unquoted_ident_case_sensitive: bool,
+        expect: &'static str,
+        err: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "create-unquoted-ok",
+            query: "CREATE DATABASE MyDB",
+            unquoted_ident_case_sensitive: true,
+            expect: "MyDB",
+            err: "",
+        },
+        TestCase {
+            name: "create-unquoted-case-insensitive-ok",
+            query: "CREATE DATABASE MyDB",
+            unquoted_ident_case_sensitive: false,
+            expect: "mydb",
+            err: "",
+        },
+        TestCase {
+            name: "create-quoted-mixed-case-ok",
+            query: "CREATE DATABASE `MyDB`",
+            unquoted_ident_case_sensitive: false,
+            expect: "MyDB",
+            err: "",
+        },
+        TestCase {
+            name: "create-multi-part-error",
+            query: "CREATE DATABASE a.b",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Create database name must not be qualified, but got a.b.",
+        },
+        TestCase {
+            name: "create-empty-error",
+            query: "CREATE DATABASE ``",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Create database name is empty.",
+        },
+        TestCase {
+            name: "drop-quoted-mixed-case-ok",
+            query: "DROP DATABASE IF EXISTS `MyDB`",
+            unquoted_ident_case_sensitive: false,
+            expect: "MyDB",
+            err: "",
+        },
+        TestCase {
+            name: "drop-unquoted-case-insensitive-ok",
+            query: "DROP DATABASE MyDB CASCADE",
+            unquoted_ident_case_sensitive: false,
+            expect: "mydb",
+            err: "",
+        },
+        TestCase {
+            name: "drop-multi-part-error",
+            query: "DROP DATABASE a.b",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Drop database name must not be qualified, but got a.b.",
+        },
+        TestCase {
+            name: "drop-empty-error",
+            query: "DROP DATABASE ``",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Drop database name is empty.",
+        },
+        TestCase {
+            name: "show-create-quoted-mixed-case-ok",
+            query: "SHOW CREATE DATABASE `MyDB`",
+            unquoted_ident_case_sensitive: false,
+            expect: "MyDB",
+            err: "",
+        },
+        TestCase {
+            name: "show-create-multi-part-error",
+            query: "SHOW CREATE DATABASE `a`.`b`",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Show create database name must not be qualified, but got `a`.`b`.",
+        },
+        TestCase {
+            name: "show-create-empty-error",
+            query: "SHOW CREATE DATABASE ``",
+            unquoted_ident_case_sensitive: true,
+            expect: "",
+            err: "Code: 1005, displayText = Show create database name is empty.",
+        },
+    ];
+
+    for test in &tests {
+        let ctx = create_query_context()?;
+        let case_sensitive = if test.unquoted_ident_case_sensitive {
+            "1"
+        } else {
+            "0"
+        };
+        ctx.get_settings().set_settings(
+            "unquoted_ident_case_sensitive".to_string(),
+            case_sensitive.to_string(),
+            false,
+        )?;
+
+        let (mut statements, _) = DfParser::parse_sql(test.query)?;
+        let statement = statements.remove(0);
+        if test.err.is_empty() {
+            let db = match statement.analyze(ctx).await? {
+                AnalyzedResult::SimpleQuery(v) => match *v {
+                    PlanNode::CreateDatabase(plan) => plan.db,
+                    PlanNode::DropDatabase(plan) => plan.db,
+                    PlanNode::ShowCreateDatabase(plan) => plan.db,
+                    other => {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "Unexpected plan {:?}: {:}",
+                            other, test.name
+                        )));
+                    }
+                },
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Query analyzed must be return SimpleQuery: {:}",
+                        test.name
+                    )));
+                }
+            };
+            assert_eq!(test.expect, db, "{}", test.name);
+        } else {
+            let result = statement.analyze(ctx).await;
+            assert_eq!(
+                test.err,
+                format!("{:}", result.err().unwrap()),
+                "{}",
+                test.name
+            )
+        }
+    }
+
+    Ok(())
+}
@@ -15,10 +15,12 @@
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserDefinedFunction;
 use databend_query::sql::statements::AnalyzableStatement;
 use databend_query::sql::statements::AnalyzedResult;
 use databend_query::sql::DfParser;
 use databend_query::sql::DfStatement;
+use databend_query::sql::PlanParser;
 
 use crate::tests::create_query_context;
 
@@ -46,6 +48,16 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT * FROM system.databases WHERE name = 'xxx' AND (name between 'aaa' and 'bbb')",
             expect: "QueryAnalyzeState { filter: ((name = xxx) AND ((name >= aaa) and (name <= bbb))), before_projection: [name], projection: [name] }",
         },
+        TestCase {
+            name: "Simple filter query is not distinct from",
+            query: "SELECT * FROM system.databases WHERE name IS NOT DISTINCT FROM 'xxx'",
+            expect: "QueryAnalyzeState { filter: if(isnull(name), false, (name = xxx)), before_projection: [name], projection: [name] }",
+        },
+        TestCase {
+            name: "Simple filter query is distinct from null",
+            query: "SELECT * FROM system.databases WHERE name IS DISTINCT FROM NULL",
+            expect: "QueryAnalyzeState { filter: isnotnull(name), before_projection: [name], projection: [name] }",
+        },
         TestCase {
             name: "Simple having query",
             query: "SELECT * FROM system.databases HAVING name = 'xxx'",
@@ -121,6 +133,11 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT avg(number), max(number + 1) + 1 FROM numbers_mt(10000) GROUP BY 1;",
             expect: "QueryAnalyzeState { before_group_by: [1, number, (number + 1)], aggregator: [1], aggregate: [avg(number), max((number + 1))], before_projection: [avg(number), (max((number + 1)) + 1)], projection: [avg(number), (max((number + 1)) + 1)] }",
         },
+        TestCase {
+            name: "Values list query with column aliases",
+            query: "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS t(id, name) WHERE id > 1",
+            expect: "QueryAnalyzeState { filter: (id > 1), before_projection: [id, name], projection: [id, name] }",
+        },
     ];
 
     for test_case in &tests {
@@ -153,3 +170,191 @@ async fn test_statement_select_analyze() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_statement_select_unnest_analyze() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        err: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Unnest without lateral table",
+            query: "SELECT * FROM UNNEST(number)",
+            err: "UNNEST in FROM clause must be cross joined with a table, e.g. FROM t CROSS JOIN UNNEST(t.arr)",
+        },
+        TestCase {
+            name: "Unnest unknown column",
+            query: "SELECT * FROM numbers(10) CROSS JOIN UNNEST(arr)",
+            err: "Unknown column arr",
+        },
+        TestCase {
+            name: "Unnest non-array column",
+            query: "SELECT * FROM numbers(10), UNNEST(number) AS u(x)",
+            err: "UNNEST argument must be Array type, but got UInt64",
+        },
+    ];
+
+    for test_case in &tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(test_case.query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query) => {
+                let result = query.analyze(ctx).await;
+                assert!(result.is_err(), "{:#?}", test_case.name);
+                assert_eq!(
+                    test_case.err,
+                    result.err().unwrap().message(),
+                    "{:#?}",
+                    test_case.name
+                );
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_select_exists_analyze() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Uncorrelated exists",
+            query: "SELECT * FROM numbers_local(1) WHERE EXISTS(SELECT * FROM numbers_local(2) WHERE number > 0)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  SemiJoin: keys=[], subquery(_subquery_1)\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        Filter: (number > 0)\
+            \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 0)]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+        },
+        TestCase {
+            name: "Uncorrelated not exists",
+            query: "SELECT * FROM numbers_local(1) WHERE NOT EXISTS(SELECT * FROM numbers_local(2))",
+            expect: "\
+            Projection: number:UInt64\
+            \n  AntiJoin: keys=[], subquery(_subquery_1)\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+        },
+        TestCase {
+            name: "Correlated exists",
+            query: "SELECT * FROM numbers_local(1) AS a WHERE EXISTS(SELECT * FROM numbers_local(2) AS b WHERE b.number = a.number)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  SemiJoin: keys=[number], subquery(_subquery_1)\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]",
+        },
+        TestCase {
+            name: "Correlated not exists with an inner filter",
+            query: "SELECT * FROM numbers_local(1) AS a WHERE a.number > 0 AND NOT EXISTS(SELECT * FROM numbers_local(2) AS b WHERE a.number = b.number AND b.number > 1)",
+            expect: "\
+            Projection: number:UInt64\
+            \n  AntiJoin: keys=[number], subquery(_subquery_1)\
+            \n    Create sub queries sets: [_subquery_1]\
+            \n      Projection: number:UInt64\
+            \n        Filter: (number > 1)\
+            \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]\
+            \n      Filter: (number > 0)\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 1, read_bytes: 8, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 0)]]",
+        },
+    ];
+
+    for test_case in &tests {
+        let ctx = create_query_context()?;
+        let plan = PlanParser::parse(ctx, test_case.query).await?;
+        assert_eq!(
+            test_case.expect,
+            format!("{:?}", plan),
+            "{:#?}",
+            test_case.name
+        );
+    }
+
+    // Only the equalities between the outer and the inner columns are decorrelated.
+    let query = "SELECT * FROM numbers_local(1) AS a WHERE EXISTS(SELECT * FROM numbers_local(2) AS b WHERE b.number > a.number)";
+    let ctx = create_query_context()?;
+    let cause = PlanParser::parse(ctx, query).await.unwrap_err();
+    assert_eq!(cause.code(), ErrorCode::UnImplementCode(), "{:#?}", query);
+    assert_eq!(
+        cause.message(),
+        "Correlated EXISTS subquery only supports the equalities between outer and inner columns ANDed in WHERE, but got: b.number > a.number"
+    );
+
+    // The EXISTS which are not ANDed in WHERE can't be correlated.
+    let query = "SELECT * FROM numbers_local(1) AS a WHERE a.number = 0 OR EXISTS(SELECT * FROM numbers_local(2) AS b WHERE b.number = a.number)";
+    let ctx = create_query_context()?;
+    let cause = PlanParser::parse(ctx, query).await.unwrap_err();
+    assert_eq!(cause.code(), ErrorCode::UnknownColumnCode(), "{:#?}", query);
+    assert!(
+        cause.message().starts_with("Unknown column a.number"),
+        "{:#?}",
+        cause.message()
+    );
+    assert!(
+        cause.message().ends_with(
+            " (while in EXISTS subquery, correlated subqueries are only supported in [NOT] EXISTS conditions ANDed in WHERE)"
+        ),
+        "{:#?}",
+        cause.message()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_select_python_udf_analyze() -> Result<()> {
+    let ctx = create_query_context()?;
+
+    // The quotes in the handler are escaped when the UDF is rewritten to python_udf.
+    let udf = UserDefinedFunction::new("quoted_udf", vec!["x".to_string()], "", "")
+        .with_types(vec!["BIGINT".to_string()], "BIGINT")
+        .with_python_handler("it's.py", "add_one");
+    ctx.get_user_manager()
+        .add_udf(&ctx.get_tenant(), udf, false)
+        .await?;
+
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "SELECT quoted_udf(number) FROM numbers_local(1)",
+    )
+    .await?;
+    let plan = format!("{:?}", plan);
+    assert!(
+        plan.contains("python_udf(it's.py, add_one, BIGINT, number)"),
+        "{}",
+        plan
+    );
+
+    // The python_udf is internal, it can't be called from SQL.
+    let query = "SELECT python_udf('it''s.py', 'add_one', 'BIGINT', number) FROM numbers_local(1)";
+    let cause = PlanParser::parse(ctx, query).await.unwrap_err();
+    assert_eq!(
+        cause.code(),
+        ErrorCode::UnknownFunctionCode(),
+        "{:#?}",
+        query
+    );
+    assert_eq!(cause.message(), "Unsupported Function: python_udf");
+
+    Ok(())
+}
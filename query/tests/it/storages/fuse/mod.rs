@@ -18,4 +18,4 @@ mod pruning;
 mod statistics;
 mod table;
 mod table_functions;
-mod table_test_fixture;
+pub mod table_test_fixture;
@@ -0,0 +1,165 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::ReadDataSourcePlan;
+use databend_query::sessions::QueryContext;
+use databend_query::storages::Table;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn read_table(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    push_downs: Option<Extras>,
+) -> Result<Vec<DataBlock>> {
+    let (_, parts) = table
+        .read_partitions(ctx.clone(), push_downs.clone())
+        .await?;
+    ctx.try_set_partitions(parts)?;
+    let stream = table
+        .read(ctx.clone(), &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs,
+        })
+        .await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+fn alter_plan(fixture: &TestFixture, operation: AlterTableOperation) -> AlterTablePlan {
+    AlterTablePlan {
+        tenant: fixture.default_tenant(),
+        if_exists: false,
+        db: fixture.default_db_name(),
+        table: fixture.default_table_name(),
+        operation,
+    }
+}
+
+#[tokio::test]
+async fn test_fuse_alter_table_add_drop_column() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 2 rows written with the original schema
+    let table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream_ex(1, 2, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // add a nullable column, and a column with default value
+    let v = DataField::new_nullable("v", i64::to_data_type());
+    let default_expr = Expression::create_literal(DataValue::Int64(7));
+    let w = DataField::new("w", i32::to_data_type())
+        .with_default_expr(Some(serde_json::to_vec(&default_expr)?));
+    for field in [v, w] {
+        let plan = alter_plan(&fixture, AlterTableOperation::AddColumn(field));
+        let table = fixture.latest_default_table().await?;
+        table.alter_schema(ctx.clone(), plan).await?;
+    }
+
+    // 1 row written with the new schema
+    let table = fixture.latest_default_table().await?;
+    let schema = table.schema();
+    assert_eq!(schema.fields().len(), 3);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![2i32]),
+        Series::from_data(vec![Some(20i64)]),
+        Series::from_data(vec![2i32]),
+    ]);
+    let stream = Box::pin(futures::stream::iter(vec![Ok(block)]));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // the old rows read the default values
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone(), None).await?;
+    let expected = vec![
+        "+----+------+---+",
+        "| id | v    | w |",
+        "+----+------+---+",
+        "| 1  | NULL | 7 |",
+        "| 1  | NULL | 7 |",
+        "| 2  | 20   | 2 |",
+        "+----+------+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // only the added column is projected
+    let push_downs = Some(Extras {
+        projection: Some(vec![2]),
+        ..Extras::default()
+    });
+    let blocks = read_table(ctx.clone(), table.clone(), push_downs).await?;
+    let expected = vec![
+        "+---+", //
+        "| w |", //
+        "+---+", //
+        "| 2 |", //
+        "| 7 |", //
+        "| 7 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // drop a column, the blocks of both schemas still read fine
+    let plan = alter_plan(&fixture, AlterTableOperation::DropColumn("v".to_string()));
+    table.alter_schema(ctx.clone(), plan).await?;
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone(), None).await?;
+    let expected = vec![
+        "+----+---+",
+        "| id | w |",
+        "+----+---+",
+        "| 1  | 7 |",
+        "| 1  | 7 |",
+        "| 2  | 2 |",
+        "+----+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // drop the dropped column
+    let plan = alter_plan(&fixture, AlterTableOperation::DropColumn("v".to_string()));
+    let res = table.alter_schema(ctx.clone(), plan).await;
+    assert_eq!(res.unwrap_err().code(), 1058);
+
+    // add an existing column
+    let field = DataField::new("id", i32::to_data_type());
+    let plan = alter_plan(&fixture, AlterTableOperation::AddColumn(field));
+    let res = table.alter_schema(ctx.clone(), plan).await;
+    assert_eq!(res.unwrap_err().code(), 1006);
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::ReadDataSourcePlan;
+use databend_query::catalogs::Catalog;
+use databend_query::clusters::Cluster;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::storages::fuse::io::MetaReaders;
+use databend_query::storages::fuse::meta::TableSnapshot;
+use databend_query::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use databend_query::storages::Table;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+pub async fn append_rows(fixture: &TestFixture, values: Vec<i32>) -> Result<()> {
+    let ctx = fixture.ctx();
+    let table = fixture.latest_default_table().await?;
+    let block = DataBlock::create(table.schema(), vec![Series::from_data(values)]);
+    let stream = Box::pin(futures::stream::iter(vec![Ok(block)]));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit_insertion(ctx, r.try_collect().await?, false)
+        .await
+}
+
+pub async fn read_table(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<Vec<DataBlock>> {
+    let (_, parts) = table.read_partitions(ctx.clone(), None).await?;
+    ctx.try_set_partitions(parts)?;
+    let stream = table
+        .read(ctx.clone(), &ReadDataSourcePlan {
+            table_info: Default::default(),
+            scan_fields: None,
+            parts: Default::default(),
+            statistics: Default::default(),
+            description: "".to_string(),
+            tbl_args: None,
+            push_downs: None,
+        })
+        .await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+pub async fn read_snapshot(
+    ctx: &QueryContext,
+    table: &dyn Table,
+) -> Result<(String, Arc<TableSnapshot>, HashSet<String>)> {
+    let snapshot_loc = table.options().get(TBL_OPT_KEY_SNAPSHOT_LOC).unwrap();
+    let snapshot = MetaReaders::table_snapshot_reader(ctx)
+        .read(snapshot_loc.as_str())
+        .await?;
+
+    let mut blocks = HashSet::new();
+    for segment_loc in &snapshot.segments {
+        let segment = MetaReaders::segment_info_reader(ctx)
+            .read(segment_loc.as_str())
+            .await?;
+        for block in &segment.blocks {
+            blocks.insert(block.location.path.clone());
+        }
+    }
+    Ok((snapshot_loc.clone(), snapshot, blocks))
+}
+
+pub fn new_query_context(ctx: Arc<QueryContext>) -> Result<Arc<QueryContext>> {
+    Ok(QueryContext::create_from_shared(
+        QueryContextShared::try_create(
+            ctx.get_config(),
+            ctx.get_current_session(),
+            Cluster::empty(),
+        )?,
+    ))
+}
+
+#[tokio::test]
+async fn test_fuse_delete() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 4 blocks, each of them in its own segment
+    for values in [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9], vec![
+        10, 11, 12,
+    ]] {
+        append_rows(&fixture, values).await?;
+    }
+    let table = fixture.latest_default_table().await?;
+    let (prev_loc, prev_snapshot, prev_blocks) =
+        read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(prev_blocks.len(), 4);
+
+    // [1, 2, 3] and [7, 8, 9] partially match, [4, 5, 6] fully matches, [10, 11, 12] doesn't match
+    let qry = format!("DELETE FROM {}.{} WHERE id >= 2 AND id <= 7", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone()).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 10 |", //
+        "| 11 |", //
+        "| 12 |", //
+        "| 8  |", //
+        "| 9  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let (_, snapshot, current_blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.summary.row_count, 6);
+    assert_eq!(snapshot.summary.block_count, 3);
+    assert_eq!(snapshot.segments.len(), 3);
+    // only the block not matching the selection is kept as it is
+    assert_eq!(current_blocks.intersection(&prev_blocks).count(), 1);
+
+    // the previous snapshot is still readable
+    let mut table_info = table.get_table_info().clone();
+    table_info
+        .meta
+        .options
+        .insert(TBL_OPT_KEY_SNAPSHOT_LOC.to_string(), prev_loc);
+    let prev_table = ctx.get_catalog().get_table_by_info(&table_info)?;
+    let blocks = read_table(ctx.clone(), prev_table).await?;
+    let rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+    assert_eq!(rows, 12);
+
+    // no row matches, no new snapshot is committed
+    let ctx = new_query_context(ctx)?;
+    let qry = format!("DELETE FROM {}.{} WHERE id > 100", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let (_, latest, _) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(latest.snapshot_id, snapshot.snapshot_id);
+
+    // the table cached by the query context is stale after the insertion, the delete conflicts
+    // with it, and is retried on the latest version of the table
+    append_rows(&fixture, vec![13, 14, 15]).await?;
+    let qry = format!("DELETE FROM {}.{} WHERE id = 1 OR id = 14", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone()).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 10 |", //
+        "| 11 |", //
+        "| 12 |", //
+        "| 13 |", //
+        "| 15 |", //
+        "| 8  |", //
+        "| 9  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // delete without selection removes all the rows
+    let ctx = new_query_context(ctx)?;
+    let qry = format!("DELETE FROM {}.{}", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let (_, latest, blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(latest.summary.row_count, 0);
+    assert!(blocks.is_empty());
+
+    Ok(())
+}
@@ -13,9 +13,13 @@
 //  limitations under the License.
 //
 
+mod alter;
 mod commit;
+mod delete;
 mod optimize;
 mod part_info;
 mod purge_drop;
 mod purge_truncate;
 mod read_plan;
+mod replace;
+mod update;
@@ -15,8 +15,14 @@
 
 use common_base::tokio;
 use common_exception::Result;
+use databend_query::catalogs::Catalog;
+use databend_query::storages::fuse::io::MetaReaders;
+use databend_query::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::operations::delete::append_rows;
+use crate::storages::fuse::operations::delete::new_query_context;
+use crate::storages::fuse::operations::delete::read_snapshot;
 use crate::storages::fuse::table_test_fixture::append_sample_data;
 use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
 use crate::storages::fuse::table_test_fixture::check_data_dir;
@@ -106,3 +112,87 @@ async fn test_fuse_history_optimize_compact() -> Result<()> {
     )
     .await
 }
+
+#[tokio::test]
+async fn test_fuse_table_optimize_compact_and_purge() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    let mut plan = fixture.default_crate_table_plan();
+    plan.table_meta
+        .options
+        .insert(TBL_OPT_KEY_ROW_PER_BLOCK.to_owned(), "30".to_owned());
+    ctx.get_catalog().create_table(plan.into()).await?;
+
+    // 100 tiny blocks, each of them in its own segment
+    for id in 0..100 {
+        append_rows(&fixture, vec![id]).await?;
+    }
+    let table = fixture.latest_default_table().await?;
+    let (prev_loc, prev_snapshot, prev_blocks) =
+        read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(prev_blocks.len(), 100);
+
+    let qry = format!("optimize table {}.{} compact", db, tbl);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+
+    // the rows are merged into the blocks of `row_per_block` rows, in a new snapshot
+    let table = fixture.latest_default_table().await?;
+    let (loc, snapshot, blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(blocks.len(), 4);
+    assert_eq!(blocks.intersection(&prev_blocks).count(), 0);
+    assert_eq!(snapshot.segments.len(), 1);
+    assert_eq!(snapshot.summary.row_count, 100);
+    assert_eq!(snapshot.summary.block_count, 4);
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+
+    let qry = format!(
+        "select count(*) as c, sum(id) as s, min(id) as min_id, max(id) as max_id from {}.{}",
+        db, tbl
+    );
+    let expected = vec![
+        "+-----+------+--------+--------+",
+        "| c   | s    | min_id | max_id |",
+        "+-----+------+--------+--------+",
+        "| 100 | 4950 | 0      | 99     |",
+        "+-----+------+--------+--------+",
+    ];
+    expects_ok(
+        "compacted_rows",
+        execute_query(new_query_context(ctx.clone())?, qry.as_str()).await,
+        expected.clone(),
+    )
+    .await?;
+
+    // the previous snapshot can still be read until it is purged
+    let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+    assert!(reader.read(prev_loc.as_str()).await.is_ok());
+
+    // nothing to compact, no new snapshot is committed
+    let qry = format!("optimize table {}.{} compact", db, tbl);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let (latest_loc, _, _) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(latest_loc, loc);
+
+    let qry = format!("optimize table {}.{} purge", db, tbl);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+
+    // the previous snapshots and the blocks of them are removed
+    let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+    assert!(reader.read(prev_loc.as_str()).await.is_err());
+    check_data_dir(&fixture, "compact_and_purge", 1, 1, 4).await;
+    history_should_have_only_one_item(&fixture, "compact_and_purge").await?;
+
+    let qry = format!(
+        "select count(*) as c, sum(id) as s, min(id) as min_id, max(id) as max_id from {}.{}",
+        db, tbl
+    );
+    expects_ok(
+        "purged_rows",
+        execute_query(new_query_context(ctx.clone())?, qry.as_str()).await,
+        expected,
+    )
+    .await
+}
@@ -45,3 +45,27 @@ async fn test_fuse_history_truncate_in_drop_stmt() -> Result<()> {
     .await;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_fuse_history_truncate_in_drop_database_cascade_stmt() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // ingests some test data
+    append_sample_data(10, &fixture).await?;
+    // let's Drop the database, together with the table
+    let qry = format!("drop database '{}' cascade", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    // there should be no files left inside test root (dirs are kept, though)
+    check_data_dir(
+        &fixture,
+        "drop database cascade: there should be no file left",
+        0,
+        0,
+        0,
+    )
+    .await;
+    Ok(())
+}
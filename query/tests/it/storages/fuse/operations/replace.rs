@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::catalogs::Catalog;
+
+use crate::storages::fuse::operations::delete::new_query_context;
+use crate::storages::fuse::operations::delete::read_snapshot;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_replace() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tenant = fixture.default_tenant();
+    let ctx = fixture.ctx();
+
+    let qry = format!("CREATE TABLE {}.t(id INT PRIMARY KEY, v VARCHAR)", db);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+
+    // 2 blocks, each of them in its own segment
+    for values in ["(1, 'a'), (2, 'b'), (3, 'c')", "(4, 'd'), (5, 'e')"] {
+        let qry = format!("INSERT INTO {}.t VALUES {}", db, values);
+        execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+    }
+    let table = ctx.get_catalog().get_table(&tenant, &db, "t").await?;
+    let (_, prev_snapshot, prev_blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+
+    // the last one of the rows having the same primary key is kept
+    let qry = format!("REPLACE INTO {}.t VALUES (2, 'x'), (6, 'y'), (6, 'z')", db);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+
+    let qry = format!("SELECT * FROM {}.t", db);
+    expects_ok(
+        "replace_rows_with_same_primary_key",
+        execute_query(new_query_context(ctx.clone())?, qry.as_str()).await,
+        vec![
+            "+----+---+",
+            "| id | v |",
+            "+----+---+",
+            "| 1  | a |",
+            "| 2  | x |",
+            "| 3  | c |",
+            "| 4  | d |",
+            "| 5  | e |",
+            "| 6  | z |",
+            "+----+---+",
+        ],
+    )
+    .await?;
+
+    let table = ctx.get_catalog().get_table(&tenant, &db, "t").await?;
+    let (_, snapshot, current_blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.summary.row_count, 6);
+    // the block without any replaced row is kept as it is
+    assert_eq!(current_blocks.intersection(&prev_blocks).count(), 1);
+
+    // INSERT OR REPLACE is the same as REPLACE
+    let qry = format!("INSERT OR REPLACE INTO {}.t(id, v) VALUES (1, 'p')", db);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+
+    // the concurrent replacements of the same key are retried on the latest snapshot
+    let ctx1 = new_query_context(ctx.clone())?;
+    let ctx2 = new_query_context(ctx.clone())?;
+    let qry1 = format!("REPLACE INTO {}.t VALUES (3, 'q')", db);
+    let qry2 = format!("REPLACE INTO {}.t VALUES (3, 'r')", db);
+    futures::future::try_join(
+        execute_command(ctx1, qry1.as_str()),
+        execute_command(ctx2, qry2.as_str()),
+    )
+    .await?;
+
+    let qry = format!(
+        "SELECT id, count(*) AS c FROM {}.t WHERE id IN (1, 3) GROUP BY id",
+        db
+    );
+    expects_ok(
+        "replace_concurrently",
+        execute_query(new_query_context(ctx.clone())?, qry.as_str()).await,
+        vec![
+            "+----+---+",
+            "| id | c |",
+            "+----+---+",
+            "| 1  | 1 |",
+            "| 3  | 1 |",
+            "+----+---+",
+        ],
+    )
+    .await?;
+
+    // the table without primary key can't be replaced into
+    let qry = format!("CREATE TABLE {}.t2(id INT)", db);
+    execute_command(new_query_context(ctx.clone())?, qry.as_str()).await?;
+    let qry = format!("REPLACE INTO {}.t2 VALUES (1)", db);
+    let res = execute_command(new_query_context(ctx.clone())?, qry.as_str()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BadArgumentsCode());
+
+    let qry = format!("REPLACE INTO {}.t SELECT * FROM {}.t", db, db);
+    let res = execute_command(new_query_context(ctx.clone())?, qry.as_str()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SyntaxExceptionCode());
+
+    let qry = format!("ALTER TABLE {}.t DROP COLUMN id", db);
+    let res = execute_command(new_query_context(ctx.clone())?, qry.as_str()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BadArgumentsCode());
+
+    Ok(())
+}
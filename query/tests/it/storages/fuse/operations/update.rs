@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::operations::delete::append_rows;
+use crate::storages::fuse::operations::delete::new_query_context;
+use crate::storages::fuse::operations::delete::read_snapshot;
+use crate::storages::fuse::operations::delete::read_table;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_update() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // 3 blocks, each of them in its own segment
+    for values in [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]] {
+        append_rows(&fixture, values).await?;
+    }
+    let table = fixture.latest_default_table().await?;
+    let (_, prev_snapshot, prev_blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+
+    // only the block [4, 5, 6] has rows matching the selection
+    let qry = format!(
+        "UPDATE {}.{} SET id = CAST(id * 10 AS INT) WHERE id >= 5 AND id <= 6",
+        db, tbl
+    );
+    execute_command(ctx.clone(), qry.as_str()).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone()).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 1  |", //
+        "| 2  |", //
+        "| 3  |", //
+        "| 4  |", //
+        "| 50 |", //
+        "| 60 |", //
+        "| 7  |", //
+        "| 8  |", //
+        "| 9  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let (_, snapshot, current_blocks) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(snapshot.prev_snapshot_id, Some(prev_snapshot.snapshot_id));
+    assert_eq!(snapshot.summary.row_count, 9);
+    assert_eq!(snapshot.summary.block_count, 3);
+    // the blocks not matching the selection are kept as they are
+    assert_eq!(current_blocks.intersection(&prev_blocks).count(), 2);
+
+    // one snapshot for each insertion, and one for the update
+    let qry = format!(
+        "select count(*) as count from fuse_history('{}', '{}')",
+        db, tbl
+    );
+    expects_ok(
+        "history_should_grow_by_one",
+        execute_query(ctx.clone(), qry.as_str()).await,
+        vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 4     |",
+            "+-------+",
+        ],
+    )
+    .await?;
+
+    // no row matches, no new snapshot is committed
+    let ctx = new_query_context(ctx)?;
+    let qry = format!("UPDATE {}.{} SET id = 0 WHERE id > 100", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let (_, latest, _) = read_snapshot(ctx.as_ref(), table.as_ref()).await?;
+    assert_eq!(latest.snapshot_id, snapshot.snapshot_id);
+
+    // the values that can't be cast to the column type without loss are rejected
+    for value in ["id + 1", "1.5", "'1'", "NULL"] {
+        let qry = format!("UPDATE {}.{} SET id = {}", db, tbl, value);
+        let res = execute_command(ctx.clone(), qry.as_str()).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::IllegalDataTypeCode());
+    }
+
+    let qry = format!("UPDATE {}.{} SET id = 1, id = 2", db, tbl);
+    let res = execute_command(ctx.clone(), qry.as_str()).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::SyntaxExceptionCode());
+
+    // update without selection updates all the rows
+    let qry = format!("UPDATE {}.{} SET id = 0", db, tbl);
+    execute_command(ctx.clone(), qry.as_str()).await?;
+    let table = fixture.latest_default_table().await?;
+    let blocks = read_table(ctx.clone(), table.clone()).await?;
+    let expected = vec![
+        "+----+", //
+        "| id |", //
+        "+----+", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "| 0  |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
@@ -162,6 +162,7 @@ async fn test_fuse_table_truncate() -> Result<()> {
 
     let table = fixture.latest_default_table().await?;
     let truncate_plan = TruncateTablePlan {
+        if_exists: false,
         db: fixture.default_db_name(),
         table: fixture.default_table_name(),
         purge: false,
@@ -91,6 +91,26 @@ fn test_range_filter() -> Result<()> {
             expect: true,
             error: "",
         },
+        Test {
+            name: "a is not distinct from 30",
+            expr: Expression::create_scalar_function("if", vec![
+                Expression::create_scalar_function("isnull", vec![col("a")]),
+                lit(false),
+                col("a").eq(lit(30)),
+            ]),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "b is distinct from 3",
+            expr: Expression::create_scalar_function("if", vec![
+                Expression::create_scalar_function("isnull", vec![col("b")]),
+                lit(true),
+                col("b").not_eq(lit(3)),
+            ]),
+            expect: true,
+            error: "",
+        },
         Test {
             name: "null",
             expr: Expression::create_literal(DataValue::Null),
@@ -135,6 +155,24 @@ fn test_range_filter() -> Result<()> {
             expect: true,
             error: "",
         },
+        Test {
+            name: "startswith(c, 'ab')",
+            expr: Expression::create_scalar_function("startswith", vec![
+                col("c"),
+                lit("ab".as_bytes()),
+            ]),
+            expect: true,
+            error: "",
+        },
+        Test {
+            name: "startswith(c, 'bcdf')",
+            expr: Expression::create_scalar_function("startswith", vec![
+                col("c"),
+                lit("bcdf".as_bytes()),
+            ]),
+            expect: false,
+            error: "",
+        },
         Test {
             name: "a + b > 30",
             expr: add(col("a"), col("b")).gt(lit(30i32)),
@@ -294,6 +332,30 @@ fn test_build_verifiable_function() -> Result<()> {
             ]),
             expect: "(min_c < ffffff)",
         },
+        Test {
+            name: "startswith(c, 'sys')",
+            expr: Expression::create_scalar_function("startswith", vec![
+                col("c"),
+                lit("sys".as_bytes()),
+            ]),
+            expect: "((max_c >= sys) and (min_c < syt))",
+        },
+        Test {
+            name: "startswith(c, '')",
+            expr: Expression::create_scalar_function("startswith", vec![
+                col("c"),
+                lit("".as_bytes()),
+            ]),
+            expect: "true",
+        },
+        Test {
+            name: "startswith('sys', c)",
+            expr: Expression::create_scalar_function("startswith", vec![
+                lit("sys".as_bytes()),
+                col("c"),
+            ]),
+            expect: "true",
+        },
         Test {
             name: "abs(a) = b - 3",
             expr: Expression::create_scalar_function("abs", vec![col("a")])
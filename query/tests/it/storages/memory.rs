@@ -186,6 +186,7 @@ async fn test_memorytable() -> Result<()> {
     // truncate.
     {
         let truncate_plan = TruncateTablePlan {
+            if_exists: false,
             db: "default".to_string(),
             table: "a".to_string(),
             purge: false,
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod fuse;
+pub mod fuse;
 mod index;
 mod memory;
 mod null;
@@ -72,6 +72,9 @@ async fn test_configs_table() -> Result<()> {
         "| mysql_handler_host                   | 127.0.0.1                | query   |             |",
         "| mysql_handler_port                   | 3307                     | query   |             |",
         "| num_cpus                             | 8                        | query   |             |",
+        "| python_udf_dir                       |                          | query   |             |",
+        "| python_udf_executable                | python3                  | query   |             |",
+        "| python_udf_timeout_millis            | 10000                    | query   |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                          | meta    |             |",
         "| rpc_tls_meta_service_domain_name     | localhost                | meta    |             |",
         "| rpc_tls_query_server_root_ca_cert    |                          | query   |             |",
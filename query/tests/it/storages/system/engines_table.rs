@@ -36,6 +36,7 @@ async fn test_engines_table() -> Result<()> {
         "| GITHUB | GITHUB Storage Engine |",
         "| MEMORY | MEMORY Storage Engine |",
         "| NULL   | NULL Storage Engine   |",
+        "| VIEW   | VIEW Storage Engine   |",
         "+--------+-----------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected.clone(), result.as_slice());
@@ -0,0 +1,135 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flatten_table() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        result: Vec<&'static str>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "flatten-array",
+            query: "select * from flatten('[1,[2,3]]')",
+            result: vec![
+                "+-----+------+------+-------+-------+-----------+",
+                "| seq | key  | path | index | value | this      |",
+                "+-----+------+------+-------+-------+-----------+",
+                "| 1   | NULL | [0]  | 0     | 1     | [1,[2,3]] |",
+                "| 1   | NULL | [1]  | 1     | [2,3] | [1,[2,3]] |",
+                "+-----+------+------+-------+-------+-----------+",
+            ],
+        },
+        Test {
+            name: "flatten-recursive",
+            query: "select * from flatten(input => '[1,[2,3]]', recursive => true)",
+            result: vec![
+                "+-----+------+--------+-------+-------+-----------+",
+                "| seq | key  | path   | index | value | this      |",
+                "+-----+------+--------+-------+-------+-----------+",
+                "| 1   | NULL | [0]    | 0     | 1     | [1,[2,3]] |",
+                "| 1   | NULL | [1][0] | 0     | 2     | [2,3]     |",
+                "| 1   | NULL | [1][1] | 1     | 3     | [2,3]     |",
+                "+-----+------+--------+-------+-------+-----------+",
+            ],
+        },
+        Test {
+            name: "flatten-path",
+            query: r#"select * from flatten(input => '{"items":[{"id":1},{"id":2}]}', path => '$.items')"#,
+            result: vec![
+                "+-----+------+----------+-------+----------+---------------------+",
+                "| seq | key  | path     | index | value    | this                |",
+                "+-----+------+----------+-------+----------+---------------------+",
+                r#"| 1   | NULL | items[0] | 0     | {"id":1} | [{"id":1},{"id":2}] |"#,
+                r#"| 1   | NULL | items[1] | 1     | {"id":2} | [{"id":1},{"id":2}] |"#,
+                "+-----+------+----------+-------+----------+---------------------+",
+            ],
+        },
+        Test {
+            name: "flatten-object-mode",
+            query: r#"select * from flatten(input => '{"a":[1,2],"b":{"c":3}}', recursive => true, mode => 'object')"#,
+            result: vec![
+                "+-----+-----+------+-------+-------+-------------------------+",
+                "| seq | key | path | index | value | this                    |",
+                "+-----+-----+------+-------+-------+-------------------------+",
+                r#"| 1   | a   | a    | NULL  | [1,2] | {"a":[1,2],"b":{"c":3}} |"#,
+                r#"| 1   | c   | b.c  | NULL  | 3     | {"c":3}                 |"#,
+                "+-----+-----+------+-------+-------+-------------------------+",
+            ],
+        },
+        Test {
+            name: "flatten-empty",
+            query: "select * from flatten(input => '[]')",
+            result: vec!["++", "++"],
+        },
+        Test {
+            name: "flatten-outer",
+            query: "select * from flatten(input => '[]', outer => true)",
+            result: vec![
+                "+-----+------+------+-------+-------+------+",
+                "| seq | key  | path | index | value | this |",
+                "+-----+------+------+-------+-------+------+",
+                "| 1   | NULL |      | NULL  | NULL  | []   |",
+                "+-----+------+------+-------+-------+------+",
+            ],
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expect = test.result;
+        let actual = result.as_slice();
+        common_datablocks::assert_blocks_sorted_eq_with_name(test.name, expect, actual);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flatten_table_error() -> Result<()> {
+    let tests = vec![
+        (
+            "select * from flatten(input => '[1]', mode => 'map')",
+            "Code: 1006, displayText = Unknown FLATTEN mode: \"map\", must be one of { OBJECT | ARRAY | BOTH }.",
+        ),
+        (
+            "select * from flatten(path => '$.a')",
+            "Code: 1006, displayText = Table function flatten requires the input argument.",
+        ),
+        (
+            "select * from flatten(input => '[1]', depth => 1)",
+            "Code: 1006, displayText = Unknown argument depth of table function flatten.",
+        ),
+    ];
+
+    for (query, expect) in tests {
+        let ctx = crate::tests::create_query_context()?;
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(expect, result.unwrap_err().to_string(), "{}", query);
+    }
+    Ok(())
+}
@@ -12,5 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.W
 
+mod flatten_table;
 mod memory_block_part;
 mod numbers_table;
+mod values_table;
@@ -0,0 +1,131 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_values_table() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        result: Vec<&'static str>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "values-with-column-aliases",
+            query: "select * from (values (1, 'a'), (2, 'b')) as t(id, name)",
+            result: vec![
+                "+----+------+",
+                "| id | name |",
+                "+----+------+",
+                "| 1  | a    |",
+                "| 2  | b    |",
+                "+----+------+",
+            ],
+        },
+        Test {
+            name: "values-default-column-names",
+            query: "select column2, column1 from (values (1, 'a'), (2, 'b'))",
+            result: vec![
+                "+---------+---------+",
+                "| column2 | column1 |",
+                "+---------+---------+",
+                "| a       | 1       |",
+                "| b       | 2       |",
+                "+---------+---------+",
+            ],
+        },
+        Test {
+            name: "values-int-and-null",
+            query: "select id, name from (values (1, NULL), (NULL, 'b')) as t(id, name)",
+            result: vec![
+                "+------+------+",
+                "| id   | name |",
+                "+------+------+",
+                "| 1    | NULL |",
+                "| NULL | b    |",
+                "+------+------+",
+            ],
+        },
+        Test {
+            name: "values-mixed-types",
+            query: "select a + 1, b from (values (1, 1), (300, 2.5), (-1, 3)) as t(a, b) order by a",
+            result: vec![
+                "+---------+-----+",
+                "| (a + 1) | b   |",
+                "+---------+-----+",
+                "| 0       | 3   |",
+                "| 2       | 1   |",
+                "| 301     | 2.5 |",
+                "+---------+-----+",
+            ],
+        },
+        Test {
+            name: "values-filter-and-aggregate",
+            query: "select name, sum(id) from (values (1, 'a'), (2, 'b'), (3, 'a')) as t(id, name) where t.id > 1 group by name",
+            result: vec![
+                "+------+---------+",
+                "| name | sum(id) |",
+                "+------+---------+",
+                "| a    | 3       |",
+                "| b    | 2       |",
+                "+------+---------+",
+            ],
+        },
+    ];
+
+    for test in tests {
+        let ctx = crate::tests::create_query_context()?;
+        let plan = PlanParser::parse(ctx.clone(), test.query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expect = test.result;
+        let actual = result.as_slice();
+        common_datablocks::assert_blocks_sorted_eq_with_name(test.name, expect, actual);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_values_table_error() -> Result<()> {
+    let tests = vec![
+        (
+            "select * from (values (1, 'a'), (2)) as t(id, name)",
+            "Code: 1005, displayText = All rows of VALUES list must have the same number of columns.",
+        ),
+        (
+            "select * from (values (1, 'a'), (2, 'b')) as t(id)",
+            "Code: 1005, displayText = VALUES list has 2 columns, but 1 column aliases are specified.",
+        ),
+        (
+            "select * from (values (1, 'a')) as t(id, id)",
+            "Code: 1005, displayText = Duplicate column alias id of VALUES list.",
+        ),
+    ];
+
+    for (query, expect) in tests {
+        let ctx = crate::tests::create_query_context()?;
+        let result = PlanParser::parse(ctx.clone(), query).await;
+        assert_eq!(expect, result.unwrap_err().to_string(), "{}", query);
+    }
+    Ok(())
+}
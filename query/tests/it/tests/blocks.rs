@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use databend_query::pipelines::processors::Processor;
+
+/// The source processor of the given blocks, to feed a transform with the exact input.
+pub struct BlocksSource {
+    schema: DataSchemaRef,
+    blocks: Vec<DataBlock>,
+}
+
+impl BlocksSource {
+    pub fn create(schema: DataSchemaRef, blocks: Vec<DataBlock>) -> Arc<dyn Processor> {
+        Arc::new(BlocksSource { schema, blocks })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for BlocksSource {
+    fn name(&self) -> &str {
+        "BlocksSource"
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        unreachable!()
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            self.blocks.clone(),
+        )))
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod blocks;
 mod catalog;
 mod config;
 mod context;
@@ -21,6 +22,7 @@ mod parquet;
 mod sessions;
 pub(crate) mod tls_constants;
 
+pub use blocks::BlocksSource;
 pub use catalog::create_catalog;
 pub use config::ConfigBuilder;
 pub use context::create_catalog_context;
@@ -24,3 +24,7 @@ SELECT argMax(b, a) from (select number + 5 as a, number - 5 as b from numbers_m
  SELECT stddev_pop(number) between  2886.751 and 2886.752 from numbers_mt(10000);
  SELECT covar_samp(number, number) from (select * from numbers_mt(5) order by number asc);
  SELECT covar_pop(number, number) from (select * from numbers_mt(5) order by number asc);
+ SELECT corr(number, number * 2 + 1) between 0.999999 and 1 from numbers_mt(10000);
+ SELECT corr(number, 10000 - number) between -1 and -0.999999 from numbers_mt(10000);
+ SELECT corr(number, number % 1) from numbers_mt(10);
+ SELECT corr(number, number) from numbers_mt(10) where 1 = 2;
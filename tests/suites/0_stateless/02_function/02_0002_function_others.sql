@@ -8,7 +8,8 @@ SELECT TRY_INET_ATON('hello');
 SELECT '=== INET_ATON ===';
 SELECT INET_ATON('10.0.5.9');
 SELECT INET_ATON(NULL);
-SELECT INET_ATON('hello');-- {ErrorCode 1060}
+SELECT INET_ATON('hello');
+SELECT INET_ATON('192.168.1.1');
 SELECT '=== TRY_INET_NTOA ===';
 SELECT TRY_INET_NTOA(167773449);
 SELECT TRY_INET_NTOA('167773449');-- {ErrorCode 1007}
@@ -17,3 +18,15 @@ SELECT '=== INET_NTOA ===';
 SELECT INET_NTOA(167773449);
 SELECT INET_NTOA('167773449'); -- {ErrorCode 1007}
 SELECT INET_NTOA(NULL);
+SELECT '=== INET6_ATON ===';
+SELECT HEX(INET6_ATON('fdfe::5a55:caff:fefa:9089'));
+SELECT HEX(INET6_ATON('10.0.5.9'));
+SELECT INET6_ATON('hello');
+SELECT INET6_ATON(NULL);
+SELECT INET6_ATON(1); -- {ErrorCode 1007}
+SELECT '=== INET6_NTOA ===';
+SELECT INET6_NTOA(INET6_ATON('fdfe::5a55:caff:fefa:9089'));
+SELECT INET6_NTOA(INET6_ATON('10.0.5.9'));
+SELECT INET6_NTOA(UNHEX('FDFE0000000000005A55CAFFFEFA9089'));
+SELECT INET6_NTOA('hello');
+SELECT INET6_NTOA(NULL);
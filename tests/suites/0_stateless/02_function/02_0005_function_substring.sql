@@ -44,3 +44,14 @@ SELECT SUBSTRING('12345' FROM 2 FOR 1);
 SELECT '=== synonyms ===';
 SELECT MID('12345', 2, 1);
 SELECT SUBSTR('12345', 2, 1);
+
+SELECT '=== negative and out of range ===';
+SELECT SUBSTRING('12345', -2);
+SELECT SUBSTRING('12345', -6);
+SELECT SUBSTRING('12345', -3, 2);
+SELECT SUBSTRING('12345', 4, 100);
+SELECT SUBSTRING('12345', 2, -1);
+SELECT '=== utf8 ===';
+SELECT SUBSTRING('数据库引擎', 2, 2);
+SELECT SUBSTRING('数据库引擎', -2);
+SELECT SUBSTRING('数据库引擎', 6);
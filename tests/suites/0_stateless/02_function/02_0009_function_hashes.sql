@@ -23,6 +23,13 @@ SELECT SHA1('1234567890');
 SELECT SHA1('1');
 SELECT SHA1('1.2');
 
+-- SHA256
+SELECT SHA256('1234567890');
+SELECT SHA256('1');
+SELECT SHA256('1.2');
+SELECT SHA256('1234567890') = SHA2('1234567890', 256);
+SELECT SHA256(NULL);
+
 -- XXHASH32
 SELECT XXHASH32('1234567890');
 SELECT XXHASH32('1');
@@ -37,3 +44,10 @@ SELECT XXHASH64('1.2');
 SELECT City64WithSeed('1234567890', 12);
 SELECT City64WithSeed('1234567890', 12.12);
 SELECT City64WithSeed(CAST(100000 as DateTime32), 1234);
+--- Hash
+SELECT hash(1, 'a') = hash(1, 'a');
+SELECT hash(1, 'a') = hash('a', 1);
+SELECT hash('ab', 'c') = hash('a', 'bc');
+SELECT hash(NULL) IS NULL;
+SELECT hash(NULL) = hash('');
+SELECT count(DISTINCT hash(number)) FROM numbers(100);
@@ -7,4 +7,13 @@ SELECT a, isNull(a), b, isNotNull(b) FROM nullable_test ORDER BY a, b ASC;
 SELECT a FROM nullable_test WHERE a is Not Null ORDER BY a;
 SELECT b FROM nullable_test WHERE a is Null ORDER BY b;
 
+SELECT a, b, a IS NOT DISTINCT FROM b, a IS DISTINCT FROM b FROM nullable_test ORDER BY a, b ASC;
+SELECT b FROM nullable_test WHERE a IS NOT DISTINCT FROM 1 ORDER BY b;
+SELECT b FROM nullable_test WHERE a IS DISTINCT FROM 1 ORDER BY b;
+SELECT a FROM nullable_test WHERE b IS NOT DISTINCT FROM NULL ORDER BY a;
+
 DROP TABLE IF EXISTS nullable_test;
+
+SELECT NULL IS NOT DISTINCT FROM NULL, NULL IS DISTINCT FROM NULL;
+SELECT 1 IS DISTINCT FROM NULL, 1 IS NOT DISTINCT FROM NULL;
+SELECT 1 IS DISTINCT FROM 1, 1 IS DISTINCT FROM 2;
@@ -2,6 +2,10 @@ CREATE TABLE math_sample_numbers (timestamp UInt32, value Int32) engine=Memory;
 INSERT INTO math_sample_numbers VALUES ('1', '-1'), ('2', '-2'), ('3', '3');
 
 SELECT pi();
+SELECT pi(2);
+SELECT pi(0.5);
+SELECT pi(NULL);
+SELECT pi('2'); -- {ErrorCode 1007}
 
 SELECT '===abs===';
 
@@ -67,6 +71,12 @@ SELECT atan2(NULL, 2);
 SELECT atan2(NULL, NULL);
 SELECT atan2(NULL, number) from numbers(2);
 SELECT atan2(number, NULL) from numbers(2);
+SELECT sinh(0), cosh(0), tanh(0);
+SELECT round(sinh(1), 6), round(cosh(1), 6), round(tanh(1), 6);
+SELECT asinh(0), round(asinh(1), 6), round(asinh(-1), 6);
+SELECT acosh(1), round(acosh(2), 6), acosh(0.5), acosh(0);
+SELECT atanh(0), round(atanh(0.5), 6), atanh(1), atanh(-1), atanh(2);
+SELECT sinh(NULL), acosh(NULL), atanh(NULL);
 
 SELECT '===sqrt===';
 
@@ -75,6 +85,20 @@ SELECT sqrt(0);
 SELECT sqrt(-4);
 SELECT sqrt('a'); -- {ErrorCode 1007}
 
+SELECT '===math_domain_error===';
+
+SET math_domain_error = 'nan';
+SELECT sqrt(-1);
+SELECT asin(2);
+SET math_domain_error = 'error';
+SELECT sqrt(-1); -- {ErrorCode 1006}
+SELECT asin(2); -- {ErrorCode 1006}
+SELECT sqrt(4);
+SET math_domain_error = 'zero'; -- {ErrorCode 1006}
+SET math_domain_error = 'null';
+SELECT sqrt(-1);
+SELECT asin(2);
+
 SELECT '===pow===';
 
 SELECT pow(2, 2);
@@ -86,5 +110,20 @@ SELECT pow(NULL, number) from numbers(2);
 SELECT pow(number, NULL) from numbers(2);
 SELECT pow('a', 2); -- {ErrorCode 1007}
 SELECT pow(2, 'a'); -- {ErrorCode 1007}
+SELECT 2 ** 3 ** 2, 2 * 3 ** 2, -2 ** 2, 2 ** -1;
+SELECT number ** 2 FROM numbers(3) ORDER BY number;
 
 DROP TABLE math_sample_numbers;
+
+SELECT crc32c('123456789'), crc32('123456789');
+SELECT crc32c(NULL);
+SELECT lpad(hex(crc32c('123456789')), 8, '0'), lpad(hex(crc32c('')), 8, '0');
+
+SELECT '===factorial===';
+SELECT factorial(0), factorial(5), factorial(20);
+SELECT factorial(21), factorial(-1), factorial(NULL);
+SET math_domain_error = 'error';
+SELECT factorial(21); -- {ErrorCode 1006}
+SELECT factorial(-1);
+SET math_domain_error = 'null';
+SELECT factorial(1.5); -- {ErrorCode 1007}
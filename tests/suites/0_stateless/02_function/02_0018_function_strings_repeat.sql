@@ -56,3 +56,8 @@ select repeat(s, 3) from strings_repeat_sample_3;
 select repeat(s, 0) from strings_repeat_sample_3;
 
 drop table strings_repeat_sample_3;
+
+select '=======> negative';
+select repeat('abc', -1);
+select repeat('abc', toInt8(-3));
+select repeat('abc', toInt8(2));
@@ -2,3 +2,5 @@ select hex(100);
 select hex(-100);
 select hex('abc');
 select hex(null);
+select hex(255);
+select hex('AB');
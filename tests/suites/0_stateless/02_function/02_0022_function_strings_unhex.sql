@@ -1,4 +1,9 @@
 select unhex('616263');
-select unhex('hello'); -- {ErrorCode 1054}
+select unhex('hello');
 select unhex(hex('hello'));
 select unhex(null);
+select from_hex(to_hex('databend'));
+select try_unhex('hello');
+select try_from_hex('616263');
+select from_hex('616');
+select unhex('4E6F77');
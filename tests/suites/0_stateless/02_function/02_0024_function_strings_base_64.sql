@@ -2,5 +2,16 @@ SELECT TO_BASE64('abc');
 SELECT FROM_BASE64(TO_BASE64('abc'));
 SELECT TO_BASE64(NULL);
 SELECT FROM_BASE64(NULL);
-SELECT FROM_BASE64('1'); -- {ErrorCode 1060}
-SELECT TO_BASE64(toString(number)) FROM numbers(5) ORDER BY number;
\ No newline at end of file
+SELECT FROM_BASE64('1'); -- {ErrorCode 1006}
+SELECT TO_BASE64(toString(number)) FROM numbers(5) ORDER BY number;SELECT TRY_FROM_BASE64('1');
+SELECT TRY_FROM_BASE64(TO_BASE64('databend'));
+SELECT TRY_FROM_BASE64(NULL);
+SELECT BASE64_ENCODE('abc');
+SELECT BASE64_DECODE(BASE64_ENCODE('abc'));
+SELECT BASE64_DECODE('1'); -- {ErrorCode 1006}
+SELECT TRY_BASE64_DECODE('1');
+SELECT BASE64URL_ENCODE('???'), BASE64_ENCODE('???');
+SELECT BASE64URL_DECODE('Pz8_');
+SELECT BASE64URL_DECODE('Pz8/'); -- {ErrorCode 1006}
+SELECT TRY_BASE64URL_DECODE('Pz8/');
+SELECT BASE64URL_ENCODE(NULL), BASE64URL_DECODE(NULL);
@@ -28,4 +28,9 @@ SELECT '=== series, series, const ===';
 SELECT REPLACE(toString(number * 10), toString(number), '1') FROM numbers(5) ORDER BY number;
 
 SELECT '=== series, series, series ===';
-SELECT REPLACE(toString(number * 10), toString(number), toString(number)) FROM numbers(5) ORDER BY number;
\ No newline at end of file
+SELECT REPLACE(toString(number * 10), toString(number), toString(number)) FROM numbers(5) ORDER BY number;
+
+SELECT '=== overlapping ===';
+SELECT REPLACE('aaa', 'aa', 'b');
+SELECT REPLACE('ababab', 'aba', 'x');
+SELECT REPLACE('ab', 'abc', 'x');
@@ -1,3 +1,4 @@
 SELECT REVERSE(NULL);
 SELECT REVERSE('');
 SELECT REVERSE('abc');
+SELECT REVERSE('数据库abc');
@@ -5,3 +5,6 @@ SELECT CONCAT_WS(NULL, 'data', 'fuse', 'labs');
 SELECT CONCAT_WS(',', toString(number), 'data', toString(number+1)) from numbers(3) order by number;
 SELECT CONCAT_WS(NULL, toString(number), 'data') from numbers(3);
 SELECT CONCAT_WS(',', NULL); -- is emtpy, not NULL
+SELECT CONCAT_WS(',', NULL, 'data', NULL, 'bend', NULL);
+SELECT CONCAT_WS(',', NULL, NULL, 'bend');
+SELECT CONCAT_WS(if(number = 1, NULL, ','), 'data', NULL, toString(number)) from numbers(3) order by number;
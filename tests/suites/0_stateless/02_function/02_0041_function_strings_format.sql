@@ -14,3 +14,8 @@ SELECT FORMAT(12332.123456, 4, '');
 SELECT FORMAT(12332.123456, 4, NULL);
 SELECT FORMAT(100 + 100, 2);
 SELECT FORMAT(number, number), number from  numbers(3) order by number;
+SELECT FORMAT(1234567.891, 2);
+SELECT FORMAT(-1234567.891, 2);
+SELECT FORMAT(-0.5, 1);
+SELECT FORMAT(1.999, 2);
+SELECT FORMAT(12332.5, 0);
@@ -8,4 +8,9 @@ SELECT isemptyUUID(null);
 SELECT isnotemptyUUID(generateUUIDv4());
 SELECT isnotemptyUUID(zeroUUID());
 SELECT isnotemptyUUID('5');
-SELECT isnotemptyUUID(null);
\ No newline at end of file
+SELECT isnotemptyUUID(null);
+SELECT LENGTH(uuid()) = 36 AND LENGTH(gen_random_uuid()) = 36;
+SELECT count(DISTINCT uuid()) FROM numbers(100);
+SELECT hex(uuid_string_to_binary(zeroUUID()));
+SELECT hex(uuid_string_to_binary('936DA01F-9ABD-4D9D-80C7-02AF85C822A8'));
+SELECT LENGTH(uuid_string_to_binary(uuid()));
@@ -0,0 +1,7 @@
+SELECT TRIM('  abc  ');
+SELECT TRIM(LEADING 'xy' FROM 'xyabcyx');
+SELECT TRIM(TRAILING 'xy' FROM 'xyabcyx');
+SELECT TRIM(BOTH 'xy' FROM 'xyabcyx');
+SELECT TRIM(BOTH '' FROM 'abc');
+SELECT TRIM(BOTH 'x' FROM NULL);
+SELECT TRIM(LEADING NULL FROM 'abc');
@@ -0,0 +1,13 @@
+SELECT ARRAY_CONTAINS(ARRAY_AGG(number), 3) FROM numbers(5);
+SELECT ARRAY_CONTAINS(ARRAY_AGG(number), 10) FROM numbers(5);
+SELECT ARRAY_CONTAINS(ARRAY_AGG(number), NULL) FROM numbers(5);
+SELECT ARRAY_DISTINCT(ARRAY_AGG(number % 1)) FROM numbers(5);
+SELECT ARRAY_CONTAINS(ARRAY_DISTINCT(ARRAY_AGG(number % 3)), 2) FROM numbers(10);
+SELECT ARRAY_CONTAINS(1, 1); -- {ErrorCode 1007}
+SELECT ARRAY_SLICE(ARRAY_AGG(number), 1, 3) FROM numbers(5);
+SELECT ARRAY_SLICE(ARRAY_AGG(number), -2) FROM numbers(5);
+SELECT ARRAY_SLICE(ARRAY_AGG(number), 3, 100) FROM numbers(5);
+SELECT ARRAY_SLICE(ARRAY_AGG(number), 4, 2) FROM numbers(5);
+SELECT ARRAY_SIZE(ARRAY_AGG(number)) FROM numbers(5);
+SELECT ARRAY_LENGTH(ARRAY_DISTINCT(ARRAY_AGG(number % 3))) FROM numbers(10);
+SELECT ARRAY_SIZE(1); -- {ErrorCode 1007}
@@ -0,0 +1,18 @@
+SELECT '===BIT_COUNT===';
+SELECT BIT_COUNT(7);
+SELECT BIT_COUNT(CAST(-1 AS Int8)), BIT_COUNT(CAST(-1 AS Int64));
+SELECT BIT_COUNT(NULL);
+SELECT BIT_COUNT(1.5); -- {ErrorCode 1007}
+SELECT '===BIT_AND/BIT_OR/BIT_XOR===';
+SELECT BIT_AND(12, 10), BIT_OR(12, 10), BIT_XOR(12, 10);
+SELECT BIT_AND(CAST(-1 AS Int8), CAST(5 AS Int8)), BIT_OR(CAST(-128 AS Int8), CAST(1 AS Int8));
+SELECT BIT_AND(CAST(255 AS UInt8), CAST(-1 AS Int8)), BIT_OR(CAST(240 AS UInt8), CAST(-16 AS Int8));
+SELECT BIT_XOR(number, 3) FROM numbers(4) ORDER BY number;
+SELECT BIT_AND(1, NULL), BIT_OR(NULL, 1), BIT_XOR(NULL, NULL);
+SELECT BIT_OR(1, 1.0); -- {ErrorCode 1007}
+SELECT '===BIT_SHIFT_LEFT/BIT_SHIFT_RIGHT===';
+SELECT BIT_SHIFT_LEFT(1, 3), BIT_SHIFT_RIGHT(8, 3);
+SELECT BIT_SHIFT_LEFT(CAST(1 AS Int8), 7), BIT_SHIFT_LEFT(CAST(1 AS Int8), 8), BIT_SHIFT_LEFT(CAST(1 AS UInt8), 100);
+SELECT BIT_SHIFT_RIGHT(CAST(-128 AS Int8), 7), BIT_SHIFT_RIGHT(CAST(-128 AS Int8), 100), BIT_SHIFT_RIGHT(CAST(255 AS UInt8), 8);
+SELECT BIT_SHIFT_LEFT(NULL, 1), BIT_SHIFT_RIGHT(1, NULL);
+SELECT BIT_SHIFT_LEFT(1, -1); -- {ErrorCode 1006}
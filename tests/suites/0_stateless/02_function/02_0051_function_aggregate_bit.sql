@@ -0,0 +1,17 @@
+SELECT bit_and(number), bit_or(number), bit_xor(number) FROM numbers(10);
+SELECT bit_and(number) FROM numbers(10) WHERE number > 7;
+SELECT number % 3 AS k, bit_and(number), bit_or(number), bit_xor(number) FROM numbers(10) GROUP BY k ORDER BY k;
+
+-- empty
+SELECT bit_and(number), bit_or(number), bit_xor(number) FROM numbers(10) WHERE 1 = 2;
+SELECT bit_and(toInt8(number)), bit_or(toInt8(number)), bit_xor(toInt8(number)) FROM numbers(10) WHERE 1 = 2;
+
+-- nulls
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, a INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 1), (1, 3), (2, NULL), (2, NULL), (3, 6), (3, NULL);
+SELECT k, bit_and(a), bit_or(a), bit_xor(a) FROM t GROUP BY k ORDER BY k;
+DROP TABLE t;
+
+SELECT bit_and('a'); -- {ErrorCode 1010}
+SELECT bit_or(1.5); -- {ErrorCode 1010}
@@ -0,0 +1,20 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, v VARCHAR, o INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 'a', 3), (1, 'b', 1), (1, NULL, 2), (1, 'c', 2), (2, 'x', 2), (2, 'y', 1), (3, NULL, 1);
+
+SELECT k, LISTAGG(v, ',') WITHIN GROUP (ORDER BY o) FROM t GROUP BY k ORDER BY k;
+SELECT k, LISTAGG(v, ',') WITHIN GROUP (ORDER BY o DESC) FROM t GROUP BY k ORDER BY k;
+SELECT LISTAGG(v, '|') WITHIN GROUP (ORDER BY k DESC, o) FROM t;
+SELECT LISTAGG(DISTINCT v) WITHIN GROUP (ORDER BY v) FROM t WHERE k = 2;
+SELECT LISTAGG(v, ',') WITHIN GROUP (ORDER BY o) FROM t WHERE k = 4;
+
+-- overflow
+SET max_listagg_length = 8;
+SELECT LISTAGG(v, ',') WITHIN GROUP (ORDER BY k, o) FROM t WHERE k = 1;
+SELECT LISTAGG(v, ',' ON OVERFLOW ERROR) WITHIN GROUP (ORDER BY k, o) FROM t; -- {ErrorCode 1049}
+SELECT LISTAGG(v, ',' ON OVERFLOW TRUNCATE '...' WITH COUNT) WITHIN GROUP (ORDER BY k, o) FROM t;
+SELECT LISTAGG(v, ',' ON OVERFLOW TRUNCATE WITHOUT COUNT) WITHIN GROUP (ORDER BY k, o) FROM t;
+
+SELECT LISTAGG(v, k) FROM t; -- {ErrorCode 1005}
+SELECT LISTAGG(o, ',') FROM t; -- {ErrorCode 1010}
+DROP TABLE t;
@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(g INT, k VARCHAR, v INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 'a', 1), (1, 'b', 2), (1, 'a', 3), (2, NULL, 4), (2, 'c', NULL), (3, NULL, 5);
+
+SELECT g, object_agg(k, v) FROM t GROUP BY g ORDER BY g;
+SELECT object_agg(k, json_object('v', v)) FROM t WHERE g = 1;
+SELECT object_agg(k, v) FROM t WHERE g = 4;
+
+SELECT object_agg(v, k) FROM t; -- {ErrorCode 1006}
+DROP TABLE t;
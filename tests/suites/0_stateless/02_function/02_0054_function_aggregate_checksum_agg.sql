@@ -0,0 +1,18 @@
+SELECT checksum_agg(number) = sum(crc32(number)) FROM numbers(1000);
+SELECT checksum_agg(toString(number)) = sum(crc32(toString(number))) FROM numbers(1000);
+
+-- Each group has the digits 0 to 9 in a different order.
+SELECT count(DISTINCT c) FROM (SELECT number % 3 AS g, checksum_agg(number % 10) AS c FROM numbers(30) GROUP BY g);
+
+-- The duplicated values don't cancel each other.
+SELECT checksum_agg(1) = 2 * crc32(1) FROM numbers(2);
+
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a INT NULL) ENGINE = Memory;
+INSERT INTO t VALUES (1), (NULL), (3);
+SELECT checksum_agg(a) = crc32(CAST(1 AS INT)) + crc32(CAST(3 AS INT)) FROM t;
+SELECT checksum_agg(a) FROM t WHERE a IS NULL;
+DROP TABLE t;
+
+SELECT checksum_agg(number) FROM numbers(10) WHERE number > 100;
+SELECT checksum_agg(NULL) FROM numbers(10);
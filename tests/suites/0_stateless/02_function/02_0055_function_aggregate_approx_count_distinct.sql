@@ -0,0 +1,13 @@
+SELECT abs(approx_count_distinct(number % 50000) - 50000) < 50000 * 0.05 FROM numbers(200000);
+SELECT approx_count_distinct(number % 5), approx_count_distinct(toString(number % 7)) FROM numbers(1000);
+SELECT number % 3 AS g, approx_count_distinct(number) FROM numbers(30) GROUP BY g ORDER BY g;
+
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a INT NULL) ENGINE = Memory;
+INSERT INTO t VALUES (1), (NULL), (3), (1);
+SELECT approx_count_distinct(a) FROM t;
+SELECT approx_count_distinct(a) FROM t WHERE a IS NULL;
+DROP TABLE t;
+
+SELECT approx_count_distinct(number) FROM numbers(10) WHERE number > 100;
+SELECT approx_count_distinct(NULL) FROM numbers(10);
@@ -0,0 +1,14 @@
+SELECT median(number) FROM numbers(5);
+SELECT median(number) FROM numbers(4);
+SELECT percentile(number, 0.5), percentile(number, 1), percentile(number, 0) FROM numbers(11);
+SELECT abs(percentile(number, 0.99) - 98999.01) < 100 FROM numbers_mt(100000);
+SELECT number % 2 AS g, median(number) FROM numbers(10) GROUP BY g ORDER BY g;
+
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a INT NULL) ENGINE = Memory;
+INSERT INTO t VALUES (3), (NULL), (1), (2);
+SELECT median(a) FROM t;
+SELECT median(a) FROM t WHERE a IS NULL;
+DROP TABLE t;
+
+SELECT median(number) FROM numbers(10) WHERE number > 100;
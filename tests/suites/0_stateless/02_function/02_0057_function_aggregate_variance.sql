@@ -0,0 +1,11 @@
+SELECT var_pop(number), variance(number), var_samp(number) FROM numbers(10);
+SELECT stddev_pop(number), stddev_samp(number) FROM numbers(4);
+SELECT abs(var_samp(number) - 833341666.6666666) < 0.01 FROM numbers_mt(100000);
+SELECT number % 2 AS g, var_samp(number) FROM numbers(10) GROUP BY g ORDER BY g;
+
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(a INT NULL) ENGINE = Memory;
+INSERT INTO t VALUES (1), (NULL), (3);
+SELECT var_pop(a), var_samp(a), stddev_samp(a) FROM t;
+SELECT var_samp(a), stddev_samp(a) FROM t WHERE a = 1;
+DROP TABLE t;
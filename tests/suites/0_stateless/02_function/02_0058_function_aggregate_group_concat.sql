@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, v VARCHAR, o INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 'a', 3), (1, 'b', 1), (1, NULL, 2), (1, 'c', 2), (1, 'b', 4), (2, 'x', 2), (3, NULL, 1);
+
+SELECT k, GROUP_CONCAT(v) FROM (SELECT k, v FROM t ORDER BY o) GROUP BY k ORDER BY k;
+SELECT GROUP_CONCAT(v, ' | ') FROM (SELECT v FROM t ORDER BY k, o);
+SELECT STRING_AGG(v, '') FROM (SELECT v FROM t ORDER BY v DESC);
+SELECT length(GROUP_CONCAT(DISTINCT v)), length(GROUP_CONCAT(v)) FROM t WHERE k = 1;
+SELECT STRING_AGG(DISTINCT v, '-') FROM t WHERE v = 'b';
+SELECT GROUP_CONCAT(v) FROM t WHERE k = 4;
+
+SELECT STRING_AGG(v) FROM t; -- {ErrorCode 1028}
+SELECT GROUP_CONCAT(v, k) FROM t; -- {ErrorCode 1005}
+SELECT GROUP_CONCAT(o) FROM t; -- {ErrorCode 1010}
+DROP TABLE t;
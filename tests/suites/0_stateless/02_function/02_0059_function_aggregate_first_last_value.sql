@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, v VARCHAR, o INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, NULL, 1), (1, 'b', 2), (1, 'c', 3), (1, NULL, 4), (2, 'x', 2), (2, 'y', 1), (3, NULL, 1);
+
+SELECT k, first_value(v, o), last_value(v, o) FROM t GROUP BY k ORDER BY k;
+SELECT k, first_value('IGNORE NULLS')(v, o), last_value('IGNORE NULLS')(v, o) FROM t GROUP BY k ORDER BY k;
+SELECT first_value('DESC', 'IGNORE NULLS')(v, o), last_value('DESC', 'RESPECT NULLS')(v, o) FROM t WHERE k = 1;
+SELECT first_value(v, o), last_value('IGNORE NULLS')(v) FROM t WHERE k = 4;
+SELECT first_value(number), last_value(number) FROM numbers(10);
+SELECT k, first_value(v ORDER BY o), last_value(v ORDER BY o) IGNORE NULLS FROM t GROUP BY k ORDER BY k;
+SELECT first_value(v IGNORE NULLS ORDER BY o DESC), last_value(v ORDER BY o DESC) RESPECT NULLS FROM t WHERE k = 1;
+
+SELECT first_value('FIRST')(v) FROM t; -- {ErrorCode 1006}
+SELECT first_value(v ORDER BY o NULLS FIRST) FROM t; -- {ErrorCode 1005}
+DROP TABLE t;
@@ -0,0 +1,11 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, v VARCHAR, o INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 'a', 1), (1, 'b', 3), (1, 'c', 3), (1, NULL, 0), (1, 'e', NULL), (2, 'x', NULL), (2, 'y', NULL), (3, 'z', 5);
+
+SELECT k, arg_max(v, o), arg_min(v, o) FROM t GROUP BY k ORDER BY k;
+SELECT arg_max(v, o), arg_min(v, o) FROM t WHERE k = 1;
+SELECT arg_max(v, o), arg_min(v, o) FROM t WHERE k = 2;
+SELECT arg_max(number, number), argMin(number, number) FROM numbers(10);
+
+SELECT arg_max(v) FROM t; -- {ErrorCode 1028}
+DROP TABLE t;
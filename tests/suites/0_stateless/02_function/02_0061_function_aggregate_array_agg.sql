@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS t;
+CREATE TABLE t(k INT, v INT, o INT) ENGINE = Memory;
+INSERT INTO t VALUES (1, 10, 3), (1, NULL, 2), (1, 30, 1), (2, 40, 4);
+
+SELECT k, ARRAY_AGG(v ORDER BY o) IGNORE NULLS FROM t GROUP BY k ORDER BY k;
+SELECT ARRAY_AGG(v IGNORE NULLS ORDER BY o DESC) FROM t;
+SELECT ARRAY_SIZE(ARRAY_AGG(v)), ARRAY_SIZE(ARRAY_AGG(v) IGNORE NULLS) FROM t;
+
+SELECT ARRAY_AGG(v ORDER BY o, k) FROM t; -- {ErrorCode 1005}
+DROP TABLE t;
@@ -2,3 +2,7 @@ select number from numbers_mt(10) where number > 5  and exists (select name from
 select number from numbers_mt(10) where number > 5  and exists (select name from system.settings) and exists (select number from numbers_mt(10)) order by number asc;
 select number from numbers_mt(10) where number > 5  and exists (select name from system.settings where exists (select number from numbers_mt(10))) order by number asc;
 select number from numbers_mt(20) where number > 15  and not exists (select number from numbers_mt(5) where number > 10) order by number asc;
+select number from numbers_mt(5) as a where exists (select number from numbers_mt(3) as b where b.number = a.number) order by number asc;
+select number from numbers_mt(5) as a where not exists (select number from numbers_mt(3) as b where a.number = b.number) order by number asc;
+select number from numbers_mt(5) as a where a.number > 0 and exists (select number from numbers_mt(10) as b where b.number = a.number + 1 and b.number < 4) order by number asc;
+select number from numbers_mt(5) as a where exists (select number from numbers_mt(5) as b where b.number > a.number); -- {ErrorCode 1002}
@@ -1 +1,3 @@
 select state, database from system.processes;
+select isNotNull(time) from system.processes;
+select command, isNotNull(info) from system.processes;
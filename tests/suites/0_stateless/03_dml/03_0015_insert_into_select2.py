@@ -26,7 +26,7 @@ INSERT INTO t1 (a,b,c,d,e,f,g,h) VALUES('1','2','3','4','2021-08-15', '2021-09-1
 
 INSERT INTO t1(a,b,c,d,e,f,g,h) select * from t1;
 SELECT COUNT(1) = 4 from t1;
-DROP DATABASE db1;
+DROP DATABASE db1 CASCADE;
 """
 
 client1.run(sqls)
@@ -11,4 +11,4 @@ INSERT INTO t1 (a,b,c,d) VALUES(-1, 33, '2021-08-15 10:00:00', 'string1234'),
 select * from t1;
 select sum(a),sum(b) from t1;
 
-DROP DATABASE db1;
+DROP DATABASE db1 CASCADE;
@@ -14,4 +14,4 @@ select * from t1;
 INSERT OVERWRITE t1 VALUES (5, 5, 5, 'change2'), (6, 6, 6, 'change2');
 select * from t1;
 
-DROP DATABASE db1;
+DROP DATABASE db1 CASCADE;
@@ -0,0 +1,8 @@
+SET max_block_size = 1000;
+SET group_by_spill_threshold = 1;
+
+SELECT count(), sum(c), sum(k), max(s) FROM (SELECT number % 1000 AS k, count() AS c, sum(number) AS s FROM numbers_mt(100000) GROUP BY k);
+SELECT k, c FROM (SELECT number % 1000 AS k, count() AS c FROM numbers_mt(100000) GROUP BY k) ORDER BY k LIMIT 3;
+
+SELECT 'GROUP BY Strings';
+SELECT a, b, count() FROM (SELECT cast((number % 4) AS bigint) AS a, cast((number % 20) AS bigint) AS b FROM numbers_mt(100000)) GROUP BY a, b ORDER BY a, b LIMIT 3;
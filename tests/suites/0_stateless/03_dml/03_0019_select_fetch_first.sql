@@ -0,0 +1,13 @@
+select '=== Test fetch first ===';
+select number from numbers(100) order by number asc fetch first 3 rows only;
+select '=== Test fetch next with offset ===';
+select number from numbers(100) order by number asc offset 10 rows fetch next 3 rows only;
+select '=== Test fetch first row ===';
+select number from numbers(100) order by number asc fetch first row only;
+select '=== Test fetch first with ties ===';
+select number from (select number, number % 3 as c from numbers(10) order by c fetch first 5 rows with ties) order by number;
+select '=== Test fetch first with ties and offset ===';
+select number % 3 as c from numbers(10) order by c offset 3 rows fetch first 2 rows with ties;
+select '=== Test fetch errors ===';
+select number from numbers(10) fetch first 2 rows with ties; -- {ErrorCode 1005}
+select number from numbers(10) order by number limit 1 fetch first 2 rows only; -- {ErrorCode 1005}
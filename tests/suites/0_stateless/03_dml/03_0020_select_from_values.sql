@@ -0,0 +1,13 @@
+select '=== Test values with column aliases ===';
+select * from (values (1, 'a'), (2, 'b')) as t(id, name) order by id;
+select '=== Test values default column names ===';
+select column1, column2 from (values (1, 'a'), (2, 'b')) order by column1;
+select '=== Test values with null ===';
+select id, name from (values (1, NULL), (NULL, 'b')) as t(id, name) order by id;
+select '=== Test values with mixed types ===';
+select a, b from (values (1, 1), (300, 2.5), (-1, 3)) as t(a, b) order by a;
+select '=== Test values with aggregate ===';
+select name, sum(id) from (values (1, 'a'), (2, 'b'), (3, 'a')) as t(id, name) group by name order by name;
+select '=== Test values errors ===';
+select * from (values (1, 'a'), (2)) as t(id, name); -- {ErrorCode 1005}
+select * from (values (1, 'a')) as t(id); -- {ErrorCode 1005}
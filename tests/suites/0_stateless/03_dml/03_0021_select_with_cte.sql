@@ -0,0 +1,11 @@
+select '=== Test cte ===';
+with t as (select number from numbers(10) where number > 5) select * from t order by number;
+select '=== Test cte refers to previous cte ===';
+with a as (select number from numbers(10)), b as (select number % 3 as n from a) select n, count() from b group by n order by n;
+select '=== Test nested with ===';
+with t as (with u as (select number from numbers(5)) select number * 2 as n from u) select sum(n) from t;
+select '=== Test cte shadows table ===';
+with one as (select number as dummy from numbers(3)) select count() from one;
+select '=== Test cte errors ===';
+with recursive t as (select 1) select * from t; -- {ErrorCode 1005}
+with t as (select 1), t as (select 2) select * from t; -- {ErrorCode 1005}
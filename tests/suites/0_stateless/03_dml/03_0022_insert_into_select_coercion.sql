@@ -0,0 +1,26 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE src(a Int32, b String) Engine = Memory;
+CREATE TABLE dst(x Int64 NOT NULL, y String, z Int64 DEFAULT 10, w Int64) Engine = Memory;
+INSERT INTO src VALUES(1, 'a'), (2, 'b'), (3, 'c');
+
+select '=== Test implicit cast ===';
+INSERT INTO dst SELECT a, b, a * 10, a FROM src WHERE a > 1;
+SELECT * FROM dst ORDER BY x;
+
+select '=== Test column list reordering ===';
+INSERT INTO dst(y, x) SELECT b, a FROM src WHERE a = 1;
+SELECT * FROM dst ORDER BY x;
+
+select '=== Test incompatible columns ===';
+INSERT INTO dst(x, y) SELECT NULL, b FROM src; -- {ErrorCode 1010}
+INSERT INTO dst(x, y) SELECT a FROM src; -- {ErrorCode 1006}
+
+select '=== Test omitted columns ===';
+INSERT INTO dst(y) SELECT b FROM src; -- {ErrorCode 1006}
+INSERT INTO dst(w, x) SELECT a * 100, a + 10 FROM src WHERE a = 3;
+SELECT * FROM dst WHERE x > 10;
+
+DROP DATABASE db1;
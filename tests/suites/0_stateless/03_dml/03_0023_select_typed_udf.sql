@@ -0,0 +1,17 @@
+DROP FUNCTION IF EXISTS add_one;
+DROP FUNCTION IF EXISTS add_both;
+CREATE FUNCTION add_one(x INT) RETURNS BIGINT AS (x + 1);
+CREATE FUNCTION add_both(a INT, b INT) RETURNS VARCHAR AS (add_one(a) + b);
+
+SELECT add_one(1), toTypeName(add_one(1));
+SELECT add_one('41');
+SELECT add_one(number) FROM numbers(3) ORDER BY number;
+SELECT add_both(1, 2), toTypeName(add_both(1, 2));
+SELECT definition FROM system.functions WHERE name = 'add_one';
+
+-- The definition is type checked on creation.
+CREATE FUNCTION bad_cast(x VARCHAR) RETURNS INT AS (parse_json(x)); -- {ErrorCode 1007}
+CREATE FUNCTION bad_param(x INT) RETURNS INT AS (x + y); -- {ErrorCode 1005}
+
+DROP FUNCTION add_one;
+DROP FUNCTION add_both;
@@ -0,0 +1,13 @@
+select '=== Test row_number ===';
+select number, row_number() over (partition by number % 3 order by number desc) as rn from numbers(7) order by number;
+select '=== Test rank and dense_rank ===';
+select number % 3 as c, rank() over (order by number % 3) as r, dense_rank() over (order by number % 3) as d from numbers(5) order by c;
+select '=== Test qualify ===';
+select number, row_number() over (partition by number % 3 order by number) as rn from numbers(10) qualify rn = 1 order by number;
+select number from numbers(10) qualify row_number() over (order by number desc) <= 2 order by number;
+select '=== Test qualify with group by ===';
+select number % 3 as c, count() as n from numbers(10) group by c qualify rank() over (order by count() desc) = 1;
+select '=== Test window errors ===';
+select number from numbers(10) where row_number() over () > 1; -- {ErrorCode 1005}
+select row_number() from numbers(10); -- {ErrorCode 1005}
+select sum(number) over () from numbers(10); -- {ErrorCode 1002}
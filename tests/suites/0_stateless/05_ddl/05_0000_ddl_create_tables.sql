@@ -44,7 +44,7 @@ SELECT a FROM db2.test5;
 SELECT '====END TEST CREATE TABLE AS SELECT STATEMENT====';
 
 -- clean up test databases
-DROP DATABASE db1;
-DROP DATABASE db2;
+DROP DATABASE db1 CASCADE;
+DROP DATABASE db2 CASCADE;
 
 CREATE TABLE system.test; -- {ErrorCode 1002}
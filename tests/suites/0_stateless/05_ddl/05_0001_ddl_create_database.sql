@@ -7,7 +7,11 @@ SELECT COUNT(1) from system.tables where name = 't' and database = 'db';
 CREATE DATABASE IF NOT EXISTS db;
 CREATE DATABASE db; -- {ErrorCode 2301}
 
-DROP DATABASE IF EXISTS db;
+DROP DATABASE IF EXISTS db CASCADE;
 
 CREATE DATABASE system; -- {ErrorCode 2301}
 DROP DATABASE system; -- {ErrorCode 1002}
+
+CREATE DATABASE db.t; -- {ErrorCode 1005}
+SHOW CREATE DATABASE db.t; -- {ErrorCode 1005}
+CREATE DATABASE ``; -- {ErrorCode 1005}
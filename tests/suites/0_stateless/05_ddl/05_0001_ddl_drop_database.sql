@@ -6,3 +6,8 @@ DROP DATABASE db;
 DROP DATABASE IF EXISTS db;
 
 DROP DATABASE db; -- {ErrorCode 1003}
+
+CREATE DATABASE db;
+DROP DATABASE db.t; -- {ErrorCode 1005}
+DROP DATABASE ``; -- {ErrorCode 1005}
+DROP DATABASE db;
@@ -13,3 +13,16 @@ TRUNCATE TABLE t2;
 SELECT * FROM t2;
 DROP TABLE IF EXISTS t2;
 
+DROP TABLE IF EXISTS t3;
+CREATE TABLE t3(c1 int) ENGINE = Fuse;
+INSERT INTO TABLE t3 values(1), (2);
+SELECT count() FROM t3;
+TRUNCATE TABLE t3;
+SELECT count() FROM t3;
+INSERT INTO TABLE t3 values(3);
+SELECT * FROM t3;
+DROP TABLE IF EXISTS t3;
+
+TRUNCATE TABLE t_not_exists; -- {ErrorCode 1025}
+TRUNCATE TABLE IF EXISTS t_not_exists;
+
@@ -21,4 +21,4 @@ SHOW GRANTS FOR 'test-grant'@'localhost';
 REVOKE ALL PRIVILEGES ON * FROM 'test-grant'@'localhost';
 SHOW GRANTS FOR 'test-grant'@'localhost';
 
-DROP DATABASE `db01`;
+DROP DATABASE `db01` CASCADE;
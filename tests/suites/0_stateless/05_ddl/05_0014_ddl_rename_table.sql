@@ -0,0 +1,25 @@
+DROP DATABASE IF EXISTS db_rename;
+DROP TABLE IF EXISTS t_rename;
+DROP TABLE IF EXISTS t_rename2;
+
+CREATE DATABASE db_rename;
+CREATE TABLE t_rename(c1 int) ENGINE = Memory;
+INSERT INTO t_rename VALUES(1);
+
+RENAME TABLE t_rename TO t_rename2;
+SELECT * FROM t_rename; -- {ErrorCode 1025}
+SELECT * FROM t_rename2;
+
+ALTER TABLE t_rename2 RENAME TO db_rename.t_rename;
+SELECT * FROM db_rename.t_rename;
+
+RENAME TABLE t_rename2 TO t_rename3; -- {ErrorCode 1025}
+RENAME TABLE IF EXISTS t_rename2 TO t_rename3;
+
+CREATE TABLE t_rename(c1 int) ENGINE = Memory;
+RENAME TABLE t_rename TO db_rename.t_rename; -- {ErrorCode 2302}
+
+RENAME TABLE t_rename TO unknown_db.t_rename; -- {ErrorCode 1003}
+
+DROP TABLE t_rename;
+DROP DATABASE db_rename;
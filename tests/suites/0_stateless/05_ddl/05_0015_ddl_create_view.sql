@@ -0,0 +1,21 @@
+DROP VIEW IF EXISTS v_05_0015;
+DROP TABLE IF EXISTS t_05_0015;
+
+CREATE TABLE t_05_0015(a int, b int) ENGINE = Memory;
+INSERT INTO t_05_0015 VALUES(1, 10), (2, 20), (3, 30), (4, 40);
+
+CREATE VIEW v_05_0015 AS SELECT a, b FROM t_05_0015 WHERE a > 1;
+SELECT * FROM v_05_0015 WHERE b < 40 ORDER BY a;
+SELECT sum(b) FROM v_05_0015;
+
+CREATE VIEW v_05_0015 AS SELECT a FROM t_05_0015; -- {ErrorCode 2302}
+CREATE VIEW IF NOT EXISTS v_05_0015 AS SELECT a FROM t_05_0015;
+CREATE OR REPLACE VIEW v_05_0015 AS SELECT a FROM t_05_0015 WHERE a = 1;
+SELECT * FROM v_05_0015;
+
+DROP TABLE t_05_0015;
+SELECT * FROM v_05_0015; -- {ErrorCode 1025}
+
+DROP VIEW v_05_0015;
+DROP VIEW v_05_0015; -- {ErrorCode 1025}
+DROP VIEW IF EXISTS v_05_0015;
@@ -8,6 +8,11 @@ CREATE TABLE `test`.`b` (
     a bigint, b int, c varchar(255), d smallint, e Date
 ) Engine = Null COMMENT = 'test b';
 SHOW CREATE TABLE `test`.`b`;
+CREATE TABLE `test`.`c` (
+    `Id` bigint NOT NULL, `select` int DEFAULT 1, name varchar DEFAULT 'it''s'
+) Engine = Null;
+SHOW CREATE TABLE `test`.`c`;
 DROP TABLE `test`.`a`;
 DROP TABLE `test`.`b`;
+DROP TABLE `test`.`c`;
 DROP DATABASE `test`;
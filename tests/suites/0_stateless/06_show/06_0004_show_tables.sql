@@ -17,6 +17,19 @@ SHOW TABLES WHERE name = 't%' AND 1 = 0;
 SHOW TABLES WHERE name = 't2' OR 1 = 1;
 SHOW TABLES WHERE name = 't2' AND 1 = 1;
 
+DROP DATABASE IF EXISTS showtabletest;
+CREATE DATABASE showtabletest;
+CREATE TABLE showtabletest.t_1(c1 int) ENGINE = Null;
+CREATE TABLE showtabletest.tx1(c1 int) ENGINE = Null;
+CREATE TABLE showtabletest.t11(c1 int) ENGINE = Memory;
+CREATE TABLE showtabletest.t12(c1 int);
+
+SHOW TABLES FROM showtabletest LIKE 't_1';
+SHOW TABLES IN showtabletest WHERE engine = 'FUSE';
+SHOW TABLES FROM showtabletest WHERE engine = 'Null' AND name LIKE 't%';
+SHOW TABLES FROM showtabletest_not_exists; -- {ErrorCode 1003}
+DROP DATABASE showtabletest;
+
 DROP TABLE IF EXISTS t1;
 DROP TABLE IF EXISTS t2;
 DROP TABLE IF EXISTS t3;
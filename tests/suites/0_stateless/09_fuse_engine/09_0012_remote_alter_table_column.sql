@@ -0,0 +1,25 @@
+DROP DATABASE IF EXISTS db_alter;
+CREATE DATABASE db_alter;
+USE db_alter;
+
+CREATE TABLE t(a int) Engine = fuse;
+INSERT INTO t VALUES(1),(2);
+
+ALTER TABLE t ADD COLUMN b varchar DEFAULT 'x';
+ALTER TABLE t ADD c int;
+INSERT INTO t VALUES(3, 'y', 30);
+SELECT * FROM t ORDER BY a;
+SELECT a FROM t WHERE c IS NULL ORDER BY a;
+SELECT a FROM t WHERE c > 10;
+
+ALTER TABLE t DROP COLUMN b;
+SELECT * FROM t ORDER BY a;
+SELECT b FROM t; -- {ErrorCode 1058}
+
+ALTER TABLE t DROP COLUMN b; -- {ErrorCode 1058}
+ALTER TABLE t ADD COLUMN a int; -- {ErrorCode 1006}
+ALTER TABLE t1 ADD COLUMN a int; -- {ErrorCode 1025}
+ALTER TABLE IF EXISTS t1 ADD COLUMN a int;
+
+DROP TABLE t;
+DROP DATABASE db_alter;
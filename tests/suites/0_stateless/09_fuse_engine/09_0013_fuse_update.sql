@@ -0,0 +1,29 @@
+DROP DATABASE IF EXISTS db_09_0013;
+CREATE DATABASE db_09_0013;
+USE db_09_0013;
+
+CREATE TABLE t(a BIGINT, b VARCHAR, c BIGINT);
+
+INSERT INTO t VALUES (1, 'x', 10), (2, 'y', 20);
+INSERT INTO t VALUES (3, 'z', 30);
+
+-- the assigned values may refer to the other columns, evaluated before the update
+UPDATE t SET b = 'w', c = a + c WHERE a >= 2;
+SELECT * FROM t ORDER BY a;
+
+-- no row matches, nothing is updated
+UPDATE t SET a = 0 WHERE b = 'nothing';
+SELECT count(*) FROM t WHERE a = 0;
+
+-- all the rows are updated without selection
+UPDATE t SET c = c * 2;
+SELECT * FROM t ORDER BY a;
+
+-- one snapshot for each insertion, and one for each update that changed rows
+SELECT count(*) = 4 FROM fuse_history('db_09_0013', 't');
+
+UPDATE t SET a = 1, a = 2; -- {ErrorCode 1005}
+UPDATE t SET d = 1; -- {ErrorCode 1006}
+
+DROP TABLE t;
+DROP DATABASE db_09_0013;
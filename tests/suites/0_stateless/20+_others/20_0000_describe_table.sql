@@ -5,4 +5,11 @@ CREATE TABLE t(a bigint, b int, c varchar(255), d smallint, e Date ) ENGINE = Nu
 DESCRIBE t;
 DESC t;
 
+CREATE DATABASE IF NOT EXISTS db_20_0000;
+CREATE TABLE db_20_0000.t(a bigint NOT NULL, b int DEFAULT 1, c varchar NOT NULL DEFAULT 'c') ENGINE = Null;
+DESC db_20_0000.t;
+DROP DATABASE db_20_0000;
+
+DESCRIBE system.databases;
+
 DROP TABLE IF EXISTS t;
@@ -35,5 +35,5 @@ mycursor.execute("SHOW TABLES FROM db1")
 res = mycursor.fetchall()
 assert res == [('t1', )]
 
-sql = "DROP DATABASE db1;"
+sql = "DROP DATABASE db1 CASCADE;"
 client1.run(sql)
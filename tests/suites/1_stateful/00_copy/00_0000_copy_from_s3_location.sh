@@ -25,5 +25,50 @@ echo "copy into ontime200 from 's3://testbucket/admin/data/' credentials=(aws_ke
 echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime200" | $MYSQL_CLIENT_CONNECT
 
 
+# Truncate the ontime table.
+echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
+
+
+## Copy from s3 with a failure on the third file, nothing is loaded.
+echo "Test copy from files atomic"
+echo "copy into ontime200 from 's3://testbucket/admin/data/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILES = ('ontime_200.csv', 'ontime_200_v1.csv', 'not_exists.csv', 'ontime_200.csv', 'ontime_200_v1.csv') FILE_FORMAT = (type = 'CSV' field_delimiter = ','  record_delimiter = '\n' skip_header = 1)" | $MYSQL_CLIENT_CONNECT > /dev/null 2>&1
+
+## Result.
+echo "select count(1) from ontime200" | $MYSQL_CLIENT_CONNECT
+
+
+## Copy from s3 with a failure on the third file, commit per file.
+echo "Test copy from files commit per file"
+echo "copy into ontime200 from 's3://testbucket/admin/data/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILES = ('ontime_200.csv', 'ontime_200_v1.csv', 'not_exists.csv', 'ontime_200.csv', 'ontime_200_v1.csv') FILE_FORMAT = (type = 'CSV' field_delimiter = ','  record_delimiter = '\n' skip_header = 1) COMMIT_PER_FILE = true" | $MYSQL_CLIENT_CONNECT > /dev/null 2>&1
+
+## Result.
+echo "select count(1) from ontime200" | $MYSQL_CLIENT_CONNECT
+
+
+# Truncate the ontime table, the copied files are cleared too.
+echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
+
+
+## Copy the same files twice, the files loaded previously are skipped unless force.
+echo "Test copy from files twice"
+for force in false false true; do
+	echo "copy into ontime200 from 's3://testbucket/admin/data/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILES = ('ontime_200.csv', 'ontime_200_v1.csv') FILE_FORMAT = (type = 'CSV' field_delimiter = ','  record_delimiter = '\n' skip_header = 1) FORCE = $force" | $MYSQL_CLIENT_CONNECT
+	echo "select count(1) from ontime200" | $MYSQL_CLIENT_CONNECT
+done
+
+
+
+# Truncate the ontime table.
+echo "truncate table ontime200" | $MYSQL_CLIENT_CONNECT
+
+
+## Copy with purge, the loaded file is deleted from the stage.
+echo "Test copy from files with purge"
+for i in 1 2; do
+	echo "copy into ontime200 from 's3://testbucket/admin/purge/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') PATTERN = '.*[.]csv' FILE_FORMAT = (type = 'CSV' field_delimiter = ','  record_delimiter = '\n' skip_header = 1) PURGE = true" | $MYSQL_CLIENT_CONNECT
+	echo "select count(1) from ontime200" | $MYSQL_CLIENT_CONNECT
+done
+
+
 ## Drop table.
 echo "drop table ontime200" | $MYSQL_CLIENT_CONNECT